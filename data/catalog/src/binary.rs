@@ -13,6 +13,14 @@
 // limitations under the License.
 
 use crate::repr::Datum;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use data_scalar::{
+    json::Jsonb,
+    temporal::{Interval, MICROS_PER_SECOND},
+};
+use std::str::FromStr;
+use uuid::Uuid;
 
 #[repr(u8)]
 enum TypeTag {
@@ -25,6 +33,38 @@ enum TypeTag {
     F32,
     F64,
     Str,
+    /// decimal string representation that keeps the scale of a number
+    Numeric,
+    /// number of days from January 1, 1 CE
+    Date,
+    /// number of microseconds from midnight
+    Time,
+    /// number of microseconds from Unix epoch
+    Timestamp,
+    TimestampTz,
+    /// months, days and microseconds
+    Interval,
+    Bytea,
+    /// 16 bytes in big-endian order
+    Uuid,
+    /// json text as it was written
+    Json,
+    /// json document tree encoded with `JsonbTag`s
+    Jsonb,
+}
+
+/// tags of nodes in a json document tree, containers keep number of their
+/// elements followed by elements, object keys are length-prefixed strings
+#[repr(u8)]
+enum JsonbTag {
+    Null = 0,
+    False,
+    True,
+    /// decimal string representation of a number
+    Number,
+    String,
+    Array,
+    Object,
 }
 
 fn assert_copy<T: Copy>(_t: T) {}
@@ -48,17 +88,82 @@ unsafe fn read<T>(data: &[u8], idx: &mut usize) -> T {
     (ptr as *const T).read_unaligned()
 }
 
-unsafe fn read_string<'a>(data: &'a [u8], idx: &mut usize) -> &'a str {
+unsafe fn read_bytes<'a>(data: &'a [u8], idx: &mut usize) -> &'a [u8] {
     let len = read::<usize>(data, idx);
     let data = &data[*idx..*idx + len];
     *idx += len;
-    std::str::from_utf8_unchecked(data)
+    data
+}
+
+unsafe fn read_string<'a>(data: &'a [u8], idx: &mut usize) -> &'a str {
+    std::str::from_utf8_unchecked(read_bytes(data, idx))
 }
 
 fn read_tag(data: &[u8], idx: &mut usize) -> TypeTag {
     unsafe { read::<TypeTag>(data, idx) }
 }
 
+fn push_str(data: &mut Vec<u8>, val: &str) {
+    push_copy!(data, val.len(), usize);
+    data.extend_from_slice(val.as_bytes());
+}
+
+fn push_jsonb(data: &mut Vec<u8>, val: &Jsonb) {
+    match val {
+        Jsonb::Null => data.push(JsonbTag::Null as u8),
+        Jsonb::Bool(false) => data.push(JsonbTag::False as u8),
+        Jsonb::Bool(true) => data.push(JsonbTag::True as u8),
+        Jsonb::Number(number) => {
+            data.push(JsonbTag::Number as u8);
+            push_str(data, &number.to_string());
+        }
+        Jsonb::String(string) => {
+            data.push(JsonbTag::String as u8);
+            push_str(data, string);
+        }
+        Jsonb::Array(elements) => {
+            data.push(JsonbTag::Array as u8);
+            push_copy!(data, elements.len(), usize);
+            for element in elements {
+                push_jsonb(data, element);
+            }
+        }
+        Jsonb::Object(pairs) => {
+            data.push(JsonbTag::Object as u8);
+            push_copy!(data, pairs.len(), usize);
+            for (key, value) in pairs {
+                push_str(data, key);
+                push_jsonb(data, value);
+            }
+        }
+    }
+}
+
+unsafe fn read_jsonb(data: &[u8], idx: &mut usize) -> Jsonb {
+    match read::<JsonbTag>(data, idx) {
+        JsonbTag::Null => Jsonb::Null,
+        JsonbTag::False => Jsonb::Bool(false),
+        JsonbTag::True => Jsonb::Bool(true),
+        JsonbTag::Number => Jsonb::Number(BigDecimal::from_str(read_string(data, idx)).unwrap()),
+        JsonbTag::String => Jsonb::String(read_string(data, idx).to_owned()),
+        JsonbTag::Array => {
+            let len = read::<usize>(data, idx);
+            Jsonb::Array((0..len).map(|_| read_jsonb(data, idx)).collect())
+        }
+        JsonbTag::Object => {
+            let len = read::<usize>(data, idx);
+            Jsonb::Object(
+                (0..len)
+                    .map(|_| {
+                        let key = read_string(data, idx).to_owned();
+                        (key, read_jsonb(data, idx))
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
 /// in-memory runtime representation of a table row. It is unable to deserialize
 /// the row without knowing the types of each column, which makes this unsafe
 /// however it is more memory efficient.
@@ -115,6 +220,51 @@ impl Binary {
                     push_copy!(&mut data, val.len(), usize);
                     data.extend_from_slice(val.as_bytes());
                 }
+                Datum::Numeric(val) => {
+                    let val = val.to_string();
+                    push_tag(&mut data, TypeTag::Numeric);
+                    push_copy!(&mut data, val.len(), usize);
+                    data.extend_from_slice(val.as_bytes());
+                }
+                Datum::Date(val) => {
+                    push_tag(&mut data, TypeTag::Date);
+                    push_copy!(&mut data, val.num_days_from_ce(), i32);
+                }
+                Datum::Time(val) => {
+                    push_tag(&mut data, TypeTag::Time);
+                    push_copy!(&mut data, time_micros(val), i64);
+                }
+                Datum::Timestamp(val) => {
+                    push_tag(&mut data, TypeTag::Timestamp);
+                    push_copy!(&mut data, timestamp_micros(val), i64);
+                }
+                Datum::TimestampTz(val) => {
+                    push_tag(&mut data, TypeTag::TimestampTz);
+                    push_copy!(&mut data, timestamp_micros(&val.naive_utc()), i64);
+                }
+                Datum::Interval(val) => {
+                    push_tag(&mut data, TypeTag::Interval);
+                    push_copy!(&mut data, val.months, i32);
+                    push_copy!(&mut data, val.days, i32);
+                    push_copy!(&mut data, val.micros, i64);
+                }
+                Datum::Bytea(val) => {
+                    push_tag(&mut data, TypeTag::Bytea);
+                    push_copy!(&mut data, val.len(), usize);
+                    data.extend_from_slice(val);
+                }
+                Datum::Uuid(val) => {
+                    push_tag(&mut data, TypeTag::Uuid);
+                    data.extend_from_slice(val.as_bytes());
+                }
+                Datum::Json(val) => {
+                    push_tag(&mut data, TypeTag::Json);
+                    push_str(&mut data, val);
+                }
+                Datum::Jsonb(val) => {
+                    push_tag(&mut data, TypeTag::Jsonb);
+                    push_jsonb(&mut data, val);
+                }
                 Datum::Null => push_tag(&mut data, TypeTag::Null),
             }
         }
@@ -164,12 +314,70 @@ fn unpack_raw(data: &[u8]) -> Vec<Datum> {
                 let val = unsafe { read::<f64>(data, &mut index) };
                 Datum::from_f64(val)
             }
+            TypeTag::Numeric => {
+                let val = unsafe { read_string(data, &mut index) };
+                Datum::Numeric(BigDecimal::from_str(val).unwrap())
+            }
+            TypeTag::Date => {
+                let val = unsafe { read::<i32>(data, &mut index) };
+                Datum::Date(NaiveDate::from_num_days_from_ce(val))
+            }
+            TypeTag::Time => {
+                let val = unsafe { read::<i64>(data, &mut index) };
+                Datum::Time(NaiveTime::from_num_seconds_from_midnight(
+                    (val / MICROS_PER_SECOND) as u32,
+                    (val % MICROS_PER_SECOND * 1_000) as u32,
+                ))
+            }
+            TypeTag::Timestamp => {
+                let val = unsafe { read::<i64>(data, &mut index) };
+                Datum::Timestamp(from_timestamp_micros(val))
+            }
+            TypeTag::TimestampTz => {
+                let val = unsafe { read::<i64>(data, &mut index) };
+                Datum::TimestampTz(DateTime::from_utc(from_timestamp_micros(val), Utc))
+            }
+            TypeTag::Interval => {
+                let months = unsafe { read::<i32>(data, &mut index) };
+                let days = unsafe { read::<i32>(data, &mut index) };
+                let micros = unsafe { read::<i64>(data, &mut index) };
+                Datum::Interval(Interval::new(months, days, micros))
+            }
+            TypeTag::Bytea => {
+                let val = unsafe { read_bytes(data, &mut index) };
+                Datum::Bytea(val.to_vec())
+            }
+            TypeTag::Uuid => {
+                let val = Uuid::from_slice(&data[index..index + 16]).unwrap();
+                index += 16;
+                Datum::Uuid(val)
+            }
+            TypeTag::Json => {
+                let val = unsafe { read_string(data, &mut index) };
+                Datum::Json(val.to_owned())
+            }
+            TypeTag::Jsonb => Datum::Jsonb(unsafe { read_jsonb(data, &mut index) }),
         };
         res.push(datum)
     }
     res
 }
 
+fn time_micros(time: &NaiveTime) -> i64 {
+    time.num_seconds_from_midnight() as i64 * MICROS_PER_SECOND + time.nanosecond() as i64 / 1_000
+}
+
+fn timestamp_micros(timestamp: &NaiveDateTime) -> i64 {
+    timestamp.timestamp() * MICROS_PER_SECOND + timestamp.timestamp_subsec_micros() as i64
+}
+
+fn from_timestamp_micros(micros: i64) -> NaiveDateTime {
+    NaiveDateTime::from_timestamp(
+        micros.div_euclid(MICROS_PER_SECOND),
+        (micros.rem_euclid(MICROS_PER_SECOND) * 1_000) as u32,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,6 +407,70 @@ mod tests {
             assert_eq!(data, row.unpack());
         }
 
+        #[test]
+        fn numerics() {
+            let data = vec![
+                Datum::Numeric(BigDecimal::from_str("12345678901234567890.120").unwrap()),
+                Datum::Numeric(BigDecimal::from_str("-0.001").unwrap()),
+            ];
+            let row = Binary::pack(&data);
+            let unpacked = row.unpack();
+            assert_eq!(data, unpacked);
+            assert_eq!(unpacked[0].to_string(), "12345678901234567890.120");
+        }
+
+        #[test]
+        fn temporals() {
+            let timestamp = NaiveDate::from_ymd(1969, 7, 20).and_hms_micro(20, 17, 40, 500);
+            let data = vec![
+                Datum::Date(NaiveDate::from_ymd(1969, 7, 20)),
+                Datum::Time(NaiveTime::from_hms_micro(20, 17, 40, 123_456)),
+                Datum::Timestamp(timestamp),
+                Datum::TimestampTz(DateTime::from_utc(timestamp, Utc)),
+                Datum::Interval(Interval::new(14, -3, 3_600_000_001)),
+            ];
+            let row = Binary::pack(&data);
+            let unpacked = row.unpack();
+            assert_eq!(data, unpacked);
+            assert_eq!(unpacked[2].to_string(), "1969-07-20 20:17:40.000500");
+            assert_eq!(unpacked[4].to_string(), "1 year 2 mons -3 days +01:00:00.000001");
+        }
+
+        #[test]
+        fn binary_strings_and_uuids() {
+            let uuid = Uuid::parse_str("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11").unwrap();
+            let data = vec![
+                Datum::Bytea(vec![0xde, 0xad, 0x00, 0xbe, 0xef]),
+                Datum::Uuid(uuid),
+                Datum::Bytea(vec![]),
+            ];
+            let row = Binary::pack(&data);
+            let unpacked = row.unpack();
+            assert_eq!(data, unpacked);
+            assert_eq!(unpacked[0].to_string(), "\\xdead00beef");
+            assert_eq!(unpacked[1].to_string(), "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11");
+        }
+
+        #[test]
+        fn json_documents() {
+            let jsonb =
+                data_scalar::json::parse_json(r#"{"a": [1, 2.50, null], "bb": {"c": true, "d": "e"}}"#).unwrap();
+            let data = vec![
+                Datum::Json(r#"{"a" : 1}"#.to_owned()),
+                Datum::Jsonb(jsonb),
+                Datum::Jsonb(Jsonb::Array(vec![])),
+                Datum::from_i32(1),
+            ];
+            let row = Binary::pack(&data);
+            let unpacked = row.unpack();
+            assert_eq!(data, unpacked);
+            assert_eq!(unpacked[0].to_string(), r#"{"a" : 1}"#);
+            assert_eq!(
+                unpacked[1].to_string(),
+                r#"{"a": [1, 2.50, null], "bb": {"c": true, "d": "e"}}"#
+            );
+        }
+
         #[test]
         fn integers() {
             let data = vec![Datum::from_i16(100), Datum::from_i32(1_000), Datum::from_i64(10_000)];
@@ -0,0 +1,2006 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data_definition_operations::{
+    ExecutionError, ExecutionOutcome, Kind, ObjectState, Record, Step, SystemObject, SystemOperation,
+};
+use data_manipulation_evaluation::{EvalError, Evaluation};
+use data_manipulation_query_result::QueryExecutionError;
+use data_manipulation_typed_tree::{DynamicTypedItem, DynamicTypedTree, StaticTypedTree};
+use data_scalar::ScalarValue;
+use definition::{
+    CheckDef, ColumnConstraints, ColumnDef, FullTableName, IndexDef, IndexKind, ReferentialAction, SchemaName, TableDef,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Bound,
+    sync::Arc,
+};
+use types::SqlType;
+
+use crate::{
+    binary::Binary,
+    index::IndexRange,
+    repr::Datum,
+    transaction::{TableChanges, TableName, Transaction, TransactionTable, Transactions, Version},
+    CatalogDefinition, Checks, Cursor, DataCatalog, DataTable, Key, SchemaHandle, SqlTable, Value, COLUMNS_TABLE,
+    DEFINITION_SCHEMA, INDEXES_TABLE, REFERENTIAL_CONSTRAINTS_TABLE, SCHEMATA_TABLE, TABLES_TABLE,
+};
+
+fn create_public_schema() -> SystemOperation {
+    SystemOperation {
+        kind: Kind::Create(SystemObject::Schema),
+        skip_steps_if: None,
+        steps: vec![vec![
+            Step::CheckExistence {
+                system_object: SystemObject::Schema,
+                object_name: vec!["public".to_owned()],
+            },
+            Step::CreateFolder {
+                name: "public".to_owned(),
+            },
+            Step::CreateRecord {
+                record: Record::Schema {
+                    schema_name: "public".to_owned(),
+                },
+            },
+        ]],
+    }
+}
+
+/// finishes catalog operation even if its execution panicked
+struct OperationGuard<'c, C: DataCatalog> {
+    catalog: &'c C,
+}
+
+impl<'c, C: DataCatalog> OperationGuard<'c, C> {
+    fn start(catalog: &'c C) -> OperationGuard<'c, C> {
+        catalog.start_operation();
+        OperationGuard { catalog }
+    }
+}
+
+impl<'c, C: DataCatalog> Drop for OperationGuard<'c, C> {
+    fn drop(&mut self) {
+        self.catalog.finish_operation();
+    }
+}
+
+pub struct DatabaseHandle<C> {
+    catalog: C,
+    transactions: Arc<Transactions>,
+}
+
+impl<C> DatabaseHandle<C> {
+    pub(crate) fn create(catalog: C) -> DatabaseHandle<C> {
+        DatabaseHandle {
+            catalog,
+            transactions: Arc::default(),
+        }
+    }
+
+    pub(crate) fn bootstrap(self) -> DatabaseHandle<C>
+    where
+        C: DataCatalog,
+    {
+        self.catalog.create_schema(DEFINITION_SCHEMA);
+        self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+            schema.create_table(SCHEMATA_TABLE);
+            schema.create_table(TABLES_TABLE);
+            schema.create_table(COLUMNS_TABLE);
+            schema.create_table(INDEXES_TABLE);
+            schema.create_table(REFERENTIAL_CONSTRAINTS_TABLE);
+        });
+        let fresh_catalog = self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+            schema.work_with(SCHEMATA_TABLE, |table| table.select().next().is_none())
+        });
+        if fresh_catalog == Some(Some(true)) {
+            let public_schema = self.execute_system_operation(create_public_schema());
+            debug_assert!(
+                matches!(public_schema, Ok(_)),
+                "Default `public` schema has to be created, but failed due to {:?}",
+                public_schema
+            );
+        }
+        for (full_table_name, index) in self.indexes() {
+            let columns = self.column_positions(&full_table_name, index.columns());
+            self.catalog.work_with(full_table_name.schema(), |schema| {
+                schema.work_with(full_table_name.table(), |table| {
+                    table.create_index(index.name(), columns.clone(), index.kind())
+                })
+            });
+        }
+        self
+    }
+
+    fn schema_exists(&self, schema_name: &str) -> bool
+    where
+        C: DataCatalog,
+    {
+        let full_schema_name = Binary::pack(&[
+            Datum::from_string("IN_MEMORY".to_owned()),
+            Datum::from_string(schema_name.to_owned()),
+        ]);
+        log::debug!("RECORD - {:?}", full_schema_name);
+        let schema = self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+            schema.work_with(SCHEMATA_TABLE, |table| {
+                table.select().any(|(_key, value)| value == full_schema_name)
+            })
+        });
+        schema == Some(Some(true))
+    }
+
+    fn table_exists(&self, full_table_name: &FullTableName) -> bool
+    where
+        C: DataCatalog,
+    {
+        let full_table_name = Binary::pack(&[
+            Datum::from_string("IN_MEMORY".to_owned()),
+            Datum::from_string((&full_table_name).schema().to_owned()),
+            Datum::from_string((&full_table_name).table().to_owned()),
+        ]);
+        let table = self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+            schema.work_with(TABLES_TABLE, |table| {
+                table.select().any(|(_key, value)| value == full_table_name)
+            })
+        });
+        table == Some(Some(true))
+    }
+
+    fn table_columns(&self, full_table_name: &FullTableName) -> Vec<ColumnDef>
+    where
+        C: DataCatalog,
+    {
+        let full_table_name = Binary::pack(&[
+            Datum::from_string("IN_MEMORY".to_owned()),
+            Datum::from_string((&full_table_name).schema().to_owned()),
+            Datum::from_string((&full_table_name).table().to_owned()),
+        ]);
+        self.catalog
+            .work_with(DEFINITION_SCHEMA, |schema| {
+                schema.work_with(COLUMNS_TABLE, |table| {
+                    table
+                        .select()
+                        .filter(|(_key, value)| value.start_with(&full_table_name))
+                        .map(|(_key, value)| {
+                            let row = value.unpack();
+                            let name = row[3].as_string();
+                            let sql_type = SqlType::from_type_id(row[4].as_u64(), row[5].as_u64());
+                            let ord_num = row[6].as_u64() as usize;
+                            let check = match (row[9].as_optional_string(), row[10].as_optional_string()) {
+                                (Some(name), Some(predicate)) => Some(CheckDef { name, predicate }),
+                                _ => None,
+                            };
+                            ColumnDef::new(name, sql_type, ord_num).with_constraints(ColumnConstraints {
+                                not_null: row[7].as_bool(),
+                                default_expr: row[8].as_optional_string(),
+                                check,
+                            })
+                        })
+                        .collect()
+                })
+            })
+            .unwrap()
+            .unwrap()
+    }
+
+    /// positions of values of `column_names` in records of the table
+    fn column_positions(&self, full_table_name: &FullTableName, column_names: &[String]) -> Vec<usize>
+    where
+        C: DataCatalog,
+    {
+        let columns = self.table_columns(full_table_name);
+        column_names
+            .iter()
+            .filter_map(|name| columns.iter().position(|column| column.has_name(name)))
+            .collect()
+    }
+
+    /// indexes of unique and primary key constraints of the table
+    fn unique_keys(&self, full_table_name: &FullTableName) -> Vec<UniqueKey>
+    where
+        C: DataCatalog,
+    {
+        self.table_indexes(full_table_name)
+            .into_iter()
+            .filter(|index| index.kind().is_unique())
+            .map(|index| UniqueKey {
+                index_name: index.name().to_owned(),
+                columns: self.column_positions(full_table_name, index.columns()),
+                primary: index.kind() == IndexKind::PrimaryKey,
+            })
+            .collect()
+    }
+
+    /// index records consist of schema, table and index names and kind of the index
+    /// followed by names of indexed columns
+    fn indexes(&self) -> Vec<(FullTableName, IndexDef)>
+    where
+        C: DataCatalog,
+    {
+        self.catalog
+            .work_with(DEFINITION_SCHEMA, |schema| {
+                schema.work_with(INDEXES_TABLE, |table| {
+                    table
+                        .select()
+                        .map(|(_key, value)| {
+                            let row = value.unpack();
+                            (
+                                FullTableName::from((&row[1].as_string(), &row[2].as_string())),
+                                IndexDef::new(
+                                    row[3].as_string(),
+                                    row[5..].iter().map(|column| column.as_string()).collect(),
+                                    IndexKind::from_kind_id(row[4].as_u64()),
+                                ),
+                            )
+                        })
+                        .collect()
+                })
+            })
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// key and values of a record of the index
+    fn index_record(&self, schema_name: &str, index_name: &str) -> Option<(Key, Vec<Datum>)>
+    where
+        C: DataCatalog,
+    {
+        self.catalog
+            .work_with(DEFINITION_SCHEMA, |schema| {
+                schema.work_with(INDEXES_TABLE, |table| {
+                    table
+                        .select()
+                        .map(|(key, value)| (key, value.unpack()))
+                        .find(|(_key, row)| row[1].as_string() == schema_name && row[3].as_string() == index_name)
+                })
+            })
+            .flatten()
+            .flatten()
+    }
+
+    fn remove_index_records<P: Fn(&[Datum]) -> bool>(&self, predicate: P)
+    where
+        C: DataCatalog,
+    {
+        self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+            schema.work_with(INDEXES_TABLE, |table| {
+                let index_ids = table
+                    .select()
+                    .filter(|(_key, value)| predicate(&value.unpack()))
+                    .map(|(key, _value)| key)
+                    .collect();
+                log::debug!("index IDs {:?}", index_ids);
+                table.delete(index_ids);
+            })
+        });
+    }
+
+    /// foreign key records consist of schema, table and constraint names, referenced schema and
+    /// table names, referential actions and number of columns followed by names of referencing
+    /// and then referenced columns
+    fn foreign_key_records(&self) -> Vec<(Key, Vec<Datum>)>
+    where
+        C: DataCatalog,
+    {
+        self.catalog
+            .work_with(DEFINITION_SCHEMA, |schema| {
+                schema.work_with(REFERENTIAL_CONSTRAINTS_TABLE, |table| {
+                    table.select().map(|(key, value)| (key, value.unpack())).collect()
+                })
+            })
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// foreign keys of tables and tables they reference that satisfy `predicate`
+    fn foreign_keys<P: Fn(&FullTableName, &FullTableName) -> bool>(&self, predicate: P) -> Vec<ForeignKey>
+    where
+        C: DataCatalog,
+    {
+        self.foreign_key_records()
+            .into_iter()
+            .filter_map(|(_key, row)| {
+                let table = FullTableName::from((&row[1].as_string(), &row[2].as_string()));
+                let referenced_table = FullTableName::from((&row[4].as_string(), &row[5].as_string()));
+                if !predicate(&table, &referenced_table) {
+                    return None;
+                }
+                let (column_names, referenced_column_names) = foreign_key_column_names(&row);
+                let columns = self.column_positions(&table, &column_names);
+                let referenced_columns = self.column_positions(&referenced_table, &referenced_column_names);
+                let unique_key = self.unique_keys(&referenced_table).into_iter().find(|unique_key| {
+                    unique_key.columns.len() == referenced_columns.len()
+                        && referenced_columns
+                            .iter()
+                            .all(|column| unique_key.columns.contains(column))
+                })?;
+                // referencing columns are matched to columns of the index in their order
+                let columns = unique_key
+                    .columns
+                    .iter()
+                    .filter_map(|column| {
+                        referenced_columns
+                            .iter()
+                            .position(|referenced| referenced == column)
+                            .map(|position| columns[position])
+                    })
+                    .collect();
+                Some(ForeignKey {
+                    name: row[3].as_string(),
+                    table,
+                    columns,
+                    referenced_table,
+                    referenced_index: unique_key.index_name,
+                    referenced_columns: unique_key.columns,
+                    on_delete: ReferentialAction::from_action_id(row[6].as_u64()),
+                    on_update: ReferentialAction::from_action_id(row[7].as_u64()),
+                })
+            })
+            .collect()
+    }
+
+    fn remove_foreign_key_records<P: Fn(&[Datum]) -> bool>(&self, predicate: P)
+    where
+        C: DataCatalog,
+    {
+        self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+            schema.work_with(REFERENTIAL_CONSTRAINTS_TABLE, |table| {
+                let foreign_key_ids = table
+                    .select()
+                    .filter(|(_key, value)| predicate(&value.unpack()))
+                    .map(|(key, _value)| key)
+                    .collect();
+                log::debug!("foreign key IDs {:?}", foreign_key_ids);
+                table.delete(foreign_key_ids);
+            })
+        });
+    }
+
+    /// rewrites records of the system table that `change` returns `true` for
+    fn update_records<F: Fn(&mut Vec<Datum>) -> bool>(&self, system_table: &str, change: F)
+    where
+        C: DataCatalog,
+    {
+        self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+            schema.work_with(system_table, |table| {
+                let records = table
+                    .select()
+                    .filter_map(|(key, value)| {
+                        let mut row = value.unpack();
+                        if change(&mut row) {
+                            Some((key, Binary::pack(&row)))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<(Key, Value)>>();
+                log::debug!("updated records {:?}", records);
+                table.update(records);
+            })
+        });
+    }
+
+    fn table_rows(&self, full_table_name: &FullTableName) -> Vec<Vec<Datum>>
+    where
+        C: DataCatalog,
+    {
+        self.catalog
+            .work_with(full_table_name.schema(), |schema| {
+                schema.work_with(full_table_name.table(), |table| {
+                    table.select().map(|(_key, value)| value.unpack()).collect()
+                })
+            })
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// replaces data table of the schema with `new_table_name` table that consists of `rows`
+    /// and has indexes that are recorded for `new_table_name` table
+    fn rebuild_table(&self, schema_name: &str, table_name: &str, new_table_name: &str, rows: &[Vec<Datum>])
+    where
+        C: DataCatalog,
+    {
+        let full_table_name = FullTableName::from((&schema_name, &new_table_name));
+        let indexes = self
+            .table_indexes(&full_table_name)
+            .into_iter()
+            .map(|index| {
+                let columns = self.column_positions(&full_table_name, index.columns());
+                (index, columns)
+            })
+            .collect::<Vec<(IndexDef, Vec<usize>)>>();
+        let column_ords = self.catalog.work_with(schema_name, |schema| {
+            schema.work_with(table_name, |table| table.next_column_ord())
+        });
+        self.catalog.work_with(schema_name, |schema| {
+            schema.drop_table(table_name);
+            schema.create_table(new_table_name);
+            schema.work_with(new_table_name, |table| {
+                // column ords of the new table continue ords of the replaced one
+                if let Some(Some(column_ords)) = column_ords {
+                    while table.next_column_ord() < column_ords {}
+                }
+                for (index, columns) in indexes.iter() {
+                    table.create_index(index.name(), columns.clone(), index.kind());
+                }
+                table.insert(rows.iter().map(|row| Binary::pack(row)).collect());
+            });
+        });
+    }
+}
+
+impl<C: DataCatalog> CatalogDefinition for DatabaseHandle<C> {
+    fn table_definition(&self, full_table_name: &FullTableName) -> Option<Option<TableDef>> {
+        if !(self.schema_exists(full_table_name.schema())) {
+            return None;
+        }
+        if !(self.table_exists(full_table_name)) {
+            return Some(None);
+        }
+        let column_info = self.table_columns(full_table_name);
+        Some(Some(TableDef::new(full_table_name, column_info)))
+    }
+
+    fn schema_exists(&self, schema_name: &SchemaName) -> bool {
+        self.schema_exists(schema_name.as_ref())
+    }
+
+    fn table_indexes(&self, full_table_name: &FullTableName) -> Vec<IndexDef> {
+        self.indexes()
+            .into_iter()
+            .filter(|(table_name, _index)| table_name == full_table_name)
+            .map(|(_table_name, index)| index)
+            .collect()
+    }
+}
+
+impl<C> DatabaseHandle<C> {
+    pub(crate) fn catalog(&self) -> &C {
+        &self.catalog
+    }
+
+    pub(crate) fn execute_system_operation(
+        &self,
+        operation: SystemOperation,
+    ) -> Result<ExecutionOutcome, ExecutionError>
+    where
+        C: DataCatalog,
+    {
+        let _operation = OperationGuard::start(&self.catalog);
+        self.execute_steps(operation)
+    }
+
+    fn execute_steps(&self, operation: SystemOperation) -> Result<ExecutionOutcome, ExecutionError>
+    where
+        C: DataCatalog,
+    {
+        let SystemOperation {
+            kind,
+            skip_steps_if,
+            steps,
+        } = operation;
+        let end = steps.len();
+        let mut index = 0;
+        while index < end {
+            let operations = &steps[index];
+            index += 1;
+            for operation in operations {
+                log::debug!("OPERATION - {:?}", operation);
+                match operation {
+                    Step::CheckExistence {
+                        system_object,
+                        object_name,
+                    } => match system_object {
+                        SystemObject::Schema => {
+                            let result = self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(SCHEMATA_TABLE, |table| {
+                                    table.select().any(|(_key, value)| {
+                                        value
+                                            == Binary::pack(&[
+                                                Datum::from_string("IN_MEMORY".to_owned()),
+                                                Datum::from_string(object_name[0].to_owned()),
+                                            ])
+                                    })
+                                })
+                            });
+                            log::debug!("check existence for {:?} is {:?}", object_name, result);
+                            // altered table has to exist regardless of `IF EXISTS` of its column
+                            if alters_table(&kind) {
+                                if result == Some(Some(false)) {
+                                    return Err(ExecutionError::SchemaDoesNotExist(object_name[0].to_owned()));
+                                }
+                                continue;
+                            }
+                            match skip_steps_if {
+                                None => {
+                                    if let (&Kind::Create(SystemObject::Schema), Some(Some(true))) = (&kind, result) {
+                                        return Err(ExecutionError::SchemaAlreadyExists(object_name[0].to_owned()));
+                                    }
+                                    if let (&Kind::Drop(SystemObject::Schema), Some(Some(false))) = (&kind, result) {
+                                        return Err(ExecutionError::SchemaDoesNotExist(object_name[0].to_owned()));
+                                    }
+                                    if let (&Kind::Create(SystemObject::Table), Some(Some(false))) = (&kind, result) {
+                                        return Err(ExecutionError::SchemaDoesNotExist(object_name[0].to_owned()));
+                                    }
+                                    if let (&Kind::Drop(SystemObject::Table), Some(Some(false))) = (&kind, result) {
+                                        return Err(ExecutionError::SchemaDoesNotExist(object_name[0].to_owned()));
+                                    }
+                                    if let (&Kind::Create(SystemObject::Index), Some(Some(false))) = (&kind, result) {
+                                        return Err(ExecutionError::SchemaDoesNotExist(object_name[0].to_owned()));
+                                    }
+                                    if let (&Kind::Drop(SystemObject::Index), Some(Some(false))) = (&kind, result) {
+                                        return Err(ExecutionError::SchemaDoesNotExist(object_name[0].to_owned()));
+                                    }
+                                }
+                                Some(ObjectState::NotExists) if result == Some(Some(false)) => break,
+                                Some(ObjectState::NotExists) => {}
+                                Some(ObjectState::Exists) => {}
+                            }
+                        }
+                        SystemObject::Table => {
+                            let result = self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(TABLES_TABLE, |table| {
+                                    table.select().any(|(_key, value)| {
+                                        value
+                                            == Binary::pack(&[
+                                                Datum::from_string("IN_MEMORY".to_owned()),
+                                                Datum::from_string(object_name[0].clone()),
+                                                Datum::from_string(object_name[1].clone()),
+                                            ])
+                                    })
+                                })
+                            });
+                            // index could be created only for an existing table regardless of `IF NOT EXISTS`
+                            if kind == Kind::Create(SystemObject::Index) {
+                                if result == Some(Some(false)) {
+                                    return Err(ExecutionError::TableDoesNotExist(
+                                        object_name[0].to_owned(),
+                                        object_name[1].to_owned(),
+                                    ));
+                                }
+                                continue;
+                            }
+                            if alters_table(&kind) {
+                                if result == Some(Some(false)) {
+                                    return Err(ExecutionError::TableDoesNotExist(
+                                        object_name[0].to_owned(),
+                                        object_name[1].to_owned(),
+                                    ));
+                                }
+                                continue;
+                            }
+                            match skip_steps_if {
+                                None => {
+                                    if let (&Kind::Create(SystemObject::Table), Some(Some(true))) = (&kind, result) {
+                                        return Err(ExecutionError::TableAlreadyExists(
+                                            object_name[0].to_owned(),
+                                            object_name[1].to_owned(),
+                                        ));
+                                    }
+                                    if let (&Kind::Drop(SystemObject::Table), Some(Some(false))) = (&kind, result) {
+                                        return Err(ExecutionError::TableDoesNotExist(
+                                            object_name[0].to_owned(),
+                                            object_name[1].to_owned(),
+                                        ));
+                                    }
+                                }
+                                Some(ObjectState::NotExists) if result == Some(Some(false)) => break,
+                                Some(ObjectState::NotExists) => {}
+                                Some(ObjectState::Exists) => break,
+                            }
+                        }
+                        SystemObject::Index => {
+                            let record = self.index_record(&object_name[0], &object_name[1]);
+                            let exists = record.is_some();
+                            // indexes of constraints are created with a new table regardless of `IF NOT EXISTS`
+                            if kind == Kind::Create(SystemObject::Table) {
+                                if exists {
+                                    return Err(ExecutionError::IndexAlreadyExists(
+                                        object_name[0].to_owned(),
+                                        object_name[1].to_owned(),
+                                    ));
+                                }
+                                continue;
+                            }
+                            if let Some((_key, row)) = record {
+                                if kind == Kind::Drop(SystemObject::Index)
+                                    && IndexKind::from_kind_id(row[4].as_u64()).is_unique()
+                                {
+                                    return Err(ExecutionError::IndexIsRequiredByConstraint(
+                                        object_name[0].to_owned(),
+                                        row[2].as_string(),
+                                        object_name[1].to_owned(),
+                                    ));
+                                }
+                            }
+                            match skip_steps_if {
+                                None => {
+                                    if let (&Kind::Create(SystemObject::Index), true) = (&kind, exists) {
+                                        return Err(ExecutionError::IndexAlreadyExists(
+                                            object_name[0].to_owned(),
+                                            object_name[1].to_owned(),
+                                        ));
+                                    }
+                                    if let (&Kind::Drop(SystemObject::Index), false) = (&kind, exists) {
+                                        return Err(ExecutionError::IndexDoesNotExist(
+                                            object_name[0].to_owned(),
+                                            object_name[1].to_owned(),
+                                        ));
+                                    }
+                                }
+                                Some(ObjectState::NotExists) if !exists => break,
+                                Some(ObjectState::Exists) if exists => break,
+                                Some(_) => {}
+                            }
+                        }
+                        SystemObject::Column => {
+                            let exists = self
+                                .table_columns(&FullTableName::from((&object_name[0], &object_name[1])))
+                                .iter()
+                                .any(|column| column.has_name(&object_name[2]));
+                            if skip_steps_if == Some(ObjectState::NotExists) && !exists {
+                                break;
+                            }
+                            if kind == Kind::Create(SystemObject::Column) && exists {
+                                return Err(ExecutionError::ColumnAlreadyExists(
+                                    object_name[1].to_owned(),
+                                    object_name[2].to_owned(),
+                                ));
+                            }
+                            if kind != Kind::Create(SystemObject::Column) && !exists {
+                                return Err(ExecutionError::ColumnDoesNotExist(
+                                    object_name[1].to_owned(),
+                                    object_name[2].to_owned(),
+                                ));
+                            }
+                        }
+                    },
+                    Step::CheckDependants {
+                        system_object,
+                        object_name,
+                    } => match system_object {
+                        SystemObject::Schema => {
+                            let result = self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                let schema_id = Binary::pack(&[
+                                    Datum::from_string("IN_MEMORY".to_owned()),
+                                    Datum::from_string(object_name[0].clone()),
+                                ]);
+                                schema.work_with(TABLES_TABLE, |table| {
+                                    table.select().any(|(_key, value)| value.start_with(&schema_id))
+                                })
+                            });
+
+                            if let Some(Some(true)) = result {
+                                return Err(ExecutionError::SchemaHasDependentObjects(object_name[0].to_owned()));
+                            }
+                        }
+                        SystemObject::Table => {
+                            // a table could reference itself
+                            let referenced = self.foreign_key_records().into_iter().any(|(_key, row)| {
+                                row[4].as_string() == object_name[0]
+                                    && row[5].as_string() == object_name[1]
+                                    && (row[1].as_string() != object_name[0] || row[2].as_string() != object_name[1])
+                            });
+                            if referenced {
+                                return Err(ExecutionError::TableHasDependentObjects(
+                                    object_name[0].to_owned(),
+                                    object_name[1].to_owned(),
+                                ));
+                            }
+                        }
+                        SystemObject::Column => {
+                            let referenced = self.foreign_key_records().into_iter().any(|(_key, row)| {
+                                row[4].as_string() == object_name[0]
+                                    && row[5].as_string() == object_name[1]
+                                    && (row[1].as_string() != object_name[0] || row[2].as_string() != object_name[1])
+                                    && foreign_key_column_names(&row).1.contains(&object_name[2])
+                            });
+                            if referenced {
+                                return Err(ExecutionError::ColumnHasDependentObjects(
+                                    object_name[1].to_owned(),
+                                    object_name[2].to_owned(),
+                                ));
+                            }
+                        }
+                        SystemObject::Index => {}
+                    },
+                    Step::RemoveDependants {
+                        system_object: SystemObject::Table,
+                        object_name,
+                    } => {
+                        self.remove_foreign_key_records(|row| {
+                            row[4].as_string() == object_name[0] && row[5].as_string() == object_name[1]
+                        });
+                    }
+                    Step::RemoveDependants {
+                        system_object: SystemObject::Column,
+                        object_name,
+                    } => {
+                        self.remove_foreign_key_records(|row| {
+                            row[4].as_string() == object_name[0]
+                                && row[5].as_string() == object_name[1]
+                                && foreign_key_column_names(row).1.contains(&object_name[2])
+                        });
+                    }
+                    Step::RemoveDependants { .. } => {}
+                    Step::RemoveForeignKeys {
+                        schema_name,
+                        table_name,
+                    } => {
+                        self.remove_foreign_key_records(|row| {
+                            &row[1].as_string() == schema_name && &row[2].as_string() == table_name
+                        });
+                    }
+                    Step::RemoveIndexes {
+                        schema_name,
+                        table_name,
+                    } => {
+                        self.remove_index_records(|row| {
+                            &row[1].as_string() == schema_name && &row[2].as_string() == table_name
+                        });
+                    }
+                    Step::CreateIndex {
+                        schema_name,
+                        table_name,
+                        index_name,
+                        column_names,
+                        kind,
+                    } => {
+                        let columns =
+                            self.column_positions(&FullTableName::from((schema_name, table_name)), column_names);
+                        self.catalog.work_with(schema_name, |schema| {
+                            schema.work_with(table_name, |table| {
+                                table.create_index(index_name, columns.clone(), *kind)
+                            })
+                        });
+                    }
+                    Step::RemoveIndex {
+                        schema_name,
+                        index_name,
+                    } => {
+                        if let Some((key, row)) = self.index_record(schema_name, index_name) {
+                            self.catalog.work_with(schema_name, |schema| {
+                                schema.work_with(&row[2].as_string(), |table| table.drop_index(index_name))
+                            });
+                            self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(INDEXES_TABLE, |table| table.delete(vec![key.clone()]))
+                            });
+                        }
+                    }
+                    Step::RemoveColumns {
+                        schema_name,
+                        table_name,
+                    } => {
+                        let full_table_name = Binary::pack(&[
+                            Datum::from_string("IN_MEMORY".to_owned()),
+                            Datum::from_string(schema_name.clone()),
+                            Datum::from_string(table_name.clone()),
+                        ]);
+                        self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                            schema.work_with(COLUMNS_TABLE, |table| {
+                                let column_ids = table
+                                    .select()
+                                    .filter(|(_key, value)| value.start_with(&full_table_name))
+                                    .map(|(key, _value)| key)
+                                    .collect();
+                                log::debug!("column IDs {:?}", column_ids);
+                                table.delete(column_ids);
+                            })
+                        });
+                    }
+                    Step::AddColumnValues {
+                        schema_name,
+                        table_name,
+                        column_name,
+                        nullable,
+                    } => {
+                        let rows = self.table_rows(&FullTableName::from((schema_name, table_name)));
+                        if !nullable && !rows.is_empty() {
+                            return Err(ExecutionError::ColumnContainsNulls(
+                                table_name.to_owned(),
+                                column_name.to_owned(),
+                            ));
+                        }
+                        let rows = rows
+                            .into_iter()
+                            .map(|mut row| {
+                                row.push(Datum::from_null());
+                                row
+                            })
+                            .collect::<Vec<Vec<Datum>>>();
+                        self.rebuild_table(schema_name, table_name, table_name, &rows);
+                    }
+                    Step::RemoveColumn {
+                        schema_name,
+                        table_name,
+                        column_name,
+                    } => {
+                        let full_table_name = FullTableName::from((schema_name, table_name));
+                        let position = self.column_positions(&full_table_name, std::slice::from_ref(column_name))[0];
+                        let of_table = |row: &[Datum], schema_at: usize, table_at: usize| {
+                            &row[schema_at].as_string() == schema_name && &row[table_at].as_string() == table_name
+                        };
+                        self.remove_index_records(|row| {
+                            of_table(row, 1, 2) && row[5..].iter().any(|name| &name.as_string() == column_name)
+                        });
+                        self.remove_foreign_key_records(|row| {
+                            let (columns, referenced_columns) = foreign_key_column_names(row);
+                            (of_table(row, 1, 2) && columns.contains(column_name))
+                                || (of_table(row, 4, 5) && referenced_columns.contains(column_name))
+                        });
+                        self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                            schema.work_with(COLUMNS_TABLE, |table| {
+                                let column_ids = table
+                                    .select()
+                                    .map(|(key, value)| (key, value.unpack()))
+                                    .filter(|(_key, row)| of_table(row, 1, 2) && &row[3].as_string() == column_name)
+                                    .map(|(key, _row)| key)
+                                    .collect();
+                                log::debug!("column IDs {:?}", column_ids);
+                                table.delete(column_ids);
+                            })
+                        });
+                        let rows = self
+                            .table_rows(&full_table_name)
+                            .into_iter()
+                            .map(|mut row| {
+                                row.remove(position);
+                                row
+                            })
+                            .collect::<Vec<Vec<Datum>>>();
+                        self.rebuild_table(schema_name, table_name, table_name, &rows);
+                    }
+                    Step::RenameColumn {
+                        schema_name,
+                        table_name,
+                        old_column_name,
+                        new_column_name,
+                    } => {
+                        if self
+                            .table_columns(&FullTableName::from((schema_name, table_name)))
+                            .iter()
+                            .any(|column| column.has_name(new_column_name))
+                        {
+                            return Err(ExecutionError::ColumnAlreadyExists(
+                                table_name.to_owned(),
+                                new_column_name.to_owned(),
+                            ));
+                        }
+                        let of_table = |row: &[Datum], schema_at: usize, table_at: usize| {
+                            &row[schema_at].as_string() == schema_name && &row[table_at].as_string() == table_name
+                        };
+                        let rename = |names: &mut [Datum]| {
+                            let mut renamed = false;
+                            for name in names.iter_mut().filter(|name| &name.as_string() == old_column_name) {
+                                *name = Datum::from_string(new_column_name.clone());
+                                renamed = true;
+                            }
+                            renamed
+                        };
+                        // predicates of `CHECK` constraints are kept as they were written
+                        self.update_records(COLUMNS_TABLE, |row| of_table(row, 1, 2) && rename(&mut row[3..4]));
+                        self.update_records(INDEXES_TABLE, |row| of_table(row, 1, 2) && rename(&mut row[5..]));
+                        self.update_records(REFERENTIAL_CONSTRAINTS_TABLE, |row| {
+                            let len = row[8].as_u64() as usize;
+                            let columns = of_table(row, 1, 2) && rename(&mut row[9..9 + len]);
+                            let referenced_columns = of_table(row, 4, 5) && rename(&mut row[9 + len..]);
+                            columns || referenced_columns
+                        });
+                    }
+                    Step::ChangeColumnType {
+                        schema_name,
+                        table_name,
+                        column_name,
+                        sql_type,
+                    } => {
+                        let full_table_name = FullTableName::from((schema_name, table_name));
+                        let columns = self.table_columns(&full_table_name);
+                        let position = self.column_positions(&full_table_name, std::slice::from_ref(column_name))[0];
+                        let cast = DynamicTypedTree::Cast {
+                            tree: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Column(column_name.clone()))),
+                            target: *sql_type,
+                        };
+                        let evaluation = Evaluation;
+                        let mut rows = vec![];
+                        for mut row in self.table_rows(&full_table_name) {
+                            let values = row.iter().cloned().map(ScalarValue::from).collect::<Vec<ScalarValue>>();
+                            match evaluation.eval_dynamic(&cast, &columns, &values) {
+                                Ok(value) => row[position] = Datum::from(value),
+                                Err(error) => {
+                                    log::debug!("value of {:?} can't be cast due to {:?}", column_name, error);
+                                    return Err(ExecutionError::ColumnCannotBeCast(
+                                        table_name.to_owned(),
+                                        column_name.to_owned(),
+                                        *sql_type,
+                                    ));
+                                }
+                            }
+                            rows.push(row);
+                        }
+                        self.update_records(COLUMNS_TABLE, |row| {
+                            if &row[1].as_string() == schema_name
+                                && &row[2].as_string() == table_name
+                                && &row[3].as_string() == column_name
+                            {
+                                row[4] = Datum::from_u64(sql_type.type_id());
+                                row[5] = Datum::from_optional_u64(sql_type.type_modifier());
+                                true
+                            } else {
+                                false
+                            }
+                        });
+                        self.rebuild_table(schema_name, table_name, table_name, &rows);
+                    }
+                    Step::RenameTable {
+                        schema_name,
+                        table_name,
+                        new_table_name,
+                    } => {
+                        if self.table_exists(&FullTableName::from((schema_name, new_table_name))) {
+                            return Err(ExecutionError::TableAlreadyExists(
+                                schema_name.to_owned(),
+                                new_table_name.to_owned(),
+                            ));
+                        }
+                        let rows = self.table_rows(&FullTableName::from((schema_name, table_name)));
+                        let rename = |row: &mut Vec<Datum>, schema_at: usize, table_at: usize| {
+                            if &row[schema_at].as_string() == schema_name && &row[table_at].as_string() == table_name {
+                                row[table_at] = Datum::from_string(new_table_name.clone());
+                                true
+                            } else {
+                                false
+                            }
+                        };
+                        self.update_records(TABLES_TABLE, |row| rename(row, 1, 2));
+                        self.update_records(COLUMNS_TABLE, |row| rename(row, 1, 2));
+                        self.update_records(INDEXES_TABLE, |row| rename(row, 1, 2));
+                        self.update_records(REFERENTIAL_CONSTRAINTS_TABLE, |row| {
+                            let table = rename(row, 1, 2);
+                            let referenced_table = rename(row, 4, 5);
+                            table || referenced_table
+                        });
+                        self.rebuild_table(schema_name, table_name, new_table_name, &rows);
+                    }
+                    Step::CreateFolder { name } => {
+                        self.catalog.create_schema(&name);
+                    }
+                    Step::RemoveFolder { name, only_if_empty } => {
+                        match self.catalog.work_with(&name, |schema| schema.empty()) {
+                            Some(true) if *only_if_empty => {
+                                self.catalog.drop_schema(&name);
+                            }
+                            Some(_) if !*only_if_empty => {
+                                let all_tables = self.catalog.work_with(&name, |schema| schema.all_tables()).unwrap();
+                                log::debug!("tables to remove {:?}", all_tables);
+                                self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                    schema.work_with(TABLES_TABLE, |table| {
+                                        let table_ids = table
+                                            .select()
+                                            .map(|(key, value)| (key, value.unpack()))
+                                            .filter(|(_key, value)| {
+                                                &value[1].as_string() == name
+                                                    && all_tables.contains(&value[2].as_string())
+                                            })
+                                            .map(|(key, _value)| key)
+                                            .collect();
+                                        log::debug!("table IDs {:?}", table_ids);
+                                        table.delete(table_ids);
+                                    });
+                                    schema.work_with(COLUMNS_TABLE, |table| {
+                                        let columns_ids = table
+                                            .select()
+                                            .map(|(key, value)| (key, value.unpack()))
+                                            .filter(|(_key, value)| {
+                                                &value[1].as_string() == name
+                                                    && all_tables.contains(&value[2].as_string())
+                                            })
+                                            .map(|(key, _value)| key)
+                                            .collect();
+                                        log::debug!("column IDs {:?}", columns_ids);
+                                        table.delete(columns_ids);
+                                    });
+                                });
+                                self.remove_index_records(|row| &row[1].as_string() == name);
+                                self.remove_foreign_key_records(|row| {
+                                    &row[1].as_string() == name || &row[4].as_string() == name
+                                });
+                                self.catalog.drop_schema(&name);
+                            }
+                            _ => {}
+                        }
+                        return Ok(ExecutionOutcome::SchemaDropped);
+                    }
+                    Step::CreateFile { folder_name, name } => {
+                        self.catalog.work_with(folder_name, |schema| schema.create_table(name));
+                    }
+                    Step::RemoveFile { folder_name, name } => {
+                        self.catalog.work_with(folder_name, |schema| schema.drop_table(name));
+                    }
+                    Step::RemoveRecord { record } => match record {
+                        Record::Schema { schema_name } => {
+                            let full_schema_name = Binary::pack(&[
+                                Datum::from_string("IN_MEMORY".to_owned()),
+                                Datum::from_string(schema_name.clone()),
+                            ]);
+                            self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(SCHEMATA_TABLE, |table| {
+                                    let schema_id = table
+                                        .select()
+                                        .find(|(_key, value)| value == &full_schema_name)
+                                        .map(|(key, _value)| key);
+                                    debug_assert!(
+                                        matches!(schema_id, Some(_)),
+                                        "record for {:?} schema had to be found in {:?} system table",
+                                        schema_name,
+                                        SCHEMATA_TABLE
+                                    );
+                                    let schema_id = schema_id.unwrap();
+                                    table.delete(vec![schema_id]);
+                                });
+                            });
+                        }
+                        Record::Table {
+                            schema_name,
+                            table_name,
+                        } => {
+                            let full_table_name = Binary::pack(&[
+                                Datum::from_string("IN_MEMORY".to_owned()),
+                                Datum::from_string(schema_name.to_owned()),
+                                Datum::from_string(table_name.to_owned()),
+                            ]);
+                            self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(TABLES_TABLE, |table| {
+                                    let table_id = table
+                                        .select()
+                                        .find(|(_key, value)| value == &full_table_name)
+                                        .map(|(key, _value)| key);
+                                    debug_assert!(
+                                        matches!(table_id, Some(_)),
+                                        "record for {:?}.{:?} table had to be found in {:?} system table",
+                                        schema_name,
+                                        table_name,
+                                        TABLES_TABLE
+                                    );
+                                    log::debug!("FOUND TABLE ID - {:?}", table_id);
+                                    let table_id = table_id.unwrap();
+                                    table.delete(vec![table_id]);
+                                    let table_id = table
+                                        .select()
+                                        .find(|(_key, value)| value == &full_table_name)
+                                        .map(|(key, _value)| key);
+                                    log::debug!("TABLE ID AFTER DROP - {:?}", table_id);
+                                });
+                            });
+                        }
+                        Record::Column { .. } | Record::Index { .. } | Record::ForeignKey { .. } => unimplemented!(),
+                    },
+                    Step::CreateRecord { record } => match record {
+                        Record::Schema { schema_name } => {
+                            self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(SCHEMATA_TABLE, |table| {
+                                    table.insert(vec![Binary::pack(&[
+                                        Datum::from_string("IN_MEMORY".to_owned()),
+                                        Datum::from_string(schema_name.clone()),
+                                    ])])
+                                })
+                            });
+                            return Ok(ExecutionOutcome::SchemaCreated);
+                        }
+                        Record::Table {
+                            schema_name,
+                            table_name,
+                        } => {
+                            let full_table_name = Binary::pack(&[
+                                Datum::from_string("IN_MEMORY".to_owned()),
+                                Datum::from_string(schema_name.clone()),
+                                Datum::from_string(table_name.clone()),
+                            ]);
+                            self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(TABLES_TABLE, |table| {
+                                    table.insert(vec![Binary::pack(&[
+                                        Datum::from_string("IN_MEMORY".to_owned()),
+                                        Datum::from_string(schema_name.clone()),
+                                        Datum::from_string(table_name.clone()),
+                                    ])]);
+                                    let table_id = table
+                                        .select()
+                                        .find(|(_key, value)| value == &full_table_name)
+                                        .map(|(key, _value)| key);
+                                    log::debug!("GENERATED TABLE ID - {:?}", table_id);
+                                })
+                            });
+                        }
+                        Record::Column {
+                            schema_name,
+                            table_name,
+                            column_name,
+                            sql_type,
+                            constraints,
+                        } => {
+                            let ord_num = self.catalog.work_with(schema_name, |schema| {
+                                schema.work_with(table_name, |table| table.next_column_ord())
+                            });
+                            debug_assert!(
+                                matches!(ord_num, Some(Some(_))),
+                                "column ord num has to be generated for {:?}.{:?} but value was {:?}",
+                                schema_name,
+                                table_name,
+                                ord_num
+                            );
+                            let ord_num = ord_num.unwrap().unwrap();
+
+                            let row = Binary::pack(&[
+                                Datum::from_string("IN_MEMORY".to_owned()),
+                                Datum::from_string(schema_name.clone()),
+                                Datum::from_string(table_name.clone()),
+                                Datum::from_string(column_name.clone()),
+                                Datum::from_u64(sql_type.type_id()),
+                                Datum::from_optional_u64(sql_type.type_modifier()),
+                                Datum::from_u64(ord_num),
+                                Datum::from_bool(constraints.not_null),
+                                Datum::from_optional_string(constraints.default_expr.clone()),
+                                Datum::from_optional_string(constraints.check.as_ref().map(|check| check.name.clone())),
+                                Datum::from_optional_string(
+                                    constraints.check.as_ref().map(|check| check.predicate.clone()),
+                                ),
+                            ]);
+
+                            self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(COLUMNS_TABLE, |table| table.insert(vec![row.clone()]))
+                            });
+                        }
+                        Record::Index {
+                            schema_name,
+                            table_name,
+                            index_name,
+                            column_names,
+                            kind,
+                        } => {
+                            let mut row = vec![
+                                Datum::from_string("IN_MEMORY".to_owned()),
+                                Datum::from_string(schema_name.clone()),
+                                Datum::from_string(table_name.clone()),
+                                Datum::from_string(index_name.clone()),
+                                Datum::from_u64(kind.kind_id()),
+                            ];
+                            row.extend(column_names.iter().cloned().map(Datum::from_string));
+                            let row = Binary::pack(&row);
+                            self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(INDEXES_TABLE, |table| table.insert(vec![row.clone()]))
+                            });
+                        }
+                        Record::ForeignKey {
+                            schema_name,
+                            table_name,
+                            constraint_name,
+                            column_names,
+                            referenced_schema_name,
+                            referenced_table_name,
+                            referenced_column_names,
+                            on_delete,
+                            on_update,
+                        } => {
+                            let mut row = vec![
+                                Datum::from_string("IN_MEMORY".to_owned()),
+                                Datum::from_string(schema_name.clone()),
+                                Datum::from_string(table_name.clone()),
+                                Datum::from_string(constraint_name.clone()),
+                                Datum::from_string(referenced_schema_name.clone()),
+                                Datum::from_string(referenced_table_name.clone()),
+                                Datum::from_u64(on_delete.action_id()),
+                                Datum::from_u64(on_update.action_id()),
+                                Datum::from_u64(column_names.len() as u64),
+                            ];
+                            row.extend(column_names.iter().cloned().map(Datum::from_string));
+                            row.extend(referenced_column_names.iter().cloned().map(Datum::from_string));
+                            let row = Binary::pack(&row);
+                            self.catalog.work_with(DEFINITION_SCHEMA, |schema| {
+                                schema.work_with(REFERENTIAL_CONSTRAINTS_TABLE, |table| table.insert(vec![row.clone()]))
+                            });
+                        }
+                    },
+                }
+            }
+        }
+        match kind {
+            Kind::Create(SystemObject::Schema) => Ok(ExecutionOutcome::SchemaCreated),
+            Kind::Drop(SystemObject::Schema) => Ok(ExecutionOutcome::SchemaDropped),
+            Kind::Create(SystemObject::Table) => Ok(ExecutionOutcome::TableCreated),
+            Kind::Drop(SystemObject::Table) => Ok(ExecutionOutcome::TableDropped),
+            Kind::Create(SystemObject::Index) => Ok(ExecutionOutcome::IndexCreated),
+            Kind::Drop(SystemObject::Index) => Ok(ExecutionOutcome::IndexDropped),
+            Kind::Create(SystemObject::Column) | Kind::Drop(SystemObject::Column) | Kind::Alter(_) => {
+                Ok(ExecutionOutcome::TableAltered)
+            }
+        }
+    }
+
+    pub(crate) fn work_with_table<R, F: Fn(&dyn SqlTable) -> R>(
+        &self,
+        transaction: &Transaction,
+        full_table_name: &FullTableName,
+        operation: F,
+    ) -> R
+    where
+        C: DataCatalog,
+        <C::Schema as SchemaHandle>::Table: Clone,
+    {
+        operation(&DatabaseTable::new(self, transaction, full_table_name))
+    }
+
+    /// data table of `full_table_name` as it is seen by `transaction`
+    fn transaction_table(
+        &self,
+        transaction: &Transaction,
+        full_table_name: &FullTableName,
+    ) -> TransactionTable<<C::Schema as SchemaHandle>::Table>
+    where
+        C: DataCatalog,
+        <C::Schema as SchemaHandle>::Table: Clone,
+    {
+        let data_table = self
+            .catalog
+            .work_with(full_table_name.schema(), |schema| {
+                schema.work_with(full_table_name.table(), |table| table.clone())
+            })
+            .flatten();
+        debug_assert!(
+            data_table.is_some(),
+            "data table for {} has to exist in the catalog",
+            full_table_name
+        );
+        transaction.table(full_table_name, data_table.unwrap())
+    }
+
+    /// rows of the table have to reference existing rows of tables that its foreign keys refer to,
+    /// rows with `NULL` in any of referencing columns are not checked
+    fn check_references(
+        &self,
+        transaction: &Transaction,
+        full_table_name: &FullTableName,
+        rows: &[Vec<Datum>],
+    ) -> Result<(), QueryExecutionError>
+    where
+        C: DataCatalog,
+        <C::Schema as SchemaHandle>::Table: Clone,
+    {
+        for foreign_key in self.foreign_keys(|table, _referenced_table| table == full_table_name) {
+            let referenced_table = self.transaction_table(transaction, &foreign_key.referenced_table);
+            for row in rows {
+                if let Some(values) = unique_values(row, &foreign_key.columns) {
+                    let candidates = referenced_table.index_scan(&foreign_key.referenced_index, &lookup(&values));
+                    if !has_duplicate(candidates, &foreign_key.referenced_columns, &values, |_key| false) {
+                        return Err(QueryExecutionError::ForeignKeyViolation {
+                            table_name: full_table_name.table().to_owned(),
+                            constraint_name: foreign_key.name,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// applies `ON DELETE` actions of foreign keys that reference deleted rows
+    fn rows_deleted(
+        &self,
+        transaction: &Transaction,
+        full_table_name: &FullTableName,
+        rows: &[Vec<Datum>],
+    ) -> Result<(), QueryExecutionError>
+    where
+        C: DataCatalog,
+        <C::Schema as SchemaHandle>::Table: Clone,
+    {
+        for foreign_key in self.foreign_keys(|_table, referenced_table| referenced_table == full_table_name) {
+            let changes = rows
+                .iter()
+                .filter_map(|row| unique_values(row, &foreign_key.referenced_columns))
+                .map(|values| (values, None))
+                .collect();
+            self.referenced_rows_changed(transaction, &foreign_key, foreign_key.on_delete, true, &changes)?;
+        }
+        Ok(())
+    }
+
+    /// applies `ON UPDATE` actions of foreign keys that reference rows with changed referenced values
+    fn rows_updated(
+        &self,
+        transaction: &Transaction,
+        full_table_name: &FullTableName,
+        rows: &[(Vec<Datum>, Vec<Datum>)],
+    ) -> Result<(), QueryExecutionError>
+    where
+        C: DataCatalog,
+        <C::Schema as SchemaHandle>::Table: Clone,
+    {
+        for foreign_key in self.foreign_keys(|_table, referenced_table| referenced_table == full_table_name) {
+            let changes = rows
+                .iter()
+                .filter_map(|(old_row, new_row)| {
+                    let old_values = unique_values(old_row, &foreign_key.referenced_columns)?;
+                    let new_values = unique_values(new_row, &foreign_key.referenced_columns);
+                    if new_values.as_ref() == Some(&old_values) {
+                        None
+                    } else {
+                        Some((old_values, new_values))
+                    }
+                })
+                .collect();
+            self.referenced_rows_changed(transaction, &foreign_key, foreign_key.on_update, false, &changes)?;
+        }
+        Ok(())
+    }
+
+    fn referenced_rows_changed(
+        &self,
+        transaction: &Transaction,
+        foreign_key: &ForeignKey,
+        action: ReferentialAction,
+        deleted: bool,
+        changes: &ReferencedChanges,
+    ) -> Result<(), QueryExecutionError>
+    where
+        C: DataCatalog,
+        <C::Schema as SchemaHandle>::Table: Clone,
+    {
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let table = DatabaseTable::new(self, transaction, &foreign_key.table);
+        let rows = table.referencing_rows(&foreign_key.columns, changes);
+        if rows.is_empty() {
+            return Ok(());
+        }
+        match action {
+            ReferentialAction::NoAction | ReferentialAction::Restrict => Err(QueryExecutionError::RowIsReferenced {
+                table_name: foreign_key.referenced_table.table().to_owned(),
+                constraint_name: foreign_key.name.clone(),
+                referencing_table_name: foreign_key.table.table().to_owned(),
+            }),
+            ReferentialAction::Cascade if deleted => table.delete_rows(rows).map(|_deleted| ()),
+            ReferentialAction::Cascade | ReferentialAction::SetNull => {
+                let delta = rows
+                    .into_iter()
+                    .map(|(key, row)| {
+                        let values = unique_values(&row, &foreign_key.columns)
+                            .and_then(|values| changes.get(&values).cloned())
+                            .flatten();
+                        let mut updated_row = row.clone();
+                        for (position, column) in foreign_key.columns.iter().enumerate() {
+                            updated_row[*column] = match (action, &values) {
+                                (ReferentialAction::Cascade, Some(values)) => values[position].clone(),
+                                _ => Datum::from_null(),
+                            };
+                        }
+                        (key, row, updated_row)
+                    })
+                    .collect();
+                table.update_rows(delta, &[]).map(|_updated| ())
+            }
+        }
+    }
+
+    /// rows that a transaction puts have to reference rows that exist after its commit and
+    /// rows that it deletes or updates must not be referenced by rows that others committed after
+    /// its snapshot, referenced and referencing rows are read at the latest committed version
+    fn check_committed_references(
+        &self,
+        foreign_key: &ForeignKey,
+        changes: &[(TableName, TableChanges)],
+    ) -> Result<(), QueryExecutionError>
+    where
+        C: DataCatalog,
+    {
+        let table_changes = |full_table_name: &FullTableName| {
+            changes
+                .iter()
+                .find(|((schema_name, table_name), _changes)| {
+                    full_table_name.schema() == schema_name && full_table_name.table() == table_name
+                })
+                .map(|(_table, changes)| changes)
+        };
+        let referencing_changes = table_changes(&foreign_key.table);
+        let referenced_changes = table_changes(&foreign_key.referenced_table);
+        let exists = |values: &[Datum]| {
+            self.catalog
+                .work_with(foreign_key.referenced_table.schema(), |schema| {
+                    schema.work_with(foreign_key.referenced_table.table(), |table| {
+                        referenced_after_commit(table, referenced_changes, foreign_key, values)
+                    })
+                })
+                .flatten()
+                .unwrap_or(false)
+        };
+
+        if let Some(changes) = referencing_changes {
+            for value in changes.updated.values().chain(changes.inserted.values()) {
+                if let Some(values) = unique_values(&value.unpack(), &foreign_key.columns) {
+                    if !exists(&values) {
+                        return Err(QueryExecutionError::ForeignKeyViolation {
+                            table_name: foreign_key.table.table().to_owned(),
+                            constraint_name: foreign_key.name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let removed = match referenced_changes {
+            None => BTreeSet::new(),
+            Some(changes) => self
+                .catalog
+                .work_with(foreign_key.referenced_table.schema(), |schema| {
+                    schema.work_with(foreign_key.referenced_table.table(), |table| {
+                        replaced_values(table, changes, &foreign_key.referenced_columns)
+                    })
+                })
+                .flatten()
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|values| !exists(values))
+                .collect::<BTreeSet<Vec<Datum>>>(),
+        };
+        if removed.is_empty() {
+            return Ok(());
+        }
+        let referenced = self
+            .catalog
+            .work_with(foreign_key.table.schema(), |schema| {
+                schema.work_with(foreign_key.table.table(), |table| {
+                    rows_after_commit(table, referencing_changes)
+                        .iter()
+                        .filter_map(|row| unique_values(row, &foreign_key.columns))
+                        .any(|values| removed.contains(&values))
+                })
+            })
+            .flatten()
+            .unwrap_or(false);
+        if referenced {
+            Err(QueryExecutionError::RowIsReferenced {
+                table_name: foreign_key.referenced_table.table().to_owned(),
+                constraint_name: foreign_key.name.clone(),
+                referencing_table_name: foreign_key.table.table().to_owned(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(crate) fn begin_transaction(&self) -> Transaction {
+        self.transactions.begin()
+    }
+
+    /// changes of all tables are applied as a single catalog operation
+    pub(crate) fn commit_transaction(&self, transaction: Transaction) -> Result<(), QueryExecutionError>
+    where
+        C: DataCatalog,
+    {
+        let changes = transaction.take_changes();
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let unique_keys = changes
+            .iter()
+            .map(|((schema_name, table_name), _changes)| {
+                self.unique_keys(&FullTableName::from((schema_name, table_name)))
+            })
+            .collect::<Vec<Vec<UniqueKey>>>();
+        let changed = |full_table_name: &FullTableName| {
+            changes.iter().any(|((schema_name, table_name), _changes)| {
+                full_table_name.schema() == schema_name && full_table_name.table() == table_name
+            })
+        };
+        let foreign_keys = self.foreign_keys(|table, referenced_table| changed(table) || changed(referenced_table));
+        {
+            let _operation = OperationGuard::start(&self.catalog);
+            let commit = self.transactions.start_commit();
+            let conflict = changes.iter().any(|((schema_name, table_name), changes)| {
+                self.catalog
+                    .work_with(schema_name, |schema| {
+                        schema.work_with(table_name, |table| {
+                            changes.conflicts_with(table, transaction.snapshot())
+                        })
+                    })
+                    .flatten()
+                    .unwrap_or(false)
+            });
+            if conflict {
+                return Err(QueryExecutionError::SerializationFailure);
+            }
+            let violation = changes.iter().zip(unique_keys.iter()).find_map(
+                |(((schema_name, table_name), changes), unique_keys)| {
+                    self.catalog
+                        .work_with(schema_name, |schema| {
+                            schema.work_with(table_name, |table| unique_violation(table, changes, unique_keys))
+                        })
+                        .flatten()
+                        .flatten()
+                },
+            );
+            if let Some(index_name) = violation {
+                return Err(QueryExecutionError::UniqueViolation(index_name));
+            }
+            for foreign_key in foreign_keys.iter() {
+                self.check_committed_references(foreign_key, &changes)?;
+            }
+            for ((schema_name, table_name), changes) in changes.iter() {
+                self.catalog.work_with(schema_name, |schema| {
+                    schema.work_with(table_name, |table| table.commit(changes, commit.version()))
+                });
+            }
+            commit.publish();
+        }
+        drop(transaction);
+        let oldest_snapshot = self.transactions.oldest_snapshot();
+        for ((schema_name, table_name), _changes) in changes {
+            self.catalog.work_with(&schema_name, |schema| {
+                schema.work_with(&table_name, |table| table.collect_garbage(oldest_snapshot))
+            });
+        }
+        Ok(())
+    }
+}
+
+/// columns are added, dropped and changed only by altering their table
+fn alters_table(kind: &Kind) -> bool {
+    matches!(
+        kind,
+        Kind::Create(SystemObject::Column) | Kind::Drop(SystemObject::Column) | Kind::Alter(_)
+    )
+}
+
+/// names of referencing and referenced columns of a foreign key record
+fn foreign_key_column_names(row: &[Datum]) -> (Vec<String>, Vec<String>) {
+    let len = row[8].as_u64() as usize;
+    let names = |from: usize| {
+        row[from..from + len]
+            .iter()
+            .map(|name| name.as_string())
+            .collect::<Vec<String>>()
+    };
+    (names(9), names(9 + len))
+}
+
+/// unique index with positions of its columns in records of the table
+struct UniqueKey {
+    index_name: String,
+    columns: Vec<usize>,
+    primary: bool,
+}
+
+/// foreign key with positions of its columns in records of the referencing and the referenced
+/// tables, columns are ordered as columns of the unique index of the referenced table
+struct ForeignKey {
+    name: String,
+    table: FullTableName,
+    columns: Vec<usize>,
+    referenced_table: FullTableName,
+    referenced_index: String,
+    referenced_columns: Vec<usize>,
+    on_delete: ReferentialAction,
+    on_update: ReferentialAction,
+}
+
+/// old values of referenced columns mapped to their new values, `None` if values are gone
+/// or become `NULL`
+type ReferencedChanges = BTreeMap<Vec<Datum>, Option<Vec<Datum>>>;
+
+/// values of unique index columns of a record, records with `NULL` in any of them
+/// do not violate unique constraints
+fn unique_values(row: &[Datum], columns: &[usize]) -> Option<Vec<Datum>> {
+    let values = columns.iter().map(|index| row[*index].clone()).collect::<Vec<Datum>>();
+    if values.contains(&Datum::from_null()) {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+/// range of index records that have the same value of the leading column
+fn lookup(values: &[Datum]) -> IndexRange {
+    (Bound::Included(values[0].clone()), Bound::Included(values[0].clone()))
+}
+
+fn has_duplicate<S: Fn(&Key) -> bool>(candidates: Cursor, columns: &[usize], values: &[Datum], skipped: S) -> bool {
+    candidates
+        .filter(|(key, _value)| !skipped(key))
+        .any(|(_key, value)| unique_values(&value.unpack(), columns).as_deref() == Some(values))
+}
+
+/// name of unique index that rows inserted or updated by a transaction share values with
+/// the latest committed rows
+fn unique_violation<T: DataTable>(table: &T, changes: &TableChanges, unique_keys: &[UniqueKey]) -> Option<String> {
+    let skipped = |key: &Key| changes.deleted.contains(key) || changes.updated.contains_key(key);
+    for unique_key in unique_keys {
+        for value in changes.updated.values().chain(changes.inserted.values()) {
+            if let Some(values) = unique_values(&value.unpack(), &unique_key.columns) {
+                let candidates = table.index_scan_at(&unique_key.index_name, &lookup(&values), Version::MAX);
+                if has_duplicate(candidates, &unique_key.columns, &values, skipped) {
+                    return Some(unique_key.index_name.clone());
+                }
+            }
+        }
+    }
+    None
+}
+
+/// latest committed rows of the table that are not deleted or updated by `changes` and rows
+/// that `changes` insert or update
+fn rows_after_commit<T: DataTable>(table: &T, changes: Option<&TableChanges>) -> Vec<Vec<Datum>> {
+    let skipped = |key: &Key| {
+        changes
+            .into_iter()
+            .any(|changes| changes.deleted.contains(key) || changes.updated.contains_key(key))
+    };
+    let mut rows = table
+        .select_at(Version::MAX)
+        .filter(|(key, _value)| !skipped(key))
+        .map(|(_key, value)| value.unpack())
+        .collect::<Vec<Vec<Datum>>>();
+    if let Some(changes) = changes {
+        rows.extend(
+            changes
+                .updated
+                .values()
+                .chain(changes.inserted.values())
+                .map(Value::unpack),
+        );
+    }
+    rows
+}
+
+/// referenced table has a row with `values` in the referenced columns after `changes` are committed
+fn referenced_after_commit<T: DataTable>(
+    table: &T,
+    changes: Option<&TableChanges>,
+    foreign_key: &ForeignKey,
+    values: &[Datum],
+) -> bool {
+    let skipped = |key: &Key| {
+        changes
+            .into_iter()
+            .any(|changes| changes.deleted.contains(key) || changes.updated.contains_key(key))
+    };
+    let put = changes.into_iter().any(|changes| {
+        changes
+            .updated
+            .values()
+            .chain(changes.inserted.values())
+            .any(|value| unique_values(&value.unpack(), &foreign_key.referenced_columns).as_deref() == Some(values))
+    });
+    put || has_duplicate(
+        table.index_scan_at(&foreign_key.referenced_index, &lookup(values), Version::MAX),
+        &foreign_key.referenced_columns,
+        values,
+        skipped,
+    )
+}
+
+/// values of `columns` of the latest committed rows that `changes` delete or update
+fn replaced_values<T: DataTable>(table: &T, changes: &TableChanges, columns: &[usize]) -> Vec<Vec<Datum>> {
+    table
+        .select_at(Version::MAX)
+        .filter(|(key, _value)| changes.deleted.contains(key) || changes.updated.contains_key(key))
+        .filter_map(|(_key, value)| unique_values(&value.unpack(), columns))
+        .collect()
+}
+
+pub(crate) struct DatabaseTable<'d, C: DataCatalog> {
+    database: &'d DatabaseHandle<C>,
+    transaction: &'d Transaction,
+    full_table_name: FullTableName,
+    data_table: TransactionTable<<C::Schema as SchemaHandle>::Table>,
+    columns: Vec<ColumnDef>,
+    unique_keys: Vec<UniqueKey>,
+    evaluation: Evaluation,
+}
+
+impl<'d, C: DataCatalog> DatabaseTable<'d, C>
+where
+    <C::Schema as SchemaHandle>::Table: Clone,
+{
+    fn new(
+        database: &'d DatabaseHandle<C>,
+        transaction: &'d Transaction,
+        full_table_name: &FullTableName,
+    ) -> DatabaseTable<'d, C> {
+        DatabaseTable {
+            database,
+            transaction,
+            full_table_name: full_table_name.clone(),
+            data_table: database.transaction_table(transaction, full_table_name),
+            columns: database.table_columns(full_table_name),
+            unique_keys: database.unique_keys(full_table_name),
+            evaluation: Evaluation,
+        }
+    }
+
+    /// rows that are put by a statement are checked against each other and against
+    /// rows of the table that are not replaced by the statement
+    fn check_constraints(
+        &self,
+        rows: &[(Option<&Key>, Vec<Datum>)],
+        checks: &Checks,
+    ) -> Result<(), QueryExecutionError> {
+        let primary_key = self
+            .unique_keys
+            .iter()
+            .filter(|unique_key| unique_key.primary)
+            .flat_map(|unique_key| unique_key.columns.iter())
+            .collect::<BTreeSet<&usize>>();
+        for (_key, row) in rows {
+            if let Some((_index, column)) = self.columns.iter().enumerate().find(|(index, column)| {
+                (column.constraints().not_null || primary_key.contains(index)) && row[*index] == Datum::from_null()
+            }) {
+                return Err(QueryExecutionError::NotNullViolation(column.name().to_owned()));
+            }
+        }
+        for (_key, row) in rows {
+            let row = row.iter().cloned().map(ScalarValue::from).collect::<Vec<ScalarValue>>();
+            for (name, predicate) in checks {
+                if self.evaluation.eval_dynamic(predicate, &self.columns, &row)? == ScalarValue::False {
+                    return Err(QueryExecutionError::CheckViolation {
+                        table_name: self.full_table_name.table().to_owned(),
+                        constraint_name: name.clone(),
+                    });
+                }
+            }
+        }
+        let replaced = rows.iter().filter_map(|(key, _row)| *key).collect::<BTreeSet<&Key>>();
+        for unique_key in self.unique_keys.iter() {
+            let mut seen = BTreeSet::new();
+            for (_key, row) in rows {
+                if let Some(values) = unique_values(row, &unique_key.columns) {
+                    let candidates = self.data_table.index_scan(&unique_key.index_name, &lookup(&values));
+                    if has_duplicate(candidates, &unique_key.columns, &values, |key| replaced.contains(key))
+                        || !seen.insert(values)
+                    {
+                        return Err(QueryExecutionError::UniqueViolation(unique_key.index_name.clone()));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// rows which values of `columns` are among old values of `changes`
+    fn referencing_rows(&self, columns: &[usize], changes: &ReferencedChanges) -> Vec<(Key, Vec<Datum>)> {
+        self.data_table
+            .select()
+            .map(|(key, value)| (key, value.unpack()))
+            .filter(|(_key, row)| {
+                unique_values(row, columns)
+                    .map(|values| changes.contains_key(&values))
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
+    fn delete_rows(&self, rows: Vec<(Key, Vec<Datum>)>) -> Result<usize, QueryExecutionError> {
+        let deleted = self
+            .data_table
+            .delete(rows.iter().map(|(key, _row)| key.clone()).collect());
+        let rows = rows.into_iter().map(|(_key, row)| row).collect::<Vec<Vec<Datum>>>();
+        self.database
+            .rows_deleted(self.transaction, &self.full_table_name, &rows)?;
+        Ok(deleted)
+    }
+
+    /// `delta` consists of keys, old and new values of updated rows
+    fn update_rows(
+        &self,
+        delta: Vec<(Key, Vec<Datum>, Vec<Datum>)>,
+        checks: &Checks,
+    ) -> Result<usize, QueryExecutionError> {
+        let to_update = delta
+            .iter()
+            .map(|(key, _old_row, new_row)| (Some(key), new_row.clone()))
+            .collect::<Vec<(Option<&Key>, Vec<Datum>)>>();
+        self.check_constraints(&to_update, checks)?;
+        let updated = self.data_table.update(
+            delta
+                .iter()
+                .map(|(key, _old_row, new_row)| (key.clone(), Binary::pack(new_row)))
+                .collect(),
+        );
+        let new_rows = delta
+            .iter()
+            .map(|(_key, _old_row, new_row)| new_row.clone())
+            .collect::<Vec<Vec<Datum>>>();
+        self.database
+            .check_references(self.transaction, &self.full_table_name, &new_rows)?;
+        let changed_rows = delta
+            .into_iter()
+            .map(|(_key, old_row, new_row)| (old_row, new_row))
+            .collect::<Vec<(Vec<Datum>, Vec<Datum>)>>();
+        self.database
+            .rows_updated(self.transaction, &self.full_table_name, &changed_rows)?;
+        Ok(updated)
+    }
+
+    /// `NULL` result of a `filter` is treated as `false`
+    fn satisfies(&self, filter: Option<&DynamicTypedTree>, row: &[ScalarValue]) -> Result<bool, QueryExecutionError> {
+        match filter {
+            None => Ok(true),
+            Some(filter) => Ok(self.evaluation.eval_dynamic(filter, &self.columns, row)? == ScalarValue::True),
+        }
+    }
+
+    fn filtered_rows(
+        &self,
+        filter: Option<&DynamicTypedTree>,
+    ) -> Result<Vec<(Binary, Vec<ScalarValue>)>, QueryExecutionError> {
+        let mut rows = vec![];
+        for (key, value) in self.data_table.select() {
+            let row = value
+                .unpack()
+                .into_iter()
+                .map(ScalarValue::from)
+                .collect::<Vec<ScalarValue>>();
+            if self.satisfies(filter, &row)? {
+                rows.push((key, row));
+            }
+        }
+        Ok(rows)
+    }
+
+    fn has_column(&self, column_name: &str) -> Option<(usize, &ColumnDef)> {
+        self.columns
+            .iter()
+            .enumerate()
+            .find(|(_index, col)| col.has_name(column_name))
+    }
+}
+
+impl<'d, C: DataCatalog> SqlTable for DatabaseTable<'d, C>
+where
+    <C::Schema as SchemaHandle>::Table: Clone,
+{
+    fn insert(&self, rows: &[Vec<Option<StaticTypedTree>>], checks: &Checks) -> Result<usize, QueryExecutionError> {
+        let mut to_insert = vec![];
+        for (row_index, row) in rows.iter().enumerate() {
+            log::debug!("ROW to INSERT {:#?}", row);
+            let mut values = vec![];
+            let mut violations = vec![];
+            for (column, value) in self.columns.iter().zip(row.iter()) {
+                match value.as_ref().map(|value| self.evaluation.eval_static(value)) {
+                    None => values.push(Datum::from_null()),
+                    Some(Ok(value)) => values.push(Datum::from(value)),
+                    Some(Err(EvalError::Cast(error))) => violations.push((column.name().to_owned(), error)),
+                    Some(Err(error)) => return Err(error.into()),
+                }
+            }
+            if !violations.is_empty() {
+                return Err(QueryExecutionError::ColumnTypeViolations {
+                    row_index: row_index + 1,
+                    violations,
+                });
+            }
+            to_insert.push((None, values));
+        }
+        self.check_constraints(&to_insert, checks)?;
+        let rows = to_insert
+            .into_iter()
+            .map(|(_key, values)| values)
+            .collect::<Vec<Vec<Datum>>>();
+        let inserted = self
+            .data_table
+            .insert(rows.iter().map(|values| Binary::pack(values)).collect());
+        self.database
+            .check_references(self.transaction, &self.full_table_name, &rows)?;
+        Ok(inserted)
+    }
+
+    fn select(
+        &self,
+        filter: Option<&DynamicTypedTree>,
+    ) -> Result<(Vec<ColumnDef>, Vec<Vec<ScalarValue>>), QueryExecutionError> {
+        Ok((
+            self.columns.clone(),
+            self.filtered_rows(filter)?.into_iter().map(|(_key, row)| row).collect(),
+        ))
+    }
+
+    fn select_with_columns(
+        &self,
+        column_names: Vec<String>,
+        filter: Option<&DynamicTypedTree>,
+    ) -> Result<(Vec<ColumnDef>, Vec<Vec<ScalarValue>>), QueryExecutionError> {
+        let mut columns = vec![];
+        let mut indexes = vec![];
+        for name in column_names {
+            match self.has_column(&name) {
+                None => return Err(QueryExecutionError::ColumnNotFound(name)),
+                Some((index, col)) => {
+                    columns.push(col.clone());
+                    indexes.push(index);
+                }
+            }
+        }
+        Ok((
+            columns,
+            self.filtered_rows(filter)?
+                .into_iter()
+                .map(|(_key, row)| indexes.iter().map(|index| row[*index].clone()).collect())
+                .collect(),
+        ))
+    }
+
+    fn select_by_index(
+        &self,
+        index_name: &str,
+        range: (Bound<ScalarValue>, Bound<ScalarValue>),
+    ) -> Result<(Vec<ColumnDef>, Vec<Vec<ScalarValue>>), QueryExecutionError> {
+        let bound = |bound: Bound<ScalarValue>| match bound {
+            Bound::Included(value) => Bound::Included(Datum::from(value)),
+            Bound::Excluded(value) => Bound::Excluded(Datum::from(value)),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let (low, high) = range;
+        let rows = self
+            .data_table
+            .index_scan(index_name, &(bound(low), bound(high)))
+            .map(|(_key, value)| value.unpack().into_iter().map(ScalarValue::from).collect())
+            .collect();
+        Ok((self.columns.clone(), rows))
+    }
+
+    fn delete(&self, filter: Option<&DynamicTypedTree>) -> Result<usize, QueryExecutionError> {
+        let rows = self
+            .filtered_rows(filter)?
+            .into_iter()
+            .map(|(key, row)| (key, row.into_iter().map(Datum::from).collect()))
+            .collect();
+        self.delete_rows(rows)
+    }
+
+    fn update(
+        &self,
+        column_names: Vec<String>,
+        assignments: Vec<DynamicTypedTree>,
+        filter: Option<&DynamicTypedTree>,
+        checks: &Checks,
+    ) -> Result<usize, QueryExecutionError> {
+        let rows = self.filtered_rows(filter)?;
+        let mut delta = vec![];
+        for (row_index, (key, row)) in rows.iter().enumerate() {
+            let old_row = row.iter().cloned().map(Datum::from).collect::<Vec<Datum>>();
+            let mut updated_row = old_row.clone();
+            let mut violations = vec![];
+            for (column_name, assignment) in column_names.iter().zip(assignments.iter()) {
+                match self.has_column(column_name) {
+                    None => return Err(QueryExecutionError::ColumnNotFound(column_name.clone())),
+                    Some((index, _)) => match self.evaluation.eval_dynamic(assignment, &self.columns, row) {
+                        Ok(value) => updated_row[index] = Datum::from(value),
+                        Err(EvalError::Cast(error)) => violations.push((column_name.clone(), error)),
+                        Err(error) => return Err(error.into()),
+                    },
+                }
+            }
+            if !violations.is_empty() {
+                return Err(QueryExecutionError::ColumnTypeViolations {
+                    row_index: row_index + 1,
+                    violations,
+                });
+            }
+            delta.push((key.clone(), old_row, updated_row));
+        }
+        self.update_rows(delta, checks)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,356 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use data_manipulation_typed_tree::{StaticTypedItem, TypedValue};
+
+fn table_existence(schema_name: &str, table_name: &str) -> Vec<Step> {
+    vec![
+        Step::CheckExistence {
+            system_object: SystemObject::Schema,
+            object_name: vec![schema_name.to_owned()],
+        },
+        Step::CheckExistence {
+            system_object: SystemObject::Table,
+            object_name: vec![schema_name.to_owned(), table_name.to_owned()],
+        },
+    ]
+}
+
+fn column_existence(column_name: &str) -> Step {
+    Step::CheckExistence {
+        system_object: SystemObject::Column,
+        object_name: vec![SCHEMA.to_owned(), TABLE.to_owned(), column_name.to_owned()],
+    }
+}
+
+fn add_column_ops(column_name: &str, sql_type: SqlType, nullable: bool) -> SystemOperation {
+    let mut steps = table_existence(SCHEMA, TABLE);
+    steps.extend(vec![
+        column_existence(column_name),
+        Step::AddColumnValues {
+            schema_name: SCHEMA.to_owned(),
+            table_name: TABLE.to_owned(),
+            column_name: column_name.to_owned(),
+            nullable,
+        },
+        Step::CreateRecord {
+            record: Record::Column {
+                schema_name: SCHEMA.to_owned(),
+                table_name: TABLE.to_owned(),
+                column_name: column_name.to_owned(),
+                sql_type,
+                constraints: ColumnConstraints::default(),
+            },
+        },
+    ]);
+    SystemOperation {
+        kind: Kind::Create(SystemObject::Column),
+        skip_steps_if: None,
+        steps: vec![steps],
+    }
+}
+
+fn drop_column_ops(column_name: &str) -> SystemOperation {
+    let mut steps = table_existence(SCHEMA, TABLE);
+    steps.extend(vec![
+        column_existence(column_name),
+        Step::CheckDependants {
+            system_object: SystemObject::Column,
+            object_name: vec![SCHEMA.to_owned(), TABLE.to_owned(), column_name.to_owned()],
+        },
+        Step::RemoveColumn {
+            schema_name: SCHEMA.to_owned(),
+            table_name: TABLE.to_owned(),
+            column_name: column_name.to_owned(),
+        },
+    ]);
+    SystemOperation {
+        kind: Kind::Drop(SystemObject::Column),
+        skip_steps_if: None,
+        steps: vec![steps],
+    }
+}
+
+fn rename_column_ops(old_column_name: &str, new_column_name: &str) -> SystemOperation {
+    let mut steps = table_existence(SCHEMA, TABLE);
+    steps.extend(vec![
+        column_existence(old_column_name),
+        Step::RenameColumn {
+            schema_name: SCHEMA.to_owned(),
+            table_name: TABLE.to_owned(),
+            old_column_name: old_column_name.to_owned(),
+            new_column_name: new_column_name.to_owned(),
+        },
+    ]);
+    SystemOperation {
+        kind: Kind::Alter(SystemObject::Column),
+        skip_steps_if: None,
+        steps: vec![steps],
+    }
+}
+
+fn change_column_type_ops(column_name: &str, sql_type: SqlType) -> SystemOperation {
+    let mut steps = table_existence(SCHEMA, TABLE);
+    steps.extend(vec![
+        column_existence(column_name),
+        Step::ChangeColumnType {
+            schema_name: SCHEMA.to_owned(),
+            table_name: TABLE.to_owned(),
+            column_name: column_name.to_owned(),
+            sql_type,
+        },
+    ]);
+    SystemOperation {
+        kind: Kind::Alter(SystemObject::Column),
+        skip_steps_if: None,
+        steps: vec![steps],
+    }
+}
+
+fn rename_table_ops(new_table_name: &str) -> SystemOperation {
+    let mut steps = table_existence(SCHEMA, TABLE);
+    steps.push(Step::RenameTable {
+        schema_name: SCHEMA.to_owned(),
+        table_name: TABLE.to_owned(),
+        new_table_name: new_table_name.to_owned(),
+    });
+    SystemOperation {
+        kind: Kind::Alter(SystemObject::Table),
+        skip_steps_if: None,
+        steps: vec![steps],
+    }
+}
+
+fn database_with_row() -> Arc<InMemoryDatabase> {
+    let database = database();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database.execute(create_table_ops(SCHEMA, TABLE)).unwrap();
+    database
+        .work_with(&FullTableName::from((&SCHEMA, &TABLE)), |table| {
+            table.insert(
+                &[vec![
+                    Some(StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::SmallInt(1)))),
+                    Some(StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::BigInt(2)))),
+                ]],
+                &[],
+            )
+        })
+        .unwrap();
+    database
+}
+
+fn rows(database: &InMemoryDatabase, table_name: &str) -> Vec<Binary> {
+    database
+        .catalog
+        .table(&FullTableName::from((&SCHEMA, &table_name)))
+        .select()
+        .map(|(_key, value)| value)
+        .collect()
+}
+
+fn column_names(database: &InMemoryDatabase, table_name: &str) -> Vec<String> {
+    database
+        .table_definition(&FullTableName::from((&SCHEMA, &table_name)))
+        .unwrap()
+        .unwrap()
+        .columns()
+        .iter()
+        .map(|column| column.name().to_owned())
+        .collect()
+}
+
+#[test]
+fn alter_nonexistent_table() {
+    let database = database();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+
+    assert_eq!(
+        database.execute(add_column_ops("col_3", SqlType::integer(), true)),
+        Err(ExecutionError::TableDoesNotExist(SCHEMA.to_owned(), TABLE.to_owned()))
+    );
+}
+
+#[test]
+fn add_column_to_table_with_rows() {
+    let database = database_with_row();
+
+    assert_eq!(
+        database.execute(add_column_ops("col_3", SqlType::integer(), true)),
+        Ok(ExecutionOutcome::TableAltered)
+    );
+
+    assert_eq!(column_names(&database, TABLE), vec!["col_1", "col_2", "col_3"]);
+    assert_eq!(
+        rows(&database, TABLE),
+        vec![Binary::pack(&[
+            Datum::from_i16(1),
+            Datum::from_i64(2),
+            Datum::from_null()
+        ])]
+    );
+}
+
+#[test]
+fn add_existing_column() {
+    let database = database_with_row();
+
+    assert_eq!(
+        database.execute(add_column_ops("col_1", SqlType::integer(), true)),
+        Err(ExecutionError::ColumnAlreadyExists(
+            TABLE.to_owned(),
+            "col_1".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn add_not_null_column_to_table_with_rows() {
+    let database = database_with_row();
+
+    assert_eq!(
+        database.execute(add_column_ops("col_3", SqlType::integer(), false)),
+        Err(ExecutionError::ColumnContainsNulls(
+            TABLE.to_owned(),
+            "col_3".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn drop_column_from_table_with_rows() {
+    let database = database_with_row();
+
+    assert_eq!(
+        database.execute(drop_column_ops("col_1")),
+        Ok(ExecutionOutcome::TableAltered)
+    );
+
+    assert_eq!(column_names(&database, TABLE), vec!["col_2"]);
+    assert_eq!(rows(&database, TABLE), vec![Binary::pack(&[Datum::from_i64(2)])]);
+}
+
+#[test]
+fn drop_nonexistent_column() {
+    let database = database_with_row();
+
+    assert_eq!(
+        database.execute(drop_column_ops("col_3")),
+        Err(ExecutionError::ColumnDoesNotExist(TABLE.to_owned(), "col_3".to_owned()))
+    );
+}
+
+#[test]
+fn rename_column() {
+    let database = database_with_row();
+
+    assert_eq!(
+        database.execute(rename_column_ops("col_1", "col_3")),
+        Ok(ExecutionOutcome::TableAltered)
+    );
+
+    assert_eq!(column_names(&database, TABLE), vec!["col_3", "col_2"]);
+    assert_eq!(
+        rows(&database, TABLE),
+        vec![Binary::pack(&[Datum::from_i16(1), Datum::from_i64(2)])]
+    );
+}
+
+#[test]
+fn rename_column_to_existing_one() {
+    let database = database_with_row();
+
+    assert_eq!(
+        database.execute(rename_column_ops("col_1", "col_2")),
+        Err(ExecutionError::ColumnAlreadyExists(
+            TABLE.to_owned(),
+            "col_2".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn change_column_type() {
+    let database = database_with_row();
+
+    assert_eq!(
+        database.execute(change_column_type_ops("col_2", SqlType::small_int())),
+        Ok(ExecutionOutcome::TableAltered)
+    );
+
+    assert_eq!(
+        rows(&database, TABLE),
+        vec![Binary::pack(&[Datum::from_i16(1), Datum::from_i16(2)])]
+    );
+}
+
+#[test]
+fn change_column_type_with_values_out_of_range() {
+    let database = database();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database.execute(create_table_ops(SCHEMA, TABLE)).unwrap();
+    database
+        .work_with(&FullTableName::from((&SCHEMA, &TABLE)), |table| {
+            table.insert(
+                &[vec![
+                    Some(StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::SmallInt(1)))),
+                    Some(StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::BigInt(
+                        i64::MAX,
+                    )))),
+                ]],
+                &[],
+            )
+        })
+        .unwrap();
+
+    assert_eq!(
+        database.execute(change_column_type_ops("col_2", SqlType::small_int())),
+        Err(ExecutionError::ColumnCannotBeCast(
+            TABLE.to_owned(),
+            "col_2".to_owned(),
+            SqlType::small_int()
+        ))
+    );
+}
+
+#[test]
+fn rename_table() {
+    let database = database_with_row();
+
+    assert_eq!(
+        database.execute(rename_table_ops(OTHER_TABLE)),
+        Ok(ExecutionOutcome::TableAltered)
+    );
+
+    assert!(!database.table_exists(&FullTableName::from((&SCHEMA, &TABLE))));
+    assert_eq!(column_names(&database, OTHER_TABLE), vec!["col_1", "col_2"]);
+    assert_eq!(
+        rows(&database, OTHER_TABLE),
+        vec![Binary::pack(&[Datum::from_i16(1), Datum::from_i64(2)])]
+    );
+}
+
+#[test]
+fn rename_table_to_existing_one() {
+    let database = database_with_row();
+    database.execute(create_table_ops(SCHEMA, OTHER_TABLE)).unwrap();
+
+    assert_eq!(
+        database.execute(rename_table_ops(OTHER_TABLE)),
+        Err(ExecutionError::TableAlreadyExists(
+            SCHEMA.to_owned(),
+            OTHER_TABLE.to_owned()
+        ))
+    );
+}
@@ -0,0 +1,244 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use data_manipulation_operators::{Arithmetic, Comparison, Logical, Operation, PatternMatching};
+use data_manipulation_query_result::QueryExecutionError;
+use data_manipulation_typed_tree::{DynamicTypedItem, DynamicTypedTree, StaticTypedItem, TypedValue};
+use data_scalar::ScalarValue;
+
+fn with_table() -> (Arc<InMemoryDatabase>, FullTableName) {
+    let database = database();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database.execute(create_table_ops(SCHEMA, TABLE)).unwrap();
+
+    let full_table_name = FullTableName::from((&SCHEMA, &TABLE));
+    database
+        .work_with(&full_table_name, |table| {
+            table.insert(
+                &[
+                    vec![Some(small_int(1)), Some(big_int(10))],
+                    vec![Some(small_int(2)), Some(big_int(20))],
+                    vec![Some(small_int(3)), Some(big_int(30))],
+                ],
+                &[],
+            )
+        })
+        .unwrap();
+
+    (database, full_table_name)
+}
+
+fn small_int(value: i16) -> StaticTypedTree {
+    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::SmallInt(value)))
+}
+
+fn big_int(value: i64) -> StaticTypedTree {
+    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::BigInt(value)))
+}
+
+fn column(name: &str) -> Box<DynamicTypedTree> {
+    Box::new(DynamicTypedTree::Item(DynamicTypedItem::Column(name.to_owned())))
+}
+
+fn constant(value: TypedValue) -> Box<DynamicTypedTree> {
+    Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(value)))
+}
+
+fn compare(name: &str, comparison: Comparison, value: TypedValue) -> DynamicTypedTree {
+    DynamicTypedTree::Operation {
+        left: column(name),
+        op: Operation::Comparison(comparison),
+        right: constant(value),
+    }
+}
+
+fn rows(database: &InMemoryDatabase, full_table_name: &FullTableName) -> Vec<Vec<ScalarValue>> {
+    database.work_with(full_table_name, |table| table.select(None).unwrap().1)
+}
+
+#[test]
+fn select_rows_that_satisfy_filter() {
+    let (database, full_table_name) = with_table();
+
+    let filter = compare("col_1", Comparison::Gt, TypedValue::SmallInt(1));
+
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table.select(Some(&filter)).unwrap().1),
+        vec![
+            vec![ScalarValue::Int16(2), ScalarValue::Int64(20)],
+            vec![ScalarValue::Int16(3), ScalarValue::Int64(30)],
+        ]
+    );
+}
+
+#[test]
+fn select_columns_of_rows_that_satisfy_filter() {
+    let (database, full_table_name) = with_table();
+
+    let filter = DynamicTypedTree::Operation {
+        left: Box::new(compare("col_1", Comparison::Eq, TypedValue::SmallInt(1))),
+        op: Operation::Logical(Logical::Or),
+        right: Box::new(compare("col_2", Comparison::GtEq, TypedValue::BigInt(30))),
+    };
+
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table
+            .select_with_columns(vec!["col_2".to_owned()], Some(&filter))
+            .map(|(_columns, rows)| rows)),
+        Ok(vec![vec![ScalarValue::Int64(10)], vec![ScalarValue::Int64(30)]])
+    );
+}
+
+#[test]
+fn update_rows_that_satisfy_filter() {
+    let (database, full_table_name) = with_table();
+
+    let filter = compare("col_1", Comparison::NotEq, TypedValue::SmallInt(2));
+
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table.update(
+            vec!["col_2".to_owned()],
+            vec![DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::BigInt(0)))],
+            Some(&filter),
+            &[]
+        )),
+        Ok(2)
+    );
+    assert_eq!(
+        rows(&database, &full_table_name),
+        vec![
+            vec![ScalarValue::Int16(1), ScalarValue::Int64(0)],
+            vec![ScalarValue::Int16(2), ScalarValue::Int64(20)],
+            vec![ScalarValue::Int16(3), ScalarValue::Int64(0)],
+        ]
+    );
+}
+
+#[test]
+fn delete_rows_that_satisfy_filter() {
+    let (database, full_table_name) = with_table();
+
+    let filter = compare("col_2", Comparison::Lt, TypedValue::BigInt(30));
+
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table.delete(Some(&filter))),
+        Ok(2)
+    );
+    assert_eq!(
+        rows(&database, &full_table_name),
+        vec![vec![ScalarValue::Int16(3), ScalarValue::Int64(30)]]
+    );
+}
+
+#[test]
+fn comparison_with_null_does_not_satisfy_filter() {
+    let database = database();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database.execute(create_table_ops(SCHEMA, TABLE)).unwrap();
+
+    let full_table_name = FullTableName::from((&SCHEMA, &TABLE));
+    database
+        .work_with(&full_table_name, |table| {
+            table.insert(
+                &[
+                    vec![Some(small_int(1)), None],
+                    vec![Some(small_int(2)), Some(big_int(20))],
+                ],
+                &[],
+            )
+        })
+        .unwrap();
+
+    let filter = compare("col_2", Comparison::NotEq, TypedValue::BigInt(10));
+
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table.delete(Some(&filter))),
+        Ok(1)
+    );
+    assert_eq!(
+        rows(&database, &full_table_name),
+        vec![vec![ScalarValue::Int16(1), ScalarValue::Null]]
+    );
+}
+
+#[test]
+fn arithmetic_in_filter() {
+    let (database, full_table_name) = with_table();
+
+    let filter = DynamicTypedTree::Operation {
+        left: Box::new(DynamicTypedTree::Operation {
+            left: column("col_1"),
+            op: Operation::Arithmetic(Arithmetic::Mul),
+            right: constant(TypedValue::SmallInt(10)),
+        }),
+        op: Operation::Comparison(Comparison::Eq),
+        right: column("col_2"),
+    };
+
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table.select(Some(&filter)).unwrap().1.len()),
+        3
+    );
+}
+
+#[test]
+fn division_by_zero_in_filter() {
+    let (database, full_table_name) = with_table();
+
+    let filter = DynamicTypedTree::Operation {
+        left: Box::new(DynamicTypedTree::Operation {
+            left: column("col_2"),
+            op: Operation::Arithmetic(Arithmetic::Div),
+            right: constant(TypedValue::SmallInt(0)),
+        }),
+        op: Operation::Comparison(Comparison::Gt),
+        right: constant(TypedValue::SmallInt(0)),
+    };
+
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table
+            .select(Some(&filter))
+            .map(|(_columns, rows)| rows)),
+        Err(QueryExecutionError::DivisionByZero)
+    );
+}
+
+#[test]
+fn pattern_matching_in_filter() {
+    let (database, full_table_name) = with_table();
+
+    let like = |pattern_matching: PatternMatching, pattern: &str| DynamicTypedTree::Operation {
+        left: constant(TypedValue::String("abc%".to_owned())),
+        op: Operation::PatternMatching(pattern_matching),
+        right: constant(TypedValue::String(pattern.to_owned())),
+    };
+
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table
+            .select(Some(&like(PatternMatching::Like, "a_c\\%")))
+            .unwrap()
+            .1
+            .len()),
+        3
+    );
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table
+            .select(Some(&like(PatternMatching::NotLike, "%c%")))
+            .unwrap()
+            .1
+            .len()),
+        0
+    );
+}
@@ -28,11 +28,16 @@ fn insert_single_column() {
         .unwrap();
 
     let full_table_name = FullTableName::from((&SCHEMA, &TABLE));
-    database.work_with(&full_table_name, |table| {
-        table.insert(&[vec![Some(StaticTypedTree::Item(StaticTypedItem::Const(
-            TypedValue::SmallInt(1),
-        )))]])
-    });
+    database
+        .work_with(&full_table_name, |table| {
+            table.insert(
+                &[vec![Some(StaticTypedTree::Item(StaticTypedItem::Const(
+                    TypedValue::SmallInt(1),
+                )))]],
+                &[],
+            )
+        })
+        .unwrap();
 
     assert_eq!(
         database
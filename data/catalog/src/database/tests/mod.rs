@@ -12,14 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod alter_table;
+#[cfg(test)]
+mod filter;
 #[cfg(test)]
 mod insert;
 #[cfg(test)]
 mod schema;
 #[cfg(test)]
 mod table;
+#[cfg(test)]
+mod transaction;
 
 use super::*;
+use crate::{Database, InMemoryDatabase};
+use std::sync::Arc;
 use types::SqlType;
 
 const SCHEMA: &str = "schema_name";
@@ -155,6 +163,7 @@ fn create_table_inner(
                 table_name: table_name.to_owned(),
                 column_name: name.to_owned(),
                 sql_type,
+                constraints: ColumnConstraints::default(),
             },
         })
         .collect::<Vec<Step>>();
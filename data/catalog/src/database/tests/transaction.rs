@@ -0,0 +1,474 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use data_manipulation_operators::{Comparison, Operation};
+use data_manipulation_typed_tree::{DynamicTypedItem, StaticTypedItem, TypedValue};
+use data_scalar::ScalarValue;
+
+fn with_table() -> (Arc<InMemoryDatabase>, FullTableName) {
+    let database = database();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database.execute(create_table_ops(SCHEMA, TABLE)).unwrap();
+
+    let full_table_name = FullTableName::from((&SCHEMA, &TABLE));
+    database
+        .work_with(&full_table_name, |table| {
+            table.insert(
+                &[
+                    vec![Some(small_int(1)), Some(big_int(10))],
+                    vec![Some(small_int(2)), Some(big_int(20))],
+                ],
+                &[],
+            )
+        })
+        .unwrap();
+
+    (database, full_table_name)
+}
+
+fn small_int(value: i16) -> StaticTypedTree {
+    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::SmallInt(value)))
+}
+
+fn big_int(value: i64) -> StaticTypedTree {
+    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::BigInt(value)))
+}
+
+fn column_equals(name: &str, value: TypedValue) -> DynamicTypedTree {
+    DynamicTypedTree::Operation {
+        left: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Column(name.to_owned()))),
+        op: Operation::Comparison(Comparison::Eq),
+        right: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(value))),
+    }
+}
+
+fn rows(
+    database: &InMemoryDatabase,
+    transaction: &Transaction,
+    full_table_name: &FullTableName,
+) -> Vec<Vec<ScalarValue>> {
+    database.work_in(transaction, full_table_name, |table| table.select(None).unwrap().1)
+}
+
+fn committed_rows(database: &InMemoryDatabase, full_table_name: &FullTableName) -> Vec<Vec<ScalarValue>> {
+    database.work_with(full_table_name, |table| table.select(None).unwrap().1)
+}
+
+#[test]
+fn changes_are_visible_only_inside_transaction() {
+    let (database, full_table_name) = with_table();
+    let transaction = database.begin();
+
+    database
+        .work_in(&transaction, &full_table_name, |table| {
+            table.insert(&[vec![Some(small_int(3)), Some(big_int(30))]], &[])
+        })
+        .unwrap();
+
+    assert_eq!(
+        rows(&database, &transaction, &full_table_name),
+        vec![
+            vec![ScalarValue::Int16(1), ScalarValue::Int64(10)],
+            vec![ScalarValue::Int16(2), ScalarValue::Int64(20)],
+            vec![ScalarValue::Int16(3), ScalarValue::Int64(30)],
+        ]
+    );
+    assert_eq!(
+        committed_rows(&database, &full_table_name),
+        vec![
+            vec![ScalarValue::Int16(1), ScalarValue::Int64(10)],
+            vec![ScalarValue::Int16(2), ScalarValue::Int64(20)],
+        ]
+    );
+}
+
+#[test]
+fn commit_applies_all_changes() {
+    let (database, full_table_name) = with_table();
+    let transaction = database.begin();
+
+    database
+        .work_in(&transaction, &full_table_name, |table| {
+            table.insert(&[vec![Some(small_int(3)), Some(big_int(30))]], &[])
+        })
+        .unwrap();
+    database
+        .work_in(&transaction, &full_table_name, |table| {
+            table.update(
+                vec!["col_2".to_owned()],
+                vec![DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::BigInt(100)))],
+                Some(&column_equals("col_1", TypedValue::SmallInt(3))),
+                &[],
+            )
+        })
+        .unwrap();
+    database
+        .work_in(&transaction, &full_table_name, |table| {
+            table.delete(Some(&column_equals("col_1", TypedValue::SmallInt(1))))
+        })
+        .unwrap();
+    database.commit(transaction).expect("transaction committed");
+
+    assert_eq!(
+        committed_rows(&database, &full_table_name),
+        vec![
+            vec![ScalarValue::Int16(2), ScalarValue::Int64(20)],
+            vec![ScalarValue::Int16(3), ScalarValue::Int64(100)],
+        ]
+    );
+}
+
+#[test]
+fn rollback_discards_all_changes() {
+    let (database, full_table_name) = with_table();
+    let transaction = database.begin();
+
+    database
+        .work_in(&transaction, &full_table_name, |table| {
+            table.update(
+                vec!["col_2".to_owned()],
+                vec![DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::BigInt(0)))],
+                None,
+                &[],
+            )
+        })
+        .unwrap();
+    database
+        .work_in(&transaction, &full_table_name, |table| table.delete(None))
+        .unwrap();
+    database.rollback(transaction);
+
+    assert_eq!(
+        committed_rows(&database, &full_table_name),
+        vec![
+            vec![ScalarValue::Int16(1), ScalarValue::Int64(10)],
+            vec![ScalarValue::Int16(2), ScalarValue::Int64(20)],
+        ]
+    );
+}
+
+#[test]
+fn snapshot_does_not_see_changes_committed_after_it_was_taken() {
+    let (database, full_table_name) = with_table();
+    let reader = database.begin();
+    let writer = database.begin();
+
+    database
+        .work_in(&writer, &full_table_name, |table| {
+            table.delete(Some(&column_equals("col_1", TypedValue::SmallInt(1))))
+        })
+        .unwrap();
+    database.commit(writer).expect("transaction committed");
+
+    assert_eq!(
+        rows(&database, &reader, &full_table_name),
+        vec![
+            vec![ScalarValue::Int16(1), ScalarValue::Int64(10)],
+            vec![ScalarValue::Int16(2), ScalarValue::Int64(20)],
+        ]
+    );
+    assert_eq!(
+        committed_rows(&database, &full_table_name),
+        vec![vec![ScalarValue::Int16(2), ScalarValue::Int64(20)]]
+    );
+}
+
+#[test]
+fn concurrent_update_of_the_same_row_fails_on_commit() {
+    let (database, full_table_name) = with_table();
+    let first = database.begin();
+    let second = database.begin();
+
+    for transaction in &[&first, &second] {
+        database
+            .work_in(transaction, &full_table_name, |table| {
+                table.update(
+                    vec!["col_2".to_owned()],
+                    vec![DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::BigInt(0)))],
+                    Some(&column_equals("col_1", TypedValue::SmallInt(1))),
+                    &[],
+                )
+            })
+            .unwrap();
+    }
+
+    assert_eq!(database.commit(first), Ok(()));
+    assert_eq!(database.commit(second), Err(QueryExecutionError::SerializationFailure));
+}
+
+#[test]
+fn concurrent_updates_of_different_rows_are_committed() {
+    let (database, full_table_name) = with_table();
+    let first = database.begin();
+    let second = database.begin();
+
+    database
+        .work_in(&first, &full_table_name, |table| {
+            table.delete(Some(&column_equals("col_1", TypedValue::SmallInt(1))))
+        })
+        .unwrap();
+    database
+        .work_in(&second, &full_table_name, |table| {
+            table.delete(Some(&column_equals("col_1", TypedValue::SmallInt(2))))
+        })
+        .unwrap();
+
+    assert_eq!(database.commit(first), Ok(()));
+    assert_eq!(database.commit(second), Ok(()));
+    assert_eq!(
+        committed_rows(&database, &full_table_name),
+        Vec::<Vec<ScalarValue>>::new()
+    );
+}
+
+#[cfg(test)]
+mod primary_key {
+    use super::*;
+    use definition::IndexKind;
+
+    const PRIMARY_KEY: &str = "table_name_pkey";
+
+    fn with_primary_key() -> (Arc<InMemoryDatabase>, FullTableName) {
+        let database = database();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        database.execute(create_table_ops(SCHEMA, TABLE)).unwrap();
+        database
+            .execute(SystemOperation {
+                kind: Kind::Create(SystemObject::Index),
+                skip_steps_if: None,
+                steps: vec![vec![
+                    Step::CreateIndex {
+                        schema_name: SCHEMA.to_owned(),
+                        table_name: TABLE.to_owned(),
+                        index_name: PRIMARY_KEY.to_owned(),
+                        column_names: vec!["col_1".to_owned()],
+                        kind: IndexKind::PrimaryKey,
+                    },
+                    Step::CreateRecord {
+                        record: Record::Index {
+                            schema_name: SCHEMA.to_owned(),
+                            table_name: TABLE.to_owned(),
+                            index_name: PRIMARY_KEY.to_owned(),
+                            column_names: vec!["col_1".to_owned()],
+                            kind: IndexKind::PrimaryKey,
+                        },
+                    },
+                ]],
+            })
+            .unwrap();
+
+        let full_table_name = FullTableName::from((&SCHEMA, &TABLE));
+        database
+            .work_with(&full_table_name, |table| {
+                table.insert(&[vec![Some(small_int(1)), Some(big_int(10))]], &[])
+            })
+            .unwrap();
+
+        (database, full_table_name)
+    }
+
+    #[test]
+    fn insert_of_existing_key() {
+        let (database, full_table_name) = with_primary_key();
+
+        assert_eq!(
+            database.work_with(&full_table_name, |table| {
+                table.insert(&[vec![Some(small_int(1)), Some(big_int(20))]], &[])
+            }),
+            Err(QueryExecutionError::UniqueViolation(PRIMARY_KEY.to_owned()))
+        );
+    }
+
+    #[test]
+    fn insert_of_null_key() {
+        let (database, full_table_name) = with_primary_key();
+
+        assert_eq!(
+            database.work_with(&full_table_name, |table| table
+                .insert(&[vec![None, Some(big_int(20))]], &[])),
+            Err(QueryExecutionError::NotNullViolation("col_1".to_owned()))
+        );
+    }
+
+    #[test]
+    fn key_of_deleted_row_is_reused() {
+        let (database, full_table_name) = with_primary_key();
+        let transaction = database.begin();
+
+        database
+            .work_in(&transaction, &full_table_name, |table| {
+                table.delete(Some(&column_equals("col_1", TypedValue::SmallInt(1))))
+            })
+            .unwrap();
+        database
+            .work_in(&transaction, &full_table_name, |table| {
+                table.insert(&[vec![Some(small_int(1)), Some(big_int(20))]], &[])
+            })
+            .unwrap();
+
+        assert_eq!(database.commit(transaction), Ok(()));
+        assert_eq!(
+            committed_rows(&database, &full_table_name),
+            vec![vec![ScalarValue::Int16(1), ScalarValue::Int64(20)]]
+        );
+    }
+
+    #[test]
+    fn concurrent_inserts_of_the_same_key_fail_on_commit() {
+        let (database, full_table_name) = with_primary_key();
+        let first = database.begin();
+        let second = database.begin();
+
+        for transaction in &[&first, &second] {
+            database
+                .work_in(transaction, &full_table_name, |table| {
+                    table.insert(&[vec![Some(small_int(2)), Some(big_int(20))]], &[])
+                })
+                .unwrap();
+        }
+
+        assert_eq!(database.commit(first), Ok(()));
+        assert_eq!(
+            database.commit(second),
+            Err(QueryExecutionError::UniqueViolation(PRIMARY_KEY.to_owned()))
+        );
+    }
+}
+
+#[cfg(test)]
+mod foreign_key {
+    use super::*;
+    use definition::{IndexKind, ReferentialAction};
+
+    const PRIMARY_KEY: &str = "table_name_pkey";
+    const FOREIGN_KEY: &str = "other_table_name_col_1_fkey";
+
+    fn with_foreign_key() -> (Arc<InMemoryDatabase>, FullTableName, FullTableName) {
+        let database = database();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        database.execute(create_table_ops(SCHEMA, TABLE)).unwrap();
+        database
+            .execute(SystemOperation {
+                kind: Kind::Create(SystemObject::Index),
+                skip_steps_if: None,
+                steps: vec![vec![
+                    Step::CreateIndex {
+                        schema_name: SCHEMA.to_owned(),
+                        table_name: TABLE.to_owned(),
+                        index_name: PRIMARY_KEY.to_owned(),
+                        column_names: vec!["col_1".to_owned()],
+                        kind: IndexKind::PrimaryKey,
+                    },
+                    Step::CreateRecord {
+                        record: Record::Index {
+                            schema_name: SCHEMA.to_owned(),
+                            table_name: TABLE.to_owned(),
+                            index_name: PRIMARY_KEY.to_owned(),
+                            column_names: vec!["col_1".to_owned()],
+                            kind: IndexKind::PrimaryKey,
+                        },
+                    },
+                ]],
+            })
+            .unwrap();
+        let mut create_referencing_table = create_table_ops(SCHEMA, OTHER_TABLE);
+        create_referencing_table.steps[0].push(Step::CreateRecord {
+            record: Record::ForeignKey {
+                schema_name: SCHEMA.to_owned(),
+                table_name: OTHER_TABLE.to_owned(),
+                constraint_name: FOREIGN_KEY.to_owned(),
+                column_names: vec!["col_1".to_owned()],
+                referenced_schema_name: SCHEMA.to_owned(),
+                referenced_table_name: TABLE.to_owned(),
+                referenced_column_names: vec!["col_1".to_owned()],
+                on_delete: ReferentialAction::NoAction,
+                on_update: ReferentialAction::NoAction,
+            },
+        });
+        database.execute(create_referencing_table).unwrap();
+
+        let referenced_table = FullTableName::from((&SCHEMA, &TABLE));
+        database
+            .work_with(&referenced_table, |table| {
+                table.insert(&[vec![Some(small_int(1)), Some(big_int(10))]], &[])
+            })
+            .unwrap();
+
+        (database, referenced_table, FullTableName::from((&SCHEMA, &OTHER_TABLE)))
+    }
+
+    #[test]
+    fn row_referencing_concurrently_deleted_row_fails_on_commit() {
+        let (database, referenced_table, referencing_table) = with_foreign_key();
+        let deleting = database.begin();
+        let inserting = database.begin();
+
+        database
+            .work_in(&deleting, &referenced_table, |table| {
+                table.delete(Some(&column_equals("col_1", TypedValue::SmallInt(1))))
+            })
+            .unwrap();
+        database
+            .work_in(&inserting, &referencing_table, |table| {
+                table.insert(&[vec![Some(small_int(1)), Some(big_int(100))]], &[])
+            })
+            .unwrap();
+
+        assert_eq!(database.commit(deleting), Ok(()));
+        assert_eq!(
+            database.commit(inserting),
+            Err(QueryExecutionError::ForeignKeyViolation {
+                table_name: OTHER_TABLE.to_owned(),
+                constraint_name: FOREIGN_KEY.to_owned(),
+            })
+        );
+        assert_eq!(
+            committed_rows(&database, &referencing_table),
+            Vec::<Vec<ScalarValue>>::new()
+        );
+    }
+
+    #[test]
+    fn deletion_of_concurrently_referenced_row_fails_on_commit() {
+        let (database, referenced_table, referencing_table) = with_foreign_key();
+        let deleting = database.begin();
+        let inserting = database.begin();
+
+        database
+            .work_in(&deleting, &referenced_table, |table| {
+                table.delete(Some(&column_equals("col_1", TypedValue::SmallInt(1))))
+            })
+            .unwrap();
+        database
+            .work_in(&inserting, &referencing_table, |table| {
+                table.insert(&[vec![Some(small_int(1)), Some(big_int(100))]], &[])
+            })
+            .unwrap();
+
+        assert_eq!(database.commit(inserting), Ok(()));
+        assert_eq!(
+            database.commit(deleting),
+            Err(QueryExecutionError::RowIsReferenced {
+                table_name: TABLE.to_owned(),
+                constraint_name: FOREIGN_KEY.to_owned(),
+                referencing_table_name: OTHER_TABLE.to_owned(),
+            })
+        );
+        assert_eq!(
+            committed_rows(&database, &referenced_table),
+            vec![vec![ScalarValue::Int16(1), ScalarValue::Int64(10)]]
+        );
+    }
+}
@@ -12,22 +12,145 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{binary::Binary, repr::Datum, Cursor, DataCatalog, DataTable, Key, SchemaHandle, Value};
+use crate::{
+    binary::Binary,
+    index::{IndexRange, Indexes},
+    repr::Datum,
+    transaction::{TableChanges, Version},
+    Cursor, DataCatalog, DataTable, Key, SchemaHandle, Value,
+};
 use dashmap::DashMap;
-use definition::FullTableName;
+use definition::IndexKind;
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, VecDeque},
+    ops::Bound,
     sync::{
         atomic::{AtomicU64, Ordering},
-        Arc, RwLock,
+        Arc, Mutex, RwLock,
     },
 };
 
+/// number of records that are read from a table under a single lock
+const SCAN_BATCH_SIZE: usize = 1024;
+
+/// record as it was committed at `version`, deleted record has no value
+#[derive(Debug)]
+struct RecordVersion {
+    version: Version,
+    value: Option<Value>,
+}
+
 #[derive(Default, Debug)]
 struct InternalInMemoryTableHandle {
-    records: RwLock<BTreeMap<Binary, Binary>>,
+    /// versions of a record are ordered from the oldest to the newest
+    records: RwLock<BTreeMap<Binary, Vec<RecordVersion>>>,
+    /// keys that have versions which become obsolete as soon as there are
+    /// no snapshots older than the version
+    obsolete: Mutex<VecDeque<(Version, Key)>>,
+    /// the latest committed version, records that are changed out of
+    /// a transaction are marked with it
+    version: AtomicU64,
     record_ids: AtomicU64,
     column_ords: AtomicU64,
+    /// locked only while `records` are locked for writing or not locked at all
+    indexes: RwLock<Indexes>,
+    /// positions of primary key columns, records of tables without a primary key
+    /// are identified by generated record ids
+    primary_key: RwLock<Vec<usize>>,
+}
+
+impl InternalInMemoryTableHandle {
+    fn key(&self, value: &Value) -> Key {
+        let primary_key = self.primary_key.read().unwrap();
+        if primary_key.is_empty() {
+            let record_id = self.record_ids.fetch_add(1, Ordering::SeqCst);
+            Binary::pack(&[Datum::from_u64(record_id)])
+        } else {
+            let datums = value.unpack();
+            Binary::pack(
+                &primary_key
+                    .iter()
+                    .map(|index| datums[*index].clone())
+                    .collect::<Vec<Datum>>(),
+            )
+        }
+    }
+
+    fn write(
+        &self,
+        records: &mut BTreeMap<Binary, Vec<RecordVersion>>,
+        key: Key,
+        version: Version,
+        value: Option<Value>,
+    ) {
+        let versions = records.entry(key.clone()).or_default();
+        let mut indexes = self.indexes.write().unwrap();
+        if let Some(value) = &value {
+            indexes.insert(&key, value);
+        }
+        match versions.last_mut() {
+            None => versions.push(RecordVersion { version, value }),
+            Some(last) => {
+                if last.version == version {
+                    if let Some(replaced) = std::mem::replace(&mut last.value, value) {
+                        let kept = versions
+                            .iter()
+                            .filter_map(|record| record.value.as_ref())
+                            .collect::<Vec<&Value>>();
+                        indexes.remove(&key, &replaced, &kept);
+                    }
+                } else {
+                    versions.push(RecordVersion { version, value });
+                }
+                self.obsolete.lock().unwrap().push_back((version, key));
+            }
+        }
+    }
+}
+
+fn latest(versions: &[RecordVersion]) -> Option<&Value> {
+    versions.last().and_then(|record| record.value.as_ref())
+}
+
+fn visible(versions: &[RecordVersion], version: Version) -> Option<&Value> {
+    versions
+        .iter()
+        .rev()
+        .find(|record| record.version <= version)
+        .and_then(|record| record.value.as_ref())
+}
+
+/// reads records in batches so that writers are not blocked for the whole scan
+struct SnapshotScan {
+    table: Arc<InternalInMemoryTableHandle>,
+    version: Version,
+    last_key: Option<Key>,
+    batch: VecDeque<(Key, Value)>,
+    finished: bool,
+}
+
+impl Iterator for SnapshotScan {
+    type Item = (Key, Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.batch.is_empty() && !self.finished {
+            let records = self.table.records.read().unwrap();
+            let lower = match &self.last_key {
+                Some(key) => Bound::Excluded(key),
+                None => Bound::Unbounded,
+            };
+            let mut scanned = 0;
+            for (key, versions) in records.range((lower, Bound::Unbounded)).take(SCAN_BATCH_SIZE) {
+                if let Some(value) = visible(versions, self.version) {
+                    self.batch.push_back((key.clone(), value.clone()));
+                }
+                self.last_key = Some(key.clone());
+                scanned += 1;
+            }
+            self.finished = scanned < SCAN_BATCH_SIZE;
+        }
+        self.batch.pop_front()
+    }
 }
 
 #[derive(Default, Debug, Clone)]
@@ -42,47 +165,48 @@ impl DataTable for InMemoryTableHandle {
             .read()
             .unwrap()
             .iter()
-            .map(|(key, value)| (key.clone(), value.clone()))
+            .filter_map(|(key, versions)| latest(versions).map(|value| (key.clone(), value.clone())))
             .collect::<Cursor>()
     }
 
     fn insert(&self, data: Vec<Value>) -> usize {
         let len = data.len();
+        let version = self.inner.version.load(Ordering::SeqCst);
         let mut rw = self.inner.records.write().unwrap();
         for value in data {
-            let record_id = self.inner.record_ids.fetch_add(1, Ordering::SeqCst);
-            let key = Binary::pack(&[Datum::from_u64(record_id)]);
+            let key = self.inner.key(&value);
             debug_assert!(
-                matches!(rw.insert(key, value), None),
+                rw.get(&key).and_then(|versions| latest(versions)).is_none(),
                 "insert operation should insert nonexistent key"
             );
+            self.inner.write(&mut rw, key, version, Some(value));
         }
         len
     }
 
     fn update(&self, data: Vec<(Key, Value)>) -> usize {
         let len = data.len();
+        let version = self.inner.version.load(Ordering::SeqCst);
         let mut rw = self.inner.records.write().unwrap();
         for (key, value) in data {
             debug_assert!(
-                matches!(rw.insert(key, value), Some(_)),
+                rw.get(&key).and_then(|versions| latest(versions)).is_some(),
                 "update operation should change already existed key"
             );
+            self.inner.write(&mut rw, key, version, Some(value));
         }
         len
     }
 
     fn delete(&self, data: Vec<Key>) -> usize {
+        let version = self.inner.version.load(Ordering::SeqCst);
         let mut rw = self.inner.records.write().unwrap();
         let mut size = 0;
-        let keys = rw
-            .iter()
-            .filter(|(key, _value)| data.contains(key))
-            .map(|(key, _value)| key.clone())
-            .collect::<Vec<Binary>>();
-        for key in keys.iter() {
-            debug_assert!(matches!(rw.remove(key), Some(_)), "delete operation delete existed key");
-            size += 1;
+        for key in data {
+            if rw.get(&key).and_then(|versions| latest(versions)).is_some() {
+                self.inner.write(&mut rw, key, version, None);
+                size += 1;
+            }
         }
         size
     }
@@ -90,6 +214,116 @@ impl DataTable for InMemoryTableHandle {
     fn next_column_ord(&self) -> u64 {
         self.inner.column_ords.fetch_add(1, Ordering::SeqCst)
     }
+
+    fn select_at(&self, version: Version) -> Cursor {
+        Cursor::lazy(SnapshotScan {
+            table: self.inner.clone(),
+            version,
+            last_key: None,
+            batch: VecDeque::new(),
+            finished: false,
+        })
+    }
+
+    fn changed_after(&self, key: &Key, version: Version) -> bool {
+        match self
+            .inner
+            .records
+            .read()
+            .unwrap()
+            .get(key)
+            .and_then(|versions| versions.last())
+        {
+            Some(record) => record.version > version,
+            None => false,
+        }
+    }
+
+    fn commit(&self, changes: &TableChanges, version: Version) {
+        let mut rw = self.inner.records.write().unwrap();
+        for key in changes.deleted.iter() {
+            self.inner.write(&mut rw, key.clone(), version, None);
+        }
+        for (key, value) in changes.updated.iter() {
+            self.inner.write(&mut rw, key.clone(), version, Some(value.clone()));
+        }
+        for value in changes.inserted.values() {
+            let key = self.inner.key(value);
+            self.inner.write(&mut rw, key, version, Some(value.clone()));
+        }
+        self.inner.version.store(version, Ordering::SeqCst);
+    }
+
+    fn collect_garbage(&self, version: Version) {
+        let mut rw = self.inner.records.write().unwrap();
+        let mut indexes = self.inner.indexes.write().unwrap();
+        let mut obsolete = self.inner.obsolete.lock().unwrap();
+        while let Some((_, key)) = obsolete.front().filter(|(obsolete_at, _)| *obsolete_at <= version) {
+            let key = key.clone();
+            obsolete.pop_front();
+            let remove = match rw.get_mut(&key) {
+                Some(versions) => {
+                    // the newest version that is seen by the oldest snapshot
+                    if let Some(index) = versions.iter().rposition(|record| record.version <= version) {
+                        let drained = versions.drain(..index).collect::<Vec<RecordVersion>>();
+                        let kept = versions
+                            .iter()
+                            .filter_map(|record| record.value.as_ref())
+                            .collect::<Vec<&Value>>();
+                        for value in drained.iter().filter_map(|record| record.value.as_ref()) {
+                            indexes.remove(&key, value, &kept);
+                        }
+                    }
+                    versions.len() == 1 && versions[0].value.is_none()
+                }
+                None => false,
+            };
+            if remove {
+                rw.remove(&key);
+            }
+        }
+    }
+
+    fn create_index(&self, index_name: &str, columns: Vec<usize>, kind: IndexKind) {
+        if kind == IndexKind::PrimaryKey {
+            *self.inner.primary_key.write().unwrap() = columns.clone();
+        }
+        let records = self.inner.records.read().unwrap();
+        let records = records.iter().flat_map(|(key, versions)| {
+            versions
+                .iter()
+                .filter_map(move |record| record.value.as_ref().map(|value| (key, value)))
+        });
+        self.inner.indexes.write().unwrap().create(index_name, columns, records);
+    }
+
+    fn drop_index(&self, index_name: &str) {
+        self.inner.indexes.write().unwrap().drop_index(index_name);
+    }
+
+    fn index_scan_at(&self, index_name: &str, range: &IndexRange, version: Version) -> Cursor {
+        let keys = match self.inner.indexes.read().unwrap().scan(index_name, range) {
+            Some(keys) => keys,
+            None => return self.select_at(version),
+        };
+        let records = self.inner.records.read().unwrap();
+        keys.into_iter()
+            .filter_map(|key| {
+                records
+                    .get(&key)
+                    .and_then(|versions| visible(versions, version))
+                    .map(|value| (key.clone(), value.clone()))
+            })
+            .collect::<Cursor>()
+    }
+
+    fn record_key(&self, value: &Value) -> Option<Key> {
+        if self.inner.primary_key.read().unwrap().is_empty() {
+            None
+        } else {
+            Some(self.inner.key(value))
+        }
+    }
 }
 
 #[derive(Default, Debug)]
@@ -142,7 +376,8 @@ pub struct InMemoryCatalogHandle {
 }
 
 impl InMemoryCatalogHandle {
-    pub(crate) fn table(&self, full_table_name: &FullTableName) -> InMemoryTableHandle {
+    #[cfg(test)]
+    pub(crate) fn table(&self, full_table_name: &definition::FullTableName) -> InMemoryTableHandle {
         self.schemas
             .get(full_table_name.schema())
             .unwrap()
@@ -594,3 +829,96 @@ mod general_cases {
         }
     }
 }
+
+#[cfg(test)]
+mod versions {
+    use super::*;
+
+    fn value(value: u64) -> Value {
+        Binary::pack(&[Datum::from_u64(value)])
+    }
+
+    fn key(record_id: u64) -> Key {
+        Binary::pack(&[Datum::from_u64(record_id)])
+    }
+
+    fn updated(key: Key, value: Value) -> TableChanges {
+        let mut changes = TableChanges::default();
+        changes.updated.insert(key, value);
+        changes
+    }
+
+    fn deleted(key: Key) -> TableChanges {
+        let mut changes = TableChanges::default();
+        changes.deleted.insert(key);
+        changes
+    }
+
+    fn table_with_record() -> InMemoryTableHandle {
+        let table = InMemoryTableHandle::default();
+        table.insert(vec![value(1)]);
+        table
+    }
+
+    #[test]
+    fn snapshot_sees_records_committed_before_it() {
+        let table = table_with_record();
+
+        table.commit(&updated(key(0), value(2)), 1);
+
+        assert_eq!(table.select_at(0).collect::<Vec<_>>(), vec![(key(0), value(1))]);
+        assert_eq!(table.select_at(1).collect::<Vec<_>>(), vec![(key(0), value(2))]);
+        assert_eq!(table.select().collect::<Vec<_>>(), vec![(key(0), value(2))]);
+    }
+
+    #[test]
+    fn deleted_record_is_seen_by_older_snapshot() {
+        let table = table_with_record();
+
+        table.commit(&deleted(key(0)), 1);
+
+        assert_eq!(table.select_at(0).collect::<Vec<_>>(), vec![(key(0), value(1))]);
+        assert_eq!(table.select_at(1).collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn record_changed_after_snapshot() {
+        let table = table_with_record();
+
+        table.commit(&updated(key(0), value(2)), 1);
+
+        assert!(table.changed_after(&key(0), 0));
+        assert!(!table.changed_after(&key(0), 1));
+    }
+
+    #[test]
+    fn garbage_collection_keeps_versions_seen_by_the_oldest_snapshot() {
+        let table = table_with_record();
+
+        table.commit(&updated(key(0), value(2)), 1);
+        table.commit(&updated(key(0), value(3)), 2);
+        table.collect_garbage(1);
+
+        assert_eq!(table.inner.records.read().unwrap().get(&key(0)).unwrap().len(), 2);
+        assert_eq!(table.select_at(1).collect::<Vec<_>>(), vec![(key(0), value(2))]);
+        assert_eq!(table.select_at(2).collect::<Vec<_>>(), vec![(key(0), value(3))]);
+    }
+
+    #[test]
+    fn garbage_collection_removes_deleted_records() {
+        let table = table_with_record();
+
+        table.commit(&deleted(key(0)), 1);
+        table.collect_garbage(1);
+
+        assert!(table.inner.records.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn snapshot_scan_reads_all_batches() {
+        let table = InMemoryTableHandle::default();
+        table.insert((0..SCAN_BATCH_SIZE as u64 * 2 + 1).map(value).collect());
+
+        assert_eq!(table.select_at(0).count(), SCAN_BATCH_SIZE * 2 + 1);
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{repr::Datum, Key, Value};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Bound,
+};
+
+/// bounds of values of the leading column of an index
+pub(crate) type IndexRange = (Bound<Datum>, Bound<Datum>);
+
+/// integers and floats of different widths are compared by their values
+fn normalize(datum: Datum) -> Datum {
+    match datum {
+        Datum::Int16(value) => Datum::Int64(value as i64),
+        Datum::Int32(value) => Datum::Int64(value as i64),
+        Datum::Float32(value) => Datum::from_f64(value.into_inner() as f64),
+        datum => datum,
+    }
+}
+
+fn normalize_range(range: IndexRange) -> IndexRange {
+    let (low, high) = range;
+    let bound = |bound: Bound<Datum>| match bound {
+        Bound::Included(datum) => Bound::Included(normalize(datum)),
+        Bound::Excluded(datum) => Bound::Excluded(normalize(datum)),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    (bound(low), bound(high))
+}
+
+/// values of indexed columns mapped to keys of records that have them
+#[derive(Debug)]
+struct Index {
+    columns: Vec<usize>,
+    entries: BTreeMap<Vec<Datum>, BTreeSet<Key>>,
+}
+
+impl Index {
+    fn values(&self, value: &Value) -> Vec<Datum> {
+        let row = value.unpack();
+        self.columns
+            .iter()
+            .map(|column| normalize(row.get(*column).cloned().unwrap_or(Datum::Null)))
+            .collect()
+    }
+
+    fn insert(&mut self, key: &Key, value: &Value) {
+        self.entries.entry(self.values(value)).or_default().insert(key.clone());
+    }
+
+    /// entry is kept if any of `kept` values of the record has the same indexed values
+    fn remove(&mut self, key: &Key, value: &Value, kept: &[&Value]) {
+        let values = self.values(value);
+        if kept.iter().any(|kept| self.values(kept) == values) {
+            return;
+        }
+        if let Some(keys) = self.entries.get_mut(&values) {
+            keys.remove(key);
+            if keys.is_empty() {
+                self.entries.remove(&values);
+            }
+        }
+    }
+
+    fn scan(&self, range: &IndexRange) -> Vec<Key> {
+        let (low, high) = range;
+        let start = match low {
+            Bound::Included(datum) | Bound::Excluded(datum) => Bound::Included(vec![datum.clone()]),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let mut seen = BTreeSet::new();
+        self.entries
+            .range((start, Bound::Unbounded))
+            .skip_while(|(values, _keys)| matches!(low, Bound::Excluded(datum) if &values[0] == datum))
+            .take_while(|(values, _keys)| match high {
+                Bound::Included(datum) => &values[0] <= datum,
+                Bound::Excluded(datum) => &values[0] < datum,
+                Bound::Unbounded => true,
+            })
+            .flat_map(|(_values, keys)| keys.iter())
+            .filter(|key| seen.insert(*key))
+            .cloned()
+            .collect()
+    }
+}
+
+/// secondary indexes of a table, index of a versioned table keeps entries
+/// for all versions of records that could be seen by snapshots
+#[derive(Default, Debug)]
+pub(crate) struct Indexes {
+    indexes: BTreeMap<String, Index>,
+}
+
+impl Indexes {
+    pub(crate) fn create<'r, R: Iterator<Item = (&'r Key, &'r Value)>>(
+        &mut self,
+        name: &str,
+        columns: Vec<usize>,
+        records: R,
+    ) {
+        let mut index = Index {
+            columns,
+            entries: BTreeMap::new(),
+        };
+        for (key, value) in records {
+            index.insert(key, value);
+        }
+        self.indexes.insert(name.to_owned(), index);
+    }
+
+    pub(crate) fn drop_index(&mut self, name: &str) {
+        self.indexes.remove(name);
+    }
+
+    pub(crate) fn insert(&mut self, key: &Key, value: &Value) {
+        for index in self.indexes.values_mut() {
+            index.insert(key, value);
+        }
+    }
+
+    pub(crate) fn remove(&mut self, key: &Key, value: &Value, kept: &[&Value]) {
+        for index in self.indexes.values_mut() {
+            index.remove(key, value, kept);
+        }
+    }
+
+    /// keys of records that could have value of the leading column within `range`,
+    /// `None` if there is no index with the name
+    pub(crate) fn scan(&self, name: &str, range: &IndexRange) -> Option<Vec<Key>> {
+        let range = normalize_range(range.clone());
+        self.indexes.get(name).map(|index| index.scan(&range))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::Binary;
+
+    fn key(id: u64) -> Key {
+        Binary::pack(&[Datum::from_u64(id)])
+    }
+
+    fn row(id: i32, name: &str) -> Value {
+        Binary::pack(&[Datum::from_i32(id), Datum::from_string(name.to_owned())])
+    }
+
+    fn indexes() -> Indexes {
+        let records = [(key(0), row(3, "c")), (key(1), row(1, "a")), (key(2), row(2, "b"))];
+        let mut indexes = Indexes::default();
+        indexes.create("idx", vec![0], records.iter().map(|(key, value)| (key, value)));
+        indexes
+    }
+
+    #[test]
+    fn scan_equal_values() {
+        let indexes = indexes();
+
+        assert_eq!(
+            indexes.scan(
+                "idx",
+                &(Bound::Included(Datum::from_i16(2)), Bound::Included(Datum::from_i16(2)))
+            ),
+            Some(vec![key(2)])
+        );
+    }
+
+    #[test]
+    fn scan_range_of_values() {
+        let indexes = indexes();
+
+        assert_eq!(
+            indexes.scan("idx", &(Bound::Excluded(Datum::from_i64(1)), Bound::Unbounded)),
+            Some(vec![key(2), key(0)])
+        );
+        assert_eq!(
+            indexes.scan("idx", &(Bound::Unbounded, Bound::Excluded(Datum::from_i64(3)))),
+            Some(vec![key(1), key(2)])
+        );
+    }
+
+    #[test]
+    fn removed_records_are_not_scanned() {
+        let mut indexes = indexes();
+        indexes.remove(&key(1), &row(1, "a"), &[]);
+        indexes.insert(&key(1), &row(5, "a"));
+
+        assert_eq!(
+            indexes.scan("idx", &(Bound::Unbounded, Bound::Unbounded)),
+            Some(vec![key(2), key(0), key(1)])
+        );
+    }
+
+    #[test]
+    fn kept_versions_are_scanned() {
+        let mut indexes = indexes();
+        indexes.insert(&key(1), &row(5, "a"));
+        indexes.remove(&key(1), &row(1, "b"), &[&row(1, "a")]);
+
+        assert_eq!(
+            indexes.scan(
+                "idx",
+                &(Bound::Included(Datum::from_i32(1)), Bound::Included(Datum::from_i32(1)))
+            ),
+            Some(vec![key(1)])
+        );
+    }
+
+    #[test]
+    fn dropped_index() {
+        let mut indexes = indexes();
+        indexes.drop_index("idx");
+
+        assert_eq!(indexes.scan("idx", &(Bound::Unbounded, Bound::Unbounded)), None);
+    }
+}
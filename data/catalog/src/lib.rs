@@ -15,18 +15,29 @@
 use std::{
     fmt::{self, Debug, Formatter},
     iter::FromIterator,
+    ops::Bound,
 };
 
 use binary::Binary;
 use data_definition_operations::{ExecutionError, ExecutionOutcome, SystemOperation};
+use data_manipulation_query_result::QueryExecutionError;
 use data_manipulation_typed_tree::{DynamicTypedTree, StaticTypedTree};
 use data_scalar::ScalarValue;
-use definition::{ColumnDef, FullTableName, SchemaName, TableDef};
+use definition::{ColumnDef, FullTableName, IndexDef, IndexKind, SchemaName, TableDef};
 pub use in_memory::InMemoryDatabase;
+use index::IndexRange;
+pub use on_disk::OnDiskDatabase;
+pub use transaction::Transaction;
+use transaction::{TableChanges, Version};
 
 mod binary;
+mod database;
 mod in_memory;
+mod index;
+mod on_disk;
 mod repr;
+mod transaction;
+mod wal;
 
 pub type Key = Binary;
 pub type Value = Binary;
@@ -44,6 +55,15 @@ impl Debug for Cursor {
     }
 }
 
+impl Cursor {
+    /// records are read from `source` as they are requested
+    fn lazy<I: Iterator<Item = (Binary, Binary)> + 'static>(source: I) -> Cursor {
+        Self {
+            source: Box::new(source),
+        }
+    }
+}
+
 impl FromIterator<(Binary, Binary)> for Cursor {
     fn from_iter<T: IntoIterator<Item = (Binary, Binary)>>(iter: T) -> Self {
         Self {
@@ -66,6 +86,49 @@ trait DataTable {
     fn update(&self, data: Vec<(Key, Value)>) -> usize;
     fn delete(&self, data: Vec<Key>) -> usize;
     fn next_column_ord(&self) -> u64;
+
+    /// records that are seen by a snapshot of `version`,
+    /// tables that do not keep versions return the latest records
+    fn select_at(&self, _version: Version) -> Cursor {
+        self.select()
+    }
+
+    fn changed_after(&self, _key: &Key, _version: Version) -> bool {
+        false
+    }
+
+    /// makes `changes` visible to snapshots of `version` and newer ones
+    fn commit(&self, changes: &TableChanges, _version: Version)
+    where
+        Self: Sized,
+    {
+        changes.apply(self)
+    }
+
+    /// removes versions that could not be seen by snapshots of `version` and newer ones
+    fn collect_garbage(&self, _version: Version) {}
+
+    /// builds index over values of columns at `columns` positions of existing records
+    fn create_index(&self, index_name: &str, columns: Vec<usize>, kind: IndexKind);
+
+    fn drop_index(&self, index_name: &str);
+
+    /// records that could have value of the leading column of the index within `range`,
+    /// tables without the index return all records
+    fn index_scan(&self, _index_name: &str, _range: &IndexRange) -> Cursor {
+        self.select()
+    }
+
+    /// records of the index that are seen by a snapshot of `version`
+    fn index_scan_at(&self, index_name: &str, range: &IndexRange, _version: Version) -> Cursor {
+        self.index_scan(index_name, range)
+    }
+
+    /// key of a record that is identified by values of its primary key,
+    /// tables that generate keys of inserted records return `None`
+    fn record_key(&self, _value: &Value) -> Option<Key> {
+        None
+    }
 }
 
 trait SchemaHandle {
@@ -82,35 +145,91 @@ trait DataCatalog {
     fn create_schema(&self, schema_name: &str) -> bool;
     fn drop_schema(&self, schema_name: &str) -> bool;
     fn work_with<T, F: Fn(&Self::Schema) -> T>(&self, schema_name: &str, operation: F) -> Option<T>;
+
+    /// all changes made between start and finish of an operation are
+    /// treated as a whole during recovery
+    fn start_operation(&self) {}
+
+    fn finish_operation(&self) {}
 }
 
 pub trait CatalogDefinition {
     fn table_definition(&self, table_full_name: &FullTableName) -> Option<Option<TableDef>>;
 
     fn schema_exists(&self, schema_name: &SchemaName) -> bool;
+
+    fn table_indexes(&self, table_full_name: &FullTableName) -> Vec<IndexDef>;
 }
 
 const DEFINITION_SCHEMA: &str = "DEFINITION_SCHEMA";
 const SCHEMATA_TABLE: &str = "SCHEMATA";
 const TABLES_TABLE: &str = "TABLES";
 const COLUMNS_TABLE: &str = "COLUMNS";
+const INDEXES_TABLE: &str = "INDEXES";
+const REFERENTIAL_CONSTRAINTS_TABLE: &str = "REFERENTIAL_CONSTRAINTS";
 
-pub trait SqlTable {
-    fn insert(&self, data: &[Vec<Option<StaticTypedTree>>]) -> usize;
-
-    fn select(&self) -> (Vec<ColumnDef>, Vec<Vec<ScalarValue>>);
-    fn select_with_columns(&self, column_names: Vec<String>)
-        -> Result<(Vec<ColumnDef>, Vec<Vec<ScalarValue>>), String>;
-
-    fn delete_all(&self) -> usize;
+/// named predicates of `CHECK` constraints that inserted and updated rows have to satisfy
+pub type Checks = [(String, DynamicTypedTree)];
 
-    fn update(&self, column_names: Vec<String>, assignments: Vec<DynamicTypedTree>) -> usize;
+pub trait SqlTable {
+    fn insert(&self, data: &[Vec<Option<StaticTypedTree>>], checks: &Checks) -> Result<usize, QueryExecutionError>;
+
+    fn select(
+        &self,
+        filter: Option<&DynamicTypedTree>,
+    ) -> Result<(Vec<ColumnDef>, Vec<Vec<ScalarValue>>), QueryExecutionError>;
+    fn select_with_columns(
+        &self,
+        column_names: Vec<String>,
+        filter: Option<&DynamicTypedTree>,
+    ) -> Result<(Vec<ColumnDef>, Vec<Vec<ScalarValue>>), QueryExecutionError>;
+
+    /// rows that could have value of the leading column of the index within `range`,
+    /// they have to be checked against the condition the range is derived from
+    fn select_by_index(
+        &self,
+        index_name: &str,
+        range: (Bound<ScalarValue>, Bound<ScalarValue>),
+    ) -> Result<(Vec<ColumnDef>, Vec<Vec<ScalarValue>>), QueryExecutionError>;
+
+    /// deletes rows that satisfy `filter` or all rows if there is no `filter`
+    fn delete(&self, filter: Option<&DynamicTypedTree>) -> Result<usize, QueryExecutionError>;
+
+    fn update(
+        &self,
+        column_names: Vec<String>,
+        assignments: Vec<DynamicTypedTree>,
+        filter: Option<&DynamicTypedTree>,
+        checks: &Checks,
+    ) -> Result<usize, QueryExecutionError>;
 }
 
 pub trait Database {
-    type Table: SqlTable;
-
     fn execute(&self, operation: SystemOperation) -> Result<ExecutionOutcome, ExecutionError>;
 
-    fn work_with<R, F: Fn(&Self::Table) -> R>(&self, full_table_name: &FullTableName, operation: F) -> R;
+    /// transaction sees a snapshot of all changes committed before it started
+    fn begin(&self) -> Transaction;
+
+    /// changes made by `operation` are visible only to `transaction` until it is committed
+    fn work_in<R, F: Fn(&dyn SqlTable) -> R>(
+        &self,
+        transaction: &Transaction,
+        full_table_name: &FullTableName,
+        operation: F,
+    ) -> R;
+
+    /// fails if rows changed by `transaction` were changed by a concurrent one
+    /// that was committed first
+    fn commit(&self, transaction: Transaction) -> Result<(), QueryExecutionError>;
+
+    /// discards all changes made in `transaction`
+    fn rollback(&self, _transaction: Transaction) {}
+
+    /// runs `operation` in its own transaction that is committed right after
+    fn work_with<R, F: Fn(&dyn SqlTable) -> R>(&self, full_table_name: &FullTableName, operation: F) -> R {
+        let transaction = self.begin();
+        let result = self.work_in(&transaction, full_table_name, operation);
+        self.commit(transaction).expect("transaction committed");
+        result
+    }
 }
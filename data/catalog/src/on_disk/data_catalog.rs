@@ -0,0 +1,734 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    binary::Binary,
+    index::{IndexRange, Indexes},
+    repr::Datum,
+    wal::{Record, Wal},
+    Cursor, DataCatalog, DataTable, Key, SchemaHandle, Value,
+};
+use dashmap::DashMap;
+use definition::IndexKind;
+use std::{
+    collections::BTreeMap,
+    convert::TryInto,
+    fs::{self, File},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+};
+
+const DATA_DIR: &str = "data";
+const WAL_FILE: &str = "wal";
+const SNAPSHOT_FILE: &str = "snapshot";
+
+fn valid_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(&['/', '\\'][..])
+}
+
+fn invalid_data(path: &Path) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{:?} is not a table file", path))
+}
+
+fn read_bytes(data: &[u8], index: &mut usize) -> Option<Binary> {
+    let len_end = index.checked_add(std::mem::size_of::<u64>())?;
+    let len = u64::from_le_bytes(data.get(*index..len_end)?.try_into().ok()?) as usize;
+    let end = len_end.checked_add(len)?;
+    let bytes = data.get(len_end..end)?;
+    *index = end;
+    Some(Binary::with_data(bytes.to_vec()))
+}
+
+fn write_bytes(buffer: &mut Vec<u8>, bytes: &[u8]) {
+    buffer.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+    buffer.extend_from_slice(bytes);
+}
+
+/// makes created, renamed or removed files of the folder durable
+fn sync_dir(path: &Path) -> io::Result<()> {
+    File::open(path)?.sync_all()
+}
+
+#[derive(Debug)]
+struct InternalOnDiskTableHandle {
+    schema: String,
+    name: String,
+    records: RwLock<BTreeMap<Binary, Binary>>,
+    record_ids: AtomicU64,
+    column_ords: AtomicU64,
+    /// indexes are not written to disk, they are built when the table is opened
+    indexes: RwLock<Indexes>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OnDiskTableHandle {
+    inner: Arc<InternalOnDiskTableHandle>,
+    wal: Arc<Wal>,
+}
+
+impl OnDiskTableHandle {
+    fn new(schema: &str, name: &str, wal: Arc<Wal>) -> OnDiskTableHandle {
+        OnDiskTableHandle::with(schema, name, wal, BTreeMap::new(), 0)
+    }
+
+    fn with(
+        schema: &str,
+        name: &str,
+        wal: Arc<Wal>,
+        records: BTreeMap<Binary, Binary>,
+        column_ords: u64,
+    ) -> OnDiskTableHandle {
+        let record_ids = records
+            .keys()
+            .map(|key| key.unpack()[0].as_u64() + 1)
+            .max()
+            .unwrap_or_default();
+        OnDiskTableHandle {
+            inner: Arc::new(InternalOnDiskTableHandle {
+                schema: schema.to_owned(),
+                name: name.to_owned(),
+                records: RwLock::new(records),
+                record_ids: AtomicU64::new(record_ids),
+                column_ords: AtomicU64::new(column_ords),
+                indexes: RwLock::default(),
+            }),
+            wal,
+        }
+    }
+
+    /// table file written by checkpoint starts with number of generated
+    /// column ords followed by length prefixed keys and values of records
+    fn open(schema: &str, path: &Path, wal: Arc<Wal>) -> io::Result<OnDiskTableHandle> {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| invalid_data(path))?;
+        let mut data = vec![];
+        File::open(path)?.read_to_end(&mut data)?;
+
+        let column_ords = data
+            .get(0..std::mem::size_of::<u64>())
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+            .ok_or_else(|| invalid_data(path))?;
+        let mut records = BTreeMap::new();
+        let mut index = std::mem::size_of::<u64>();
+        while index < data.len() {
+            let key = read_bytes(&data, &mut index).ok_or_else(|| invalid_data(path))?;
+            let value = read_bytes(&data, &mut index).ok_or_else(|| invalid_data(path))?;
+            records.insert(key, value);
+        }
+        Ok(OnDiskTableHandle::with(schema, name, wal, records, column_ords))
+    }
+
+    fn write_snapshot(&self, path: &Path, snapshot_path: &Path) -> io::Result<()> {
+        let mut buffer = self.inner.column_ords.load(Ordering::SeqCst).to_le_bytes().to_vec();
+        for (key, value) in self.inner.records.read().unwrap().iter() {
+            write_bytes(&mut buffer, key.to_bytes());
+            write_bytes(&mut buffer, value.to_bytes());
+        }
+        let mut snapshot = File::create(snapshot_path)?;
+        snapshot.write_all(&buffer)?;
+        snapshot.sync_all()?;
+        fs::rename(snapshot_path, path)
+    }
+
+    fn apply(&self, record: Record) {
+        match record {
+            Record::Put { records, .. } => {
+                let mut rw = self.inner.records.write().unwrap();
+                let mut indexes = self.inner.indexes.write().unwrap();
+                for (key, value) in records {
+                    self.inner
+                        .record_ids
+                        .fetch_max(key.unpack()[0].as_u64() + 1, Ordering::SeqCst);
+                    indexes.insert(&key, &value);
+                    if let Some(replaced) = rw.insert(key.clone(), value) {
+                        indexes.remove(&key, &replaced, &[&rw[&key]]);
+                    }
+                }
+            }
+            Record::Delete { keys, .. } => {
+                let mut rw = self.inner.records.write().unwrap();
+                let mut indexes = self.inner.indexes.write().unwrap();
+                for key in keys.iter() {
+                    if let Some(removed) = rw.remove(key) {
+                        indexes.remove(key, &removed, &[]);
+                    }
+                }
+            }
+            Record::ColumnOrd { ord, .. } => {
+                self.inner.column_ords.fetch_max(ord + 1, Ordering::SeqCst);
+            }
+            _ => unreachable!("{:?} can't be applied to a table", record),
+        }
+    }
+}
+
+impl DataTable for OnDiskTableHandle {
+    fn select(&self) -> Cursor {
+        self.inner
+            .records
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<Cursor>()
+    }
+
+    fn insert(&self, data: Vec<Value>) -> usize {
+        let len = data.len();
+        let records = data
+            .into_iter()
+            .map(|value| {
+                let record_id = self.inner.record_ids.fetch_add(1, Ordering::SeqCst);
+                (Binary::pack(&[Datum::from_u64(record_id)]), value)
+            })
+            .collect();
+        self.wal.log(
+            Record::Put {
+                schema: self.inner.schema.clone(),
+                table: self.inner.name.clone(),
+                records,
+            },
+            |record| self.apply(record),
+        );
+        len
+    }
+
+    fn update(&self, data: Vec<(Key, Value)>) -> usize {
+        let len = data.len();
+        self.wal.log(
+            Record::Put {
+                schema: self.inner.schema.clone(),
+                table: self.inner.name.clone(),
+                records: data,
+            },
+            |record| self.apply(record),
+        );
+        len
+    }
+
+    fn delete(&self, data: Vec<Key>) -> usize {
+        let keys = {
+            let records = self.inner.records.read().unwrap();
+            data.into_iter()
+                .filter(|key| records.contains_key(key))
+                .collect::<Vec<Binary>>()
+        };
+        let len = keys.len();
+        self.wal.log(
+            Record::Delete {
+                schema: self.inner.schema.clone(),
+                table: self.inner.name.clone(),
+                keys,
+            },
+            |record| self.apply(record),
+        );
+        len
+    }
+
+    fn next_column_ord(&self) -> u64 {
+        let ord = self.inner.column_ords.fetch_add(1, Ordering::SeqCst);
+        self.wal.log(
+            Record::ColumnOrd {
+                schema: self.inner.schema.clone(),
+                table: self.inner.name.clone(),
+                ord,
+            },
+            |record| self.apply(record),
+        );
+        ord
+    }
+
+    fn create_index(&self, index_name: &str, columns: Vec<usize>, _kind: IndexKind) {
+        let records = self.inner.records.read().unwrap();
+        self.inner
+            .indexes
+            .write()
+            .unwrap()
+            .create(index_name, columns, records.iter());
+    }
+
+    fn drop_index(&self, index_name: &str) {
+        self.inner.indexes.write().unwrap().drop_index(index_name);
+    }
+
+    fn index_scan(&self, index_name: &str, range: &IndexRange) -> Cursor {
+        let keys = match self.inner.indexes.read().unwrap().scan(index_name, range) {
+            Some(keys) => keys,
+            None => return self.select(),
+        };
+        let records = self.inner.records.read().unwrap();
+        keys.into_iter()
+            .filter_map(|key| records.get(&key).map(|value| (key.clone(), value.clone())))
+            .collect::<Cursor>()
+    }
+}
+
+#[derive(Debug)]
+struct InternalOnDiskSchemaHandle {
+    name: String,
+    tables: DashMap<String, OnDiskTableHandle>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OnDiskSchemaHandle {
+    inner: Arc<InternalOnDiskSchemaHandle>,
+    wal: Arc<Wal>,
+}
+
+impl OnDiskSchemaHandle {
+    fn new(name: &str, wal: Arc<Wal>) -> OnDiskSchemaHandle {
+        OnDiskSchemaHandle {
+            inner: Arc::new(InternalOnDiskSchemaHandle {
+                name: name.to_owned(),
+                tables: DashMap::default(),
+            }),
+            wal,
+        }
+    }
+
+    fn open(name: &str, path: &Path, wal: Arc<Wal>) -> io::Result<OnDiskSchemaHandle> {
+        let schema = OnDiskSchemaHandle::new(name, wal);
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                log::warn!("{:?} is not a table file and will be ignored", entry.path());
+                continue;
+            }
+            let table = OnDiskTableHandle::open(name, &entry.path(), schema.wal.clone())?;
+            schema.inner.tables.insert(table.inner.name.clone(), table);
+        }
+        Ok(schema)
+    }
+
+    fn write_snapshot(&self, path: &Path, snapshot_path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path)?;
+        for table in self.inner.tables.iter() {
+            table.write_snapshot(&path.join(table.key()), snapshot_path)?;
+        }
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            let stale = match entry.file_name().to_str() {
+                Some(name) => !self.inner.tables.contains_key(name),
+                None => true,
+            };
+            if stale {
+                fs::remove_file(entry.path())?;
+            }
+        }
+        sync_dir(path)
+    }
+
+    fn apply(&self, record: Record) {
+        match record {
+            Record::CreateTable { table, .. } => {
+                let wal = self.wal.clone();
+                let schema = &self.inner.name;
+                self.inner
+                    .tables
+                    .entry(table.clone())
+                    .or_insert_with(|| OnDiskTableHandle::new(schema, &table, wal));
+            }
+            Record::DropTable { table, .. } => {
+                self.inner.tables.remove(&table);
+            }
+            Record::Put { ref table, .. } | Record::Delete { ref table, .. } | Record::ColumnOrd { ref table, .. } => {
+                match self.inner.tables.get(table).map(|table| table.clone()) {
+                    Some(table) => table.apply(record),
+                    None => log::warn!("{:?} is skipped as table does not exist", record),
+                }
+            }
+            _ => unreachable!("{:?} can't be applied to a schema", record),
+        }
+    }
+}
+
+impl SchemaHandle for OnDiskSchemaHandle {
+    type Table = OnDiskTableHandle;
+
+    fn create_table(&self, table_name: &str) -> bool {
+        if self.inner.tables.contains_key(table_name) {
+            log::error!("TABLE {:?} is already exist", table_name);
+            return false;
+        }
+        if !valid_name(table_name) {
+            log::error!("TABLE {:?} can't be stored on disk", table_name);
+            return false;
+        }
+        self.wal.log(
+            Record::CreateTable {
+                schema: self.inner.name.clone(),
+                table: table_name.to_owned(),
+            },
+            |record| self.apply(record),
+        );
+        log::warn!("TABLE {:?} was created", table_name);
+        true
+    }
+
+    fn drop_table(&self, table_name: &str) -> bool {
+        if !self.inner.tables.contains_key(table_name) {
+            log::warn!("TABLE {:?} does not exist", table_name);
+            return false;
+        }
+        self.wal.log(
+            Record::DropTable {
+                schema: self.inner.name.clone(),
+                table: table_name.to_owned(),
+            },
+            |record| self.apply(record),
+        );
+        log::warn!("TABLE {:?} was removed", table_name);
+        true
+    }
+
+    fn empty(&self) -> bool {
+        self.inner.tables.is_empty()
+    }
+
+    fn all_tables(&self) -> Vec<String> {
+        self.inner.tables.iter().map(|entry| entry.key().clone()).collect()
+    }
+
+    fn work_with<T, F: Fn(&Self::Table) -> T>(&self, table_name: &str, operation: F) -> Option<T> {
+        let table = self.inner.tables.get(table_name).map(|table| table.clone());
+        table.map(|table| operation(&table))
+    }
+}
+
+pub struct OnDiskCatalogHandle {
+    path: PathBuf,
+    schemas: DashMap<String, OnDiskSchemaHandle>,
+    wal: Arc<Wal>,
+    checkpoint_threshold: u64,
+}
+
+impl OnDiskCatalogHandle {
+    /// loads schemas and tables written by the last checkpoint, replays
+    /// write-ahead log on top of them and makes a new checkpoint
+    pub(crate) fn open<P: AsRef<Path>>(path: P, checkpoint_threshold: u64) -> io::Result<OnDiskCatalogHandle> {
+        let path = path.as_ref().to_path_buf();
+        fs::create_dir_all(path.join(DATA_DIR))?;
+        let (wal, records) = Wal::open(&path.join(WAL_FILE))?;
+        let wal = Arc::new(wal);
+
+        let schemas = DashMap::default();
+        for entry in fs::read_dir(path.join(DATA_DIR))? {
+            let entry = entry?;
+            match entry.file_name().into_string() {
+                Ok(name) if entry.file_type()?.is_dir() => {
+                    schemas.insert(
+                        name.clone(),
+                        OnDiskSchemaHandle::open(&name, &entry.path(), wal.clone())?,
+                    );
+                }
+                _ => log::warn!("{:?} is not a schema folder and will be ignored", entry.path()),
+            }
+        }
+
+        let catalog = OnDiskCatalogHandle {
+            path,
+            schemas,
+            wal,
+            checkpoint_threshold,
+        };
+        if !records.is_empty() {
+            log::info!("replaying {} records of write-ahead log", records.len());
+        }
+        for record in records {
+            catalog.apply(record);
+        }
+        catalog.checkpoint()?;
+        Ok(catalog)
+    }
+
+    /// writes all tables into their files and truncates write-ahead log
+    pub(crate) fn checkpoint(&self) -> io::Result<()> {
+        self.wal.checkpoint(0, || self.write_snapshot())
+    }
+
+    /// makes a checkpoint when write-ahead log grows over the threshold
+    pub(crate) fn checkpoint_if_needed(&self) {
+        if self.wal.size() >= self.checkpoint_threshold {
+            if let Err(io_error) = self.wal.checkpoint(self.checkpoint_threshold, || self.write_snapshot()) {
+                log::error!("checkpoint failed due to {:?}", io_error);
+            }
+        }
+    }
+
+    fn write_snapshot(&self) -> io::Result<()> {
+        let data_path = self.path.join(DATA_DIR);
+        let snapshot_path = self.path.join(SNAPSHOT_FILE);
+        for schema in self.schemas.iter() {
+            schema.write_snapshot(&data_path.join(schema.key()), &snapshot_path)?;
+        }
+        for entry in fs::read_dir(&data_path)? {
+            let entry = entry?;
+            let stale = match entry.file_name().to_str() {
+                Some(name) => !self.schemas.contains_key(name),
+                None => true,
+            };
+            if stale && entry.file_type()?.is_dir() {
+                fs::remove_dir_all(entry.path())?;
+            } else if stale {
+                fs::remove_file(entry.path())?;
+            }
+        }
+        sync_dir(&data_path)
+    }
+
+    fn apply(&self, record: Record) {
+        match record {
+            Record::CreateSchema { schema } => {
+                let wal = self.wal.clone();
+                self.schemas
+                    .entry(schema.clone())
+                    .or_insert_with(|| OnDiskSchemaHandle::new(&schema, wal));
+            }
+            Record::DropSchema { schema } => {
+                self.schemas.remove(&schema);
+            }
+            Record::CreateTable { ref schema, .. }
+            | Record::DropTable { ref schema, .. }
+            | Record::Put { ref schema, .. }
+            | Record::Delete { ref schema, .. }
+            | Record::ColumnOrd { ref schema, .. } => match self.schemas.get(schema).map(|schema| schema.clone()) {
+                Some(schema) => schema.apply(record),
+                None => log::warn!("{:?} is skipped as schema does not exist", record),
+            },
+        }
+    }
+}
+
+impl DataCatalog for OnDiskCatalogHandle {
+    type Schema = OnDiskSchemaHandle;
+
+    fn create_schema(&self, schema_name: &str) -> bool {
+        if self.schemas.contains_key(schema_name) {
+            return false;
+        }
+        if !valid_name(schema_name) {
+            log::error!("SCHEMA {:?} can't be stored on disk", schema_name);
+            return false;
+        }
+        self.wal.log(
+            Record::CreateSchema {
+                schema: schema_name.to_owned(),
+            },
+            |record| self.apply(record),
+        );
+        true
+    }
+
+    fn drop_schema(&self, schema_name: &str) -> bool {
+        if !self.schemas.contains_key(schema_name) {
+            return false;
+        }
+        self.wal.log(
+            Record::DropSchema {
+                schema: schema_name.to_owned(),
+            },
+            |record| self.apply(record),
+        );
+        true
+    }
+
+    fn work_with<T, F: Fn(&Self::Schema) -> T>(&self, schema_name: &str, operation: F) -> Option<T> {
+        let schema = self.schemas.get(schema_name).map(|schema| schema.clone());
+        schema.map(|schema| operation(&schema))
+    }
+
+    fn start_operation(&self) {
+        self.wal.start_operation();
+    }
+
+    fn finish_operation(&self) {
+        self.wal.finish_operation();
+    }
+}
+
+#[cfg(test)]
+mod general_cases {
+    use super::*;
+
+    const SCHEMA: &str = "schema_name";
+    const TABLE: &str = "table_name";
+
+    fn catalog(root_path: &Path) -> OnDiskCatalogHandle {
+        OnDiskCatalogHandle::open(root_path, u64::MAX).expect("catalog is opened")
+    }
+
+    fn values(catalog_handle: &OnDiskCatalogHandle) -> Option<Option<Vec<Binary>>> {
+        catalog_handle.work_with(SCHEMA, |schema| {
+            schema.work_with(TABLE, |table| {
+                table.select().map(|(_key, value)| value).collect::<Vec<Binary>>()
+            })
+        })
+    }
+
+    #[test]
+    fn checkpoint_writes_schemas_and_tables_as_folders_and_files() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let catalog_handle = catalog(root_path.path());
+
+        assert!(catalog_handle.create_schema(SCHEMA));
+        assert_eq!(
+            catalog_handle.work_with(SCHEMA, |schema| schema.create_table(TABLE)),
+            Some(true)
+        );
+        assert!(!root_path.path().join(DATA_DIR).join(SCHEMA).exists());
+
+        catalog_handle.checkpoint().expect("checkpoint is made");
+
+        assert!(root_path.path().join(DATA_DIR).join(SCHEMA).is_dir());
+        assert!(root_path.path().join(DATA_DIR).join(SCHEMA).join(TABLE).is_file());
+        assert_eq!(fs::metadata(root_path.path().join(WAL_FILE)).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn checkpoint_removes_folders_and_files_of_dropped_schemas_and_tables() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let catalog_handle = catalog(root_path.path());
+        catalog_handle.create_schema(SCHEMA);
+        catalog_handle.create_schema("other_schema");
+        catalog_handle.work_with(SCHEMA, |schema| schema.create_table(TABLE));
+        catalog_handle.checkpoint().expect("checkpoint is made");
+
+        assert_eq!(
+            catalog_handle.work_with(SCHEMA, |schema| schema.drop_table(TABLE)),
+            Some(true)
+        );
+        assert!(catalog_handle.drop_schema("other_schema"));
+        catalog_handle.checkpoint().expect("checkpoint is made");
+
+        assert!(!root_path.path().join(DATA_DIR).join(SCHEMA).join(TABLE).exists());
+        assert!(!root_path.path().join(DATA_DIR).join("other_schema").exists());
+    }
+
+    #[test]
+    fn records_are_recovered_from_log() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        {
+            let catalog_handle = catalog(root_path.path());
+            catalog_handle.create_schema(SCHEMA);
+            catalog_handle.work_with(SCHEMA, |schema| schema.create_table(TABLE));
+            catalog_handle.work_with(SCHEMA, |schema| {
+                schema.work_with(TABLE, |table| {
+                    table.insert(vec![
+                        Binary::pack(&[Datum::from_i16(1)]),
+                        Binary::pack(&[Datum::from_i16(2)]),
+                        Binary::pack(&[Datum::from_i16(3)]),
+                    ]);
+                    let keys = table.select().map(|(key, _value)| key).collect::<Vec<Binary>>();
+                    table.update(vec![(keys[0].clone(), Binary::pack(&[Datum::from_i16(10)]))]);
+                    table.delete(vec![keys[1].clone()]);
+                })
+            });
+        }
+
+        assert_eq!(
+            values(&catalog(root_path.path())),
+            Some(Some(vec![
+                Binary::pack(&[Datum::from_i16(10)]),
+                Binary::pack(&[Datum::from_i16(3)])
+            ]))
+        );
+    }
+
+    #[test]
+    fn records_are_recovered_from_checkpoint_and_log() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        {
+            let catalog_handle = catalog(root_path.path());
+            catalog_handle.create_schema(SCHEMA);
+            catalog_handle.work_with(SCHEMA, |schema| schema.create_table(TABLE));
+            catalog_handle.work_with(SCHEMA, |schema| {
+                schema.work_with(TABLE, |table| table.insert(vec![Binary::pack(&[Datum::from_i16(1)])]))
+            });
+            catalog_handle.checkpoint().expect("checkpoint is made");
+            catalog_handle.work_with(SCHEMA, |schema| {
+                schema.work_with(TABLE, |table| table.insert(vec![Binary::pack(&[Datum::from_i16(2)])]))
+            });
+        }
+
+        assert_eq!(
+            values(&catalog(root_path.path())),
+            Some(Some(vec![
+                Binary::pack(&[Datum::from_i16(1)]),
+                Binary::pack(&[Datum::from_i16(2)])
+            ]))
+        );
+    }
+
+    #[test]
+    fn record_ids_and_column_ords_continue_after_reopening() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        {
+            let catalog_handle = catalog(root_path.path());
+            catalog_handle.create_schema(SCHEMA);
+            catalog_handle.work_with(SCHEMA, |schema| schema.create_table(TABLE));
+            catalog_handle.work_with(SCHEMA, |schema| {
+                schema.work_with(TABLE, |table| {
+                    table.next_column_ord();
+                    table.insert(vec![Binary::pack(&[Datum::from_i16(1)])]);
+                })
+            });
+        }
+
+        let catalog_handle = catalog(root_path.path());
+        catalog_handle.work_with(SCHEMA, |schema| {
+            schema.work_with(TABLE, |table| {
+                assert_eq!(table.next_column_ord(), 1);
+                table.insert(vec![Binary::pack(&[Datum::from_i16(2)])]);
+                assert_eq!(table.select().count(), 2);
+            })
+        });
+    }
+
+    #[test]
+    fn changes_of_unfinished_operation_are_not_recovered() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        {
+            let catalog_handle = Arc::new(catalog(root_path.path()));
+            catalog_handle.create_schema(SCHEMA);
+            let in_operation = catalog_handle.clone();
+            std::thread::spawn(move || {
+                in_operation.start_operation();
+                in_operation.work_with(SCHEMA, |schema| schema.create_table(TABLE));
+            })
+            .join()
+            .unwrap();
+        }
+
+        let catalog_handle = catalog(root_path.path());
+        assert_eq!(catalog_handle.work_with(SCHEMA, |schema| schema.empty()), Some(true));
+    }
+
+    #[test]
+    fn log_is_checkpointed_when_it_reaches_threshold() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let catalog_handle = OnDiskCatalogHandle::open(root_path.path(), 1).expect("catalog is opened");
+        catalog_handle.create_schema(SCHEMA);
+
+        catalog_handle.checkpoint_if_needed();
+
+        assert!(root_path.path().join(DATA_DIR).join(SCHEMA).is_dir());
+        assert_eq!(fs::metadata(root_path.path().join(WAL_FILE)).unwrap().len(), 0);
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data_definition_operations::{ExecutionError, ExecutionOutcome, SystemOperation};
+use data_manipulation_query_result::QueryExecutionError;
+use definition::FullTableName;
+use std::{io, path::Path, sync::Arc};
+
+use crate::{database::DatabaseHandle, transaction::Transaction, Database, SqlTable};
+pub(crate) use data_catalog::OnDiskCatalogHandle;
+
+mod data_catalog;
+
+const CHECKPOINT_THRESHOLD: u64 = 64 * 1024 * 1024;
+
+pub type OnDiskDatabase = DatabaseHandle<OnDiskCatalogHandle>;
+
+impl OnDiskDatabase {
+    pub fn new<P: AsRef<Path>>(root_path: P) -> io::Result<Arc<OnDiskDatabase>> {
+        OnDiskDatabase::with_checkpoint_threshold(root_path, CHECKPOINT_THRESHOLD)
+    }
+
+    /// checkpoint is made automatically as soon as write-ahead log size
+    /// reaches `checkpoint_threshold` bytes
+    pub fn with_checkpoint_threshold<P: AsRef<Path>>(
+        root_path: P,
+        checkpoint_threshold: u64,
+    ) -> io::Result<Arc<OnDiskDatabase>> {
+        Ok(Arc::new(
+            DatabaseHandle::create(OnDiskCatalogHandle::open(root_path, checkpoint_threshold)?).bootstrap(),
+        ))
+    }
+
+    pub fn checkpoint(&self) -> io::Result<()> {
+        self.catalog().checkpoint()
+    }
+}
+
+impl Database for OnDiskDatabase {
+    fn execute(&self, operation: SystemOperation) -> Result<ExecutionOutcome, ExecutionError> {
+        let result = self.execute_system_operation(operation);
+        self.catalog().checkpoint_if_needed();
+        result
+    }
+
+    fn begin(&self) -> Transaction {
+        self.begin_transaction()
+    }
+
+    fn work_in<R, F: Fn(&dyn SqlTable) -> R>(
+        &self,
+        transaction: &Transaction,
+        full_table_name: &FullTableName,
+        operation: F,
+    ) -> R {
+        self.work_with_table(transaction, full_table_name, operation)
+    }
+
+    fn commit(&self, transaction: Transaction) -> Result<(), QueryExecutionError> {
+        let result = self.commit_transaction(transaction);
+        self.catalog().checkpoint_if_needed();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests;
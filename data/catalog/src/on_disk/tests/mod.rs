@@ -0,0 +1,208 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{CatalogDefinition, Database};
+use data_definition_operations::{
+    ExecutionError, ExecutionOutcome, Kind, ObjectState, Record, Step, SystemObject, SystemOperation,
+};
+use data_manipulation_typed_tree::{StaticTypedItem, StaticTypedTree, TypedValue};
+use data_scalar::ScalarValue;
+use definition::{ColumnConstraints, ColumnDef, FullTableName, SchemaName};
+use types::SqlType;
+
+const SCHEMA: &str = "schema_name";
+const TABLE: &str = "table_name";
+
+fn create_schema_ops(schema_name: &str) -> SystemOperation {
+    SystemOperation {
+        kind: Kind::Create(SystemObject::Schema),
+        skip_steps_if: None,
+        steps: vec![vec![
+            Step::CheckExistence {
+                system_object: SystemObject::Schema,
+                object_name: vec![schema_name.to_owned()],
+            },
+            Step::CreateFolder {
+                name: schema_name.to_owned(),
+            },
+            Step::CreateRecord {
+                record: Record::Schema {
+                    schema_name: schema_name.to_owned(),
+                },
+            },
+        ]],
+    }
+}
+
+fn create_table_ops(schema_name: &str, table_name: &str) -> SystemOperation {
+    SystemOperation {
+        kind: Kind::Create(SystemObject::Table),
+        skip_steps_if: None,
+        steps: vec![vec![
+            Step::CheckExistence {
+                system_object: SystemObject::Schema,
+                object_name: vec![schema_name.to_owned()],
+            },
+            Step::CheckExistence {
+                system_object: SystemObject::Table,
+                object_name: vec![schema_name.to_owned(), table_name.to_owned()],
+            },
+            Step::CreateFile {
+                folder_name: schema_name.to_owned(),
+                name: table_name.to_owned(),
+            },
+            Step::CreateRecord {
+                record: Record::Table {
+                    schema_name: schema_name.to_owned(),
+                    table_name: table_name.to_owned(),
+                },
+            },
+            Step::CreateRecord {
+                record: Record::Column {
+                    schema_name: schema_name.to_owned(),
+                    table_name: table_name.to_owned(),
+                    column_name: "col_1".to_owned(),
+                    sql_type: SqlType::small_int(),
+                    constraints: ColumnConstraints::default(),
+                },
+            },
+        ]],
+    }
+}
+
+fn drop_table_ops(schema_name: &str, table_name: &str) -> SystemOperation {
+    SystemOperation {
+        kind: Kind::Drop(SystemObject::Table),
+        skip_steps_if: Some(ObjectState::NotExists),
+        steps: vec![vec![
+            Step::CheckExistence {
+                system_object: SystemObject::Schema,
+                object_name: vec![schema_name.to_owned()],
+            },
+            Step::CheckExistence {
+                system_object: SystemObject::Table,
+                object_name: vec![schema_name.to_owned(), table_name.to_owned()],
+            },
+            Step::RemoveColumns {
+                schema_name: schema_name.to_owned(),
+                table_name: table_name.to_owned(),
+            },
+            Step::RemoveRecord {
+                record: Record::Table {
+                    schema_name: schema_name.to_owned(),
+                    table_name: table_name.to_owned(),
+                },
+            },
+            Step::RemoveFile {
+                folder_name: schema_name.to_owned(),
+                name: table_name.to_owned(),
+            },
+        ]],
+    }
+}
+
+#[test]
+fn public_schema_is_created_once() {
+    let root_path = tempfile::tempdir().expect("temporary folder is created");
+    {
+        let database = OnDiskDatabase::new(root_path.path()).expect("database is opened");
+        assert!(CatalogDefinition::schema_exists(
+            &*database,
+            &SchemaName::from(&"public")
+        ));
+    }
+
+    let database = OnDiskDatabase::new(root_path.path()).expect("database is opened");
+    assert_eq!(
+        database.execute(create_schema_ops("public")),
+        Err(ExecutionError::SchemaAlreadyExists("public".to_owned()))
+    );
+}
+
+#[test]
+fn schema_and_table_survive_restart() {
+    let root_path = tempfile::tempdir().expect("temporary folder is created");
+    {
+        let database = OnDiskDatabase::new(root_path.path()).expect("database is opened");
+        assert_eq!(
+            database.execute(create_schema_ops(SCHEMA)),
+            Ok(ExecutionOutcome::SchemaCreated)
+        );
+        assert_eq!(
+            database.execute(create_table_ops(SCHEMA, TABLE)),
+            Ok(ExecutionOutcome::TableCreated)
+        );
+    }
+
+    let database = OnDiskDatabase::new(root_path.path()).expect("database is opened");
+    let full_table_name = FullTableName::from((&SCHEMA, &TABLE));
+    assert_eq!(
+        database
+            .table_definition(&full_table_name)
+            .map(|table_def| table_def.map(|table_def| table_def.columns().to_vec())),
+        Some(Some(vec![ColumnDef::new("col_1".to_owned(), SqlType::small_int(), 0)]))
+    );
+}
+
+#[test]
+fn inserted_records_survive_restart() {
+    let root_path = tempfile::tempdir().expect("temporary folder is created");
+    let full_table_name = FullTableName::from((&SCHEMA, &TABLE));
+    {
+        let database = OnDiskDatabase::new(root_path.path()).expect("database is opened");
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        database.execute(create_table_ops(SCHEMA, TABLE)).unwrap();
+        database
+            .work_with(&full_table_name, |table| {
+                table.insert(
+                    &[
+                        vec![Some(StaticTypedTree::Item(StaticTypedItem::Const(
+                            TypedValue::SmallInt(1),
+                        )))],
+                        vec![Some(StaticTypedTree::Item(StaticTypedItem::Const(
+                            TypedValue::SmallInt(2),
+                        )))],
+                    ],
+                    &[],
+                )
+            })
+            .unwrap();
+    }
+
+    let database = OnDiskDatabase::new(root_path.path()).expect("database is opened");
+    assert_eq!(
+        database.work_with(&full_table_name, |table| table.select(None).unwrap().1),
+        vec![vec![ScalarValue::Int16(1)], vec![ScalarValue::Int16(2)]]
+    );
+}
+
+#[test]
+fn dropped_table_does_not_come_back_after_restart() {
+    let root_path = tempfile::tempdir().expect("temporary folder is created");
+    let full_table_name = FullTableName::from((&SCHEMA, &TABLE));
+    {
+        let database = OnDiskDatabase::new(root_path.path()).expect("database is opened");
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        database.execute(create_table_ops(SCHEMA, TABLE)).unwrap();
+        assert_eq!(
+            database.execute(drop_table_ops(SCHEMA, TABLE)),
+            Ok(ExecutionOutcome::TableDropped)
+        );
+    }
+
+    let database = OnDiskDatabase::new(root_path.path()).expect("database is opened");
+    assert!(matches!(database.table_definition(&full_table_name), Some(None)));
+    assert!(!root_path.path().join("data").join(SCHEMA).join(TABLE).exists());
+}
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use data_scalar::{bytea, json::Jsonb, temporal::Interval, ScalarValue};
 use ordered_float::OrderedFloat;
 use std::fmt::{self, Display, Formatter};
+use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum Datum {
@@ -25,7 +29,17 @@ pub enum Datum {
     Int64(i64),
     Float32(OrderedFloat<f32>),
     Float64(OrderedFloat<f64>),
+    Numeric(BigDecimal),
     String(String),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Timestamp(NaiveDateTime),
+    TimestampTz(DateTime<Utc>),
+    Interval(Interval),
+    Bytea(Vec<u8>),
+    Uuid(Uuid),
+    Json(String),
+    Jsonb(Jsonb),
 }
 
 impl Datum {
@@ -39,7 +53,15 @@ impl Datum {
             Self::Int64(_) => 1 + std::mem::size_of::<i64>(),
             Self::Float32(_) => 1 + std::mem::size_of::<f32>(),
             Self::Float64(_) => 1 + std::mem::size_of::<f64>(),
+            Self::Numeric(val) => 1 + std::mem::size_of::<usize>() + val.to_string().len(),
             Self::String(val) => 1 + std::mem::size_of::<usize>() + val.len(),
+            Self::Date(_) => 1 + std::mem::size_of::<i32>(),
+            Self::Time(_) | Self::Timestamp(_) | Self::TimestampTz(_) => 1 + std::mem::size_of::<i64>(),
+            Self::Interval(_) => 1 + 2 * std::mem::size_of::<i32>() + std::mem::size_of::<i64>(),
+            Self::Bytea(val) => 1 + std::mem::size_of::<usize>() + val.len(),
+            Self::Uuid(_) => 1 + 16,
+            Self::Json(val) => 1 + std::mem::size_of::<usize>() + val.len(),
+            Self::Jsonb(val) => 1 + std::mem::size_of::<usize>() + val.to_string().len(),
         }
     }
 
@@ -95,6 +117,21 @@ impl Datum {
         Datum::String(val)
     }
 
+    pub fn from_optional_string(val: Option<String>) -> Datum {
+        match val {
+            None => Datum::Null,
+            Some(val) => Datum::String(val),
+        }
+    }
+
+    pub fn as_bool(&self) -> bool {
+        match self {
+            Self::True => true,
+            Self::False => false,
+            _ => panic!("invalid use of Datum::as_bool"),
+        }
+    }
+
     pub fn as_u32(&self) -> u32 {
         match self {
             Self::Int32(val) => *val as u32,
@@ -115,6 +152,65 @@ impl Datum {
             _ => panic!("invalid use of Datum::as_str"),
         }
     }
+
+    pub fn as_optional_string(&self) -> Option<String> {
+        match self {
+            Self::Null => None,
+            _ => Some(self.as_string()),
+        }
+    }
+}
+
+impl From<Datum> for ScalarValue {
+    fn from(datum: Datum) -> ScalarValue {
+        match datum {
+            Datum::Null => ScalarValue::Null,
+            Datum::True => ScalarValue::True,
+            Datum::False => ScalarValue::False,
+            Datum::Int16(v) => ScalarValue::Int16(v),
+            Datum::Int32(v) => ScalarValue::Int32(v),
+            Datum::Int64(v) => ScalarValue::Int64(v),
+            Datum::Float32(v) => ScalarValue::Float32(v),
+            Datum::Float64(v) => ScalarValue::Float64(v),
+            Datum::Numeric(v) => ScalarValue::Numeric(v),
+            Datum::String(v) => ScalarValue::String(v),
+            Datum::Date(v) => ScalarValue::Date(v),
+            Datum::Time(v) => ScalarValue::Time(v),
+            Datum::Timestamp(v) => ScalarValue::Timestamp(v),
+            Datum::TimestampTz(v) => ScalarValue::TimestampTz(v),
+            Datum::Interval(v) => ScalarValue::Interval(v),
+            Datum::Bytea(v) => ScalarValue::Bytea(v),
+            Datum::Uuid(v) => ScalarValue::Uuid(v),
+            Datum::Json(v) => ScalarValue::Json(v),
+            Datum::Jsonb(v) => ScalarValue::Jsonb(v),
+        }
+    }
+}
+
+impl From<ScalarValue> for Datum {
+    fn from(value: ScalarValue) -> Datum {
+        match value {
+            ScalarValue::Null => Datum::Null,
+            ScalarValue::True => Datum::True,
+            ScalarValue::False => Datum::False,
+            ScalarValue::Int16(v) => Datum::Int16(v),
+            ScalarValue::Int32(v) => Datum::Int32(v),
+            ScalarValue::Int64(v) => Datum::Int64(v),
+            ScalarValue::Float32(v) => Datum::Float32(v),
+            ScalarValue::Float64(v) => Datum::Float64(v),
+            ScalarValue::Numeric(v) => Datum::Numeric(v),
+            ScalarValue::String(v) => Datum::String(v),
+            ScalarValue::Date(v) => Datum::Date(v),
+            ScalarValue::Time(v) => Datum::Time(v),
+            ScalarValue::Timestamp(v) => Datum::Timestamp(v),
+            ScalarValue::TimestampTz(v) => Datum::TimestampTz(v),
+            ScalarValue::Interval(v) => Datum::Interval(v),
+            ScalarValue::Bytea(v) => Datum::Bytea(v),
+            ScalarValue::Uuid(v) => Datum::Uuid(v),
+            ScalarValue::Json(v) => Datum::Json(v),
+            ScalarValue::Jsonb(v) => Datum::Jsonb(v),
+        }
+    }
 }
 
 impl Display for Datum {
@@ -128,7 +224,17 @@ impl Display for Datum {
             Self::Int64(val) => write!(f, "{}", val),
             Self::Float32(val) => write!(f, "{}", val.into_inner()),
             Self::Float64(val) => write!(f, "{}", val.into_inner()),
+            Self::Numeric(val) => write!(f, "{}", val),
             Self::String(val) => write!(f, "{}", val),
+            Self::Date(val) => write!(f, "{}", val),
+            Self::Time(val) => write!(f, "{}", val),
+            Self::Timestamp(val) => write!(f, "{}", val),
+            Self::TimestampTz(val) => write!(f, "{}", val),
+            Self::Interval(val) => write!(f, "{}", val),
+            Self::Bytea(val) => write!(f, "{}", bytea::bytea_text(val)),
+            Self::Uuid(val) => write!(f, "{}", val),
+            Self::Json(val) => write!(f, "{}", val),
+            Self::Jsonb(val) => write!(f, "{}", val),
         }
     }
 }
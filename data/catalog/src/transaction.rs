@@ -0,0 +1,294 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{binary::Binary, index::IndexRange, repr::Datum, Cursor, DataTable, Key, Value};
+use definition::{FullTableName, IndexKind};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+/// version of committed changes, a snapshot of a version sees all changes
+/// committed at the version or before it
+pub(crate) type Version = u64;
+
+pub(crate) type TableName = (String, String);
+
+/// keeps track of committed versions and snapshots that are still in use
+#[derive(Default, Debug)]
+pub(crate) struct Transactions {
+    last_committed: AtomicU64,
+    active: Mutex<BTreeMap<Version, usize>>,
+    commit: Mutex<()>,
+}
+
+impl Transactions {
+    pub(crate) fn begin(self: &Arc<Self>) -> Transaction {
+        let mut active = self.active.lock().unwrap();
+        let version = self.last_committed.load(Ordering::SeqCst);
+        *active.entry(version).or_default() += 1;
+        Transaction {
+            snapshot: Snapshot {
+                version,
+                transactions: self.clone(),
+            },
+            tables: Mutex::default(),
+        }
+    }
+
+    /// commits are serialized, changes are seen by new snapshots only after
+    /// their version is published
+    pub(crate) fn start_commit(&self) -> CommitGuard<'_> {
+        let lock = self.commit.lock().unwrap();
+        CommitGuard {
+            version: self.last_committed.load(Ordering::SeqCst) + 1,
+            transactions: self,
+            _lock: lock,
+        }
+    }
+
+    /// versions older than the oldest snapshot in use could not be seen by anyone
+    pub(crate) fn oldest_snapshot(&self) -> Version {
+        let active = self.active.lock().unwrap();
+        match active.keys().next() {
+            Some(version) => *version,
+            None => self.last_committed.load(Ordering::SeqCst),
+        }
+    }
+
+    fn release(&self, version: Version) {
+        let mut active = self.active.lock().unwrap();
+        if let Some(count) = active.get_mut(&version) {
+            *count -= 1;
+            if *count == 0 {
+                active.remove(&version);
+            }
+        }
+    }
+}
+
+pub(crate) struct CommitGuard<'t> {
+    version: Version,
+    transactions: &'t Transactions,
+    _lock: MutexGuard<'t, ()>,
+}
+
+impl<'t> CommitGuard<'t> {
+    pub(crate) fn version(&self) -> Version {
+        self.version
+    }
+
+    pub(crate) fn publish(self) {
+        self.transactions.last_committed.store(self.version, Ordering::SeqCst);
+    }
+}
+
+#[derive(Debug)]
+struct Snapshot {
+    version: Version,
+    transactions: Arc<Transactions>,
+}
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        self.transactions.release(self.version);
+    }
+}
+
+/// changes of a transaction, they are visible only to the transaction
+/// until it is committed
+#[derive(Debug)]
+pub struct Transaction {
+    snapshot: Snapshot,
+    tables: Mutex<HashMap<TableName, Arc<Mutex<TableChanges>>>>,
+}
+
+impl Transaction {
+    pub(crate) fn table<T>(&self, full_table_name: &FullTableName, data_table: T) -> TransactionTable<T> {
+        let changes = self
+            .tables
+            .lock()
+            .unwrap()
+            .entry((full_table_name.schema().to_owned(), full_table_name.table().to_owned()))
+            .or_default()
+            .clone();
+        TransactionTable {
+            data_table,
+            snapshot: self.snapshot.version,
+            changes,
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> Version {
+        self.snapshot.version
+    }
+
+    /// snapshot is kept until the transaction is dropped
+    pub(crate) fn take_changes(&self) -> Vec<(TableName, TableChanges)> {
+        self.tables
+            .lock()
+            .unwrap()
+            .drain()
+            .map(|(table, changes)| (table, std::mem::take(&mut *changes.lock().unwrap())))
+            .filter(|(_table, changes)| !changes.is_empty())
+            .collect()
+    }
+}
+
+#[derive(Default, Debug)]
+pub(crate) struct TableChanges {
+    /// rows inserted by the transaction, their keys are not known until commit
+    pub(crate) inserted: BTreeMap<Key, Value>,
+    pub(crate) updated: BTreeMap<Key, Value>,
+    pub(crate) deleted: BTreeSet<Key>,
+    next_record_id: u64,
+}
+
+impl TableChanges {
+    fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.updated.is_empty() && self.deleted.is_empty()
+    }
+
+    /// rows that were changed by the transaction and by someone else after `snapshot`
+    pub(crate) fn conflicts_with<T: DataTable>(&self, data_table: &T, snapshot: Version) -> bool {
+        self.updated
+            .keys()
+            .chain(self.deleted.iter())
+            .any(|key| data_table.changed_after(key, snapshot))
+    }
+
+    pub(crate) fn apply<T: DataTable>(&self, data_table: &T) {
+        data_table.delete(self.deleted.iter().cloned().collect());
+        data_table.update(
+            self.updated
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        );
+        data_table.insert(self.inserted.values().cloned().collect());
+    }
+}
+
+/// data table as it is seen by a transaction
+pub struct TransactionTable<T> {
+    data_table: T,
+    snapshot: Version,
+    changes: Arc<Mutex<TableChanges>>,
+}
+
+impl<T: DataTable> DataTable for TransactionTable<T> {
+    fn select(&self) -> Cursor {
+        let changes = self.changes.lock().unwrap();
+        let deleted = changes.deleted.clone();
+        let updated = changes.updated.clone();
+        let inserted = changes.inserted.clone();
+        Cursor::lazy(
+            self.data_table
+                .select_at(self.snapshot)
+                .filter(move |(key, _value)| !deleted.contains(key))
+                .map(move |(key, value)| match updated.get(&key) {
+                    Some(updated) => (key, updated.clone()),
+                    None => (key, value),
+                })
+                .chain(inserted),
+        )
+    }
+
+    fn insert(&self, data: Vec<Value>) -> usize {
+        let mut changes = self.changes.lock().unwrap();
+        let len = data.len();
+        for value in data {
+            let key = match self.data_table.record_key(&value) {
+                Some(key) => key,
+                None => {
+                    // differs from keys of committed rows that consist of a single record id
+                    let key = Binary::pack(&[Datum::from_null(), Datum::from_u64(changes.next_record_id)]);
+                    changes.next_record_id += 1;
+                    key
+                }
+            };
+            changes.inserted.insert(key, value);
+        }
+        len
+    }
+
+    /// rows with changed primary key are moved to new keys after all old keys are removed
+    fn update(&self, data: Vec<(Key, Value)>) -> usize {
+        let mut changes = self.changes.lock().unwrap();
+        let len = data.len();
+        let mut moved = vec![];
+        for (key, value) in data {
+            match self.data_table.record_key(&value) {
+                Some(new_key) if new_key != key => {
+                    if changes.inserted.remove(&key).is_none() {
+                        changes.updated.remove(&key);
+                        changes.deleted.insert(key);
+                    }
+                    moved.push((new_key, value));
+                }
+                _ => match changes.inserted.get_mut(&key) {
+                    Some(inserted) => *inserted = value,
+                    None => {
+                        changes.updated.insert(key, value);
+                    }
+                },
+            }
+        }
+        changes.inserted.extend(moved);
+        len
+    }
+
+    fn delete(&self, data: Vec<Key>) -> usize {
+        let mut changes = self.changes.lock().unwrap();
+        let len = data.len();
+        for key in data {
+            if changes.inserted.remove(&key).is_none() {
+                changes.updated.remove(&key);
+                changes.deleted.insert(key);
+            }
+        }
+        len
+    }
+
+    fn next_column_ord(&self) -> u64 {
+        self.data_table.next_column_ord()
+    }
+
+    fn create_index(&self, index_name: &str, columns: Vec<usize>, kind: IndexKind) {
+        self.data_table.create_index(index_name, columns, kind)
+    }
+
+    fn drop_index(&self, index_name: &str) {
+        self.data_table.drop_index(index_name)
+    }
+
+    /// rows changed by the transaction are not in the index and are returned regardless of `range`
+    fn index_scan(&self, index_name: &str, range: &IndexRange) -> Cursor {
+        let changes = self.changes.lock().unwrap();
+        let deleted = changes.deleted.clone();
+        let updated = changes.updated.clone();
+        let inserted = changes.inserted.clone();
+        let changed = updated.keys().cloned().collect::<BTreeSet<Key>>();
+        Cursor::lazy(
+            self.data_table
+                .index_scan_at(index_name, range, self.snapshot)
+                .filter(move |(key, _value)| !deleted.contains(key) && !changed.contains(key))
+                .chain(updated)
+                .chain(inserted),
+        )
+    }
+}
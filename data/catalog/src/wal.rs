@@ -0,0 +1,580 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Write-ahead log of the on-disk catalog.
+//!
+//! Every change of schemas, tables and table records is appended to the log
+//! and flushed to disk before it is applied to the catalog. Table files are
+//! only rewritten during a checkpoint, after that the log is truncated. On
+//! startup the log is replayed on top of the table files written by the last
+//! checkpoint.
+//!
+//! The log is a sequence of frames, every frame is a `u32` payload length,
+//! `u32` crc32 checksum of the payload and the payload itself. Payload starts
+//! with `u64` id of the system operation the record belongs to (`0` if the
+//! record does not belong to any) and a tag byte of the record.
+
+use crate::{Key, Value};
+use std::{
+    cell::Cell,
+    collections::HashSet,
+    convert::TryInto,
+    fs::{File, OpenOptions},
+    io::{self, Read, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Mutex, MutexGuard, RwLock,
+    },
+};
+
+const CREATE_SCHEMA: u8 = 1;
+const DROP_SCHEMA: u8 = 2;
+const CREATE_TABLE: u8 = 3;
+const DROP_TABLE: u8 = 4;
+const PUT: u8 = 5;
+const DELETE: u8 = 6;
+const COLUMN_ORD: u8 = 7;
+const OPERATION_END: u8 = 8;
+
+const FRAME_HEADER_LEN: usize = 8;
+const NO_OPERATION: u64 = 0;
+
+thread_local! {
+    static CURRENT_OPERATION: Cell<u64> = const { Cell::new(NO_OPERATION) };
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Record {
+    CreateSchema {
+        schema: String,
+    },
+    DropSchema {
+        schema: String,
+    },
+    CreateTable {
+        schema: String,
+        table: String,
+    },
+    DropTable {
+        schema: String,
+        table: String,
+    },
+    Put {
+        schema: String,
+        table: String,
+        records: Vec<(Key, Value)>,
+    },
+    Delete {
+        schema: String,
+        table: String,
+        keys: Vec<Key>,
+    },
+    ColumnOrd {
+        schema: String,
+        table: String,
+        ord: u64,
+    },
+}
+
+impl Record {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Record::CreateSchema { schema } => {
+                buffer.push(CREATE_SCHEMA);
+                write_bytes(buffer, schema.as_bytes());
+            }
+            Record::DropSchema { schema } => {
+                buffer.push(DROP_SCHEMA);
+                write_bytes(buffer, schema.as_bytes());
+            }
+            Record::CreateTable { schema, table } => {
+                buffer.push(CREATE_TABLE);
+                write_bytes(buffer, schema.as_bytes());
+                write_bytes(buffer, table.as_bytes());
+            }
+            Record::DropTable { schema, table } => {
+                buffer.push(DROP_TABLE);
+                write_bytes(buffer, schema.as_bytes());
+                write_bytes(buffer, table.as_bytes());
+            }
+            Record::Put { schema, table, records } => {
+                buffer.push(PUT);
+                write_bytes(buffer, schema.as_bytes());
+                write_bytes(buffer, table.as_bytes());
+                buffer.extend_from_slice(&(records.len() as u64).to_le_bytes());
+                for (key, value) in records {
+                    write_bytes(buffer, key.to_bytes());
+                    write_bytes(buffer, value.to_bytes());
+                }
+            }
+            Record::Delete { schema, table, keys } => {
+                buffer.push(DELETE);
+                write_bytes(buffer, schema.as_bytes());
+                write_bytes(buffer, table.as_bytes());
+                buffer.extend_from_slice(&(keys.len() as u64).to_le_bytes());
+                for key in keys {
+                    write_bytes(buffer, key.to_bytes());
+                }
+            }
+            Record::ColumnOrd { schema, table, ord } => {
+                buffer.push(COLUMN_ORD);
+                write_bytes(buffer, schema.as_bytes());
+                write_bytes(buffer, table.as_bytes());
+                buffer.extend_from_slice(&ord.to_le_bytes());
+            }
+        }
+    }
+
+    fn decode(tag: u8, reader: &mut Reader) -> Option<Record> {
+        let record = match tag {
+            CREATE_SCHEMA => Record::CreateSchema {
+                schema: reader.string()?,
+            },
+            DROP_SCHEMA => Record::DropSchema {
+                schema: reader.string()?,
+            },
+            CREATE_TABLE => Record::CreateTable {
+                schema: reader.string()?,
+                table: reader.string()?,
+            },
+            DROP_TABLE => Record::DropTable {
+                schema: reader.string()?,
+                table: reader.string()?,
+            },
+            PUT => {
+                let schema = reader.string()?;
+                let table = reader.string()?;
+                let len = reader.u64()?;
+                let mut records = vec![];
+                for _ in 0..len {
+                    records.push((Key::with_data(reader.bytes()?), Value::with_data(reader.bytes()?)));
+                }
+                Record::Put { schema, table, records }
+            }
+            DELETE => {
+                let schema = reader.string()?;
+                let table = reader.string()?;
+                let len = reader.u64()?;
+                let mut keys = vec![];
+                for _ in 0..len {
+                    keys.push(Key::with_data(reader.bytes()?));
+                }
+                Record::Delete { schema, table, keys }
+            }
+            COLUMN_ORD => Record::ColumnOrd {
+                schema: reader.string()?,
+                table: reader.string()?,
+                ord: reader.u64()?,
+            },
+            _ => return None,
+        };
+        Some(record)
+    }
+}
+
+fn write_bytes(buffer: &mut Vec<u8>, bytes: &[u8]) {
+    buffer.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+    buffer.extend_from_slice(bytes);
+}
+
+struct Reader<'d> {
+    data: &'d [u8],
+    index: usize,
+}
+
+impl<'d> Reader<'d> {
+    fn new(data: &'d [u8]) -> Reader<'d> {
+        Reader { data, index: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'d [u8]> {
+        let end = self.index.checked_add(len)?;
+        let bytes = self.data.get(self.index..end)?;
+        self.index = end;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(std::mem::size_of::<u32>())
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take(std::mem::size_of::<u64>())
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn bytes(&mut self) -> Option<Vec<u8>> {
+        let len = self.u64()?.try_into().ok()?;
+        self.take(len).map(ToOwned::to_owned)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        String::from_utf8(self.bytes()?).ok()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.index == self.data.len()
+    }
+}
+
+fn frame(operation: u64, record: Option<&Record>) -> Vec<u8> {
+    let mut payload = operation.to_le_bytes().to_vec();
+    match record {
+        Some(record) => record.encode(&mut payload),
+        None => payload.push(OPERATION_END),
+    }
+    let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+    frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    frame.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+    frame.extend_from_slice(&payload);
+    frame
+}
+
+enum Frame {
+    Record(u64, Record),
+    OperationEnd(u64),
+}
+
+/// returns `None` if frame was not completely written or it is corrupted
+fn read_frame(reader: &mut Reader) -> Option<Frame> {
+    let len = reader.u32()? as usize;
+    let checksum = reader.u32()?;
+    let payload = reader.take(len)?;
+    if crc32fast::hash(payload) != checksum {
+        return None;
+    }
+    let mut payload = Reader::new(payload);
+    let operation = payload.u64()?;
+    let frame = match payload.u8()? {
+        OPERATION_END => Frame::OperationEnd(operation),
+        tag => Frame::Record(operation, Record::decode(tag, &mut payload)?),
+    };
+    if payload.is_empty() {
+        Some(frame)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Wal {
+    file: Mutex<File>,
+    size: AtomicU64,
+    checkpoint: RwLock<()>,
+    operation_ids: AtomicU64,
+    operations_in_progress: AtomicUsize,
+}
+
+impl Wal {
+    /// opens log file and returns records that have to be replayed in the
+    /// order they were logged. Records of system operations that did not
+    /// finish are skipped, incomplete frame at the end of the file is cut off
+    pub(crate) fn open(path: &Path) -> io::Result<(Wal, Vec<Record>)> {
+        let mut file = OpenOptions::new().read(true).append(true).create(true).open(path)?;
+        let mut data = vec![];
+        file.read_to_end(&mut data)?;
+
+        let mut reader = Reader::new(&data);
+        let mut valid = 0;
+        let mut frames = vec![];
+        while let Some(frame) = read_frame(&mut reader) {
+            valid = reader.index;
+            frames.push(frame);
+        }
+        if valid < data.len() {
+            log::warn!(
+                "{} bytes at the end of {:?} are not complete records and will be discarded",
+                data.len() - valid,
+                path
+            );
+            file.set_len(valid as u64)?;
+            file.sync_all()?;
+        }
+
+        let finished = frames
+            .iter()
+            .filter_map(|frame| match frame {
+                Frame::OperationEnd(operation) => Some(*operation),
+                Frame::Record(..) => None,
+            })
+            .collect::<HashSet<u64>>();
+        let records = frames
+            .into_iter()
+            .filter_map(|frame| match frame {
+                Frame::Record(operation, record) if operation == NO_OPERATION || finished.contains(&operation) => {
+                    Some(record)
+                }
+                _ => None,
+            })
+            .collect();
+
+        Ok((
+            Wal {
+                file: Mutex::new(file),
+                size: AtomicU64::new(valid as u64),
+                checkpoint: RwLock::new(()),
+                operation_ids: AtomicU64::new(NO_OPERATION + 1),
+                operations_in_progress: AtomicUsize::new(0),
+            },
+            records,
+        ))
+    }
+
+    /// writes `record` to the log and flushes it on disk then `apply`s it.
+    /// Records are applied in the same order they are logged
+    pub(crate) fn log<R, F: FnOnce(Record) -> R>(&self, record: Record, apply: F) -> R {
+        let _checkpoint = self.checkpoint.read().unwrap();
+        let frame = frame(CURRENT_OPERATION.with(Cell::get), Some(&record));
+        let _file = self.append(&frame);
+        apply(record)
+    }
+
+    /// marks that all records logged by current thread belong to one system
+    /// operation until `finish_operation` is called. Records of operation that
+    /// was not finished are not replayed
+    pub(crate) fn start_operation(&self) {
+        let _checkpoint = self.checkpoint.read().unwrap();
+        let operation = self.operation_ids.fetch_add(1, Ordering::SeqCst);
+        self.operations_in_progress.fetch_add(1, Ordering::SeqCst);
+        CURRENT_OPERATION.with(|current| current.set(operation));
+    }
+
+    pub(crate) fn finish_operation(&self) {
+        let _checkpoint = self.checkpoint.read().unwrap();
+        let operation = CURRENT_OPERATION.with(|current| current.replace(NO_OPERATION));
+        debug_assert_ne!(
+            operation, NO_OPERATION,
+            "operation has to be started before it is finished"
+        );
+        let _file = self.append(&frame(operation, None));
+        self.operations_in_progress.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn size(&self) -> u64 {
+        self.size.load(Ordering::SeqCst)
+    }
+
+    /// blocks writes to the log, `snapshot`s catalog state and truncates the
+    /// log. Checkpoint is skipped if log is smaller than `min_size` or there
+    /// are system operations in progress
+    pub(crate) fn checkpoint<F: FnOnce() -> io::Result<()>>(&self, min_size: u64, snapshot: F) -> io::Result<()> {
+        let _checkpoint = self.checkpoint.write().unwrap();
+        if self.size() < min_size || self.operations_in_progress.load(Ordering::SeqCst) > 0 {
+            return Ok(());
+        }
+        snapshot()?;
+        let file = self.file.lock().unwrap();
+        file.set_len(0)?;
+        file.sync_all()?;
+        self.size.store(0, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn append(&self, frame: &[u8]) -> MutexGuard<'_, File> {
+        let mut file = self.file.lock().unwrap();
+        file.write_all(frame).expect("to write record into write-ahead log");
+        file.sync_data().expect("to flush record of write-ahead log");
+        self.size.fetch_add(frame.len() as u64, Ordering::SeqCst);
+        file
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, thread};
+
+    const SCHEMA: &str = "schema_name";
+
+    fn create_schema(schema: &str) -> Record {
+        Record::CreateSchema {
+            schema: schema.to_owned(),
+        }
+    }
+
+    fn replayed(path: &Path) -> Vec<Record> {
+        let (_wal, records) = Wal::open(path).expect("log is opened");
+        records
+    }
+
+    #[test]
+    fn records_are_replayed_in_logged_order() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let path = root_path.path().join("wal");
+        {
+            let (wal, _records) = Wal::open(&path).expect("log is opened");
+            wal.log(create_schema(SCHEMA), |_| ());
+            wal.log(
+                Record::Put {
+                    schema: SCHEMA.to_owned(),
+                    table: "table_name".to_owned(),
+                    records: vec![(Key::with_data(vec![1]), Value::with_data(vec![2, 3]))],
+                },
+                |_| (),
+            );
+            wal.log(
+                Record::DropSchema {
+                    schema: SCHEMA.to_owned(),
+                },
+                |_| (),
+            );
+        }
+
+        assert_eq!(
+            replayed(&path),
+            vec![
+                create_schema(SCHEMA),
+                Record::Put {
+                    schema: SCHEMA.to_owned(),
+                    table: "table_name".to_owned(),
+                    records: vec![(Key::with_data(vec![1]), Value::with_data(vec![2, 3]))],
+                },
+                Record::DropSchema {
+                    schema: SCHEMA.to_owned()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn records_of_finished_operation_are_replayed() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let path = root_path.path().join("wal");
+        {
+            let (wal, _records) = Wal::open(&path).expect("log is opened");
+            wal.start_operation();
+            wal.log(create_schema("schema_1"), |_| ());
+            wal.log(create_schema("schema_2"), |_| ());
+            wal.finish_operation();
+        }
+
+        assert_eq!(
+            replayed(&path),
+            vec![create_schema("schema_1"), create_schema("schema_2")]
+        );
+    }
+
+    #[test]
+    fn records_of_unfinished_operation_are_skipped() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let path = root_path.path().join("wal");
+        {
+            let (wal, _records) = Wal::open(&path).expect("log is opened");
+            let wal = std::sync::Arc::new(wal);
+            let in_operation = wal.clone();
+            thread::spawn(move || {
+                in_operation.start_operation();
+                in_operation.log(create_schema("unfinished"), |_| ());
+            })
+            .join()
+            .unwrap();
+            wal.log(create_schema("outside_of_operation"), |_| ());
+        }
+
+        assert_eq!(replayed(&path), vec![create_schema("outside_of_operation")]);
+    }
+
+    #[test]
+    fn incomplete_record_at_the_end_is_discarded() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let path = root_path.path().join("wal");
+        {
+            let (wal, _records) = Wal::open(&path).expect("log is opened");
+            wal.log(create_schema(SCHEMA), |_| ());
+        }
+        let complete_len = fs::metadata(&path).unwrap().len();
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(&frame(NO_OPERATION, Some(&create_schema("incomplete")))[..10])
+            .unwrap();
+
+        assert_eq!(replayed(&path), vec![create_schema(SCHEMA)]);
+        assert_eq!(fs::metadata(&path).unwrap().len(), complete_len);
+    }
+
+    #[test]
+    fn corrupted_record_is_discarded_with_everything_after_it() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let path = root_path.path().join("wal");
+        {
+            let (wal, _records) = Wal::open(&path).expect("log is opened");
+            wal.log(create_schema("schema_1"), |_| ());
+            wal.log(create_schema("schema_2"), |_| ());
+            wal.log(create_schema("schema_3"), |_| ());
+        }
+        let mut data = fs::read(&path).unwrap();
+        let second_frame = frame(NO_OPERATION, Some(&create_schema("schema_1"))).len();
+        data[second_frame + FRAME_HEADER_LEN + 10] ^= 0xff;
+        fs::write(&path, data).unwrap();
+
+        assert_eq!(replayed(&path), vec![create_schema("schema_1")]);
+    }
+
+    #[test]
+    fn checkpoint_truncates_log() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let path = root_path.path().join("wal");
+        let (wal, _records) = Wal::open(&path).expect("log is opened");
+        wal.log(create_schema(SCHEMA), |_| ());
+
+        let mut snapshot_made = false;
+        wal.checkpoint(0, || {
+            snapshot_made = true;
+            Ok(())
+        })
+        .expect("checkpoint is made");
+
+        assert!(snapshot_made);
+        assert_eq!(wal.size(), 0);
+        assert_eq!(replayed(&path), vec![]);
+    }
+
+    #[test]
+    fn checkpoint_is_skipped_for_small_log() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let (wal, _records) = Wal::open(&root_path.path().join("wal")).expect("log is opened");
+        wal.log(create_schema(SCHEMA), |_| ());
+        let size = wal.size();
+
+        wal.checkpoint(size + 1, || panic!("snapshot should not be made"))
+            .expect("checkpoint is skipped");
+
+        assert_eq!(wal.size(), size);
+    }
+
+    #[test]
+    fn checkpoint_is_skipped_while_operation_is_in_progress() {
+        let root_path = tempfile::tempdir().expect("temporary folder is created");
+        let (wal, _records) = Wal::open(&root_path.path().join("wal")).expect("log is opened");
+        wal.start_operation();
+        wal.log(create_schema(SCHEMA), |_| ());
+
+        wal.checkpoint(0, || panic!("snapshot should not be made"))
+            .expect("checkpoint is skipped");
+
+        wal.finish_operation();
+        let mut snapshot_made = false;
+        wal.checkpoint(0, || {
+            snapshot_made = true;
+            Ok(())
+        })
+        .expect("checkpoint is made");
+        assert!(snapshot_made);
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+/// reads binary strings in both PostgreSQL input formats: hex format starts with `\x`
+/// followed by pairs of hex digits, which could be separated by whitespaces, and in escape
+/// format bytes are written as characters, `\\` or three octal digits after a backslash
+pub fn parse_bytea(text: &str) -> Option<Vec<u8>> {
+    match text.strip_prefix("\\x") {
+        Some(hex) => parse_hex(hex),
+        None => parse_escape(text),
+    }
+}
+
+fn parse_hex(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() / 2);
+    let mut chars = text.chars().filter(|c| !c.is_ascii_whitespace());
+    while let Some(high) = chars.next() {
+        let low = chars.next()?;
+        bytes.push((high.to_digit(16)? * 16 + low.to_digit(16)?) as u8);
+    }
+    Some(bytes)
+}
+
+fn parse_escape(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte != b'\\' {
+            bytes.push(byte);
+            rest = tail;
+        } else if tail.first() == Some(&b'\\') {
+            bytes.push(b'\\');
+            rest = &tail[1..];
+        } else {
+            let octal = tail.get(0..3)?;
+            if !matches!(octal[0], b'0'..=b'3') || !octal[1..].iter().all(|digit| matches!(digit, b'0'..=b'7')) {
+                return None;
+            }
+            bytes.push(octal.iter().fold(0, |byte, digit| byte * 8 + (digit - b'0')));
+            rest = &tail[3..];
+        }
+    }
+    Some(bytes)
+}
+
+/// binary strings are output in hex format as PostgreSQL does by default
+pub fn bytea_text(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(2 + bytes.len() * 2);
+    text.push_str("\\x");
+    for byte in bytes {
+        write!(text, "{:02x}", byte).unwrap();
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_format() {
+        assert_eq!(parse_bytea("\\xDEADbeef"), Some(vec![0xde, 0xad, 0xbe, 0xef]));
+        assert_eq!(parse_bytea("\\x de ad"), Some(vec![0xde, 0xad]));
+        assert_eq!(parse_bytea("\\x"), Some(vec![]));
+        assert_eq!(parse_bytea("\\xabc"), None);
+        assert_eq!(parse_bytea("\\xzz"), None);
+    }
+
+    #[test]
+    fn escape_format() {
+        assert_eq!(parse_bytea("abc"), Some(b"abc".to_vec()));
+        assert_eq!(parse_bytea("a\\\\b"), Some(b"a\\b".to_vec()));
+        assert_eq!(parse_bytea("\\000\\377"), Some(vec![0, 255]));
+        assert_eq!(parse_bytea("\\400"), None);
+        assert_eq!(parse_bytea("\\12"), None);
+        assert_eq!(parse_bytea("abc\\"), None);
+    }
+
+    #[test]
+    fn output() {
+        assert_eq!(bytea_text(&[0xde, 0xad, 0x00]), "\\xdead00");
+        assert_eq!(bytea_text(&[]), "\\x");
+    }
+}
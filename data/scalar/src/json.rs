@@ -0,0 +1,545 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ScalarValue;
+use bigdecimal::BigDecimal;
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter, Write},
+    str::FromStr,
+};
+
+/// decomposed json document as PostgreSQL `jsonb` keeps it: numbers are exact,
+/// object keys are unique and stored shorter keys first
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Jsonb {
+    Null,
+    Bool(bool),
+    Number(BigDecimal),
+    String(String),
+    Array(Vec<Jsonb>),
+    Object(Vec<(String, Jsonb)>),
+}
+
+impl Jsonb {
+    /// builds an object out of pairs, the last value wins for duplicated keys
+    pub fn object(mut pairs: Vec<(String, Jsonb)>) -> Jsonb {
+        pairs.sort_by(|(left, _), (right, _)| key_order(left, right));
+        let mut object: Vec<(String, Jsonb)> = Vec::with_capacity(pairs.len());
+        for (key, value) in pairs {
+            match object.last_mut() {
+                Some(last) if last.0 == key => last.1 = value,
+                _ => object.push((key, value)),
+            }
+        }
+        Jsonb::Object(object)
+    }
+
+    pub fn field(&self, key: &str) -> Option<&Jsonb> {
+        match self {
+            Jsonb::Object(pairs) => pairs.iter().find(|(k, _)| k == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// negative indexes count elements from the end of an array
+    pub fn element(&self, index: i64) -> Option<&Jsonb> {
+        match self {
+            Jsonb::Array(elements) => {
+                let index = if index < 0 {
+                    elements.len() as i64 + index
+                } else {
+                    index
+                };
+                if index < 0 {
+                    None
+                } else {
+                    elements.get(index as usize)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// follows path steps as object keys or array indexes
+    pub fn path<S: AsRef<str>>(&self, steps: &[S]) -> Option<&Jsonb> {
+        steps.iter().try_fold(self, |value, step| match value {
+            Jsonb::Object(_) => value.field(step.as_ref()),
+            Jsonb::Array(_) => step.as_ref().trim().parse().ok().and_then(|index| value.element(index)),
+            _ => None,
+        })
+    }
+
+    /// PostgreSQL `@>` semantic: objects contain subsets of their pairs, arrays contain
+    /// subsets of their elements and a top level array could contain a scalar
+    pub fn contains(&self, other: &Jsonb) -> bool {
+        match (self, other) {
+            (Jsonb::Array(_), Jsonb::Array(_)) | (Jsonb::Array(_), Jsonb::Object(_)) => self.deep_contains(other),
+            (Jsonb::Array(elements), scalar) => elements.contains(scalar),
+            _ => self.deep_contains(other),
+        }
+    }
+
+    fn deep_contains(&self, other: &Jsonb) -> bool {
+        match (self, other) {
+            (Jsonb::Object(pairs), Jsonb::Object(other_pairs)) => other_pairs.iter().all(|(key, other_value)| {
+                pairs
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, value)| value.deep_contains(other_value))
+                    .unwrap_or(false)
+            }),
+            (Jsonb::Array(elements), Jsonb::Array(other_elements)) => other_elements
+                .iter()
+                .all(|other_element| elements.iter().any(|element| element.deep_contains(other_element))),
+            (Jsonb::Object(_), _) | (Jsonb::Array(_), _) => false,
+            (scalar, other_scalar) => scalar == other_scalar,
+        }
+    }
+
+    /// PostgreSQL `?` semantic: whether a string is a top level key or an array element
+    pub fn exists(&self, key: &str) -> bool {
+        match self {
+            Jsonb::Object(_) => self.field(key).is_some(),
+            Jsonb::Array(elements) => elements
+                .iter()
+                .any(|element| matches!(element, Jsonb::String(s) if s == key)),
+            Jsonb::String(s) => s == key,
+            _ => false,
+        }
+    }
+
+    /// text representation as `->>` returns it: strings without quotes, `null` as NULL
+    pub fn as_text(&self) -> Option<String> {
+        match self {
+            Jsonb::Null => None,
+            Jsonb::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        }
+    }
+
+    fn type_rank(&self) -> u8 {
+        match self {
+            Jsonb::Null => 0,
+            Jsonb::String(_) => 1,
+            Jsonb::Number(_) => 2,
+            Jsonb::Bool(_) => 3,
+            Jsonb::Array(_) => 4,
+            Jsonb::Object(_) => 5,
+        }
+    }
+}
+
+fn key_order(left: &str, right: &str) -> Ordering {
+    left.len().cmp(&right.len()).then_with(|| left.cmp(right))
+}
+
+/// orders documents as PostgreSQL does: objects are greater than arrays, then
+/// booleans, numbers, strings and null; larger containers are greater than smaller
+impl Ord for Jsonb {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Jsonb::Bool(left), Jsonb::Bool(right)) => left.cmp(right),
+            (Jsonb::Number(left), Jsonb::Number(right)) => left.cmp(right),
+            (Jsonb::String(left), Jsonb::String(right)) => left.cmp(right),
+            (Jsonb::Array(left), Jsonb::Array(right)) => left.len().cmp(&right.len()).then_with(|| left.cmp(right)),
+            (Jsonb::Object(left), Jsonb::Object(right)) => left.len().cmp(&right.len()).then_with(|| left.cmp(right)),
+            _ => self.type_rank().cmp(&other.type_rank()),
+        }
+    }
+}
+
+impl PartialOrd for Jsonb {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Jsonb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Jsonb::Null => write!(f, "null"),
+            Jsonb::Bool(value) => write!(f, "{}", value),
+            Jsonb::Number(value) => write!(f, "{}", value),
+            Jsonb::String(value) => write_string(f, value),
+            Jsonb::Array(elements) => {
+                f.write_char('[')?;
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                f.write_char(']')
+            }
+            Jsonb::Object(pairs) => {
+                f.write_char('{')?;
+                for (index, (key, value)) in pairs.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ": {}", value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            '\u{8}' => f.write_str("\\b")?,
+            '\u{c}' => f.write_str("\\f")?,
+            c if c < ' ' => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// converts SQL values into json as `jsonb_build_object` and `jsonb_agg` do:
+/// numbers stay numbers, booleans become booleans and everything else is a string
+impl From<ScalarValue> for Jsonb {
+    fn from(value: ScalarValue) -> Jsonb {
+        match value {
+            ScalarValue::Null => Jsonb::Null,
+            ScalarValue::True => Jsonb::Bool(true),
+            ScalarValue::False => Jsonb::Bool(false),
+            ScalarValue::Jsonb(value) => value,
+            ScalarValue::Json(text) => parse_json(&text).unwrap_or(Jsonb::String(text)),
+            ScalarValue::String(text) => Jsonb::String(text),
+            number @ ScalarValue::Int16(_)
+            | number @ ScalarValue::Int32(_)
+            | number @ ScalarValue::Int64(_)
+            | number @ ScalarValue::Float32(_)
+            | number @ ScalarValue::Float64(_)
+            | number @ ScalarValue::Numeric(_) => {
+                let text = number.as_text();
+                match BigDecimal::from_str(&text) {
+                    Ok(number) => Jsonb::Number(number),
+                    Err(_) => Jsonb::String(text),
+                }
+            }
+            ScalarValue::Timestamp(_) => Jsonb::String(value.as_text().replacen(' ', "T", 1)),
+            ScalarValue::TimestampTz(_) => Jsonb::String(format!("{}:00", value.as_text().replacen(' ', "T", 1))),
+            other => Jsonb::String(other.as_text()),
+        }
+    }
+}
+
+/// parses json text strictly following RFC 8259
+pub fn parse_json(text: &str) -> Option<Jsonb> {
+    let mut parser = Parser {
+        text: text.as_bytes(),
+        pos: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespaces();
+    if parser.pos == parser.text.len() {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+struct Parser<'t> {
+    text: &'t [u8],
+    pos: usize,
+}
+
+impl<'t> Parser<'t> {
+    fn skip_whitespaces(&mut self) {
+        while matches!(self.peek(), Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    fn expect(&mut self, expected: &[u8]) -> Option<()> {
+        if self.text[self.pos..].starts_with(expected) {
+            self.pos += expected.len();
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn value(&mut self) -> Option<Jsonb> {
+        self.skip_whitespaces();
+        match self.peek()? {
+            b'n' => self.expect(b"null").map(|()| Jsonb::Null),
+            b't' => self.expect(b"true").map(|()| Jsonb::Bool(true)),
+            b'f' => self.expect(b"false").map(|()| Jsonb::Bool(false)),
+            b'"' => self.string().map(Jsonb::String),
+            b'[' => self.array(),
+            b'{' => self.object(),
+            _ => self.number(),
+        }
+    }
+
+    fn array(&mut self) -> Option<Jsonb> {
+        self.pos += 1;
+        let mut elements = vec![];
+        self.skip_whitespaces();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Some(Jsonb::Array(elements));
+        }
+        loop {
+            elements.push(self.value()?);
+            self.skip_whitespaces();
+            match self.next()? {
+                b',' => continue,
+                b']' => return Some(Jsonb::Array(elements)),
+                _ => return None,
+            }
+        }
+    }
+
+    fn object(&mut self) -> Option<Jsonb> {
+        self.pos += 1;
+        let mut pairs = vec![];
+        self.skip_whitespaces();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Some(Jsonb::Object(pairs));
+        }
+        loop {
+            self.skip_whitespaces();
+            if self.peek() != Some(b'"') {
+                return None;
+            }
+            let key = self.string()?;
+            self.skip_whitespaces();
+            self.expect(b":")?;
+            pairs.push((key, self.value()?));
+            self.skip_whitespaces();
+            match self.next()? {
+                b',' => continue,
+                b'}' => return Some(Jsonb::object(pairs)),
+                _ => return None,
+            }
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.pos += 1;
+        let mut bytes = vec![];
+        loop {
+            match self.next()? {
+                b'"' => return String::from_utf8(bytes).ok(),
+                b'\\' => {
+                    let c = match self.next()? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return None,
+                    };
+                    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                byte if byte < b' ' => return None,
+                byte => bytes.push(byte),
+            }
+        }
+    }
+
+    fn unicode_escape(&mut self) -> Option<char> {
+        let high = self.hex_code()?;
+        if (0xd800..0xdc00).contains(&high) {
+            self.expect(b"\\u")?;
+            let low = self.hex_code()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return None;
+            }
+            std::char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+        } else {
+            std::char::from_u32(high)
+        }
+    }
+
+    fn hex_code(&mut self) -> Option<u32> {
+        let digits = std::str::from_utf8(self.text.get(self.pos..self.pos + 4)?).ok()?;
+        self.pos += 4;
+        if digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+            u32::from_str_radix(digits, 16).ok()
+        } else {
+            None
+        }
+    }
+
+    fn number(&mut self) -> Option<Jsonb> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.next()? {
+            b'0' => {}
+            b'1'..=b'9' => self.digits(),
+            _ => return None,
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            self.required_digits()?;
+        }
+        if matches!(self.peek(), Some(b'e') | Some(b'E')) {
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+') | Some(b'-')) {
+                self.pos += 1;
+            }
+            self.required_digits()?;
+        }
+        let number = std::str::from_utf8(&self.text[start..self.pos]).ok()?;
+        BigDecimal::from_str(number).ok().map(Jsonb::Number)
+    }
+
+    fn digits(&mut self) {
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+    }
+
+    fn required_digits(&mut self) -> Option<()> {
+        if matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.digits();
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jsonb(text: &str) -> Jsonb {
+        parse_json(text).unwrap()
+    }
+
+    #[test]
+    fn parse_and_output() {
+        assert_eq!(
+            jsonb(r#" {"b": [1, 2.50, -3e2], "aa": {"c": null}, "a": true} "#).to_string(),
+            r#"{"a": true, "b": [1, 2.50, -300], "aa": {"c": null}}"#
+        );
+        assert_eq!(jsonb(r#""t\"ab\u00e9\ud83d\ude00""#).to_string(), "\"t\\\"abé😀\"");
+        assert_eq!(jsonb(r#"{"a": 1, "a": 2}"#).to_string(), r#"{"a": 2}"#);
+        assert_eq!(jsonb("[]").to_string(), "[]");
+    }
+
+    #[test]
+    fn invalid_documents() {
+        for text in &[
+            "",
+            "{",
+            "[1,]",
+            "{\"a\" 1}",
+            "01",
+            "1.",
+            "-",
+            "nul",
+            "'a'",
+            "{a: 1}",
+            "1 2",
+            "\"\\x\"",
+        ] {
+            assert_eq!(parse_json(text), None, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn access_fields_elements_and_paths() {
+        let doc = jsonb(r#"{"a": {"b": [10, 20, {"c": "d"}]}}"#);
+        assert_eq!(
+            doc.field("a").and_then(|a| a.field("b")),
+            Some(&jsonb("[10, 20, {\"c\": \"d\"}]"))
+        );
+        assert_eq!(doc.field("z"), None);
+        assert_eq!(jsonb("[1, 2, 3]").element(-1), Some(&jsonb("3")));
+        assert_eq!(jsonb("[1, 2, 3]").element(3), None);
+        assert_eq!(jsonb("[1, 2, 3]").element(-4), None);
+        assert_eq!(doc.path(&["a", "b", "2", "c"]), Some(&Jsonb::String("d".to_owned())));
+        assert_eq!(doc.path(&["a", "b", "x"]), None);
+        assert_eq!(doc.path::<&str>(&[]), Some(&doc));
+    }
+
+    #[test]
+    fn containment_and_existence() {
+        let doc = jsonb(r#"{"a": 1, "b": [1, 2, {"c": 3}], "d": "e"}"#);
+        assert!(doc.contains(&jsonb(r#"{"a": 1}"#)));
+        assert!(doc.contains(&jsonb(r#"{"b": [2, {"c": 3}]}"#)));
+        assert!(!doc.contains(&jsonb(r#"{"a": 2}"#)));
+        assert!(!doc.contains(&jsonb(r#"{"b": 1}"#)));
+        assert!(jsonb("[1, 2, [3]]").contains(&jsonb("[[3], 1]")));
+        assert!(jsonb("[1, 2]").contains(&jsonb("1")));
+        assert!(!jsonb("[[1, 2]]").contains(&jsonb("1")));
+        assert!(jsonb("\"a\"").contains(&jsonb("\"a\"")));
+
+        assert!(doc.exists("d"));
+        assert!(!doc.exists("e"));
+        assert!(jsonb(r#"["a", 1]"#).exists("a"));
+        assert!(!jsonb(r#"["a", 1]"#).exists("1"));
+    }
+
+    #[test]
+    fn ordering() {
+        let mut values = [
+            jsonb("{}"),
+            jsonb("[1, 2]"),
+            jsonb("[3]"),
+            jsonb("true"),
+            jsonb("10"),
+            jsonb("9.5"),
+            jsonb("\"a\""),
+            jsonb("null"),
+        ];
+        values.sort();
+        assert_eq!(
+            values.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["null", "\"a\"", "9.5", "10", "true", "[3]", "[1, 2]", "{}"]
+        );
+    }
+
+    #[test]
+    fn scalars_to_json() {
+        assert_eq!(Jsonb::from(ScalarValue::Int32(7)), jsonb("7"));
+        assert_eq!(Jsonb::from(ScalarValue::False), jsonb("false"));
+        assert_eq!(Jsonb::from(ScalarValue::Null), jsonb("null"));
+        assert_eq!(Jsonb::from(ScalarValue::String("x".to_owned())), jsonb("\"x\""));
+        assert_eq!(Jsonb::from(ScalarValue::Json("[1,2]".to_owned())), jsonb("[1, 2]"));
+    }
+}
@@ -12,9 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use json::Jsonb;
 use ordered_float::OrderedFloat;
+use std::fmt::{Display, LowerExp};
+use temporal::Interval;
+use uuid::Uuid;
 
-#[derive(Debug, PartialEq)]
+pub mod bytea;
+pub mod json;
+pub mod temporal;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ScalarValue {
     Null,
     True,
@@ -24,7 +34,18 @@ pub enum ScalarValue {
     Int64(i64),
     Float32(OrderedFloat<f32>),
     Float64(OrderedFloat<f64>),
+    Numeric(BigDecimal),
     String(String),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Timestamp(NaiveDateTime),
+    TimestampTz(DateTime<Utc>),
+    Interval(Interval),
+    Bytea(Vec<u8>),
+    Uuid(Uuid),
+    /// json text kept as it was written
+    Json(String),
+    Jsonb(Jsonb),
 }
 
 impl ScalarValue {
@@ -37,9 +58,86 @@ impl ScalarValue {
             Self::Int16(val) => val.to_string(),
             Self::Int32(val) => val.to_string(),
             Self::Int64(val) => val.to_string(),
-            Self::Float32(val) => val.to_string(),
-            Self::Float64(val) => val.to_string(),
+            Self::Float32(val) => float_text(val.into_inner(), 6),
+            Self::Float64(val) => float_text(val.into_inner(), 15),
+            Self::Numeric(val) => val.to_string(),
             Self::String(val) => val,
+            Self::Date(val) => temporal::date_text(&val),
+            Self::Time(val) => temporal::time_text(&val),
+            Self::Timestamp(val) => temporal::timestamp_text(&val),
+            Self::TimestampTz(val) => format!("{}+00", temporal::timestamp_text(&val.naive_utc())),
+            Self::Interval(val) => val.to_string(),
+            Self::Bytea(val) => bytea::bytea_text(&val),
+            Self::Uuid(val) => val.to_hyphenated().to_string(),
+            Self::Json(val) => val,
+            Self::Jsonb(val) => val.to_string(),
+        }
+    }
+}
+
+/// formats floats as PostgreSQL does: the shortest representation that is read
+/// back to the same value, in exponential notation when the decimal exponent is
+/// less than -4 or not less than `precision` significant digits of the type
+fn float_text<F: Display + LowerExp + Into<f64> + Copy>(value: F, precision: i32) -> String {
+    let float: f64 = value.into();
+    if float.is_nan() {
+        "NaN".to_owned()
+    } else if float == f64::INFINITY {
+        "Infinity".to_owned()
+    } else if float == f64::NEG_INFINITY {
+        "-Infinity".to_owned()
+    } else {
+        let exponential = format!("{:e}", value);
+        let (mantissa, exponent) = exponential.split_at(exponential.find('e').unwrap());
+        let exponent = exponent[1..].parse::<i32>().unwrap();
+        if exponent < -4 || exponent >= precision {
+            format!(
+                "{}e{}{:02}",
+                mantissa,
+                if exponent < 0 { '-' } else { '+' },
+                exponent.abs()
+            )
+        } else {
+            value.to_string()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn real(value: f32) -> String {
+        ScalarValue::Float32(OrderedFloat(value)).as_text()
+    }
+
+    fn double(value: f64) -> String {
+        ScalarValue::Float64(OrderedFloat(value)).as_text()
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(real(f32::NAN), "NaN");
+        assert_eq!(double(f64::INFINITY), "Infinity");
+        assert_eq!(double(f64::NEG_INFINITY), "-Infinity");
+        assert_eq!(double(-0.0), "-0");
+    }
+
+    #[test]
+    fn decimal_notation() {
+        assert_eq!(real(1.0), "1");
+        assert_eq!(real(0.1), "0.1");
+        assert_eq!(real(123_456.0), "123456");
+        assert_eq!(double(0.000_1), "0.0001");
+        assert_eq!(double(123_456_789_012_345.0), "123456789012345");
+    }
+
+    #[test]
+    fn exponential_notation() {
+        assert_eq!(real(1_234_567.0), "1.234567e+06");
+        assert_eq!(real(f32::MAX), "3.4028235e+38");
+        assert_eq!(double(0.000_01), "1e-05");
+        assert_eq!(double(1e15), "1e+15");
+        assert_eq!(double(1.5e-300), "1.5e-300");
+    }
+}
@@ -0,0 +1,416 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+pub const MICROS_PER_SECOND: i64 = 1_000_000;
+pub const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SECOND;
+
+/// time span kept as in PostgreSQL: months and days are not converted into each other
+/// or into time as their length varies, though spans are compared assuming that
+/// a month has 30 days and a day has 24 hours
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Interval {
+    pub months: i32,
+    pub days: i32,
+    pub micros: i64,
+}
+
+impl Interval {
+    pub fn new(months: i32, days: i32, micros: i64) -> Interval {
+        Interval { months, days, micros }
+    }
+
+    pub fn checked_add(&self, other: &Interval) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            micros: self.micros.checked_add(other.micros)?,
+        })
+    }
+
+    pub fn checked_neg(&self) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            micros: self.micros.checked_neg()?,
+        })
+    }
+
+    /// fractions of months are carried over into days and fractions of days into time
+    pub fn checked_mul(&self, factor: f64) -> Option<Interval> {
+        from_fractions(
+            self.months as f64 * factor,
+            self.days as f64 * factor,
+            self.micros as f64 * factor,
+        )
+    }
+
+    fn span(&self) -> i128 {
+        (self.months as i128 * 30 + self.days as i128) * MICROS_PER_DAY as i128 + self.micros as i128
+    }
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Interval) -> bool {
+        self.span() == other.span()
+    }
+}
+
+impl Eq for Interval {}
+
+impl Hash for Interval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.span().hash(state)
+    }
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Interval) -> Ordering {
+        self.span().cmp(&other.span())
+    }
+}
+
+/// formats intervals as PostgreSQL does with `postgres` interval style,
+/// e.g. `1 year 2 mons -3 days +04:05:06.5`
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut parts = vec![];
+        let mut negative_before = false;
+        for (value, unit) in [
+            (self.months / 12, "year"),
+            (self.months % 12, "mon"),
+            (self.days, "day"),
+        ]
+        .iter()
+        {
+            if *value != 0 {
+                parts.push(format!(
+                    "{}{} {}{}",
+                    if negative_before && *value > 0 { "+" } else { "" },
+                    value,
+                    unit,
+                    if *value != 1 { "s" } else { "" }
+                ));
+                negative_before = *value < 0;
+            }
+        }
+        if parts.is_empty() || self.micros != 0 {
+            let sign = if self.micros < 0 {
+                "-"
+            } else if negative_before {
+                "+"
+            } else {
+                ""
+            };
+            let micros = self.micros.unsigned_abs();
+            let seconds = micros / MICROS_PER_SECOND as u64;
+            parts.push(format!(
+                "{}{:02}:{:02}:{:02}{}",
+                sign,
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60,
+                fraction(micros % MICROS_PER_SECOND as u64)
+            ));
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+fn from_fractions(months: f64, days: f64, micros: f64) -> Option<Interval> {
+    let days = days + months.fract() * 30.0;
+    let micros = (micros + days.fract() * MICROS_PER_DAY as f64).round();
+    let (months, days) = (months.trunc(), days.trunc());
+    if months.abs() > i32::MAX as f64 || days.abs() > i32::MAX as f64 || micros.abs() >= i64::MAX as f64 {
+        None
+    } else {
+        Some(Interval::new(months as i32, days as i32, micros as i64))
+    }
+}
+
+/// parses intervals as `1 year 2 months 3 days 04:05:06`, `-2 hours ago` or `1.5 weeks`,
+/// a number without unit is a number of seconds
+pub fn parse_interval(text: &str) -> Option<Interval> {
+    let mut interval = Interval::default();
+    let mut tokens = text.split_whitespace().map(str::to_lowercase).peekable();
+    let mut parsed = false;
+    let mut ago = false;
+    while let Some(token) = tokens.next() {
+        if ago {
+            return None;
+        } else if token == "ago" {
+            ago = true;
+            continue;
+        } else if token.contains(':') {
+            interval.micros = interval.micros.checked_add(parse_time_span(&token)?)?;
+        } else {
+            let split = token.find(char::is_alphabetic).unwrap_or(token.len());
+            let (number, unit) = token.split_at(split);
+            let number = number.parse::<f64>().ok()?;
+            let unit = match tokens.peek() {
+                Some(next) if unit.is_empty() && next != "ago" && next.chars().all(char::is_alphabetic) => {
+                    tokens.next().unwrap()
+                }
+                _ if unit.is_empty() => "second".to_owned(),
+                _ => unit.to_owned(),
+            };
+            interval = interval.checked_add(&unit_span(number, &unit)?)?;
+        }
+        parsed = true;
+    }
+    match (parsed, ago) {
+        (false, _) => None,
+        (true, true) => interval.checked_neg(),
+        (true, false) => Some(interval),
+    }
+}
+
+fn unit_span(number: f64, unit: &str) -> Option<Interval> {
+    let micros = |micros_per_unit: i64| from_fractions(0.0, 0.0, number * micros_per_unit as f64);
+    match unit {
+        "microsecond" | "microseconds" | "us" | "usec" | "usecs" => micros(1),
+        "millisecond" | "milliseconds" | "ms" | "msec" | "msecs" => micros(1_000),
+        "second" | "seconds" | "sec" | "secs" | "s" => micros(MICROS_PER_SECOND),
+        "minute" | "minutes" | "min" | "mins" | "m" => micros(60 * MICROS_PER_SECOND),
+        "hour" | "hours" | "hr" | "hrs" | "h" => micros(3_600 * MICROS_PER_SECOND),
+        "day" | "days" | "d" => from_fractions(0.0, number, 0.0),
+        "week" | "weeks" | "w" => from_fractions(0.0, number * 7.0, 0.0),
+        "month" | "months" | "mon" | "mons" => from_fractions(number, 0.0, 0.0),
+        "year" | "years" | "yr" | "yrs" | "y" => from_fractions(number * 12.0, 0.0, 0.0),
+        "decade" | "decades" => from_fractions(number * 120.0, 0.0, 0.0),
+        "century" | "centuries" => from_fractions(number * 1_200.0, 0.0, 0.0),
+        "millennium" | "millennia" | "millenniums" => from_fractions(number * 12_000.0, 0.0, 0.0),
+        _ => None,
+    }
+}
+
+/// parses `[-]hh:mm[:ss[.ffffff]]` into microseconds
+fn parse_time_span(token: &str) -> Option<i64> {
+    let (sign, token) = match token.strip_prefix('-') {
+        Some(token) => (-1, token),
+        None => (1, token.strip_prefix('+').unwrap_or(token)),
+    };
+    let mut parts = token.split(':');
+    let hours = parts.next()?.parse::<i64>().ok()?;
+    let minutes = parts.next()?.parse::<i64>().ok()?;
+    let seconds = match parts.next() {
+        Some(seconds) => seconds.parse::<f64>().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some() || !(0..60).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    let micros = hours
+        .checked_mul(3_600 * MICROS_PER_SECOND)?
+        .checked_add(minutes * 60 * MICROS_PER_SECOND + (seconds * MICROS_PER_SECOND as f64).round() as i64)?;
+    Some(sign * micros)
+}
+
+/// parses `yyyy-mm-dd`, time of timestamps is ignored
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d")
+        .ok()
+        .or_else(|| parse_timestamp(text).map(|timestamp| timestamp.date()))
+}
+
+/// parses `hh:mm[:ss[.ffffff]]`, fractions of seconds are truncated to microseconds
+pub fn parse_time(text: &str) -> Option<NaiveTime> {
+    ["%H:%M:%S%.f", "%H:%M"]
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(text.trim(), format).ok())
+        .map(truncate_to_micros)
+}
+
+/// parses `yyyy-mm-dd[ hh:mm[:ss[.ffffff]]]`, time zone offset is ignored
+pub fn parse_timestamp(text: &str) -> Option<NaiveDateTime> {
+    let (timestamp, _offset) = split_offset(text)?;
+    parse_naive_timestamp(timestamp)
+}
+
+/// parses `yyyy-mm-dd[ hh:mm[:ss[.ffffff]]][(+|-)hh[:mm]]`, timestamps without
+/// time zone offset are in UTC
+pub fn parse_timestamp_tz(text: &str) -> Option<DateTime<Utc>> {
+    let (timestamp, offset) = split_offset(text)?;
+    let timestamp = parse_naive_timestamp(timestamp)?.checked_sub_signed(Duration::seconds(offset))?;
+    Some(DateTime::from_utc(timestamp, Utc))
+}
+
+fn parse_naive_timestamp(text: &str) -> Option<NaiveDateTime> {
+    [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+    .or_else(|| {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .ok()
+            .map(|date| date.and_hms(0, 0, 0))
+    })
+    .map(|timestamp| timestamp.date().and_time(truncate_to_micros(timestamp.time())))
+}
+
+/// splits time zone offset in seconds from a timestamp
+fn split_offset(text: &str) -> Option<(&str, i64)> {
+    let text = text.trim();
+    if let Some(timestamp) = text.strip_suffix('Z').or_else(|| text.strip_suffix("UTC")) {
+        return Some((timestamp.trim_end(), 0));
+    }
+    match text.rfind(&['+', '-'][..]) {
+        Some(index) if index > "yyyy-mm-dd".len() => {
+            let (timestamp, offset) = text.split_at(index);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let offset = offset[1..].replace(':', "");
+            let (hours, minutes) = match offset.len() {
+                1 | 2 => (offset.parse::<i64>().ok()?, 0),
+                4 => (offset[..2].parse::<i64>().ok()?, offset[2..].parse::<i64>().ok()?),
+                _ => return None,
+            };
+            Some((timestamp.trim_end(), sign * (hours * 3_600 + minutes * 60)))
+        }
+        _ => Some((text, 0)),
+    }
+}
+
+fn truncate_to_micros(time: NaiveTime) -> NaiveTime {
+    time.with_nanosecond(time.nanosecond() / 1_000 * 1_000).unwrap()
+}
+
+pub(crate) fn date_text(date: &NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+pub(crate) fn time_text(time: &NaiveTime) -> String {
+    format!(
+        "{}{}",
+        time.format("%H:%M:%S"),
+        fraction(time.nanosecond() as u64 / 1_000)
+    )
+}
+
+pub(crate) fn timestamp_text(timestamp: &NaiveDateTime) -> String {
+    format!("{} {}", date_text(&timestamp.date()), time_text(&timestamp.time()))
+}
+
+/// microseconds of a second without trailing zeros
+fn fraction(micros: u64) -> String {
+    if micros == 0 {
+        String::new()
+    } else {
+        format!(".{:06}", micros).trim_end_matches('0').to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(text: &str) -> String {
+        parse_interval(text).unwrap().to_string()
+    }
+
+    #[test]
+    fn interval_units() {
+        assert_eq!(interval("1 day"), "1 day");
+        assert_eq!(interval("2 years 3 months 1 week"), "2 years 3 mons 7 days");
+        assert_eq!(interval("90 minutes"), "01:30:00");
+        assert_eq!(interval("1.5 days"), "1 day 12:00:00");
+        assert_eq!(interval("1.5 months"), "1 mon 15 days");
+        assert_eq!(interval("30"), "00:00:30");
+        assert_eq!(interval("0 days"), "00:00:00");
+    }
+
+    #[test]
+    fn interval_times() {
+        assert_eq!(interval("1 day 04:05:06.5"), "1 day 04:05:06.5");
+        assert_eq!(interval("-01:30"), "-01:30:00");
+        assert_eq!(interval("36:00:00"), "36:00:00");
+    }
+
+    #[test]
+    fn negative_intervals() {
+        assert_eq!(interval("1 day ago"), "-1 days");
+        assert_eq!(interval("-1 day 2 hours"), "-1 days +02:00:00");
+        assert_eq!(interval("1 year -2 months"), "10 mons");
+        assert_eq!(interval("-1 year -2 months 3 days"), "-1 years -2 mons +3 days");
+    }
+
+    #[test]
+    fn invalid_intervals() {
+        assert_eq!(parse_interval(""), None);
+        assert_eq!(parse_interval("1 fortnight"), None);
+        assert_eq!(parse_interval("1 day ago ago"), None);
+        assert_eq!(parse_interval("10:60"), None);
+    }
+
+    #[test]
+    fn intervals_are_compared_by_their_spans() {
+        assert_eq!(Interval::new(1, 0, 0), Interval::new(0, 30, 0));
+        assert!(Interval::new(0, 1, 0) > Interval::new(0, 0, 23 * 3_600 * MICROS_PER_SECOND));
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(
+            parse_timestamp("2021-02-03 04:05:06.789").map(|timestamp| timestamp_text(&timestamp)),
+            Some("2021-02-03 04:05:06.789".to_owned())
+        );
+        assert_eq!(
+            parse_timestamp("2021-02-03").map(|timestamp| timestamp_text(&timestamp)),
+            Some("2021-02-03 00:00:00".to_owned())
+        );
+        assert_eq!(parse_timestamp("2021-02-30 00:00:00"), None);
+    }
+
+    #[test]
+    fn timestamps_with_time_zone() {
+        assert_eq!(
+            parse_timestamp_tz("2021-02-03 04:05:06+03").map(|timestamp| timestamp_text(&timestamp.naive_utc())),
+            Some("2021-02-03 01:05:06".to_owned())
+        );
+        assert_eq!(
+            parse_timestamp_tz("2021-02-03T04:05:06-01:30").map(|timestamp| timestamp_text(&timestamp.naive_utc())),
+            Some("2021-02-03 05:35:06".to_owned())
+        );
+        assert_eq!(
+            parse_timestamp_tz("2021-02-03 04:05").map(|timestamp| timestamp_text(&timestamp.naive_utc())),
+            Some("2021-02-03 04:05:00".to_owned())
+        );
+    }
+
+    #[test]
+    fn dates_and_times() {
+        assert_eq!(parse_date("2021-02-03"), Some(NaiveDate::from_ymd(2021, 2, 3)));
+        assert_eq!(parse_date("03/02/2021"), None);
+        assert_eq!(
+            parse_time("04:05").map(|time| time_text(&time)),
+            Some("04:05:00".to_owned())
+        );
+        assert_eq!(parse_time("25:00"), None);
+    }
+}
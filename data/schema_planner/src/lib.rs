@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use data_definition_execution_plan::{
-    ColumnInfo, CreateSchemaQuery, CreateTableQuery, DropSchemasQuery, DropTablesQuery, SchemaChange,
+    AddColumnQuery, AlterColumnTypeQuery, ColumnInfo, ConstraintInfo, CreateIndexQuery, CreateSchemaQuery,
+    CreateTableQuery, DropColumnQuery, DropIndexesQuery, DropSchemasQuery, DropTablesQuery, ForeignKeyInfo,
+    RenameColumnQuery, RenameTableQuery, SchemaChange,
 };
 use data_definition_operations::{Kind, ObjectState, Record, Step, SystemObject, SystemOperation};
+use definition::{FullTableName, IndexKind};
 
 pub struct SystemSchemaPlanner;
 
@@ -96,6 +99,8 @@ impl SystemSchemaPlanner {
             SchemaChange::CreateTable(CreateTableQuery {
                 full_table_name,
                 column_defs,
+                constraints,
+                foreign_keys,
                 if_not_exists,
             }) => {
                 let mut steps = vec![];
@@ -107,6 +112,12 @@ impl SystemSchemaPlanner {
                     system_object: SystemObject::Table,
                     object_name: vec![full_table_name.schema().to_owned(), full_table_name.table().to_owned()],
                 });
+                for ConstraintInfo { name, .. } in constraints {
+                    steps.push(Step::CheckExistence {
+                        system_object: SystemObject::Index,
+                        object_name: vec![full_table_name.schema().to_owned(), name.clone()],
+                    });
+                }
                 steps.push(Step::CreateFile {
                     folder_name: full_table_name.schema().to_owned(),
                     name: full_table_name.table().to_owned(),
@@ -117,16 +128,68 @@ impl SystemSchemaPlanner {
                         table_name: full_table_name.table().to_owned(),
                     },
                 });
-                for ColumnInfo { name, sql_type } in column_defs {
+                for ColumnInfo {
+                    name,
+                    sql_type,
+                    constraints,
+                } in column_defs
+                {
                     steps.push(Step::CreateRecord {
                         record: Record::Column {
                             schema_name: full_table_name.schema().to_owned(),
                             table_name: full_table_name.table().to_owned(),
                             column_name: name.clone(),
                             sql_type: *sql_type,
+                            constraints: constraints.clone(),
                         },
                     })
                 }
+                for ConstraintInfo {
+                    name,
+                    kind,
+                    column_names,
+                } in constraints
+                {
+                    steps.push(Step::CreateIndex {
+                        schema_name: full_table_name.schema().to_owned(),
+                        table_name: full_table_name.table().to_owned(),
+                        index_name: name.clone(),
+                        column_names: column_names.clone(),
+                        kind: *kind,
+                    });
+                    steps.push(Step::CreateRecord {
+                        record: Record::Index {
+                            schema_name: full_table_name.schema().to_owned(),
+                            table_name: full_table_name.table().to_owned(),
+                            index_name: name.clone(),
+                            column_names: column_names.clone(),
+                            kind: *kind,
+                        },
+                    });
+                }
+                for ForeignKeyInfo {
+                    name,
+                    column_names,
+                    referenced_table,
+                    referenced_column_names,
+                    on_delete,
+                    on_update,
+                } in foreign_keys
+                {
+                    steps.push(Step::CreateRecord {
+                        record: Record::ForeignKey {
+                            schema_name: full_table_name.schema().to_owned(),
+                            table_name: full_table_name.table().to_owned(),
+                            constraint_name: name.clone(),
+                            column_names: column_names.clone(),
+                            referenced_schema_name: referenced_table.schema().to_owned(),
+                            referenced_table_name: referenced_table.table().to_owned(),
+                            referenced_column_names: referenced_column_names.clone(),
+                            on_delete: *on_delete,
+                            on_update: *on_update,
+                        },
+                    });
+                }
                 SystemOperation {
                     kind: Kind::Create(SystemObject::Table),
                     skip_steps_if: if *if_not_exists {
@@ -139,8 +202,8 @@ impl SystemSchemaPlanner {
             }
             SchemaChange::DropTables(DropTablesQuery {
                 full_table_names,
+                cascade,
                 if_exists,
-                ..
             }) => {
                 let mut steps = vec![];
                 for full_table_name in full_table_names {
@@ -153,6 +216,26 @@ impl SystemSchemaPlanner {
                         system_object: SystemObject::Table,
                         object_name: vec![full_table_name.schema().to_owned(), full_table_name.table().to_owned()],
                     });
+                    // foreign keys of other tables that reference the table are dropped only with `CASCADE`
+                    if *cascade {
+                        for_table.push(Step::RemoveDependants {
+                            system_object: SystemObject::Table,
+                            object_name: vec![full_table_name.schema().to_owned(), full_table_name.table().to_owned()],
+                        });
+                    } else {
+                        for_table.push(Step::CheckDependants {
+                            system_object: SystemObject::Table,
+                            object_name: vec![full_table_name.schema().to_owned(), full_table_name.table().to_owned()],
+                        });
+                    }
+                    for_table.push(Step::RemoveForeignKeys {
+                        schema_name: full_table_name.schema().to_owned(),
+                        table_name: full_table_name.table().to_owned(),
+                    });
+                    for_table.push(Step::RemoveIndexes {
+                        schema_name: full_table_name.schema().to_owned(),
+                        table_name: full_table_name.table().to_owned(),
+                    });
                     for_table.push(Step::RemoveColumns {
                         schema_name: full_table_name.schema().to_owned(),
                         table_name: full_table_name.table().to_owned(),
@@ -175,10 +258,231 @@ impl SystemSchemaPlanner {
                     steps,
                 }
             }
+            SchemaChange::CreateIndex(CreateIndexQuery {
+                index_name,
+                full_table_name,
+                column_names,
+                if_not_exists,
+            }) => {
+                let steps = vec![
+                    Step::CheckExistence {
+                        system_object: SystemObject::Schema,
+                        object_name: vec![full_table_name.schema().to_owned()],
+                    },
+                    Step::CheckExistence {
+                        system_object: SystemObject::Table,
+                        object_name: vec![full_table_name.schema().to_owned(), full_table_name.table().to_owned()],
+                    },
+                    Step::CheckExistence {
+                        system_object: SystemObject::Index,
+                        object_name: vec![full_table_name.schema().to_owned(), index_name.clone()],
+                    },
+                    Step::CreateIndex {
+                        schema_name: full_table_name.schema().to_owned(),
+                        table_name: full_table_name.table().to_owned(),
+                        index_name: index_name.clone(),
+                        column_names: column_names.clone(),
+                        kind: IndexKind::Plain,
+                    },
+                    Step::CreateRecord {
+                        record: Record::Index {
+                            schema_name: full_table_name.schema().to_owned(),
+                            table_name: full_table_name.table().to_owned(),
+                            index_name: index_name.clone(),
+                            column_names: column_names.clone(),
+                            kind: IndexKind::Plain,
+                        },
+                    },
+                ];
+                SystemOperation {
+                    kind: Kind::Create(SystemObject::Index),
+                    skip_steps_if: if *if_not_exists {
+                        Some(ObjectState::Exists)
+                    } else {
+                        None
+                    },
+                    steps: vec![steps],
+                }
+            }
+            SchemaChange::DropIndexes(DropIndexesQuery {
+                full_index_names,
+                if_exists,
+            }) => {
+                let mut steps = vec![];
+                for full_index_name in full_index_names {
+                    steps.push(vec![
+                        Step::CheckExistence {
+                            system_object: SystemObject::Schema,
+                            object_name: vec![full_index_name.schema().to_owned()],
+                        },
+                        Step::CheckExistence {
+                            system_object: SystemObject::Index,
+                            object_name: vec![full_index_name.schema().to_owned(), full_index_name.table().to_owned()],
+                        },
+                        Step::RemoveIndex {
+                            schema_name: full_index_name.schema().to_owned(),
+                            index_name: full_index_name.table().to_owned(),
+                        },
+                    ]);
+                }
+                SystemOperation {
+                    kind: Kind::Drop(SystemObject::Index),
+                    skip_steps_if: if *if_exists { Some(ObjectState::NotExists) } else { None },
+                    steps,
+                }
+            }
+            SchemaChange::AddColumn(AddColumnQuery {
+                full_table_name,
+                column:
+                    ColumnInfo {
+                        name,
+                        sql_type,
+                        constraints,
+                    },
+            }) => {
+                let mut steps = table_existence(full_table_name);
+                steps.push(column_existence(full_table_name, name));
+                // default values are written into the new column after it is added
+                steps.push(Step::AddColumnValues {
+                    schema_name: full_table_name.schema().to_owned(),
+                    table_name: full_table_name.table().to_owned(),
+                    column_name: name.clone(),
+                    nullable: !constraints.not_null || constraints.default_expr.is_some(),
+                });
+                steps.push(Step::CreateRecord {
+                    record: Record::Column {
+                        schema_name: full_table_name.schema().to_owned(),
+                        table_name: full_table_name.table().to_owned(),
+                        column_name: name.clone(),
+                        sql_type: *sql_type,
+                        constraints: constraints.clone(),
+                    },
+                });
+                SystemOperation {
+                    kind: Kind::Create(SystemObject::Column),
+                    skip_steps_if: None,
+                    steps: vec![steps],
+                }
+            }
+            SchemaChange::DropColumn(DropColumnQuery {
+                full_table_name,
+                column_name,
+                cascade,
+                if_exists,
+            }) => {
+                let mut steps = table_existence(full_table_name);
+                steps.push(column_existence(full_table_name, column_name));
+                let object_name = vec![
+                    full_table_name.schema().to_owned(),
+                    full_table_name.table().to_owned(),
+                    column_name.clone(),
+                ];
+                // foreign keys of other tables that reference the column are dropped only with `CASCADE`
+                if *cascade {
+                    steps.push(Step::RemoveDependants {
+                        system_object: SystemObject::Column,
+                        object_name,
+                    });
+                } else {
+                    steps.push(Step::CheckDependants {
+                        system_object: SystemObject::Column,
+                        object_name,
+                    });
+                }
+                steps.push(Step::RemoveColumn {
+                    schema_name: full_table_name.schema().to_owned(),
+                    table_name: full_table_name.table().to_owned(),
+                    column_name: column_name.clone(),
+                });
+                SystemOperation {
+                    kind: Kind::Drop(SystemObject::Column),
+                    skip_steps_if: if *if_exists { Some(ObjectState::NotExists) } else { None },
+                    steps: vec![steps],
+                }
+            }
+            SchemaChange::RenameColumn(RenameColumnQuery {
+                full_table_name,
+                old_column_name,
+                new_column_name,
+            }) => {
+                let mut steps = table_existence(full_table_name);
+                steps.push(column_existence(full_table_name, old_column_name));
+                steps.push(Step::RenameColumn {
+                    schema_name: full_table_name.schema().to_owned(),
+                    table_name: full_table_name.table().to_owned(),
+                    old_column_name: old_column_name.clone(),
+                    new_column_name: new_column_name.clone(),
+                });
+                SystemOperation {
+                    kind: Kind::Alter(SystemObject::Column),
+                    skip_steps_if: None,
+                    steps: vec![steps],
+                }
+            }
+            SchemaChange::RenameTable(RenameTableQuery {
+                full_table_name,
+                new_table_name,
+            }) => {
+                let mut steps = table_existence(full_table_name);
+                steps.push(Step::RenameTable {
+                    schema_name: full_table_name.schema().to_owned(),
+                    table_name: full_table_name.table().to_owned(),
+                    new_table_name: new_table_name.clone(),
+                });
+                SystemOperation {
+                    kind: Kind::Alter(SystemObject::Table),
+                    skip_steps_if: None,
+                    steps: vec![steps],
+                }
+            }
+            SchemaChange::AlterColumnType(AlterColumnTypeQuery {
+                full_table_name,
+                column_name,
+                sql_type,
+            }) => {
+                let mut steps = table_existence(full_table_name);
+                steps.push(column_existence(full_table_name, column_name));
+                steps.push(Step::ChangeColumnType {
+                    schema_name: full_table_name.schema().to_owned(),
+                    table_name: full_table_name.table().to_owned(),
+                    column_name: column_name.clone(),
+                    sql_type: *sql_type,
+                });
+                SystemOperation {
+                    kind: Kind::Alter(SystemObject::Column),
+                    skip_steps_if: None,
+                    steps: vec![steps],
+                }
+            }
         }
     }
 }
 
+/// altered table and its schema have to exist
+fn table_existence(full_table_name: &FullTableName) -> Vec<Step> {
+    vec![
+        Step::CheckExistence {
+            system_object: SystemObject::Schema,
+            object_name: vec![full_table_name.schema().to_owned()],
+        },
+        Step::CheckExistence {
+            system_object: SystemObject::Table,
+            object_name: vec![full_table_name.schema().to_owned(), full_table_name.table().to_owned()],
+        },
+    ]
+}
+
+fn column_existence(full_table_name: &FullTableName, column_name: &str) -> Step {
+    Step::CheckExistence {
+        system_object: SystemObject::Column,
+        object_name: vec![
+            full_table_name.schema().to_owned(),
+            full_table_name.table().to_owned(),
+            column_name.to_owned(),
+        ],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -455,10 +759,12 @@ mod tests {
 
     #[cfg(test)]
     mod table {
-        use data_definition_execution_plan::{ColumnInfo, CreateTableQuery, DropTablesQuery, SchemaChange};
+        use data_definition_execution_plan::{
+            ColumnInfo, ConstraintInfo, CreateTableQuery, DropTablesQuery, ForeignKeyInfo, SchemaChange,
+        };
 
         use super::*;
-        use definition::FullTableName;
+        use definition::{CheckDef, ColumnConstraints, FullTableName, ReferentialAction};
 
         #[test]
         fn create_without_columns() {
@@ -466,6 +772,8 @@ mod tests {
                 QUERY_PLANNER.schema_change_plan(&SchemaChange::CreateTable(CreateTableQuery {
                     full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
                     column_defs: vec![],
+                    constraints: vec![],
+                    foreign_keys: vec![],
                     if_not_exists: false,
                 })),
                 SystemOperation {
@@ -501,6 +809,8 @@ mod tests {
                 QUERY_PLANNER.schema_change_plan(&SchemaChange::CreateTable(CreateTableQuery {
                     full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
                     column_defs: vec![],
+                    constraints: vec![],
+                    foreign_keys: vec![],
                     if_not_exists: true,
                 })),
                 SystemOperation {
@@ -538,13 +848,24 @@ mod tests {
                     column_defs: vec![
                         ColumnInfo {
                             name: "col_1".to_owned(),
-                            sql_type: SqlType::small_int()
+                            sql_type: SqlType::small_int(),
+                            constraints: ColumnConstraints::default()
                         },
                         ColumnInfo {
                             name: "col_2".to_owned(),
-                            sql_type: SqlType::big_int()
+                            sql_type: SqlType::big_int(),
+                            constraints: ColumnConstraints {
+                                not_null: true,
+                                default_expr: Some("0".to_owned()),
+                                check: Some(CheckDef {
+                                    name: "table_col_2_check".to_owned(),
+                                    predicate: "col_2 >= 0".to_owned()
+                                })
+                            }
                         }
                     ],
+                    constraints: vec![],
+                    foreign_keys: vec![],
                     if_not_exists: false,
                 })),
                 SystemOperation {
@@ -574,7 +895,8 @@ mod tests {
                                 schema_name: SCHEMA.to_string(),
                                 table_name: TABLE.to_string(),
                                 column_name: "col_1".to_string(),
-                                sql_type: SqlType::small_int()
+                                sql_type: SqlType::small_int(),
+                                constraints: ColumnConstraints::default()
                             }
                         },
                         Step::CreateRecord {
@@ -582,7 +904,159 @@ mod tests {
                                 schema_name: SCHEMA.to_string(),
                                 table_name: TABLE.to_string(),
                                 column_name: "col_2".to_string(),
-                                sql_type: SqlType::big_int()
+                                sql_type: SqlType::big_int(),
+                                constraints: ColumnConstraints {
+                                    not_null: true,
+                                    default_expr: Some("0".to_owned()),
+                                    check: Some(CheckDef {
+                                        name: "table_col_2_check".to_owned(),
+                                        predicate: "col_2 >= 0".to_owned()
+                                    })
+                                }
+                            }
+                        }
+                    ]]
+                }
+            );
+        }
+
+        #[test]
+        fn create_with_constraints() {
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::CreateTable(CreateTableQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_defs: vec![ColumnInfo {
+                        name: "col_1".to_owned(),
+                        sql_type: SqlType::small_int(),
+                        constraints: ColumnConstraints::default()
+                    }],
+                    constraints: vec![ConstraintInfo {
+                        name: "table_pkey".to_owned(),
+                        kind: IndexKind::PrimaryKey,
+                        column_names: vec!["col_1".to_owned()]
+                    }],
+                    foreign_keys: vec![],
+                    if_not_exists: false,
+                })),
+                SystemOperation {
+                    kind: Kind::Create(SystemObject::Table),
+                    skip_steps_if: None,
+                    steps: vec![vec![
+                        Step::CheckExistence {
+                            system_object: SystemObject::Schema,
+                            object_name: vec![SCHEMA.to_owned()],
+                        },
+                        Step::CheckExistence {
+                            system_object: SystemObject::Table,
+                            object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
+                        },
+                        Step::CheckExistence {
+                            system_object: SystemObject::Index,
+                            object_name: vec![SCHEMA.to_owned(), "table_pkey".to_owned()],
+                        },
+                        Step::CreateFile {
+                            folder_name: SCHEMA.to_owned(),
+                            name: TABLE.to_owned()
+                        },
+                        Step::CreateRecord {
+                            record: Record::Table {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned(),
+                            }
+                        },
+                        Step::CreateRecord {
+                            record: Record::Column {
+                                schema_name: SCHEMA.to_string(),
+                                table_name: TABLE.to_string(),
+                                column_name: "col_1".to_string(),
+                                sql_type: SqlType::small_int(),
+                                constraints: ColumnConstraints::default()
+                            }
+                        },
+                        Step::CreateIndex {
+                            schema_name: SCHEMA.to_owned(),
+                            table_name: TABLE.to_owned(),
+                            index_name: "table_pkey".to_owned(),
+                            column_names: vec!["col_1".to_owned()],
+                            kind: IndexKind::PrimaryKey,
+                        },
+                        Step::CreateRecord {
+                            record: Record::Index {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned(),
+                                index_name: "table_pkey".to_owned(),
+                                column_names: vec!["col_1".to_owned()],
+                                kind: IndexKind::PrimaryKey,
+                            }
+                        }
+                    ]]
+                }
+            );
+        }
+
+        #[test]
+        fn create_with_foreign_keys() {
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::CreateTable(CreateTableQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_defs: vec![ColumnInfo {
+                        name: "col_1".to_owned(),
+                        sql_type: SqlType::small_int(),
+                        constraints: ColumnConstraints::default()
+                    }],
+                    constraints: vec![],
+                    foreign_keys: vec![ForeignKeyInfo {
+                        name: "table_col_1_fkey".to_owned(),
+                        column_names: vec!["col_1".to_owned()],
+                        referenced_table: FullTableName::from((&SCHEMA, &OTHER_TABLE)),
+                        referenced_column_names: vec!["col_1".to_owned()],
+                        on_delete: ReferentialAction::Cascade,
+                        on_update: ReferentialAction::NoAction,
+                    }],
+                    if_not_exists: false,
+                })),
+                SystemOperation {
+                    kind: Kind::Create(SystemObject::Table),
+                    skip_steps_if: None,
+                    steps: vec![vec![
+                        Step::CheckExistence {
+                            system_object: SystemObject::Schema,
+                            object_name: vec![SCHEMA.to_owned()],
+                        },
+                        Step::CheckExistence {
+                            system_object: SystemObject::Table,
+                            object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
+                        },
+                        Step::CreateFile {
+                            folder_name: SCHEMA.to_owned(),
+                            name: TABLE.to_owned()
+                        },
+                        Step::CreateRecord {
+                            record: Record::Table {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned(),
+                            }
+                        },
+                        Step::CreateRecord {
+                            record: Record::Column {
+                                schema_name: SCHEMA.to_string(),
+                                table_name: TABLE.to_string(),
+                                column_name: "col_1".to_string(),
+                                sql_type: SqlType::small_int(),
+                                constraints: ColumnConstraints::default()
+                            }
+                        },
+                        Step::CreateRecord {
+                            record: Record::ForeignKey {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned(),
+                                constraint_name: "table_col_1_fkey".to_owned(),
+                                column_names: vec!["col_1".to_owned()],
+                                referenced_schema_name: SCHEMA.to_owned(),
+                                referenced_table_name: OTHER_TABLE.to_owned(),
+                                referenced_column_names: vec!["col_1".to_owned()],
+                                on_delete: ReferentialAction::Cascade,
+                                on_update: ReferentialAction::NoAction,
                             }
                         }
                     ]]
@@ -614,6 +1088,18 @@ mod tests {
                                 system_object: SystemObject::Table,
                                 object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
                             },
+                            Step::CheckDependants {
+                                system_object: SystemObject::Table,
+                                object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
+                            },
+                            Step::RemoveForeignKeys {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned()
+                            },
+                            Step::RemoveIndexes {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned()
+                            },
                             Step::RemoveColumns {
                                 schema_name: SCHEMA.to_owned(),
                                 table_name: TABLE.to_owned()
@@ -638,6 +1124,18 @@ mod tests {
                                 system_object: SystemObject::Table,
                                 object_name: vec![SCHEMA.to_owned(), OTHER_TABLE.to_owned()],
                             },
+                            Step::CheckDependants {
+                                system_object: SystemObject::Table,
+                                object_name: vec![SCHEMA.to_owned(), OTHER_TABLE.to_owned()],
+                            },
+                            Step::RemoveForeignKeys {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: OTHER_TABLE.to_owned()
+                            },
+                            Step::RemoveIndexes {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: OTHER_TABLE.to_owned()
+                            },
                             Step::RemoveColumns {
                                 schema_name: SCHEMA.to_owned(),
                                 table_name: OTHER_TABLE.to_owned()
@@ -682,6 +1180,18 @@ mod tests {
                                 system_object: SystemObject::Table,
                                 object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
                             },
+                            Step::RemoveDependants {
+                                system_object: SystemObject::Table,
+                                object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
+                            },
+                            Step::RemoveForeignKeys {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned()
+                            },
+                            Step::RemoveIndexes {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned()
+                            },
                             Step::RemoveColumns {
                                 schema_name: SCHEMA.to_owned(),
                                 table_name: TABLE.to_owned()
@@ -706,6 +1216,18 @@ mod tests {
                                 system_object: SystemObject::Table,
                                 object_name: vec![SCHEMA.to_owned(), OTHER_TABLE.to_owned()],
                             },
+                            Step::RemoveDependants {
+                                system_object: SystemObject::Table,
+                                object_name: vec![SCHEMA.to_owned(), OTHER_TABLE.to_owned()],
+                            },
+                            Step::RemoveForeignKeys {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: OTHER_TABLE.to_owned()
+                            },
+                            Step::RemoveIndexes {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: OTHER_TABLE.to_owned()
+                            },
                             Step::RemoveColumns {
                                 schema_name: SCHEMA.to_owned(),
                                 table_name: OTHER_TABLE.to_owned()
@@ -750,6 +1272,18 @@ mod tests {
                                 system_object: SystemObject::Table,
                                 object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
                             },
+                            Step::CheckDependants {
+                                system_object: SystemObject::Table,
+                                object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
+                            },
+                            Step::RemoveForeignKeys {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned()
+                            },
+                            Step::RemoveIndexes {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: TABLE.to_owned(),
+                            },
                             Step::RemoveColumns {
                                 schema_name: SCHEMA.to_owned(),
                                 table_name: TABLE.to_owned(),
@@ -774,6 +1308,18 @@ mod tests {
                                 system_object: SystemObject::Table,
                                 object_name: vec![SCHEMA.to_owned(), OTHER_TABLE.to_owned()],
                             },
+                            Step::CheckDependants {
+                                system_object: SystemObject::Table,
+                                object_name: vec![SCHEMA.to_owned(), OTHER_TABLE.to_owned()],
+                            },
+                            Step::RemoveForeignKeys {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: OTHER_TABLE.to_owned()
+                            },
+                            Step::RemoveIndexes {
+                                schema_name: SCHEMA.to_owned(),
+                                table_name: OTHER_TABLE.to_owned(),
+                            },
                             Step::RemoveColumns {
                                 schema_name: SCHEMA.to_owned(),
                                 table_name: OTHER_TABLE.to_owned(),
@@ -794,4 +1340,350 @@ mod tests {
             );
         }
     }
+    #[cfg(test)]
+    mod index {
+        use data_definition_execution_plan::{CreateIndexQuery, DropIndexesQuery, SchemaChange};
+
+        use super::*;
+        use definition::FullTableName;
+
+        const INDEX: &str = "index";
+        const OTHER_INDEX: &str = "other_index";
+
+        fn create_index_steps() -> Vec<Step> {
+            vec![
+                Step::CheckExistence {
+                    system_object: SystemObject::Schema,
+                    object_name: vec![SCHEMA.to_owned()],
+                },
+                Step::CheckExistence {
+                    system_object: SystemObject::Table,
+                    object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
+                },
+                Step::CheckExistence {
+                    system_object: SystemObject::Index,
+                    object_name: vec![SCHEMA.to_owned(), INDEX.to_owned()],
+                },
+                Step::CreateIndex {
+                    schema_name: SCHEMA.to_owned(),
+                    table_name: TABLE.to_owned(),
+                    index_name: INDEX.to_owned(),
+                    column_names: vec!["col_1".to_owned(), "col_2".to_owned()],
+                    kind: IndexKind::Plain,
+                },
+                Step::CreateRecord {
+                    record: Record::Index {
+                        schema_name: SCHEMA.to_owned(),
+                        table_name: TABLE.to_owned(),
+                        index_name: INDEX.to_owned(),
+                        column_names: vec!["col_1".to_owned(), "col_2".to_owned()],
+                        kind: IndexKind::Plain,
+                    },
+                },
+            ]
+        }
+
+        fn drop_index_steps(index_name: &str) -> Vec<Step> {
+            vec![
+                Step::CheckExistence {
+                    system_object: SystemObject::Schema,
+                    object_name: vec![SCHEMA.to_owned()],
+                },
+                Step::CheckExistence {
+                    system_object: SystemObject::Index,
+                    object_name: vec![SCHEMA.to_owned(), index_name.to_owned()],
+                },
+                Step::RemoveIndex {
+                    schema_name: SCHEMA.to_owned(),
+                    index_name: index_name.to_owned(),
+                },
+            ]
+        }
+
+        #[test]
+        fn create() {
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::CreateIndex(CreateIndexQuery {
+                    index_name: INDEX.to_owned(),
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_names: vec!["col_1".to_owned(), "col_2".to_owned()],
+                    if_not_exists: false,
+                })),
+                SystemOperation {
+                    kind: Kind::Create(SystemObject::Index),
+                    skip_steps_if: None,
+                    steps: vec![create_index_steps()]
+                }
+            );
+        }
+
+        #[test]
+        fn create_if_not_exists() {
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::CreateIndex(CreateIndexQuery {
+                    index_name: INDEX.to_owned(),
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_names: vec!["col_1".to_owned(), "col_2".to_owned()],
+                    if_not_exists: true,
+                })),
+                SystemOperation {
+                    kind: Kind::Create(SystemObject::Index),
+                    skip_steps_if: Some(ObjectState::Exists),
+                    steps: vec![create_index_steps()]
+                }
+            );
+        }
+
+        #[test]
+        fn drop_many() {
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::DropIndexes(DropIndexesQuery {
+                    full_index_names: vec![
+                        FullTableName::from((&SCHEMA, &INDEX)),
+                        FullTableName::from((&SCHEMA, &OTHER_INDEX))
+                    ],
+                    if_exists: false
+                })),
+                SystemOperation {
+                    kind: Kind::Drop(SystemObject::Index),
+                    skip_steps_if: None,
+                    steps: vec![drop_index_steps(INDEX), drop_index_steps(OTHER_INDEX)]
+                }
+            );
+        }
+
+        #[test]
+        fn drop_many_if_exists() {
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::DropIndexes(DropIndexesQuery {
+                    full_index_names: vec![
+                        FullTableName::from((&SCHEMA, &INDEX)),
+                        FullTableName::from((&SCHEMA, &OTHER_INDEX))
+                    ],
+                    if_exists: true
+                })),
+                SystemOperation {
+                    kind: Kind::Drop(SystemObject::Index),
+                    skip_steps_if: Some(ObjectState::NotExists),
+                    steps: vec![drop_index_steps(INDEX), drop_index_steps(OTHER_INDEX)]
+                }
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod alter_table {
+        use super::*;
+        use data_definition_execution_plan::{
+            AddColumnQuery, AlterColumnTypeQuery, ColumnInfo, DropColumnQuery, RenameColumnQuery, RenameTableQuery,
+            SchemaChange,
+        };
+        use definition::ColumnConstraints;
+
+        const COLUMN: &str = "col";
+
+        fn existence_steps() -> Vec<Step> {
+            vec![
+                Step::CheckExistence {
+                    system_object: SystemObject::Schema,
+                    object_name: vec![SCHEMA.to_owned()],
+                },
+                Step::CheckExistence {
+                    system_object: SystemObject::Table,
+                    object_name: vec![SCHEMA.to_owned(), TABLE.to_owned()],
+                },
+                Step::CheckExistence {
+                    system_object: SystemObject::Column,
+                    object_name: vec![SCHEMA.to_owned(), TABLE.to_owned(), COLUMN.to_owned()],
+                },
+            ]
+        }
+
+        #[test]
+        fn add_column() {
+            let constraints = ColumnConstraints {
+                not_null: true,
+                default_expr: Some("1".to_owned()),
+                check: None,
+            };
+            let mut steps = existence_steps();
+            steps.push(Step::AddColumnValues {
+                schema_name: SCHEMA.to_owned(),
+                table_name: TABLE.to_owned(),
+                column_name: COLUMN.to_owned(),
+                nullable: true,
+            });
+            steps.push(Step::CreateRecord {
+                record: Record::Column {
+                    schema_name: SCHEMA.to_owned(),
+                    table_name: TABLE.to_owned(),
+                    column_name: COLUMN.to_owned(),
+                    sql_type: SqlType::small_int(),
+                    constraints: constraints.clone(),
+                },
+            });
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::AddColumn(AddColumnQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column: ColumnInfo {
+                        name: COLUMN.to_owned(),
+                        sql_type: SqlType::small_int(),
+                        constraints,
+                    },
+                })),
+                SystemOperation {
+                    kind: Kind::Create(SystemObject::Column),
+                    skip_steps_if: None,
+                    steps: vec![steps]
+                }
+            );
+        }
+
+        #[test]
+        fn add_not_null_column_without_default() {
+            let operation = QUERY_PLANNER.schema_change_plan(&SchemaChange::AddColumn(AddColumnQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column: ColumnInfo {
+                    name: COLUMN.to_owned(),
+                    sql_type: SqlType::small_int(),
+                    constraints: ColumnConstraints {
+                        not_null: true,
+                        default_expr: None,
+                        check: None,
+                    },
+                },
+            }));
+            assert_eq!(
+                operation.steps[0][3],
+                Step::AddColumnValues {
+                    schema_name: SCHEMA.to_owned(),
+                    table_name: TABLE.to_owned(),
+                    column_name: COLUMN.to_owned(),
+                    nullable: false,
+                }
+            );
+        }
+
+        #[test]
+        fn drop_column() {
+            let mut steps = existence_steps();
+            steps.push(Step::CheckDependants {
+                system_object: SystemObject::Column,
+                object_name: vec![SCHEMA.to_owned(), TABLE.to_owned(), COLUMN.to_owned()],
+            });
+            steps.push(Step::RemoveColumn {
+                schema_name: SCHEMA.to_owned(),
+                table_name: TABLE.to_owned(),
+                column_name: COLUMN.to_owned(),
+            });
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::DropColumn(DropColumnQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_name: COLUMN.to_owned(),
+                    cascade: false,
+                    if_exists: false,
+                })),
+                SystemOperation {
+                    kind: Kind::Drop(SystemObject::Column),
+                    skip_steps_if: None,
+                    steps: vec![steps]
+                }
+            );
+        }
+
+        #[test]
+        fn drop_column_if_exists_cascade() {
+            let mut steps = existence_steps();
+            steps.push(Step::RemoveDependants {
+                system_object: SystemObject::Column,
+                object_name: vec![SCHEMA.to_owned(), TABLE.to_owned(), COLUMN.to_owned()],
+            });
+            steps.push(Step::RemoveColumn {
+                schema_name: SCHEMA.to_owned(),
+                table_name: TABLE.to_owned(),
+                column_name: COLUMN.to_owned(),
+            });
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::DropColumn(DropColumnQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_name: COLUMN.to_owned(),
+                    cascade: true,
+                    if_exists: true,
+                })),
+                SystemOperation {
+                    kind: Kind::Drop(SystemObject::Column),
+                    skip_steps_if: Some(ObjectState::NotExists),
+                    steps: vec![steps]
+                }
+            );
+        }
+
+        #[test]
+        fn rename_column() {
+            let mut steps = existence_steps();
+            steps.push(Step::RenameColumn {
+                schema_name: SCHEMA.to_owned(),
+                table_name: TABLE.to_owned(),
+                old_column_name: COLUMN.to_owned(),
+                new_column_name: "new_col".to_owned(),
+            });
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::RenameColumn(RenameColumnQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    old_column_name: COLUMN.to_owned(),
+                    new_column_name: "new_col".to_owned(),
+                })),
+                SystemOperation {
+                    kind: Kind::Alter(SystemObject::Column),
+                    skip_steps_if: None,
+                    steps: vec![steps]
+                }
+            );
+        }
+
+        #[test]
+        fn rename_table() {
+            let mut steps = existence_steps();
+            steps.pop();
+            steps.push(Step::RenameTable {
+                schema_name: SCHEMA.to_owned(),
+                table_name: TABLE.to_owned(),
+                new_table_name: OTHER_TABLE.to_owned(),
+            });
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::RenameTable(RenameTableQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    new_table_name: OTHER_TABLE.to_owned(),
+                })),
+                SystemOperation {
+                    kind: Kind::Alter(SystemObject::Table),
+                    skip_steps_if: None,
+                    steps: vec![steps]
+                }
+            );
+        }
+
+        #[test]
+        fn alter_column_type() {
+            let mut steps = existence_steps();
+            steps.push(Step::ChangeColumnType {
+                schema_name: SCHEMA.to_owned(),
+                table_name: TABLE.to_owned(),
+                column_name: COLUMN.to_owned(),
+                sql_type: SqlType::big_int(),
+            });
+            assert_eq!(
+                QUERY_PLANNER.schema_change_plan(&SchemaChange::AlterColumnType(AlterColumnTypeQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_name: COLUMN.to_owned(),
+                    sql_type: SqlType::big_int(),
+                })),
+                SystemOperation {
+                    kind: Kind::Alter(SystemObject::Column),
+                    skip_steps_if: None,
+                    steps: vec![steps]
+                }
+            );
+        }
+    }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use definition::{FullTableName, SchemaName};
+use definition::{ColumnConstraints, FullTableName, IndexKind, ReferentialAction, SchemaName};
 use types::SqlType;
 
 #[derive(Debug, PartialEq)]
@@ -32,12 +32,35 @@ pub struct DropSchemasQuery {
 pub struct ColumnInfo {
     pub name: String,
     pub sql_type: SqlType,
+    pub constraints: ColumnConstraints,
+}
+
+/// unique or primary key constraint of a table
+#[derive(Debug, PartialEq)]
+pub struct ConstraintInfo {
+    pub name: String,
+    pub kind: IndexKind,
+    pub column_names: Vec<String>,
+}
+
+/// foreign key constraint of a table, values of `column_names` have to match values of
+/// `referenced_column_names` of a row of the referenced table
+#[derive(Debug, PartialEq)]
+pub struct ForeignKeyInfo {
+    pub name: String,
+    pub column_names: Vec<String>,
+    pub referenced_table: FullTableName,
+    pub referenced_column_names: Vec<String>,
+    pub on_delete: ReferentialAction,
+    pub on_update: ReferentialAction,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct CreateTableQuery {
     pub full_table_name: FullTableName,
     pub column_defs: Vec<ColumnInfo>,
+    pub constraints: Vec<ConstraintInfo>,
+    pub foreign_keys: Vec<ForeignKeyInfo>,
     pub if_not_exists: bool,
 }
 
@@ -48,6 +71,56 @@ pub struct DropTablesQuery {
     pub if_exists: bool,
 }
 
+#[derive(Debug, PartialEq)]
+pub struct CreateIndexQuery {
+    pub index_name: String,
+    pub full_table_name: FullTableName,
+    pub column_names: Vec<String>,
+    pub if_not_exists: bool,
+}
+
+/// indexes are named within a schema of their tables
+#[derive(Debug, PartialEq)]
+pub struct DropIndexesQuery {
+    pub full_index_names: Vec<FullTableName>,
+    pub if_exists: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AddColumnQuery {
+    pub full_table_name: FullTableName,
+    pub column: ColumnInfo,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DropColumnQuery {
+    pub full_table_name: FullTableName,
+    pub column_name: String,
+    pub cascade: bool,
+    pub if_exists: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RenameColumnQuery {
+    pub full_table_name: FullTableName,
+    pub old_column_name: String,
+    pub new_column_name: String,
+}
+
+/// table is renamed within its schema
+#[derive(Debug, PartialEq)]
+pub struct RenameTableQuery {
+    pub full_table_name: FullTableName,
+    pub new_table_name: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterColumnTypeQuery {
+    pub full_table_name: FullTableName,
+    pub column_name: String,
+    pub sql_type: SqlType,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ColumnDesc {
     pub name: String,
@@ -72,4 +145,11 @@ pub enum SchemaChange {
     DropSchemas(DropSchemasQuery),
     CreateTable(CreateTableQuery),
     DropTables(DropTablesQuery),
+    CreateIndex(CreateIndexQuery),
+    DropIndexes(DropIndexesQuery),
+    AddColumn(AddColumnQuery),
+    DropColumn(DropColumnQuery),
+    RenameColumn(RenameColumnQuery),
+    RenameTable(RenameTableQuery),
+    AlterColumnType(AlterColumnTypeQuery),
 }
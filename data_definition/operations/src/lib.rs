@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use definition::{ColumnConstraints, IndexKind, ReferentialAction};
 use types::SqlType;
 
 #[derive(Debug, PartialEq)]
@@ -25,6 +26,7 @@ pub struct SystemOperation {
 pub enum Kind {
     Create(SystemObject),
     Drop(SystemObject),
+    Alter(SystemObject),
 }
 
 #[derive(Debug, PartialEq)]
@@ -45,6 +47,64 @@ pub enum Step {
         schema_name: String,
         table_name: String,
     },
+    /// removes records of all indexes of the table
+    RemoveIndexes {
+        schema_name: String,
+        table_name: String,
+    },
+    /// removes records of all foreign keys of the table
+    RemoveForeignKeys {
+        schema_name: String,
+        table_name: String,
+    },
+    /// builds index over values of `column_names` of existing rows of the table
+    CreateIndex {
+        schema_name: String,
+        table_name: String,
+        index_name: String,
+        column_names: Vec<String>,
+        kind: IndexKind,
+    },
+    /// removes index from the table it is built for together with its record
+    RemoveIndex {
+        schema_name: String,
+        index_name: String,
+    },
+    /// extends rows of the table with `NULL` as a value of a new column, fails if
+    /// the column can't be `NULL` and the table has rows
+    AddColumnValues {
+        schema_name: String,
+        table_name: String,
+        column_name: String,
+        nullable: bool,
+    },
+    /// removes values of the column from rows of the table together with its record,
+    /// indexes and foreign keys that include the column
+    RemoveColumn {
+        schema_name: String,
+        table_name: String,
+        column_name: String,
+    },
+    /// renames the column in records of the table, its indexes and foreign keys
+    RenameColumn {
+        schema_name: String,
+        table_name: String,
+        old_column_name: String,
+        new_column_name: String,
+    },
+    /// converts values of the column of rows of the table into `sql_type`
+    ChangeColumnType {
+        schema_name: String,
+        table_name: String,
+        column_name: String,
+        sql_type: SqlType,
+    },
+    /// renames the table together with its records
+    RenameTable {
+        schema_name: String,
+        table_name: String,
+        new_table_name: String,
+    },
     CreateFolder {
         name: String,
     },
@@ -72,6 +132,8 @@ pub enum Step {
 pub enum SystemObject {
     Schema,
     Table,
+    Index,
+    Column,
 }
 
 #[derive(Debug, PartialEq)]
@@ -94,6 +156,27 @@ pub enum Record {
         table_name: String,
         column_name: String,
         sql_type: SqlType,
+        constraints: ColumnConstraints,
+    },
+    Index {
+        schema_name: String,
+        table_name: String,
+        index_name: String,
+        column_names: Vec<String>,
+        kind: IndexKind,
+    },
+    /// values of `column_names` of the table have to match values of `referenced_column_names`
+    /// of a row of the referenced table
+    ForeignKey {
+        schema_name: String,
+        table_name: String,
+        constraint_name: String,
+        column_names: Vec<String>,
+        referenced_schema_name: String,
+        referenced_table_name: String,
+        referenced_column_names: Vec<String>,
+        on_delete: ReferentialAction,
+        on_update: ReferentialAction,
     },
 }
 
@@ -103,6 +186,9 @@ pub enum ExecutionOutcome {
     SchemaDropped,
     TableCreated,
     TableDropped,
+    IndexCreated,
+    IndexDropped,
+    TableAltered,
 }
 
 #[derive(Debug, PartialEq)]
@@ -112,4 +198,18 @@ pub enum ExecutionError {
     TableAlreadyExists(String, String),
     TableDoesNotExist(String, String),
     SchemaHasDependentObjects(String),
+    /// table is referenced by a foreign key of another table
+    TableHasDependentObjects(String, String),
+    IndexAlreadyExists(String, String),
+    IndexDoesNotExist(String, String),
+    /// index of a unique or primary key constraint could be dropped only with its table
+    IndexIsRequiredByConstraint(String, String, String),
+    ColumnAlreadyExists(String, String),
+    ColumnDoesNotExist(String, String),
+    /// column that can't be `NULL` is added to a table that has rows
+    ColumnContainsNulls(String, String),
+    /// column is referenced by a foreign key of another table
+    ColumnHasDependentObjects(String, String),
+    /// values of the column can't be converted into the type
+    ColumnCannotBeCast(String, String, SqlType),
 }
@@ -0,0 +1,164 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{as_integer, from_bool, undefined_function, EvalError};
+use data_manipulation_operators::{JsonOp, Operation};
+use data_manipulation_untyped_tree::ImplicitCastError;
+use data_scalar::{
+    json::{self, Jsonb},
+    ScalarValue,
+};
+use types::SqlType;
+
+pub(crate) fn json_type(value: &ScalarValue) -> Option<SqlType> {
+    match value {
+        ScalarValue::Json(_) => Some(SqlType::json()),
+        ScalarValue::Jsonb(_) => Some(SqlType::jsonb()),
+        _ => None,
+    }
+}
+
+/// strings are validated as `json` and parsed as `jsonb`, documents are
+/// converted into each other and into their text representation
+pub(crate) fn cast(value: ScalarValue, target: SqlType) -> Result<ScalarValue, EvalError> {
+    match (value, target) {
+        (ScalarValue::String(text), SqlType::Json) => match json::parse_json(&text) {
+            Some(_) => Ok(ScalarValue::Json(text)),
+            None => Err(invalid_input(target, &text)),
+        },
+        (ScalarValue::String(text), SqlType::Jsonb) | (ScalarValue::Json(text), SqlType::Jsonb) => {
+            parse(&text, target).map(ScalarValue::Jsonb)
+        }
+        (ScalarValue::Jsonb(document), SqlType::Json) => Ok(ScalarValue::Json(document.to_string())),
+        (value @ ScalarValue::Json(_), SqlType::Json) | (value @ ScalarValue::Jsonb(_), SqlType::Jsonb) => Ok(value),
+        (value, SqlType::Str { .. }) => crate::cast(ScalarValue::String(value.as_text()), target),
+        (value, _) => match json_type(&value) {
+            Some(source) => Err(EvalError::Cast(ImplicitCastError::datatype_mismatch(target, source))),
+            None => unreachable!("only strings and json documents are cast into {}", target),
+        },
+    }
+}
+
+/// `->` and `#>` return values of the same type as the document,
+/// missing fields, elements and `null`s as text are `NULL`
+pub(crate) fn eval_operation(json_op: JsonOp, left: ScalarValue, right: ScalarValue) -> Result<ScalarValue, EvalError> {
+    let op = Operation::JsonOp(json_op);
+    let (document, as_jsonb) = match (&left, &right) {
+        (ScalarValue::Null, _) | (_, ScalarValue::Null) => return Ok(ScalarValue::Null),
+        (ScalarValue::Jsonb(document), _) => (document.clone(), true),
+        (ScalarValue::Json(text), _) if json_op != JsonOp::Contains && json_op != JsonOp::Exists => {
+            (parse(text, SqlType::json())?, false)
+        }
+        _ => return Err(undefined_function(op, &left, &right)),
+    };
+    let value = |found: Option<&Jsonb>| match found {
+        Some(found) if as_jsonb => ScalarValue::Jsonb(found.clone()),
+        Some(found) => ScalarValue::Json(found.to_string()),
+        None => ScalarValue::Null,
+    };
+    let text = |found: Option<&Jsonb>| match found.and_then(Jsonb::as_text) {
+        Some(text) => ScalarValue::String(text),
+        None => ScalarValue::Null,
+    };
+    match (json_op, &right) {
+        (JsonOp::Get, ScalarValue::String(key)) => Ok(value(document.field(key))),
+        (JsonOp::GetText, ScalarValue::String(key)) => Ok(text(document.field(key))),
+        (JsonOp::Get, index) if as_integer(index).is_some() => Ok(value(document.element(as_integer(index).unwrap()))),
+        (JsonOp::GetText, index) if as_integer(index).is_some() => {
+            Ok(text(document.element(as_integer(index).unwrap())))
+        }
+        (JsonOp::GetPath, ScalarValue::String(path)) => Ok(value(document.path(&parse_path(path)?))),
+        (JsonOp::Contains, ScalarValue::String(other)) => {
+            Ok(from_bool(document.contains(&parse(other, SqlType::jsonb())?)))
+        }
+        (JsonOp::Contains, ScalarValue::Jsonb(other)) => Ok(from_bool(document.contains(other))),
+        (JsonOp::Exists, ScalarValue::String(key)) => Ok(from_bool(document.exists(key))),
+        _ => Err(undefined_function(op, &left, &right)),
+    }
+}
+
+/// keys are converted into their text representation and can't be `NULL`
+pub(crate) fn build_object(args: &[ScalarValue]) -> Result<ScalarValue, EvalError> {
+    let mut pairs = Vec::with_capacity(args.len() / 2);
+    for (index, pair) in args.chunks(2).enumerate() {
+        match pair {
+            [ScalarValue::Null, _] => {
+                return Err(EvalError::InvalidParameterValue(format!(
+                    "argument {}: key must not be null",
+                    index * 2 + 1
+                )))
+            }
+            [key, value] => pairs.push((key.clone().as_text(), Jsonb::from(value.clone()))),
+            _ => unreachable!("number of arguments is checked during type check"),
+        }
+    }
+    Ok(ScalarValue::Jsonb(Jsonb::object(pairs)))
+}
+
+fn parse(text: &str, target: SqlType) -> Result<Jsonb, EvalError> {
+    json::parse_json(text).ok_or_else(|| invalid_input(target, text))
+}
+
+fn invalid_input(target: SqlType, text: &str) -> EvalError {
+    EvalError::Cast(ImplicitCastError::invalid_input_syntax_for_type(target, text))
+}
+
+/// paths are written as text array literals, e.g. `{a,"b c",0}`
+fn parse_path(path: &str) -> Result<Vec<String>, EvalError> {
+    let malformed = || EvalError::InvalidParameterValue(format!("malformed array literal: \"{}\"", path));
+    let elements = path
+        .trim()
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .ok_or_else(malformed)?;
+    if elements.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    let mut raw = vec![String::new()];
+    let mut quoted = false;
+    let mut escaped = false;
+    for c in elements.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                raw.push(String::new());
+                continue;
+            }
+            _ => {}
+        }
+        raw.last_mut().unwrap().push(c);
+    }
+    if quoted {
+        return Err(malformed());
+    }
+    raw.iter()
+        .map(|element| {
+            let element = element.trim();
+            match element.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) {
+                Some(quoted) => {
+                    let mut step = String::with_capacity(quoted.len());
+                    let mut chars = quoted.chars();
+                    while let Some(c) = chars.next() {
+                        step.push(if c == '\\' { chars.next().unwrap_or(c) } else { c });
+                    }
+                    Ok(step)
+                }
+                None if !element.is_empty() && !element.contains('"') => Ok(element.to_owned()),
+                None => Err(malformed()),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,718 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
+use data_manipulation_operators::{
+    Arithmetic, Bitwise, Comparison, Logical, Operation, PatternMatching, ScalarFunction, StringOp, UnaryOperation,
+};
+use data_manipulation_typed_tree::{DynamicTypedItem, DynamicTypedTree, StaticTypedItem, StaticTypedTree, TypedValue};
+use data_manipulation_untyped_tree::{round_to_scale, Bool, ImplicitCastError, UntypedValue};
+use data_scalar::{bytea, ScalarValue};
+use definition::ColumnDef;
+use ordered_float::OrderedFloat;
+use std::{cmp::Ordering, convert::TryFrom, str::FromStr};
+use types::{Num, SqlType};
+use uuid::Uuid;
+
+mod json;
+mod temporal;
+
+#[derive(Debug, PartialEq)]
+pub enum EvalError {
+    OutOfRange(SqlType),
+    DivisionByZero,
+    InvalidArgumentForPower,
+    UndefinedFunction {
+        op: Operation,
+        left: &'static str,
+        right: &'static str,
+    },
+    Cast(ImplicitCastError),
+    InvalidParameterValue(String),
+}
+
+/// evaluates typed trees with SQL semantics: any operation with `NULL`
+/// operand results in `NULL`, except `AND` and `OR` which follow three-valued logic
+pub struct Evaluation;
+
+impl Evaluation {
+    pub fn eval_static(&self, tree: &StaticTypedTree) -> Result<ScalarValue, EvalError> {
+        match tree {
+            StaticTypedTree::Item(StaticTypedItem::Const(value)) => Ok(scalar(value)),
+            StaticTypedTree::Item(StaticTypedItem::Null(_)) => Ok(ScalarValue::Null),
+            StaticTypedTree::Item(StaticTypedItem::Param { index, .. }) => {
+                unreachable!("parameter ${} has to be bound before evaluation", index + 1)
+            }
+            StaticTypedTree::Operation { left, op, right, .. } => {
+                eval_operation(*op, self.eval_static(left)?, self.eval_static(right)?)
+            }
+            StaticTypedTree::UnaryOperation { op, item, .. } => eval_unary_operation(*op, self.eval_static(item)?),
+            StaticTypedTree::Cast { tree, target } => cast(self.eval_static(tree)?, *target),
+            StaticTypedTree::Function { function, args, .. } => eval_function(
+                *function,
+                args.iter().map(|arg| self.eval_static(arg)).collect::<Result<_, _>>()?,
+            ),
+        }
+    }
+
+    /// evaluates `tree` over `row` whose values are ordered as `columns`
+    pub fn eval_dynamic(
+        &self,
+        tree: &DynamicTypedTree,
+        columns: &[ColumnDef],
+        row: &[ScalarValue],
+    ) -> Result<ScalarValue, EvalError> {
+        match tree {
+            DynamicTypedTree::Item(DynamicTypedItem::Const(value)) => Ok(scalar(value)),
+            DynamicTypedTree::Item(DynamicTypedItem::Column(name)) => {
+                match columns.iter().position(|column| column.has_name(name)) {
+                    Some(index) => Ok(row[index].clone()),
+                    None => unreachable!("column {:?} existence is checked during analysis", name),
+                }
+            }
+            DynamicTypedTree::Operation { left, op, right } => eval_operation(
+                *op,
+                self.eval_dynamic(left, columns, row)?,
+                self.eval_dynamic(right, columns, row)?,
+            ),
+            DynamicTypedTree::UnaryOperation { op, item } => {
+                eval_unary_operation(*op, self.eval_dynamic(item, columns, row)?)
+            }
+            DynamicTypedTree::Cast { tree, target } => cast(self.eval_dynamic(tree, columns, row)?, *target),
+            DynamicTypedTree::Function { function, args } => eval_function(
+                *function,
+                args.iter()
+                    .map(|arg| self.eval_dynamic(arg, columns, row))
+                    .collect::<Result<_, _>>()?,
+            ),
+            DynamicTypedTree::Aggregate { .. } => {
+                unreachable!("aggregate {:?} has to be computed by aggregate operator", tree)
+            }
+        }
+    }
+}
+
+fn scalar(value: &TypedValue) -> ScalarValue {
+    match value {
+        TypedValue::SmallInt(value) => ScalarValue::Int16(*value),
+        TypedValue::Integer(value) => ScalarValue::Int32(*value),
+        TypedValue::BigInt(value) => ScalarValue::Int64(*value),
+        TypedValue::Real(value) => ScalarValue::Float32(OrderedFloat(*value)),
+        TypedValue::Double(value) => ScalarValue::Float64(OrderedFloat(*value)),
+        TypedValue::Numeric(value) => ScalarValue::Numeric(value.clone()),
+        TypedValue::String(value) => ScalarValue::String(value.clone()),
+        TypedValue::Bool(value) => from_bool(*value),
+    }
+}
+
+/// converts `value` into `target` type by the same rules as untyped values are implicitly cast
+fn cast(value: ScalarValue, target: SqlType) -> Result<ScalarValue, EvalError> {
+    if let SqlType::Temporal(temporal) = target {
+        if value == ScalarValue::Null || is_string(&value) || temporal::temporal_type(&value).is_some() {
+            return temporal::cast(value, temporal);
+        }
+    }
+    if let Some(source) = temporal::temporal_type(&value) {
+        return match target {
+            SqlType::Str { .. } => cast(ScalarValue::String(value.as_text()), target),
+            _ => Err(EvalError::Cast(ImplicitCastError::datatype_mismatch(
+                target,
+                SqlType::Temporal(source),
+            ))),
+        };
+    }
+    if matches!(target, SqlType::Bytea | SqlType::Uuid) && is_string(&value) || binary_type(&value).is_some() {
+        return cast_binary(value, target);
+    }
+    if matches!(target, SqlType::Json | SqlType::Jsonb) && is_string(&value) || json::json_type(&value).is_some() {
+        return json::cast(value, target);
+    }
+    if let (SqlType::Num(num), Some(special)) = (target, special_float(&value)) {
+        return match num {
+            Num::Real => Ok(ScalarValue::Float32(OrderedFloat(special as f32))),
+            Num::Double => Ok(ScalarValue::Float64(OrderedFloat(special))),
+            _ => Err(EvalError::Cast(ImplicitCastError::numeric_value_out_of_range(target))),
+        };
+    }
+    let untyped = match value {
+        ScalarValue::Null => UntypedValue::Null,
+        ScalarValue::True => UntypedValue::Bool(Bool(true)),
+        ScalarValue::False => UntypedValue::Bool(Bool(false)),
+        ScalarValue::Int16(value) => UntypedValue::Number(BigDecimal::from(value)),
+        ScalarValue::Int32(value) => UntypedValue::Number(BigDecimal::from(value)),
+        ScalarValue::Int64(value) => UntypedValue::Number(BigDecimal::from(value)),
+        ScalarValue::Float32(value) if value.is_finite() => {
+            UntypedValue::Number(BigDecimal::from_str(&value.to_string()).unwrap())
+        }
+        ScalarValue::Float64(value) if value.is_finite() => {
+            UntypedValue::Number(BigDecimal::from_str(&value.to_string()).unwrap())
+        }
+        float @ ScalarValue::Float32(_) | float @ ScalarValue::Float64(_) => UntypedValue::String(float.as_text()),
+        ScalarValue::Numeric(value) => UntypedValue::Number(value),
+        ScalarValue::String(value) => UntypedValue::String(value),
+        temporal => unreachable!("{:?} is cast above", temporal),
+    };
+    match untyped.implicit_cast_to(target).map_err(EvalError::Cast)? {
+        UntypedValue::Null => Ok(ScalarValue::Null),
+        UntypedValue::Bool(Bool(value)) => Ok(from_bool(value)),
+        UntypedValue::String(value) => Ok(ScalarValue::String(value)),
+        UntypedValue::Number(value) => match target {
+            SqlType::Num(Num::SmallInt) => Ok(ScalarValue::Int16(value.to_i16().unwrap())),
+            SqlType::Num(Num::Integer) => Ok(ScalarValue::Int32(value.to_i32().unwrap())),
+            SqlType::Num(Num::BigInt) => Ok(ScalarValue::Int64(value.to_i64().unwrap())),
+            // floats are parsed from decimal text, `ToPrimitive` of `BigDecimal` scales
+            // its integer digits by a power of ten and loses precision
+            SqlType::Num(Num::Real) => f32::from_str(&value.to_string())
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(|value| ScalarValue::Float32(OrderedFloat(value)))
+                .ok_or_else(|| EvalError::Cast(ImplicitCastError::numeric_value_out_of_range(target))),
+            SqlType::Num(Num::Double) => f64::from_str(&value.to_string())
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(|value| ScalarValue::Float64(OrderedFloat(value)))
+                .ok_or_else(|| EvalError::Cast(ImplicitCastError::numeric_value_out_of_range(target))),
+            SqlType::Num(Num::Numeric { .. }) => Ok(ScalarValue::Numeric(value)),
+            SqlType::Bool
+            | SqlType::Str { .. }
+            | SqlType::Temporal(_)
+            | SqlType::Bytea
+            | SqlType::Uuid
+            | SqlType::Json
+            | SqlType::Jsonb => unreachable!("numbers are cast only into numeric types"),
+        },
+    }
+}
+
+/// binary strings and uuids are parsed from and converted into their text representation
+fn cast_binary(value: ScalarValue, target: SqlType) -> Result<ScalarValue, EvalError> {
+    let invalid = |text: &str| EvalError::Cast(ImplicitCastError::invalid_input_syntax_for_type(target, text));
+    match (value, target) {
+        (ScalarValue::String(text), SqlType::Bytea) => bytea::parse_bytea(&text)
+            .map(ScalarValue::Bytea)
+            .ok_or_else(|| invalid(&text)),
+        (ScalarValue::String(text), SqlType::Uuid) => Uuid::parse_str(text.trim())
+            .map(ScalarValue::Uuid)
+            .map_err(|_| invalid(&text)),
+        (value @ ScalarValue::Bytea(_), SqlType::Bytea) | (value @ ScalarValue::Uuid(_), SqlType::Uuid) => Ok(value),
+        (value, SqlType::Str { .. }) => cast(ScalarValue::String(value.as_text()), target),
+        (value, _) => match binary_type(&value) {
+            Some(source) => Err(EvalError::Cast(ImplicitCastError::datatype_mismatch(target, source))),
+            None => unreachable!("only strings, binary strings and uuids are cast into {}", target),
+        },
+    }
+}
+
+fn binary_type(value: &ScalarValue) -> Option<SqlType> {
+    match value {
+        ScalarValue::Bytea(_) => Some(SqlType::bytea()),
+        ScalarValue::Uuid(_) => Some(SqlType::uuid()),
+        _ => None,
+    }
+}
+
+/// `NaN` and infinities can't be represented by untyped numbers,
+/// so they are cast only into float types
+fn special_float(value: &ScalarValue) -> Option<f64> {
+    match value {
+        ScalarValue::Float32(value) if !value.is_finite() => Some(value.into_inner() as f64),
+        ScalarValue::Float64(value) if !value.is_finite() => Some(value.into_inner()),
+        ScalarValue::String(value) => match value.trim().to_lowercase().as_str() {
+            "nan" => Some(f64::NAN),
+            "infinity" | "+infinity" | "inf" | "+inf" => Some(f64::INFINITY),
+            "-infinity" | "-inf" => Some(f64::NEG_INFINITY),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn eval_function(function: ScalarFunction, args: Vec<ScalarValue>) -> Result<ScalarValue, EvalError> {
+    match (function, args.as_slice()) {
+        (ScalarFunction::Now, []) => Ok(ScalarValue::TimestampTz(temporal::now())),
+        (ScalarFunction::CurrentDate, []) => Ok(ScalarValue::Date(temporal::now().naive_utc().date())),
+        (ScalarFunction::GenRandomUuid, []) => Ok(ScalarValue::Uuid(Uuid::new_v4())),
+        (ScalarFunction::JsonbBuildObject, args) => json::build_object(args),
+        (_, [ScalarValue::Null, _]) | (_, [_, ScalarValue::Null]) => Ok(ScalarValue::Null),
+        (ScalarFunction::DatePart, [ScalarValue::String(field), source]) => temporal::date_part(field, source),
+        (ScalarFunction::DateTrunc, [ScalarValue::String(field), source]) => temporal::date_trunc(field, source),
+        _ => unreachable!("arguments {:?} of {} are checked during type check", args, function),
+    }
+}
+
+fn eval_operation(op: Operation, left: ScalarValue, right: ScalarValue) -> Result<ScalarValue, EvalError> {
+    match op {
+        Operation::Arithmetic(arithmetic) => eval_arithmetic(arithmetic, left, right),
+        Operation::Comparison(comparison) => eval_comparison(comparison, left, right),
+        Operation::Bitwise(bitwise) => eval_bitwise(bitwise, left, right),
+        Operation::Logical(logical) => eval_logical(logical, left, right),
+        Operation::PatternMatching(pattern_matching) => eval_pattern_matching(pattern_matching, left, right),
+        Operation::StringOp(StringOp::Concat) => eval_concat(left, right),
+        Operation::JsonOp(json_op) => json::eval_operation(json_op, left, right),
+    }
+}
+
+/// `IS NULL` and `IS NOT NULL` are the only operations that result in a value for `NULL` operand
+fn eval_unary_operation(op: UnaryOperation, value: ScalarValue) -> Result<ScalarValue, EvalError> {
+    match (op, value) {
+        (UnaryOperation::IsNull, value) => Ok(from_bool(value == ScalarValue::Null)),
+        (UnaryOperation::IsNotNull, value) => Ok(from_bool(value != ScalarValue::Null)),
+        (_, ScalarValue::Null) => Ok(ScalarValue::Null),
+        (UnaryOperation::Not, ScalarValue::True) => Ok(ScalarValue::False),
+        (UnaryOperation::Not, ScalarValue::False) => Ok(ScalarValue::True),
+        (UnaryOperation::Minus, ScalarValue::Int16(value)) => value
+            .checked_neg()
+            .map(ScalarValue::Int16)
+            .ok_or(EvalError::OutOfRange(SqlType::small_int())),
+        (UnaryOperation::Minus, ScalarValue::Int32(value)) => value
+            .checked_neg()
+            .map(ScalarValue::Int32)
+            .ok_or(EvalError::OutOfRange(SqlType::integer())),
+        (UnaryOperation::Minus, ScalarValue::Int64(value)) => value
+            .checked_neg()
+            .map(ScalarValue::Int64)
+            .ok_or(EvalError::OutOfRange(SqlType::big_int())),
+        (UnaryOperation::Minus, ScalarValue::Float32(value)) => Ok(ScalarValue::Float32(-value)),
+        (UnaryOperation::Minus, ScalarValue::Float64(value)) => Ok(ScalarValue::Float64(-value)),
+        (UnaryOperation::Minus, ScalarValue::Numeric(value)) => Ok(ScalarValue::Numeric(-value)),
+        (UnaryOperation::Minus, ScalarValue::Interval(interval)) => interval
+            .checked_neg()
+            .map(ScalarValue::Interval)
+            .ok_or(EvalError::OutOfRange(SqlType::interval())),
+        (op, value) => unreachable!("type of {:?} operand {:?} is checked during analysis", op, value),
+    }
+}
+
+/// integer operands are computed at least as `integer`s because numeric
+/// constants are inferred to the narrowest type that can hold them
+fn eval_arithmetic(arithmetic: Arithmetic, left: ScalarValue, right: ScalarValue) -> Result<ScalarValue, EvalError> {
+    if temporal::temporal_type(&left).is_some() || temporal::temporal_type(&right).is_some() {
+        return temporal::eval_arithmetic(arithmetic, left, right);
+    }
+    let op = Operation::Arithmetic(arithmetic);
+    check_operands(op, &left, &right, is_number)?;
+    if left == ScalarValue::Null || right == ScalarValue::Null {
+        return Ok(ScalarValue::Null);
+    }
+    if arithmetic == Arithmetic::Exp {
+        return eval_power(as_float(&left).unwrap(), as_float(&right).unwrap());
+    }
+    if matches!(
+        (&left, &right),
+        (ScalarValue::Numeric(_), _) | (_, ScalarValue::Numeric(_))
+    ) {
+        if let (Some(l), Some(r)) = (as_numeric(&left), as_numeric(&right)) {
+            return eval_numeric(arithmetic, l, r);
+        }
+    }
+    match (as_integer(&left), as_integer(&right)) {
+        (Some(l), Some(r)) => {
+            let sql_type = integer_result_type(&left, &right);
+            let result = match arithmetic {
+                Arithmetic::Add => l.checked_add(r),
+                Arithmetic::Sub => l.checked_sub(r),
+                Arithmetic::Mul => l.checked_mul(r),
+                Arithmetic::Div if r == 0 => return Err(EvalError::DivisionByZero),
+                Arithmetic::Div => l.checked_div(r),
+                Arithmetic::Mod if r == 0 => return Err(EvalError::DivisionByZero),
+                Arithmetic::Mod => Some(l.wrapping_rem(r)),
+                Arithmetic::Exp => unreachable!(),
+            };
+            match result {
+                Some(value) => integer(value, sql_type),
+                None => Err(EvalError::OutOfRange(sql_type)),
+            }
+        }
+        _ => {
+            let (l, r) = (as_float(&left).unwrap(), as_float(&right).unwrap());
+            let sql_type = float_result_type(&left, &right);
+            let result = match arithmetic {
+                Arithmetic::Add => l + r,
+                Arithmetic::Sub => l - r,
+                Arithmetic::Mul => l * r,
+                Arithmetic::Div if r == 0.0 => return Err(EvalError::DivisionByZero),
+                Arithmetic::Div => l / r,
+                Arithmetic::Mod => return Err(undefined_function(op, &left, &right)),
+                Arithmetic::Exp => unreachable!(),
+            };
+            if l.is_finite() && r.is_finite() {
+                float(result, sql_type)
+            } else {
+                Ok(float_value(result, sql_type))
+            }
+        }
+    }
+}
+
+/// numerics are computed exactly except quotients, which are rounded
+fn eval_numeric(arithmetic: Arithmetic, left: BigDecimal, right: BigDecimal) -> Result<ScalarValue, EvalError> {
+    let result = match arithmetic {
+        Arithmetic::Add => left + right,
+        Arithmetic::Sub => left - right,
+        Arithmetic::Mul => left * right,
+        Arithmetic::Div | Arithmetic::Mod if right.is_zero() => return Err(EvalError::DivisionByZero),
+        Arithmetic::Div => quotient(&left, &right),
+        Arithmetic::Mod => left % right,
+        Arithmetic::Exp => unreachable!(),
+    };
+    Ok(ScalarValue::Numeric(result))
+}
+
+/// as in PostgreSQL quotients have at least 16 significant digits
+/// and no less digits after the decimal point than any of operands
+fn quotient(left: &BigDecimal, right: &BigDecimal) -> BigDecimal {
+    let (left_weight, left_digit) = base_10000_digit(left);
+    let (right_weight, right_digit) = base_10000_digit(right);
+    let mut weight = left_weight - right_weight;
+    if left_digit <= right_digit {
+        weight -= 1;
+    }
+    let scale = (16 - weight * 4)
+        .max(left.as_bigint_and_exponent().1)
+        .max(right.as_bigint_and_exponent().1)
+        .clamp(0, 1000);
+    round_to_scale(&(left / right), scale)
+}
+
+/// weight and the leading digit of a number in base 10000 in which PostgreSQL keeps numerics
+fn base_10000_digit(value: &BigDecimal) -> (i64, u32) {
+    let (digits, exponent) = value.abs().as_bigint_and_exponent();
+    let digits = digits.to_string();
+    if digits == "0" {
+        return (0, 0);
+    }
+    let decimal_exponent = digits.len() as i64 - 1 - exponent;
+    let weight = decimal_exponent.div_euclid(4);
+    let leading = (decimal_exponent - weight * 4 + 1) as usize;
+    let digit = format!("{:0<width$}", &digits[..leading.min(digits.len())], width = leading);
+    (weight, digit.parse().unwrap())
+}
+
+fn eval_power(base: f64, exponent: f64) -> Result<ScalarValue, EvalError> {
+    if base == 0.0 && exponent < 0.0 || base < 0.0 && exponent.fract() != 0.0 {
+        Err(EvalError::InvalidArgumentForPower)
+    } else {
+        float(base.powf(exponent), SqlType::double_precision())
+    }
+}
+
+fn eval_comparison(comparison: Comparison, left: ScalarValue, right: ScalarValue) -> Result<ScalarValue, EvalError> {
+    let parsed_type = |value: &ScalarValue| binary_type(value).or_else(|| json::json_type(value));
+    if let (Some(sql_type), ScalarValue::String(_)) = (parsed_type(&left), &right) {
+        return eval_comparison(comparison, left, cast(right, sql_type)?);
+    }
+    if let (ScalarValue::String(_), Some(sql_type)) = (&left, parsed_type(&right)) {
+        return eval_comparison(comparison, cast(left, sql_type)?, right);
+    }
+    let ordering = match (&left, &right) {
+        (ScalarValue::Null, _) | (_, ScalarValue::Null) => return Ok(ScalarValue::Null),
+        (ScalarValue::String(l), ScalarValue::String(r)) => l.cmp(r),
+        (ScalarValue::Bytea(l), ScalarValue::Bytea(r)) => l.cmp(r),
+        (ScalarValue::Uuid(l), ScalarValue::Uuid(r)) => l.cmp(r),
+        (ScalarValue::Jsonb(l), ScalarValue::Jsonb(r)) => l.cmp(r),
+        _ if temporal::temporal_type(&left).is_some() || temporal::temporal_type(&right).is_some() => {
+            match temporal::compare(&left, &right)? {
+                Some(ordering) => ordering,
+                None => return Err(undefined_function(Operation::Comparison(comparison), &left, &right)),
+            }
+        }
+        _ => match (as_bool(&left), as_bool(&right)) {
+            (Some(l), Some(r)) => l.cmp(&r),
+            _ => match (as_integer(&left), as_integer(&right)) {
+                (Some(l), Some(r)) => l.cmp(&r),
+                _ => match (as_numeric(&left), as_numeric(&right)) {
+                    (Some(l), Some(r)) => l.cmp(&r),
+                    _ => match (as_float(&left), as_float(&right)) {
+                        (Some(l), Some(r)) => OrderedFloat(l).cmp(&OrderedFloat(r)),
+                        _ => return Err(undefined_function(Operation::Comparison(comparison), &left, &right)),
+                    },
+                },
+            },
+        },
+    };
+    Ok(from_bool(match comparison {
+        Comparison::Eq => ordering == Ordering::Equal,
+        Comparison::NotEq => ordering != Ordering::Equal,
+        Comparison::Lt => ordering == Ordering::Less,
+        Comparison::LtEq => ordering != Ordering::Greater,
+        Comparison::Gt => ordering == Ordering::Greater,
+        Comparison::GtEq => ordering != Ordering::Less,
+    }))
+}
+
+/// shift operations keep type of the left operand and use only lower bits
+/// of the right operand as a shift amount
+fn eval_bitwise(bitwise: Bitwise, left: ScalarValue, right: ScalarValue) -> Result<ScalarValue, EvalError> {
+    check_operands(Operation::Bitwise(bitwise), &left, &right, is_integer)?;
+    let (l, r) = match (as_integer(&left), as_integer(&right)) {
+        (Some(l), Some(r)) => (l, r),
+        _ => return Ok(ScalarValue::Null),
+    };
+    match bitwise {
+        Bitwise::And => integer(l & r, integer_result_type(&left, &right)),
+        Bitwise::Or => integer(l | r, integer_result_type(&left, &right)),
+        Bitwise::Xor => integer(l ^ r, integer_result_type(&left, &right)),
+        Bitwise::ShiftLeft | Bitwise::ShiftRight => match left {
+            ScalarValue::Int64(_) if bitwise == Bitwise::ShiftLeft => Ok(ScalarValue::Int64(l.wrapping_shl(r as u32))),
+            ScalarValue::Int64(_) => Ok(ScalarValue::Int64(l.wrapping_shr(r as u32))),
+            _ if bitwise == Bitwise::ShiftLeft => Ok(ScalarValue::Int32((l as i32).wrapping_shl(r as u32))),
+            _ => Ok(ScalarValue::Int32((l as i32).wrapping_shr(r as u32))),
+        },
+    }
+}
+
+fn eval_logical(logical: Logical, left: ScalarValue, right: ScalarValue) -> Result<ScalarValue, EvalError> {
+    check_operands(Operation::Logical(logical), &left, &right, |value| {
+        as_bool(value).is_some()
+    })?;
+    Ok(match (logical, left, right) {
+        (Logical::And, ScalarValue::False, _) | (Logical::And, _, ScalarValue::False) => ScalarValue::False,
+        (Logical::And, ScalarValue::True, ScalarValue::True) => ScalarValue::True,
+        (Logical::Or, ScalarValue::True, _) | (Logical::Or, _, ScalarValue::True) => ScalarValue::True,
+        (Logical::Or, ScalarValue::False, ScalarValue::False) => ScalarValue::False,
+        _ => ScalarValue::Null,
+    })
+}
+
+fn eval_pattern_matching(
+    pattern_matching: PatternMatching,
+    left: ScalarValue,
+    right: ScalarValue,
+) -> Result<ScalarValue, EvalError> {
+    check_operands(Operation::PatternMatching(pattern_matching), &left, &right, is_string)?;
+    match (left, right) {
+        (ScalarValue::String(value), ScalarValue::String(pattern)) => Ok(from_bool(
+            like(&value, &pattern) == (pattern_matching == PatternMatching::Like),
+        )),
+        _ => Ok(ScalarValue::Null),
+    }
+}
+
+/// non string operand is converted to its text representation
+fn eval_concat(left: ScalarValue, right: ScalarValue) -> Result<ScalarValue, EvalError> {
+    match (left, right) {
+        (ScalarValue::Null, _) | (_, ScalarValue::Null) => Ok(ScalarValue::Null),
+        (left, right) if is_string(&left) || is_string(&right) => {
+            Ok(ScalarValue::String(text(left) + text(right).as_str()))
+        }
+        (left, right) => Err(undefined_function(Operation::StringOp(StringOp::Concat), &left, &right)),
+    }
+}
+
+enum PatternToken {
+    Char(char),
+    AnyChar,
+    AnySequence,
+}
+
+/// `%` matches any sequence of characters, `_` matches any single character
+/// and `\` escapes the following pattern character
+fn like(value: &str, pattern: &str) -> bool {
+    let mut tokens = vec![];
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '%' => PatternToken::AnySequence,
+            '_' => PatternToken::AnyChar,
+            '\\' => PatternToken::Char(chars.next().unwrap_or('\\')),
+            c => PatternToken::Char(c),
+        });
+    }
+    let value = value.chars().collect::<Vec<char>>();
+    let mut value_index = 0;
+    let mut token_index = 0;
+    let mut last_sequence: Option<(usize, usize)> = None;
+    while value_index < value.len() {
+        match tokens.get(token_index) {
+            Some(PatternToken::AnySequence) => {
+                token_index += 1;
+                last_sequence = Some((token_index, value_index));
+            }
+            Some(PatternToken::AnyChar) => {
+                token_index += 1;
+                value_index += 1;
+            }
+            Some(PatternToken::Char(c)) if *c == value[value_index] => {
+                token_index += 1;
+                value_index += 1;
+            }
+            _ => match last_sequence {
+                Some((sequence_token_index, sequence_value_index)) => {
+                    token_index = sequence_token_index;
+                    value_index = sequence_value_index + 1;
+                    last_sequence = Some((sequence_token_index, value_index));
+                }
+                None => return false,
+            },
+        }
+    }
+    tokens[token_index..]
+        .iter()
+        .all(|token| matches!(token, PatternToken::AnySequence))
+}
+
+/// `NULL` is accepted as operand of any operation
+fn check_operands(
+    op: Operation,
+    left: &ScalarValue,
+    right: &ScalarValue,
+    accepted: fn(&ScalarValue) -> bool,
+) -> Result<(), EvalError> {
+    if (*left == ScalarValue::Null || accepted(left)) && (*right == ScalarValue::Null || accepted(right)) {
+        Ok(())
+    } else {
+        Err(undefined_function(op, left, right))
+    }
+}
+
+fn undefined_function(op: Operation, left: &ScalarValue, right: &ScalarValue) -> EvalError {
+    EvalError::UndefinedFunction {
+        op,
+        left: type_name(left),
+        right: type_name(right),
+    }
+}
+
+fn type_name(value: &ScalarValue) -> &'static str {
+    match value {
+        ScalarValue::Null => "unknown",
+        ScalarValue::True | ScalarValue::False => "boolean",
+        ScalarValue::Int16(_) => "smallint",
+        ScalarValue::Int32(_) => "integer",
+        ScalarValue::Int64(_) => "bigint",
+        ScalarValue::Float32(_) => "real",
+        ScalarValue::Float64(_) => "double precision",
+        ScalarValue::Numeric(_) => "numeric",
+        ScalarValue::String(_) => "text",
+        ScalarValue::Date(_) => "date",
+        ScalarValue::Time(_) => "time without time zone",
+        ScalarValue::Timestamp(_) => "timestamp without time zone",
+        ScalarValue::TimestampTz(_) => "timestamp with time zone",
+        ScalarValue::Interval(_) => "interval",
+        ScalarValue::Bytea(_) => "bytea",
+        ScalarValue::Uuid(_) => "uuid",
+        ScalarValue::Json(_) => "json",
+        ScalarValue::Jsonb(_) => "jsonb",
+    }
+}
+
+fn integer_result_type(left: &ScalarValue, right: &ScalarValue) -> SqlType {
+    match (left, right) {
+        (ScalarValue::Int64(_), _) | (_, ScalarValue::Int64(_)) => SqlType::big_int(),
+        _ => SqlType::integer(),
+    }
+}
+
+fn float_result_type(left: &ScalarValue, right: &ScalarValue) -> SqlType {
+    match (left, right) {
+        (ScalarValue::Float64(_), _)
+        | (_, ScalarValue::Float64(_))
+        | (ScalarValue::Numeric(_), _)
+        | (_, ScalarValue::Numeric(_)) => SqlType::double_precision(),
+        _ => SqlType::real(),
+    }
+}
+
+fn integer(value: i64, sql_type: SqlType) -> Result<ScalarValue, EvalError> {
+    if sql_type == SqlType::big_int() {
+        Ok(ScalarValue::Int64(value))
+    } else {
+        i32::try_from(value)
+            .map(ScalarValue::Int32)
+            .map_err(|_| EvalError::OutOfRange(sql_type))
+    }
+}
+
+fn float(value: f64, sql_type: SqlType) -> Result<ScalarValue, EvalError> {
+    if sql_type == SqlType::real() && (value as f32).is_infinite() || value.is_infinite() {
+        Err(EvalError::OutOfRange(sql_type))
+    } else {
+        Ok(float_value(value, sql_type))
+    }
+}
+
+/// operations over infinite operands are not checked for overflow
+fn float_value(value: f64, sql_type: SqlType) -> ScalarValue {
+    if sql_type == SqlType::real() {
+        ScalarValue::Float32(OrderedFloat(value as f32))
+    } else {
+        ScalarValue::Float64(OrderedFloat(value))
+    }
+}
+
+fn from_bool(value: bool) -> ScalarValue {
+    if value {
+        ScalarValue::True
+    } else {
+        ScalarValue::False
+    }
+}
+
+fn text(value: ScalarValue) -> String {
+    match value {
+        ScalarValue::True => "true".to_owned(),
+        ScalarValue::False => "false".to_owned(),
+        other => other.as_text(),
+    }
+}
+
+fn as_bool(value: &ScalarValue) -> Option<bool> {
+    match value {
+        ScalarValue::True => Some(true),
+        ScalarValue::False => Some(false),
+        _ => None,
+    }
+}
+
+fn as_integer(value: &ScalarValue) -> Option<i64> {
+    match value {
+        ScalarValue::Int16(value) => Some(*value as i64),
+        ScalarValue::Int32(value) => Some(*value as i64),
+        ScalarValue::Int64(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn as_float(value: &ScalarValue) -> Option<f64> {
+    match value {
+        // widened through the shortest representation so `real` 0.1 is equal to `double precision` 0.1
+        ScalarValue::Float32(value) => value.to_string().parse().ok(),
+        ScalarValue::Float64(value) => Some(value.into_inner()),
+        ScalarValue::Numeric(value) => value.to_f64(),
+        _ => as_integer(value).map(|value| value as f64),
+    }
+}
+
+/// integers and numerics, which are compared and computed exactly
+fn as_numeric(value: &ScalarValue) -> Option<BigDecimal> {
+    match value {
+        ScalarValue::Numeric(value) => Some(value.clone()),
+        _ => as_integer(value).map(BigDecimal::from),
+    }
+}
+
+fn is_number(value: &ScalarValue) -> bool {
+    as_float(value).is_some()
+}
+
+fn is_integer(value: &ScalarValue) -> bool {
+    as_integer(value).is_some()
+}
+
+fn is_string(value: &ScalarValue) -> bool {
+    matches!(value, ScalarValue::String(_))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,477 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{as_float, as_integer, undefined_function, EvalError};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use data_manipulation_operators::{Arithmetic, Operation};
+use data_manipulation_untyped_tree::ImplicitCastError;
+use data_scalar::{
+    temporal::{self, Interval, MICROS_PER_DAY, MICROS_PER_SECOND},
+    ScalarValue,
+};
+use std::{cmp::Ordering, convert::TryFrom};
+use types::{SqlType, Temporal};
+
+pub(crate) fn temporal_type(value: &ScalarValue) -> Option<Temporal> {
+    match value {
+        ScalarValue::Date(_) => Some(Temporal::Date),
+        ScalarValue::Time(_) => Some(Temporal::Time),
+        ScalarValue::Timestamp(_) => Some(Temporal::Timestamp),
+        ScalarValue::TimestampTz(_) => Some(Temporal::TimestampTz),
+        ScalarValue::Interval(_) => Some(Temporal::Interval),
+        _ => None,
+    }
+}
+
+/// strings are parsed, dates and timestamps are converted into each other
+/// and times could be converted into intervals
+pub(crate) fn cast(value: ScalarValue, target: Temporal) -> Result<ScalarValue, EvalError> {
+    let sql_type = SqlType::Temporal(target);
+    let invalid = |text: &str| EvalError::Cast(ImplicitCastError::invalid_input_syntax_for_type(sql_type, text));
+    match (value, target) {
+        (ScalarValue::Null, _) => Ok(ScalarValue::Null),
+        (ScalarValue::String(text), Temporal::Date) => temporal::parse_date(&text)
+            .map(ScalarValue::Date)
+            .ok_or_else(|| invalid(&text)),
+        (ScalarValue::String(text), Temporal::Time) => temporal::parse_time(&text)
+            .map(ScalarValue::Time)
+            .ok_or_else(|| invalid(&text)),
+        (ScalarValue::String(text), Temporal::Timestamp) => temporal::parse_timestamp(&text)
+            .map(ScalarValue::Timestamp)
+            .ok_or_else(|| invalid(&text)),
+        (ScalarValue::String(text), Temporal::TimestampTz) => temporal::parse_timestamp_tz(&text)
+            .map(ScalarValue::TimestampTz)
+            .ok_or_else(|| invalid(&text)),
+        (ScalarValue::String(text), Temporal::Interval) => temporal::parse_interval(&text)
+            .map(ScalarValue::Interval)
+            .ok_or_else(|| invalid(&text)),
+        (ScalarValue::Date(date), Temporal::Date) => Ok(ScalarValue::Date(date)),
+        (ScalarValue::Date(date), Temporal::Timestamp) => Ok(ScalarValue::Timestamp(date.and_hms(0, 0, 0))),
+        (ScalarValue::Date(date), Temporal::TimestampTz) => Ok(ScalarValue::TimestampTz(utc(date.and_hms(0, 0, 0)))),
+        (ScalarValue::Timestamp(timestamp), Temporal::Date) => Ok(ScalarValue::Date(timestamp.date())),
+        (ScalarValue::Timestamp(timestamp), Temporal::Time) => Ok(ScalarValue::Time(timestamp.time())),
+        (ScalarValue::Timestamp(timestamp), Temporal::Timestamp) => Ok(ScalarValue::Timestamp(timestamp)),
+        (ScalarValue::Timestamp(timestamp), Temporal::TimestampTz) => Ok(ScalarValue::TimestampTz(utc(timestamp))),
+        (ScalarValue::TimestampTz(timestamp), Temporal::Date) => Ok(ScalarValue::Date(timestamp.naive_utc().date())),
+        (ScalarValue::TimestampTz(timestamp), Temporal::Time) => Ok(ScalarValue::Time(timestamp.naive_utc().time())),
+        (ScalarValue::TimestampTz(timestamp), Temporal::Timestamp) => Ok(ScalarValue::Timestamp(timestamp.naive_utc())),
+        (ScalarValue::TimestampTz(timestamp), Temporal::TimestampTz) => Ok(ScalarValue::TimestampTz(timestamp)),
+        (ScalarValue::Time(time), Temporal::Time) => Ok(ScalarValue::Time(time)),
+        (ScalarValue::Time(time), Temporal::Interval) => {
+            Ok(ScalarValue::Interval(Interval::new(0, 0, micros_of(time))))
+        }
+        (ScalarValue::Interval(interval), Temporal::Interval) => Ok(ScalarValue::Interval(interval)),
+        (value, _) => match temporal_type(&value) {
+            Some(source) => Err(EvalError::Cast(ImplicitCastError::datatype_mismatch(
+                sql_type,
+                SqlType::Temporal(source),
+            ))),
+            None => unreachable!("only strings, dates, times and intervals are cast into {}", sql_type),
+        },
+    }
+}
+
+/// arithmetic over dates, times and intervals as in PostgreSQL: dates are shifted by
+/// a number of days, intervals are added to timestamps month by month, day by day
+/// and differences of timestamps are intervals of days and time
+pub(crate) fn eval_arithmetic(
+    arithmetic: Arithmetic,
+    left: ScalarValue,
+    right: ScalarValue,
+) -> Result<ScalarValue, EvalError> {
+    let out_of_range = |temporal: Temporal| EvalError::OutOfRange(SqlType::Temporal(temporal));
+    match (arithmetic, &left, &right) {
+        (_, ScalarValue::Null, _) | (_, _, ScalarValue::Null) => Ok(ScalarValue::Null),
+        (Arithmetic::Add, ScalarValue::Date(date), other) | (Arithmetic::Add, other, ScalarValue::Date(date))
+            if as_integer(other).is_some() =>
+        {
+            shift_date(*date, as_integer(other).unwrap())
+        }
+        (Arithmetic::Sub, ScalarValue::Date(date), other) if as_integer(other).is_some() => {
+            shift_date(*date, -as_integer(other).unwrap())
+        }
+        (Arithmetic::Sub, ScalarValue::Date(left), ScalarValue::Date(right)) => {
+            Ok(ScalarValue::Int32(left.signed_duration_since(*right).num_days() as i32))
+        }
+        (Arithmetic::Add, ScalarValue::Date(date), ScalarValue::Time(time))
+        | (Arithmetic::Add, ScalarValue::Time(time), ScalarValue::Date(date)) => {
+            Ok(ScalarValue::Timestamp(date.and_time(*time)))
+        }
+        (Arithmetic::Add, ScalarValue::Date(date), ScalarValue::Interval(interval))
+        | (Arithmetic::Add, ScalarValue::Interval(interval), ScalarValue::Date(date)) => {
+            add_interval(date.and_hms(0, 0, 0), *interval)
+                .map(ScalarValue::Timestamp)
+                .ok_or_else(|| out_of_range(Temporal::Timestamp))
+        }
+        (Arithmetic::Sub, ScalarValue::Date(date), ScalarValue::Interval(interval)) => interval
+            .checked_neg()
+            .and_then(|interval| add_interval(date.and_hms(0, 0, 0), interval))
+            .map(ScalarValue::Timestamp)
+            .ok_or_else(|| out_of_range(Temporal::Timestamp)),
+        (Arithmetic::Add, ScalarValue::Timestamp(timestamp), ScalarValue::Interval(interval))
+        | (Arithmetic::Add, ScalarValue::Interval(interval), ScalarValue::Timestamp(timestamp)) => {
+            add_interval(*timestamp, *interval)
+                .map(ScalarValue::Timestamp)
+                .ok_or_else(|| out_of_range(Temporal::Timestamp))
+        }
+        (Arithmetic::Sub, ScalarValue::Timestamp(timestamp), ScalarValue::Interval(interval)) => interval
+            .checked_neg()
+            .and_then(|interval| add_interval(*timestamp, interval))
+            .map(ScalarValue::Timestamp)
+            .ok_or_else(|| out_of_range(Temporal::Timestamp)),
+        (Arithmetic::Add, ScalarValue::TimestampTz(timestamp), ScalarValue::Interval(interval))
+        | (Arithmetic::Add, ScalarValue::Interval(interval), ScalarValue::TimestampTz(timestamp)) => {
+            add_interval(timestamp.naive_utc(), *interval)
+                .map(|timestamp| ScalarValue::TimestampTz(utc(timestamp)))
+                .ok_or_else(|| out_of_range(Temporal::TimestampTz))
+        }
+        (Arithmetic::Sub, ScalarValue::TimestampTz(timestamp), ScalarValue::Interval(interval)) => interval
+            .checked_neg()
+            .and_then(|interval| add_interval(timestamp.naive_utc(), interval))
+            .map(|timestamp| ScalarValue::TimestampTz(utc(timestamp)))
+            .ok_or_else(|| out_of_range(Temporal::TimestampTz)),
+        (Arithmetic::Add, ScalarValue::Time(time), ScalarValue::Interval(interval))
+        | (Arithmetic::Add, ScalarValue::Interval(interval), ScalarValue::Time(time)) => {
+            Ok(ScalarValue::Time(shift_time(*time, interval.micros % MICROS_PER_DAY)))
+        }
+        (Arithmetic::Sub, ScalarValue::Time(time), ScalarValue::Interval(interval)) => Ok(ScalarValue::Time(
+            shift_time(*time, -(interval.micros % MICROS_PER_DAY)),
+        )),
+        (Arithmetic::Sub, ScalarValue::Timestamp(left), ScalarValue::Timestamp(right)) => difference(*left, *right)
+            .map(ScalarValue::Interval)
+            .ok_or_else(|| out_of_range(Temporal::Interval)),
+        (Arithmetic::Sub, ScalarValue::TimestampTz(left), ScalarValue::TimestampTz(right)) => {
+            difference(left.naive_utc(), right.naive_utc())
+                .map(ScalarValue::Interval)
+                .ok_or_else(|| out_of_range(Temporal::Interval))
+        }
+        (Arithmetic::Sub, ScalarValue::Time(left), ScalarValue::Time(right)) => Ok(ScalarValue::Interval(
+            Interval::new(0, 0, micros_of(*left) - micros_of(*right)),
+        )),
+        (Arithmetic::Add, ScalarValue::Interval(left), ScalarValue::Interval(right)) => left
+            .checked_add(right)
+            .map(ScalarValue::Interval)
+            .ok_or_else(|| out_of_range(Temporal::Interval)),
+        (Arithmetic::Sub, ScalarValue::Interval(left), ScalarValue::Interval(right)) => right
+            .checked_neg()
+            .and_then(|right| left.checked_add(&right))
+            .map(ScalarValue::Interval)
+            .ok_or_else(|| out_of_range(Temporal::Interval)),
+        (Arithmetic::Mul, ScalarValue::Interval(interval), factor)
+        | (Arithmetic::Mul, factor, ScalarValue::Interval(interval))
+            if as_float(factor).is_some() =>
+        {
+            interval
+                .checked_mul(as_float(factor).unwrap())
+                .map(ScalarValue::Interval)
+                .ok_or_else(|| out_of_range(Temporal::Interval))
+        }
+        (Arithmetic::Div, ScalarValue::Interval(interval), divisor) if as_float(divisor).is_some() => {
+            let divisor = as_float(divisor).unwrap();
+            if divisor == 0.0 {
+                Err(EvalError::DivisionByZero)
+            } else {
+                interval
+                    .checked_mul(1.0 / divisor)
+                    .map(ScalarValue::Interval)
+                    .ok_or_else(|| out_of_range(Temporal::Interval))
+            }
+        }
+        _ => Err(undefined_function(Operation::Arithmetic(arithmetic), &left, &right)),
+    }
+}
+
+/// strings are parsed into the type of the other operand, dates are compared
+/// with timestamps as midnights and timestamps without time zone as if they are in UTC
+pub(crate) fn compare(left: &ScalarValue, right: &ScalarValue) -> Result<Option<Ordering>, EvalError> {
+    let left = match (left, temporal_type(right)) {
+        (ScalarValue::String(_), Some(target)) => cast(left.clone(), target)?,
+        _ => left.clone(),
+    };
+    let right = match (right, temporal_type(&left)) {
+        (ScalarValue::String(_), Some(target)) => cast(right.clone(), target)?,
+        _ => right.clone(),
+    };
+    Ok(match (&left, &right) {
+        (ScalarValue::Time(left), ScalarValue::Time(right)) => Some(left.cmp(right)),
+        (ScalarValue::Interval(left), ScalarValue::Interval(right)) => Some(left.cmp(right)),
+        _ => match (as_timestamp(&left), as_timestamp(&right)) {
+            (Some(left), Some(right)) => Some(left.cmp(&right)),
+            _ => None,
+        },
+    })
+}
+
+/// current time truncated to microseconds that are kept in storage
+pub(crate) fn now() -> DateTime<Utc> {
+    let now = Utc::now();
+    now.with_nanosecond(now.nanosecond() / 1_000 * 1_000).unwrap()
+}
+
+/// fields of dates and timestamps are extracted as of a timestamp at midnight
+pub(crate) fn date_part(field: &str, source: &ScalarValue) -> Result<ScalarValue, EvalError> {
+    let part = unit(field).and_then(|unit| match source {
+        ScalarValue::Date(date) => timestamp_part(unit, date.and_hms(0, 0, 0)),
+        ScalarValue::Timestamp(timestamp) => timestamp_part(unit, *timestamp),
+        ScalarValue::TimestampTz(timestamp) => timestamp_part(unit, timestamp.naive_utc()),
+        ScalarValue::Time(time) => time_part(unit, *time),
+        ScalarValue::Interval(interval) => interval_part(unit, *interval),
+        _ => unreachable!("date_part argument {:?} is checked during type check", source),
+    });
+    part.map(|part| ScalarValue::Float64(part.into()))
+        .ok_or_else(|| units_not_recognized(field, source))
+}
+
+/// dates are truncated as timestamps with time zone
+pub(crate) fn date_trunc(field: &str, source: &ScalarValue) -> Result<ScalarValue, EvalError> {
+    let truncated =
+        unit(field).and_then(|unit| match source {
+            ScalarValue::Date(date) => truncate_timestamp(unit, date.and_hms(0, 0, 0))
+                .map(|timestamp| ScalarValue::TimestampTz(utc(timestamp))),
+            ScalarValue::Timestamp(timestamp) => truncate_timestamp(unit, *timestamp).map(ScalarValue::Timestamp),
+            ScalarValue::TimestampTz(timestamp) => truncate_timestamp(unit, timestamp.naive_utc())
+                .map(|timestamp| ScalarValue::TimestampTz(utc(timestamp))),
+            ScalarValue::Interval(interval) => truncate_interval(unit, *interval).map(ScalarValue::Interval),
+            _ => unreachable!("date_trunc argument {:?} is checked during type check", source),
+        });
+    truncated.ok_or_else(|| units_not_recognized(field, source))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Unit {
+    Microseconds,
+    Milliseconds,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    Decade,
+    Century,
+    Millennium,
+    Dow,
+    Isodow,
+    Doy,
+    Epoch,
+}
+
+fn unit(field: &str) -> Option<Unit> {
+    match field.trim().to_lowercase().as_str() {
+        "microsecond" | "microseconds" | "us" | "usec" | "usecs" => Some(Unit::Microseconds),
+        "millisecond" | "milliseconds" | "ms" | "msec" | "msecs" => Some(Unit::Milliseconds),
+        "second" | "seconds" | "sec" | "secs" | "s" => Some(Unit::Second),
+        "minute" | "minutes" | "min" | "mins" | "m" => Some(Unit::Minute),
+        "hour" | "hours" | "hr" | "hrs" | "h" => Some(Unit::Hour),
+        "day" | "days" | "d" => Some(Unit::Day),
+        "week" | "weeks" | "w" => Some(Unit::Week),
+        "month" | "months" | "mon" | "mons" => Some(Unit::Month),
+        "quarter" | "qtr" => Some(Unit::Quarter),
+        "year" | "years" | "yr" | "yrs" | "y" => Some(Unit::Year),
+        "decade" | "decades" | "dec" => Some(Unit::Decade),
+        "century" | "centuries" | "c" => Some(Unit::Century),
+        "millennium" | "millennia" | "mil" => Some(Unit::Millennium),
+        "dow" => Some(Unit::Dow),
+        "isodow" => Some(Unit::Isodow),
+        "doy" => Some(Unit::Doy),
+        "epoch" => Some(Unit::Epoch),
+        _ => None,
+    }
+}
+
+fn units_not_recognized(field: &str, source: &ScalarValue) -> EvalError {
+    let type_name = match source {
+        ScalarValue::TimestampTz(_) => "timestamp with time zone",
+        ScalarValue::Time(_) => "\"time\"",
+        ScalarValue::Interval(_) => "interval",
+        _ => "timestamp",
+    };
+    EvalError::InvalidParameterValue(format!("{} units \"{}\" not recognized", type_name, field))
+}
+
+fn timestamp_part(unit: Unit, timestamp: NaiveDateTime) -> Option<f64> {
+    let date = timestamp.date();
+    let year = date.year();
+    match unit {
+        Unit::Microseconds | Unit::Milliseconds | Unit::Second | Unit::Minute | Unit::Hour => {
+            time_part(unit, timestamp.time())
+        }
+        Unit::Day => Some(date.day() as f64),
+        Unit::Week => Some(date.iso_week().week() as f64),
+        Unit::Month => Some(date.month() as f64),
+        Unit::Quarter => Some((date.month0() / 3 + 1) as f64),
+        Unit::Year => Some(year as f64),
+        Unit::Decade => Some(year.div_euclid(10) as f64),
+        Unit::Century => Some(((year - 1).div_euclid(100) + 1) as f64),
+        Unit::Millennium => Some(((year - 1).div_euclid(1_000) + 1) as f64),
+        Unit::Dow => Some(date.weekday().num_days_from_sunday() as f64),
+        Unit::Isodow => Some(date.weekday().number_from_monday() as f64),
+        Unit::Doy => Some(date.ordinal() as f64),
+        Unit::Epoch => Some(timestamp.timestamp() as f64 + timestamp.timestamp_subsec_micros() as f64 / 1e6),
+    }
+}
+
+fn time_part(unit: Unit, time: NaiveTime) -> Option<f64> {
+    let micros = micros_of(time);
+    let micros_of_minute = micros % (60 * MICROS_PER_SECOND);
+    match unit {
+        Unit::Microseconds => Some(micros_of_minute as f64),
+        Unit::Milliseconds => Some(micros_of_minute as f64 / 1e3),
+        Unit::Second => Some(micros_of_minute as f64 / 1e6),
+        Unit::Minute => Some(time.minute() as f64),
+        Unit::Hour => Some(time.hour() as f64),
+        Unit::Epoch => Some(micros as f64 / 1e6),
+        _ => None,
+    }
+}
+
+/// epoch of intervals counts years as 365.25 days and months as 30 days
+fn interval_part(unit: Unit, interval: Interval) -> Option<f64> {
+    let micros_of_minute = interval.micros % (60 * MICROS_PER_SECOND);
+    let years = interval.months / 12;
+    match unit {
+        Unit::Microseconds => Some(micros_of_minute as f64),
+        Unit::Milliseconds => Some(micros_of_minute as f64 / 1e3),
+        Unit::Second => Some(micros_of_minute as f64 / 1e6),
+        Unit::Minute => Some((interval.micros / (60 * MICROS_PER_SECOND) % 60) as f64),
+        Unit::Hour => Some((interval.micros / (3_600 * MICROS_PER_SECOND)) as f64),
+        Unit::Day => Some(interval.days as f64),
+        Unit::Month => Some((interval.months % 12) as f64),
+        Unit::Quarter => Some((interval.months % 12 / 3 + 1) as f64),
+        Unit::Year => Some(years as f64),
+        Unit::Decade => Some((years / 10) as f64),
+        Unit::Century => Some((years / 100) as f64),
+        Unit::Millennium => Some((years / 1_000) as f64),
+        Unit::Epoch => Some(
+            years as f64 * 365.25 * 86_400.0
+                + (interval.months % 12) as f64 * 30.0 * 86_400.0
+                + interval.days as f64 * 86_400.0
+                + interval.micros as f64 / 1e6,
+        ),
+        Unit::Week | Unit::Dow | Unit::Isodow | Unit::Doy => None,
+    }
+}
+
+fn truncate_timestamp(unit: Unit, timestamp: NaiveDateTime) -> Option<NaiveDateTime> {
+    let date = timestamp.date();
+    let year = date.year();
+    let micros = micros_of(timestamp.time());
+    let time = |unit_micros: i64| Some(date.and_time(time_of(micros / unit_micros * unit_micros)));
+    let start_of = |year: i32, month: u32| NaiveDate::from_ymd_opt(year, month, 1).map(|date| date.and_hms(0, 0, 0));
+    match unit {
+        Unit::Microseconds => Some(timestamp),
+        Unit::Milliseconds => time(1_000),
+        Unit::Second => time(MICROS_PER_SECOND),
+        Unit::Minute => time(60 * MICROS_PER_SECOND),
+        Unit::Hour => time(3_600 * MICROS_PER_SECOND),
+        Unit::Day => time(MICROS_PER_DAY),
+        Unit::Week => date
+            .checked_sub_signed(Duration::days(date.weekday().num_days_from_monday() as i64))
+            .map(|date| date.and_hms(0, 0, 0)),
+        Unit::Month => start_of(year, date.month()),
+        Unit::Quarter => start_of(year, date.month0() / 3 * 3 + 1),
+        Unit::Year => start_of(year, 1),
+        Unit::Decade => start_of(year - year.rem_euclid(10), 1),
+        Unit::Century => start_of((year - 1).div_euclid(100) * 100 + 1, 1),
+        Unit::Millennium => start_of((year - 1).div_euclid(1_000) * 1_000 + 1, 1),
+        Unit::Dow | Unit::Isodow | Unit::Doy | Unit::Epoch => None,
+    }
+}
+
+fn truncate_interval(unit: Unit, interval: Interval) -> Option<Interval> {
+    let time = |unit_micros: i64| {
+        Some(Interval::new(
+            interval.months,
+            interval.days,
+            interval.micros / unit_micros * unit_micros,
+        ))
+    };
+    let months = |unit_months: i32| Some(Interval::new(interval.months / unit_months * unit_months, 0, 0));
+    match unit {
+        Unit::Microseconds => Some(interval),
+        Unit::Milliseconds => time(1_000),
+        Unit::Second => time(MICROS_PER_SECOND),
+        Unit::Minute => time(60 * MICROS_PER_SECOND),
+        Unit::Hour => time(3_600 * MICROS_PER_SECOND),
+        Unit::Day => Some(Interval::new(interval.months, interval.days, 0)),
+        Unit::Month => months(1),
+        Unit::Quarter => months(3),
+        Unit::Year => months(12),
+        Unit::Decade => months(120),
+        Unit::Century => months(1_200),
+        Unit::Millennium => months(12_000),
+        Unit::Week | Unit::Dow | Unit::Isodow | Unit::Doy | Unit::Epoch => None,
+    }
+}
+
+fn shift_date(date: NaiveDate, days: i64) -> Result<ScalarValue, EvalError> {
+    i32::try_from(days)
+        .ok()
+        .and_then(|days| date.checked_add_signed(Duration::days(days as i64)))
+        .map(ScalarValue::Date)
+        .ok_or(EvalError::OutOfRange(SqlType::date()))
+}
+
+/// months are added first keeping the day of month unless the resulting month is shorter
+fn add_interval(timestamp: NaiveDateTime, interval: Interval) -> Option<NaiveDateTime> {
+    let months = timestamp.year() as i64 * 12 + timestamp.month0() as i64 + interval.months as i64;
+    let year = i32::try_from(months.div_euclid(12)).ok()?;
+    let month = months.rem_euclid(12) as u32 + 1;
+    let date = (1..=timestamp.day())
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))?;
+    date.and_time(timestamp.time())
+        .checked_add_signed(Duration::days(interval.days as i64))?
+        .checked_add_signed(Duration::microseconds(interval.micros))
+}
+
+/// whole days of a difference are not converted into months
+fn difference(left: NaiveDateTime, right: NaiveDateTime) -> Option<Interval> {
+    let micros = left.signed_duration_since(right).num_microseconds()?;
+    Some(Interval::new(
+        0,
+        (micros / MICROS_PER_DAY) as i32,
+        micros % MICROS_PER_DAY,
+    ))
+}
+
+fn shift_time(time: NaiveTime, micros: i64) -> NaiveTime {
+    time_of((micros_of(time) + micros).rem_euclid(MICROS_PER_DAY))
+}
+
+fn micros_of(time: NaiveTime) -> i64 {
+    time.num_seconds_from_midnight() as i64 * MICROS_PER_SECOND + time.nanosecond() as i64 / 1_000
+}
+
+fn time_of(micros: i64) -> NaiveTime {
+    NaiveTime::from_num_seconds_from_midnight(
+        (micros / MICROS_PER_SECOND) as u32,
+        (micros % MICROS_PER_SECOND * 1_000) as u32,
+    )
+}
+
+fn as_timestamp(value: &ScalarValue) -> Option<NaiveDateTime> {
+    match value {
+        ScalarValue::Date(date) => Some(date.and_hms(0, 0, 0)),
+        ScalarValue::Timestamp(timestamp) => Some(*timestamp),
+        ScalarValue::TimestampTz(timestamp) => Some(timestamp.naive_utc()),
+        _ => None,
+    }
+}
+
+fn utc(timestamp: NaiveDateTime) -> DateTime<Utc> {
+    DateTime::from_utc(timestamp, Utc)
+}
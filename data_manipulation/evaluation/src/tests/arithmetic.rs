@@ -0,0 +1,195 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn add(left: Box<StaticTypedTree>, right: Box<StaticTypedTree>) -> Result<ScalarValue, EvalError> {
+    eval(left, Operation::Arithmetic(Arithmetic::Add), right)
+}
+
+#[test]
+fn integers_are_computed_at_least_as_integer() {
+    assert_eq!(
+        add(small_int(i16::MAX), small_int(1)),
+        Ok(ScalarValue::Int32(i16::MAX as i32 + 1))
+    );
+}
+
+#[test]
+fn result_has_type_of_the_widest_operand() {
+    assert_eq!(add(small_int(1), big_int(2)), Ok(ScalarValue::Int64(3)));
+    assert_eq!(add(integer(1), real(2.5)), Ok(ScalarValue::Float32(OrderedFloat(3.5))));
+    assert_eq!(add(real(1.5), double(2.5)), Ok(ScalarValue::Float64(OrderedFloat(4.0))));
+}
+
+#[test]
+fn all_operators_on_integers() {
+    assert_eq!(
+        eval(integer(7), Operation::Arithmetic(Arithmetic::Sub), integer(2)),
+        Ok(ScalarValue::Int32(5))
+    );
+    assert_eq!(
+        eval(integer(7), Operation::Arithmetic(Arithmetic::Mul), integer(2)),
+        Ok(ScalarValue::Int32(14))
+    );
+    assert_eq!(
+        eval(integer(7), Operation::Arithmetic(Arithmetic::Div), integer(2)),
+        Ok(ScalarValue::Int32(3))
+    );
+    assert_eq!(
+        eval(integer(-7), Operation::Arithmetic(Arithmetic::Mod), integer(2)),
+        Ok(ScalarValue::Int32(-1))
+    );
+}
+
+#[test]
+fn exponentiation_results_in_double_precision() {
+    assert_eq!(
+        eval(integer(2), Operation::Arithmetic(Arithmetic::Exp), integer(10)),
+        Ok(ScalarValue::Float64(OrderedFloat(1024.0)))
+    );
+}
+
+#[test]
+fn invalid_arguments_of_exponentiation() {
+    assert_eq!(
+        eval(integer(0), Operation::Arithmetic(Arithmetic::Exp), integer(-1)),
+        Err(EvalError::InvalidArgumentForPower)
+    );
+    assert_eq!(
+        eval(integer(-8), Operation::Arithmetic(Arithmetic::Exp), double(0.5)),
+        Err(EvalError::InvalidArgumentForPower)
+    );
+}
+
+#[test]
+fn integer_overflow() {
+    assert_eq!(
+        add(integer(i32::MAX), small_int(1)),
+        Err(EvalError::OutOfRange(SqlType::integer()))
+    );
+    assert_eq!(
+        eval(big_int(i64::MIN), Operation::Arithmetic(Arithmetic::Mul), integer(-1)),
+        Err(EvalError::OutOfRange(SqlType::big_int()))
+    );
+    assert_eq!(
+        eval(integer(i32::MIN), Operation::Arithmetic(Arithmetic::Div), integer(-1)),
+        Err(EvalError::OutOfRange(SqlType::integer()))
+    );
+}
+
+#[test]
+fn float_overflow() {
+    assert_eq!(
+        eval(real(f32::MAX), Operation::Arithmetic(Arithmetic::Mul), real(2.0)),
+        Err(EvalError::OutOfRange(SqlType::real()))
+    );
+    assert_eq!(
+        eval(double(f64::MAX), Operation::Arithmetic(Arithmetic::Mul), double(2.0)),
+        Err(EvalError::OutOfRange(SqlType::double_precision()))
+    );
+}
+
+#[test]
+fn division_by_zero() {
+    assert_eq!(
+        eval(integer(1), Operation::Arithmetic(Arithmetic::Div), integer(0)),
+        Err(EvalError::DivisionByZero)
+    );
+    assert_eq!(
+        eval(integer(1), Operation::Arithmetic(Arithmetic::Mod), small_int(0)),
+        Err(EvalError::DivisionByZero)
+    );
+    assert_eq!(
+        eval(double(1.0), Operation::Arithmetic(Arithmetic::Div), integer(0)),
+        Err(EvalError::DivisionByZero)
+    );
+}
+
+#[test]
+fn null_operand() {
+    assert_eq!(add(integer(1), null()), Ok(ScalarValue::Null));
+    assert_eq!(
+        eval(null(), Operation::Arithmetic(Arithmetic::Div), integer(0)),
+        Ok(ScalarValue::Null)
+    );
+}
+
+#[test]
+fn non_numeric_operand() {
+    assert_eq!(
+        add(integer(1), string("1")),
+        Err(EvalError::UndefinedFunction {
+            op: Operation::Arithmetic(Arithmetic::Add),
+            left: "integer",
+            right: "text"
+        })
+    );
+}
+
+#[test]
+fn infinite_operands() {
+    assert_eq!(
+        add(double(f64::INFINITY), integer(1)),
+        Ok(ScalarValue::Float64(OrderedFloat(f64::INFINITY)))
+    );
+    assert!(matches!(
+        eval(double(f64::INFINITY), Operation::Arithmetic(Arithmetic::Sub), double(f64::INFINITY)),
+        Ok(ScalarValue::Float64(value)) if value.is_nan()
+    ));
+}
+
+#[test]
+fn real_is_widened_by_its_shortest_representation() {
+    assert_eq!(
+        add(real(0.1), double(0.2)),
+        Ok(ScalarValue::Float64(OrderedFloat(0.1 + 0.2)))
+    );
+}
+
+#[test]
+fn numerics_are_computed_exactly() {
+    assert_eq!(add(numeric("0.1"), numeric("0.2")), Ok(numeric_value("0.3")));
+    assert_eq!(add(integer(1), numeric("0.25")), Ok(numeric_value("1.25")));
+    assert_eq!(
+        eval(numeric("1.5"), Operation::Arithmetic(Arithmetic::Mul), numeric("1.5")),
+        Ok(numeric_value("2.25"))
+    );
+    assert_eq!(
+        eval(numeric("5.5"), Operation::Arithmetic(Arithmetic::Mod), integer(2)),
+        Ok(numeric_value("1.5"))
+    );
+    assert_eq!(
+        add(numeric("0.5"), double(0.25)),
+        Ok(ScalarValue::Float64(OrderedFloat(0.75)))
+    );
+}
+
+#[test]
+fn numeric_quotients_are_rounded() {
+    let div = |left, right| eval(left, Operation::Arithmetic(Arithmetic::Div), right);
+    assert_eq!(
+        div(numeric("1.0"), integer(3)).map(ScalarValue::as_text),
+        Ok("0.33333333333333333333".to_owned())
+    );
+    assert_eq!(
+        div(numeric("10.0"), integer(4)).map(ScalarValue::as_text),
+        Ok("2.5000000000000000".to_owned())
+    );
+    assert_eq!(
+        div(numeric("2"), numeric("3.000")).map(ScalarValue::as_text),
+        Ok("0.66666666666666666667".to_owned())
+    );
+    assert_eq!(div(numeric("1"), numeric("0")), Err(EvalError::DivisionByZero));
+}
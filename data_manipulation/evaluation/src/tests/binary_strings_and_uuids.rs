@@ -0,0 +1,128 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+const UUID: &str = "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11";
+
+fn bytea(value: &str) -> Box<StaticTypedTree> {
+    typed(value, SqlType::bytea())
+}
+
+fn uuid(value: &str) -> Box<StaticTypedTree> {
+    typed(value, SqlType::uuid())
+}
+
+fn typed(value: &str, target: SqlType) -> Box<StaticTypedTree> {
+    Box::new(StaticTypedTree::Cast {
+        tree: string(value),
+        target,
+    })
+}
+
+#[test]
+fn cast_strings_to_bytea() {
+    assert_eq!(
+        Evaluation.eval_static(&bytea("\\xDEADbeef")),
+        Ok(ScalarValue::Bytea(vec![0xde, 0xad, 0xbe, 0xef]))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&bytea("a\\\\b\\001")),
+        Ok(ScalarValue::Bytea(vec![b'a', b'\\', b'b', 1]))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&bytea("\\xabc")),
+        Err(EvalError::Cast(ImplicitCastError::invalid_input_syntax_for_type(
+            SqlType::bytea(),
+            "\\xabc"
+        )))
+    );
+}
+
+#[test]
+fn cast_strings_to_uuid() {
+    assert_eq!(
+        Evaluation.eval_static(&uuid(&UUID.to_uppercase())),
+        Ok(ScalarValue::Uuid(Uuid::parse_str(UUID).unwrap()))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&uuid("not-a-uuid")),
+        Err(EvalError::Cast(ImplicitCastError::invalid_input_syntax_for_type(
+            SqlType::uuid(),
+            "not-a-uuid"
+        )))
+    );
+}
+
+#[test]
+fn cast_to_strings() {
+    assert_eq!(
+        Evaluation.eval_static(&StaticTypedTree::Cast {
+            tree: bytea("\\x00ff"),
+            target: SqlType::text()
+        }),
+        Ok(ScalarValue::String("\\x00ff".to_owned()))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&StaticTypedTree::Cast {
+            tree: uuid(UUID),
+            target: SqlType::var_char(10)
+        }),
+        Err(EvalError::Cast(ImplicitCastError::string_data_right_truncation(
+            SqlType::var_char(10)
+        )))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&StaticTypedTree::Cast {
+            tree: uuid(UUID),
+            target: SqlType::bytea()
+        }),
+        Err(EvalError::Cast(ImplicitCastError::datatype_mismatch(
+            SqlType::bytea(),
+            SqlType::uuid()
+        )))
+    );
+}
+
+#[test]
+fn comparison() {
+    assert_eq!(
+        eval(bytea("\\x01"), Operation::Comparison(Comparison::Lt), bytea("\\x0100")),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(uuid(UUID), Operation::Comparison(Comparison::Eq), string(UUID)),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(string("\\x02"), Operation::Comparison(Comparison::Gt), bytea("\\x01")),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(uuid(UUID), Operation::Comparison(Comparison::Eq), null()),
+        Ok(ScalarValue::Null)
+    );
+}
+
+#[test]
+fn gen_random_uuid() {
+    let function = StaticTypedTree::Function {
+        type_family: None,
+        function: ScalarFunction::GenRandomUuid,
+        args: vec![],
+    };
+    let first = Evaluation.eval_static(&function).unwrap();
+    assert!(matches!(first, ScalarValue::Uuid(_)));
+    assert_ne!(Evaluation.eval_static(&function), Ok(first));
+}
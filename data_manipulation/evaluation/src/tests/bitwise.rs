@@ -0,0 +1,63 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn and_or_xor() {
+    assert_eq!(
+        eval(integer(0b1100), Operation::Bitwise(Bitwise::And), small_int(0b1010)),
+        Ok(ScalarValue::Int32(0b1000))
+    );
+    assert_eq!(
+        eval(integer(0b1100), Operation::Bitwise(Bitwise::Or), small_int(0b1010)),
+        Ok(ScalarValue::Int32(0b1110))
+    );
+    assert_eq!(
+        eval(big_int(0b1100), Operation::Bitwise(Bitwise::Xor), small_int(0b1010)),
+        Ok(ScalarValue::Int64(0b0110))
+    );
+}
+
+#[test]
+fn shifts_keep_type_of_left_operand() {
+    assert_eq!(
+        eval(small_int(1), Operation::Bitwise(Bitwise::ShiftLeft), big_int(4)),
+        Ok(ScalarValue::Int32(16))
+    );
+    assert_eq!(
+        eval(big_int(-16), Operation::Bitwise(Bitwise::ShiftRight), small_int(2)),
+        Ok(ScalarValue::Int64(-4))
+    );
+}
+
+#[test]
+fn null_operand() {
+    assert_eq!(
+        eval(null(), Operation::Bitwise(Bitwise::And), integer(1)),
+        Ok(ScalarValue::Null)
+    );
+}
+
+#[test]
+fn non_integer_operand() {
+    assert_eq!(
+        eval(integer(1), Operation::Bitwise(Bitwise::Or), real(1.0)),
+        Err(EvalError::UndefinedFunction {
+            op: Operation::Bitwise(Bitwise::Or),
+            left: "integer",
+            right: "real"
+        })
+    );
+}
@@ -0,0 +1,169 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn cast(tree: Box<StaticTypedTree>, target: SqlType) -> Result<ScalarValue, EvalError> {
+    Evaluation.eval_static(&StaticTypedTree::Cast { tree, target })
+}
+
+#[test]
+fn number_to_number() {
+    assert_eq!(cast(small_int(1), SqlType::big_int()), Ok(ScalarValue::Int64(1)));
+    assert_eq!(cast(real(1.5), SqlType::small_int()), Ok(ScalarValue::Int16(2)));
+    assert_eq!(
+        cast(integer(1), SqlType::double_precision()),
+        Ok(ScalarValue::Float64(OrderedFloat(1.0)))
+    );
+}
+
+#[test]
+fn number_out_of_range() {
+    assert_eq!(
+        cast(integer(32768), SqlType::small_int()),
+        Err(EvalError::Cast(ImplicitCastError::numeric_value_out_of_range(
+            SqlType::small_int()
+        )))
+    );
+}
+
+#[test]
+fn string_to_number() {
+    assert_eq!(cast(string("123"), SqlType::integer()), Ok(ScalarValue::Int32(123)));
+    assert_eq!(
+        cast(string("abc"), SqlType::integer()),
+        Err(EvalError::Cast(ImplicitCastError::invalid_input_syntax_for_type(
+            SqlType::integer(),
+            "abc"
+        )))
+    );
+}
+
+#[test]
+fn string_to_bool() {
+    assert_eq!(cast(string("t"), SqlType::bool()), Ok(ScalarValue::True));
+}
+
+#[test]
+fn value_too_long() {
+    assert_eq!(
+        cast(string("abcdef"), SqlType::var_char(5)),
+        Err(EvalError::Cast(ImplicitCastError::string_data_right_truncation(
+            SqlType::var_char(5)
+        )))
+    );
+    assert_eq!(
+        cast(integer(123456), SqlType::char(5)),
+        Err(EvalError::Cast(ImplicitCastError::string_data_right_truncation(
+            SqlType::char(5)
+        )))
+    );
+}
+
+#[test]
+fn bool_to_number() {
+    assert_eq!(
+        cast(boolean(true), SqlType::integer()),
+        Err(EvalError::Cast(ImplicitCastError::datatype_mismatch(
+            SqlType::integer(),
+            SqlType::bool()
+        )))
+    );
+}
+
+#[test]
+fn null() {
+    assert_eq!(cast(super::null(), SqlType::integer()), Ok(ScalarValue::Null));
+}
+
+#[test]
+fn special_floats() {
+    assert!(matches!(
+        cast(string("NaN"), SqlType::real()),
+        Ok(ScalarValue::Float32(value)) if value.is_nan()
+    ));
+    assert_eq!(
+        cast(string("-Infinity"), SqlType::double_precision()),
+        Ok(ScalarValue::Float64(OrderedFloat(f64::NEG_INFINITY)))
+    );
+    assert_eq!(
+        cast(double(f64::INFINITY), SqlType::real()),
+        Ok(ScalarValue::Float32(OrderedFloat(f32::INFINITY)))
+    );
+    assert_eq!(
+        cast(double(f64::INFINITY), SqlType::integer()),
+        Err(EvalError::Cast(ImplicitCastError::numeric_value_out_of_range(
+            SqlType::integer()
+        )))
+    );
+    assert_eq!(
+        cast(real(f32::NAN), SqlType::var_char(10)),
+        Ok(ScalarValue::String("NaN".to_owned()))
+    );
+}
+
+#[test]
+fn to_numeric() {
+    assert_eq!(
+        cast(numeric("1.005"), SqlType::numeric(5, 2)),
+        Ok(numeric_value("1.01"))
+    );
+    assert_eq!(cast(real(0.1), SqlType::numeric(5, 2)), Ok(numeric_value("0.10")));
+    assert_eq!(
+        cast(numeric("1000"), SqlType::numeric(5, 2)),
+        Err(EvalError::Cast(ImplicitCastError::numeric_value_out_of_range(
+            SqlType::numeric(5, 2)
+        )))
+    );
+    assert_eq!(
+        cast(numeric("2.5"), SqlType::double_precision()),
+        Ok(ScalarValue::Float64(OrderedFloat(2.5)))
+    );
+}
+
+#[test]
+fn decimal_to_float() {
+    assert_eq!(
+        cast(numeric("0.3"), SqlType::double_precision()),
+        Ok(ScalarValue::Float64(OrderedFloat(0.3)))
+    );
+    assert_eq!(
+        cast(numeric("2.675"), SqlType::double_precision()),
+        Ok(ScalarValue::Float64(OrderedFloat(2.675)))
+    );
+    assert_eq!(
+        cast(numeric("1e300"), SqlType::double_precision()),
+        Ok(ScalarValue::Float64(OrderedFloat(1e300)))
+    );
+    assert_eq!(
+        cast(numeric("0.3"), SqlType::real()),
+        Ok(ScalarValue::Float32(OrderedFloat(0.3)))
+    );
+}
+
+#[test]
+fn decimal_out_of_float_range() {
+    assert_eq!(
+        cast(numeric("1e39"), SqlType::real()),
+        Err(EvalError::Cast(ImplicitCastError::numeric_value_out_of_range(
+            SqlType::real()
+        )))
+    );
+    assert_eq!(
+        cast(numeric("1e309"), SqlType::double_precision()),
+        Err(EvalError::Cast(ImplicitCastError::numeric_value_out_of_range(
+            SqlType::double_precision()
+        )))
+    );
+}
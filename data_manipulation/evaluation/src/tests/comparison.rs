@@ -0,0 +1,99 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn numbers_of_different_types() {
+    assert_eq!(
+        eval(small_int(1), Operation::Comparison(Comparison::Eq), big_int(1)),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(integer(1), Operation::Comparison(Comparison::Lt), double(1.5)),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(real(2.5), Operation::Comparison(Comparison::LtEq), integer(2)),
+        Ok(ScalarValue::False)
+    );
+}
+
+#[test]
+fn all_operators() {
+    let compare = |comparison| eval(integer(1), Operation::Comparison(comparison), integer(2));
+
+    assert_eq!(compare(Comparison::Eq), Ok(ScalarValue::False));
+    assert_eq!(compare(Comparison::NotEq), Ok(ScalarValue::True));
+    assert_eq!(compare(Comparison::Lt), Ok(ScalarValue::True));
+    assert_eq!(compare(Comparison::LtEq), Ok(ScalarValue::True));
+    assert_eq!(compare(Comparison::Gt), Ok(ScalarValue::False));
+    assert_eq!(compare(Comparison::GtEq), Ok(ScalarValue::False));
+}
+
+#[test]
+fn strings_and_booleans() {
+    assert_eq!(
+        eval(string("abc"), Operation::Comparison(Comparison::Lt), string("abd")),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(boolean(true), Operation::Comparison(Comparison::Gt), boolean(false)),
+        Ok(ScalarValue::True)
+    );
+}
+
+#[test]
+fn null_operand() {
+    assert_eq!(
+        eval(null(), Operation::Comparison(Comparison::Eq), null()),
+        Ok(ScalarValue::Null)
+    );
+    assert_eq!(
+        eval(string("abc"), Operation::Comparison(Comparison::NotEq), null()),
+        Ok(ScalarValue::Null)
+    );
+}
+
+#[test]
+fn incomparable_operands() {
+    assert_eq!(
+        eval(string("1"), Operation::Comparison(Comparison::Eq), integer(1)),
+        Err(EvalError::UndefinedFunction {
+            op: Operation::Comparison(Comparison::Eq),
+            left: "text",
+            right: "integer"
+        })
+    );
+}
+
+#[test]
+fn numerics() {
+    assert_eq!(
+        eval(numeric("1.50"), Operation::Comparison(Comparison::Eq), numeric("1.5")),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(
+            numeric("9223372036854775808"),
+            Operation::Comparison(Comparison::Gt),
+            big_int(i64::MAX)
+        ),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(numeric("0.5"), Operation::Comparison(Comparison::Lt), real(0.75)),
+        Ok(ScalarValue::True)
+    );
+}
@@ -0,0 +1,63 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn columns() -> Vec<ColumnDef> {
+    vec![
+        ColumnDef::new("col_1".to_owned(), SqlType::small_int(), 0),
+        ColumnDef::new("col_2".to_owned(), SqlType::var_char(10), 1),
+    ]
+}
+
+fn column(name: &str) -> Box<DynamicTypedTree> {
+    Box::new(DynamicTypedTree::Item(DynamicTypedItem::Column(name.to_owned())))
+}
+
+#[test]
+fn column_value() {
+    let row = vec![ScalarValue::Int16(1), ScalarValue::String("abc".to_owned())];
+
+    assert_eq!(
+        Evaluation.eval_dynamic(&column("col_2"), &columns(), &row),
+        Ok(ScalarValue::String("abc".to_owned()))
+    );
+}
+
+#[test]
+fn operation_on_column() {
+    let row = vec![ScalarValue::Int16(1), ScalarValue::String("abc".to_owned())];
+    let tree = DynamicTypedTree::Operation {
+        left: column("col_1"),
+        op: Operation::Arithmetic(Arithmetic::Add),
+        right: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::SmallInt(1)))),
+    };
+
+    assert_eq!(
+        Evaluation.eval_dynamic(&tree, &columns(), &row),
+        Ok(ScalarValue::Int32(2))
+    );
+}
+
+#[test]
+fn null_column_value() {
+    let row = vec![ScalarValue::Null, ScalarValue::String("abc".to_owned())];
+    let tree = DynamicTypedTree::Operation {
+        left: column("col_1"),
+        op: Operation::Comparison(Comparison::Eq),
+        right: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::SmallInt(1)))),
+    };
+
+    assert_eq!(Evaluation.eval_dynamic(&tree, &columns(), &row), Ok(ScalarValue::Null));
+}
@@ -0,0 +1,233 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use data_manipulation_operators::JsonOp;
+use data_scalar::json::parse_json;
+
+const DOCUMENT: &str = r#"{"a": {"b": [1, "two", null]}, "c": "d"}"#;
+
+fn json(value: &str) -> Box<StaticTypedTree> {
+    typed(value, SqlType::json())
+}
+
+fn jsonb(value: &str) -> Box<StaticTypedTree> {
+    typed(value, SqlType::jsonb())
+}
+
+fn typed(value: &str, target: SqlType) -> Box<StaticTypedTree> {
+    Box::new(StaticTypedTree::Cast {
+        tree: string(value),
+        target,
+    })
+}
+
+fn jsonb_value(value: &str) -> ScalarValue {
+    ScalarValue::Jsonb(parse_json(value).unwrap())
+}
+
+fn string_value(value: &str) -> ScalarValue {
+    ScalarValue::String(value.to_owned())
+}
+
+#[test]
+fn cast_strings_to_json() {
+    assert_eq!(
+        Evaluation.eval_static(&json(r#"{"b" : 1,"a":2}"#)),
+        Ok(ScalarValue::Json(r#"{"b" : 1,"a":2}"#.to_owned()))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&jsonb(r#"{"b" : 1,"a":2}"#)),
+        Ok(jsonb_value(r#"{"a": 2, "b": 1}"#))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&json("{a: 1}")),
+        Err(EvalError::Cast(ImplicitCastError::invalid_input_syntax_for_type(
+            SqlType::json(),
+            "{a: 1}"
+        )))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&jsonb("[1,")),
+        Err(EvalError::Cast(ImplicitCastError::invalid_input_syntax_for_type(
+            SqlType::jsonb(),
+            "[1,"
+        )))
+    );
+}
+
+#[test]
+fn cast_documents() {
+    assert_eq!(
+        Evaluation.eval_static(&StaticTypedTree::Cast {
+            tree: json(r#"{"a":1, "a":2}"#),
+            target: SqlType::jsonb()
+        }),
+        Ok(jsonb_value(r#"{"a": 2}"#))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&StaticTypedTree::Cast {
+            tree: jsonb(r#"[1,true]"#),
+            target: SqlType::text()
+        }),
+        Ok(string_value("[1, true]"))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&StaticTypedTree::Cast {
+            tree: jsonb("1"),
+            target: SqlType::integer()
+        }),
+        Err(EvalError::Cast(ImplicitCastError::datatype_mismatch(
+            SqlType::integer(),
+            SqlType::jsonb()
+        )))
+    );
+}
+
+#[test]
+fn get_fields_and_elements() {
+    assert_eq!(
+        eval(jsonb(DOCUMENT), Operation::JsonOp(JsonOp::Get), string("a")),
+        Ok(jsonb_value(r#"{"b": [1, "two", null]}"#))
+    );
+    assert_eq!(
+        eval(json(DOCUMENT), Operation::JsonOp(JsonOp::Get), string("c")),
+        Ok(ScalarValue::Json("\"d\"".to_owned()))
+    );
+    assert_eq!(
+        eval(jsonb(DOCUMENT), Operation::JsonOp(JsonOp::GetText), string("c")),
+        Ok(string_value("d"))
+    );
+    assert_eq!(
+        eval(jsonb("[1, 2, 3]"), Operation::JsonOp(JsonOp::Get), integer(-1)),
+        Ok(jsonb_value("3"))
+    );
+    assert_eq!(
+        eval(jsonb("[1, null]"), Operation::JsonOp(JsonOp::GetText), small_int(1)),
+        Ok(ScalarValue::Null)
+    );
+    assert_eq!(
+        eval(jsonb(DOCUMENT), Operation::JsonOp(JsonOp::Get), string("missing")),
+        Ok(ScalarValue::Null)
+    );
+    assert_eq!(
+        eval(jsonb(DOCUMENT), Operation::JsonOp(JsonOp::Get), null()),
+        Ok(ScalarValue::Null)
+    );
+}
+
+#[test]
+fn get_path() {
+    assert_eq!(
+        eval(jsonb(DOCUMENT), Operation::JsonOp(JsonOp::GetPath), string("{a,b,1}")),
+        Ok(jsonb_value("\"two\""))
+    );
+    assert_eq!(
+        eval(
+            jsonb(r#"{"a b": 1}"#),
+            Operation::JsonOp(JsonOp::GetPath),
+            string(r#"{"a b"}"#)
+        ),
+        Ok(jsonb_value("1"))
+    );
+    assert_eq!(
+        eval(jsonb(DOCUMENT), Operation::JsonOp(JsonOp::GetPath), string("{}")),
+        Ok(jsonb_value(DOCUMENT))
+    );
+    assert_eq!(
+        eval(jsonb(DOCUMENT), Operation::JsonOp(JsonOp::GetPath), string("a,b")),
+        Err(EvalError::InvalidParameterValue(
+            "malformed array literal: \"a,b\"".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn containment_and_existence() {
+    assert_eq!(
+        eval(
+            jsonb(DOCUMENT),
+            Operation::JsonOp(JsonOp::Contains),
+            string(r#"{"a": {"b": ["two"]}}"#)
+        ),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(
+            jsonb(DOCUMENT),
+            Operation::JsonOp(JsonOp::Contains),
+            jsonb(r#"{"c": "e"}"#)
+        ),
+        Ok(ScalarValue::False)
+    );
+    assert_eq!(
+        eval(jsonb(DOCUMENT), Operation::JsonOp(JsonOp::Exists), string("c")),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(jsonb(DOCUMENT), Operation::JsonOp(JsonOp::Exists), string("b")),
+        Ok(ScalarValue::False)
+    );
+    assert_eq!(
+        eval(json(DOCUMENT), Operation::JsonOp(JsonOp::Exists), string("c")),
+        Err(EvalError::UndefinedFunction {
+            op: Operation::JsonOp(JsonOp::Exists),
+            left: "json",
+            right: "text"
+        })
+    );
+}
+
+#[test]
+fn comparison() {
+    assert_eq!(
+        eval(
+            jsonb(r#"{"a":1,"b":2}"#),
+            Operation::Comparison(Comparison::Eq),
+            string(r#"{"b": 2, "a": 1}"#)
+        ),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(jsonb("[1, 2]"), Operation::Comparison(Comparison::Gt), jsonb("[3]")),
+        Ok(ScalarValue::True)
+    );
+}
+
+#[test]
+fn build_object() {
+    let function = |args: Vec<Box<StaticTypedTree>>| StaticTypedTree::Function {
+        type_family: None,
+        function: ScalarFunction::JsonbBuildObject,
+        args: args.into_iter().map(|arg| *arg).collect(),
+    };
+    assert_eq!(
+        Evaluation.eval_static(&function(vec![
+            string("b"),
+            integer(1),
+            string("a"),
+            null(),
+            integer(2),
+            jsonb("[true]")
+        ])),
+        Ok(jsonb_value(r#"{"2": [true], "a": null, "b": 1}"#))
+    );
+    assert_eq!(Evaluation.eval_static(&function(vec![])), Ok(jsonb_value("{}")));
+    assert_eq!(
+        Evaluation.eval_static(&function(vec![string("a"), integer(1), null(), integer(2)])),
+        Err(EvalError::InvalidParameterValue(
+            "argument 3: key must not be null".to_owned()
+        ))
+    );
+}
@@ -0,0 +1,47 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn and() {
+    let and = |left, right| eval(left, Operation::Logical(Logical::And), right);
+
+    assert_eq!(and(boolean(true), boolean(true)), Ok(ScalarValue::True));
+    assert_eq!(and(boolean(true), boolean(false)), Ok(ScalarValue::False));
+    assert_eq!(and(boolean(true), null()), Ok(ScalarValue::Null));
+    assert_eq!(and(null(), boolean(false)), Ok(ScalarValue::False));
+}
+
+#[test]
+fn or() {
+    let or = |left, right| eval(left, Operation::Logical(Logical::Or), right);
+
+    assert_eq!(or(boolean(false), boolean(false)), Ok(ScalarValue::False));
+    assert_eq!(or(boolean(false), boolean(true)), Ok(ScalarValue::True));
+    assert_eq!(or(boolean(false), null()), Ok(ScalarValue::Null));
+    assert_eq!(or(null(), boolean(true)), Ok(ScalarValue::True));
+}
+
+#[test]
+fn non_boolean_operand() {
+    assert_eq!(
+        eval(boolean(true), Operation::Logical(Logical::And), integer(1)),
+        Err(EvalError::UndefinedFunction {
+            op: Operation::Logical(Logical::And),
+            left: "boolean",
+            right: "integer"
+        })
+    );
+}
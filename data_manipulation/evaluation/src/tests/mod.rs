@@ -0,0 +1,93 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(test)]
+mod arithmetic;
+#[cfg(test)]
+mod binary_strings_and_uuids;
+#[cfg(test)]
+mod bitwise;
+#[cfg(test)]
+mod cast;
+#[cfg(test)]
+mod comparison;
+#[cfg(test)]
+mod dynamic;
+#[cfg(test)]
+mod json;
+#[cfg(test)]
+mod logical;
+#[cfg(test)]
+mod pattern_matching;
+#[cfg(test)]
+mod string_op;
+#[cfg(test)]
+mod temporal;
+#[cfg(test)]
+mod unary;
+
+fn small_int(value: i16) -> Box<StaticTypedTree> {
+    constant(TypedValue::SmallInt(value))
+}
+
+fn integer(value: i32) -> Box<StaticTypedTree> {
+    constant(TypedValue::Integer(value))
+}
+
+fn big_int(value: i64) -> Box<StaticTypedTree> {
+    constant(TypedValue::BigInt(value))
+}
+
+fn real(value: f32) -> Box<StaticTypedTree> {
+    constant(TypedValue::Real(value))
+}
+
+fn double(value: f64) -> Box<StaticTypedTree> {
+    constant(TypedValue::Double(value))
+}
+
+fn numeric(value: &str) -> Box<StaticTypedTree> {
+    constant(TypedValue::Numeric(BigDecimal::from_str(value).unwrap()))
+}
+
+fn numeric_value(value: &str) -> ScalarValue {
+    ScalarValue::Numeric(BigDecimal::from_str(value).unwrap())
+}
+
+fn string(value: &str) -> Box<StaticTypedTree> {
+    constant(TypedValue::String(value.to_owned()))
+}
+
+fn boolean(value: bool) -> Box<StaticTypedTree> {
+    constant(TypedValue::Bool(value))
+}
+
+fn null() -> Box<StaticTypedTree> {
+    Box::new(StaticTypedTree::Item(StaticTypedItem::Null(None)))
+}
+
+fn constant(value: TypedValue) -> Box<StaticTypedTree> {
+    Box::new(StaticTypedTree::Item(StaticTypedItem::Const(value)))
+}
+
+fn eval(left: Box<StaticTypedTree>, op: Operation, right: Box<StaticTypedTree>) -> Result<ScalarValue, EvalError> {
+    Evaluation.eval_static(&StaticTypedTree::Operation {
+        type_family: None,
+        left,
+        op,
+        right,
+    })
+}
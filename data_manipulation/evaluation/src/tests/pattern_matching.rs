@@ -0,0 +1,70 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn like(value: &str, pattern: &str) -> Result<ScalarValue, EvalError> {
+    eval(
+        string(value),
+        Operation::PatternMatching(PatternMatching::Like),
+        string(pattern),
+    )
+}
+
+#[test]
+fn exact_match() {
+    assert_eq!(like("abc", "abc"), Ok(ScalarValue::True));
+    assert_eq!(like("abc", "ab"), Ok(ScalarValue::False));
+}
+
+#[test]
+fn any_sequence() {
+    assert_eq!(like("abc", "a%"), Ok(ScalarValue::True));
+    assert_eq!(like("abc", "%c"), Ok(ScalarValue::True));
+    assert_eq!(like("abcbc", "a%bc"), Ok(ScalarValue::True));
+    assert_eq!(like("", "%"), Ok(ScalarValue::True));
+    assert_eq!(like("abc", "%d%"), Ok(ScalarValue::False));
+}
+
+#[test]
+fn any_char() {
+    assert_eq!(like("abc", "a_c"), Ok(ScalarValue::True));
+    assert_eq!(like("ac", "a_c"), Ok(ScalarValue::False));
+}
+
+#[test]
+fn escaped_wildcard() {
+    assert_eq!(like("100%", "100\\%"), Ok(ScalarValue::True));
+    assert_eq!(like("1000", "100\\%"), Ok(ScalarValue::False));
+}
+
+#[test]
+fn not_like() {
+    assert_eq!(
+        eval(
+            string("abc"),
+            Operation::PatternMatching(PatternMatching::NotLike),
+            string("a%")
+        ),
+        Ok(ScalarValue::False)
+    );
+}
+
+#[test]
+fn null_operand() {
+    assert_eq!(
+        eval(null(), Operation::PatternMatching(PatternMatching::Like), string("%")),
+        Ok(ScalarValue::Null)
+    );
+}
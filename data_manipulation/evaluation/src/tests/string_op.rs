@@ -0,0 +1,56 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn concat(left: Box<StaticTypedTree>, right: Box<StaticTypedTree>) -> Result<ScalarValue, EvalError> {
+    eval(left, Operation::StringOp(StringOp::Concat), right)
+}
+
+#[test]
+fn strings() {
+    assert_eq!(
+        concat(string("abc"), string("def")),
+        Ok(ScalarValue::String("abcdef".to_owned()))
+    );
+}
+
+#[test]
+fn string_with_non_string() {
+    assert_eq!(
+        concat(string("abc"), integer(1)),
+        Ok(ScalarValue::String("abc1".to_owned()))
+    );
+    assert_eq!(
+        concat(boolean(true), string("abc")),
+        Ok(ScalarValue::String("trueabc".to_owned()))
+    );
+}
+
+#[test]
+fn null_operand() {
+    assert_eq!(concat(string("abc"), null()), Ok(ScalarValue::Null));
+}
+
+#[test]
+fn non_string_operands() {
+    assert_eq!(
+        concat(integer(1), integer(1)),
+        Err(EvalError::UndefinedFunction {
+            op: Operation::StringOp(StringOp::Concat),
+            left: "integer",
+            right: "integer"
+        })
+    );
+}
@@ -0,0 +1,212 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use chrono::NaiveDate;
+use data_scalar::temporal::Interval;
+
+fn date(value: &str) -> Box<StaticTypedTree> {
+    typed(value, SqlType::date())
+}
+
+fn timestamp(value: &str) -> Box<StaticTypedTree> {
+    typed(value, SqlType::timestamp())
+}
+
+fn interval(value: &str) -> Box<StaticTypedTree> {
+    typed(value, SqlType::interval())
+}
+
+fn typed(value: &str, target: SqlType) -> Box<StaticTypedTree> {
+    Box::new(StaticTypedTree::Cast {
+        tree: string(value),
+        target,
+    })
+}
+
+fn function(function: ScalarFunction, args: Vec<StaticTypedTree>) -> Result<ScalarValue, EvalError> {
+    Evaluation.eval_static(&StaticTypedTree::Function {
+        type_family: None,
+        function,
+        args,
+    })
+}
+
+fn ymd_hms(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> ScalarValue {
+    ScalarValue::Timestamp(NaiveDate::from_ymd(year, month, day).and_hms(hour, minute, second))
+}
+
+#[test]
+fn cast_strings() {
+    assert_eq!(
+        Evaluation.eval_static(&date("2021-03-04")),
+        Ok(ScalarValue::Date(NaiveDate::from_ymd(2021, 3, 4)))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&interval("1 day ago")),
+        Ok(ScalarValue::Interval(Interval::new(0, -1, 0)))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&date("yesterday-ish")),
+        Err(EvalError::Cast(ImplicitCastError::invalid_input_syntax_for_type(
+            SqlType::date(),
+            "yesterday-ish"
+        )))
+    );
+}
+
+#[test]
+fn cast_between_types() {
+    assert_eq!(
+        Evaluation.eval_static(&StaticTypedTree::Cast {
+            tree: timestamp("2021-03-04 05:06:07"),
+            target: SqlType::date()
+        }),
+        Ok(ScalarValue::Date(NaiveDate::from_ymd(2021, 3, 4)))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&StaticTypedTree::Cast {
+            tree: date("2021-03-04"),
+            target: SqlType::var_char(5)
+        }),
+        Err(EvalError::Cast(ImplicitCastError::string_data_right_truncation(
+            SqlType::var_char(5)
+        )))
+    );
+    assert_eq!(
+        Evaluation.eval_static(&StaticTypedTree::Cast {
+            tree: integer(1),
+            target: SqlType::date()
+        }),
+        Err(EvalError::Cast(ImplicitCastError::datatype_mismatch(
+            SqlType::date(),
+            SqlType::integer()
+        )))
+    );
+}
+
+#[test]
+fn months_are_added_up_to_the_end_of_month() {
+    assert_eq!(
+        eval(
+            timestamp("2021-01-31 10:00:00"),
+            Operation::Arithmetic(Arithmetic::Add),
+            interval("1 month 1 day")
+        ),
+        Ok(ymd_hms(2021, 3, 1, 10, 0, 0))
+    );
+    assert_eq!(
+        eval(
+            date("2020-02-29"),
+            Operation::Arithmetic(Arithmetic::Sub),
+            interval("1 year")
+        ),
+        Ok(ymd_hms(2019, 2, 28, 0, 0, 0))
+    );
+}
+
+#[test]
+fn differences() {
+    assert_eq!(
+        eval(
+            timestamp("2021-01-01 00:00:00"),
+            Operation::Arithmetic(Arithmetic::Sub),
+            timestamp("2021-01-02 06:00:00")
+        ),
+        Ok(ScalarValue::Interval(Interval::new(0, -1, -6 * 3_600_000_000)))
+    );
+    assert_eq!(
+        eval(
+            date("2021-03-01"),
+            Operation::Arithmetic(Arithmetic::Sub),
+            date("2021-02-01")
+        ),
+        Ok(ScalarValue::Int32(28))
+    );
+}
+
+#[test]
+fn intervals_are_scaled() {
+    assert_eq!(
+        eval(interval("1 month"), Operation::Arithmetic(Arithmetic::Mul), real(1.5)),
+        Ok(ScalarValue::Interval(Interval::new(1, 15, 0)))
+    );
+    assert_eq!(
+        eval(interval("1 day"), Operation::Arithmetic(Arithmetic::Div), integer(0)),
+        Err(EvalError::DivisionByZero)
+    );
+}
+
+#[test]
+fn dates_are_compared_with_timestamps() {
+    assert_eq!(
+        eval(
+            date("2021-03-04"),
+            Operation::Comparison(Comparison::Lt),
+            timestamp("2021-03-04 00:00:01")
+        ),
+        Ok(ScalarValue::True)
+    );
+    assert_eq!(
+        eval(
+            string("2021-03-04"),
+            Operation::Comparison(Comparison::Eq),
+            timestamp("2021-03-04 00:00:00")
+        ),
+        Ok(ScalarValue::True)
+    );
+}
+
+#[test]
+fn date_part() {
+    assert_eq!(
+        function(ScalarFunction::DatePart, vec![*string("dow"), *date("2021-03-07")]),
+        Ok(ScalarValue::Float64(OrderedFloat(0.0)))
+    );
+    assert_eq!(
+        function(
+            ScalarFunction::DatePart,
+            vec![*string("epoch"), *interval("1 day 1 second")]
+        ),
+        Ok(ScalarValue::Float64(OrderedFloat(86_401.0)))
+    );
+    assert_eq!(
+        function(ScalarFunction::DatePart, vec![*string("week"), *interval("1 day")]),
+        Err(EvalError::InvalidParameterValue(
+            "interval units \"week\" not recognized".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn date_trunc() {
+    assert_eq!(
+        function(
+            ScalarFunction::DateTrunc,
+            vec![*string("week"), *timestamp("2021-03-07 10:11:12")]
+        ),
+        Ok(ymd_hms(2021, 3, 1, 0, 0, 0))
+    );
+    assert_eq!(
+        function(
+            ScalarFunction::DateTrunc,
+            vec![*string("century"), *timestamp("2000-12-31 00:00:00")]
+        ),
+        Ok(ymd_hms(1901, 1, 1, 0, 0, 0))
+    );
+    assert_eq!(
+        function(ScalarFunction::DateTrunc, vec![*string("hour"), *null()]),
+        Ok(ScalarValue::Null)
+    );
+}
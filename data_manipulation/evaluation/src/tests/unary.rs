@@ -0,0 +1,64 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn unary(op: UnaryOperation, item: Box<StaticTypedTree>) -> Result<ScalarValue, EvalError> {
+    Evaluation.eval_static(&StaticTypedTree::UnaryOperation {
+        type_family: None,
+        op,
+        item,
+    })
+}
+
+#[test]
+fn minus() {
+    let minus = |item| unary(UnaryOperation::Minus, item);
+
+    assert_eq!(minus(small_int(1)), Ok(ScalarValue::Int16(-1)));
+    assert_eq!(minus(integer(-2)), Ok(ScalarValue::Int32(2)));
+    assert_eq!(minus(big_int(3)), Ok(ScalarValue::Int64(-3)));
+    assert_eq!(minus(double(4.5)), Ok(ScalarValue::Float64(OrderedFloat(-4.5))));
+    assert_eq!(minus(numeric("0.3")), Ok(numeric_value("-0.3")));
+    assert_eq!(minus(null()), Ok(ScalarValue::Null));
+}
+
+#[test]
+fn minus_out_of_range() {
+    assert_eq!(
+        unary(UnaryOperation::Minus, small_int(i16::MIN)),
+        Err(EvalError::OutOfRange(SqlType::small_int()))
+    );
+    assert_eq!(
+        unary(UnaryOperation::Minus, big_int(i64::MIN)),
+        Err(EvalError::OutOfRange(SqlType::big_int()))
+    );
+}
+
+#[test]
+fn not() {
+    let not = |item| unary(UnaryOperation::Not, item);
+
+    assert_eq!(not(boolean(true)), Ok(ScalarValue::False));
+    assert_eq!(not(boolean(false)), Ok(ScalarValue::True));
+    assert_eq!(not(null()), Ok(ScalarValue::Null));
+}
+
+#[test]
+fn is_null() {
+    assert_eq!(unary(UnaryOperation::IsNull, null()), Ok(ScalarValue::True));
+    assert_eq!(unary(UnaryOperation::IsNull, integer(1)), Ok(ScalarValue::False));
+    assert_eq!(unary(UnaryOperation::IsNotNull, null()), Ok(ScalarValue::False));
+    assert_eq!(unary(UnaryOperation::IsNotNull, string("a")), Ok(ScalarValue::True));
+}
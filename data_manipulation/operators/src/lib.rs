@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::{self, Display, Formatter};
 use types::SqlTypeFamily;
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -24,6 +25,48 @@ pub enum Arithmetic {
     Exp,
 }
 
+impl Arithmetic {
+    /// type family of arithmetic over dates, times and intervals as in PostgreSQL,
+    /// `None` if the operation is not defined for the operands
+    pub fn temporal_result(&self, left: SqlTypeFamily, right: SqlTypeFamily) -> Option<SqlTypeFamily> {
+        let is_number =
+            |family: SqlTypeFamily| family.is_int() || family.is_float() || family == SqlTypeFamily::Numeric;
+        let is_point = |family: SqlTypeFamily| {
+            family == SqlTypeFamily::Timestamp || family == SqlTypeFamily::TimestampTz || family == SqlTypeFamily::Time
+        };
+        match (self, left, right) {
+            (Arithmetic::Add, SqlTypeFamily::Date, other) | (Arithmetic::Sub, SqlTypeFamily::Date, other)
+                if other.is_int() =>
+            {
+                Some(SqlTypeFamily::Date)
+            }
+            (Arithmetic::Add, other, SqlTypeFamily::Date) if other.is_int() => Some(SqlTypeFamily::Date),
+            (Arithmetic::Sub, SqlTypeFamily::Date, SqlTypeFamily::Date) => Some(SqlTypeFamily::Integer),
+            (Arithmetic::Add, SqlTypeFamily::Date, SqlTypeFamily::Time)
+            | (Arithmetic::Add, SqlTypeFamily::Time, SqlTypeFamily::Date)
+            | (Arithmetic::Add, SqlTypeFamily::Date, SqlTypeFamily::Interval)
+            | (Arithmetic::Add, SqlTypeFamily::Interval, SqlTypeFamily::Date)
+            | (Arithmetic::Sub, SqlTypeFamily::Date, SqlTypeFamily::Interval) => Some(SqlTypeFamily::Timestamp),
+            (Arithmetic::Add, point, SqlTypeFamily::Interval) | (Arithmetic::Sub, point, SqlTypeFamily::Interval)
+                if is_point(point) =>
+            {
+                Some(point)
+            }
+            (Arithmetic::Add, SqlTypeFamily::Interval, point) if is_point(point) => Some(point),
+            (Arithmetic::Sub, left, right) if left == right && is_point(left) => Some(SqlTypeFamily::Interval),
+            (Arithmetic::Add, SqlTypeFamily::Interval, SqlTypeFamily::Interval)
+            | (Arithmetic::Sub, SqlTypeFamily::Interval, SqlTypeFamily::Interval) => Some(SqlTypeFamily::Interval),
+            (Arithmetic::Mul, SqlTypeFamily::Interval, other) | (Arithmetic::Div, SqlTypeFamily::Interval, other)
+                if is_number(other) =>
+            {
+                Some(SqlTypeFamily::Interval)
+            }
+            (Arithmetic::Mul, other, SqlTypeFamily::Interval) if is_number(other) => Some(SqlTypeFamily::Interval),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum Comparison {
     NotEq,
@@ -60,6 +103,32 @@ pub enum StringOp {
     Concat,
 }
 
+/// operators over `json` and `jsonb` documents
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum JsonOp {
+    /// object field by key or array element by index
+    Get,
+    /// object field or array element as text
+    GetText,
+    /// value at the path of keys and indexes
+    GetPath,
+    /// whether the left document contains the right one
+    Contains,
+    /// whether the string is a top level key or array element
+    Exists,
+}
+
+impl JsonOp {
+    /// `->` and `#>` return values of the document type, `->>` returns text
+    pub fn result_type_family(&self, document: SqlTypeFamily) -> SqlTypeFamily {
+        match self {
+            JsonOp::Get | JsonOp::GetPath => document,
+            JsonOp::GetText => SqlTypeFamily::String,
+            JsonOp::Contains | JsonOp::Exists => SqlTypeFamily::Bool,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum Operation {
     Arithmetic(Arithmetic),
@@ -68,6 +137,7 @@ pub enum Operation {
     Logical(Logical),
     PatternMatching(PatternMatching),
     StringOp(StringOp),
+    JsonOp(JsonOp),
 }
 
 impl Operation {
@@ -79,24 +149,234 @@ impl Operation {
             Operation::Logical(_) => vec![SqlTypeFamily::Bool],
             Operation::PatternMatching(_) => vec![SqlTypeFamily::Bool],
             Operation::StringOp(_) => vec![SqlTypeFamily::Bool],
+            Operation::JsonOp(_) => vec![SqlTypeFamily::Json, SqlTypeFamily::Jsonb, SqlTypeFamily::Bool],
         }
     }
 
     pub fn supported_type_family(&self, left: Option<SqlTypeFamily>, right: Option<SqlTypeFamily>) -> bool {
         match self {
-            Operation::Arithmetic(_) => {
+            Operation::Arithmetic(arithmetic) => {
                 left == Some(SqlTypeFamily::Integer) && right == Some(SqlTypeFamily::Integer)
                     || left == Some(SqlTypeFamily::Real) && right == Some(SqlTypeFamily::Integer)
                     || left == Some(SqlTypeFamily::Integer) && right == Some(SqlTypeFamily::Real)
                     || left == Some(SqlTypeFamily::Real) && right == Some(SqlTypeFamily::Real)
+                    || left
+                        .zip(right)
+                        .and_then(|(left, right)| arithmetic.temporal_result(left, right))
+                        .is_some()
+            }
+            Operation::Comparison(_) if left == Some(SqlTypeFamily::Json) || right == Some(SqlTypeFamily::Json) => {
+                false
+            }
+            Operation::Comparison(_) => {
+                left.is_some() && left == right
+                    || left == Some(SqlTypeFamily::Real) && right == Some(SqlTypeFamily::Integer)
+                    || left == Some(SqlTypeFamily::Integer) && right == Some(SqlTypeFamily::Real)
+                    || matches!(left.zip(right), Some((left, right)) if left.is_date_time() && right.is_date_time()
+                        || left.is_parsed_from_string() && right == SqlTypeFamily::String
+                        || left == SqlTypeFamily::String && right.is_parsed_from_string())
             }
-            Operation::Comparison(_) => left.is_some() && left == right,
             Operation::Bitwise(_) => left == Some(SqlTypeFamily::Integer) && right == Some(SqlTypeFamily::Integer),
             Operation::Logical(_) => left == Some(SqlTypeFamily::Bool) && right == Some(SqlTypeFamily::Bool),
             Operation::PatternMatching(_) => {
                 left == Some(SqlTypeFamily::String) && right == Some(SqlTypeFamily::String)
             }
-            Operation::StringOp(_) => left == Some(SqlTypeFamily::String) && right == Some(SqlTypeFamily::String),
+            Operation::StringOp(_) => {
+                left.is_some()
+                    && right.is_some()
+                    && (left == Some(SqlTypeFamily::String) || right == Some(SqlTypeFamily::String))
+            }
+            Operation::JsonOp(json_op) => match (json_op, left, right) {
+                (JsonOp::Get, Some(left), Some(right)) | (JsonOp::GetText, Some(left), Some(right)) => {
+                    left.is_json() && (right == SqlTypeFamily::String || right == SqlTypeFamily::Integer)
+                }
+                (JsonOp::GetPath, Some(left), Some(SqlTypeFamily::String)) => left.is_json(),
+                (JsonOp::Contains, Some(SqlTypeFamily::Jsonb), Some(right)) => {
+                    right == SqlTypeFamily::Jsonb || right == SqlTypeFamily::String
+                }
+                (JsonOp::Exists, Some(SqlTypeFamily::Jsonb), Some(SqlTypeFamily::String)) => true,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Arithmetic(Arithmetic::Add) => write!(f, "+"),
+            Operation::Arithmetic(Arithmetic::Sub) => write!(f, "-"),
+            Operation::Arithmetic(Arithmetic::Mul) => write!(f, "*"),
+            Operation::Arithmetic(Arithmetic::Div) => write!(f, "/"),
+            Operation::Arithmetic(Arithmetic::Mod) => write!(f, "%"),
+            Operation::Arithmetic(Arithmetic::Exp) => write!(f, "^"),
+            Operation::Comparison(Comparison::NotEq) => write!(f, "<>"),
+            Operation::Comparison(Comparison::Eq) => write!(f, "="),
+            Operation::Comparison(Comparison::LtEq) => write!(f, "<="),
+            Operation::Comparison(Comparison::GtEq) => write!(f, ">="),
+            Operation::Comparison(Comparison::Lt) => write!(f, "<"),
+            Operation::Comparison(Comparison::Gt) => write!(f, ">"),
+            Operation::Bitwise(Bitwise::ShiftRight) => write!(f, ">>"),
+            Operation::Bitwise(Bitwise::ShiftLeft) => write!(f, "<<"),
+            Operation::Bitwise(Bitwise::Xor) => write!(f, "#"),
+            Operation::Bitwise(Bitwise::And) => write!(f, "&"),
+            Operation::Bitwise(Bitwise::Or) => write!(f, "|"),
+            Operation::Logical(Logical::Or) => write!(f, "OR"),
+            Operation::Logical(Logical::And) => write!(f, "AND"),
+            Operation::PatternMatching(PatternMatching::Like) => write!(f, "LIKE"),
+            Operation::PatternMatching(PatternMatching::NotLike) => write!(f, "NOT LIKE"),
+            Operation::StringOp(StringOp::Concat) => write!(f, "||"),
+            Operation::JsonOp(JsonOp::Get) => write!(f, "->"),
+            Operation::JsonOp(JsonOp::GetText) => write!(f, "->>"),
+            Operation::JsonOp(JsonOp::GetPath) => write!(f, "#>"),
+            Operation::JsonOp(JsonOp::Contains) => write!(f, "@>"),
+            Operation::JsonOp(JsonOp::Exists) => write!(f, "?"),
+        }
+    }
+}
+
+/// operators that take a single operand
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum UnaryOperation {
+    Minus,
+    Not,
+    IsNull,
+    IsNotNull,
+}
+
+impl UnaryOperation {
+    pub fn supported_type_family(&self, operand: Option<SqlTypeFamily>) -> bool {
+        match self {
+            UnaryOperation::Minus => {
+                matches!(operand, Some(family) if family.is_int()
+                    || family.is_float()
+                    || family == SqlTypeFamily::Numeric
+                    || family == SqlTypeFamily::Interval)
+            }
+            UnaryOperation::Not => operand == Some(SqlTypeFamily::Bool),
+            UnaryOperation::IsNull | UnaryOperation::IsNotNull => true,
+        }
+    }
+
+    pub fn result_type_family(&self, operand: Option<SqlTypeFamily>) -> Option<SqlTypeFamily> {
+        match self {
+            UnaryOperation::Minus => operand,
+            UnaryOperation::Not | UnaryOperation::IsNull | UnaryOperation::IsNotNull => Some(SqlTypeFamily::Bool),
+        }
+    }
+}
+
+impl Display for UnaryOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            UnaryOperation::Minus => write!(f, "-"),
+            UnaryOperation::Not => write!(f, "NOT"),
+            UnaryOperation::IsNull => write!(f, "IS NULL"),
+            UnaryOperation::IsNotNull => write!(f, "IS NOT NULL"),
+        }
+    }
+}
+
+/// functions that compute a single result from values of a group of rows
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum AggregateFunction {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+    /// collects values into a `jsonb` array
+    JsonbAgg,
+}
+
+impl Display for AggregateFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AggregateFunction::Count => write!(f, "count"),
+            AggregateFunction::Sum => write!(f, "sum"),
+            AggregateFunction::Avg => write!(f, "avg"),
+            AggregateFunction::Min => write!(f, "min"),
+            AggregateFunction::Max => write!(f, "max"),
+            AggregateFunction::JsonbAgg => write!(f, "jsonb_agg"),
+        }
+    }
+}
+
+/// functions that compute a single result from values of a row
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ScalarFunction {
+    Now,
+    CurrentDate,
+    /// `extract(field from source)` is `date_part('field', source)`
+    DatePart,
+    DateTrunc,
+    GenRandomUuid,
+    /// builds a `jsonb` object out of alternating keys and values
+    JsonbBuildObject,
+}
+
+impl ScalarFunction {
+    /// type family of the function result, `None` if the function is not defined
+    /// for arguments of given type families where `None` stands for the type of `NULL`
+    pub fn result_type_family(&self, args: &[Option<SqlTypeFamily>]) -> Option<SqlTypeFamily> {
+        let is_text = |arg: &Option<SqlTypeFamily>| matches!(arg, None | Some(SqlTypeFamily::String));
+        match (self, args) {
+            (ScalarFunction::Now, []) => Some(SqlTypeFamily::TimestampTz),
+            (ScalarFunction::CurrentDate, []) => Some(SqlTypeFamily::Date),
+            (ScalarFunction::GenRandomUuid, []) => Some(SqlTypeFamily::Uuid),
+            (ScalarFunction::JsonbBuildObject, args) if args.len() % 2 == 0 => Some(SqlTypeFamily::Jsonb),
+            (ScalarFunction::DatePart, [field, source])
+                if is_text(field) && source.iter().all(SqlTypeFamily::is_temporal) =>
+            {
+                Some(SqlTypeFamily::Double)
+            }
+            (ScalarFunction::DateTrunc, [field, source]) if is_text(field) => match source {
+                Some(SqlTypeFamily::Timestamp) => Some(SqlTypeFamily::Timestamp),
+                Some(SqlTypeFamily::Interval) => Some(SqlTypeFamily::Interval),
+                Some(SqlTypeFamily::TimestampTz) | Some(SqlTypeFamily::Date) | None => Some(SqlTypeFamily::TimestampTz),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// whether the function returns a different value on every call
+    pub fn is_volatile(&self) -> bool {
+        matches!(self, ScalarFunction::GenRandomUuid)
+    }
+}
+
+impl Display for ScalarFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ScalarFunction::Now => write!(f, "now"),
+            ScalarFunction::CurrentDate => write!(f, "current_date"),
+            ScalarFunction::DatePart => write!(f, "date_part"),
+            ScalarFunction::DateTrunc => write!(f, "date_trunc"),
+            ScalarFunction::GenRandomUuid => write!(f, "gen_random_uuid"),
+            ScalarFunction::JsonbBuildObject => write!(f, "jsonb_build_object"),
+        }
+    }
+}
+
+/// how rows of a joined table are combined with rows of the preceding tables
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum JoinKind {
+    Inner,
+    LeftOuter,
+    RightOuter,
+    FullOuter,
+    Cross,
+}
+
+impl Display for JoinKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinKind::Inner => write!(f, "INNER JOIN"),
+            JoinKind::LeftOuter => write!(f, "LEFT OUTER JOIN"),
+            JoinKind::RightOuter => write!(f, "RIGHT OUTER JOIN"),
+            JoinKind::FullOuter => write!(f, "FULL OUTER JOIN"),
+            JoinKind::Cross => write!(f, "CROSS JOIN"),
         }
     }
 }
@@ -70,3 +70,286 @@ mod arithmetic {
         );
     }
 }
+
+#[cfg(test)]
+mod comparison {
+    use super::*;
+
+    #[test]
+    fn same_type_families() {
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::Integer), Some(SqlTypeFamily::Integer)),
+            true
+        );
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::String), Some(SqlTypeFamily::String)),
+            true
+        );
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::Bool), Some(SqlTypeFamily::Bool)),
+            true
+        );
+    }
+
+    #[test]
+    fn number_and_number() {
+        assert_eq!(
+            Operation::Comparison(Comparison::Lt)
+                .supported_type_family(Some(SqlTypeFamily::Real), Some(SqlTypeFamily::Integer)),
+            true
+        );
+        assert_eq!(
+            Operation::Comparison(Comparison::Lt)
+                .supported_type_family(Some(SqlTypeFamily::Integer), Some(SqlTypeFamily::Real)),
+            true
+        );
+    }
+
+    #[test]
+    fn number_and_string() {
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::Integer), Some(SqlTypeFamily::String)),
+            false
+        );
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::String), Some(SqlTypeFamily::Integer)),
+            false
+        );
+    }
+}
+
+#[cfg(test)]
+mod string_op {
+    use super::*;
+
+    #[test]
+    fn string_and_string() {
+        assert_eq!(
+            Operation::StringOp(StringOp::Concat)
+                .supported_type_family(Some(SqlTypeFamily::String), Some(SqlTypeFamily::String)),
+            true
+        );
+    }
+
+    #[test]
+    fn string_and_non_string() {
+        assert_eq!(
+            Operation::StringOp(StringOp::Concat)
+                .supported_type_family(Some(SqlTypeFamily::Integer), Some(SqlTypeFamily::String)),
+            true
+        );
+        assert_eq!(
+            Operation::StringOp(StringOp::Concat)
+                .supported_type_family(Some(SqlTypeFamily::String), Some(SqlTypeFamily::Bool)),
+            true
+        );
+    }
+
+    #[test]
+    fn non_string_and_non_string() {
+        assert_eq!(
+            Operation::StringOp(StringOp::Concat)
+                .supported_type_family(Some(SqlTypeFamily::Integer), Some(SqlTypeFamily::Integer)),
+            false
+        );
+    }
+}
+
+#[cfg(test)]
+mod temporal {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(
+            Arithmetic::Add.temporal_result(SqlTypeFamily::Date, SqlTypeFamily::Integer),
+            Some(SqlTypeFamily::Date)
+        );
+        assert_eq!(
+            Arithmetic::Sub.temporal_result(SqlTypeFamily::Date, SqlTypeFamily::Date),
+            Some(SqlTypeFamily::Integer)
+        );
+        assert_eq!(
+            Arithmetic::Add.temporal_result(SqlTypeFamily::Interval, SqlTypeFamily::TimestampTz),
+            Some(SqlTypeFamily::TimestampTz)
+        );
+        assert_eq!(
+            Arithmetic::Sub.temporal_result(SqlTypeFamily::Timestamp, SqlTypeFamily::Timestamp),
+            Some(SqlTypeFamily::Interval)
+        );
+        assert_eq!(
+            Arithmetic::Mul.temporal_result(SqlTypeFamily::Double, SqlTypeFamily::Interval),
+            Some(SqlTypeFamily::Interval)
+        );
+        assert_eq!(
+            Arithmetic::Add.temporal_result(SqlTypeFamily::Timestamp, SqlTypeFamily::Timestamp),
+            None
+        );
+        assert_eq!(
+            Arithmetic::Sub.temporal_result(SqlTypeFamily::Interval, SqlTypeFamily::Date),
+            None
+        );
+    }
+
+    #[test]
+    fn comparison() {
+        assert_eq!(
+            Operation::Comparison(Comparison::Lt)
+                .supported_type_family(Some(SqlTypeFamily::Date), Some(SqlTypeFamily::TimestampTz)),
+            true
+        );
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::String), Some(SqlTypeFamily::Interval)),
+            true
+        );
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::Date), Some(SqlTypeFamily::Interval)),
+            false
+        );
+    }
+}
+
+#[cfg(test)]
+mod binary_strings_and_uuids {
+    use super::*;
+
+    #[test]
+    fn comparison() {
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::Uuid), Some(SqlTypeFamily::String)),
+            true
+        );
+        assert_eq!(
+            Operation::Comparison(Comparison::Lt)
+                .supported_type_family(Some(SqlTypeFamily::String), Some(SqlTypeFamily::Bytea)),
+            true
+        );
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::Uuid), Some(SqlTypeFamily::Bytea)),
+            false
+        );
+    }
+}
+
+#[cfg(test)]
+mod json {
+    use super::*;
+
+    #[test]
+    fn operators() {
+        assert_eq!(
+            Operation::JsonOp(JsonOp::Get)
+                .supported_type_family(Some(SqlTypeFamily::Json), Some(SqlTypeFamily::String)),
+            true
+        );
+        assert_eq!(
+            Operation::JsonOp(JsonOp::GetText)
+                .supported_type_family(Some(SqlTypeFamily::Jsonb), Some(SqlTypeFamily::Integer)),
+            true
+        );
+        assert_eq!(
+            Operation::JsonOp(JsonOp::GetPath)
+                .supported_type_family(Some(SqlTypeFamily::Jsonb), Some(SqlTypeFamily::Integer)),
+            false
+        );
+        assert_eq!(
+            Operation::JsonOp(JsonOp::Contains)
+                .supported_type_family(Some(SqlTypeFamily::Jsonb), Some(SqlTypeFamily::String)),
+            true
+        );
+        assert_eq!(
+            Operation::JsonOp(JsonOp::Contains)
+                .supported_type_family(Some(SqlTypeFamily::Json), Some(SqlTypeFamily::Json)),
+            false
+        );
+        assert_eq!(
+            Operation::JsonOp(JsonOp::Exists)
+                .supported_type_family(Some(SqlTypeFamily::String), Some(SqlTypeFamily::String)),
+            false
+        );
+    }
+
+    #[test]
+    fn comparison() {
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::Jsonb), Some(SqlTypeFamily::String)),
+            true
+        );
+        assert_eq!(
+            Operation::Comparison(Comparison::Eq)
+                .supported_type_family(Some(SqlTypeFamily::Json), Some(SqlTypeFamily::Json)),
+            false
+        );
+    }
+
+    #[test]
+    fn result_types() {
+        assert_eq!(JsonOp::Get.result_type_family(SqlTypeFamily::Json), SqlTypeFamily::Json);
+        assert_eq!(
+            JsonOp::GetText.result_type_family(SqlTypeFamily::Jsonb),
+            SqlTypeFamily::String
+        );
+        assert_eq!(
+            JsonOp::Exists.result_type_family(SqlTypeFamily::Jsonb),
+            SqlTypeFamily::Bool
+        );
+        assert_eq!(
+            ScalarFunction::JsonbBuildObject
+                .result_type_family(&[Some(SqlTypeFamily::String), Some(SqlTypeFamily::Integer)]),
+            Some(SqlTypeFamily::Jsonb)
+        );
+        assert_eq!(
+            ScalarFunction::JsonbBuildObject.result_type_family(&[Some(SqlTypeFamily::String)]),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod scalar_functions {
+    use super::*;
+
+    #[test]
+    fn date_time_functions() {
+        assert_eq!(
+            ScalarFunction::Now.result_type_family(&[]),
+            Some(SqlTypeFamily::TimestampTz)
+        );
+        assert_eq!(
+            ScalarFunction::DatePart.result_type_family(&[Some(SqlTypeFamily::String), Some(SqlTypeFamily::Date)]),
+            Some(SqlTypeFamily::Double)
+        );
+        assert_eq!(
+            ScalarFunction::DateTrunc.result_type_family(&[Some(SqlTypeFamily::String), Some(SqlTypeFamily::Date)]),
+            Some(SqlTypeFamily::TimestampTz)
+        );
+        assert_eq!(
+            ScalarFunction::DateTrunc.result_type_family(&[Some(SqlTypeFamily::String), Some(SqlTypeFamily::Integer)]),
+            None
+        );
+        assert_eq!(
+            ScalarFunction::CurrentDate.result_type_family(&[Some(SqlTypeFamily::String)]),
+            None
+        );
+    }
+
+    #[test]
+    fn gen_random_uuid() {
+        assert_eq!(
+            ScalarFunction::GenRandomUuid.result_type_family(&[]),
+            Some(SqlTypeFamily::Uuid)
+        );
+        assert!(ScalarFunction::GenRandomUuid.is_volatile());
+        assert!(!ScalarFunction::Now.is_volatile());
+    }
+}
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data_manipulation_evaluation::EvalError;
+use data_manipulation_operators::Operation;
+use data_manipulation_untyped_tree::ImplicitCastError;
 use data_scalar::ScalarValue;
 use definition::ColumnDef;
+use types::SqlType;
 
 #[derive(Debug, PartialEq)]
 pub enum QueryExecution {
@@ -27,4 +31,78 @@ pub enum QueryExecution {
 pub enum QueryExecutionError {
     SchemaDoesNotExist(String),
     ColumnNotFound(String),
+    OutOfRange(SqlType),
+    DivisionByZero,
+    InvalidArgumentForPower,
+    InvalidParameterValue(String),
+    /// string that is cast into a type outside of a column is not a valid value of the type
+    InvalidTextRepresentation {
+        sql_type: SqlType,
+        value: String,
+    },
+    CannotCoerce {
+        source_type: SqlType,
+        target_type: SqlType,
+    },
+    UndefinedFunction {
+        op: Operation,
+        left: &'static str,
+        right: &'static str,
+    },
+    /// values of a row, numbered from 1, that could not be cast into types of their columns
+    ColumnTypeViolations {
+        row_index: usize,
+        violations: Vec<(String, ImplicitCastError)>,
+    },
+    /// rows changed by a transaction were changed by a concurrent one that committed first
+    SerializationFailure,
+    /// values of the constraint columns of a row are the same as of another row
+    UniqueViolation(String),
+    NotNullViolation(String),
+    /// `CHECK` constraint predicate is `false` for a row that is inserted or updated
+    CheckViolation {
+        table_name: String,
+        constraint_name: String,
+    },
+    /// inserted or updated row references a row that does not exist in the referenced table
+    ForeignKeyViolation {
+        table_name: String,
+        constraint_name: String,
+    },
+    /// deleted or updated row is still referenced by a row of `referencing_table_name`
+    RowIsReferenced {
+        table_name: String,
+        constraint_name: String,
+        referencing_table_name: String,
+    },
+}
+
+impl From<EvalError> for QueryExecutionError {
+    fn from(error: EvalError) -> QueryExecutionError {
+        match error {
+            EvalError::OutOfRange(sql_type) => QueryExecutionError::OutOfRange(sql_type),
+            EvalError::DivisionByZero => QueryExecutionError::DivisionByZero,
+            EvalError::InvalidArgumentForPower => QueryExecutionError::InvalidArgumentForPower,
+            EvalError::UndefinedFunction { op, left, right } => {
+                QueryExecutionError::UndefinedFunction { op, left, right }
+            }
+            EvalError::InvalidParameterValue(message) => QueryExecutionError::InvalidParameterValue(message),
+            EvalError::Cast(ImplicitCastError::InvalidInputSyntaxForType { sql_type, value }) => {
+                QueryExecutionError::InvalidTextRepresentation { sql_type, value }
+            }
+            EvalError::Cast(ImplicitCastError::NumericValueOutOfRange(sql_type)) => {
+                QueryExecutionError::OutOfRange(sql_type)
+            }
+            EvalError::Cast(ImplicitCastError::DatatypeMismatch {
+                column_type,
+                source_type,
+            }) => QueryExecutionError::CannotCoerce {
+                source_type,
+                target_type: column_type,
+            },
+            EvalError::Cast(error) => {
+                unreachable!("{:?} has to be reported with a column it is stored into", error)
+            }
+        }
+    }
 }
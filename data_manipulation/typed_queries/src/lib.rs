@@ -12,18 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data_manipulation_operators::JoinKind;
 use data_manipulation_typed_tree::{DynamicTypedTree, StaticTypedTree};
-use definition::FullTableName;
+use definition::{ColumnDef, FullTableName};
 
 #[derive(Debug, PartialEq)]
 pub struct InsertQuery {
     pub full_table_name: FullTableName,
     pub values: Vec<Vec<Option<StaticTypedTree>>>,
+    /// named predicates of `CHECK` constraints of the table
+    pub checks: Vec<(String, DynamicTypedTree)>,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct DeleteQuery {
     pub full_table_name: FullTableName,
+    pub filter: Option<DynamicTypedTree>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -31,6 +35,8 @@ pub struct UpdateQuery {
     pub full_table_name: FullTableName,
     pub column_names: Vec<String>,
     pub assignments: Vec<DynamicTypedTree>,
+    pub filter: Option<DynamicTypedTree>,
+    pub checks: Vec<(String, DynamicTypedTree)>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -40,8 +46,37 @@ pub enum TypedWrite {
     Update(UpdateQuery),
 }
 
+/// rows are sorted by value of `expr`
 #[derive(Debug, PartialEq)]
-pub struct TypedSelectQuery {
+pub struct OrderBy {
+    pub expr: DynamicTypedTree,
+    pub ascending: bool,
+    pub nulls_first: bool,
+}
+
+/// table joined to the tables that precede it in `FROM` clause
+#[derive(Debug, PartialEq)]
+pub struct Join {
+    pub kind: JoinKind,
     pub full_table_name: FullTableName,
+    pub relation: String,
+    pub condition: Option<DynamicTypedTree>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TypedSelectQuery {
+    /// table in `FROM` clause, `None` when the query has no `FROM` clause
+    pub full_table_name: Option<FullTableName>,
+    /// alias or name of the table that qualifies its columns
+    pub relation: String,
+    pub joins: Vec<Join>,
     pub projection_items: Vec<DynamicTypedTree>,
+    /// descriptions of columns that `projection_items` are evaluated into
+    pub projection_columns: Vec<ColumnDef>,
+    pub filter: Option<DynamicTypedTree>,
+    pub group_by: Vec<DynamicTypedTree>,
+    pub having: Option<DynamicTypedTree>,
+    pub order_by: Vec<OrderBy>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
 }
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data_manipulation_operators::Operation;
-use types::SqlTypeFamily;
+use bigdecimal::BigDecimal;
+use data_manipulation_operators::{AggregateFunction, Operation, ScalarFunction, UnaryOperation};
+use std::fmt::{self, Display, Formatter};
+use types::{SqlType, SqlTypeFamily};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum StaticTypedTree {
@@ -24,6 +26,20 @@ pub enum StaticTypedTree {
         op: Operation,
         right: Box<StaticTypedTree>,
     },
+    UnaryOperation {
+        type_family: Option<SqlTypeFamily>,
+        op: UnaryOperation,
+        item: Box<StaticTypedTree>,
+    },
+    Cast {
+        tree: Box<StaticTypedTree>,
+        target: SqlType,
+    },
+    Function {
+        type_family: Option<SqlTypeFamily>,
+        function: ScalarFunction,
+        args: Vec<StaticTypedTree>,
+    },
 }
 
 impl StaticTypedTree {
@@ -31,6 +47,9 @@ impl StaticTypedTree {
         match self {
             StaticTypedTree::Item(item) => item.type_family(),
             StaticTypedTree::Operation { type_family, .. } => *type_family,
+            StaticTypedTree::UnaryOperation { type_family, .. } => *type_family,
+            StaticTypedTree::Cast { target, .. } => Some(target.family()),
+            StaticTypedTree::Function { type_family, .. } => *type_family,
         }
     }
 }
@@ -63,23 +82,40 @@ pub enum TypedValue {
     Real(f32),
     String(String),
     Double(f64),
+    Numeric(BigDecimal),
     Bool(bool),
 }
 
 impl TypedValue {
-    fn type_family(&self) -> Option<SqlTypeFamily> {
+    pub fn type_family(&self) -> Option<SqlTypeFamily> {
         match self {
             TypedValue::SmallInt(_) => Some(SqlTypeFamily::SmallInt),
             TypedValue::Integer(_) => Some(SqlTypeFamily::Integer),
             TypedValue::BigInt(_) => Some(SqlTypeFamily::BigInt),
             TypedValue::Real(_) => Some(SqlTypeFamily::Real),
             TypedValue::Double(_) => Some(SqlTypeFamily::Double),
+            TypedValue::Numeric(_) => Some(SqlTypeFamily::Numeric),
             TypedValue::String(_) => Some(SqlTypeFamily::String),
             TypedValue::Bool(_) => Some(SqlTypeFamily::Bool),
         }
     }
 }
 
+impl Display for TypedValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TypedValue::SmallInt(value) => write!(f, "{}", value),
+            TypedValue::Integer(value) => write!(f, "{}", value),
+            TypedValue::BigInt(value) => write!(f, "{}", value),
+            TypedValue::Real(value) => write!(f, "{}", value),
+            TypedValue::Double(value) => write!(f, "{}", value),
+            TypedValue::Numeric(value) => write!(f, "{}", value),
+            TypedValue::String(value) => write!(f, "'{}'", value.replace('\'', "''")),
+            TypedValue::Bool(value) => write!(f, "{}", value),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum DynamicTypedTree {
     Operation {
@@ -87,7 +123,81 @@ pub enum DynamicTypedTree {
         op: Operation,
         right: Box<DynamicTypedTree>,
     },
+    UnaryOperation {
+        op: UnaryOperation,
+        item: Box<DynamicTypedTree>,
+    },
     Item(DynamicTypedItem),
+    Cast {
+        tree: Box<DynamicTypedTree>,
+        target: SqlType,
+    },
+    Function {
+        function: ScalarFunction,
+        args: Vec<DynamicTypedTree>,
+    },
+    /// `argument` is `None` for `count(*)`
+    Aggregate {
+        function: AggregateFunction,
+        argument: Option<Box<DynamicTypedTree>>,
+        distinct: bool,
+    },
+}
+
+impl DynamicTypedTree {
+    pub fn has_aggregates(&self) -> bool {
+        match self {
+            DynamicTypedTree::Operation { left, right, .. } => left.has_aggregates() || right.has_aggregates(),
+            DynamicTypedTree::UnaryOperation { item, .. } => item.has_aggregates(),
+            DynamicTypedTree::Item(_) => false,
+            DynamicTypedTree::Cast { tree, .. } => tree.has_aggregates(),
+            DynamicTypedTree::Function { args, .. } => args.iter().any(DynamicTypedTree::has_aggregates),
+            DynamicTypedTree::Aggregate { .. } => true,
+        }
+    }
+}
+
+/// renders the tree as an SQL expression, operations are parenthesized
+impl Display for DynamicTypedTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicTypedTree::Operation { left, op, right } => write!(f, "({} {} {})", left, op, right),
+            DynamicTypedTree::UnaryOperation {
+                op: UnaryOperation::Minus,
+                item,
+            } => write!(f, "(-{})", item),
+            DynamicTypedTree::UnaryOperation {
+                op: UnaryOperation::Not,
+                item,
+            } => write!(f, "(NOT {})", item),
+            DynamicTypedTree::UnaryOperation { op, item } => write!(f, "({} {})", item, op),
+            DynamicTypedTree::Item(DynamicTypedItem::Const(value)) => write!(f, "{}", value),
+            DynamicTypedTree::Item(DynamicTypedItem::Column(name)) => write!(f, "{}", name),
+            DynamicTypedTree::Cast { tree, target } => write!(f, "({})::{}", tree, target),
+            DynamicTypedTree::Function {
+                function: ScalarFunction::CurrentDate,
+                ..
+            } => write!(f, "{}", ScalarFunction::CurrentDate),
+            DynamicTypedTree::Function { function, args } => {
+                let args = args.iter().map(ToString::to_string).collect::<Vec<String>>();
+                write!(f, "{}({})", function, args.join(", "))
+            }
+            DynamicTypedTree::Aggregate {
+                function,
+                argument,
+                distinct,
+            } => {
+                write!(f, "{}(", function)?;
+                if *distinct {
+                    write!(f, "DISTINCT ")?;
+                }
+                match argument {
+                    None => write!(f, "*)"),
+                    Some(argument) => write!(f, "{})", argument),
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data_manipulation_operators::JoinKind;
 use data_manipulation_untyped_tree::{DynamicUntypedTree, StaticUntypedTree};
 use definition::FullTableName;
 
 #[derive(Debug, PartialEq)]
 pub struct InsertQuery {
     pub full_table_name: FullTableName,
+    /// values of omitted columns and of `DEFAULT` are replaced with default expressions of the columns
     pub values: Vec<Vec<Option<StaticUntypedTree>>>,
+    /// named predicates of `CHECK` constraints of the table
+    pub checks: Vec<(String, DynamicUntypedTree)>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -26,17 +30,47 @@ pub struct UpdateQuery {
     pub full_table_name: FullTableName,
     pub column_names: Vec<String>,
     pub assignments: Vec<DynamicUntypedTree>,
+    pub filter: Option<DynamicUntypedTree>,
+    pub checks: Vec<(String, DynamicUntypedTree)>,
 }
 
+/// rows are sorted by value of `expr`
 #[derive(Debug, PartialEq)]
-pub struct SelectQuery {
+pub struct OrderBy {
+    pub expr: DynamicUntypedTree,
+    pub ascending: bool,
+    pub nulls_first: bool,
+}
+
+/// table joined to the tables that precede it in `FROM` clause
+#[derive(Debug, PartialEq)]
+pub struct Join {
+    pub kind: JoinKind,
     pub full_table_name: FullTableName,
+    pub relation: String,
+    pub condition: Option<DynamicUntypedTree>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SelectQuery {
+    /// table in `FROM` clause, `None` when the query has no `FROM` clause
+    pub full_table_name: Option<FullTableName>,
+    /// alias or name of the table that qualifies its columns
+    pub relation: String,
+    pub joins: Vec<Join>,
     pub projection_items: Vec<DynamicUntypedTree>,
+    pub filter: Option<DynamicUntypedTree>,
+    pub group_by: Vec<DynamicUntypedTree>,
+    pub having: Option<DynamicUntypedTree>,
+    pub order_by: Vec<OrderBy>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct DeleteQuery {
     pub full_table_name: FullTableName,
+    pub filter: Option<DynamicUntypedTree>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -13,13 +13,13 @@
 // limitations under the License.
 
 use bigdecimal::BigDecimal;
-use data_manipulation_operators::Operation;
+use data_manipulation_operators::{AggregateFunction, Operation, ScalarFunction, UnaryOperation};
 use std::{
     fmt,
     fmt::{Display, Formatter},
     str::FromStr,
 };
-use types::{SqlType, SqlTypeFamily};
+use types::{Num, SqlType, SqlTypeFamily};
 
 #[derive(PartialEq, Debug, Copy, Clone, Eq)]
 pub struct Bool(pub bool);
@@ -51,6 +51,7 @@ pub enum ImplicitCastError {
     StringDataRightTruncation(SqlType),                              // Error code: 22001
     DatatypeMismatch { column_type: SqlType, source_type: SqlType }, // Error code: 42804
     InvalidInputSyntaxForType { sql_type: SqlType, value: String },  // Error code: 22P02
+    NumericValueOutOfRange(SqlType),                                 // Error code: 22003
 }
 
 impl ImplicitCastError {
@@ -71,6 +72,10 @@ impl ImplicitCastError {
             value: value.to_string(),
         }
     }
+
+    pub fn numeric_value_out_of_range(sql_type: SqlType) -> ImplicitCastError {
+        ImplicitCastError::NumericValueOutOfRange(sql_type)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -79,7 +84,7 @@ pub enum StaticUntypedItem {
     Param(usize),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum DynamicUntypedItem {
     Const(UntypedValue),
     Param(usize),
@@ -121,7 +126,12 @@ impl UntypedValue {
                         Ok(UntypedValue::String(r))
                     }
                 }
-                SqlType::Num(_) => Err(ImplicitCastError::datatype_mismatch(target_type, SqlType::bool())),
+                SqlType::Num(_)
+                | SqlType::Temporal(_)
+                | SqlType::Bytea
+                | SqlType::Uuid
+                | SqlType::Json
+                | SqlType::Jsonb => Err(ImplicitCastError::datatype_mismatch(target_type, SqlType::bool())),
             },
             UntypedValue::String(string) => match target_type {
                 SqlType::Bool => match Bool::from_str(&string) {
@@ -131,24 +141,42 @@ impl UntypedValue {
                         Err(ImplicitCastError::invalid_input_syntax_for_type(target_type, string))
                     }
                 },
-                SqlType::Str { .. } => Ok(UntypedValue::String(string.clone())),
+                SqlType::Str { len, .. } => {
+                    if string.chars().count() as u64 > len {
+                        Err(ImplicitCastError::string_data_right_truncation(target_type))
+                    } else {
+                        Ok(UntypedValue::String(string.clone()))
+                    }
+                }
                 SqlType::Num(_) => match BigDecimal::from_str(&string) {
-                    Ok(num) => Ok(UntypedValue::Number(num)),
+                    Ok(num) => UntypedValue::Number(num).implicit_cast_to(target_type),
                     Err(parse_error) => {
                         log::debug!("Could not cast {:?} to bool due to {:?}", string, parse_error);
                         Err(ImplicitCastError::invalid_input_syntax_for_type(target_type, string))
                     }
                 },
+                // dates, times, intervals, binary strings, uuids and json are parsed from strings during evaluation
+                SqlType::Temporal(_) | SqlType::Bytea | SqlType::Uuid | SqlType::Json | SqlType::Jsonb => {
+                    Ok(UntypedValue::String(string.clone()))
+                }
             },
             UntypedValue::Number(num) => match target_type {
-                SqlType::Bool => {
+                SqlType::Bool
+                | SqlType::Temporal(_)
+                | SqlType::Bytea
+                | SqlType::Uuid
+                | SqlType::Json
+                | SqlType::Jsonb => {
                     if num.is_integer() {
                         if &BigDecimal::from(i32::MIN) <= num && num <= &BigDecimal::from(i32::MAX) {
                             Err(ImplicitCastError::datatype_mismatch(target_type, SqlType::integer()))
                         } else if &BigDecimal::from(i64::MIN) <= num && num <= &BigDecimal::from(i64::MAX) {
                             Err(ImplicitCastError::datatype_mismatch(target_type, SqlType::big_int()))
                         } else {
-                            unimplemented!("NUMERIC types are not implemented")
+                            Err(ImplicitCastError::datatype_mismatch(
+                                target_type,
+                                SqlType::unbounded_numeric(),
+                            ))
                         }
                     } else if &BigDecimal::from_str(&f32::MIN.to_string()).unwrap() <= num
                         && num <= &BigDecimal::from_str(&f32::MAX.to_string()).unwrap()
@@ -162,7 +190,10 @@ impl UntypedValue {
                             SqlType::double_precision(),
                         ))
                     } else {
-                        unimplemented!("NUMERIC types are not implemented")
+                        Err(ImplicitCastError::datatype_mismatch(
+                            target_type,
+                            SqlType::unbounded_numeric(),
+                        ))
                     }
                 }
                 SqlType::Str { len, .. } => {
@@ -173,13 +204,71 @@ impl UntypedValue {
                         Err(ImplicitCastError::string_data_right_truncation(target_type))
                     }
                 }
-                SqlType::Num(_) => Ok(UntypedValue::Number(num.clone())),
+                SqlType::Num(Num::Numeric { precision: None, .. }) => Ok(UntypedValue::Number(num.clone())),
+                SqlType::Num(Num::Numeric {
+                    precision: Some(precision),
+                    scale,
+                }) => {
+                    let num = round_to_scale(num, scale as i64);
+                    let limit = BigDecimal::from_str(&format!("1e{}", precision - scale)).unwrap();
+                    if num.abs() < limit {
+                        Ok(UntypedValue::Number(num))
+                    } else {
+                        Err(ImplicitCastError::numeric_value_out_of_range(target_type))
+                    }
+                }
+                SqlType::Num(num_type) => {
+                    let (min, max, num) = match num_type {
+                        Num::SmallInt => (
+                            BigDecimal::from(i16::MIN),
+                            BigDecimal::from(i16::MAX),
+                            round_to_scale(num, 0),
+                        ),
+                        Num::Integer => (
+                            BigDecimal::from(i32::MIN),
+                            BigDecimal::from(i32::MAX),
+                            round_to_scale(num, 0),
+                        ),
+                        Num::BigInt => (
+                            BigDecimal::from(i64::MIN),
+                            BigDecimal::from(i64::MAX),
+                            round_to_scale(num, 0),
+                        ),
+                        Num::Real => (
+                            BigDecimal::from_str(&f32::MIN.to_string()).unwrap(),
+                            BigDecimal::from_str(&f32::MAX.to_string()).unwrap(),
+                            num.clone(),
+                        ),
+                        Num::Double => (
+                            BigDecimal::from_str(&f64::MIN.to_string()).unwrap(),
+                            BigDecimal::from_str(&f64::MAX.to_string()).unwrap(),
+                            num.clone(),
+                        ),
+                        Num::Numeric { .. } => unreachable!("numerics are cast above"),
+                    };
+                    if min <= num && num <= max {
+                        Ok(UntypedValue::Number(num))
+                    } else {
+                        Err(ImplicitCastError::numeric_value_out_of_range(target_type))
+                    }
+                }
             },
             UntypedValue::Null => Ok(UntypedValue::Null),
         }
     }
 }
 
+/// rounds half away from zero to `scale` digits after the decimal point
+/// as it is done when numbers are stored into integer and numeric columns
+pub fn round_to_scale(num: &BigDecimal, scale: i64) -> BigDecimal {
+    let half = BigDecimal::from_str(&format!("5e-{}", scale + 1)).unwrap();
+    if num < &BigDecimal::from(0) {
+        (num - half).with_scale(scale)
+    } else {
+        (num + half).with_scale(scale)
+    }
+}
+
 impl Display for UntypedValue {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -199,27 +288,73 @@ pub enum StaticUntypedTree {
         op: Operation,
         right: Box<StaticUntypedTree>,
     },
+    UnaryOperation {
+        op: UnaryOperation,
+        item: Box<StaticUntypedTree>,
+    },
     Item(StaticUntypedItem),
+    Cast {
+        tree: Box<StaticUntypedTree>,
+        target: SqlType,
+    },
+    Function {
+        function: ScalarFunction,
+        args: Vec<StaticUntypedTree>,
+    },
 }
 
 impl StaticUntypedTree {
     pub fn kind(&self) -> Option<SqlTypeFamily> {
         match self {
             StaticUntypedTree::Operation { .. } => None,
+            StaticUntypedTree::UnaryOperation { .. } => None,
             StaticUntypedTree::Item(StaticUntypedItem::Const(value)) => value.kind(),
             StaticUntypedTree::Item(StaticUntypedItem::Param(_)) => None,
+            StaticUntypedTree::Cast { target, .. } => Some(target.family()),
+            StaticUntypedTree::Function { .. } => None,
         }
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum DynamicUntypedTree {
     Operation {
         left: Box<DynamicUntypedTree>,
         op: Operation,
         right: Box<DynamicUntypedTree>,
     },
+    UnaryOperation {
+        op: UnaryOperation,
+        item: Box<DynamicUntypedTree>,
+    },
     Item(DynamicUntypedItem),
+    Cast {
+        tree: Box<DynamicUntypedTree>,
+        target: SqlType,
+    },
+    Function {
+        function: ScalarFunction,
+        args: Vec<DynamicUntypedTree>,
+    },
+    /// `argument` is `None` for `count(*)`
+    Aggregate {
+        function: AggregateFunction,
+        argument: Option<Box<DynamicUntypedTree>>,
+        distinct: bool,
+    },
+}
+
+impl DynamicUntypedTree {
+    pub fn has_aggregates(&self) -> bool {
+        match self {
+            DynamicUntypedTree::Operation { left, right, .. } => left.has_aggregates() || right.has_aggregates(),
+            DynamicUntypedTree::UnaryOperation { item, .. } => item.has_aggregates(),
+            DynamicUntypedTree::Item(_) => false,
+            DynamicUntypedTree::Cast { tree, .. } => tree.has_aggregates(),
+            DynamicUntypedTree::Function { args, .. } => args.iter().any(DynamicUntypedTree::has_aggregates),
+            DynamicUntypedTree::Aggregate { .. } => true,
+        }
+    }
 }
 
 #[cfg(test)]
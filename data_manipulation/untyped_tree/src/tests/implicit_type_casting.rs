@@ -77,6 +77,30 @@ mod strings {
         );
     }
 
+    #[test]
+    fn to_number_out_of_range() {
+        assert_eq!(
+            UntypedValue::String("32768".to_owned()).implicit_cast_to(SqlType::small_int()),
+            Err(ImplicitCastError::numeric_value_out_of_range(SqlType::small_int()))
+        );
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(
+            UntypedValue::String("12345".to_owned()).implicit_cast_to(SqlType::var_char(5)),
+            Ok(UntypedValue::String("12345".to_owned()))
+        );
+    }
+
+    #[test]
+    fn to_string_too_long() {
+        assert_eq!(
+            UntypedValue::String("123456".to_owned()).implicit_cast_to(SqlType::var_char(5)),
+            Err(ImplicitCastError::string_data_right_truncation(SqlType::var_char(5)))
+        );
+    }
+
     #[test]
     fn to_number_invalid() {
         assert_eq!(
@@ -142,3 +166,66 @@ mod numbers {
         );
     }
 }
+
+#[cfg(test)]
+mod numbers_to_numbers {
+    use super::*;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(
+            UntypedValue::Number(BigDecimal::from(32767)).implicit_cast_to(SqlType::small_int()),
+            Ok(UntypedValue::Number(BigDecimal::from(32767)))
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(
+            UntypedValue::Number(BigDecimal::from(-2_147_483_649i64)).implicit_cast_to(SqlType::integer()),
+            Err(ImplicitCastError::numeric_value_out_of_range(SqlType::integer()))
+        );
+    }
+
+    #[test]
+    fn fraction_to_integer() {
+        assert_eq!(
+            UntypedValue::Number(BigDecimal::from_str("1.5").unwrap()).implicit_cast_to(SqlType::integer()),
+            Ok(UntypedValue::Number(BigDecimal::from(2)))
+        );
+        assert_eq!(
+            UntypedValue::Number(BigDecimal::from_str("-1.4").unwrap()).implicit_cast_to(SqlType::integer()),
+            Ok(UntypedValue::Number(BigDecimal::from(-1)))
+        );
+    }
+
+    #[test]
+    fn rounded_to_numeric_scale() {
+        let number = |value: &str| UntypedValue::Number(BigDecimal::from_str(value).unwrap());
+        assert_eq!(
+            number("123.455").implicit_cast_to(SqlType::numeric(5, 2)),
+            Ok(number("123.46"))
+        );
+        assert_eq!(
+            number("-0.5").implicit_cast_to(SqlType::numeric(3, 0)),
+            Ok(number("-1"))
+        );
+        assert_eq!(
+            number("12345678901234567890.123456789").implicit_cast_to(SqlType::unbounded_numeric()),
+            Ok(number("12345678901234567890.123456789"))
+        );
+    }
+
+    #[test]
+    fn numeric_overflow() {
+        let number = |value: &str| UntypedValue::Number(BigDecimal::from_str(value).unwrap());
+        assert_eq!(
+            number("999.995").implicit_cast_to(SqlType::numeric(5, 2)),
+            Err(ImplicitCastError::numeric_value_out_of_range(SqlType::numeric(5, 2)))
+        );
+        assert_eq!(
+            number("1").implicit_cast_to(SqlType::numeric(2, 2)),
+            Err(ImplicitCastError::numeric_value_out_of_range(SqlType::numeric(2, 2)))
+        );
+    }
+}
@@ -18,7 +18,7 @@ use std::{
 };
 use types::SqlType;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FullTableName((String, String));
 
 impl FullTableName {
@@ -114,11 +114,29 @@ impl Display for SchemaNamingError {
     }
 }
 
+/// `CHECK` constraint of a column, its predicate is kept as SQL text
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckDef {
+    pub name: String,
+    pub predicate: String,
+}
+
+/// `NOT NULL`, `DEFAULT` and `CHECK` constraints of a column
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnConstraints {
+    pub not_null: bool,
+    /// SQL text of an expression that is stored when a value of the column is not specified
+    pub default_expr: Option<String>,
+    pub check: Option<CheckDef>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ColumnDef {
     name: String,
     sql_type: SqlType,
     ord_num: usize,
+    relation: Option<String>,
+    constraints: ColumnConstraints,
 }
 
 impl ColumnDef {
@@ -127,6 +145,30 @@ impl ColumnDef {
             name,
             sql_type,
             ord_num,
+            relation: None,
+            constraints: ColumnConstraints::default(),
+        }
+    }
+
+    pub fn with_constraints(self, constraints: ColumnConstraints) -> ColumnDef {
+        ColumnDef { constraints, ..self }
+    }
+
+    /// the same column referenced as `relation.column` by queries that read several tables
+    pub fn qualified<R: ToString>(&self, relation: R, ord_num: usize) -> ColumnDef {
+        ColumnDef {
+            name: self.name.clone(),
+            sql_type: self.sql_type,
+            ord_num,
+            relation: Some(relation.to_string()),
+            constraints: self.constraints.clone(),
+        }
+    }
+
+    pub fn qualified_name(&self) -> String {
+        match &self.relation {
+            None => self.name.clone(),
+            Some(relation) => format!("{}.{}", relation, self.name),
         }
     }
 
@@ -138,8 +180,107 @@ impl ColumnDef {
         self.sql_type
     }
 
+    pub fn constraints(&self) -> &ColumnConstraints {
+        &self.constraints
+    }
+
     pub fn has_name(&self, name: &str) -> bool {
-        self.name == name
+        match &self.relation {
+            None => self.name == name,
+            Some(relation) => {
+                name.strip_prefix(relation.as_str())
+                    .and_then(|name| name.strip_prefix('.'))
+                    == Some(self.name.as_str())
+            }
+        }
+    }
+}
+
+/// unique and primary key constraints are enforced with indexes named after them
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum IndexKind {
+    Plain,
+    Unique,
+    PrimaryKey,
+}
+
+impl IndexKind {
+    pub fn is_unique(&self) -> bool {
+        self != &IndexKind::Plain
+    }
+
+    pub fn kind_id(&self) -> u64 {
+        match self {
+            IndexKind::Plain => 0,
+            IndexKind::Unique => 1,
+            IndexKind::PrimaryKey => 2,
+        }
+    }
+
+    pub fn from_kind_id(kind_id: u64) -> IndexKind {
+        match kind_id {
+            0 => IndexKind::Plain,
+            1 => IndexKind::Unique,
+            2 => IndexKind::PrimaryKey,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// what happens to referencing rows when rows of a referenced table are deleted or updated,
+/// `NO ACTION` and `RESTRICT` both reject the change
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+}
+
+impl ReferentialAction {
+    pub fn action_id(&self) -> u64 {
+        match self {
+            ReferentialAction::NoAction => 0,
+            ReferentialAction::Restrict => 1,
+            ReferentialAction::Cascade => 2,
+            ReferentialAction::SetNull => 3,
+        }
+    }
+
+    pub fn from_action_id(action_id: u64) -> ReferentialAction {
+        match action_id {
+            0 => ReferentialAction::NoAction,
+            1 => ReferentialAction::Restrict,
+            2 => ReferentialAction::Cascade,
+            3 => ReferentialAction::SetNull,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// secondary index over values of `columns` of a table
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexDef {
+    name: String,
+    columns: Vec<String>,
+    kind: IndexKind,
+}
+
+impl IndexDef {
+    pub fn new(name: String, columns: Vec<String>, kind: IndexKind) -> IndexDef {
+        IndexDef { name, columns, kind }
+    }
+
+    pub fn kind(&self) -> IndexKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn columns(&self) -> &[String] {
+        &self.columns
     }
 }
 
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pg_wire::PgType;
+use pg_model::pg_types::PgType;
 use sql_ast::DataType;
 use std::{
     convert::TryFrom,
@@ -34,6 +34,16 @@ pub enum SqlTypeFamily {
     BigInt,
     Real,
     Double,
+    Numeric,
+    Date,
+    Time,
+    Timestamp,
+    TimestampTz,
+    Interval,
+    Bytea,
+    Uuid,
+    Json,
+    Jsonb,
 }
 
 impl SqlTypeFamily {
@@ -60,6 +70,19 @@ impl SqlTypeFamily {
             Ok(*self)
         } else if self.is_int() && other.is_float() {
             Ok(*other)
+        } else if self == &SqlTypeFamily::Numeric && other.is_int() || self.is_int() && other == &SqlTypeFamily::Numeric
+        {
+            Ok(SqlTypeFamily::Numeric)
+        } else if self == &SqlTypeFamily::Numeric && other.is_float()
+            || self.is_float() && other == &SqlTypeFamily::Numeric
+        {
+            Ok(SqlTypeFamily::Double)
+        } else if self.is_date_time() && other.is_date_time() && self != other {
+            if self == &SqlTypeFamily::TimestampTz || other == &SqlTypeFamily::TimestampTz {
+                Ok(SqlTypeFamily::TimestampTz)
+            } else {
+                Ok(SqlTypeFamily::Timestamp)
+            }
         } else if self != other {
             Err(IncomparableSqlTypeFamilies {
                 left: *self,
@@ -70,20 +93,95 @@ impl SqlTypeFamily {
         }
     }
 
-    fn is_float(&self) -> bool {
+    pub fn is_float(&self) -> bool {
         self == &SqlTypeFamily::Real || self == &SqlTypeFamily::Double
     }
 
-    fn is_int(&self) -> bool {
+    pub fn is_int(&self) -> bool {
         self == &SqlTypeFamily::SmallInt || self == &SqlTypeFamily::Integer || self == &SqlTypeFamily::BigInt
     }
+
+    /// dates and timestamps, which could be compared with each other
+    pub fn is_date_time(&self) -> bool {
+        self == &SqlTypeFamily::Date || self == &SqlTypeFamily::Timestamp || self == &SqlTypeFamily::TimestampTz
+    }
+
+    pub fn is_temporal(&self) -> bool {
+        self.is_date_time() || self == &SqlTypeFamily::Time || self == &SqlTypeFamily::Interval
+    }
+
+    /// types whose constants are written as string literals
+    pub fn is_parsed_from_string(&self) -> bool {
+        self.is_temporal() || self.is_json() || self == &SqlTypeFamily::Bytea || self == &SqlTypeFamily::Uuid
+    }
+
+    pub fn is_json(&self) -> bool {
+        self == &SqlTypeFamily::Json || self == &SqlTypeFamily::Jsonb
+    }
+
+    /// type that values of the family are described with, strings are of unlimited length
+    pub fn sql_type(&self) -> SqlType {
+        match self {
+            SqlTypeFamily::Bool => SqlType::bool(),
+            SqlTypeFamily::String => SqlType::text(),
+            SqlTypeFamily::SmallInt => SqlType::small_int(),
+            SqlTypeFamily::Integer => SqlType::integer(),
+            SqlTypeFamily::BigInt => SqlType::big_int(),
+            SqlTypeFamily::Real => SqlType::real(),
+            SqlTypeFamily::Double => SqlType::double_precision(),
+            SqlTypeFamily::Numeric => SqlType::unbounded_numeric(),
+            SqlTypeFamily::Date => SqlType::date(),
+            SqlTypeFamily::Time => SqlType::time(),
+            SqlTypeFamily::Timestamp => SqlType::timestamp(),
+            SqlTypeFamily::TimestampTz => SqlType::timestamp_with_time_zone(),
+            SqlTypeFamily::Interval => SqlType::interval(),
+            SqlTypeFamily::Bytea => SqlType::bytea(),
+            SqlTypeFamily::Uuid => SqlType::uuid(),
+            SqlTypeFamily::Json => SqlType::json(),
+            SqlTypeFamily::Jsonb => SqlType::jsonb(),
+        }
+    }
+}
+
+impl Display for SqlTypeFamily {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SqlTypeFamily::Bool => write!(f, "boolean"),
+            SqlTypeFamily::String => write!(f, "text"),
+            SqlTypeFamily::SmallInt => write!(f, "smallint"),
+            SqlTypeFamily::Integer => write!(f, "integer"),
+            SqlTypeFamily::BigInt => write!(f, "bigint"),
+            SqlTypeFamily::Real => write!(f, "real"),
+            SqlTypeFamily::Double => write!(f, "double precision"),
+            SqlTypeFamily::Numeric => write!(f, "numeric"),
+            SqlTypeFamily::Date => write!(f, "date"),
+            SqlTypeFamily::Time => write!(f, "time without time zone"),
+            SqlTypeFamily::Timestamp => write!(f, "timestamp without time zone"),
+            SqlTypeFamily::TimestampTz => write!(f, "timestamp with time zone"),
+            SqlTypeFamily::Interval => write!(f, "interval"),
+            SqlTypeFamily::Bytea => write!(f, "bytea"),
+            SqlTypeFamily::Uuid => write!(f, "uuid"),
+            SqlTypeFamily::Json => write!(f, "json"),
+            SqlTypeFamily::Jsonb => write!(f, "jsonb"),
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Copy, Clone, Hash, Ord, PartialOrd)]
 pub enum SqlType {
     Bool,
-    Str { len: u64, kind: Str },
+    Str {
+        len: u64,
+        kind: Str,
+    },
     Num(Num),
+    Temporal(Temporal),
+    Bytea,
+    Uuid,
+    /// json documents kept as they are written
+    Json,
+    /// json documents kept in a decomposed binary format
+    Jsonb,
 }
 
 #[derive(PartialEq, Eq, Debug, Copy, Clone, Hash, Ord, PartialOrd)]
@@ -93,12 +191,30 @@ pub enum Num {
     BigInt,
     Real,
     Double,
+    /// exact number with at most `precision` digits, `scale` of them after the
+    /// decimal point; values of any precision and scale are stored if it is not specified
+    Numeric {
+        precision: Option<u32>,
+        scale: u32,
+    },
+}
+
+/// timestamps with time zone are kept in UTC
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash, Ord, PartialOrd)]
+pub enum Temporal {
+    Date,
+    Time,
+    Timestamp,
+    TimestampTz,
+    Interval,
 }
 
 #[derive(PartialEq, Eq, Debug, Copy, Clone, Hash, Ord, PartialOrd)]
 pub enum Str {
     Const,
     Var,
+    /// strings of unlimited length
+    Text,
 }
 
 impl SqlType {
@@ -106,10 +222,21 @@ impl SqlType {
         match self {
             SqlType::Bool => SqlTypeFamily::Bool,
             SqlType::Str { .. } => SqlTypeFamily::String,
-            SqlType::Num(Num::SmallInt) | SqlType::Num(Num::Integer) | SqlType::Num(Num::BigInt) => {
-                SqlTypeFamily::Integer
-            }
-            SqlType::Num(Num::Real) | SqlType::Num(Num::Double) => SqlTypeFamily::Real,
+            SqlType::Num(Num::SmallInt) => SqlTypeFamily::SmallInt,
+            SqlType::Num(Num::Integer) => SqlTypeFamily::Integer,
+            SqlType::Num(Num::BigInt) => SqlTypeFamily::BigInt,
+            SqlType::Num(Num::Real) => SqlTypeFamily::Real,
+            SqlType::Num(Num::Double) => SqlTypeFamily::Double,
+            SqlType::Num(Num::Numeric { .. }) => SqlTypeFamily::Numeric,
+            SqlType::Temporal(Temporal::Date) => SqlTypeFamily::Date,
+            SqlType::Temporal(Temporal::Time) => SqlTypeFamily::Time,
+            SqlType::Temporal(Temporal::Timestamp) => SqlTypeFamily::Timestamp,
+            SqlType::Temporal(Temporal::TimestampTz) => SqlTypeFamily::TimestampTz,
+            SqlType::Temporal(Temporal::Interval) => SqlTypeFamily::Interval,
+            SqlType::Bytea => SqlTypeFamily::Bytea,
+            SqlType::Uuid => SqlTypeFamily::Uuid,
+            SqlType::Json => SqlTypeFamily::Json,
+            SqlType::Jsonb => SqlTypeFamily::Jsonb,
         }
     }
 
@@ -133,6 +260,40 @@ impl SqlType {
         SqlType::Num(Num::Double)
     }
 
+    pub fn numeric(precision: u32, scale: u32) -> SqlType {
+        SqlType::Num(Num::Numeric {
+            precision: Some(precision),
+            scale,
+        })
+    }
+
+    pub fn unbounded_numeric() -> SqlType {
+        SqlType::Num(Num::Numeric {
+            precision: None,
+            scale: 0,
+        })
+    }
+
+    pub fn date() -> SqlType {
+        SqlType::Temporal(Temporal::Date)
+    }
+
+    pub fn time() -> SqlType {
+        SqlType::Temporal(Temporal::Time)
+    }
+
+    pub fn timestamp() -> SqlType {
+        SqlType::Temporal(Temporal::Timestamp)
+    }
+
+    pub fn timestamp_with_time_zone() -> SqlType {
+        SqlType::Temporal(Temporal::TimestampTz)
+    }
+
+    pub fn interval() -> SqlType {
+        SqlType::Temporal(Temporal::Interval)
+    }
+
     pub fn bool() -> SqlType {
         SqlType::Bool
     }
@@ -145,6 +306,29 @@ impl SqlType {
         SqlType::Str { len, kind: Str::Var }
     }
 
+    pub fn text() -> SqlType {
+        SqlType::Str {
+            len: u64::MAX,
+            kind: Str::Text,
+        }
+    }
+
+    pub fn bytea() -> SqlType {
+        SqlType::Bytea
+    }
+
+    pub fn uuid() -> SqlType {
+        SqlType::Uuid
+    }
+
+    pub fn json() -> SqlType {
+        SqlType::Json
+    }
+
+    pub fn jsonb() -> SqlType {
+        SqlType::Jsonb
+    }
+
     pub fn type_id(&self) -> u64 {
         match self {
             SqlType::Bool => 0,
@@ -155,25 +339,61 @@ impl SqlType {
             SqlType::Num(Num::BigInt) => 5,
             SqlType::Num(Num::Real) => 6,
             SqlType::Num(Num::Double) => 7,
+            SqlType::Num(Num::Numeric { .. }) => 8,
+            SqlType::Temporal(Temporal::Date) => 9,
+            SqlType::Temporal(Temporal::Time) => 10,
+            SqlType::Temporal(Temporal::Timestamp) => 11,
+            SqlType::Temporal(Temporal::TimestampTz) => 12,
+            SqlType::Temporal(Temporal::Interval) => 13,
+            SqlType::Str { kind: Str::Text, .. } => 14,
+            SqlType::Bytea => 15,
+            SqlType::Uuid => 16,
+            SqlType::Json => 17,
+            SqlType::Jsonb => 18,
         }
     }
 
-    pub fn from_type_id(type_id: u64, chars_len: u64) -> SqlType {
+    /// length of strings or precision and scale of numbers packed into one number
+    /// to be kept in a catalog along with `type_id`
+    pub fn type_modifier(&self) -> Option<u64> {
+        match self {
+            SqlType::Num(Num::Numeric {
+                precision: Some(precision),
+                scale,
+            }) => Some(((*precision as u64) << 16) | *scale as u64),
+            _ => self.chars_len(),
+        }
+    }
+
+    pub fn from_type_id(type_id: u64, type_modifier: u64) -> SqlType {
         match type_id {
             0 => SqlType::Bool,
-            1 => SqlType::char(chars_len),
-            2 => SqlType::var_char(chars_len),
+            1 => SqlType::char(type_modifier),
+            2 => SqlType::var_char(type_modifier),
             3 => SqlType::small_int(),
             4 => SqlType::integer(),
             5 => SqlType::big_int(),
             6 => SqlType::real(),
             7 => SqlType::double_precision(),
+            8 if type_modifier == 0 => SqlType::unbounded_numeric(),
+            8 => SqlType::numeric((type_modifier >> 16) as u32, (type_modifier & 0xffff) as u32),
+            9 => SqlType::date(),
+            10 => SqlType::time(),
+            11 => SqlType::timestamp(),
+            12 => SqlType::timestamp_with_time_zone(),
+            13 => SqlType::interval(),
+            14 => SqlType::text(),
+            15 => SqlType::bytea(),
+            16 => SqlType::uuid(),
+            17 => SqlType::json(),
+            18 => SqlType::jsonb(),
             _ => unreachable!(),
         }
     }
 
     pub fn chars_len(&self) -> Option<u64> {
         match self {
+            SqlType::Str { kind: Str::Text, .. } => None,
             SqlType::Str { len, .. } => Some(*len),
             _ => None,
         }
@@ -188,9 +408,35 @@ impl TryFrom<&DataType> for SqlType {
             DataType::SmallInt => Ok(SqlType::small_int()),
             DataType::Int => Ok(SqlType::integer()),
             DataType::BigInt => Ok(SqlType::big_int()),
-            DataType::Char(len) => Ok(SqlType::char(len.unwrap_or(255))),
-            DataType::Varchar(len) => Ok(SqlType::var_char(len.unwrap_or(255))),
+            DataType::Real => Ok(SqlType::real()),
+            DataType::Double => Ok(SqlType::double_precision()),
+            DataType::Float(Some(precision)) if (1..=24).contains(precision) => Ok(SqlType::real()),
+            DataType::Float(Some(precision)) if (25..=53).contains(precision) => Ok(SqlType::double_precision()),
+            DataType::Float(None) => Ok(SqlType::double_precision()),
+            DataType::Decimal(None, None) => Ok(SqlType::unbounded_numeric()),
+            DataType::Decimal(Some(precision), scale) => {
+                let scale = scale.unwrap_or(0);
+                if (1..=1000).contains(precision) && scale <= *precision {
+                    Ok(SqlType::numeric(*precision as u32, scale as u32))
+                } else {
+                    Err(NotSupportedType)
+                }
+            }
+            DataType::Char(len) => Ok(SqlType::char(len.unwrap_or(1))),
+            DataType::Varchar(len) => Ok(SqlType::var_char(len.unwrap_or(u64::MAX))),
+            DataType::Text => Ok(SqlType::text()),
+            DataType::Bytea => Ok(SqlType::bytea()),
+            DataType::Uuid => Ok(SqlType::uuid()),
             DataType::Boolean => Ok(SqlType::Bool),
+            DataType::Date => Ok(SqlType::date()),
+            DataType::Time => Ok(SqlType::time()),
+            DataType::Timestamp => Ok(SqlType::timestamp()),
+            DataType::Interval => Ok(SqlType::interval()),
+            DataType::Custom(name) if name.to_string().eq_ignore_ascii_case("timestamptz") => {
+                Ok(SqlType::timestamp_with_time_zone())
+            }
+            DataType::Custom(name) if name.to_string().eq_ignore_ascii_case("json") => Ok(SqlType::json()),
+            DataType::Custom(name) if name.to_string().eq_ignore_ascii_case("jsonb") => Ok(SqlType::jsonb()),
             _other_type => Err(NotSupportedType),
         }
     }
@@ -203,12 +449,31 @@ impl Display for SqlType {
         match self {
             SqlType::Bool => write!(f, "bool"),
             SqlType::Str { len, kind: Str::Const } => write!(f, "char({})", len),
+            SqlType::Str {
+                len: u64::MAX,
+                kind: Str::Var,
+            } => write!(f, "varchar"),
             SqlType::Str { len, kind: Str::Var } => write!(f, "varchar({})", len),
+            SqlType::Str { kind: Str::Text, .. } => write!(f, "text"),
             SqlType::Num(Num::SmallInt) => write!(f, "smallint"),
             SqlType::Num(Num::Integer) => write!(f, "integer"),
             SqlType::Num(Num::BigInt) => write!(f, "bigint"),
             SqlType::Num(Num::Real) => write!(f, "real"),
             SqlType::Num(Num::Double) => write!(f, "double precision"),
+            SqlType::Num(Num::Numeric {
+                precision: Some(precision),
+                scale,
+            }) => write!(f, "numeric({},{})", precision, scale),
+            SqlType::Num(Num::Numeric { precision: None, .. }) => write!(f, "numeric"),
+            SqlType::Temporal(Temporal::Date) => write!(f, "date"),
+            SqlType::Temporal(Temporal::Time) => write!(f, "time"),
+            SqlType::Temporal(Temporal::Timestamp) => write!(f, "timestamp"),
+            SqlType::Temporal(Temporal::TimestampTz) => write!(f, "timestamp with time zone"),
+            SqlType::Temporal(Temporal::Interval) => write!(f, "interval"),
+            SqlType::Bytea => write!(f, "bytea"),
+            SqlType::Uuid => write!(f, "uuid"),
+            SqlType::Json => write!(f, "json"),
+            SqlType::Jsonb => write!(f, "jsonb"),
         }
     }
 }
@@ -219,10 +484,22 @@ impl Into<PgType> for &SqlType {
             SqlType::Bool => PgType::Bool,
             SqlType::Str { kind: Str::Const, .. } => PgType::Char,
             SqlType::Str { kind: Str::Var, .. } => PgType::VarChar,
+            SqlType::Str { kind: Str::Text, .. } => PgType::Text,
             SqlType::Num(Num::SmallInt) => PgType::SmallInt,
             SqlType::Num(Num::Integer) => PgType::Integer,
             SqlType::Num(Num::BigInt) => PgType::BigInt,
-            SqlType::Num(Num::Real) | SqlType::Num(Num::Double) => unreachable!(),
+            SqlType::Num(Num::Real) => PgType::Real,
+            SqlType::Num(Num::Double) => PgType::DoublePrecision,
+            SqlType::Num(Num::Numeric { .. }) => PgType::Numeric,
+            SqlType::Temporal(Temporal::Date) => PgType::Date,
+            SqlType::Temporal(Temporal::Time) => PgType::Time,
+            SqlType::Temporal(Temporal::Timestamp) => PgType::Timestamp,
+            SqlType::Temporal(Temporal::TimestampTz) => PgType::TimestampTz,
+            SqlType::Temporal(Temporal::Interval) => PgType::Interval,
+            SqlType::Bytea => PgType::Bytea,
+            SqlType::Uuid => PgType::Uuid,
+            SqlType::Json => PgType::Json,
+            SqlType::Jsonb => PgType::Jsonb,
         }
     }
 }
@@ -49,3 +49,66 @@ fn var_char() {
     let pg_type: PgType = (&SqlType::var_char(0)).into();
     assert_eq!(pg_type, PgType::VarChar);
 }
+
+#[test]
+fn real() {
+    let pg_type: PgType = (&SqlType::real()).into();
+    assert_eq!(pg_type, PgType::Real);
+}
+
+#[test]
+fn double_precision() {
+    let pg_type: PgType = (&SqlType::double_precision()).into();
+    assert_eq!(pg_type, PgType::DoublePrecision);
+}
+
+#[test]
+fn numeric() {
+    let pg_type: PgType = (&SqlType::numeric(10, 2)).into();
+    assert_eq!(pg_type, PgType::Numeric);
+}
+
+#[test]
+fn temporal() {
+    let pg_types: Vec<PgType> = vec![
+        (&SqlType::date()).into(),
+        (&SqlType::time()).into(),
+        (&SqlType::timestamp()).into(),
+        (&SqlType::timestamp_with_time_zone()).into(),
+        (&SqlType::interval()).into(),
+    ];
+    assert_eq!(
+        pg_types,
+        vec![
+            PgType::Date,
+            PgType::Time,
+            PgType::Timestamp,
+            PgType::TimestampTz,
+            PgType::Interval
+        ]
+    );
+}
+
+#[test]
+fn text() {
+    let pg_type: PgType = (&SqlType::text()).into();
+    assert_eq!(pg_type, PgType::Text);
+}
+
+#[test]
+fn bytea() {
+    let pg_type: PgType = (&SqlType::bytea()).into();
+    assert_eq!(pg_type, PgType::Bytea);
+}
+
+#[test]
+fn uuid() {
+    let pg_type: PgType = (&SqlType::uuid()).into();
+    assert_eq!(pg_type, PgType::Uuid);
+}
+
+#[test]
+fn json() {
+    let pg_types: Vec<PgType> = vec![(&SqlType::json()).into(), (&SqlType::jsonb()).into()];
+    assert_eq!(pg_types, vec![PgType::Json, PgType::Jsonb]);
+}
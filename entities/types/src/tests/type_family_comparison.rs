@@ -149,3 +149,90 @@ mod with_higher_in_same_type_group {
         );
     }
 }
+
+#[test]
+fn numeric_with_other_numbers() {
+    assert_eq!(
+        SqlTypeFamily::Numeric.compare(&SqlTypeFamily::Numeric),
+        Ok(SqlTypeFamily::Numeric)
+    );
+    assert_eq!(
+        SqlTypeFamily::SmallInt.compare(&SqlTypeFamily::Numeric),
+        Ok(SqlTypeFamily::Numeric)
+    );
+    assert_eq!(
+        SqlTypeFamily::Numeric.compare(&SqlTypeFamily::BigInt),
+        Ok(SqlTypeFamily::Numeric)
+    );
+    assert_eq!(
+        SqlTypeFamily::Numeric.compare(&SqlTypeFamily::Real),
+        Ok(SqlTypeFamily::Double)
+    );
+    assert_eq!(
+        SqlTypeFamily::Double.compare(&SqlTypeFamily::Numeric),
+        Ok(SqlTypeFamily::Double)
+    );
+}
+
+#[test]
+fn numeric_type_modifier() {
+    let sql_type = SqlType::numeric(10, 2);
+    assert_eq!(
+        SqlType::from_type_id(sql_type.type_id(), sql_type.type_modifier().unwrap()),
+        sql_type
+    );
+    assert_eq!(SqlType::unbounded_numeric().type_modifier(), None);
+    assert_eq!(SqlType::from_type_id(8, 0), SqlType::unbounded_numeric());
+}
+
+#[test]
+fn dates_with_timestamps() {
+    assert_eq!(
+        SqlTypeFamily::Date.compare(&SqlTypeFamily::Timestamp),
+        Ok(SqlTypeFamily::Timestamp)
+    );
+    assert_eq!(
+        SqlTypeFamily::TimestampTz.compare(&SqlTypeFamily::Date),
+        Ok(SqlTypeFamily::TimestampTz)
+    );
+    assert_eq!(
+        SqlTypeFamily::Timestamp.compare(&SqlTypeFamily::TimestampTz),
+        Ok(SqlTypeFamily::TimestampTz)
+    );
+    assert_eq!(
+        SqlTypeFamily::Date.compare(&SqlTypeFamily::Interval),
+        Err(IncomparableSqlTypeFamilies {
+            left: SqlTypeFamily::Date,
+            right: SqlTypeFamily::Interval
+        })
+    );
+}
+
+#[test]
+fn strings_without_length() {
+    assert_eq!(SqlType::try_from(&DataType::Text).ok(), Some(SqlType::text()));
+    assert_eq!(SqlType::try_from(&DataType::Char(None)).ok(), Some(SqlType::char(1)));
+    assert_eq!(
+        SqlType::try_from(&DataType::Varchar(None)).ok(),
+        Some(SqlType::var_char(u64::MAX))
+    );
+    assert_eq!(SqlType::var_char(u64::MAX).to_string(), "varchar");
+    assert_eq!(SqlType::text().type_modifier(), None);
+    assert_eq!(SqlType::from_type_id(SqlType::text().type_id(), 0), SqlType::text());
+    assert_eq!(SqlTypeFamily::String.sql_type(), SqlType::text());
+}
+
+#[test]
+fn binary_strings_with_uuids() {
+    assert_eq!(
+        SqlTypeFamily::Bytea.compare(&SqlTypeFamily::Bytea),
+        Ok(SqlTypeFamily::Bytea)
+    );
+    assert_eq!(
+        SqlTypeFamily::Uuid.compare(&SqlTypeFamily::Bytea),
+        Err(IncomparableSqlTypeFamilies {
+            left: SqlTypeFamily::Uuid,
+            right: SqlTypeFamily::Bytea
+        })
+    );
+}
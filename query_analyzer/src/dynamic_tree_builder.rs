@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::{
+    operation_mapper::OperationMapper, parse_param_index, scope::Scope, AnalysisError, AnalysisResult, Feature,
+};
+use data_manipulation_operators::{AggregateFunction, Comparison, Logical, Operation, ScalarFunction, UnaryOperation};
 use data_manipulation_untyped_tree::{Bool, DynamicUntypedItem, DynamicUntypedTree, UntypedValue};
-use definition::ColumnDef;
-
-use crate::{operation_mapper::OperationMapper, parse_param_index, AnalysisError, AnalysisResult, Feature};
+use std::convert::TryFrom;
+use types::SqlType;
 
 pub(crate) struct DynamicTreeBuilder;
 
@@ -23,69 +26,248 @@ impl DynamicTreeBuilder {
     pub(crate) fn build_from(
         root_expr: &sql_ast::Expr,
         original: &sql_ast::Statement,
-        table_columns: &[ColumnDef],
+        scope: &Scope,
     ) -> AnalysisResult<DynamicUntypedTree> {
-        Self::inner_build(root_expr, original, table_columns)
+        Self::inner_build(root_expr, original, scope)
     }
 
     fn inner_build(
         root_expr: &sql_ast::Expr,
         original: &sql_ast::Statement,
-        table_columns: &[ColumnDef],
+        scope: &Scope,
     ) -> AnalysisResult<DynamicUntypedTree> {
         match root_expr {
             sql_ast::Expr::Value(value) => Self::value(value),
-            sql_ast::Expr::Identifier(ident) => Self::ident(ident, table_columns),
-            sql_ast::Expr::BinaryOp { left, op, right } => Self::op(op, &**left, &**right, original, table_columns),
-            expr => {
-                log::warn!("Syntax error in '{:#?}' around '{:?}'", original, expr);
-                Err(AnalysisError::syntax_error(format!(
-                    "Syntax error in '{}' around '{}'",
-                    original, expr
-                )))
-            }
+            sql_ast::Expr::Identifier(ident) => Self::ident(ident, scope),
+            sql_ast::Expr::CompoundIdentifier(idents) => match idents.as_slice() {
+                [relation, column] => {
+                    scope.column(None, Some(&relation.value.to_lowercase()), &column.value.to_lowercase())
+                }
+                [schema, relation, column] => scope.column(
+                    Some(&schema.value.to_lowercase()),
+                    Some(&relation.value.to_lowercase()),
+                    &column.value.to_lowercase(),
+                ),
+                _ => Self::syntax_error(root_expr, original),
+            },
+            sql_ast::Expr::BinaryOp { left, op, right } => Self::op(op, &**left, &**right, original, scope),
+            sql_ast::Expr::UnaryOp { op, expr } => match (op, &**expr) {
+                (sql_ast::UnaryOperator::Minus, sql_ast::Expr::Value(sql_ast::Value::Number(num))) => {
+                    Self::value(&sql_ast::Value::Number(-num))
+                }
+                (sql_ast::UnaryOperator::Plus, expr) => Self::inner_build(expr, original, scope),
+                (sql_ast::UnaryOperator::Minus, expr) => Self::unary(UnaryOperation::Minus, expr, original, scope),
+                (sql_ast::UnaryOperator::Not, expr) => Self::unary(UnaryOperation::Not, expr, original, scope),
+                _ => Self::syntax_error(root_expr, original),
+            },
+            sql_ast::Expr::IsNull(expr) => Self::unary(UnaryOperation::IsNull, expr, original, scope),
+            sql_ast::Expr::IsNotNull(expr) => Self::unary(UnaryOperation::IsNotNull, expr, original, scope),
+            sql_ast::Expr::Nested(expr) => Self::inner_build(expr, original, scope),
+            sql_ast::Expr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => Self::between(expr, *negated, low, high, original, scope),
+            sql_ast::Expr::InList { expr, list, negated } => Self::in_list(expr, list, *negated, original, scope),
+            sql_ast::Expr::Function(function) => Self::function(function, original, scope),
+            sql_ast::Expr::Cast { expr, data_type } => Self::cast(Self::inner_build(expr, original, scope)?, data_type),
+            sql_ast::Expr::TypedString { data_type, value } => Self::cast(
+                DynamicUntypedTree::Item(DynamicUntypedItem::Const(UntypedValue::String(value.clone()))),
+                data_type,
+            ),
+            sql_ast::Expr::Extract { field, expr } => Ok(DynamicUntypedTree::Function {
+                function: ScalarFunction::DatePart,
+                args: vec![
+                    DynamicUntypedTree::Item(DynamicUntypedItem::Const(UntypedValue::String(
+                        field.to_string().to_lowercase(),
+                    ))),
+                    Self::inner_build(expr, original, scope)?,
+                ],
+            }),
+            expr => Self::syntax_error(expr, original),
         }
     }
 
+    fn syntax_error(expr: &sql_ast::Expr, original: &sql_ast::Statement) -> AnalysisResult<DynamicUntypedTree> {
+        log::warn!("Syntax error in '{:#?}' around '{:?}'", original, expr);
+        Err(AnalysisError::syntax_error(format!(
+            "Syntax error in '{}' around '{}'",
+            original, expr
+        )))
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn op(
         op: &sql_ast::BinaryOperator,
         left: &sql_ast::Expr,
         right: &sql_ast::Expr,
         original: &sql_ast::Statement,
-        table_columns: &[ColumnDef],
+        scope: &Scope,
+    ) -> AnalysisResult<DynamicUntypedTree> {
+        Ok(DynamicUntypedTree::Operation {
+            left: Box::new(Self::inner_build(left, original, scope)?),
+            op: OperationMapper::binary_operation(op),
+            right: Box::new(Self::inner_build(right, original, scope)?),
+        })
+    }
+
+    fn unary(
+        op: UnaryOperation,
+        expr: &sql_ast::Expr,
+        original: &sql_ast::Statement,
+        scope: &Scope,
+    ) -> AnalysisResult<DynamicUntypedTree> {
+        Ok(DynamicUntypedTree::UnaryOperation {
+            op,
+            item: Box::new(Self::inner_build(expr, original, scope)?),
+        })
+    }
+
+    /// `x BETWEEN low AND high` is computed as `x >= low AND x <= high`
+    fn between(
+        expr: &sql_ast::Expr,
+        negated: bool,
+        low: &sql_ast::Expr,
+        high: &sql_ast::Expr,
+        original: &sql_ast::Statement,
+        scope: &Scope,
     ) -> AnalysisResult<DynamicUntypedTree> {
-        let operation = OperationMapper::binary_operation(op);
-        match (
-            Self::inner_build(left, original, table_columns),
-            Self::inner_build(right, original, table_columns),
-        ) {
-            (Ok(left_item), Ok(right_item)) => Ok(DynamicUntypedTree::Operation {
-                left: Box::new(left_item),
-                op: operation,
-                right: Box::new(right_item),
+        let (low_comparison, logical, high_comparison) = if negated {
+            (Comparison::Lt, Logical::Or, Comparison::Gt)
+        } else {
+            (Comparison::GtEq, Logical::And, Comparison::LtEq)
+        };
+        let item = Self::inner_build(expr, original, scope)?;
+        Ok(DynamicUntypedTree::Operation {
+            left: Box::new(DynamicUntypedTree::Operation {
+                left: Box::new(item.clone()),
+                op: Operation::Comparison(low_comparison),
+                right: Box::new(Self::inner_build(low, original, scope)?),
             }),
-            _ => Err(AnalysisError::UndefinedFunction(operation)),
+            op: Operation::Logical(logical),
+            right: Box::new(DynamicUntypedTree::Operation {
+                left: Box::new(item),
+                op: Operation::Comparison(high_comparison),
+                right: Box::new(Self::inner_build(high, original, scope)?),
+            }),
+        })
+    }
+
+    /// `x IN (a, b)` is computed as `x = a OR x = b`
+    fn in_list(
+        expr: &sql_ast::Expr,
+        list: &[sql_ast::Expr],
+        negated: bool,
+        original: &sql_ast::Statement,
+        scope: &Scope,
+    ) -> AnalysisResult<DynamicUntypedTree> {
+        let (comparison, logical) = if negated {
+            (Comparison::NotEq, Logical::And)
+        } else {
+            (Comparison::Eq, Logical::Or)
+        };
+        let item = Self::inner_build(expr, original, scope)?;
+        let mut tree = None;
+        for value in list {
+            let compared = DynamicUntypedTree::Operation {
+                left: Box::new(item.clone()),
+                op: Operation::Comparison(comparison),
+                right: Box::new(Self::inner_build(value, original, scope)?),
+            };
+            tree = Some(match tree {
+                None => compared,
+                Some(tree) => DynamicUntypedTree::Operation {
+                    left: Box::new(tree),
+                    op: Operation::Logical(logical),
+                    right: Box::new(compared),
+                },
+            });
         }
+        match tree {
+            Some(tree) => Ok(tree),
+            None => Self::syntax_error(expr, original),
+        }
+    }
+
+    fn function(
+        function: &sql_ast::Function,
+        original: &sql_ast::Statement,
+        scope: &Scope,
+    ) -> AnalysisResult<DynamicUntypedTree> {
+        let sql_ast::Function {
+            name,
+            args,
+            over,
+            distinct,
+        } = function;
+        if over.is_some() {
+            return Err(AnalysisError::feature_not_supported(Feature::WindowFunctions));
+        }
+        if let Some(op) = OperationMapper::json_operator_function(&name.to_string()) {
+            return match args.as_slice() {
+                [left, right] => Ok(DynamicUntypedTree::Operation {
+                    left: Box::new(Self::inner_build(left, original, scope)?),
+                    op,
+                    right: Box::new(Self::inner_build(right, original, scope)?),
+                }),
+                _ => Err(AnalysisError::function_does_not_exist(name)),
+            };
+        }
+        if let Some(function) = OperationMapper::scalar_function(&name.to_string()) {
+            return Ok(DynamicUntypedTree::Function {
+                function,
+                args: args
+                    .iter()
+                    .map(|arg| Self::inner_build(arg, original, scope))
+                    .collect::<AnalysisResult<_>>()?,
+            });
+        }
+        let aggregate = match name.to_string().to_lowercase().as_str() {
+            "count" => AggregateFunction::Count,
+            "sum" => AggregateFunction::Sum,
+            "avg" => AggregateFunction::Avg,
+            "min" => AggregateFunction::Min,
+            "max" => AggregateFunction::Max,
+            "jsonb_agg" => AggregateFunction::JsonbAgg,
+            _ => return Err(AnalysisError::function_does_not_exist(name)),
+        };
+        let argument = match args.as_slice() {
+            [sql_ast::Expr::Wildcard] if aggregate == AggregateFunction::Count && !distinct => None,
+            [argument] => {
+                let argument = Self::inner_build(argument, original, scope)?;
+                if argument.has_aggregates() {
+                    return Err(AnalysisError::aggregate_function_calls_cannot_be_nested());
+                }
+                Some(Box::new(argument))
+            }
+            _ => return Err(AnalysisError::function_does_not_exist(name)),
+        };
+        Ok(DynamicUntypedTree::Aggregate {
+            function: aggregate,
+            argument,
+            distinct: *distinct,
+        })
     }
 
-    fn ident(ident: &sql_ast::Ident, table_columns: &[ColumnDef]) -> AnalysisResult<DynamicUntypedTree> {
+    fn cast(tree: DynamicUntypedTree, data_type: &sql_ast::DataType) -> AnalysisResult<DynamicUntypedTree> {
+        match SqlType::try_from(data_type) {
+            Ok(target) => Ok(DynamicUntypedTree::Cast {
+                tree: Box::new(tree),
+                target,
+            }),
+            Err(_not_supported_type_error) => Err(AnalysisError::type_is_not_supported(data_type)),
+        }
+    }
+
+    fn ident(ident: &sql_ast::Ident, scope: &Scope) -> AnalysisResult<DynamicUntypedTree> {
+        if let Some(function) = OperationMapper::keyword_function(ident) {
+            return Ok(DynamicUntypedTree::Function { function, args: vec![] });
+        }
         let sql_ast::Ident { value, .. } = ident;
         match parse_param_index(value.as_str()) {
             Some(index) => Ok(DynamicUntypedTree::Item(DynamicUntypedItem::Param(index))),
-            None => {
-                for (index, table_column) in table_columns.iter().enumerate() {
-                    let column_name = value.to_lowercase();
-                    if table_column.has_name(column_name.as_str()) {
-                        return Ok(DynamicUntypedTree::Item(DynamicUntypedItem::Column {
-                            name: table_column.name().to_owned(),
-                            sql_type: table_column.sql_type(),
-                            index,
-                        }));
-                    }
-                }
-                Err(AnalysisError::column_not_found(value))
-            }
+            None => scope.column(None, None, &value.to_lowercase()),
         }
     }
 
@@ -100,11 +282,23 @@ impl DynamicTreeBuilder {
             sql_ast::Value::NationalStringLiteral(_) => {
                 Err(AnalysisError::feature_not_supported(Feature::NationalStringLiteral))
             }
-            sql_ast::Value::HexStringLiteral(_) => Err(AnalysisError::feature_not_supported(Feature::HexStringLiteral)),
+            sql_ast::Value::HexStringLiteral(hex) => Ok(DynamicUntypedTree::Cast {
+                tree: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+                    UntypedValue::String(format!("\\x{}", hex)),
+                ))),
+                target: SqlType::bytea(),
+            }),
             sql_ast::Value::Boolean(boolean) => Ok(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
                 UntypedValue::Bool(Bool(*boolean)),
             ))),
-            sql_ast::Value::Interval { .. } => Err(AnalysisError::feature_not_supported(Feature::TimeInterval)),
+            sql_ast::Value::Interval {
+                value, leading_field, ..
+            } => Ok(DynamicUntypedTree::Cast {
+                tree: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+                    UntypedValue::String(OperationMapper::interval_text(value, leading_field)),
+                ))),
+                target: SqlType::interval(),
+            }),
             sql_ast::Value::Null => Ok(DynamicUntypedTree::Item(DynamicUntypedItem::Const(UntypedValue::Null))),
         }
     }
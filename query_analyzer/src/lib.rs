@@ -16,19 +16,27 @@ use std::{convert::TryFrom, sync::Arc};
 
 use catalog::CatalogDefinition;
 use data_definition_execution_plan::{
-    ColumnInfo, CreateSchemaQuery, CreateTableQuery, DropSchemasQuery, DropTablesQuery, SchemaChange,
+    AddColumnQuery, AlterColumnTypeQuery, ColumnInfo, ConstraintInfo, CreateIndexQuery, CreateSchemaQuery,
+    CreateTableQuery, DropColumnQuery, DropIndexesQuery, DropSchemasQuery, DropTablesQuery, ForeignKeyInfo,
+    RenameColumnQuery, RenameTableQuery, SchemaChange,
 };
-use data_manipulation_operators::Operation;
-use data_manipulation_untyped_queries::{DeleteQuery, InsertQuery, SelectQuery, UntypedWrite, UpdateQuery};
-use data_manipulation_untyped_tree::{DynamicUntypedItem, DynamicUntypedTree};
-use definition::{FullTableName, SchemaName};
+use data_manipulation_operators::{JoinKind, Operation};
+use data_manipulation_untyped_queries::{
+    DeleteQuery, InsertQuery, Join, OrderBy, SelectQuery, UntypedWrite, UpdateQuery,
+};
+use data_manipulation_untyped_tree::{DynamicUntypedItem, DynamicUntypedTree, StaticUntypedTree};
+use definition::{
+    CheckDef, ColumnConstraints, ColumnDef, FullTableName, IndexKind, ReferentialAction, SchemaName, TableDef,
+};
+use parser::AlterColumnType;
 use types::SqlType;
 
-use crate::{dynamic_tree_builder::DynamicTreeBuilder, static_tree_builder::StaticTreeBuilder};
+use crate::{dynamic_tree_builder::DynamicTreeBuilder, scope::Scope, static_tree_builder::StaticTreeBuilder};
 use std::collections::HashMap;
 
 mod dynamic_tree_builder;
 mod operation_mapper;
+mod scope;
 mod static_tree_builder;
 
 pub struct Analyzer<CD: CatalogDefinition> {
@@ -52,7 +60,6 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
                     None => Err(AnalysisError::schema_does_not_exist(full_table_name.schema())),
                     Some(None) => Err(AnalysisError::table_does_not_exist(full_table_name)),
                     Some(Some(table_info)) => {
-                        let table_columns = table_info.column_names();
                         let column_names = if columns.is_empty() {
                             table_info.column_names().into_iter()
                         } else {
@@ -77,12 +84,15 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
                                 let mut values = vec![];
                                 for insert_row in insert_rows {
                                     let mut row = vec![];
-                                    for table_column in &table_columns {
-                                        let value = match column_map.get(table_column) {
-                                            None => None,
-                                            Some(index) => {
-                                                Some(StaticTreeBuilder::build_from(&insert_row[*index], &statement)?)
+                                    for table_column in table_info.columns() {
+                                        let value = match column_map
+                                            .get(table_column.name())
+                                            .and_then(|index| insert_row.get(*index))
+                                        {
+                                            Some(value) if !is_default(value) => {
+                                                Some(StaticTreeBuilder::build_from(value, &statement)?)
                                             }
+                                            _ => default_value(table_column, &statement)?,
                                         };
                                         row.push(value);
                                     }
@@ -97,9 +107,12 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
                                 return Err(AnalysisError::FeatureNotSupported(Feature::SetOperations))
                             }
                         };
+                        let scope = Scope::table(&full_table_name, None, table_info.columns());
+                        let checks = table_checks(table_info.columns(), &statement, &scope)?;
                         Ok(QueryAnalysis::Write(UntypedWrite::Insert(InsertQuery {
                             full_table_name,
                             values,
+                            checks,
                         })))
                     }
                 },
@@ -107,7 +120,7 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
             sql_ast::Statement::Update {
                 table_name,
                 assignments: stmt_assignments,
-                ..
+                selection,
             } => match FullTableName::try_from(table_name) {
                 Err(error) => Err(AnalysisError::table_naming_error(error)),
                 Ok(full_table_name) => match self.database.table_definition(&full_table_name) {
@@ -115,6 +128,7 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
                     Some(None) => Err(AnalysisError::table_does_not_exist(full_table_name)),
                     Some(Some(table_info)) => {
                         let table_columns = table_info.columns();
+                        let scope = Scope::table(&full_table_name, None, table_columns);
                         let mut column_names = vec![];
                         let mut assignments = vec![];
                         for assignment in stmt_assignments {
@@ -130,25 +144,40 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
                             match found {
                                 None => return Err(AnalysisError::ColumnNotFound(name)),
                                 Some(name) => {
-                                    assignments.push(DynamicTreeBuilder::build_from(
-                                        &value,
-                                        &statement,
-                                        &table_columns,
+                                    assignments.push(without_aggregates(
+                                        DynamicTreeBuilder::build_from(&value, &statement, &scope)?,
+                                        "UPDATE",
                                     )?);
                                     column_names.push(name);
                                 }
                             }
                         }
+                        let filter = match selection {
+                            None => None,
+                            Some(expr) => Some(without_aggregates(
+                                DynamicTreeBuilder::build_from(expr, &statement, &scope)?,
+                                "WHERE",
+                            )?),
+                        };
+                        let checks = table_checks(table_columns, &statement, &scope)?;
                         Ok(QueryAnalysis::Write(UntypedWrite::Update(UpdateQuery {
                             full_table_name,
                             column_names,
                             assignments,
+                            filter,
+                            checks,
                         })))
                     }
                 },
             },
             sql_ast::Statement::Query(query) => {
-                let sql_ast::Query { body, .. } = &**query;
+                let sql_ast::Query {
+                    body,
+                    order_by,
+                    limit,
+                    offset,
+                    ..
+                } = &**query;
                 match body {
                     sql_ast::SetExpr::Query(_) => Err(AnalysisError::feature_not_supported(Feature::SubQueries)),
                     sql_ast::SetExpr::SetOperation { .. } => {
@@ -159,80 +188,206 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
                         statement, value_expr
                     ))),
                     sql_ast::SetExpr::Select(select) => {
-                        let sql_ast::Select { projection, from, .. } = &**select;
-                        if from.len() > 1 {
-                            return Err(AnalysisError::feature_not_supported(Feature::Joins));
-                        }
-                        let sql_ast::TableWithJoins { relation, .. } = &from[0];
-                        let name = match relation {
-                            sql_ast::TableFactor::Table { name, .. } => name,
-                            sql_ast::TableFactor::Derived { .. } => {
-                                return Err(AnalysisError::feature_not_supported(Feature::FromSubQuery))
+                        let sql_ast::Select {
+                            projection,
+                            from,
+                            selection,
+                            group_by,
+                            having,
+                            ..
+                        } = &**select;
+                        let (full_table_name, mut scope) = match from.first() {
+                            None => (None, Scope::empty()),
+                            Some(sql_ast::TableWithJoins { relation, .. }) => {
+                                let (full_table_name, alias, table_info) = self.table_factor(relation)?;
+                                let scope = Scope::table(&full_table_name, alias, table_info.columns());
+                                (Some(full_table_name), scope)
+                            }
+                        };
+                        let mut relations = vec![];
+                        for (position, sql_ast::TableWithJoins { relation, joins }) in from.iter().enumerate() {
+                            // tables listed through comma are cross joined
+                            if position > 0 {
+                                relations.push((relation, None));
                             }
-                            sql_ast::TableFactor::TableFunction { .. } => {
-                                return Err(AnalysisError::feature_not_supported(Feature::TableFunctions))
+                            for sql_ast::Join {
+                                relation,
+                                join_operator,
+                            } in joins
+                            {
+                                relations.push((relation, Some(join_operator)));
                             }
-                            sql_ast::TableFactor::NestedJoin(_) => {
-                                return Err(AnalysisError::feature_not_supported(Feature::NestedJoin))
+                        }
+                        let mut join_items = vec![];
+                        for (relation, join_operator) in relations {
+                            let (join_table_name, alias, table_info) = self.table_factor(relation)?;
+                            let relation = scope.join(&join_table_name, alias, table_info.columns())?;
+                            let (kind, constraint) = match join_operator {
+                                None | Some(sql_ast::JoinOperator::CrossJoin) => (JoinKind::Cross, None),
+                                Some(sql_ast::JoinOperator::Inner(constraint)) => (JoinKind::Inner, Some(constraint)),
+                                Some(sql_ast::JoinOperator::LeftOuter(constraint)) => {
+                                    (JoinKind::LeftOuter, Some(constraint))
+                                }
+                                Some(sql_ast::JoinOperator::RightOuter(constraint)) => {
+                                    (JoinKind::RightOuter, Some(constraint))
+                                }
+                                Some(sql_ast::JoinOperator::FullOuter(constraint)) => {
+                                    (JoinKind::FullOuter, Some(constraint))
+                                }
+                                Some(sql_ast::JoinOperator::CrossApply) | Some(sql_ast::JoinOperator::OuterApply) => {
+                                    return Err(AnalysisError::feature_not_supported(Feature::LateralJoins))
+                                }
+                            };
+                            let condition = match constraint {
+                                None => None,
+                                Some(sql_ast::JoinConstraint::On(expr)) => Some(without_aggregates(
+                                    DynamicTreeBuilder::build_from(expr, &statement, &scope)?,
+                                    "JOIN conditions",
+                                )?),
+                                Some(sql_ast::JoinConstraint::Using(_)) => {
+                                    return Err(AnalysisError::feature_not_supported(Feature::JoinUsing))
+                                }
+                                Some(sql_ast::JoinConstraint::Natural) => {
+                                    return Err(AnalysisError::feature_not_supported(Feature::NaturalJoins))
+                                }
+                            };
+                            join_items.push(Join {
+                                kind,
+                                full_table_name: join_table_name,
+                                relation,
+                                condition,
+                            });
+                        }
+                        let mut projection_items = vec![];
+                        for item in projection {
+                            match item {
+                                sql_ast::SelectItem::Wildcard => {
+                                    projection_items.extend(scope.wildcard(None)?);
+                                }
+                                sql_ast::SelectItem::UnnamedExpr(expr) => {
+                                    projection_items.push(DynamicTreeBuilder::build_from(&expr, &statement, &scope)?)
+                                }
+                                sql_ast::SelectItem::ExprWithAlias { .. } => {
+                                    return Err(AnalysisError::feature_not_supported(Feature::Aliases))
+                                }
+                                sql_ast::SelectItem::QualifiedWildcard(name) => match name.0.as_slice() {
+                                    [relation] => {
+                                        projection_items.extend(scope.wildcard(Some(&relation.value.to_lowercase()))?)
+                                    }
+                                    _ => return Err(AnalysisError::feature_not_supported(Feature::QualifiedAliases)),
+                                },
                             }
+                        }
+                        let filter = match selection {
+                            None => None,
+                            Some(expr) => Some(without_aggregates(
+                                DynamicTreeBuilder::build_from(expr, &statement, &scope)?,
+                                "WHERE",
+                            )?),
                         };
-                        match FullTableName::try_from(name) {
-                            Err(error) => Err(AnalysisError::table_naming_error(error)),
-                            Ok(full_table_name) => match self.database.table_definition(&full_table_name) {
-                                None => Err(AnalysisError::schema_does_not_exist(full_table_name.schema())),
-                                Some(None) => Err(AnalysisError::table_does_not_exist(full_table_name)),
-                                Some(Some(table_info)) => {
-                                    let table_columns = table_info.columns();
-                                    let mut projection_items = vec![];
-                                    for item in projection {
-                                        match item {
-                                            sql_ast::SelectItem::Wildcard => {
-                                                for (index, table_column) in table_columns.iter().enumerate() {
-                                                    projection_items.push(DynamicUntypedTree::Item(
-                                                        DynamicUntypedItem::Column {
-                                                            name: table_column.name().to_lowercase(),
-                                                            index,
-                                                            sql_type: table_column.sql_type(),
-                                                        },
-                                                    ));
-                                                }
-                                            }
-                                            sql_ast::SelectItem::UnnamedExpr(expr) => projection_items.push(
-                                                DynamicTreeBuilder::build_from(&expr, &statement, &table_columns)?,
-                                            ),
-                                            sql_ast::SelectItem::ExprWithAlias { .. } => {
-                                                return Err(AnalysisError::feature_not_supported(Feature::Aliases))
-                                            }
-                                            sql_ast::SelectItem::QualifiedWildcard(_) => {
-                                                return Err(AnalysisError::feature_not_supported(
-                                                    Feature::QualifiedAliases,
-                                                ))
-                                            }
+                        let mut group_by_items = vec![];
+                        for expr in group_by {
+                            group_by_items.push(without_aggregates(
+                                DynamicTreeBuilder::build_from(expr, &statement, &scope)?,
+                                "GROUP BY",
+                            )?);
+                        }
+                        let having = match having {
+                            None => None,
+                            Some(expr) => Some(DynamicTreeBuilder::build_from(expr, &statement, &scope)?),
+                        };
+                        let mut order_by_items = vec![];
+                        for sql_ast::OrderByExpr { expr, asc, nulls_first } in order_by {
+                            let expr = match expr {
+                                sql_ast::Expr::Value(sql_ast::Value::Number(position)) => {
+                                    match position
+                                        .to_string()
+                                        .parse::<usize>()
+                                        .ok()
+                                        .filter(|position| *position > 0)
+                                        .and_then(|position| projection_items.get(position - 1))
+                                    {
+                                        Some(item) => item.clone(),
+                                        None => {
+                                            return Err(AnalysisError::order_by_position_is_not_in_select_list(
+                                                position,
+                                            ))
                                         }
                                     }
-                                    Ok(QueryAnalysis::Read(SelectQuery {
-                                        full_table_name,
-                                        projection_items,
-                                    }))
                                 }
-                            },
+                                expr => DynamicTreeBuilder::build_from(expr, &statement, &scope)?,
+                            };
+                            let ascending = asc.unwrap_or(true);
+                            order_by_items.push(OrderBy {
+                                expr,
+                                ascending,
+                                // nulls are treated as larger than any value
+                                nulls_first: nulls_first.unwrap_or(!ascending),
+                            });
                         }
+                        let limit = match limit {
+                            None => None,
+                            Some(limit) => row_count(limit, AnalysisError::invalid_row_count_in_limit_clause)?,
+                        };
+                        let offset = match offset {
+                            None => None,
+                            Some(sql_ast::Offset { value, .. }) => {
+                                row_count(value, AnalysisError::invalid_row_count_in_result_offset_clause)?
+                            }
+                        };
+                        let aggregated = !group_by_items.is_empty()
+                            || having.is_some()
+                            || projection_items.iter().any(DynamicUntypedTree::has_aggregates)
+                            || order_by_items.iter().any(|item| item.expr.has_aggregates());
+                        if aggregated {
+                            for tree in projection_items
+                                .iter()
+                                .chain(having.iter())
+                                .chain(order_by_items.iter().map(|item| &item.expr))
+                            {
+                                check_grouped(tree, &group_by_items)?;
+                            }
+                        }
+                        Ok(QueryAnalysis::Read(SelectQuery {
+                            full_table_name,
+                            relation: scope.relation_name(),
+                            joins: join_items,
+                            projection_items,
+                            filter,
+                            group_by: group_by_items,
+                            having,
+                            order_by: order_by_items,
+                            limit,
+                            offset,
+                        }))
                     }
                 }
             }
-            sql_ast::Statement::Delete { table_name, .. } => match FullTableName::try_from(table_name) {
+            sql_ast::Statement::Delete { table_name, selection } => match FullTableName::try_from(table_name) {
                 Err(error) => Err(AnalysisError::table_naming_error(error)),
                 Ok(full_table_name) => match self.database.table_definition(&full_table_name) {
                     None => Err(AnalysisError::schema_does_not_exist(full_table_name.schema())),
                     Some(None) => Err(AnalysisError::table_does_not_exist(full_table_name)),
-                    Some(Some(_table_info)) => Ok(QueryAnalysis::Write(UntypedWrite::Delete(DeleteQuery {
-                        full_table_name,
-                    }))),
+                    Some(Some(table_info)) => {
+                        let scope = Scope::table(&full_table_name, None, table_info.columns());
+                        let filter = match selection {
+                            None => None,
+                            Some(expr) => Some(without_aggregates(
+                                DynamicTreeBuilder::build_from(expr, &statement, &scope)?,
+                                "WHERE",
+                            )?),
+                        };
+                        Ok(QueryAnalysis::Write(UntypedWrite::Delete(DeleteQuery {
+                            full_table_name,
+                            filter,
+                        })))
+                    }
                 },
             },
             sql_ast::Statement::CreateTable {
                 name,
                 columns,
+                constraints,
                 if_not_exists,
                 ..
             } => match FullTableName::try_from(name) {
@@ -247,16 +402,100 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
                                 Ok(sql_type) => column_defs.push(ColumnInfo {
                                     name: column.name.value.as_str().to_lowercase(),
                                     sql_type,
+                                    constraints: ColumnConstraints::default(),
                                 }),
                                 Err(_not_supported_type_error) => {
                                     return Err(AnalysisError::type_is_not_supported(&column.data_type));
                                 }
                             }
                         }
+                        let scope_columns = column_defs
+                            .iter()
+                            .enumerate()
+                            .map(|(ord_num, column)| ColumnDef::new(column.name.clone(), column.sql_type, ord_num))
+                            .collect::<Vec<ColumnDef>>();
+                        let scope = Scope::table(&full_table_name, None, &scope_columns);
+                        for (column, column_def) in columns.iter().zip(column_defs.iter_mut()) {
+                            column_def.constraints =
+                                column_constraints(full_table_name.table(), column, &statement, &scope)?;
+                        }
+                        if constraints
+                            .iter()
+                            .any(|constraint| matches!(constraint, sql_ast::TableConstraint::Check { .. }))
+                        {
+                            return Err(AnalysisError::feature_not_supported(Feature::TableCheckConstraints));
+                        }
+                        let mut keys = vec![];
+                        for column in columns {
+                            for option in column.options.iter() {
+                                if let sql_ast::ColumnOption::Unique { is_primary } = option.option {
+                                    keys.push((option.name.as_ref(), is_primary, vec![column.name.clone()]));
+                                }
+                            }
+                        }
+                        for constraint in constraints {
+                            if let sql_ast::TableConstraint::Unique {
+                                name,
+                                columns,
+                                is_primary,
+                            } = constraint
+                            {
+                                keys.push((name.as_ref(), *is_primary, columns.clone()));
+                            }
+                        }
+                        let mut references = vec![];
+                        for column in columns {
+                            for option in column.options.iter() {
+                                if let sql_ast::ColumnOption::ForeignKey {
+                                    foreign_table,
+                                    referred_columns,
+                                    on_delete,
+                                    on_update,
+                                } = &option.option
+                                {
+                                    references.push(Reference {
+                                        name: option.name.as_ref(),
+                                        columns: vec![column.name.clone()],
+                                        foreign_table,
+                                        referred_columns,
+                                        on_delete: *on_delete,
+                                        on_update: *on_update,
+                                    });
+                                }
+                            }
+                        }
+                        for constraint in constraints {
+                            if let sql_ast::TableConstraint::ForeignKey {
+                                name,
+                                columns,
+                                foreign_table,
+                                referred_columns,
+                            } = constraint
+                            {
+                                references.push(Reference {
+                                    name: name.as_ref(),
+                                    columns: columns.clone(),
+                                    foreign_table,
+                                    referred_columns,
+                                    on_delete: None,
+                                    on_update: None,
+                                });
+                            }
+                        }
+                        let constraints = table_constraints(full_table_name.table(), &column_defs, keys)?;
+                        let foreign_keys = foreign_keys(
+                            self.database.as_ref(),
+                            &full_table_name,
+                            &column_defs,
+                            &constraints,
+                            references,
+                        )?;
                         Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
                             CreateTableQuery {
                                 full_table_name,
                                 column_defs,
+                                constraints,
+                                foreign_keys,
                                 if_not_exists: *if_not_exists,
                             },
                         )))
@@ -327,13 +566,159 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
                     )))
                 }
                 sql_ast::ObjectType::View => unimplemented!("VIEWs are not implemented yet"),
-                sql_ast::ObjectType::Index => unimplemented!("INDEXes are not implemented yet"),
+                sql_ast::ObjectType::Index => {
+                    let mut full_index_names = vec![];
+                    for name in names {
+                        match FullTableName::try_from(name) {
+                            Ok(full_index_name) => {
+                                if self
+                                    .database
+                                    .schema_exists(&SchemaName::from(&full_index_name.schema()))
+                                {
+                                    full_index_names.push(full_index_name)
+                                } else {
+                                    return Err(AnalysisError::schema_does_not_exist(full_index_name.schema()));
+                                }
+                            }
+                            Err(_) => {
+                                return Err(AnalysisError::syntax_error(format!(
+                                    "improper qualified name (too many dotted names): {}",
+                                    name
+                                )))
+                            }
+                        }
+                    }
+                    Ok(QueryAnalysis::DataDefinition(SchemaChange::DropIndexes(
+                        DropIndexesQuery {
+                            full_index_names,
+                            if_exists: *if_exists,
+                        },
+                    )))
+                }
             },
             sql_ast::Statement::Copy { .. } => unimplemented!(),
             sql_ast::Statement::CreateView { .. } => unimplemented!(),
             sql_ast::Statement::CreateVirtualTable { .. } => unimplemented!(),
-            sql_ast::Statement::CreateIndex { .. } => unimplemented!(),
-            sql_ast::Statement::AlterTable { .. } => unimplemented!(),
+            sql_ast::Statement::CreateIndex {
+                name,
+                table_name,
+                columns,
+                unique,
+                if_not_exists,
+            } => {
+                if *unique {
+                    return Err(AnalysisError::feature_not_supported(Feature::UniqueIndexes));
+                }
+                // index is created in the schema of its table
+                let index_name = match name.0.as_slice() {
+                    [index_name] => index_name.value.to_lowercase(),
+                    _ => return Err(AnalysisError::syntax_error("syntax error at or near \".\"".to_owned())),
+                };
+                let full_table_name = match FullTableName::try_from(table_name) {
+                    Ok(full_table_name) => full_table_name,
+                    Err(error) => return Err(AnalysisError::table_naming_error(&error)),
+                };
+                let table_info = match self.database.table_definition(&full_table_name) {
+                    None => return Err(AnalysisError::schema_does_not_exist(full_table_name.schema())),
+                    Some(None) => return Err(AnalysisError::table_does_not_exist(full_table_name)),
+                    Some(Some(table_info)) => table_info,
+                };
+                let mut column_names = vec![];
+                for column in columns {
+                    match &column.expr {
+                        sql_ast::Expr::Identifier(ident) => {
+                            let column_name = ident.value.to_lowercase();
+                            if !table_info.has_column(&column_name) {
+                                return Err(AnalysisError::column_not_found(column_name));
+                            }
+                            column_names.push(column_name);
+                        }
+                        _ => return Err(AnalysisError::feature_not_supported(Feature::IndexExpressions)),
+                    }
+                }
+                Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateIndex(
+                    CreateIndexQuery {
+                        index_name,
+                        full_table_name,
+                        column_names,
+                        if_not_exists: *if_not_exists,
+                    },
+                )))
+            }
+            sql_ast::Statement::AlterTable { name, operation } => {
+                let full_table_name = match FullTableName::try_from(name) {
+                    Ok(full_table_name) => full_table_name,
+                    Err(error) => return Err(AnalysisError::table_naming_error(&error)),
+                };
+                let table_info = match self.database.table_definition(&full_table_name) {
+                    None => return Err(AnalysisError::schema_does_not_exist(full_table_name.schema())),
+                    Some(None) => return Err(AnalysisError::table_does_not_exist(full_table_name)),
+                    Some(Some(table_info)) => table_info,
+                };
+                match operation {
+                    sql_ast::AlterTableOperation::AddColumn { column_def } => {
+                        let sql_type = match SqlType::try_from(&column_def.data_type) {
+                            Ok(sql_type) => sql_type,
+                            Err(_not_supported_type_error) => {
+                                return Err(AnalysisError::type_is_not_supported(&column_def.data_type))
+                            }
+                        };
+                        if column_def.options.iter().any(|option| {
+                            matches!(
+                                option.option,
+                                sql_ast::ColumnOption::Unique { .. } | sql_ast::ColumnOption::ForeignKey { .. }
+                            )
+                        }) {
+                            return Err(AnalysisError::feature_not_supported(Feature::AddColumnConstraints));
+                        }
+                        let name = column_def.name.value.to_lowercase();
+                        let mut scope_columns = table_info.columns().to_vec();
+                        scope_columns.push(ColumnDef::new(name.clone(), sql_type, scope_columns.len()));
+                        let scope = Scope::table(&full_table_name, None, &scope_columns);
+                        let constraints = column_constraints(full_table_name.table(), column_def, &statement, &scope)?;
+                        Ok(QueryAnalysis::DataDefinition(SchemaChange::AddColumn(AddColumnQuery {
+                            full_table_name,
+                            column: ColumnInfo {
+                                name,
+                                sql_type,
+                                constraints,
+                            },
+                        })))
+                    }
+                    sql_ast::AlterTableOperation::DropColumn {
+                        column_name,
+                        if_exists,
+                        cascade,
+                    } => Ok(QueryAnalysis::DataDefinition(SchemaChange::DropColumn(
+                        DropColumnQuery {
+                            full_table_name,
+                            column_name: column_name.value.to_lowercase(),
+                            cascade: *cascade,
+                            if_exists: *if_exists,
+                        },
+                    ))),
+                    sql_ast::AlterTableOperation::RenameColumn {
+                        old_column_name,
+                        new_column_name,
+                    } => Ok(QueryAnalysis::DataDefinition(SchemaChange::RenameColumn(
+                        RenameColumnQuery {
+                            full_table_name,
+                            old_column_name: old_column_name.value.to_lowercase(),
+                            new_column_name: new_column_name.value.to_lowercase(),
+                        },
+                    ))),
+                    sql_ast::AlterTableOperation::RenameTable { table_name } => Ok(QueryAnalysis::DataDefinition(
+                        SchemaChange::RenameTable(RenameTableQuery {
+                            full_table_name,
+                            new_table_name: table_name.value.to_lowercase(),
+                        }),
+                    )),
+                    sql_ast::AlterTableOperation::AddConstraint(_)
+                    | sql_ast::AlterTableOperation::DropConstraint { .. } => {
+                        Err(AnalysisError::feature_not_supported(Feature::AlterTableConstraints))
+                    }
+                }
+            }
             sql_ast::Statement::SetVariable { .. } => unimplemented!(),
             sql_ast::Statement::ShowVariable { .. } => unimplemented!(),
             sql_ast::Statement::ShowColumns { .. } => unimplemented!(),
@@ -346,8 +731,427 @@ impl<CD: CatalogDefinition> Analyzer<CD> {
             sql_ast::Statement::Execute { .. } => unimplemented!(),
             sql_ast::Statement::Prepare { .. } => unimplemented!(),
             sql_ast::Statement::Analyze { .. } => unimplemented!(),
-            sql_ast::Statement::Explain { .. } => unimplemented!(),
+            sql_ast::Statement::Explain { analyze, statement, .. } => match &**statement {
+                query @ sql_ast::Statement::Query(_) => match self.analyze(query.clone())? {
+                    QueryAnalysis::Read(select) => Ok(QueryAnalysis::Explain {
+                        analyze: *analyze,
+                        select,
+                    }),
+                    analysis => unreachable!("query could not be analyzed as {:?}", analysis),
+                },
+                _ => Err(AnalysisError::feature_not_supported(Feature::ExplainNonSelect)),
+            },
+        }
+    }
+
+    pub fn analyze_alter_column_type(&self, statement: &AlterColumnType) -> Result<QueryAnalysis, AnalysisError> {
+        let AlterColumnType {
+            table_name,
+            column_name,
+            data_type,
+        } = statement;
+        let full_table_name = match FullTableName::try_from(table_name) {
+            Ok(full_table_name) => full_table_name,
+            Err(error) => return Err(AnalysisError::table_naming_error(&error)),
+        };
+        match self.database.table_definition(&full_table_name) {
+            None => return Err(AnalysisError::schema_does_not_exist(full_table_name.schema())),
+            Some(None) => return Err(AnalysisError::table_does_not_exist(full_table_name)),
+            Some(Some(_)) => {}
+        }
+        let sql_type = match SqlType::try_from(data_type) {
+            Ok(sql_type) => sql_type,
+            Err(_not_supported_type_error) => return Err(AnalysisError::type_is_not_supported(data_type)),
+        };
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::AlterColumnType(
+            AlterColumnTypeQuery {
+                full_table_name,
+                column_name: column_name.value.to_lowercase(),
+                sql_type,
+            },
+        )))
+    }
+}
+
+impl<CD: CatalogDefinition> Analyzer<CD> {
+    /// table listed in `FROM` clause with its alias
+    fn table_factor(
+        &self,
+        relation: &sql_ast::TableFactor,
+    ) -> AnalysisResult<(FullTableName, Option<String>, TableDef)> {
+        let (name, alias) = match relation {
+            sql_ast::TableFactor::Table { name, alias, .. } => (name, alias),
+            sql_ast::TableFactor::Derived { .. } => {
+                return Err(AnalysisError::feature_not_supported(Feature::FromSubQuery))
+            }
+            sql_ast::TableFactor::TableFunction { .. } => {
+                return Err(AnalysisError::feature_not_supported(Feature::TableFunctions))
+            }
+            sql_ast::TableFactor::NestedJoin(_) => {
+                return Err(AnalysisError::feature_not_supported(Feature::NestedJoin))
+            }
+        };
+        let alias = match alias {
+            None => None,
+            Some(sql_ast::TableAlias { name, columns }) if columns.is_empty() => Some(name.value.to_lowercase()),
+            Some(_) => return Err(AnalysisError::feature_not_supported(Feature::Aliases)),
+        };
+        match FullTableName::try_from(name) {
+            Err(error) => Err(AnalysisError::table_naming_error(error)),
+            Ok(full_table_name) => match self.database.table_definition(&full_table_name) {
+                None => Err(AnalysisError::schema_does_not_exist(full_table_name.schema())),
+                Some(None) => Err(AnalysisError::table_does_not_exist(full_table_name)),
+                Some(Some(table_info)) => Ok((full_table_name, alias, table_info)),
+            },
+        }
+    }
+}
+
+/// `DEFAULT` and `CHECK` expressions of a column are kept as SQL text, a `CHECK`
+/// constraint that is not named is named as in PostgreSQL
+fn column_constraints(
+    table_name: &str,
+    column: &sql_ast::ColumnDef,
+    statement: &sql_ast::Statement,
+    scope: &Scope,
+) -> AnalysisResult<ColumnConstraints> {
+    let mut constraints = ColumnConstraints::default();
+    for option in column.options.iter() {
+        match &option.option {
+            sql_ast::ColumnOption::NotNull => constraints.not_null = true,
+            sql_ast::ColumnOption::Null => constraints.not_null = false,
+            sql_ast::ColumnOption::Default(expr) => {
+                StaticTreeBuilder::build_from(expr, statement)?;
+                constraints.default_expr = Some(expr.to_string());
+            }
+            sql_ast::ColumnOption::Check(expr) => {
+                if constraints.check.is_some() {
+                    return Err(AnalysisError::feature_not_supported(
+                        Feature::MultipleColumnCheckConstraints,
+                    ));
+                }
+                without_aggregates(
+                    DynamicTreeBuilder::build_from(expr, statement, scope)?,
+                    "check constraints",
+                )?;
+                let name = match &option.name {
+                    Some(name) => name.value.to_lowercase(),
+                    None => format!("{}_{}_check", table_name, column.name.value.to_lowercase()),
+                };
+                constraints.check = Some(CheckDef {
+                    name,
+                    predicate: expr.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(constraints)
+}
+
+/// expressions of column constraints are kept in the catalog as SQL text
+fn stored_expr(text: &str) -> AnalysisResult<sql_ast::Expr> {
+    if let Ok(statements) = parser::parse_sql(&format!("select {}", text)) {
+        if let [sql_ast::Statement::Query(query)] = statements.as_slice() {
+            if let sql_ast::SetExpr::Select(select) = &query.body {
+                if let [sql_ast::SelectItem::UnnamedExpr(expr)] = select.projection.as_slice() {
+                    return Ok(expr.clone());
+                }
+            }
+        }
+    }
+    Err(AnalysisError::syntax_error(format!(
+        "Syntax error in stored expression '{}'",
+        text
+    )))
+}
+
+/// `DEFAULT` in `VALUES` is parsed as an identifier
+fn is_default(expr: &sql_ast::Expr) -> bool {
+    matches!(expr, sql_ast::Expr::Identifier(ident) if ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("default"))
+}
+
+/// value of a column that is omitted from `INSERT` or is specified as `DEFAULT`,
+/// columns without default expression are `NULL`
+fn default_value(column: &ColumnDef, statement: &sql_ast::Statement) -> AnalysisResult<Option<StaticUntypedTree>> {
+    match &column.constraints().default_expr {
+        None => Ok(None),
+        Some(default_expr) => Ok(Some(StaticTreeBuilder::build_from(
+            &stored_expr(default_expr)?,
+            statement,
+        )?)),
+    }
+}
+
+/// predicates of `CHECK` constraints of table columns that rows are checked against on write
+fn table_checks(
+    columns: &[ColumnDef],
+    statement: &sql_ast::Statement,
+    scope: &Scope,
+) -> AnalysisResult<Vec<(String, DynamicUntypedTree)>> {
+    let mut checks = vec![];
+    for column in columns {
+        if let Some(CheckDef { name, predicate }) = &column.constraints().check {
+            checks.push((
+                name.clone(),
+                DynamicTreeBuilder::build_from(&stored_expr(predicate)?, statement, scope)?,
+            ));
+        }
+    }
+    Ok(checks)
+}
+
+/// unique and primary key constraints that are not named are named as in PostgreSQL
+fn table_constraints(
+    table_name: &str,
+    column_defs: &[ColumnInfo],
+    keys: Vec<(Option<&sql_ast::Ident>, bool, Vec<sql_ast::Ident>)>,
+) -> AnalysisResult<Vec<ConstraintInfo>> {
+    let mut constraints: Vec<ConstraintInfo> = vec![];
+    for (name, is_primary, columns) in keys {
+        let column_names = columns
+            .iter()
+            .map(|column| column.value.to_lowercase())
+            .collect::<Vec<String>>();
+        if let Some(column_name) = column_names
+            .iter()
+            .find(|column_name| !column_defs.iter().any(|column| &column.name == *column_name))
+        {
+            return Err(AnalysisError::column_not_found(column_name));
+        }
+        let kind = if is_primary {
+            if constraints
+                .iter()
+                .any(|constraint| constraint.kind == IndexKind::PrimaryKey)
+            {
+                return Err(AnalysisError::multiple_primary_keys(table_name));
+            }
+            IndexKind::PrimaryKey
+        } else {
+            IndexKind::Unique
+        };
+        let name = match name {
+            Some(name) => name.value.to_lowercase(),
+            None => {
+                let prefix = if is_primary {
+                    format!("{}_pkey", table_name)
+                } else {
+                    format!("{}_{}_key", table_name, column_names.join("_"))
+                };
+                let mut name = prefix.clone();
+                let mut suffix = 0;
+                while constraints.iter().any(|constraint| constraint.name == name) {
+                    suffix += 1;
+                    name = format!("{}{}", prefix, suffix);
+                }
+                name
+            }
+        };
+        constraints.push(ConstraintInfo {
+            name,
+            kind,
+            column_names,
+        });
+    }
+    Ok(constraints)
+}
+
+/// `REFERENCES` column option or `FOREIGN KEY` table constraint
+struct Reference<'r> {
+    name: Option<&'r sql_ast::Ident>,
+    columns: Vec<sql_ast::Ident>,
+    foreign_table: &'r sql_ast::ObjectName,
+    referred_columns: &'r [sql_ast::Ident],
+    on_delete: Option<sql_ast::ReferentialAction>,
+    on_update: Option<sql_ast::ReferentialAction>,
+}
+
+/// foreign keys that are not named are named as in PostgreSQL, they reference the primary key
+/// of the referenced table when referenced columns are not specified
+fn foreign_keys<CD: CatalogDefinition>(
+    database: &CD,
+    full_table_name: &FullTableName,
+    column_defs: &[ColumnInfo],
+    constraints: &[ConstraintInfo],
+    references: Vec<Reference>,
+) -> AnalysisResult<Vec<ForeignKeyInfo>> {
+    let mut foreign_keys: Vec<ForeignKeyInfo> = vec![];
+    for reference in references {
+        let column_names = reference
+            .columns
+            .iter()
+            .map(|column| column.value.to_lowercase())
+            .collect::<Vec<String>>();
+        let mut column_types = vec![];
+        for column_name in column_names.iter() {
+            match column_defs.iter().find(|column| &column.name == column_name) {
+                Some(column) => column_types.push(column.sql_type),
+                None => return Err(AnalysisError::column_not_found(column_name)),
+            }
         }
+        let referenced_table = match FullTableName::try_from(reference.foreign_table) {
+            Ok(referenced_table) => referenced_table,
+            Err(error) => return Err(AnalysisError::table_naming_error(&error)),
+        };
+        // a table could reference its own columns
+        let (referenced_columns, unique_keys) = if &referenced_table == full_table_name {
+            (
+                column_defs
+                    .iter()
+                    .map(|column| (column.name.clone(), column.sql_type))
+                    .collect::<Vec<(String, SqlType)>>(),
+                constraints
+                    .iter()
+                    .map(|constraint| (constraint.kind, constraint.column_names.clone()))
+                    .collect::<Vec<(IndexKind, Vec<String>)>>(),
+            )
+        } else {
+            match database.table_definition(&referenced_table) {
+                None => return Err(AnalysisError::schema_does_not_exist(referenced_table.schema())),
+                Some(None) => return Err(AnalysisError::table_does_not_exist(&referenced_table)),
+                Some(Some(table_def)) => (
+                    table_def
+                        .columns()
+                        .iter()
+                        .map(|column| (column.name().to_owned(), column.sql_type()))
+                        .collect(),
+                    database
+                        .table_indexes(&referenced_table)
+                        .into_iter()
+                        .filter(|index| index.kind().is_unique())
+                        .map(|index| (index.kind(), index.columns().to_vec()))
+                        .collect(),
+                ),
+            }
+        };
+        let referenced_column_names = if reference.referred_columns.is_empty() {
+            match unique_keys
+                .iter()
+                .find(|(kind, _columns)| kind == &IndexKind::PrimaryKey)
+            {
+                Some((_kind, columns)) => columns.clone(),
+                None => {
+                    return Err(AnalysisError::no_unique_constraint_for_referenced_table(
+                        referenced_table.table(),
+                    ))
+                }
+            }
+        } else {
+            reference
+                .referred_columns
+                .iter()
+                .map(|column| column.value.to_lowercase())
+                .collect()
+        };
+        let mut referenced_types = vec![];
+        for column_name in referenced_column_names.iter() {
+            match referenced_columns.iter().find(|(name, _sql_type)| name == column_name) {
+                Some((_name, sql_type)) => referenced_types.push(*sql_type),
+                None => return Err(AnalysisError::column_not_found(column_name)),
+            }
+        }
+        if column_names.len() != referenced_column_names.len() {
+            return Err(AnalysisError::ForeignKeyColumnsDisagree);
+        }
+        if !unique_keys.iter().any(|(_kind, columns)| {
+            columns.len() == referenced_column_names.len()
+                && referenced_column_names.iter().all(|column| columns.contains(column))
+        }) {
+            return Err(AnalysisError::no_unique_constraint_for_referenced_table(
+                referenced_table.table(),
+            ));
+        }
+        let name = match reference.name {
+            Some(name) => name.value.to_lowercase(),
+            None => {
+                let prefix = format!("{}_{}_fkey", full_table_name.table(), column_names.join("_"));
+                let mut name = prefix.clone();
+                let mut suffix = 0;
+                while foreign_keys.iter().any(|foreign_key| foreign_key.name == name) {
+                    suffix += 1;
+                    name = format!("{}{}", prefix, suffix);
+                }
+                name
+            }
+        };
+        if column_types
+            .iter()
+            .zip(referenced_types.iter())
+            .any(|(column_type, referenced_type)| column_type.family() != referenced_type.family())
+        {
+            return Err(AnalysisError::foreign_key_cannot_be_implemented(name));
+        }
+        foreign_keys.push(ForeignKeyInfo {
+            name,
+            column_names,
+            referenced_table,
+            referenced_column_names,
+            on_delete: referential_action(reference.on_delete)?,
+            on_update: referential_action(reference.on_update)?,
+        });
+    }
+    Ok(foreign_keys)
+}
+
+/// `NO ACTION` is the default referential action
+fn referential_action(action: Option<sql_ast::ReferentialAction>) -> AnalysisResult<ReferentialAction> {
+    match action {
+        None | Some(sql_ast::ReferentialAction::NoAction) => Ok(ReferentialAction::NoAction),
+        Some(sql_ast::ReferentialAction::Restrict) => Ok(ReferentialAction::Restrict),
+        Some(sql_ast::ReferentialAction::Cascade) => Ok(ReferentialAction::Cascade),
+        Some(sql_ast::ReferentialAction::SetNull) => Ok(ReferentialAction::SetNull),
+        Some(sql_ast::ReferentialAction::SetDefault) => Err(AnalysisError::feature_not_supported(
+            Feature::SetDefaultReferentialAction,
+        )),
+    }
+}
+
+/// number of rows in `LIMIT` or `OFFSET` clause, `NULL` means there is no limit
+fn row_count(expr: &sql_ast::Expr, negative: fn() -> AnalysisError) -> AnalysisResult<Option<usize>> {
+    match expr {
+        sql_ast::Expr::Value(sql_ast::Value::Null) => Ok(None),
+        // parameters of a prepared statement are known only when it is bound
+        sql_ast::Expr::Identifier(sql_ast::Ident { value, .. }) if parse_param_index(value).is_some() => Ok(None),
+        sql_ast::Expr::Value(sql_ast::Value::Number(number)) => match number.to_string().parse::<usize>() {
+            Ok(count) => Ok(Some(count)),
+            Err(_) => Err(AnalysisError::invalid_input_syntax_for_type(SqlType::big_int(), number)),
+        },
+        sql_ast::Expr::UnaryOp {
+            op: sql_ast::UnaryOperator::Minus,
+            expr,
+        } if matches!(**expr, sql_ast::Expr::Value(sql_ast::Value::Number(_))) => Err(negative()),
+        expr => Err(AnalysisError::invalid_input_syntax_for_type(SqlType::big_int(), expr)),
+    }
+}
+
+/// aggregate functions are computed over groups of rows and can't be used in
+/// clauses that are evaluated for each row
+fn without_aggregates(tree: DynamicUntypedTree, clause: &str) -> AnalysisResult<DynamicUntypedTree> {
+    if tree.has_aggregates() {
+        Err(AnalysisError::aggregate_functions_are_not_allowed_in(clause))
+    } else {
+        Ok(tree)
+    }
+}
+
+/// columns could be referenced in a grouped query only as a part of
+/// a grouping expression or an argument of an aggregate function
+fn check_grouped(tree: &DynamicUntypedTree, group_by: &[DynamicUntypedTree]) -> AnalysisResult<()> {
+    if group_by.contains(tree) {
+        return Ok(());
+    }
+    match tree {
+        DynamicUntypedTree::Operation { left, right, .. } => {
+            check_grouped(left, group_by)?;
+            check_grouped(right, group_by)
+        }
+        DynamicUntypedTree::UnaryOperation { item, .. } => check_grouped(item, group_by),
+        DynamicUntypedTree::Cast { tree, .. } => check_grouped(tree, group_by),
+        DynamicUntypedTree::Function { args, .. } => args.iter().try_for_each(|arg| check_grouped(arg, group_by)),
+        DynamicUntypedTree::Item(DynamicUntypedItem::Column { name, .. }) => {
+            Err(AnalysisError::column_must_appear_in_group_by(name))
+        }
+        DynamicUntypedTree::Item(_) | DynamicUntypedTree::Aggregate { .. } => Ok(()),
     }
 }
 
@@ -372,6 +1176,11 @@ pub enum QueryAnalysis {
     DataDefinition(SchemaChange),
     Write(UntypedWrite),
     Read(SelectQuery),
+    /// `select` is executed only when it is analyzed
+    Explain {
+        analyze: bool,
+        select: SelectQuery,
+    },
 }
 
 #[derive(Debug, PartialEq)]
@@ -390,7 +1199,20 @@ pub enum AnalysisError {
     StringDataRightTruncation(SqlType),                              // Error code: 22001
     DatatypeMismatch { column_type: SqlType, source_type: SqlType }, // Error code: 42804
     AmbiguousFunction(Operation),                                    // Error code: 42725
-    UndefinedFunction(Operation),                                    // Error code: 42883
+    OrderByPositionIsNotInSelectList(String),                        // Error code: 42P10
+    InvalidRowCountInLimitClause,                                    // Error code: 2201W
+    InvalidRowCountInResultOffsetClause,                             // Error code: 2201X
+    FunctionDoesNotExist(String),                                    // Error code: 42883
+    AggregateFunctionsAreNotAllowedIn(String),                       // Error code: 42803
+    AggregateFunctionCallsCannotBeNested,                            // Error code: 42803
+    ColumnMustAppearInGroupBy(String),                               // Error code: 42803
+    AmbiguousColumn(String),                                         // Error code: 42702
+    MissingFromClauseEntry(String),                                  // Error code: 42P01
+    DuplicateAlias(String),                                          // Error code: 42712
+    MultiplePrimaryKeys(String),                                     // Error code: 42P16
+    NoUniqueConstraintForReferencedTable(String),                    // Error code: 42830
+    ForeignKeyColumnsDisagree,                                       // Error code: 42830
+    ForeignKeyCannotBeImplemented(String),                           // Error code: 42804
     FeatureNotSupported(Feature),
 }
 
@@ -453,6 +1275,58 @@ impl AnalysisError {
         }
     }
 
+    pub fn order_by_position_is_not_in_select_list<P: ToString>(position: P) -> AnalysisError {
+        AnalysisError::OrderByPositionIsNotInSelectList(position.to_string())
+    }
+
+    pub fn invalid_row_count_in_limit_clause() -> AnalysisError {
+        AnalysisError::InvalidRowCountInLimitClause
+    }
+
+    pub fn invalid_row_count_in_result_offset_clause() -> AnalysisError {
+        AnalysisError::InvalidRowCountInResultOffsetClause
+    }
+
+    pub fn function_does_not_exist<F: ToString>(function: F) -> AnalysisError {
+        AnalysisError::FunctionDoesNotExist(function.to_string())
+    }
+
+    pub fn aggregate_functions_are_not_allowed_in<C: ToString>(clause: C) -> AnalysisError {
+        AnalysisError::AggregateFunctionsAreNotAllowedIn(clause.to_string())
+    }
+
+    pub fn aggregate_function_calls_cannot_be_nested() -> AnalysisError {
+        AnalysisError::AggregateFunctionCallsCannotBeNested
+    }
+
+    pub fn column_must_appear_in_group_by<C: ToString>(column_name: C) -> AnalysisError {
+        AnalysisError::ColumnMustAppearInGroupBy(column_name.to_string())
+    }
+
+    pub fn ambiguous_column<C: ToString>(column_name: C) -> AnalysisError {
+        AnalysisError::AmbiguousColumn(column_name.to_string())
+    }
+
+    pub fn missing_from_clause_entry<T: ToString>(table_name: T) -> AnalysisError {
+        AnalysisError::MissingFromClauseEntry(table_name.to_string())
+    }
+
+    pub fn duplicate_alias<T: ToString>(table_name: T) -> AnalysisError {
+        AnalysisError::DuplicateAlias(table_name.to_string())
+    }
+
+    pub fn multiple_primary_keys<T: ToString>(table_name: T) -> AnalysisError {
+        AnalysisError::MultiplePrimaryKeys(table_name.to_string())
+    }
+
+    pub fn no_unique_constraint_for_referenced_table<T: ToString>(table_name: T) -> AnalysisError {
+        AnalysisError::NoUniqueConstraintForReferencedTable(table_name.to_string())
+    }
+
+    pub fn foreign_key_cannot_be_implemented<C: ToString>(constraint_name: C) -> AnalysisError {
+        AnalysisError::ForeignKeyCannotBeImplemented(constraint_name.to_string())
+    }
+
     pub fn feature_not_supported(feature: Feature) -> AnalysisError {
         AnalysisError::FeatureNotSupported(feature)
     }
@@ -463,15 +1337,24 @@ pub enum Feature {
     SetOperations,
     SubQueries,
     NationalStringLiteral,
-    HexStringLiteral,
-    TimeInterval,
-    Joins,
     NestedJoin,
+    JoinUsing,
+    NaturalJoins,
+    LateralJoins,
     FromSubQuery,
     TableFunctions,
     Aliases,
     QualifiedAliases,
     InsertIntoSelect,
+    WindowFunctions,
+    ExplainNonSelect,
+    UniqueIndexes,
+    IndexExpressions,
+    TableCheckConstraints,
+    MultipleColumnCheckConstraints,
+    SetDefaultReferentialAction,
+    AddColumnConstraints,
+    AlterTableConstraints,
 }
 
 #[cfg(test)]
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data_manipulation_operators::{Arithmetic, Bitwise, Comparison, Logical, Operation, PatternMatching, StringOp};
+use data_manipulation_operators::{
+    Arithmetic, Bitwise, Comparison, JsonOp, Logical, Operation, PatternMatching, ScalarFunction, StringOp,
+};
 
 pub(crate) struct OperationMapper;
 
@@ -43,4 +45,49 @@ impl OperationMapper {
             sql_ast::BinaryOperator::PGBitwiseShiftRight => Operation::Bitwise(Bitwise::ShiftRight),
         }
     }
+
+    pub(crate) fn scalar_function(name: &str) -> Option<ScalarFunction> {
+        match name.to_lowercase().as_str() {
+            "now" | "current_timestamp" => Some(ScalarFunction::Now),
+            "current_date" => Some(ScalarFunction::CurrentDate),
+            "date_part" => Some(ScalarFunction::DatePart),
+            "date_trunc" => Some(ScalarFunction::DateTrunc),
+            "gen_random_uuid" => Some(ScalarFunction::GenRandomUuid),
+            "jsonb_build_object" => Some(ScalarFunction::JsonbBuildObject),
+            _ => None,
+        }
+    }
+
+    /// json operators are called through the functions PostgreSQL implements them with
+    pub(crate) fn json_operator_function(name: &str) -> Option<Operation> {
+        match name.to_lowercase().as_str() {
+            "json_object_field" | "jsonb_object_field" | "json_array_element" | "jsonb_array_element" => {
+                Some(Operation::JsonOp(JsonOp::Get))
+            }
+            "json_object_field_text"
+            | "jsonb_object_field_text"
+            | "json_array_element_text"
+            | "jsonb_array_element_text" => Some(Operation::JsonOp(JsonOp::GetText)),
+            "json_extract_path_op" | "jsonb_extract_path_op" => Some(Operation::JsonOp(JsonOp::GetPath)),
+            "jsonb_contains" => Some(Operation::JsonOp(JsonOp::Contains)),
+            "jsonb_exists" => Some(Operation::JsonOp(JsonOp::Exists)),
+            _ => None,
+        }
+    }
+
+    /// `current_date` and `current_timestamp` are keywords that are parsed as identifiers
+    pub(crate) fn keyword_function(ident: &sql_ast::Ident) -> Option<ScalarFunction> {
+        match ident.value.to_lowercase().as_str() {
+            "current_date" | "current_timestamp" if ident.quote_style.is_none() => Self::scalar_function(&ident.value),
+            _ => None,
+        }
+    }
+
+    /// `INTERVAL '3' DAY` is the same as `INTERVAL '3 day'`
+    pub(crate) fn interval_text(value: &str, leading_field: &Option<sql_ast::DateTimeField>) -> String {
+        match leading_field {
+            Some(field) => format!("{} {}", value, field.to_string().to_lowercase()),
+            None => value.to_owned(),
+        }
+    }
 }
@@ -0,0 +1,161 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data_manipulation_untyped_tree::{DynamicUntypedItem, DynamicUntypedTree};
+use definition::{ColumnDef, FullTableName};
+
+use crate::{AnalysisError, AnalysisResult};
+
+struct Relation {
+    name: String,
+    full_table_name: FullTableName,
+    aliased: bool,
+    columns: Vec<ColumnDef>,
+}
+
+/// tables listed in `FROM` clause that column references are resolved against,
+/// columns are qualified with names of their tables when there are more than one
+pub(crate) struct Scope {
+    relations: Vec<Relation>,
+}
+
+impl Scope {
+    /// scope of a query without `FROM` clause, it has no columns to refer to
+    pub(crate) fn empty() -> Scope {
+        Scope { relations: vec![] }
+    }
+
+    pub(crate) fn table(full_table_name: &FullTableName, alias: Option<String>, columns: &[ColumnDef]) -> Scope {
+        let mut scope = Scope { relations: vec![] };
+        scope.relations.push(Relation::new(full_table_name, alias, columns));
+        scope
+    }
+
+    /// adds a table and returns its alias or name
+    pub(crate) fn join(
+        &mut self,
+        full_table_name: &FullTableName,
+        alias: Option<String>,
+        columns: &[ColumnDef],
+    ) -> AnalysisResult<String> {
+        let relation = Relation::new(full_table_name, alias, columns);
+        if self.relations.iter().any(|existing| existing.name == relation.name) {
+            return Err(AnalysisError::duplicate_alias(relation.name));
+        }
+        let name = relation.name.clone();
+        self.relations.push(relation);
+        Ok(name)
+    }
+
+    /// alias or name of the first table, empty when there are no tables
+    pub(crate) fn relation_name(&self) -> String {
+        self.relations
+            .first()
+            .map(|relation| relation.name.clone())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn column(
+        &self,
+        schema: Option<&str>,
+        relation: Option<&str>,
+        name: &str,
+    ) -> AnalysisResult<DynamicUntypedTree> {
+        let mut found = None;
+        let mut matched_relation = relation.is_none();
+        for (relation_index, current) in self.relations.iter().enumerate() {
+            if let Some(relation) = relation {
+                if !current.is_named(schema, relation) {
+                    continue;
+                }
+            }
+            matched_relation = true;
+            for (index, column) in current.columns.iter().enumerate() {
+                if column.has_name(name) {
+                    if found.is_some() {
+                        return Err(AnalysisError::ambiguous_column(name));
+                    }
+                    found = Some(self.item(relation_index, index, column));
+                }
+            }
+        }
+        match (found, relation) {
+            (Some(item), _) => Ok(DynamicUntypedTree::Item(item)),
+            (None, Some(relation)) if !matched_relation => Err(AnalysisError::missing_from_clause_entry(relation)),
+            (None, Some(relation)) => Err(AnalysisError::column_not_found(format!("{}.{}", relation, name))),
+            (None, None) => Err(AnalysisError::column_not_found(name)),
+        }
+    }
+
+    /// columns of all tables or of the table with the given name
+    pub(crate) fn wildcard(&self, relation: Option<&str>) -> AnalysisResult<Vec<DynamicUntypedTree>> {
+        let mut items = vec![];
+        let mut matched_relation = relation.is_none();
+        for (relation_index, current) in self.relations.iter().enumerate() {
+            if let Some(relation) = relation {
+                if !current.is_named(None, relation) {
+                    continue;
+                }
+            }
+            matched_relation = true;
+            for (index, column) in current.columns.iter().enumerate() {
+                items.push(DynamicUntypedTree::Item(self.item(relation_index, index, column)));
+            }
+        }
+        match relation {
+            Some(relation) if !matched_relation => Err(AnalysisError::missing_from_clause_entry(relation)),
+            _ => Ok(items),
+        }
+    }
+
+    fn item(&self, relation_index: usize, index: usize, column: &ColumnDef) -> DynamicUntypedItem {
+        let offset = self.relations[0..relation_index]
+            .iter()
+            .map(|relation| relation.columns.len())
+            .sum::<usize>();
+        let name = if self.relations.len() > 1 {
+            column
+                .qualified(&self.relations[relation_index].name, offset + index)
+                .qualified_name()
+        } else {
+            column.name().to_owned()
+        };
+        DynamicUntypedItem::Column {
+            name,
+            sql_type: column.sql_type(),
+            index: offset + index,
+        }
+    }
+}
+
+impl Relation {
+    fn new(full_table_name: &FullTableName, alias: Option<String>, columns: &[ColumnDef]) -> Relation {
+        Relation {
+            name: alias.clone().unwrap_or_else(|| full_table_name.table().to_owned()),
+            full_table_name: FullTableName::from((&full_table_name.schema(), &full_table_name.table())),
+            aliased: alias.is_some(),
+            columns: columns.to_vec(),
+        }
+    }
+
+    /// aliased tables could be referenced only by their aliases
+    fn is_named(&self, schema: Option<&str>, name: &str) -> bool {
+        match schema {
+            None => self.name == name,
+            Some(schema) => {
+                !self.aliased && self.full_table_name.schema() == schema && self.full_table_name.table() == name
+            }
+        }
+    }
+}
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data_manipulation_operators::{ScalarFunction, UnaryOperation};
 use data_manipulation_untyped_tree::{Bool, StaticUntypedItem, StaticUntypedTree, UntypedValue};
+use std::convert::TryFrom;
+use types::SqlType;
 
 use crate::{operation_mapper::OperationMapper, parse_param_index, AnalysisError, AnalysisResult, Feature};
 
@@ -31,34 +34,110 @@ impl StaticTreeBuilder {
             sql_ast::Expr::Value(value) => Self::value(value),
             sql_ast::Expr::Identifier(ident) => Self::ident(ident),
             sql_ast::Expr::BinaryOp { left, op, right } => Self::op(op, &**left, &**right, original),
-            expr => {
-                log::warn!("Syntax error in '{:#?}' around '{:?}'", original, expr);
-                Err(AnalysisError::syntax_error(format!(
-                    "Syntax error in '{}' around '{}'",
-                    original, expr
-                )))
-            }
+            sql_ast::Expr::UnaryOp { op, expr } => match (op, &**expr) {
+                (sql_ast::UnaryOperator::Minus, sql_ast::Expr::Value(sql_ast::Value::Number(num))) => {
+                    Self::value(&sql_ast::Value::Number(-num))
+                }
+                (sql_ast::UnaryOperator::Plus, expr) => Self::inner_build(expr, original),
+                (sql_ast::UnaryOperator::Minus, expr) => Self::unary(UnaryOperation::Minus, expr, original),
+                (sql_ast::UnaryOperator::Not, expr) => Self::unary(UnaryOperation::Not, expr, original),
+                _ => Self::syntax_error(root_expr, original),
+            },
+            sql_ast::Expr::IsNull(expr) => Self::unary(UnaryOperation::IsNull, expr, original),
+            sql_ast::Expr::IsNotNull(expr) => Self::unary(UnaryOperation::IsNotNull, expr, original),
+            sql_ast::Expr::Nested(expr) => Self::inner_build(expr, original),
+            sql_ast::Expr::Cast { expr, data_type } => Self::cast(Self::inner_build(expr, original)?, data_type),
+            sql_ast::Expr::TypedString { data_type, value } => Self::cast(
+                StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::String(value.clone()))),
+                data_type,
+            ),
+            sql_ast::Expr::Extract { field, expr } => Ok(StaticUntypedTree::Function {
+                function: ScalarFunction::DatePart,
+                args: vec![
+                    StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::String(
+                        field.to_string().to_lowercase(),
+                    ))),
+                    Self::inner_build(expr, original)?,
+                ],
+            }),
+            sql_ast::Expr::Function(function) => Self::function(function, original),
+            expr => Self::syntax_error(expr, original),
         }
     }
 
+    fn syntax_error(expr: &sql_ast::Expr, original: &sql_ast::Statement) -> AnalysisResult<StaticUntypedTree> {
+        log::warn!("Syntax error in '{:#?}' around '{:?}'", original, expr);
+        Err(AnalysisError::syntax_error(format!(
+            "Syntax error in '{}' around '{}'",
+            original, expr
+        )))
+    }
+
     fn op(
         op: &sql_ast::BinaryOperator,
         left: &sql_ast::Expr,
         right: &sql_ast::Expr,
         original: &sql_ast::Statement,
     ) -> AnalysisResult<StaticUntypedTree> {
-        let operation = OperationMapper::binary_operation(op);
-        match (Self::inner_build(left, original), Self::inner_build(right, original)) {
-            (Ok(left_item), Ok(right_item)) => Ok(StaticUntypedTree::Operation {
-                left: Box::new(left_item),
-                op: operation,
-                right: Box::new(right_item),
+        Ok(StaticUntypedTree::Operation {
+            left: Box::new(Self::inner_build(left, original)?),
+            op: OperationMapper::binary_operation(op),
+            right: Box::new(Self::inner_build(right, original)?),
+        })
+    }
+
+    fn unary(
+        op: UnaryOperation,
+        expr: &sql_ast::Expr,
+        original: &sql_ast::Statement,
+    ) -> AnalysisResult<StaticUntypedTree> {
+        Ok(StaticUntypedTree::UnaryOperation {
+            op,
+            item: Box::new(Self::inner_build(expr, original)?),
+        })
+    }
+
+    fn cast(tree: StaticUntypedTree, data_type: &sql_ast::DataType) -> AnalysisResult<StaticUntypedTree> {
+        match SqlType::try_from(data_type) {
+            Ok(target) => Ok(StaticUntypedTree::Cast {
+                tree: Box::new(tree),
+                target,
             }),
-            _ => Err(AnalysisError::UndefinedFunction(operation)),
+            Err(_not_supported_type_error) => Err(AnalysisError::type_is_not_supported(data_type)),
+        }
+    }
+
+    fn function(function: &sql_ast::Function, original: &sql_ast::Statement) -> AnalysisResult<StaticUntypedTree> {
+        let sql_ast::Function { name, args, over, .. } = function;
+        if over.is_some() {
+            return Err(AnalysisError::feature_not_supported(Feature::WindowFunctions));
+        }
+        if let Some(op) = OperationMapper::json_operator_function(&name.to_string()) {
+            return match args.as_slice() {
+                [left, right] => Ok(StaticUntypedTree::Operation {
+                    left: Box::new(Self::inner_build(left, original)?),
+                    op,
+                    right: Box::new(Self::inner_build(right, original)?),
+                }),
+                _ => Err(AnalysisError::function_does_not_exist(name)),
+            };
+        }
+        match OperationMapper::scalar_function(&name.to_string()) {
+            Some(function) => Ok(StaticUntypedTree::Function {
+                function,
+                args: args
+                    .iter()
+                    .map(|arg| Self::inner_build(arg, original))
+                    .collect::<AnalysisResult<_>>()?,
+            }),
+            None => Err(AnalysisError::function_does_not_exist(name)),
         }
     }
 
     fn ident(ident: &sql_ast::Ident) -> AnalysisResult<StaticUntypedTree> {
+        if let Some(function) = OperationMapper::keyword_function(ident) {
+            return Ok(StaticUntypedTree::Function { function, args: vec![] });
+        }
         let sql_ast::Ident { value, .. } = ident;
         match parse_param_index(value.as_str()) {
             Some(index) => Ok(StaticUntypedTree::Item(StaticUntypedItem::Param(index))),
@@ -77,11 +156,23 @@ impl StaticTreeBuilder {
             sql_ast::Value::NationalStringLiteral(_) => {
                 Err(AnalysisError::feature_not_supported(Feature::NationalStringLiteral))
             }
-            sql_ast::Value::HexStringLiteral(_) => Err(AnalysisError::feature_not_supported(Feature::HexStringLiteral)),
+            sql_ast::Value::HexStringLiteral(hex) => Ok(StaticUntypedTree::Cast {
+                tree: Box::new(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::String(
+                    format!("\\x{}", hex),
+                )))),
+                target: SqlType::bytea(),
+            }),
             sql_ast::Value::Boolean(boolean) => Ok(StaticUntypedTree::Item(StaticUntypedItem::Const(
                 UntypedValue::Bool(Bool(*boolean)),
             ))),
-            sql_ast::Value::Interval { .. } => Err(AnalysisError::feature_not_supported(Feature::TimeInterval)),
+            sql_ast::Value::Interval {
+                value, leading_field, ..
+            } => Ok(StaticUntypedTree::Cast {
+                tree: Box::new(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::String(
+                    OperationMapper::interval_text(value, leading_field),
+                )))),
+                target: SqlType::interval(),
+            }),
             sql_ast::Value::Null => Ok(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Null))),
         }
     }
@@ -0,0 +1,267 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use data_definition_execution_plan::{
+    AddColumnQuery, AlterColumnTypeQuery, DropColumnQuery, RenameColumnQuery, RenameTableQuery,
+};
+use definition::{CheckDef, ColumnConstraints};
+use parser::AlterColumnType;
+
+fn alter_table(name: Vec<&str>, operation: sql_ast::AlterTableOperation) -> sql_ast::Statement {
+    sql_ast::Statement::AlterTable {
+        name: sql_ast::ObjectName(name.into_iter().map(ident).collect()),
+        operation,
+    }
+}
+
+fn add_column(name: &str, options: Vec<sql_ast::ColumnOption>) -> sql_ast::AlterTableOperation {
+    sql_ast::AlterTableOperation::AddColumn {
+        column_def: sql_ast::ColumnDef {
+            name: ident(name),
+            data_type: sql_ast::DataType::SmallInt,
+            collation: None,
+            options: options
+                .into_iter()
+                .map(|option| sql_ast::ColumnOptionDef { name: None, option })
+                .collect(),
+        },
+    }
+}
+
+fn database_with_table() -> Arc<InMemoryDatabase> {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database
+        .execute(create_table_ops(SCHEMA, TABLE, vec![("col_1", SqlType::small_int())]))
+        .unwrap();
+    database
+}
+
+#[test]
+fn alter_table_in_nonexistent_schema() {
+    let analyzer = Analyzer::new(InMemoryDatabase::new());
+
+    assert_eq!(
+        analyzer.analyze(alter_table(
+            vec!["non_existent_schema", TABLE],
+            add_column("col_2", vec![])
+        )),
+        Err(AnalysisError::schema_does_not_exist("non_existent_schema"))
+    );
+}
+
+#[test]
+fn alter_nonexistent_table() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(alter_table(
+            vec![SCHEMA, "non_existent_table"],
+            add_column("col_2", vec![])
+        )),
+        Err(AnalysisError::table_does_not_exist(format!(
+            "{}.{}",
+            SCHEMA, "non_existent_table"
+        )))
+    );
+}
+
+#[test]
+fn add_column_with_constraints() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(alter_table(
+            vec![SCHEMA, TABLE],
+            add_column(
+                "COL_2",
+                vec![
+                    sql_ast::ColumnOption::NotNull,
+                    sql_ast::ColumnOption::Default(sql_ast::Expr::Value(number(1))),
+                    sql_ast::ColumnOption::Check(sql_ast::Expr::BinaryOp {
+                        left: Box::new(sql_ast::Expr::Identifier(ident("col_2"))),
+                        op: sql_ast::BinaryOperator::Gt,
+                        right: Box::new(sql_ast::Expr::Identifier(ident("col_1"))),
+                    })
+                ]
+            )
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::AddColumn(AddColumnQuery {
+            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            column: ColumnInfo {
+                name: "col_2".to_owned(),
+                sql_type: SqlType::small_int(),
+                constraints: ColumnConstraints {
+                    not_null: true,
+                    default_expr: Some("1".to_owned()),
+                    check: Some(CheckDef {
+                        name: "table_name_col_2_check".to_owned(),
+                        predicate: "col_2 > col_1".to_owned()
+                    })
+                }
+            }
+        })))
+    );
+}
+
+#[test]
+fn add_unique_column() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(alter_table(
+            vec![SCHEMA, TABLE],
+            add_column("col_2", vec![sql_ast::ColumnOption::Unique { is_primary: false }])
+        )),
+        Err(AnalysisError::feature_not_supported(Feature::AddColumnConstraints))
+    );
+}
+
+#[test]
+fn drop_column() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(alter_table(
+            vec![SCHEMA, TABLE],
+            sql_ast::AlterTableOperation::DropColumn {
+                column_name: ident("COL_1"),
+                if_exists: true,
+                cascade: false,
+            }
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::DropColumn(
+            DropColumnQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_name: "col_1".to_owned(),
+                cascade: false,
+                if_exists: true,
+            }
+        )))
+    );
+}
+
+#[test]
+fn rename_column() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(alter_table(
+            vec![SCHEMA, TABLE],
+            sql_ast::AlterTableOperation::RenameColumn {
+                old_column_name: ident("col_1"),
+                new_column_name: ident("col_2"),
+            }
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::RenameColumn(
+            RenameColumnQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                old_column_name: "col_1".to_owned(),
+                new_column_name: "col_2".to_owned(),
+            }
+        )))
+    );
+}
+
+#[test]
+fn rename_table() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(alter_table(
+            vec![SCHEMA, TABLE],
+            sql_ast::AlterTableOperation::RenameTable {
+                table_name: ident("other_table")
+            }
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::RenameTable(
+            RenameTableQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                new_table_name: "other_table".to_owned(),
+            }
+        )))
+    );
+}
+
+#[test]
+fn drop_constraint() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(alter_table(
+            vec![SCHEMA, TABLE],
+            sql_ast::AlterTableOperation::DropConstraint {
+                name: ident("table_name_pkey")
+            }
+        )),
+        Err(AnalysisError::feature_not_supported(Feature::AlterTableConstraints))
+    );
+}
+
+fn alter_column_type(name: Vec<&str>, column_name: &str, data_type: sql_ast::DataType) -> AlterColumnType {
+    AlterColumnType {
+        table_name: sql_ast::ObjectName(name.into_iter().map(ident).collect()),
+        column_name: ident(column_name),
+        data_type,
+    }
+}
+
+#[test]
+fn change_type_of_column() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze_alter_column_type(&alter_column_type(
+            vec![SCHEMA, TABLE],
+            "COL_1",
+            sql_ast::DataType::BigInt
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::AlterColumnType(
+            AlterColumnTypeQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_name: "col_1".to_owned(),
+                sql_type: SqlType::big_int(),
+            }
+        )))
+    );
+}
+
+#[test]
+fn change_type_of_column_of_nonexistent_table() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze_alter_column_type(&alter_column_type(
+            vec![SCHEMA, "non_existent"],
+            "col_1",
+            sql_ast::DataType::BigInt
+        )),
+        Err(AnalysisError::table_does_not_exist(format!("{}.non_existent", SCHEMA)))
+    );
+}
+
+#[test]
+fn change_type_of_column_to_not_supported_type() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze_alter_column_type(&alter_column_type(
+            vec![SCHEMA, TABLE],
+            "col_1",
+            sql_ast::DataType::Custom(sql_ast::ObjectName(vec![ident("strange_type_name_whatever")]))
+        )),
+        Err(AnalysisError::type_is_not_supported("strange_type_name_whatever"))
+    );
+}
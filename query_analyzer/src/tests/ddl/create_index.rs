@@ -0,0 +1,173 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn create_index(
+    name: Vec<&str>,
+    table_name: Vec<&str>,
+    columns: Vec<sql_ast::Expr>,
+    unique: bool,
+) -> sql_ast::Statement {
+    sql_ast::Statement::CreateIndex {
+        name: sql_ast::ObjectName(name.into_iter().map(ident).collect()),
+        table_name: sql_ast::ObjectName(table_name.into_iter().map(ident).collect()),
+        columns: columns
+            .into_iter()
+            .map(|expr| sql_ast::OrderByExpr {
+                expr,
+                asc: None,
+                nulls_first: None,
+            })
+            .collect(),
+        unique,
+        if_not_exists: false,
+    }
+}
+
+fn column(name: &str) -> sql_ast::Expr {
+    sql_ast::Expr::Identifier(ident(name))
+}
+
+fn database_with_table() -> Arc<InMemoryDatabase> {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database
+        .execute(create_table_ops(
+            SCHEMA,
+            TABLE,
+            vec![("col_1", SqlType::integer()), ("col_2", SqlType::var_char(10))],
+        ))
+        .unwrap();
+    database
+}
+
+#[test]
+fn create_index_for_table_in_nonexistent_schema() {
+    let analyzer = Analyzer::new(InMemoryDatabase::new());
+
+    assert_eq!(
+        analyzer.analyze(create_index(
+            vec!["index_name"],
+            vec!["non_existent_schema", TABLE],
+            vec![column("col_1")],
+            false
+        )),
+        Err(AnalysisError::schema_does_not_exist("non_existent_schema"))
+    );
+}
+
+#[test]
+fn create_index_for_nonexistent_table() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(create_index(
+            vec!["index_name"],
+            vec![SCHEMA, "non_existent_table"],
+            vec![column("col_1")],
+            false
+        )),
+        Err(AnalysisError::table_does_not_exist(format!(
+            "{}.{}",
+            SCHEMA, "non_existent_table"
+        )))
+    );
+}
+
+#[test]
+fn create_index_for_nonexistent_column() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(create_index(
+            vec!["index_name"],
+            vec![SCHEMA, TABLE],
+            vec![column("col_3")],
+            false
+        )),
+        Err(AnalysisError::column_not_found("col_3"))
+    );
+}
+
+#[test]
+fn create_index_with_qualified_name() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(create_index(
+            vec![SCHEMA, "index_name"],
+            vec![SCHEMA, TABLE],
+            vec![column("col_1")],
+            false
+        )),
+        Err(AnalysisError::syntax_error("syntax error at or near \".\"".to_owned()))
+    );
+}
+
+#[test]
+fn create_unique_index() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(create_index(
+            vec!["index_name"],
+            vec![SCHEMA, TABLE],
+            vec![column("col_1")],
+            true
+        )),
+        Err(AnalysisError::feature_not_supported(Feature::UniqueIndexes))
+    );
+}
+
+#[test]
+fn create_index_over_expression() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(create_index(
+            vec!["index_name"],
+            vec![SCHEMA, TABLE],
+            vec![sql_ast::Expr::BinaryOp {
+                left: Box::new(column("col_1")),
+                op: sql_ast::BinaryOperator::Plus,
+                right: Box::new(sql_ast::Expr::Value(number(1))),
+            }],
+            false
+        )),
+        Err(AnalysisError::feature_not_supported(Feature::IndexExpressions))
+    );
+}
+
+#[test]
+fn create_index_over_several_columns() {
+    let analyzer = Analyzer::new(database_with_table());
+
+    assert_eq!(
+        analyzer.analyze(create_index(
+            vec!["Index_Name"],
+            vec![SCHEMA, TABLE],
+            vec![column("col_2"), column("COL_1")],
+            false
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateIndex(
+            CreateIndexQuery {
+                index_name: "index_name".to_owned(),
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_names: vec!["col_2".to_owned(), "col_1".to_owned()],
+                if_not_exists: false,
+            }
+        )))
+    );
+}
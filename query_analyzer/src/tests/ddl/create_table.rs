@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use super::*;
+use data_definition_execution_plan::ConstraintInfo;
+use definition::{CheckDef, ColumnConstraints, IndexKind};
 
 fn column(name: &str, data_type: sql_ast::DataType) -> sql_ast::ColumnDef {
     sql_ast::ColumnDef {
@@ -23,6 +25,46 @@ fn column(name: &str, data_type: sql_ast::DataType) -> sql_ast::ColumnDef {
     }
 }
 
+fn key_column(name: &str, is_primary: bool) -> sql_ast::ColumnDef {
+    sql_ast::ColumnDef {
+        name: ident(name),
+        data_type: sql_ast::DataType::SmallInt,
+        collation: None,
+        options: vec![sql_ast::ColumnOptionDef {
+            name: None,
+            option: sql_ast::ColumnOption::Unique { is_primary },
+        }],
+    }
+}
+
+fn unique(name: Option<&str>, columns: Vec<&str>, is_primary: bool) -> sql_ast::TableConstraint {
+    sql_ast::TableConstraint::Unique {
+        name: name.map(ident),
+        columns: columns.into_iter().map(ident).collect(),
+        is_primary,
+    }
+}
+
+fn create_table_with_constraints(
+    name: Vec<&str>,
+    columns: Vec<sql_ast::ColumnDef>,
+    constraints: Vec<sql_ast::TableConstraint>,
+) -> sql_ast::Statement {
+    sql_ast::Statement::CreateTable {
+        or_replace: false,
+        name: sql_ast::ObjectName(name.into_iter().map(ident).collect()),
+        columns,
+        constraints,
+        with_options: vec![],
+        if_not_exists: false,
+        external: false,
+        file_format: None,
+        location: None,
+        query: None,
+        without_rowid: false,
+    }
+}
+
 fn create_table_if_not_exists(
     name: Vec<&str>,
     columns: Vec<sql_ast::ColumnDef>,
@@ -67,6 +109,8 @@ fn create_table_with_unqualified_name() {
             CreateTableQuery {
                 full_table_name: FullTableName::from((&"public", &"only_table_in_the_name")),
                 column_defs: vec![],
+                constraints: vec![],
+                foreign_keys: vec![],
                 if_not_exists: false
             }
         )))
@@ -121,6 +165,8 @@ fn create_table_with_the_same_name() {
             CreateTableQuery {
                 full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
                 column_defs: vec![],
+                constraints: vec![],
+                foreign_keys: vec![],
                 if_not_exists: false,
             }
         )))
@@ -143,8 +189,11 @@ fn create_new_table_if_not_exist() {
                 full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
                 column_defs: vec![ColumnInfo {
                     name: "column_name".to_owned(),
-                    sql_type: SqlType::small_int()
+                    sql_type: SqlType::small_int(),
+                    constraints: ColumnConstraints::default(),
                 }],
+                constraints: vec![],
+                foreign_keys: vec![],
                 if_not_exists: true,
             }
         )))
@@ -166,10 +215,708 @@ fn successfully_create_table() {
                 full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
                 column_defs: vec![ColumnInfo {
                     name: "column_name".to_owned(),
-                    sql_type: SqlType::small_int()
+                    sql_type: SqlType::small_int(),
+                    constraints: ColumnConstraints::default(),
                 }],
+                constraints: vec![],
+                foreign_keys: vec![],
+                if_not_exists: false,
+            }
+        )))
+    );
+}
+
+#[test]
+fn create_table_with_float_columns() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    let analyzer = Analyzer::new(database);
+    let float_column = |name: &str, sql_type: SqlType| ColumnInfo {
+        name: name.to_owned(),
+        sql_type,
+        constraints: ColumnConstraints::default(),
+    };
+    assert_eq!(
+        analyzer.analyze(create_table(
+            vec![SCHEMA, TABLE],
+            vec![
+                column("col_1", sql_ast::DataType::Real),
+                column("col_2", sql_ast::DataType::Double),
+                column("col_3", sql_ast::DataType::Float(Some(24))),
+                column("col_4", sql_ast::DataType::Float(Some(25))),
+                column("col_5", sql_ast::DataType::Float(None)),
+            ],
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+            CreateTableQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_defs: vec![
+                    float_column("col_1", SqlType::real()),
+                    float_column("col_2", SqlType::double_precision()),
+                    float_column("col_3", SqlType::real()),
+                    float_column("col_4", SqlType::double_precision()),
+                    float_column("col_5", SqlType::double_precision()),
+                ],
+                constraints: vec![],
+                foreign_keys: vec![],
+                if_not_exists: false,
+            }
+        )))
+    );
+}
+
+#[test]
+fn create_table_with_numeric_columns() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    let analyzer = Analyzer::new(database);
+    let numeric_column = |name: &str, sql_type: SqlType| ColumnInfo {
+        name: name.to_owned(),
+        sql_type,
+        constraints: ColumnConstraints::default(),
+    };
+    assert_eq!(
+        analyzer.analyze(create_table(
+            vec![SCHEMA, TABLE],
+            vec![
+                column("col_1", sql_ast::DataType::Decimal(Some(10), Some(2))),
+                column("col_2", sql_ast::DataType::Decimal(Some(5), None)),
+                column("col_3", sql_ast::DataType::Decimal(None, None)),
+            ],
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+            CreateTableQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_defs: vec![
+                    numeric_column("col_1", SqlType::numeric(10, 2)),
+                    numeric_column("col_2", SqlType::numeric(5, 0)),
+                    numeric_column("col_3", SqlType::unbounded_numeric()),
+                ],
+                constraints: vec![],
+                foreign_keys: vec![],
+                if_not_exists: false,
+            }
+        )))
+    );
+}
+
+#[test]
+fn create_table_with_temporal_columns() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    let analyzer = Analyzer::new(database);
+    let temporal_column = |name: &str, sql_type: SqlType| ColumnInfo {
+        name: name.to_owned(),
+        sql_type,
+        constraints: ColumnConstraints::default(),
+    };
+    assert_eq!(
+        analyzer.analyze(create_table(
+            vec![SCHEMA, TABLE],
+            vec![
+                column("col_1", sql_ast::DataType::Date),
+                column("col_2", sql_ast::DataType::Time),
+                column("col_3", sql_ast::DataType::Timestamp),
+                column(
+                    "col_4",
+                    sql_ast::DataType::Custom(sql_ast::ObjectName(vec![ident("timestamptz")]))
+                ),
+                column("col_5", sql_ast::DataType::Interval),
+            ],
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+            CreateTableQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_defs: vec![
+                    temporal_column("col_1", SqlType::date()),
+                    temporal_column("col_2", SqlType::time()),
+                    temporal_column("col_3", SqlType::timestamp()),
+                    temporal_column("col_4", SqlType::timestamp_with_time_zone()),
+                    temporal_column("col_5", SqlType::interval()),
+                ],
+                constraints: vec![],
+                foreign_keys: vec![],
+                if_not_exists: false,
+            }
+        )))
+    );
+}
+
+#[test]
+fn create_table_with_text_bytea_and_uuid_columns() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    let analyzer = Analyzer::new(database);
+    let column_info = |name: &str, sql_type: SqlType| ColumnInfo {
+        name: name.to_owned(),
+        sql_type,
+        constraints: ColumnConstraints::default(),
+    };
+    assert_eq!(
+        analyzer.analyze(create_table(
+            vec![SCHEMA, TABLE],
+            vec![
+                column("col_1", sql_ast::DataType::Text),
+                column("col_2", sql_ast::DataType::Varchar(None)),
+                column("col_3", sql_ast::DataType::Char(None)),
+                column("col_4", sql_ast::DataType::Bytea),
+                column("col_5", sql_ast::DataType::Uuid),
+            ],
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+            CreateTableQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_defs: vec![
+                    column_info("col_1", SqlType::text()),
+                    column_info("col_2", SqlType::var_char(u64::MAX)),
+                    column_info("col_3", SqlType::char(1)),
+                    column_info("col_4", SqlType::bytea()),
+                    column_info("col_5", SqlType::uuid()),
+                ],
+                constraints: vec![],
+                foreign_keys: vec![],
                 if_not_exists: false,
             }
         )))
     );
 }
+
+#[test]
+fn create_table_with_json_columns() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    let analyzer = Analyzer::new(database);
+    let column_info = |name: &str, sql_type: SqlType| ColumnInfo {
+        name: name.to_owned(),
+        sql_type,
+        constraints: ColumnConstraints::default(),
+    };
+    let custom = |name: &str| sql_ast::DataType::Custom(sql_ast::ObjectName(vec![ident(name)]));
+    assert_eq!(
+        analyzer.analyze(create_table(
+            vec![SCHEMA, TABLE],
+            vec![column("col_1", custom("json")), column("col_2", custom("JSONB"))],
+        )),
+        Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+            CreateTableQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_defs: vec![
+                    column_info("col_1", SqlType::json()),
+                    column_info("col_2", SqlType::jsonb())
+                ],
+                constraints: vec![],
+                foreign_keys: vec![],
+                if_not_exists: false,
+            }
+        )))
+    );
+}
+
+#[cfg(test)]
+mod constraints {
+    use super::*;
+
+    fn small_int(name: &str) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_owned(),
+            sql_type: SqlType::small_int(),
+            constraints: ColumnConstraints::default(),
+        }
+    }
+
+    #[test]
+    fn primary_key_and_unique_columns() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(create_table(
+                vec![SCHEMA, TABLE],
+                vec![key_column("col_1", true), key_column("col_2", false)],
+            )),
+            Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+                CreateTableQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_defs: vec![small_int("col_1"), small_int("col_2")],
+                    constraints: vec![
+                        ConstraintInfo {
+                            name: "table_name_pkey".to_owned(),
+                            kind: IndexKind::PrimaryKey,
+                            column_names: vec!["col_1".to_owned()],
+                        },
+                        ConstraintInfo {
+                            name: "table_name_col_2_key".to_owned(),
+                            kind: IndexKind::Unique,
+                            column_names: vec!["col_2".to_owned()],
+                        }
+                    ],
+                    foreign_keys: vec![],
+                    if_not_exists: false,
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn table_constraints() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(create_table_with_constraints(
+                vec![SCHEMA, TABLE],
+                vec![
+                    column("col_1", sql_ast::DataType::SmallInt),
+                    column("col_2", sql_ast::DataType::SmallInt)
+                ],
+                vec![
+                    unique(Some("Named_Key"), vec!["col_1", "col_2"], false),
+                    unique(None, vec!["col_2"], false),
+                    unique(None, vec!["col_2"], false)
+                ]
+            )),
+            Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+                CreateTableQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_defs: vec![small_int("col_1"), small_int("col_2")],
+                    constraints: vec![
+                        ConstraintInfo {
+                            name: "named_key".to_owned(),
+                            kind: IndexKind::Unique,
+                            column_names: vec!["col_1".to_owned(), "col_2".to_owned()],
+                        },
+                        ConstraintInfo {
+                            name: "table_name_col_2_key".to_owned(),
+                            kind: IndexKind::Unique,
+                            column_names: vec!["col_2".to_owned()],
+                        },
+                        ConstraintInfo {
+                            name: "table_name_col_2_key1".to_owned(),
+                            kind: IndexKind::Unique,
+                            column_names: vec!["col_2".to_owned()],
+                        }
+                    ],
+                    foreign_keys: vec![],
+                    if_not_exists: false,
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn multiple_primary_keys() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(create_table_with_constraints(
+                vec![SCHEMA, TABLE],
+                vec![key_column("col_1", true), column("col_2", sql_ast::DataType::SmallInt)],
+                vec![unique(None, vec!["col_2"], true)]
+            )),
+            Err(AnalysisError::multiple_primary_keys(TABLE))
+        );
+    }
+
+    #[test]
+    fn key_column_does_not_exist() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(create_table_with_constraints(
+                vec![SCHEMA, TABLE],
+                vec![column("col_1", sql_ast::DataType::SmallInt)],
+                vec![unique(None, vec!["col_2"], true)]
+            )),
+            Err(AnalysisError::column_not_found("col_2"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod column_constraints {
+    use super::*;
+
+    fn column_with(name: &str, options: Vec<sql_ast::ColumnOption>) -> sql_ast::ColumnDef {
+        sql_ast::ColumnDef {
+            name: ident(name),
+            data_type: sql_ast::DataType::SmallInt,
+            collation: None,
+            options: options
+                .into_iter()
+                .map(|option| sql_ast::ColumnOptionDef { name: None, option })
+                .collect(),
+        }
+    }
+
+    fn positive(name: &str) -> sql_ast::Expr {
+        sql_ast::Expr::BinaryOp {
+            left: Box::new(sql_ast::Expr::Identifier(ident(name))),
+            op: sql_ast::BinaryOperator::Gt,
+            right: Box::new(sql_ast::Expr::Value(number(0))),
+        }
+    }
+
+    #[test]
+    fn not_null_default_and_check() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(create_table(
+                vec![SCHEMA, TABLE],
+                vec![
+                    column_with(
+                        "col_1",
+                        vec![
+                            sql_ast::ColumnOption::NotNull,
+                            sql_ast::ColumnOption::Default(sql_ast::Expr::Value(number(1))),
+                            sql_ast::ColumnOption::Check(positive("col_1"))
+                        ]
+                    ),
+                    column_with("col_2", vec![sql_ast::ColumnOption::Null])
+                ],
+            )),
+            Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+                CreateTableQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_defs: vec![
+                        ColumnInfo {
+                            name: "col_1".to_owned(),
+                            sql_type: SqlType::small_int(),
+                            constraints: ColumnConstraints {
+                                not_null: true,
+                                default_expr: Some("1".to_owned()),
+                                check: Some(CheckDef {
+                                    name: "table_name_col_1_check".to_owned(),
+                                    predicate: "col_1 > 0".to_owned(),
+                                }),
+                            },
+                        },
+                        ColumnInfo {
+                            name: "col_2".to_owned(),
+                            sql_type: SqlType::small_int(),
+                            constraints: ColumnConstraints::default(),
+                        }
+                    ],
+                    constraints: vec![],
+                    foreign_keys: vec![],
+                    if_not_exists: false,
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn default_references_column() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(create_table(
+                vec![SCHEMA, TABLE],
+                vec![column_with(
+                    "col_1",
+                    vec![sql_ast::ColumnOption::Default(sql_ast::Expr::Identifier(ident(
+                        "col_2"
+                    )))]
+                )],
+            )),
+            Err(AnalysisError::column_cant_be_referenced("col_2"))
+        );
+    }
+
+    #[test]
+    fn check_references_column_that_does_not_exist() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(create_table(
+                vec![SCHEMA, TABLE],
+                vec![column_with(
+                    "col_1",
+                    vec![sql_ast::ColumnOption::Check(sql_ast::Expr::Identifier(ident("col_2")))]
+                )],
+            )),
+            Err(AnalysisError::column_not_found("col_2"))
+        );
+    }
+
+    #[test]
+    fn table_check_constraint() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(create_table_with_constraints(
+                vec![SCHEMA, TABLE],
+                vec![column("col_1", sql_ast::DataType::SmallInt)],
+                vec![sql_ast::TableConstraint::Check {
+                    name: None,
+                    expr: Box::new(positive("col_1"))
+                }]
+            )),
+            Err(AnalysisError::feature_not_supported(Feature::TableCheckConstraints))
+        );
+    }
+}
+
+#[cfg(test)]
+mod foreign_keys {
+    use super::*;
+    use data_definition_execution_plan::ForeignKeyInfo;
+    use definition::ReferentialAction;
+
+    const PARENT: &str = "parent";
+
+    fn primary_key_ops(schema_name: &str, table_name: &str, column_name: &str) -> SystemOperation {
+        SystemOperation {
+            kind: Kind::Create(SystemObject::Index),
+            skip_steps_if: None,
+            steps: vec![vec![
+                Step::CreateIndex {
+                    schema_name: schema_name.to_owned(),
+                    table_name: table_name.to_owned(),
+                    index_name: format!("{}_pkey", table_name),
+                    column_names: vec![column_name.to_owned()],
+                    kind: IndexKind::PrimaryKey,
+                },
+                Step::CreateRecord {
+                    record: Record::Index {
+                        schema_name: schema_name.to_owned(),
+                        table_name: table_name.to_owned(),
+                        index_name: format!("{}_pkey", table_name),
+                        column_names: vec![column_name.to_owned()],
+                        kind: IndexKind::PrimaryKey,
+                    },
+                },
+            ]],
+        }
+    }
+
+    fn with_parent(primary_key: bool) -> Analyzer<InMemoryDatabase> {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        database
+            .execute(create_table_ops(SCHEMA, PARENT, vec![("id", SqlType::small_int())]))
+            .unwrap();
+        if primary_key {
+            database.execute(primary_key_ops(SCHEMA, PARENT, "id")).unwrap();
+        }
+        Analyzer::new(database)
+    }
+
+    fn references(
+        name: &str,
+        data_type: sql_ast::DataType,
+        referred_columns: Vec<&str>,
+        on_delete: Option<sql_ast::ReferentialAction>,
+    ) -> sql_ast::ColumnDef {
+        sql_ast::ColumnDef {
+            name: ident(name),
+            data_type,
+            collation: None,
+            options: vec![sql_ast::ColumnOptionDef {
+                name: None,
+                option: sql_ast::ColumnOption::ForeignKey {
+                    foreign_table: sql_ast::ObjectName(vec![ident(SCHEMA), ident(PARENT)]),
+                    referred_columns: referred_columns.into_iter().map(ident).collect(),
+                    on_delete,
+                    on_update: None,
+                },
+            }],
+        }
+    }
+
+    fn foreign_key(
+        name: Option<&str>,
+        columns: Vec<&str>,
+        foreign_table: Vec<&str>,
+        referred_columns: Vec<&str>,
+    ) -> sql_ast::TableConstraint {
+        sql_ast::TableConstraint::ForeignKey {
+            name: name.map(ident),
+            columns: columns.into_iter().map(ident).collect(),
+            foreign_table: sql_ast::ObjectName(foreign_table.into_iter().map(ident).collect()),
+            referred_columns: referred_columns.into_iter().map(ident).collect(),
+        }
+    }
+
+    fn small_int(name: &str) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_owned(),
+            sql_type: SqlType::small_int(),
+            constraints: ColumnConstraints::default(),
+        }
+    }
+
+    #[test]
+    fn column_references_primary_key() {
+        let analyzer = with_parent(true);
+
+        assert_eq!(
+            analyzer.analyze(create_table(
+                vec![SCHEMA, TABLE],
+                vec![references(
+                    "parent_id",
+                    sql_ast::DataType::SmallInt,
+                    vec![],
+                    Some(sql_ast::ReferentialAction::Cascade)
+                )],
+            )),
+            Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+                CreateTableQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_defs: vec![small_int("parent_id")],
+                    constraints: vec![],
+                    foreign_keys: vec![ForeignKeyInfo {
+                        name: "table_name_parent_id_fkey".to_owned(),
+                        column_names: vec!["parent_id".to_owned()],
+                        referenced_table: FullTableName::from((&SCHEMA, &PARENT)),
+                        referenced_column_names: vec!["id".to_owned()],
+                        on_delete: ReferentialAction::Cascade,
+                        on_update: ReferentialAction::NoAction,
+                    }],
+                    if_not_exists: false,
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn table_references_itself() {
+        let analyzer = with_parent(false);
+
+        assert_eq!(
+            analyzer.analyze(create_table_with_constraints(
+                vec![SCHEMA, TABLE],
+                vec![key_column("col_1", true), column("col_2", sql_ast::DataType::SmallInt)],
+                vec![foreign_key(
+                    Some("Parent_Row"),
+                    vec!["col_2"],
+                    vec![SCHEMA, TABLE],
+                    vec!["col_1"]
+                )]
+            )),
+            Ok(QueryAnalysis::DataDefinition(SchemaChange::CreateTable(
+                CreateTableQuery {
+                    full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                    column_defs: vec![small_int("col_1"), small_int("col_2")],
+                    constraints: vec![ConstraintInfo {
+                        name: "table_name_pkey".to_owned(),
+                        kind: IndexKind::PrimaryKey,
+                        column_names: vec!["col_1".to_owned()],
+                    }],
+                    foreign_keys: vec![ForeignKeyInfo {
+                        name: "parent_row".to_owned(),
+                        column_names: vec!["col_2".to_owned()],
+                        referenced_table: FullTableName::from((&SCHEMA, &TABLE)),
+                        referenced_column_names: vec!["col_1".to_owned()],
+                        on_delete: ReferentialAction::NoAction,
+                        on_update: ReferentialAction::NoAction,
+                    }],
+                    if_not_exists: false,
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn referenced_table_does_not_exist() {
+        let analyzer = with_parent(true);
+
+        assert_eq!(
+            analyzer.analyze(create_table_with_constraints(
+                vec![SCHEMA, TABLE],
+                vec![column("col_1", sql_ast::DataType::SmallInt)],
+                vec![foreign_key(None, vec!["col_1"], vec![SCHEMA, "non_existent"], vec![])]
+            )),
+            Err(AnalysisError::table_does_not_exist(format!("{}.non_existent", SCHEMA)))
+        );
+    }
+
+    #[test]
+    fn referenced_columns_are_not_unique() {
+        let analyzer = with_parent(false);
+
+        assert_eq!(
+            analyzer.analyze(create_table(
+                vec![SCHEMA, TABLE],
+                vec![references("parent_id", sql_ast::DataType::SmallInt, vec!["id"], None)],
+            )),
+            Err(AnalysisError::no_unique_constraint_for_referenced_table(PARENT))
+        );
+    }
+
+    #[test]
+    fn number_of_columns_disagree() {
+        let analyzer = with_parent(true);
+
+        assert_eq!(
+            analyzer.analyze(create_table_with_constraints(
+                vec![SCHEMA, TABLE],
+                vec![
+                    column("col_1", sql_ast::DataType::SmallInt),
+                    column("col_2", sql_ast::DataType::SmallInt)
+                ],
+                vec![foreign_key(
+                    None,
+                    vec!["col_1", "col_2"],
+                    vec![SCHEMA, PARENT],
+                    vec!["id"]
+                )]
+            )),
+            Err(AnalysisError::ForeignKeyColumnsDisagree)
+        );
+    }
+
+    #[test]
+    fn incompatible_column_types() {
+        let analyzer = with_parent(true);
+
+        assert_eq!(
+            analyzer.analyze(create_table(
+                vec![SCHEMA, TABLE],
+                vec![references(
+                    "parent_id",
+                    sql_ast::DataType::Varchar(Some(5)),
+                    vec![],
+                    None
+                )],
+            )),
+            Err(AnalysisError::foreign_key_cannot_be_implemented(
+                "table_name_parent_id_fkey"
+            ))
+        );
+    }
+
+    #[test]
+    fn set_default_action() {
+        let analyzer = with_parent(true);
+
+        assert_eq!(
+            analyzer.analyze(create_table(
+                vec![SCHEMA, TABLE],
+                vec![references(
+                    "parent_id",
+                    sql_ast::DataType::SmallInt,
+                    vec![],
+                    Some(sql_ast::ReferentialAction::SetDefault)
+                )],
+            )),
+            Err(AnalysisError::feature_not_supported(
+                Feature::SetDefaultReferentialAction
+            ))
+        );
+    }
+}
@@ -274,3 +274,58 @@ mod table {
         );
     }
 }
+
+#[cfg(test)]
+mod index {
+    use super::*;
+
+    const INDEX_TYPE: sql_ast::ObjectType = sql_ast::ObjectType::Index;
+
+    #[test]
+    fn drop_index_from_nonexistent_schema() {
+        let analyzer = Analyzer::new(InMemoryDatabase::new());
+        assert_eq!(
+            analyzer.analyze(drop_statement(
+                vec![vec!["non_existent_schema", "index_name"]],
+                INDEX_TYPE
+            )),
+            Err(AnalysisError::schema_does_not_exist(&"non_existent_schema"))
+        );
+    }
+
+    #[test]
+    fn drop_index_with_unsupported_name() {
+        let analyzer = Analyzer::new(InMemoryDatabase::new());
+        assert_eq!(
+            analyzer.analyze(drop_statement(
+                vec![vec!["first_part", "second_part", "third_part"]],
+                INDEX_TYPE,
+            )),
+            Err(AnalysisError::syntax_error(
+                "improper qualified name (too many dotted names): first_part.second_part.third_part".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn drop_indexes_if_exists() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        let analyzer = Analyzer::new(database);
+        assert_eq!(
+            analyzer.analyze(drop_if_exists(
+                vec![vec![SCHEMA, "index_name"], vec!["other_index_name"]],
+                INDEX_TYPE,
+            )),
+            Ok(QueryAnalysis::DataDefinition(SchemaChange::DropIndexes(
+                DropIndexesQuery {
+                    full_index_names: vec![
+                        FullTableName::from((&SCHEMA, &"index_name")),
+                        FullTableName::from((&"public", &"other_index_name"))
+                    ],
+                    if_exists: true
+                }
+            )))
+        );
+    }
+}
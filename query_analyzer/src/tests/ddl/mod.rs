@@ -15,6 +15,10 @@
 use super::*;
 use data_definition_execution_plan::{ColumnInfo, CreateSchemaQuery, CreateTableQuery, DropSchemasQuery, SchemaChange};
 
+#[cfg(test)]
+mod alter_table;
+#[cfg(test)]
+mod create_index;
 #[cfg(test)]
 mod create_schema;
 #[cfg(test)]
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data_manipulation_untyped_tree::{DynamicUntypedItem, DynamicUntypedTree, UntypedValue};
+
 use super::*;
 
 fn delete_statement(table_name: Vec<&'static str>) -> sql_ast::Statement {
@@ -77,6 +79,41 @@ fn delete_all_from_table() {
         }),
         Ok(QueryAnalysis::Write(UntypedWrite::Delete(DeleteQuery {
             full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            filter: None,
+        })))
+    );
+}
+
+#[test]
+fn delete_from_table_with_filter() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database
+        .execute(create_table_ops(SCHEMA, TABLE, vec![("col1", SqlType::integer())]))
+        .unwrap();
+    let analyzer = Analyzer::new(database);
+    assert_eq!(
+        analyzer.analyze(sql_ast::Statement::Delete {
+            table_name: sql_ast::ObjectName(vec![ident(SCHEMA), ident(TABLE)]),
+            selection: Some(sql_ast::Expr::BinaryOp {
+                left: Box::new(sql_ast::Expr::Identifier(ident("col1"))),
+                op: sql_ast::BinaryOperator::Gt,
+                right: Box::new(sql_ast::Expr::Value(number(1)))
+            })
+        }),
+        Ok(QueryAnalysis::Write(UntypedWrite::Delete(DeleteQuery {
+            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            filter: Some(DynamicUntypedTree::Operation {
+                left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+                    name: "col1".to_owned(),
+                    sql_type: SqlType::integer(),
+                    index: 0
+                })),
+                op: Operation::Comparison(Comparison::Gt),
+                right: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+                    UntypedValue::Number(BigDecimal::from(1))
+                )))
+            }),
         })))
     );
 }
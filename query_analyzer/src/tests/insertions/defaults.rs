@@ -0,0 +1,109 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use definition::CheckDef;
+
+fn create_table_with_defaults() -> SystemOperation {
+    let mut operation = create_table(
+        SCHEMA,
+        TABLE,
+        vec![("col_1", SqlType::small_int()), ("col_2", SqlType::small_int())],
+    );
+    operation.steps[0].push(Step::CreateRecord {
+        record: Record::Column {
+            schema_name: SCHEMA.to_owned(),
+            table_name: TABLE.to_owned(),
+            column_name: "col_3".to_owned(),
+            sql_type: SqlType::small_int(),
+            constraints: ColumnConstraints {
+                not_null: true,
+                default_expr: Some("1 + 2".to_owned()),
+                check: Some(CheckDef {
+                    name: "col_3_is_positive".to_owned(),
+                    predicate: "col_3 > 0".to_owned(),
+                }),
+            },
+        },
+    });
+    operation
+}
+
+fn number_tree(value: i16) -> StaticUntypedTree {
+    StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Number(BigDecimal::from(value))))
+}
+
+fn default_tree() -> StaticUntypedTree {
+    StaticUntypedTree::Operation {
+        left: Box::new(number_tree(1)),
+        op: Operation::Arithmetic(Arithmetic::Add),
+        right: Box::new(number_tree(2)),
+    }
+}
+
+fn check_tree() -> DynamicUntypedTree {
+    DynamicUntypedTree::Operation {
+        left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+            name: "col_3".to_owned(),
+            sql_type: SqlType::small_int(),
+            index: 2,
+        })),
+        op: Operation::Comparison(Comparison::Gt),
+        right: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+            UntypedValue::Number(BigDecimal::from(0)),
+        ))),
+    }
+}
+
+#[test]
+fn omitted_columns() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema(SCHEMA)).unwrap();
+    database.execute(create_table_with_defaults()).unwrap();
+    let analyzer = Analyzer::new(database);
+
+    assert_eq!(
+        analyzer.analyze(inner_insert(
+            vec![SCHEMA, TABLE],
+            vec![vec![small_int(10)]],
+            vec!["col_1"]
+        )),
+        Ok(QueryAnalysis::Write(UntypedWrite::Insert(InsertQuery {
+            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            values: vec![vec![Some(number_tree(10)), None, Some(default_tree())]],
+            checks: vec![("col_3_is_positive".to_owned(), check_tree())],
+        })))
+    );
+}
+
+#[test]
+fn default_keyword() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema(SCHEMA)).unwrap();
+    database.execute(create_table_with_defaults()).unwrap();
+    let analyzer = Analyzer::new(database);
+
+    let default = || sql_ast::Expr::Identifier(ident("DEFAULT"));
+    assert_eq!(
+        analyzer.analyze(insert_with_values(
+            vec![SCHEMA, TABLE],
+            vec![vec![default(), small_int(20), default()]]
+        )),
+        Ok(QueryAnalysis::Write(UntypedWrite::Insert(InsertQuery {
+            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            values: vec![vec![None, Some(number_tree(20)), Some(default_tree())]],
+            checks: vec![("col_3_is_positive".to_owned(), check_tree())],
+        })))
+    );
+}
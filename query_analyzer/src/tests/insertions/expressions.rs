@@ -41,6 +41,34 @@ fn insert_number() {
             values: vec![vec![Some(StaticUntypedTree::Item(StaticUntypedItem::Const(
                 UntypedValue::Number(BigDecimal::from(1))
             )))]],
+            checks: vec![],
+        })))
+    );
+}
+
+#[test]
+fn insert_negative_number() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema(SCHEMA)).unwrap();
+    database
+        .execute(create_table(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+        .unwrap();
+    let analyzer = Analyzer::new(database);
+
+    assert_eq!(
+        analyzer.analyze(insert_with_values(
+            vec![SCHEMA, TABLE],
+            vec![vec![sql_ast::Expr::UnaryOp {
+                op: sql_ast::UnaryOperator::Minus,
+                expr: Box::new(small_int(1))
+            }]]
+        )),
+        Ok(QueryAnalysis::Write(UntypedWrite::Insert(InsertQuery {
+            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            values: vec![vec![Some(StaticUntypedTree::Item(StaticUntypedItem::Const(
+                UntypedValue::Number(BigDecimal::from(-1))
+            )))]],
+            checks: vec![],
         })))
     );
 }
@@ -61,6 +89,7 @@ fn insert_string() {
             values: vec![vec![Some(StaticUntypedTree::Item(StaticUntypedItem::Const(
                 UntypedValue::String("str".to_owned())
             )))]],
+            checks: vec![],
         })))
     );
 }
@@ -81,6 +110,7 @@ fn insert_boolean() {
             values: vec![vec![Some(StaticUntypedTree::Item(StaticUntypedItem::Const(
                 UntypedValue::Bool(Bool(true))
             )))]],
+            checks: vec![],
         })))
     );
 }
@@ -101,6 +131,7 @@ fn insert_null() {
             values: vec![vec![Some(StaticUntypedTree::Item(StaticUntypedItem::Const(
                 UntypedValue::Null
             )))]],
+            checks: vec![],
         })))
     );
 }
@@ -144,6 +175,38 @@ fn insert_into_table_with_parameters() {
                 Some(StaticUntypedTree::Item(StaticUntypedItem::Param(0))),
                 Some(StaticUntypedTree::Item(StaticUntypedItem::Param(1)))
             ]],
+            checks: vec![],
+        })))
+    );
+}
+
+#[test]
+fn insert_parameters_into_leading_columns() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema(SCHEMA)).unwrap();
+    database
+        .execute(create_table(
+            SCHEMA,
+            TABLE,
+            vec![
+                ("col_1", SqlType::small_int()),
+                ("col_2", SqlType::small_int()),
+                ("col_3", SqlType::small_int()),
+            ],
+        ))
+        .unwrap();
+    let analyzer = Analyzer::new(database);
+
+    assert_eq!(
+        analyzer.analyze(insert_with_parameters(vec![SCHEMA, TABLE], vec!["$1", "$2"])),
+        Ok(QueryAnalysis::Write(UntypedWrite::Insert(InsertQuery {
+            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            values: vec![vec![
+                Some(StaticUntypedTree::Item(StaticUntypedItem::Param(0))),
+                Some(StaticUntypedTree::Item(StaticUntypedItem::Param(1))),
+                None
+            ]],
+            checks: vec![],
         })))
     );
 }
@@ -177,12 +240,14 @@ fn insert_into_table_with_parameters_and_values() {
                     BigDecimal::from(1)
                 ))))
             ]],
+            checks: vec![],
         })))
     );
 }
 
 #[cfg(test)]
 mod multiple_values {
+    use data_manipulation_operators::UnaryOperation;
     use data_manipulation_untyped_tree::{StaticUntypedItem, StaticUntypedTree, UntypedValue};
 
     use super::*;
@@ -228,6 +293,7 @@ mod multiple_values {
                         BigDecimal::from(1)
                     ))))
                 })]],
+                checks: vec![],
             })))
         );
     }
@@ -258,6 +324,7 @@ mod multiple_values {
                         "str".to_owned()
                     ))))
                 })]],
+                checks: vec![],
             })))
         );
     }
@@ -288,6 +355,7 @@ mod multiple_values {
                         BigDecimal::from(1)
                     ))))
                 })]],
+                checks: vec![],
             })))
         );
     }
@@ -318,6 +386,7 @@ mod multiple_values {
                         Bool(true)
                     )))),
                 })]],
+                checks: vec![],
             })))
         );
     }
@@ -348,6 +417,7 @@ mod multiple_values {
                         BigDecimal::from(1)
                     ))))
                 })]],
+                checks: vec![],
             })))
         );
     }
@@ -378,9 +448,110 @@ mod multiple_values {
                         "str".to_owned()
                     ))))
                 })]],
+                checks: vec![],
+            })))
+        );
+    }
+
+    #[test]
+    fn nested_expression() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema(SCHEMA)).unwrap();
+        database
+            .execute(create_table(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+            .unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(insert_value_as_expression_with_operation(
+                sql_ast::Expr::Nested(Box::new(sql_ast::Expr::BinaryOp {
+                    left: Box::new(small_int(1)),
+                    op: sql_ast::BinaryOperator::Plus,
+                    right: Box::new(small_int(2)),
+                })),
+                sql_ast::BinaryOperator::Multiply,
+                small_int(3)
+            )),
+            Ok(QueryAnalysis::Write(UntypedWrite::Insert(InsertQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                values: vec![vec![Some(StaticUntypedTree::Operation {
+                    left: Box::new(StaticUntypedTree::Operation {
+                        left: Box::new(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Number(
+                            BigDecimal::from(1)
+                        )))),
+                        op: Operation::Arithmetic(Arithmetic::Add),
+                        right: Box::new(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Number(
+                            BigDecimal::from(2)
+                        ))))
+                    }),
+                    op: Operation::Arithmetic(Arithmetic::Mul),
+                    right: Box::new(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Number(
+                        BigDecimal::from(3)
+                    ))))
+                })]],
+                checks: vec![],
+            })))
+        );
+    }
+
+    #[test]
+    fn minus_of_expression() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema(SCHEMA)).unwrap();
+        database
+            .execute(create_table(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+            .unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(insert_with_values(
+                vec![SCHEMA, TABLE],
+                vec![vec![sql_ast::Expr::UnaryOp {
+                    op: sql_ast::UnaryOperator::Minus,
+                    expr: Box::new(sql_ast::Expr::Nested(Box::new(sql_ast::Expr::BinaryOp {
+                        left: Box::new(small_int(1)),
+                        op: sql_ast::BinaryOperator::Plus,
+                        right: Box::new(small_int(2)),
+                    })))
+                }]]
+            )),
+            Ok(QueryAnalysis::Write(UntypedWrite::Insert(InsertQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                values: vec![vec![Some(StaticUntypedTree::UnaryOperation {
+                    op: UnaryOperation::Minus,
+                    item: Box::new(StaticUntypedTree::Operation {
+                        left: Box::new(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Number(
+                            BigDecimal::from(1)
+                        )))),
+                        op: Operation::Arithmetic(Arithmetic::Add),
+                        right: Box::new(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Number(
+                            BigDecimal::from(2)
+                        ))))
+                    })
+                })]],
+                checks: vec![],
             })))
         );
     }
+
+    #[test]
+    fn error_of_operand_is_kept() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema(SCHEMA)).unwrap();
+        database
+            .execute(create_table(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+            .unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(insert_value_as_expression_with_operation(
+                small_int(1),
+                sql_ast::BinaryOperator::Plus,
+                sql_ast::Expr::Identifier(ident("col"))
+            )),
+            Err(AnalysisError::column_cant_be_referenced("col"))
+        );
+    }
 }
 
 #[cfg(test)]
@@ -412,7 +583,7 @@ mod not_supported_values {
         let database = InMemoryDatabase::new();
         database.execute(create_schema(SCHEMA)).unwrap();
         database
-            .execute(create_table(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+            .execute(create_table(SCHEMA, TABLE, vec![("col", SqlType::bytea())]))
             .unwrap();
         let analyzer = Analyzer::new(database);
 
@@ -420,10 +591,19 @@ mod not_supported_values {
             analyzer.analyze(insert_with_values(
                 vec![SCHEMA, TABLE],
                 vec![vec![sql_ast::Expr::Value(sql_ast::Value::HexStringLiteral(
-                    "str".to_owned()
+                    "DEADBEEF".to_owned()
                 ))]]
             )),
-            Err(AnalysisError::FeatureNotSupported(Feature::HexStringLiteral))
+            Ok(QueryAnalysis::Write(UntypedWrite::Insert(InsertQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                values: vec![vec![Some(StaticUntypedTree::Cast {
+                    tree: Box::new(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::String(
+                        "\\xDEADBEEF".to_owned()
+                    )))),
+                    target: SqlType::bytea(),
+                })]],
+                checks: vec![],
+            })))
         );
     }
 
@@ -432,7 +612,7 @@ mod not_supported_values {
         let database = InMemoryDatabase::new();
         database.execute(create_schema(SCHEMA)).unwrap();
         database
-            .execute(create_table(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+            .execute(create_table(SCHEMA, TABLE, vec![("col", SqlType::interval())]))
             .unwrap();
         let analyzer = Analyzer::new(database);
 
@@ -440,14 +620,23 @@ mod not_supported_values {
             analyzer.analyze(insert_with_values(
                 vec![SCHEMA, TABLE],
                 vec![vec![sql_ast::Expr::Value(sql_ast::Value::Interval {
-                    value: "value".to_owned(),
-                    leading_field: None,
+                    value: "3".to_owned(),
+                    leading_field: Some(sql_ast::DateTimeField::Day),
                     leading_precision: None,
                     last_field: None,
                     fractional_seconds_precision: None
                 })]]
             )),
-            Err(AnalysisError::FeatureNotSupported(Feature::TimeInterval))
+            Ok(QueryAnalysis::Write(UntypedWrite::Insert(InsertQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                values: vec![vec![Some(StaticUntypedTree::Cast {
+                    tree: Box::new(StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::String(
+                        "3 day".to_owned()
+                    )))),
+                    target: SqlType::interval(),
+                })]],
+                checks: vec![],
+            })))
         );
     }
 }
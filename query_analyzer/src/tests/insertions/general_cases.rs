@@ -85,6 +85,7 @@ fn with_column_names() {
             values: vec![vec![Some(StaticUntypedTree::Item(StaticUntypedItem::Const(
                 UntypedValue::Number(BigDecimal::from(100))
             )))]],
+            checks: vec![],
         })))
     );
 }
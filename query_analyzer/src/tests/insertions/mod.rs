@@ -16,6 +16,8 @@ use super::*;
 use data_definition_operations::{Kind, Record, Step, SystemObject, SystemOperation};
 use data_manipulation_untyped_tree::{StaticUntypedItem, StaticUntypedTree, UntypedValue};
 
+#[cfg(test)]
+mod defaults;
 #[cfg(test)]
 mod expressions;
 #[cfg(test)]
@@ -55,6 +57,7 @@ fn create_table(schema_name: &str, table_name: &str, columns: Vec<(&str, SqlType
                 table_name: table_name.to_owned(),
                 column_name: name.to_owned(),
                 sql_type,
+                constraints: ColumnConstraints::default(),
             },
         })
         .collect::<Vec<Step>>();
@@ -90,6 +90,7 @@ fn create_table_ops(schema_name: &str, table_name: &str, columns: Vec<(&str, Sql
                 table_name: table_name.to_owned(),
                 column_name: column_name.to_owned(),
                 sql_type: column_type,
+                constraints: ColumnConstraints::default(),
             },
         })
         .collect();
@@ -0,0 +1,282 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data_manipulation_operators::AggregateFunction;
+use data_manipulation_untyped_tree::{DynamicUntypedItem, DynamicUntypedTree, UntypedValue};
+
+use super::*;
+
+fn analyzer() -> Analyzer<InMemoryDatabase> {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database
+        .execute(create_table_ops(
+            SCHEMA,
+            TABLE,
+            vec![("col1", SqlType::integer()), ("col2", SqlType::integer())],
+        ))
+        .unwrap();
+    Analyzer::new(database)
+}
+
+fn select_grouped(
+    projection: Vec<sql_ast::Expr>,
+    selection: Option<sql_ast::Expr>,
+    group_by: Vec<sql_ast::Expr>,
+    having: Option<sql_ast::Expr>,
+) -> sql_ast::Statement {
+    match select_with_columns(
+        vec![SCHEMA, TABLE],
+        projection.into_iter().map(sql_ast::SelectItem::UnnamedExpr).collect(),
+    ) {
+        sql_ast::Statement::Query(mut query) => {
+            if let sql_ast::SetExpr::Select(select) = &mut query.body {
+                select.selection = selection;
+                select.group_by = group_by;
+                select.having = having;
+            }
+            sql_ast::Statement::Query(query)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn function(name: &str, args: Vec<sql_ast::Expr>, distinct: bool) -> sql_ast::Expr {
+    sql_ast::Expr::Function(sql_ast::Function {
+        name: sql_ast::ObjectName(vec![ident(name)]),
+        args,
+        over: None,
+        distinct,
+    })
+}
+
+fn identifier(name: &str) -> sql_ast::Expr {
+    sql_ast::Expr::Identifier(ident(name))
+}
+
+fn column(name: &str, index: usize) -> DynamicUntypedTree {
+    DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+        name: name.to_owned(),
+        index,
+        sql_type: SqlType::integer(),
+    })
+}
+
+fn aggregate(function: AggregateFunction, argument: Option<DynamicUntypedTree>, distinct: bool) -> DynamicUntypedTree {
+    DynamicUntypedTree::Aggregate {
+        function,
+        argument: argument.map(Box::new),
+        distinct,
+    }
+}
+
+#[test]
+fn count_all_rows() {
+    assert_eq!(
+        analyzer().analyze(select_grouped(
+            vec![function("count", vec![sql_ast::Expr::Wildcard], false)],
+            None,
+            vec![],
+            None
+        )),
+        Ok(QueryAnalysis::Read(SelectQuery {
+            full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+            relation: TABLE.to_owned(),
+            joins: vec![],
+            projection_items: vec![aggregate(AggregateFunction::Count, None, false)],
+            filter: None,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        }))
+    );
+}
+
+#[test]
+fn distinct_values_of_expression() {
+    assert_eq!(
+        analyzer().analyze(select_grouped(
+            vec![function(
+                "SUM",
+                vec![sql_ast::Expr::BinaryOp {
+                    left: Box::new(identifier("col1")),
+                    op: sql_ast::BinaryOperator::Plus,
+                    right: Box::new(identifier("col2")),
+                }],
+                true
+            )],
+            None,
+            vec![],
+            None
+        )),
+        Ok(QueryAnalysis::Read(SelectQuery {
+            full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+            relation: TABLE.to_owned(),
+            joins: vec![],
+            projection_items: vec![aggregate(
+                AggregateFunction::Sum,
+                Some(DynamicUntypedTree::Operation {
+                    left: Box::new(column("col1", 0)),
+                    op: Operation::Arithmetic(Arithmetic::Add),
+                    right: Box::new(column("col2", 1)),
+                }),
+                true
+            )],
+            filter: None,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        }))
+    );
+}
+
+#[test]
+fn group_by_with_having() {
+    assert_eq!(
+        analyzer().analyze(select_grouped(
+            vec![identifier("col1"), function("max", vec![identifier("col2")], false)],
+            None,
+            vec![identifier("col1")],
+            Some(sql_ast::Expr::BinaryOp {
+                left: Box::new(function("count", vec![sql_ast::Expr::Wildcard], false)),
+                op: sql_ast::BinaryOperator::Gt,
+                right: Box::new(sql_ast::Expr::Value(number(1))),
+            })
+        )),
+        Ok(QueryAnalysis::Read(SelectQuery {
+            full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+            relation: TABLE.to_owned(),
+            joins: vec![],
+            projection_items: vec![
+                column("col1", 0),
+                aggregate(AggregateFunction::Max, Some(column("col2", 1)), false)
+            ],
+            filter: None,
+            group_by: vec![column("col1", 0)],
+            having: Some(DynamicUntypedTree::Operation {
+                left: Box::new(aggregate(AggregateFunction::Count, None, false)),
+                op: Operation::Comparison(Comparison::Gt),
+                right: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+                    UntypedValue::Number(BigDecimal::from(1))
+                ))),
+            }),
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        }))
+    );
+}
+
+#[test]
+fn column_that_is_not_grouped() {
+    assert_eq!(
+        analyzer().analyze(select_grouped(
+            vec![identifier("col1"), identifier("col2")],
+            None,
+            vec![identifier("col1")],
+            None
+        )),
+        Err(AnalysisError::column_must_appear_in_group_by("col2"))
+    );
+}
+
+#[test]
+fn column_together_with_aggregate_without_grouping() {
+    assert_eq!(
+        analyzer().analyze(select_grouped(
+            vec![identifier("col1"), function("count", vec![identifier("col2")], false)],
+            None,
+            vec![],
+            None
+        )),
+        Err(AnalysisError::column_must_appear_in_group_by("col1"))
+    );
+}
+
+#[test]
+fn aggregate_in_where_clause() {
+    assert_eq!(
+        analyzer().analyze(select_grouped(
+            vec![identifier("col1")],
+            Some(sql_ast::Expr::BinaryOp {
+                left: Box::new(function("min", vec![identifier("col2")], false)),
+                op: sql_ast::BinaryOperator::Gt,
+                right: Box::new(sql_ast::Expr::Value(number(1))),
+            }),
+            vec![],
+            None
+        )),
+        Err(AnalysisError::aggregate_functions_are_not_allowed_in("WHERE"))
+    );
+}
+
+#[test]
+fn aggregate_in_group_by_clause() {
+    assert_eq!(
+        analyzer().analyze(select_grouped(
+            vec![function("count", vec![sql_ast::Expr::Wildcard], false)],
+            None,
+            vec![function("max", vec![identifier("col2")], false)],
+            None
+        )),
+        Err(AnalysisError::aggregate_functions_are_not_allowed_in("GROUP BY"))
+    );
+}
+
+#[test]
+fn nested_aggregates() {
+    assert_eq!(
+        analyzer().analyze(select_grouped(
+            vec![function(
+                "avg",
+                vec![function("count", vec![sql_ast::Expr::Wildcard], false)],
+                false
+            )],
+            None,
+            vec![],
+            None
+        )),
+        Err(AnalysisError::aggregate_function_calls_cannot_be_nested())
+    );
+}
+
+#[test]
+fn undefined_function() {
+    assert_eq!(
+        analyzer().analyze(select_grouped(
+            vec![function("median", vec![identifier("col1")], false)],
+            None,
+            vec![],
+            None
+        )),
+        Err(AnalysisError::function_does_not_exist("median"))
+    );
+}
+
+#[test]
+fn sum_of_all_columns() {
+    assert!(matches!(
+        analyzer().analyze(select_grouped(
+            vec![function("sum", vec![sql_ast::Expr::Wildcard], false)],
+            None,
+            vec![],
+            None
+        )),
+        Err(AnalysisError::SyntaxError(_))
+    ));
+}
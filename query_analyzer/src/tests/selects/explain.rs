@@ -0,0 +1,110 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data_manipulation_untyped_tree::{DynamicUntypedItem, DynamicUntypedTree};
+
+use super::*;
+
+fn explain(analyze: bool, statement: sql_ast::Statement) -> sql_ast::Statement {
+    sql_ast::Statement::Explain {
+        analyze,
+        verbose: false,
+        statement: Box::new(statement),
+    }
+}
+
+fn analyzer_with_table() -> Analyzer<InMemoryDatabase> {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database
+        .execute(create_table_ops(SCHEMA, TABLE, vec![("col1", SqlType::integer())]))
+        .unwrap();
+    Analyzer::new(database)
+}
+
+fn select_all_query() -> SelectQuery {
+    SelectQuery {
+        full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+        relation: TABLE.to_owned(),
+        joins: vec![],
+        projection_items: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+            name: "col1".to_owned(),
+            index: 0,
+            sql_type: SqlType::integer(),
+        })],
+        filter: None,
+        group_by: vec![],
+        having: None,
+        order_by: vec![],
+        limit: None,
+        offset: None,
+    }
+}
+
+#[test]
+fn explain_select() {
+    let analyzer = analyzer_with_table();
+
+    assert_eq!(
+        analyzer.analyze(explain(false, select(vec![SCHEMA, TABLE]))),
+        Ok(QueryAnalysis::Explain {
+            analyze: false,
+            select: select_all_query()
+        })
+    );
+}
+
+#[test]
+fn explain_analyze_select() {
+    let analyzer = analyzer_with_table();
+
+    assert_eq!(
+        analyzer.analyze(explain(true, select(vec![SCHEMA, TABLE]))),
+        Ok(QueryAnalysis::Explain {
+            analyze: true,
+            select: select_all_query()
+        })
+    );
+}
+
+#[test]
+fn explain_select_from_nonexistent_table() {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    let analyzer = Analyzer::new(database);
+
+    assert_eq!(
+        analyzer.analyze(explain(false, select(vec![SCHEMA, "non_existent_table"]))),
+        Err(AnalysisError::table_does_not_exist(format!(
+            "{}.{}",
+            SCHEMA, "non_existent_table"
+        )))
+    );
+}
+
+#[test]
+fn explain_delete() {
+    let analyzer = analyzer_with_table();
+
+    assert_eq!(
+        analyzer.analyze(explain(
+            false,
+            sql_ast::Statement::Delete {
+                table_name: sql_ast::ObjectName(vec![ident(SCHEMA), ident(TABLE)]),
+                selection: None,
+            }
+        )),
+        Err(AnalysisError::feature_not_supported(Feature::ExplainNonSelect))
+    );
+}
@@ -28,12 +28,20 @@ fn select_all_columns_from_table() {
     assert_eq!(
         analyzer.analyze(select(vec![SCHEMA, TABLE])),
         Ok(QueryAnalysis::Read(SelectQuery {
-            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+            relation: TABLE.to_owned(),
+            joins: vec![],
             projection_items: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Column {
                 name: "col1".to_owned(),
                 index: 0,
                 sql_type: SqlType::integer()
             })],
+            filter: None,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+            offset: None,
         }))
     );
 }
@@ -55,12 +63,20 @@ fn select_specified_column_from_table() {
             )))]
         )),
         Ok(QueryAnalysis::Read(SelectQuery {
-            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+            relation: TABLE.to_owned(),
+            joins: vec![],
             projection_items: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Column {
                 name: "col1".to_owned(),
                 index: 0,
                 sql_type: SqlType::integer()
             })],
+            filter: None,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+            offset: None,
         }))
     );
 }
@@ -100,14 +116,59 @@ fn select_from_table_with_constant() {
             vec![sql_ast::SelectItem::UnnamedExpr(sql_ast::Expr::Value(number(1)))],
         )),
         Ok(QueryAnalysis::Read(SelectQuery {
-            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+            relation: TABLE.to_owned(),
+            joins: vec![],
             projection_items: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Const(
                 UntypedValue::Number(BigDecimal::from(1))
             ))],
+            filter: None,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+            offset: None,
         }))
     );
 }
 
+#[test]
+fn select_constant_without_from() {
+    let analyzer = Analyzer::new(InMemoryDatabase::new());
+
+    assert_eq!(
+        analyzer.analyze(select_without_from(vec![sql_ast::SelectItem::UnnamedExpr(
+            sql_ast::Expr::Value(number(1))
+        )])),
+        Ok(QueryAnalysis::Read(SelectQuery {
+            full_table_name: None,
+            relation: "".to_owned(),
+            joins: vec![],
+            projection_items: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+                UntypedValue::Number(BigDecimal::from(1))
+            ))],
+            filter: None,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        }))
+    );
+}
+
+#[test]
+fn select_column_without_from() {
+    let analyzer = Analyzer::new(InMemoryDatabase::new());
+
+    assert_eq!(
+        analyzer.analyze(select_without_from(vec![sql_ast::SelectItem::UnnamedExpr(
+            sql_ast::Expr::Identifier(ident("col1"))
+        )])),
+        Err(AnalysisError::column_not_found("col1"))
+    );
+}
+
 #[test]
 fn select_parameters_from_a_table() {
     let database = InMemoryDatabase::new();
@@ -123,8 +184,16 @@ fn select_parameters_from_a_table() {
             vec![sql_ast::SelectItem::UnnamedExpr(sql_ast::Expr::Identifier(ident("$1")))],
         )),
         Ok(QueryAnalysis::Read(SelectQuery {
-            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+            full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+            relation: TABLE.to_owned(),
+            joins: vec![],
             projection_items: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Param(0))],
+            filter: None,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+            offset: None,
         }))
     );
 }
@@ -166,7 +235,9 @@ mod multiple_values {
                 sql_ast::Expr::Value(number(1))
             )),
             Ok(QueryAnalysis::Read(SelectQuery {
-                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+                relation: TABLE.to_owned(),
+                joins: vec![],
                 projection_items: vec![DynamicUntypedTree::Operation {
                     left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
                         UntypedValue::String("1".to_owned())
@@ -176,6 +247,12 @@ mod multiple_values {
                         UntypedValue::Number(BigDecimal::from(1))
                     )))
                 }],
+                filter: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
             }))
         );
     }
@@ -196,7 +273,9 @@ mod multiple_values {
                 string("str")
             )),
             Ok(QueryAnalysis::Read(SelectQuery {
-                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+                relation: TABLE.to_owned(),
+                joins: vec![],
                 projection_items: vec![DynamicUntypedTree::Operation {
                     left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
                         UntypedValue::String("str".to_owned())
@@ -206,6 +285,12 @@ mod multiple_values {
                         UntypedValue::String("str".to_owned())
                     )))
                 }],
+                filter: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
             }))
         );
     }
@@ -226,7 +311,9 @@ mod multiple_values {
                 sql_ast::Expr::Value(number(1))
             )),
             Ok(QueryAnalysis::Read(SelectQuery {
-                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+                relation: TABLE.to_owned(),
+                joins: vec![],
                 projection_items: vec![DynamicUntypedTree::Operation {
                     left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
                         UntypedValue::String("1".to_owned())
@@ -236,6 +323,12 @@ mod multiple_values {
                         UntypedValue::Number(BigDecimal::from(1))
                     )))
                 }],
+                filter: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
             }))
         );
     }
@@ -256,7 +349,9 @@ mod multiple_values {
                 sql_ast::Expr::Value(sql_ast::Value::Boolean(true)),
             )),
             Ok(QueryAnalysis::Read(SelectQuery {
-                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+                relation: TABLE.to_owned(),
+                joins: vec![],
                 projection_items: vec![DynamicUntypedTree::Operation {
                     left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(UntypedValue::Bool(
                         Bool(true)
@@ -266,6 +361,12 @@ mod multiple_values {
                         Bool(true)
                     )))),
                 }],
+                filter: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
             }))
         );
     }
@@ -286,7 +387,9 @@ mod multiple_values {
                 sql_ast::Expr::Value(number(1))
             )),
             Ok(QueryAnalysis::Read(SelectQuery {
-                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+                relation: TABLE.to_owned(),
+                joins: vec![],
                 projection_items: vec![DynamicUntypedTree::Operation {
                     left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
                         UntypedValue::Number(BigDecimal::from(1))
@@ -296,6 +399,12 @@ mod multiple_values {
                         UntypedValue::Number(BigDecimal::from(1))
                     )))
                 }],
+                filter: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
             }))
         );
     }
@@ -316,7 +425,9 @@ mod multiple_values {
                 string("str")
             )),
             Ok(QueryAnalysis::Read(SelectQuery {
-                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+                relation: TABLE.to_owned(),
+                joins: vec![],
                 projection_items: vec![DynamicUntypedTree::Operation {
                     left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
                         UntypedValue::String("s".to_owned())
@@ -326,7 +437,220 @@ mod multiple_values {
                         UntypedValue::String("str".to_owned())
                     )))
                 }],
+                filter: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
             }))
         );
     }
 }
+
+#[cfg(test)]
+mod unary_operations_and_predicates {
+    use data_manipulation_operators::UnaryOperation;
+    use data_manipulation_untyped_tree::{DynamicUntypedItem, DynamicUntypedTree, UntypedValue};
+
+    use super::*;
+
+    fn projection_of(expr: sql_ast::Expr) -> AnalysisResult<DynamicUntypedTree> {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        database
+            .execute(create_table_ops(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+            .unwrap();
+        let analyzer = Analyzer::new(database);
+
+        match analyzer.analyze(select_with_columns(
+            vec![SCHEMA, TABLE],
+            vec![sql_ast::SelectItem::UnnamedExpr(expr)],
+        ))? {
+            QueryAnalysis::Read(SelectQuery {
+                mut projection_items, ..
+            }) => Ok(projection_items.remove(0)),
+            analysis => panic!("unexpected analysis {:?}", analysis),
+        }
+    }
+
+    fn col() -> sql_ast::Expr {
+        sql_ast::Expr::Identifier(ident("col"))
+    }
+
+    fn column() -> Box<DynamicUntypedTree> {
+        Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+            name: "col".to_owned(),
+            sql_type: SqlType::small_int(),
+            index: 0,
+        }))
+    }
+
+    fn constant(value: i16) -> Box<DynamicUntypedTree> {
+        Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+            UntypedValue::Number(BigDecimal::from(value)),
+        )))
+    }
+
+    fn operation(
+        left: Box<DynamicUntypedTree>,
+        op: Operation,
+        right: Box<DynamicUntypedTree>,
+    ) -> Box<DynamicUntypedTree> {
+        Box::new(DynamicUntypedTree::Operation { left, op, right })
+    }
+
+    #[test]
+    fn nested_expression() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::BinaryOp {
+                left: Box::new(sql_ast::Expr::Value(number(2))),
+                op: sql_ast::BinaryOperator::Multiply,
+                right: Box::new(sql_ast::Expr::Nested(Box::new(sql_ast::Expr::BinaryOp {
+                    left: Box::new(col()),
+                    op: sql_ast::BinaryOperator::Plus,
+                    right: Box::new(col()),
+                }))),
+            }),
+            Ok(*operation(
+                constant(2),
+                Operation::Arithmetic(Arithmetic::Mul),
+                operation(column(), Operation::Arithmetic(Arithmetic::Add), column())
+            ))
+        );
+    }
+
+    #[test]
+    fn minus_of_column() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::UnaryOp {
+                op: sql_ast::UnaryOperator::Minus,
+                expr: Box::new(col()),
+            }),
+            Ok(DynamicUntypedTree::UnaryOperation {
+                op: UnaryOperation::Minus,
+                item: column()
+            })
+        );
+    }
+
+    #[test]
+    fn not() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::UnaryOp {
+                op: sql_ast::UnaryOperator::Not,
+                expr: Box::new(sql_ast::Expr::BinaryOp {
+                    left: Box::new(col()),
+                    op: sql_ast::BinaryOperator::Eq,
+                    right: Box::new(sql_ast::Expr::Value(number(1))),
+                }),
+            }),
+            Ok(DynamicUntypedTree::UnaryOperation {
+                op: UnaryOperation::Not,
+                item: operation(column(), Operation::Comparison(Comparison::Eq), constant(1))
+            })
+        );
+    }
+
+    #[test]
+    fn is_null() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::IsNull(Box::new(col()))),
+            Ok(DynamicUntypedTree::UnaryOperation {
+                op: UnaryOperation::IsNull,
+                item: column()
+            })
+        );
+    }
+
+    #[test]
+    fn is_not_null() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::IsNotNull(Box::new(col()))),
+            Ok(DynamicUntypedTree::UnaryOperation {
+                op: UnaryOperation::IsNotNull,
+                item: column()
+            })
+        );
+    }
+
+    #[test]
+    fn between() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::Between {
+                expr: Box::new(col()),
+                negated: false,
+                low: Box::new(sql_ast::Expr::Value(number(1))),
+                high: Box::new(sql_ast::Expr::Value(number(3))),
+            }),
+            Ok(*operation(
+                operation(column(), Operation::Comparison(Comparison::GtEq), constant(1)),
+                Operation::Logical(Logical::And),
+                operation(column(), Operation::Comparison(Comparison::LtEq), constant(3))
+            ))
+        );
+    }
+
+    #[test]
+    fn not_between() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::Between {
+                expr: Box::new(col()),
+                negated: true,
+                low: Box::new(sql_ast::Expr::Value(number(1))),
+                high: Box::new(sql_ast::Expr::Value(number(3))),
+            }),
+            Ok(*operation(
+                operation(column(), Operation::Comparison(Comparison::Lt), constant(1)),
+                Operation::Logical(Logical::Or),
+                operation(column(), Operation::Comparison(Comparison::Gt), constant(3))
+            ))
+        );
+    }
+
+    #[test]
+    fn in_list() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::InList {
+                expr: Box::new(col()),
+                list: vec![sql_ast::Expr::Value(number(1)), sql_ast::Expr::Value(number(2))],
+                negated: false,
+            }),
+            Ok(*operation(
+                operation(column(), Operation::Comparison(Comparison::Eq), constant(1)),
+                Operation::Logical(Logical::Or),
+                operation(column(), Operation::Comparison(Comparison::Eq), constant(2))
+            ))
+        );
+    }
+
+    #[test]
+    fn not_in_list() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::InList {
+                expr: Box::new(col()),
+                list: vec![sql_ast::Expr::Value(number(1)), sql_ast::Expr::Value(number(2))],
+                negated: true,
+            }),
+            Ok(*operation(
+                operation(column(), Operation::Comparison(Comparison::NotEq), constant(1)),
+                Operation::Logical(Logical::And),
+                operation(column(), Operation::Comparison(Comparison::NotEq), constant(2))
+            ))
+        );
+    }
+
+    #[test]
+    fn error_of_operand_is_kept() {
+        assert_eq!(
+            projection_of(sql_ast::Expr::BinaryOp {
+                left: Box::new(sql_ast::Expr::Value(number(1))),
+                op: sql_ast::BinaryOperator::Plus,
+                right: Box::new(sql_ast::Expr::Nested(Box::new(sql_ast::Expr::Identifier(ident(
+                    "unknown"
+                ))))),
+            }),
+            Err(AnalysisError::column_not_found("unknown"))
+        );
+    }
+}
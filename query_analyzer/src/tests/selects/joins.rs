@@ -0,0 +1,329 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data_manipulation_operators::JoinKind;
+use data_manipulation_untyped_queries::Join;
+use data_manipulation_untyped_tree::{DynamicUntypedItem, DynamicUntypedTree};
+
+use super::*;
+
+const OTHER_TABLE: &str = "other_table";
+
+fn analyzer() -> Analyzer<InMemoryDatabase> {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database
+        .execute(create_table_ops(
+            SCHEMA,
+            TABLE,
+            vec![("col1", SqlType::integer()), ("col2", SqlType::integer())],
+        ))
+        .unwrap();
+    database
+        .execute(create_table_ops(
+            SCHEMA,
+            OTHER_TABLE,
+            vec![("col1", SqlType::integer()), ("col3", SqlType::integer())],
+        ))
+        .unwrap();
+    Analyzer::new(database)
+}
+
+fn table(name: &'static str, alias: Option<&'static str>) -> sql_ast::TableFactor {
+    sql_ast::TableFactor::Table {
+        name: sql_ast::ObjectName(vec![ident(SCHEMA), ident(name)]),
+        alias: alias.map(|alias| sql_ast::TableAlias {
+            name: ident(alias),
+            columns: vec![],
+        }),
+        args: vec![],
+        with_hints: vec![],
+    }
+}
+
+fn select_from(projection: Vec<sql_ast::SelectItem>, from: Vec<sql_ast::TableWithJoins>) -> sql_ast::Statement {
+    match select_with_columns(vec![SCHEMA, TABLE], projection) {
+        sql_ast::Statement::Query(mut query) => {
+            if let sql_ast::SetExpr::Select(select) = &mut query.body {
+                select.from = from;
+            }
+            sql_ast::Statement::Query(query)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn select_joined(
+    projection: Vec<sql_ast::SelectItem>,
+    relation: sql_ast::TableFactor,
+    joins: Vec<(sql_ast::TableFactor, sql_ast::JoinOperator)>,
+) -> sql_ast::Statement {
+    select_from(
+        projection,
+        vec![sql_ast::TableWithJoins {
+            relation,
+            joins: joins
+                .into_iter()
+                .map(|(relation, join_operator)| sql_ast::Join {
+                    relation,
+                    join_operator,
+                })
+                .collect(),
+        }],
+    )
+}
+
+fn qualified(relation: &str, column: &str) -> sql_ast::Expr {
+    sql_ast::Expr::CompoundIdentifier(vec![ident(relation), ident(column)])
+}
+
+fn equal(left: sql_ast::Expr, right: sql_ast::Expr) -> sql_ast::Expr {
+    sql_ast::Expr::BinaryOp {
+        left: Box::new(left),
+        op: sql_ast::BinaryOperator::Eq,
+        right: Box::new(right),
+    }
+}
+
+fn on(condition: sql_ast::Expr) -> sql_ast::JoinConstraint {
+    sql_ast::JoinConstraint::On(condition)
+}
+
+fn column(name: &str, index: usize) -> DynamicUntypedTree {
+    DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+        name: name.to_owned(),
+        index,
+        sql_type: SqlType::integer(),
+    })
+}
+
+fn equal_columns(left: DynamicUntypedTree, right: DynamicUntypedTree) -> DynamicUntypedTree {
+    DynamicUntypedTree::Operation {
+        left: Box::new(left),
+        op: Operation::Comparison(Comparison::Eq),
+        right: Box::new(right),
+    }
+}
+
+fn selected(
+    relation: &str,
+    joins: Vec<Join>,
+    projection_items: Vec<DynamicUntypedTree>,
+) -> AnalysisResult<QueryAnalysis> {
+    Ok(QueryAnalysis::Read(SelectQuery {
+        full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+        relation: relation.to_owned(),
+        joins,
+        projection_items,
+        filter: None,
+        group_by: vec![],
+        having: None,
+        order_by: vec![],
+        limit: None,
+        offset: None,
+    }))
+}
+
+fn joined(kind: JoinKind, relation: &str, condition: Option<DynamicUntypedTree>) -> Join {
+    Join {
+        kind,
+        full_table_name: FullTableName::from((&SCHEMA, &OTHER_TABLE)),
+        relation: relation.to_owned(),
+        condition,
+    }
+}
+
+#[test]
+fn inner_join_with_qualified_columns() {
+    assert_eq!(
+        analyzer().analyze(select_joined(
+            vec![
+                sql_ast::SelectItem::UnnamedExpr(qualified(TABLE, "col2")),
+                sql_ast::SelectItem::UnnamedExpr(qualified(OTHER_TABLE, "col3")),
+            ],
+            table(TABLE, None),
+            vec![(
+                table(OTHER_TABLE, None),
+                sql_ast::JoinOperator::Inner(on(equal(qualified(TABLE, "col1"), qualified(OTHER_TABLE, "col1"))))
+            )]
+        )),
+        selected(
+            TABLE,
+            vec![joined(
+                JoinKind::Inner,
+                OTHER_TABLE,
+                Some(equal_columns(
+                    column("table_name.col1", 0),
+                    column("other_table.col1", 2)
+                ))
+            )],
+            vec![column("table_name.col2", 1), column("other_table.col3", 3)]
+        )
+    );
+}
+
+#[test]
+fn outer_joins_of_aliased_tables() {
+    for (join_operator, kind) in &[
+        (sql_ast::JoinOperator::LeftOuter as fn(_) -> _, JoinKind::LeftOuter),
+        (sql_ast::JoinOperator::RightOuter, JoinKind::RightOuter),
+        (sql_ast::JoinOperator::FullOuter, JoinKind::FullOuter),
+    ] {
+        assert_eq!(
+            analyzer().analyze(select_joined(
+                vec![sql_ast::SelectItem::UnnamedExpr(sql_ast::Expr::Identifier(ident(
+                    "col3"
+                )))],
+                table(TABLE, Some("t")),
+                vec![(
+                    table(OTHER_TABLE, Some("o")),
+                    join_operator(on(equal(qualified("t", "col1"), qualified("o", "col1"))))
+                )]
+            )),
+            selected(
+                "t",
+                vec![joined(
+                    *kind,
+                    "o",
+                    Some(equal_columns(column("t.col1", 0), column("o.col1", 2)))
+                )],
+                vec![column("o.col3", 3)]
+            )
+        );
+    }
+}
+
+#[test]
+fn tables_listed_through_comma_are_cross_joined() {
+    assert_eq!(
+        analyzer().analyze(select_from(
+            vec![sql_ast::SelectItem::Wildcard],
+            vec![
+                sql_ast::TableWithJoins {
+                    relation: table(TABLE, None),
+                    joins: vec![],
+                },
+                sql_ast::TableWithJoins {
+                    relation: table(OTHER_TABLE, None),
+                    joins: vec![],
+                }
+            ]
+        )),
+        selected(
+            TABLE,
+            vec![joined(JoinKind::Cross, OTHER_TABLE, None)],
+            vec![
+                column("table_name.col1", 0),
+                column("table_name.col2", 1),
+                column("other_table.col1", 2),
+                column("other_table.col3", 3)
+            ]
+        )
+    );
+}
+
+#[test]
+fn qualified_wildcard() {
+    assert_eq!(
+        analyzer().analyze(select_joined(
+            vec![sql_ast::SelectItem::QualifiedWildcard(sql_ast::ObjectName(vec![
+                ident("o")
+            ]))],
+            table(TABLE, None),
+            vec![(table(OTHER_TABLE, Some("o")), sql_ast::JoinOperator::CrossJoin)]
+        )),
+        selected(
+            TABLE,
+            vec![joined(JoinKind::Cross, "o", None)],
+            vec![column("o.col1", 2), column("o.col3", 3)]
+        )
+    );
+}
+
+#[test]
+fn qualified_column_of_single_table() {
+    assert_eq!(
+        analyzer().analyze(select_joined(
+            vec![sql_ast::SelectItem::UnnamedExpr(qualified("t", "col2"))],
+            table(TABLE, Some("t")),
+            vec![]
+        )),
+        selected("t", vec![], vec![column("col2", 1)])
+    );
+}
+
+#[test]
+fn ambiguous_column() {
+    assert_eq!(
+        analyzer().analyze(select_joined(
+            vec![sql_ast::SelectItem::UnnamedExpr(sql_ast::Expr::Identifier(ident(
+                "col1"
+            )))],
+            table(TABLE, None),
+            vec![(table(OTHER_TABLE, None), sql_ast::JoinOperator::CrossJoin)]
+        )),
+        Err(AnalysisError::ambiguous_column("col1"))
+    );
+}
+
+#[test]
+fn column_of_table_that_is_not_listed_in_from_clause() {
+    assert_eq!(
+        analyzer().analyze(select_joined(
+            vec![sql_ast::SelectItem::UnnamedExpr(qualified("o", "col1"))],
+            table(TABLE, Some("t")),
+            vec![]
+        )),
+        Err(AnalysisError::missing_from_clause_entry("o"))
+    );
+}
+
+#[test]
+fn aliased_table_referenced_by_its_name() {
+    assert_eq!(
+        analyzer().analyze(select_joined(
+            vec![sql_ast::SelectItem::UnnamedExpr(qualified(TABLE, "col1"))],
+            table(TABLE, Some("t")),
+            vec![]
+        )),
+        Err(AnalysisError::missing_from_clause_entry(TABLE))
+    );
+}
+
+#[test]
+fn table_name_specified_more_than_once() {
+    assert_eq!(
+        analyzer().analyze(select_joined(
+            vec![sql_ast::SelectItem::Wildcard],
+            table(TABLE, None),
+            vec![(table(TABLE, None), sql_ast::JoinOperator::CrossJoin)]
+        )),
+        Err(AnalysisError::duplicate_alias(TABLE))
+    );
+}
+
+#[test]
+fn join_with_using_clause() {
+    assert_eq!(
+        analyzer().analyze(select_joined(
+            vec![sql_ast::SelectItem::Wildcard],
+            table(TABLE, None),
+            vec![(
+                table(OTHER_TABLE, None),
+                sql_ast::JoinOperator::Inner(sql_ast::JoinConstraint::Using(vec![ident("col1")]))
+            )]
+        )),
+        Err(AnalysisError::feature_not_supported(Feature::JoinUsing))
+    );
+}
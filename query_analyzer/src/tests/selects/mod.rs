@@ -14,10 +14,18 @@
 
 use super::*;
 
+#[cfg(test)]
+mod aggregates;
+#[cfg(test)]
+mod explain;
 #[cfg(test)]
 mod expressions;
 #[cfg(test)]
 mod general_cases;
+#[cfg(test)]
+mod joins;
+#[cfg(test)]
+mod order_by;
 
 fn select_with_columns(name: Vec<&'static str>, projection: Vec<sql_ast::SelectItem>) -> sql_ast::Statement {
     sql_ast::Statement::Query(Box::new(sql_ast::Query {
@@ -46,6 +54,25 @@ fn select_with_columns(name: Vec<&'static str>, projection: Vec<sql_ast::SelectI
     }))
 }
 
+fn select_without_from(projection: Vec<sql_ast::SelectItem>) -> sql_ast::Statement {
+    sql_ast::Statement::Query(Box::new(sql_ast::Query {
+        with: None,
+        body: sql_ast::SetExpr::Select(Box::new(sql_ast::Select {
+            distinct: false,
+            top: None,
+            projection,
+            from: vec![],
+            selection: None,
+            group_by: vec![],
+            having: None,
+        })),
+        order_by: vec![],
+        limit: None,
+        offset: None,
+        fetch: None,
+    }))
+}
+
 fn select(name: Vec<&'static str>) -> sql_ast::Statement {
     select_with_columns(name, vec![sql_ast::SelectItem::Wildcard])
 }
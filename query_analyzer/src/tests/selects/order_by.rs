@@ -0,0 +1,258 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data_manipulation_untyped_queries::OrderBy;
+use data_manipulation_untyped_tree::{DynamicUntypedItem, DynamicUntypedTree};
+
+use super::*;
+
+fn analyzer() -> Analyzer<InMemoryDatabase> {
+    let database = InMemoryDatabase::new();
+    database.execute(create_schema_ops(SCHEMA)).unwrap();
+    database
+        .execute(create_table_ops(
+            SCHEMA,
+            TABLE,
+            vec![("col1", SqlType::integer()), ("col2", SqlType::integer())],
+        ))
+        .unwrap();
+    Analyzer::new(database)
+}
+
+fn select_ordered(
+    order_by: Vec<sql_ast::OrderByExpr>,
+    limit: Option<sql_ast::Expr>,
+    offset: Option<sql_ast::Expr>,
+) -> sql_ast::Statement {
+    match select(vec![SCHEMA, TABLE]) {
+        sql_ast::Statement::Query(mut query) => {
+            query.order_by = order_by;
+            query.limit = limit;
+            query.offset = offset.map(|value| sql_ast::Offset {
+                value,
+                rows: sql_ast::OffsetRows::None,
+            });
+            sql_ast::Statement::Query(query)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn order_by(expr: sql_ast::Expr, asc: Option<bool>, nulls_first: Option<bool>) -> sql_ast::OrderByExpr {
+    sql_ast::OrderByExpr { expr, asc, nulls_first }
+}
+
+fn column(name: &str, index: usize) -> DynamicUntypedTree {
+    DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+        name: name.to_owned(),
+        index,
+        sql_type: SqlType::integer(),
+    })
+}
+
+fn selected(order_by: Vec<OrderBy>, limit: Option<usize>, offset: Option<usize>) -> AnalysisResult<QueryAnalysis> {
+    Ok(QueryAnalysis::Read(SelectQuery {
+        full_table_name: Some(FullTableName::from((&SCHEMA, &TABLE))),
+        relation: TABLE.to_owned(),
+        joins: vec![],
+        projection_items: vec![column("col1", 0), column("col2", 1)],
+        filter: None,
+        group_by: vec![],
+        having: None,
+        order_by,
+        limit,
+        offset,
+    }))
+}
+
+#[test]
+fn ascending_order_places_nulls_last_by_default() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![order_by(sql_ast::Expr::Identifier(ident("col2")), None, None)],
+            None,
+            None
+        )),
+        selected(
+            vec![OrderBy {
+                expr: column("col2", 1),
+                ascending: true,
+                nulls_first: false
+            }],
+            None,
+            None
+        )
+    );
+}
+
+#[test]
+fn descending_order_places_nulls_first_by_default() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![order_by(sql_ast::Expr::Identifier(ident("col2")), Some(false), None)],
+            None,
+            None
+        )),
+        selected(
+            vec![OrderBy {
+                expr: column("col2", 1),
+                ascending: false,
+                nulls_first: true
+            }],
+            None,
+            None
+        )
+    );
+}
+
+#[test]
+fn explicit_nulls_placement() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![order_by(
+                sql_ast::Expr::Identifier(ident("col1")),
+                Some(true),
+                Some(true)
+            )],
+            None,
+            None
+        )),
+        selected(
+            vec![OrderBy {
+                expr: column("col1", 0),
+                ascending: true,
+                nulls_first: true
+            }],
+            None,
+            None
+        )
+    );
+}
+
+#[test]
+fn order_by_position_in_select_list() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![order_by(sql_ast::Expr::Value(number(2)), None, None)],
+            None,
+            None
+        )),
+        selected(
+            vec![OrderBy {
+                expr: column("col2", 1),
+                ascending: true,
+                nulls_first: false
+            }],
+            None,
+            None
+        )
+    );
+}
+
+#[test]
+fn order_by_position_that_is_not_in_select_list() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![order_by(sql_ast::Expr::Value(number(3)), None, None)],
+            None,
+            None
+        )),
+        Err(AnalysisError::order_by_position_is_not_in_select_list(3))
+    );
+}
+
+#[test]
+fn order_by_column_that_is_not_in_table() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![order_by(sql_ast::Expr::Identifier(ident("col3")), None, None)],
+            None,
+            None
+        )),
+        Err(AnalysisError::column_not_found("col3"))
+    );
+}
+
+#[test]
+fn limit_and_offset() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![],
+            Some(sql_ast::Expr::Value(number(10))),
+            Some(sql_ast::Expr::Value(number(5)))
+        )),
+        selected(vec![], Some(10), Some(5))
+    );
+}
+
+#[test]
+fn null_limit_means_no_limit() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(vec![], Some(null()), None)),
+        selected(vec![], None, None)
+    );
+}
+
+#[test]
+fn parameters_in_limit_and_offset() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![],
+            Some(sql_ast::Expr::Identifier(ident("$1"))),
+            Some(sql_ast::Expr::Identifier(ident("$2")))
+        )),
+        selected(vec![], None, None)
+    );
+}
+
+#[test]
+fn negative_limit() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![],
+            Some(sql_ast::Expr::UnaryOp {
+                op: sql_ast::UnaryOperator::Minus,
+                expr: Box::new(sql_ast::Expr::Value(number(1)))
+            }),
+            None
+        )),
+        Err(AnalysisError::invalid_row_count_in_limit_clause())
+    );
+}
+
+#[test]
+fn negative_offset() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(
+            vec![],
+            None,
+            Some(sql_ast::Expr::UnaryOp {
+                op: sql_ast::UnaryOperator::Minus,
+                expr: Box::new(sql_ast::Expr::Value(number(1)))
+            })
+        )),
+        Err(AnalysisError::invalid_row_count_in_result_offset_clause())
+    );
+}
+
+#[test]
+fn limit_that_is_not_a_number() {
+    assert_eq!(
+        analyzer().analyze(select_ordered(vec![], Some(string("abc")), None)),
+        Err(AnalysisError::invalid_input_syntax_for_type(
+            SqlType::big_int(),
+            "'abc'"
+        ))
+    );
+}
@@ -35,7 +35,9 @@ fn update_number() {
             column_names: vec!["col".to_owned()],
             assignments: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Const(
                 UntypedValue::Number(BigDecimal::from(1))
-            ))]
+            ))],
+            filter: None,
+            checks: vec![],
         })))
     );
 }
@@ -56,7 +58,9 @@ fn update_string() {
             column_names: vec!["col".to_owned()],
             assignments: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Const(
                 UntypedValue::String("str".to_owned())
-            ))]
+            ))],
+            filter: None,
+            checks: vec![],
         })))
     );
 }
@@ -78,6 +82,8 @@ fn update_boolean() {
             assignments: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Const(UntypedValue::Bool(
                 Bool(true)
             )))],
+            filter: None,
+            checks: vec![],
         })))
     );
 }
@@ -97,6 +103,8 @@ fn update_null() {
             full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
             column_names: vec!["col".to_owned()],
             assignments: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Const(UntypedValue::Null))],
+            filter: None,
+            checks: vec![],
         })))
     );
 }
@@ -127,6 +135,8 @@ fn update_with_column_value() {
                 sql_type: SqlType::small_int(),
                 index: 1
             })],
+            filter: None,
+            checks: vec![],
         })))
     );
 }
@@ -171,7 +181,9 @@ fn update_table_with_parameters() {
         Ok(QueryAnalysis::Write(UntypedWrite::Update(UpdateQuery {
             full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
             column_names: vec!["col_2".to_owned()],
-            assignments: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Param(0))]
+            assignments: vec![DynamicUntypedTree::Item(DynamicUntypedItem::Param(0))],
+            filter: None,
+            checks: vec![],
         })))
     );
 }
@@ -227,6 +239,8 @@ mod multiple_values {
                         UntypedValue::Number(BigDecimal::from(1))
                     )))
                 }],
+                filter: None,
+                checks: vec![],
             })))
         );
     }
@@ -258,6 +272,8 @@ mod multiple_values {
                         UntypedValue::String("str".to_owned())
                     )))
                 }],
+                filter: None,
+                checks: vec![],
             })))
         );
     }
@@ -289,6 +305,8 @@ mod multiple_values {
                         UntypedValue::Number(BigDecimal::from(1))
                     )))
                 }],
+                filter: None,
+                checks: vec![],
             })))
         );
     }
@@ -320,6 +338,8 @@ mod multiple_values {
                         Bool(true)
                     )))),
                 }],
+                filter: None,
+                checks: vec![],
             })))
         );
     }
@@ -351,6 +371,8 @@ mod multiple_values {
                         UntypedValue::Number(BigDecimal::from(1))
                     )))
                 }],
+                filter: None,
+                checks: vec![],
             })))
         );
     }
@@ -382,6 +404,49 @@ mod multiple_values {
                         UntypedValue::String("str".to_owned())
                     )))
                 }],
+                filter: None,
+                checks: vec![],
+            })))
+        );
+    }
+
+    #[test]
+    fn nested_expression_with_column() {
+        let database = InMemoryDatabase::new();
+        database.execute(create_schema_ops(SCHEMA)).unwrap();
+        database
+            .execute(create_table_ops(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+            .unwrap();
+        let analyzer = Analyzer::new(database);
+
+        assert_eq!(
+            analyzer.analyze(update_statement(
+                vec![SCHEMA, TABLE],
+                vec![(
+                    "col",
+                    sql_ast::Expr::Nested(Box::new(sql_ast::Expr::BinaryOp {
+                        left: Box::new(sql_ast::Expr::Identifier(ident("col"))),
+                        op: sql_ast::BinaryOperator::Plus,
+                        right: Box::new(sql_ast::Expr::Value(number(1))),
+                    }))
+                )]
+            )),
+            Ok(QueryAnalysis::Write(UntypedWrite::Update(UpdateQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_names: vec!["col".to_owned()],
+                assignments: vec![DynamicUntypedTree::Operation {
+                    left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+                        name: "col".to_owned(),
+                        sql_type: SqlType::small_int(),
+                        index: 0
+                    })),
+                    op: Operation::Arithmetic(Arithmetic::Add),
+                    right: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+                        UntypedValue::Number(BigDecimal::from(1))
+                    )))
+                }],
+                filter: None,
+                checks: vec![],
             })))
         );
     }
@@ -417,7 +482,7 @@ mod not_supported_values {
         let database = InMemoryDatabase::new();
         database.execute(create_schema_ops(SCHEMA)).unwrap();
         database
-            .execute(create_table_ops(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+            .execute(create_table_ops(SCHEMA, TABLE, vec![("col", SqlType::bytea())]))
             .unwrap();
         let analyzer = Analyzer::new(database);
 
@@ -426,10 +491,21 @@ mod not_supported_values {
                 vec![SCHEMA, TABLE],
                 vec![(
                     "col",
-                    sql_ast::Expr::Value(sql_ast::Value::HexStringLiteral("str".to_owned()))
+                    sql_ast::Expr::Value(sql_ast::Value::HexStringLiteral("DEADBEEF".to_owned()))
                 )]
             )),
-            Err(AnalysisError::FeatureNotSupported(Feature::HexStringLiteral))
+            Ok(QueryAnalysis::Write(UntypedWrite::Update(UpdateQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_names: vec!["col".to_owned()],
+                assignments: vec![DynamicUntypedTree::Cast {
+                    tree: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+                        UntypedValue::String("\\xDEADBEEF".to_owned())
+                    ))),
+                    target: SqlType::bytea(),
+                }],
+                filter: None,
+                checks: vec![],
+            })))
         );
     }
 
@@ -438,7 +514,7 @@ mod not_supported_values {
         let database = InMemoryDatabase::new();
         database.execute(create_schema_ops(SCHEMA)).unwrap();
         database
-            .execute(create_table_ops(SCHEMA, TABLE, vec![("col", SqlType::small_int())]))
+            .execute(create_table_ops(SCHEMA, TABLE, vec![("col", SqlType::interval())]))
             .unwrap();
         let analyzer = Analyzer::new(database);
 
@@ -448,7 +524,7 @@ mod not_supported_values {
                 vec![(
                     "col",
                     sql_ast::Expr::Value(sql_ast::Value::Interval {
-                        value: "value".to_owned(),
+                        value: "1 day 02:00:00".to_owned(),
                         leading_field: None,
                         leading_precision: None,
                         last_field: None,
@@ -456,7 +532,18 @@ mod not_supported_values {
                     })
                 )]
             )),
-            Err(AnalysisError::FeatureNotSupported(Feature::TimeInterval))
+            Ok(QueryAnalysis::Write(UntypedWrite::Update(UpdateQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                column_names: vec!["col".to_owned()],
+                assignments: vec![DynamicUntypedTree::Cast {
+                    tree: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+                        UntypedValue::String("1 day 02:00:00".to_owned())
+                    ))),
+                    target: SqlType::interval(),
+                }],
+                filter: None,
+                checks: vec![],
+            })))
         );
     }
 }
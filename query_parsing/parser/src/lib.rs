@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use sqlparser::{
+    ast::{DataType, Expr, Ident, ObjectName, Statement},
+    tokenizer::{Token, Tokenizer},
+};
 pub use sqlparser::{dialect::Dialect, parser::*};
+use std::iter;
 
 #[derive(Debug, Default)]
 pub struct PreparedStatementDialect;
@@ -26,3 +31,422 @@ impl Dialect for PreparedStatementDialect {
         ('a'..='z').contains(&ch) || ('A'..='Z').contains(&ch) || ('0'..='9').contains(&ch) || ch == '$' || ch == '_'
     }
 }
+
+/// `ALTER TABLE ... ALTER [COLUMN] ... [SET DATA] TYPE ...` statement,
+/// the parser doesn't have a node of syntax tree for it
+#[derive(Debug, PartialEq, Clone)]
+pub struct AlterColumnType {
+    pub table_name: ObjectName,
+    pub column_name: Ident,
+    pub data_type: DataType,
+}
+
+/// parses `sql` if it changes type of a column, `None` is returned for other statements
+pub fn parse_alter_column_type(sql: &str) -> Option<Result<AlterColumnType, ParserError>> {
+    let tokens = match tokenize(sql) {
+        Ok(tokens) => tokens,
+        Err(error) => return Some(Err(error)),
+    };
+    let mut parser = Parser::new(tokens);
+    if !consume_word(&mut parser, "ALTER") || !consume_word(&mut parser, "TABLE") {
+        return None;
+    }
+    let table_name = match parser.parse_object_name() {
+        Ok(table_name) => table_name,
+        Err(_) => return None,
+    };
+    if !consume_word(&mut parser, "ALTER") {
+        return None;
+    }
+    Some(alter_column_type(&mut parser, table_name))
+}
+
+fn alter_column_type(parser: &mut Parser, table_name: ObjectName) -> Result<AlterColumnType, ParserError> {
+    consume_word(parser, "COLUMN");
+    let column_name = parser.parse_identifier()?;
+    if consume_word(parser, "SET") && !consume_word(parser, "DATA") {
+        return parser.expected("DATA", parser.peek_token());
+    }
+    if !consume_word(parser, "TYPE") {
+        return parser.expected("TYPE", parser.peek_token());
+    }
+    let data_type = parser.parse_data_type()?;
+    while parser.consume_token(&Token::SemiColon) {}
+    if parser.peek_token() != Token::EOF {
+        return parser.expected("end of statement", parser.peek_token());
+    }
+    Ok(AlterColumnType {
+        table_name,
+        column_name,
+        data_type,
+    })
+}
+
+fn consume_word(parser: &mut Parser, expected: &str) -> bool {
+    match parser.peek_token() {
+        Token::Word(word) if word.quote_style.is_none() && word.value.eq_ignore_ascii_case(expected) => {
+            parser.next_token();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// `EXPLAIN` statement with options in parentheses, the parser knows only
+/// `ANALYZE` and `VERBOSE` keywords that follow `EXPLAIN`
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExplainWithOptions {
+    pub analyze: bool,
+    /// value of `FORMAT` option in lower case
+    pub format: Option<String>,
+    pub statement: Statement,
+}
+
+/// options of `EXPLAIN` that are accepted but don't change its output
+const IGNORED_EXPLAIN_OPTIONS: &[&str] = &["BUFFERS", "COSTS", "SETTINGS", "SUMMARY", "TIMING", "VERBOSE", "WAL"];
+
+/// parses `sql` if it is `EXPLAIN` with options in parentheses, `None` is returned for other statements
+pub fn parse_explain_with_options(sql: &str) -> Option<Result<ExplainWithOptions, ParserError>> {
+    let tokens = match tokenize(sql) {
+        Ok(tokens) => tokens,
+        Err(error) => return Some(Err(error)),
+    };
+    let mut parser = Parser::new(tokens);
+    if !consume_word(&mut parser, "EXPLAIN") || !parser.consume_token(&Token::LParen) {
+        return None;
+    }
+    Some(explain_with_options(&mut parser))
+}
+
+fn explain_with_options(parser: &mut Parser) -> Result<ExplainWithOptions, ParserError> {
+    let mut analyze = false;
+    let mut format = None;
+    loop {
+        let option = match parser.next_token() {
+            Token::Word(word) => word.value.to_uppercase(),
+            token => return parser.expected("EXPLAIN option", token),
+        };
+        let value = match parser.peek_token() {
+            Token::Comma | Token::RParen => None,
+            _ => Some(parser.next_token()),
+        };
+        match option.as_str() {
+            "FORMAT" => match value {
+                Some(Token::Word(word)) => format = Some(word.value.to_lowercase()),
+                _ => {
+                    return Err(ParserError::ParserError(
+                        "EXPLAIN option \"format\" requires a value".to_owned(),
+                    ))
+                }
+            },
+            "ANALYZE" => analyze = explain_option_enabled(&option, value)?,
+            _ if IGNORED_EXPLAIN_OPTIONS.contains(&option.as_str()) => {
+                explain_option_enabled(&option, value)?;
+            }
+            _ => {
+                return Err(ParserError::ParserError(format!(
+                    "unrecognized EXPLAIN option \"{}\"",
+                    option.to_lowercase()
+                )))
+            }
+        }
+        if parser.consume_token(&Token::RParen) {
+            break;
+        }
+        if !parser.consume_token(&Token::Comma) {
+            return parser.expected(", or )", parser.peek_token());
+        }
+    }
+    let statement = parser.parse_statement()?;
+    while parser.consume_token(&Token::SemiColon) {}
+    if parser.peek_token() != Token::EOF {
+        return parser.expected("end of statement", parser.peek_token());
+    }
+    Ok(ExplainWithOptions {
+        analyze,
+        format,
+        statement,
+    })
+}
+
+/// boolean options are enabled when their value is omitted
+fn explain_option_enabled(option: &str, value: Option<Token>) -> Result<bool, ParserError> {
+    let value = match value {
+        None => return Ok(true),
+        Some(Token::Word(word)) => word.value.to_uppercase(),
+        Some(Token::Number(number)) => number,
+        Some(_) => String::new(),
+    };
+    match value.as_str() {
+        "TRUE" | "ON" | "1" => Ok(true),
+        "FALSE" | "OFF" | "0" => Ok(false),
+        _ => Err(ParserError::ParserError(format!(
+            "{} requires a Boolean value",
+            option.to_lowercase()
+        ))),
+    }
+}
+
+/// parses statements of a simple query
+pub fn parse_sql(sql: &str) -> Result<Vec<Statement>, ParserError> {
+    let mut parser = Parser::new(tokenize(sql)?);
+    let mut statements = vec![];
+    let mut expecting_statement_delimiter = false;
+    loop {
+        while parser.consume_token(&Token::SemiColon) {
+            expecting_statement_delimiter = false;
+        }
+        if parser.peek_token() == Token::EOF {
+            break;
+        }
+        if expecting_statement_delimiter {
+            return parser.expected("end of statement", parser.peek_token());
+        }
+        statements.push(parser.parse_statement()?);
+        expecting_statement_delimiter = true;
+    }
+    Ok(statements)
+}
+
+/// parses a single statement of the extended query protocol.
+/// `LIMIT` and `OFFSET` clauses accept only numbers, so their parameters
+/// are parsed as zero row counts and put back into the statement afterwards
+pub fn parse_prepared_statement(sql: &str) -> Result<Statement, ParserError> {
+    let mut tokens = tokenize(sql)?;
+    let mut limit = None;
+    let mut offset = None;
+    let mut previous_keyword = None;
+    for token in tokens.iter_mut() {
+        let keyword = match token {
+            Token::Whitespace(_) => continue,
+            Token::Word(word) if word.quote_style.is_none() && word.value.starts_with('$') => {
+                let row_count = match previous_keyword.as_deref() {
+                    Some("LIMIT") => Some(&mut limit),
+                    Some("OFFSET") => Some(&mut offset),
+                    _ => None,
+                };
+                if let Some(row_count) = row_count {
+                    *row_count = Some(Ident::new(word.value.as_str()));
+                    *token = Token::Number("0".to_owned());
+                }
+                None
+            }
+            Token::Word(word) if word.quote_style.is_none() => Some(word.value.to_uppercase()),
+            _ => None,
+        };
+        previous_keyword = keyword;
+    }
+
+    let mut parser = Parser::new(tokens);
+    let mut statement = parser.parse_statement()?;
+    while parser.consume_token(&Token::SemiColon) {}
+    if parser.peek_token() != Token::EOF {
+        return parser.expected("end of statement", parser.peek_token());
+    }
+    if let Statement::Query(query) = &mut statement {
+        if let Some(param) = limit {
+            query.limit = Some(Expr::Identifier(param));
+        }
+        if let (Some(param), Some(offset)) = (offset, query.offset.as_mut()) {
+            offset.value = Expr::Identifier(param);
+        }
+    }
+    Ok(statement)
+}
+
+/// `TIME` and `TIMESTAMP` data types are parsed without time zone even when
+/// `WITH TIME ZONE` is spelled out, so the spelling is replaced with
+/// `timetz` and `timestamptz` type names before parsing
+fn tokenize(sql: &str) -> Result<Vec<Token>, ParserError> {
+    let mut tokens = json_operators(Tokenizer::new(&PreparedStatementDialect, sql).tokenize()?)?;
+    let words = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !matches!(token, Token::Whitespace(_)))
+        .map(|(index, token)| match token {
+            Token::Word(word) if word.quote_style.is_none() => (index, Some(word.value.to_uppercase())),
+            _ => (index, None),
+        })
+        .collect::<Vec<_>>();
+    // replaced from the end so indexes of preceding tokens stay the same
+    for window in words.windows(4).rev() {
+        if let [(start, Some(data_type)), (_, Some(with)), (_, Some(time)), (end, Some(zone))] = window {
+            let type_name = match data_type.as_str() {
+                "TIME" => "timetz",
+                "TIMESTAMP" => "timestamptz",
+                _ => continue,
+            };
+            if with == "WITH" && time == "TIME" && zone == "ZONE" {
+                tokens.splice(*start..=*end, iter::once(Token::make_word(type_name, None)));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// words that can't be names of functions when they precede a parenthesized expression
+const KEYWORDS: &[&str] = &[
+    "AND", "AS", "BETWEEN", "BY", "CASE", "ELSE", "EXISTS", "HAVING", "IN", "IS", "LIKE", "NOT", "ON", "OR", "SELECT",
+    "SET", "THEN", "VALUES", "WHEN", "WHERE",
+];
+
+/// the parser doesn't know `->`, `->>`, `#>`, `@>` and `?` operators, so they are
+/// replaced with calls of functions that implement them, operands are single values,
+/// columns, function calls or parenthesized expressions optionally followed by casts
+fn json_operators(tokens: Vec<Token>) -> Result<Vec<Token>, ParserError> {
+    if !tokens.iter().any(|token| json_operator(token).is_some()) {
+        return Ok(tokens);
+    }
+    let mut tokens = tokens
+        .into_iter()
+        .filter(|token| !matches!(token, Token::Whitespace(_)))
+        .collect::<Vec<Token>>();
+    while let Some((position, (operator, function))) = tokens
+        .iter()
+        .enumerate()
+        .find_map(|(position, token)| json_operator(token).map(|json_operator| (position, json_operator)))
+    {
+        let start = match operand_start(&tokens, position) {
+            Some(start) => start,
+            None => {
+                return Err(ParserError::ParserError(format!(
+                    "Expected an expression before {}",
+                    operator
+                )))
+            }
+        };
+        let end = match operand_end(&tokens, position + 1) {
+            Some(end) => end,
+            None => {
+                return Err(ParserError::ParserError(format!(
+                    "Expected an expression after {}",
+                    operator
+                )))
+            }
+        };
+        let call = iter::once(Token::make_word(function, None))
+            .chain(iter::once(Token::LParen))
+            .chain(tokens[start..position].iter().cloned())
+            .chain(iter::once(Token::Comma))
+            .chain(tokens[position + 1..end].iter().cloned())
+            .chain(iter::once(Token::RParen))
+            .collect::<Vec<Token>>();
+        tokens.splice(start..end, call);
+    }
+    Ok(tokens)
+}
+
+/// symbol of a JSON operator and name of the function that implements it
+fn json_operator(token: &Token) -> Option<(&'static str, &'static str)> {
+    match token {
+        Token::Arrow => Some(("->", "jsonb_object_field")),
+        Token::LongArrow => Some(("->>", "jsonb_object_field_text")),
+        Token::HashArrow => Some(("#>", "jsonb_extract_path_op")),
+        Token::AtArrow => Some(("@>", "jsonb_contains")),
+        Token::Question => Some(("?", "jsonb_exists")),
+        _ => None,
+    }
+}
+
+/// index of the first token of an operand that ends before `end`
+fn operand_start(tokens: &[Token], end: usize) -> Option<usize> {
+    let mut end = end;
+    // casts of the operand
+    loop {
+        let type_start = match tokens[..end].last() {
+            Some(Token::RParen) => match group_start(tokens, end - 1)?.checked_sub(1) {
+                Some(type_start) => type_start,
+                None => break,
+            },
+            Some(Token::Word(_)) => end - 1,
+            _ => break,
+        };
+        match (
+            type_start.checked_sub(1).map(|index| &tokens[index]),
+            &tokens[type_start],
+        ) {
+            (Some(Token::DoubleColon), Token::Word(_)) => end = type_start - 1,
+            _ => break,
+        }
+    }
+    let mut start = match tokens[..end].last()? {
+        Token::RParen => {
+            let group_start = group_start(tokens, end - 1)?;
+            match group_start.checked_sub(1).map(|index| &tokens[index]) {
+                Some(Token::Word(word)) if !is_keyword(word.value.as_str()) => group_start - 1,
+                _ => return Some(group_start),
+            }
+        }
+        Token::Word(word) if !is_keyword(word.value.as_str()) => end - 1,
+        Token::Number(_) | Token::SingleQuotedString(_) => return Some(end - 1),
+        _ => return None,
+    };
+    // qualified names of columns and functions
+    while start >= 2 && tokens[start - 1] == Token::Period && matches!(tokens[start - 2], Token::Word(_)) {
+        start -= 2;
+    }
+    Some(start)
+}
+
+/// index of the token that follows an operand starting at `start`
+fn operand_end(tokens: &[Token], start: usize) -> Option<usize> {
+    let mut end = start;
+    if matches!(tokens.get(end), Some(Token::Minus) | Some(Token::Plus)) {
+        end += 1;
+    }
+    end = match tokens.get(end)? {
+        Token::LParen => group_end(tokens, end)?,
+        Token::Word(word) if !is_keyword(word.value.as_str()) => {
+            end += 1;
+            while tokens.get(end) == Some(&Token::Period) && matches!(tokens.get(end + 1), Some(Token::Word(_))) {
+                end += 2;
+            }
+            match tokens.get(end) {
+                Some(Token::LParen) => group_end(tokens, end)?,
+                _ => end,
+            }
+        }
+        Token::Number(_) | Token::SingleQuotedString(_) => end + 1,
+        _ => return None,
+    };
+    // casts of the operand
+    while tokens.get(end) == Some(&Token::DoubleColon) && matches!(tokens.get(end + 1), Some(Token::Word(_))) {
+        end += 2;
+        if tokens.get(end) == Some(&Token::LParen) {
+            end = group_end(tokens, end)?;
+        }
+    }
+    Some(end)
+}
+
+/// index of `(` that matches `)` at `end`
+fn group_start(tokens: &[Token], end: usize) -> Option<usize> {
+    let mut depth = 0;
+    for index in (0..=end).rev() {
+        match tokens[index] {
+            Token::RParen => depth += 1,
+            Token::LParen if depth == 1 => return Some(index),
+            Token::LParen => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// index of the token that follows `)` matching `(` at `start`
+fn group_end(tokens: &[Token], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen if depth == 1 => return Some(index + 1),
+            Token::RParen => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_keyword(word: &str) -> bool {
+    KEYWORDS.iter().any(|keyword| keyword.eq_ignore_ascii_case(word))
+}
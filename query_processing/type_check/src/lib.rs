@@ -12,16 +12,287 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data_manipulation_typed_tree::{DynamicTypedTree, StaticTypedTree};
+use data_manipulation_operators::{AggregateFunction, Bitwise, Operation, ScalarFunction, UnaryOperation};
+use data_manipulation_typed_tree::{DynamicTypedItem, DynamicTypedTree, StaticTypedTree};
+use definition::ColumnDef;
+use types::SqlTypeFamily;
+
+#[derive(Debug, PartialEq)]
+pub enum TypeCheckError {
+    UndefinedFunction {
+        op: Operation,
+        left: SqlTypeFamily,
+        right: SqlTypeFamily,
+    },
+    UndefinedUnaryOperation {
+        op: UnaryOperation,
+        operand: SqlTypeFamily,
+    },
+    DatatypeMismatch {
+        argument_of: String,
+        expected: SqlTypeFamily,
+        actual: SqlTypeFamily,
+    },
+    UndefinedAggregateFunction {
+        function: AggregateFunction,
+        argument: SqlTypeFamily,
+    },
+    /// `None` in `arguments` stands for the type of `NULL`
+    UndefinedScalarFunction {
+        function: ScalarFunction,
+        arguments: Vec<Option<SqlTypeFamily>>,
+    },
+}
 
 pub struct TypeChecker;
 
 impl TypeChecker {
-    pub fn check_dynamic(&self, tree: DynamicTypedTree) -> DynamicTypedTree {
-        tree
+    pub fn check_dynamic(
+        &self,
+        tree: DynamicTypedTree,
+        columns: &[ColumnDef],
+    ) -> Result<DynamicTypedTree, TypeCheckError> {
+        self.dynamic_type_family(&tree, columns)?;
+        Ok(tree)
+    }
+
+    /// checks that a tree used as a `WHERE` clause evaluates to boolean
+    pub fn check_predicate(
+        &self,
+        tree: DynamicTypedTree,
+        columns: &[ColumnDef],
+    ) -> Result<DynamicTypedTree, TypeCheckError> {
+        self.check_condition(tree, columns, "WHERE")
+    }
+
+    /// checks that a tree used as a `HAVING` clause evaluates to boolean
+    pub fn check_group_predicate(
+        &self,
+        tree: DynamicTypedTree,
+        columns: &[ColumnDef],
+    ) -> Result<DynamicTypedTree, TypeCheckError> {
+        self.check_condition(tree, columns, "HAVING")
+    }
+
+    /// checks that a tree used as a condition of `JOIN` evaluates to boolean
+    pub fn check_join_condition(
+        &self,
+        tree: DynamicTypedTree,
+        columns: &[ColumnDef],
+    ) -> Result<DynamicTypedTree, TypeCheckError> {
+        self.check_condition(tree, columns, "JOIN/ON")
+    }
+
+    /// type family of a value that `tree` evaluates to, `None` stands for the type of `NULL`
+    pub fn type_family(
+        &self,
+        tree: &DynamicTypedTree,
+        columns: &[ColumnDef],
+    ) -> Result<Option<SqlTypeFamily>, TypeCheckError> {
+        self.dynamic_type_family(tree, columns)
+    }
+
+    pub fn check_static(&self, tree: StaticTypedTree) -> Result<StaticTypedTree, TypeCheckError> {
+        self.static_type_family(&tree)?;
+        Ok(tree)
+    }
+
+    fn check_condition(
+        &self,
+        tree: DynamicTypedTree,
+        columns: &[ColumnDef],
+        clause: &str,
+    ) -> Result<DynamicTypedTree, TypeCheckError> {
+        match self.dynamic_type_family(&tree, columns)? {
+            None | Some(SqlTypeFamily::Bool) => Ok(tree),
+            Some(actual) => Err(TypeCheckError::DatatypeMismatch {
+                argument_of: clause.to_owned(),
+                expected: SqlTypeFamily::Bool,
+                actual,
+            }),
+        }
+    }
+
+    fn dynamic_type_family(
+        &self,
+        tree: &DynamicTypedTree,
+        columns: &[ColumnDef],
+    ) -> Result<Option<SqlTypeFamily>, TypeCheckError> {
+        match tree {
+            DynamicTypedTree::Item(DynamicTypedItem::Const(value)) => Ok(value.type_family()),
+            DynamicTypedTree::Item(DynamicTypedItem::Column(name)) => Ok(columns
+                .iter()
+                .find(|column| column.has_name(name))
+                .map(|column| column.sql_type().family())),
+            DynamicTypedTree::Operation { left, op, right } => {
+                let left = self.dynamic_type_family(left, columns)?;
+                let right = self.dynamic_type_family(right, columns)?;
+                operation_type_family(*op, left, right)
+            }
+            DynamicTypedTree::UnaryOperation { op, item } => {
+                unary_operation_type_family(*op, self.dynamic_type_family(item, columns)?)
+            }
+            DynamicTypedTree::Cast { tree, target } => {
+                self.dynamic_type_family(tree, columns)?;
+                Ok(Some(target.family()))
+            }
+            DynamicTypedTree::Function { function, args } => {
+                let arguments = args
+                    .iter()
+                    .map(|arg| self.dynamic_type_family(arg, columns))
+                    .collect::<Result<Vec<_>, _>>()?;
+                function_type_family(*function, arguments)
+            }
+            DynamicTypedTree::Aggregate { function, argument, .. } => match argument {
+                None => Ok(Some(SqlTypeFamily::BigInt)),
+                Some(argument) => {
+                    let argument = self.dynamic_type_family(argument, columns)?;
+                    aggregate_type_family(*function, argument)
+                }
+            },
+        }
+    }
+
+    fn static_type_family(&self, tree: &StaticTypedTree) -> Result<Option<SqlTypeFamily>, TypeCheckError> {
+        match tree {
+            StaticTypedTree::Item(_) => Ok(tree.type_family()),
+            StaticTypedTree::Operation { left, op, right, .. } => {
+                let left = self.static_type_family(left)?;
+                let right = self.static_type_family(right)?;
+                operation_type_family(*op, left, right)
+            }
+            StaticTypedTree::UnaryOperation { op, item, .. } => {
+                unary_operation_type_family(*op, self.static_type_family(item)?)
+            }
+            StaticTypedTree::Cast { tree, target } => {
+                self.static_type_family(tree)?;
+                Ok(Some(target.family()))
+            }
+            StaticTypedTree::Function { function, args, .. } => {
+                let arguments = args
+                    .iter()
+                    .map(|arg| self.static_type_family(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                function_type_family(*function, arguments)
+            }
+        }
     }
+}
+
+/// resolves type family of an operation result, `None` stands for the type of `NULL`
+fn operation_type_family(
+    op: Operation,
+    left: Option<SqlTypeFamily>,
+    right: Option<SqlTypeFamily>,
+) -> Result<Option<SqlTypeFamily>, TypeCheckError> {
+    if let Operation::Logical(_) = op {
+        for actual in left.iter().chain(right.iter()) {
+            if *actual != SqlTypeFamily::Bool {
+                return Err(TypeCheckError::DatatypeMismatch {
+                    argument_of: op.to_string(),
+                    expected: SqlTypeFamily::Bool,
+                    actual: *actual,
+                });
+            }
+        }
+        return Ok(Some(SqlTypeFamily::Bool));
+    }
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            if op.supported_type_family(Some(general(left)), Some(general(right))) {
+                Ok(Some(result_type_family(op, left, right)))
+            } else {
+                Err(TypeCheckError::UndefinedFunction { op, left, right })
+            }
+        }
+        (Some(known), None) | (None, Some(known)) => Ok(Some(result_type_family(op, known, known))),
+        (None, None) => match op {
+            Operation::Comparison(_) | Operation::PatternMatching(_) => Ok(Some(SqlTypeFamily::Bool)),
+            Operation::StringOp(_) => Ok(Some(SqlTypeFamily::String)),
+            _ => Ok(None),
+        },
+    }
+}
+
+/// `NOT` requires a boolean like logical operations do
+fn unary_operation_type_family(
+    op: UnaryOperation,
+    operand: Option<SqlTypeFamily>,
+) -> Result<Option<SqlTypeFamily>, TypeCheckError> {
+    match operand {
+        Some(actual) if op == UnaryOperation::Not && actual != SqlTypeFamily::Bool => {
+            Err(TypeCheckError::DatatypeMismatch {
+                argument_of: op.to_string(),
+                expected: SqlTypeFamily::Bool,
+                actual,
+            })
+        }
+        Some(operand) if !op.supported_type_family(Some(operand)) => {
+            Err(TypeCheckError::UndefinedUnaryOperation { op, operand })
+        }
+        _ => Ok(op.result_type_family(operand)),
+    }
+}
+
+fn function_type_family(
+    function: ScalarFunction,
+    arguments: Vec<Option<SqlTypeFamily>>,
+) -> Result<Option<SqlTypeFamily>, TypeCheckError> {
+    match function.result_type_family(&arguments) {
+        Some(type_family) => Ok(Some(type_family)),
+        None => Err(TypeCheckError::UndefinedScalarFunction { function, arguments }),
+    }
+}
+
+/// as in PostgreSQL `smallint`s and `integer`s are summed up as `bigint`s, sums of `bigint`s
+/// and averages of integers are `numeric`s, floats are summed up and averaged as floats
+fn aggregate_type_family(
+    function: AggregateFunction,
+    argument: Option<SqlTypeFamily>,
+) -> Result<Option<SqlTypeFamily>, TypeCheckError> {
+    let argument = match (function, argument) {
+        (AggregateFunction::Count, _) => return Ok(Some(SqlTypeFamily::BigInt)),
+        (AggregateFunction::JsonbAgg, _) => return Ok(Some(SqlTypeFamily::Jsonb)),
+        (_, None) => return Ok(None),
+        (_, Some(argument)) => argument,
+    };
+    match function {
+        AggregateFunction::Sum if argument == SqlTypeFamily::BigInt => Ok(Some(SqlTypeFamily::Numeric)),
+        AggregateFunction::Sum if argument.is_int() => Ok(Some(SqlTypeFamily::BigInt)),
+        AggregateFunction::Sum if argument.is_float() => Ok(Some(argument)),
+        AggregateFunction::Sum | AggregateFunction::Avg if argument == SqlTypeFamily::Numeric => {
+            Ok(Some(SqlTypeFamily::Numeric))
+        }
+        AggregateFunction::Avg if argument.is_int() => Ok(Some(SqlTypeFamily::Numeric)),
+        AggregateFunction::Avg if argument.is_float() => Ok(Some(SqlTypeFamily::Double)),
+        AggregateFunction::Min | AggregateFunction::Max if argument != SqlTypeFamily::Bool => Ok(Some(argument)),
+        _ => Err(TypeCheckError::UndefinedAggregateFunction { function, argument }),
+    }
+}
 
-    pub fn check_static(&self, tree: StaticTypedTree) -> StaticTypedTree {
-        tree
+fn result_type_family(op: Operation, left: SqlTypeFamily, right: SqlTypeFamily) -> SqlTypeFamily {
+    match op {
+        Operation::Bitwise(Bitwise::ShiftLeft) | Operation::Bitwise(Bitwise::ShiftRight) => left,
+        Operation::Arithmetic(arithmetic) => arithmetic
+            .temporal_result(left, right)
+            .unwrap_or_else(|| left.compare(&right).unwrap_or(left)),
+        Operation::Bitwise(_) => left.compare(&right).unwrap_or(left),
+        Operation::Comparison(_) | Operation::Logical(_) | Operation::PatternMatching(_) => SqlTypeFamily::Bool,
+        Operation::StringOp(_) => SqlTypeFamily::String,
+        Operation::JsonOp(json_op) => json_op.result_type_family(left),
     }
 }
+
+/// `Operation::supported_type_family` does not distinguish sizes of numeric types
+fn general(type_family: SqlTypeFamily) -> SqlTypeFamily {
+    if type_family.is_int() {
+        SqlTypeFamily::Integer
+    } else if type_family.is_float() || type_family == SqlTypeFamily::Numeric {
+        SqlTypeFamily::Real
+    } else {
+        type_family
+    }
+}
+
+#[cfg(test)]
+mod tests;
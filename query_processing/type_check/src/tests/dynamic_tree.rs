@@ -0,0 +1,282 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn columns() -> Vec<ColumnDef> {
+    vec![
+        ColumnDef::new("col_1".to_owned(), SqlType::small_int(), 0),
+        ColumnDef::new("col_2".to_owned(), SqlType::var_char(10), 1),
+        ColumnDef::new("col_3".to_owned(), SqlType::bool(), 2),
+        ColumnDef::new("col_4".to_owned(), SqlType::jsonb(), 3),
+    ]
+}
+
+fn column(name: &str) -> Box<DynamicTypedTree> {
+    Box::new(DynamicTypedTree::Item(DynamicTypedItem::Column(name.to_owned())))
+}
+
+fn value(value: TypedValue) -> Box<DynamicTypedTree> {
+    Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(value)))
+}
+
+fn operation(left: Box<DynamicTypedTree>, op: Operation, right: Box<DynamicTypedTree>) -> DynamicTypedTree {
+    DynamicTypedTree::Operation { left, op, right }
+}
+
+#[test]
+fn column_types_are_resolved() {
+    let tree = operation(
+        column("col_1"),
+        Operation::Arithmetic(Arithmetic::Add),
+        value(TypedValue::Integer(1)),
+    );
+
+    assert_eq!(TypeChecker.check_dynamic(tree.clone(), &columns()), Ok(tree));
+}
+
+#[test]
+fn operation_on_columns_of_different_types() {
+    let tree = operation(column("col_1"), Operation::Arithmetic(Arithmetic::Sub), column("col_2"));
+
+    assert_eq!(
+        TypeChecker.check_dynamic(tree, &columns()),
+        Err(TypeCheckError::UndefinedFunction {
+            op: Operation::Arithmetic(Arithmetic::Sub),
+            left: SqlTypeFamily::SmallInt,
+            right: SqlTypeFamily::String,
+        })
+    );
+}
+
+#[test]
+fn boolean_predicate() {
+    let tree = operation(
+        column("col_1"),
+        Operation::Comparison(Comparison::GtEq),
+        value(TypedValue::SmallInt(1)),
+    );
+
+    assert_eq!(TypeChecker.check_predicate(tree.clone(), &columns()), Ok(tree));
+}
+
+#[test]
+fn boolean_column_as_predicate() {
+    assert_eq!(
+        TypeChecker.check_predicate(*column("col_3"), &columns()),
+        Ok(*column("col_3"))
+    );
+}
+
+#[test]
+fn non_boolean_predicate() {
+    let tree = operation(
+        column("col_1"),
+        Operation::Arithmetic(Arithmetic::Add),
+        value(TypedValue::SmallInt(1)),
+    );
+
+    assert_eq!(
+        TypeChecker.check_predicate(tree, &columns()),
+        Err(TypeCheckError::DatatypeMismatch {
+            argument_of: "WHERE".to_owned(),
+            expected: SqlTypeFamily::Bool,
+            actual: SqlTypeFamily::SmallInt,
+        })
+    );
+}
+
+fn aggregate(function: AggregateFunction, argument: Option<Box<DynamicTypedTree>>) -> DynamicTypedTree {
+    DynamicTypedTree::Aggregate {
+        function,
+        argument,
+        distinct: false,
+    }
+}
+
+#[test]
+fn count_of_rows_is_big_int() {
+    assert_eq!(
+        TypeChecker.type_family(&aggregate(AggregateFunction::Count, None), &columns()),
+        Ok(Some(SqlTypeFamily::BigInt))
+    );
+}
+
+#[test]
+fn integers_are_summed_up_as_big_int() {
+    assert_eq!(
+        TypeChecker.type_family(&aggregate(AggregateFunction::Sum, Some(column("col_1"))), &columns()),
+        Ok(Some(SqlTypeFamily::BigInt))
+    );
+}
+
+#[test]
+fn sum_of_bigints_is_numeric() {
+    let tree = aggregate(AggregateFunction::Sum, Some(value(TypedValue::BigInt(1))));
+    assert_eq!(
+        TypeChecker.type_family(&tree, &columns()),
+        Ok(Some(SqlTypeFamily::Numeric))
+    );
+}
+
+#[test]
+fn average_of_integers_is_numeric() {
+    assert_eq!(
+        TypeChecker.type_family(&aggregate(AggregateFunction::Avg, Some(column("col_1"))), &columns()),
+        Ok(Some(SqlTypeFamily::Numeric))
+    );
+}
+
+#[test]
+fn average_of_floats_is_double_precision() {
+    let tree = aggregate(AggregateFunction::Avg, Some(value(TypedValue::Double(1.0))));
+    assert_eq!(
+        TypeChecker.type_family(&tree, &columns()),
+        Ok(Some(SqlTypeFamily::Double))
+    );
+}
+
+#[test]
+fn average_of_numerics_is_numeric() {
+    let tree = aggregate(
+        AggregateFunction::Avg,
+        Some(value(TypedValue::Numeric(BigDecimal::from(1)))),
+    );
+    assert_eq!(
+        TypeChecker.type_family(&tree, &columns()),
+        Ok(Some(SqlTypeFamily::Numeric))
+    );
+}
+
+#[test]
+fn numeric_arithmetic_with_integers() {
+    let tree = operation(
+        column("col_1"),
+        Operation::Arithmetic(Arithmetic::Mul),
+        value(TypedValue::Numeric(BigDecimal::from(1))),
+    );
+    assert_eq!(
+        TypeChecker.type_family(&tree, &columns()),
+        Ok(Some(SqlTypeFamily::Numeric))
+    );
+}
+
+#[test]
+fn maximum_has_type_of_its_argument() {
+    assert_eq!(
+        TypeChecker.type_family(&aggregate(AggregateFunction::Max, Some(column("col_2"))), &columns()),
+        Ok(Some(SqlTypeFamily::String))
+    );
+}
+
+#[test]
+fn jsonb_agg_of_any_values_is_jsonb() {
+    assert_eq!(
+        TypeChecker.type_family(
+            &aggregate(AggregateFunction::JsonbAgg, Some(column("col_3"))),
+            &columns()
+        ),
+        Ok(Some(SqlTypeFamily::Jsonb))
+    );
+}
+
+#[test]
+fn json_operators() {
+    let tree = operation(
+        column("col_4"),
+        Operation::JsonOp(JsonOp::GetText),
+        value(TypedValue::String("key".to_owned())),
+    );
+    assert_eq!(
+        TypeChecker.type_family(&tree, &columns()),
+        Ok(Some(SqlTypeFamily::String))
+    );
+
+    let tree = operation(column("col_4"), Operation::JsonOp(JsonOp::Contains), column("col_1"));
+    assert_eq!(
+        TypeChecker.check_dynamic(tree, &columns()),
+        Err(TypeCheckError::UndefinedFunction {
+            op: Operation::JsonOp(JsonOp::Contains),
+            left: SqlTypeFamily::Jsonb,
+            right: SqlTypeFamily::SmallInt,
+        })
+    );
+}
+
+#[test]
+fn sum_of_strings() {
+    assert_eq!(
+        TypeChecker.check_dynamic(aggregate(AggregateFunction::Sum, Some(column("col_2"))), &columns()),
+        Err(TypeCheckError::UndefinedAggregateFunction {
+            function: AggregateFunction::Sum,
+            argument: SqlTypeFamily::String,
+        })
+    );
+}
+
+#[test]
+fn non_boolean_group_predicate() {
+    assert_eq!(
+        TypeChecker.check_group_predicate(aggregate(AggregateFunction::Count, None), &columns()),
+        Err(TypeCheckError::DatatypeMismatch {
+            argument_of: "HAVING".to_owned(),
+            expected: SqlTypeFamily::Bool,
+            actual: SqlTypeFamily::BigInt,
+        })
+    );
+}
+
+fn unary(op: UnaryOperation, item: Box<DynamicTypedTree>) -> DynamicTypedTree {
+    DynamicTypedTree::UnaryOperation { op, item }
+}
+
+#[test]
+fn unary_minus_keeps_operand_type() {
+    assert_eq!(
+        TypeChecker.type_family(&unary(UnaryOperation::Minus, column("col_1")), &columns()),
+        Ok(Some(SqlTypeFamily::SmallInt))
+    );
+}
+
+#[test]
+fn unary_minus_of_string() {
+    assert_eq!(
+        TypeChecker.check_dynamic(unary(UnaryOperation::Minus, column("col_2")), &columns()),
+        Err(TypeCheckError::UndefinedUnaryOperation {
+            op: UnaryOperation::Minus,
+            operand: SqlTypeFamily::String,
+        })
+    );
+}
+
+#[test]
+fn not_of_non_boolean() {
+    assert_eq!(
+        TypeChecker.check_predicate(unary(UnaryOperation::Not, column("col_1")), &columns()),
+        Err(TypeCheckError::DatatypeMismatch {
+            argument_of: "NOT".to_owned(),
+            expected: SqlTypeFamily::Bool,
+            actual: SqlTypeFamily::SmallInt,
+        })
+    );
+}
+
+#[test]
+fn is_null_of_any_type() {
+    let tree = unary(UnaryOperation::IsNull, column("col_4"));
+    assert_eq!(TypeChecker.check_predicate(tree.clone(), &columns()), Ok(tree));
+
+    let tree = unary(UnaryOperation::IsNotNull, column("col_2"));
+    assert_eq!(TypeChecker.check_predicate(tree.clone(), &columns()), Ok(tree));
+}
@@ -0,0 +1,59 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use bigdecimal::BigDecimal;
+use data_manipulation_operators::{
+    AggregateFunction, Arithmetic, Comparison, JsonOp, Logical, StringOp, UnaryOperation,
+};
+use data_manipulation_typed_tree::{StaticTypedItem, TypedValue};
+use types::SqlType;
+
+#[cfg(test)]
+mod dynamic_tree;
+#[cfg(test)]
+mod static_tree;
+
+fn small_int(value: i16) -> Box<StaticTypedTree> {
+    constant(TypedValue::SmallInt(value))
+}
+
+fn real(value: f32) -> Box<StaticTypedTree> {
+    constant(TypedValue::Real(value))
+}
+
+fn string(value: &str) -> Box<StaticTypedTree> {
+    constant(TypedValue::String(value.to_owned()))
+}
+
+fn boolean(value: bool) -> Box<StaticTypedTree> {
+    constant(TypedValue::Bool(value))
+}
+
+fn null() -> Box<StaticTypedTree> {
+    Box::new(StaticTypedTree::Item(StaticTypedItem::Null(None)))
+}
+
+fn constant(value: TypedValue) -> Box<StaticTypedTree> {
+    Box::new(StaticTypedTree::Item(StaticTypedItem::Const(value)))
+}
+
+fn operation(left: Box<StaticTypedTree>, op: Operation, right: Box<StaticTypedTree>) -> Box<StaticTypedTree> {
+    Box::new(StaticTypedTree::Operation {
+        type_family: None,
+        left,
+        op,
+        right,
+    })
+}
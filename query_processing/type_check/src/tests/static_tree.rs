@@ -0,0 +1,115 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn constant_value() {
+    assert_eq!(TypeChecker.check_static(*small_int(1)), Ok(*small_int(1)));
+}
+
+#[test]
+fn arithmetic_on_numbers() {
+    let tree = operation(small_int(1), Operation::Arithmetic(Arithmetic::Add), real(2.0));
+
+    assert_eq!(TypeChecker.check_static(*tree.clone()), Ok(*tree));
+}
+
+#[test]
+fn arithmetic_on_number_and_string() {
+    let tree = operation(small_int(1), Operation::Arithmetic(Arithmetic::Add), string("1"));
+
+    assert_eq!(
+        TypeChecker.check_static(*tree),
+        Err(TypeCheckError::UndefinedFunction {
+            op: Operation::Arithmetic(Arithmetic::Add),
+            left: SqlTypeFamily::SmallInt,
+            right: SqlTypeFamily::String,
+        })
+    );
+}
+
+#[test]
+fn comparison_of_different_numbers() {
+    let tree = operation(real(1.5), Operation::Comparison(Comparison::Lt), small_int(2));
+
+    assert_eq!(TypeChecker.check_static(*tree.clone()), Ok(*tree));
+}
+
+#[test]
+fn comparison_of_number_and_bool() {
+    let tree = operation(small_int(1), Operation::Comparison(Comparison::Eq), boolean(true));
+
+    assert_eq!(
+        TypeChecker.check_static(*tree),
+        Err(TypeCheckError::UndefinedFunction {
+            op: Operation::Comparison(Comparison::Eq),
+            left: SqlTypeFamily::SmallInt,
+            right: SqlTypeFamily::Bool,
+        })
+    );
+}
+
+#[test]
+fn concatenation_of_numbers() {
+    let tree = operation(small_int(1), Operation::StringOp(StringOp::Concat), small_int(2));
+
+    assert_eq!(
+        TypeChecker.check_static(*tree),
+        Err(TypeCheckError::UndefinedFunction {
+            op: Operation::StringOp(StringOp::Concat),
+            left: SqlTypeFamily::SmallInt,
+            right: SqlTypeFamily::SmallInt,
+        })
+    );
+}
+
+#[test]
+fn logical_operation_on_number() {
+    let tree = operation(boolean(true), Operation::Logical(Logical::And), small_int(1));
+
+    assert_eq!(
+        TypeChecker.check_static(*tree),
+        Err(TypeCheckError::DatatypeMismatch {
+            argument_of: "AND".to_owned(),
+            expected: SqlTypeFamily::Bool,
+            actual: SqlTypeFamily::SmallInt,
+        })
+    );
+}
+
+#[test]
+fn operation_with_null() {
+    let tree = operation(null(), Operation::Arithmetic(Arithmetic::Mul), small_int(1));
+
+    assert_eq!(TypeChecker.check_static(*tree.clone()), Ok(*tree));
+}
+
+#[test]
+fn result_type_of_nested_operation() {
+    let tree = operation(
+        operation(small_int(1), Operation::Comparison(Comparison::Lt), small_int(2)),
+        Operation::Arithmetic(Arithmetic::Add),
+        small_int(3),
+    );
+
+    assert_eq!(
+        TypeChecker.check_static(*tree),
+        Err(TypeCheckError::UndefinedFunction {
+            op: Operation::Arithmetic(Arithmetic::Add),
+            left: SqlTypeFamily::Bool,
+            right: SqlTypeFamily::SmallInt,
+        })
+    );
+}
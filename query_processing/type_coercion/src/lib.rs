@@ -15,14 +15,22 @@
 use data_manipulation_typed_tree::{DynamicTypedTree, StaticTypedTree};
 use types::SqlType;
 
+/// wraps values that are stored into columns with explicit casts to column types,
+/// which are checked against type ranges and lengths during evaluation
 pub struct TypeCoercion;
 
 impl TypeCoercion {
-    pub fn coerce_dynamic(&self, tree: DynamicTypedTree) -> DynamicTypedTree {
-        tree
+    pub fn coerce_dynamic(&self, tree: DynamicTypedTree, column_type: SqlType) -> DynamicTypedTree {
+        DynamicTypedTree::Cast {
+            tree: Box::new(tree),
+            target: column_type,
+        }
     }
 
-    pub fn coerce_static(&self, tree: StaticTypedTree, _column_type: SqlType) -> StaticTypedTree {
-        tree
+    pub fn coerce_static(&self, tree: StaticTypedTree, column_type: SqlType) -> StaticTypedTree {
+        StaticTypedTree::Cast {
+            tree: Box::new(tree),
+            target: column_type,
+        }
     }
 }
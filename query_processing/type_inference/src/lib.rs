@@ -12,19 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use bigdecimal::{BigDecimal, FromPrimitive, ToPrimitive};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use data_manipulation_operators::{Arithmetic, Bitwise, JsonOp, Operation, UnaryOperation};
 use data_manipulation_typed_tree::{DynamicTypedItem, DynamicTypedTree, StaticTypedItem, StaticTypedTree, TypedValue};
 use data_manipulation_untyped_tree::{
     Bool, DynamicUntypedItem, DynamicUntypedTree, StaticUntypedItem, StaticUntypedTree, UntypedValue,
 };
-use std::ops::RangeInclusive;
+use std::{collections::HashMap, ops::RangeInclusive};
+use types::SqlType;
 
 pub struct TypeInference {
     small_int_range: RangeInclusive<BigDecimal>,
     integer_range: RangeInclusive<BigDecimal>,
     big_int_range: RangeInclusive<BigDecimal>,
-    real_range: RangeInclusive<BigDecimal>,
-    double_precision_range: RangeInclusive<BigDecimal>,
 }
 
 impl Default for TypeInference {
@@ -33,8 +33,6 @@ impl Default for TypeInference {
             small_int_range: BigDecimal::from(i16::MIN)..=BigDecimal::from(i16::MAX),
             integer_range: BigDecimal::from(i32::MIN)..=BigDecimal::from(i32::MAX),
             big_int_range: BigDecimal::from(i64::MIN)..=BigDecimal::from(i64::MAX),
-            real_range: BigDecimal::from_f32(f32::MIN).unwrap()..=BigDecimal::from_f32(f32::MAX).unwrap(),
-            double_precision_range: BigDecimal::from_f64(f64::MIN).unwrap()..=BigDecimal::from_f64(f64::MAX).unwrap(),
         }
     }
 }
@@ -42,26 +40,27 @@ impl Default for TypeInference {
 impl TypeInference {
     pub fn infer_dynamic(&self, tree: DynamicUntypedTree) -> DynamicTypedTree {
         match tree {
+            DynamicUntypedTree::Operation { left, op, right } => DynamicTypedTree::Operation {
+                left: Box::new(self.infer_dynamic(*left)),
+                op,
+                right: Box::new(self.infer_dynamic(*right)),
+            },
+            DynamicUntypedTree::UnaryOperation { op, item } => DynamicTypedTree::UnaryOperation {
+                op,
+                item: Box::new(self.infer_dynamic(*item)),
+            },
             DynamicUntypedTree::Item(DynamicUntypedItem::Column { name, .. }) => {
                 DynamicTypedTree::Item(DynamicTypedItem::Column(name))
             }
             DynamicUntypedTree::Item(DynamicUntypedItem::Const(UntypedValue::Number(num))) => {
-                if num.is_integer() {
-                    if self.small_int_range.contains(&num) {
-                        DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::SmallInt(num.to_i16().unwrap())))
-                    } else if self.integer_range.contains(&num) {
-                        DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::Integer(num.to_i32().unwrap())))
-                    } else if self.big_int_range.contains(&num) {
-                        DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::BigInt(num.to_i64().unwrap())))
-                    } else {
-                        unimplemented!()
-                    }
-                } else if self.real_range.contains(&num) {
-                    DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::Real(num.to_f32().unwrap())))
-                } else if self.double_precision_range.contains(&num) {
-                    DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::Double(num.to_f64().unwrap())))
+                if num.is_integer() && self.small_int_range.contains(&num) {
+                    DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::SmallInt(num.to_i16().unwrap())))
+                } else if num.is_integer() && self.integer_range.contains(&num) {
+                    DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::Integer(num.to_i32().unwrap())))
+                } else if num.is_integer() && self.big_int_range.contains(&num) {
+                    DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::BigInt(num.to_i64().unwrap())))
                 } else {
-                    unimplemented!()
+                    DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::Numeric(num)))
                 }
             }
             DynamicUntypedTree::Item(DynamicUntypedItem::Const(UntypedValue::String(str))) => {
@@ -70,6 +69,23 @@ impl TypeInference {
             DynamicUntypedTree::Item(DynamicUntypedItem::Const(UntypedValue::Bool(Bool(boolean)))) => {
                 DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::Bool(boolean)))
             }
+            DynamicUntypedTree::Aggregate {
+                function,
+                argument,
+                distinct,
+            } => DynamicTypedTree::Aggregate {
+                function,
+                argument: argument.map(|argument| Box::new(self.infer_dynamic(*argument))),
+                distinct,
+            },
+            DynamicUntypedTree::Cast { tree, target } => DynamicTypedTree::Cast {
+                tree: Box::new(self.infer_dynamic(*tree)),
+                target,
+            },
+            DynamicUntypedTree::Function { function, args } => DynamicTypedTree::Function {
+                function,
+                args: args.into_iter().map(|arg| self.infer_dynamic(arg)).collect(),
+            },
             _ => unimplemented!(),
         }
     }
@@ -80,12 +96,12 @@ impl TypeInference {
                 let left_tree = self.infer_static(*left);
                 let right_tree = self.infer_static(*right);
                 let type_family = match (left_tree.type_family(), right_tree.type_family()) {
-                    (Some(left_type_family), Some(right_type_family)) => {
-                        match left_type_family.compare(&right_type_family) {
-                            Ok(type_family) => Some(type_family),
-                            Err(_) => unimplemented!(),
-                        }
-                    }
+                    (Some(left_type_family), Some(right_type_family)) => match op {
+                        Operation::Arithmetic(arithmetic) => arithmetic
+                            .temporal_result(left_type_family, right_type_family)
+                            .or_else(|| left_type_family.compare(&right_type_family).ok()),
+                        _ => left_type_family.compare(&right_type_family).ok(),
+                    },
                     (Some(left_type_family), None) => Some(left_type_family),
                     (None, Some(right_type_family)) => Some(right_type_family),
                     (None, None) => None,
@@ -97,35 +113,153 @@ impl TypeInference {
                     right: Box::new(right_tree),
                 }
             }
+            StaticUntypedTree::UnaryOperation { op, item } => {
+                let item = self.infer_static(*item);
+                StaticTypedTree::UnaryOperation {
+                    type_family: op.result_type_family(item.type_family()),
+                    op,
+                    item: Box::new(item),
+                }
+            }
             StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Number(num))) => {
-                if num.is_integer() {
-                    if self.small_int_range.contains(&num) {
-                        StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::SmallInt(num.to_i16().unwrap())))
-                    } else if self.integer_range.contains(&num) {
-                        StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::Integer(num.to_i32().unwrap())))
-                    } else if self.big_int_range.contains(&num) {
-                        StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::BigInt(num.to_i64().unwrap())))
-                    } else {
-                        unimplemented!()
-                    }
-                } else if self.real_range.contains(&num) {
-                    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::Real(num.to_f32().unwrap())))
-                } else if self.double_precision_range.contains(&num) {
-                    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::Double(num.to_f64().unwrap())))
+                if num.is_integer() && self.small_int_range.contains(&num) {
+                    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::SmallInt(num.to_i16().unwrap())))
+                } else if num.is_integer() && self.integer_range.contains(&num) {
+                    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::Integer(num.to_i32().unwrap())))
+                } else if num.is_integer() && self.big_int_range.contains(&num) {
+                    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::BigInt(num.to_i64().unwrap())))
                 } else {
-                    unimplemented!()
+                    StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::Numeric(num)))
                 }
             }
             StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::String(str))) => {
                 StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::String(str)))
             }
+            StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Null)) => {
+                StaticTypedTree::Item(StaticTypedItem::Null(None))
+            }
             StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Bool(Bool(boolean)))) => {
                 StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::Bool(boolean)))
             }
             StaticUntypedTree::Item(_) => unimplemented!(),
+            StaticUntypedTree::Cast { tree, target } => StaticTypedTree::Cast {
+                tree: Box::new(self.infer_static(*tree)),
+                target,
+            },
+            StaticUntypedTree::Function { function, args } => {
+                let args = args.into_iter().map(|arg| self.infer_static(arg)).collect::<Vec<_>>();
+                let type_families = args.iter().map(StaticTypedTree::type_family).collect::<Vec<_>>();
+                StaticTypedTree::Function {
+                    type_family: function.result_type_family(&type_families),
+                    function,
+                    args,
+                }
+            }
+        }
+    }
+
+    /// collects types of `$n` parameters of a value that is stored into a column of `target` type
+    pub fn infer_static_params(
+        &self,
+        tree: &StaticUntypedTree,
+        target: Option<SqlType>,
+        param_types: &mut HashMap<usize, SqlType>,
+    ) {
+        match tree {
+            StaticUntypedTree::Operation { left, op, right } => {
+                let target = operand_target(op, target);
+                self.infer_static_params(left, target, param_types);
+                self.infer_static_params(right, target, param_types);
+            }
+            StaticUntypedTree::UnaryOperation { op, item } => {
+                self.infer_static_params(item, unary_operand_target(op, target), param_types)
+            }
+            StaticUntypedTree::Item(StaticUntypedItem::Param(index)) => {
+                if let Some(sql_type) = target {
+                    param_types.entry(*index).or_insert(sql_type);
+                }
+            }
+            StaticUntypedTree::Item(StaticUntypedItem::Const(_)) => {}
+            StaticUntypedTree::Cast { tree, target } => self.infer_static_params(tree, Some(*target), param_types),
+            StaticUntypedTree::Function { args, .. } => {
+                for arg in args {
+                    self.infer_static_params(arg, None, param_types);
+                }
+            }
+        }
+    }
+
+    /// collects types of `$n` parameters from columns they are stored into or compared with
+    pub fn infer_dynamic_params(
+        &self,
+        tree: &DynamicUntypedTree,
+        target: Option<SqlType>,
+        param_types: &mut HashMap<usize, SqlType>,
+    ) {
+        match tree {
+            DynamicUntypedTree::Operation { left, op, right } => {
+                let target = operand_target(op, target);
+                // keys, indexes and paths are not of the type of a json document
+                let (left_type, right_type) = match op {
+                    Operation::JsonOp(json_op) if *json_op != JsonOp::Contains => (None, None),
+                    _ => (column_type(left), column_type(right)),
+                };
+                self.infer_dynamic_params(left, right_type.or(target), param_types);
+                self.infer_dynamic_params(right, left_type.or(target), param_types);
+            }
+            DynamicUntypedTree::UnaryOperation { op, item } => {
+                self.infer_dynamic_params(item, unary_operand_target(op, target), param_types)
+            }
+            DynamicUntypedTree::Item(DynamicUntypedItem::Param(index)) => {
+                if let Some(sql_type) = target {
+                    param_types.entry(*index).or_insert(sql_type);
+                }
+            }
+            DynamicUntypedTree::Item(_) => {}
+            DynamicUntypedTree::Cast { tree, target } => self.infer_dynamic_params(tree, Some(*target), param_types),
+            DynamicUntypedTree::Function { args, .. } => {
+                for arg in args {
+                    self.infer_dynamic_params(arg, None, param_types);
+                }
+            }
+            DynamicUntypedTree::Aggregate { argument, .. } => {
+                if let Some(argument) = argument {
+                    self.infer_dynamic_params(argument, None, param_types);
+                }
+            }
         }
     }
 }
 
+fn operand_target(op: &Operation, target: Option<SqlType>) -> Option<SqlType> {
+    match op {
+        Operation::Arithmetic(Arithmetic::Exp) => None,
+        // operands of date and time arithmetic are of different types
+        Operation::Arithmetic(_) if matches!(target, Some(sql_type) if sql_type.family().is_temporal()) => None,
+        Operation::Arithmetic(_) => target,
+        Operation::Bitwise(Bitwise::ShiftLeft) | Operation::Bitwise(Bitwise::ShiftRight) => None,
+        Operation::Bitwise(_) => target,
+        Operation::Logical(_) => Some(SqlType::Bool),
+        Operation::Comparison(_) | Operation::PatternMatching(_) | Operation::StringOp(_) | Operation::JsonOp(_) => {
+            None
+        }
+    }
+}
+
+fn unary_operand_target(op: &UnaryOperation, target: Option<SqlType>) -> Option<SqlType> {
+    match op {
+        UnaryOperation::Minus => target,
+        UnaryOperation::Not => Some(SqlType::Bool),
+        UnaryOperation::IsNull | UnaryOperation::IsNotNull => None,
+    }
+}
+
+fn column_type(tree: &DynamicUntypedTree) -> Option<SqlType> {
+    match tree {
+        DynamicUntypedTree::Item(DynamicUntypedItem::Column { sql_type, .. }) => Some(*sql_type),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests;
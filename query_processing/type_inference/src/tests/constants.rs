@@ -13,8 +13,9 @@
 // limitations under the License.
 
 use super::*;
-use bigdecimal::{BigDecimal, FromPrimitive};
+use bigdecimal::BigDecimal;
 use data_manipulation_typed_tree::{StaticTypedItem, StaticTypedTree, TypedValue};
+use std::str::FromStr;
 
 #[test]
 fn smallint() {
@@ -50,13 +51,28 @@ fn bigint() {
 }
 
 #[test]
-fn real() {
+fn numeric() {
     let type_inference = TypeInference::default();
-    let tree = untyped_number(BigDecimal::from_f32(3.8).unwrap());
+    let tree = untyped_number(BigDecimal::from_str("3.8").unwrap());
 
     assert_eq!(
         type_inference.infer_static(tree),
-        StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::Real(3.8)))
+        StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::Numeric(
+            BigDecimal::from_str("3.8").unwrap()
+        )))
+    );
+}
+
+#[test]
+fn integer_out_of_big_int_range() {
+    let type_inference = TypeInference::default();
+    let tree = untyped_number(BigDecimal::from_str("9223372036854775808").unwrap());
+
+    assert_eq!(
+        type_inference.infer_static(tree),
+        StaticTypedTree::Item(StaticTypedItem::Const(TypedValue::Numeric(
+            BigDecimal::from_str("9223372036854775808").unwrap()
+        )))
     );
 }
 
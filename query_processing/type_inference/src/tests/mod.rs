@@ -18,6 +18,8 @@ use super::*;
 mod constants;
 #[cfg(test)]
 mod operations;
+#[cfg(test)]
+mod params;
 
 fn untyped_number(num: BigDecimal) -> StaticUntypedTree {
     StaticUntypedTree::Item(StaticUntypedItem::Const(UntypedValue::Number(num)))
@@ -13,9 +13,9 @@
 // limitations under the License.
 
 use super::*;
-use data_manipulation_operators::{Arithmetic, Operation};
-use data_manipulation_typed_tree::{StaticTypedItem, StaticTypedTree, TypedValue};
-use types::SqlTypeFamily;
+use data_manipulation_operators::{Arithmetic, Comparison, Operation};
+use data_manipulation_typed_tree::{DynamicTypedItem, DynamicTypedTree, StaticTypedItem, StaticTypedTree, TypedValue};
+use types::{SqlType, SqlTypeFamily};
 
 #[test]
 fn add_same_types() {
@@ -58,3 +58,28 @@ fn add_different_types() {
         }
     )
 }
+
+#[test]
+fn compare_column_with_constant() {
+    let type_inference = TypeInference::default();
+    let untyped_tree = DynamicUntypedTree::Operation {
+        left: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+            name: "col".to_owned(),
+            sql_type: SqlType::integer(),
+            index: 0,
+        })),
+        op: Operation::Comparison(Comparison::Eq),
+        right: Box::new(DynamicUntypedTree::Item(DynamicUntypedItem::Const(
+            UntypedValue::Number(BigDecimal::from(1)),
+        ))),
+    };
+
+    assert_eq!(
+        type_inference.infer_dynamic(untyped_tree),
+        DynamicTypedTree::Operation {
+            left: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Column("col".to_owned()))),
+            op: Operation::Comparison(Comparison::Eq),
+            right: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::SmallInt(1))))
+        }
+    );
+}
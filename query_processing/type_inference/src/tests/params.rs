@@ -0,0 +1,115 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use data_manipulation_operators::{Arithmetic, Comparison, Logical, Operation};
+
+fn static_param(index: usize) -> StaticUntypedTree {
+    StaticUntypedTree::Item(StaticUntypedItem::Param(index))
+}
+
+fn dynamic_param(index: usize) -> DynamicUntypedTree {
+    DynamicUntypedTree::Item(DynamicUntypedItem::Param(index))
+}
+
+fn column(name: &str, sql_type: SqlType) -> DynamicUntypedTree {
+    DynamicUntypedTree::Item(DynamicUntypedItem::Column {
+        name: name.to_owned(),
+        sql_type,
+        index: 0,
+    })
+}
+
+#[test]
+fn static_param_takes_target_type() {
+    let type_inference = TypeInference::default();
+    let mut param_types = HashMap::new();
+
+    type_inference.infer_static_params(&static_param(0), Some(SqlType::small_int()), &mut param_types);
+
+    assert_eq!(param_types, vec![(0, SqlType::small_int())].into_iter().collect());
+}
+
+#[test]
+fn static_param_in_arithmetic_operation() {
+    let type_inference = TypeInference::default();
+    let mut param_types = HashMap::new();
+
+    type_inference.infer_static_params(
+        &StaticUntypedTree::Operation {
+            left: Box::new(static_param(1)),
+            op: Operation::Arithmetic(Arithmetic::Add),
+            right: Box::new(untyped_number(BigDecimal::from(1))),
+        },
+        Some(SqlType::integer()),
+        &mut param_types,
+    );
+
+    assert_eq!(param_types, vec![(1, SqlType::integer())].into_iter().collect());
+}
+
+#[test]
+fn static_param_without_target() {
+    let type_inference = TypeInference::default();
+    let mut param_types = HashMap::new();
+
+    type_inference.infer_static_params(&static_param(0), None, &mut param_types);
+
+    assert_eq!(param_types, HashMap::new());
+}
+
+#[test]
+fn dynamic_param_compared_with_column() {
+    let type_inference = TypeInference::default();
+    let mut param_types = HashMap::new();
+
+    type_inference.infer_dynamic_params(
+        &DynamicUntypedTree::Operation {
+            left: Box::new(column("col1", SqlType::big_int())),
+            op: Operation::Comparison(Comparison::Eq),
+            right: Box::new(dynamic_param(2)),
+        },
+        Some(SqlType::Bool),
+        &mut param_types,
+    );
+
+    assert_eq!(param_types, vec![(2, SqlType::big_int())].into_iter().collect());
+}
+
+#[test]
+fn dynamic_params_in_logical_operation() {
+    let type_inference = TypeInference::default();
+    let mut param_types = HashMap::new();
+
+    type_inference.infer_dynamic_params(
+        &DynamicUntypedTree::Operation {
+            left: Box::new(dynamic_param(0)),
+            op: Operation::Logical(Logical::And),
+            right: Box::new(DynamicUntypedTree::Operation {
+                left: Box::new(dynamic_param(1)),
+                op: Operation::Comparison(Comparison::Lt),
+                right: Box::new(column("col1", SqlType::var_char(10))),
+            }),
+        },
+        Some(SqlType::Bool),
+        &mut param_types,
+    );
+
+    assert_eq!(
+        param_types,
+        vec![(0, SqlType::Bool), (1, SqlType::var_char(10))]
+            .into_iter()
+            .collect()
+    );
+}
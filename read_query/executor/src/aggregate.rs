@@ -0,0 +1,237 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    sort::{self, float, integer, numeric},
+    Row, Rows,
+};
+use bigdecimal::BigDecimal;
+use data_manipulation_evaluation::Evaluation;
+use data_manipulation_operators::{AggregateFunction, Arithmetic, Operation};
+use data_manipulation_query_result::QueryExecutionError;
+use data_manipulation_typed_tree::{DynamicTypedItem, DynamicTypedTree, TypedValue};
+use data_scalar::{json::Jsonb, ScalarValue};
+use definition::ColumnDef;
+use ordered_float::OrderedFloat;
+use read_query_plan::AggregateCall;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+use types::{SqlType, SqlTypeFamily};
+
+/// hash aggregate: groups rows by values of grouping expressions, each group
+/// is reduced into its first row followed by values of aggregate calls
+pub(crate) fn aggregate(
+    source: (Vec<ColumnDef>, Rows),
+    group_by: &[DynamicTypedTree],
+    aggregates: Vec<AggregateCall>,
+) -> Result<(Vec<ColumnDef>, Rows), QueryExecutionError> {
+    let (columns, rows) = source;
+    let evaluation = Evaluation;
+    let mut indexes = HashMap::new();
+    let mut groups = vec![];
+    for row in rows {
+        let row = row?;
+        let mut group_key = vec![];
+        for tree in group_by {
+            group_key.push(evaluation.eval_dynamic(tree, &columns, &row)?);
+        }
+        let index = match indexes.get(&group_key) {
+            Some(index) => *index,
+            None => {
+                indexes.insert(group_key, groups.len());
+                groups.push(Group::new(&aggregates, row.clone()));
+                groups.len() - 1
+            }
+        };
+        let group = &mut groups[index];
+        for (call, accumulator) in aggregates.iter().zip(group.accumulators.iter_mut()) {
+            let value = match &call.argument {
+                Some(argument) => evaluation.eval_dynamic(argument, &columns, &row)?,
+                None => ScalarValue::True,
+            };
+            accumulator.update(value)?;
+        }
+    }
+    // without grouping expressions the whole table is a single group even if it is empty
+    if groups.is_empty() && group_by.is_empty() {
+        groups.push(Group::new(&aggregates, vec![ScalarValue::Null; columns.len()]));
+    }
+
+    let rows = groups
+        .into_iter()
+        .map(|group| {
+            let mut row = group.row;
+            row.extend(group.accumulators.into_iter().map(Accumulator::finish));
+            Ok(row)
+        })
+        .collect::<Vec<Result<Row, QueryExecutionError>>>();
+    let columns = columns
+        .into_iter()
+        .chain(aggregates.into_iter().map(|call| call.column))
+        .collect();
+    Ok((columns, Box::new(rows.into_iter())))
+}
+
+struct Group {
+    /// values of columns are taken from the first row of the group
+    row: Row,
+    accumulators: Vec<Accumulator>,
+}
+
+impl Group {
+    fn new(calls: &[AggregateCall], row: Row) -> Group {
+        Group {
+            row,
+            accumulators: calls.iter().map(Accumulator::new).collect(),
+        }
+    }
+}
+
+enum State {
+    Count(i64),
+    /// `bigint`s are summed up exactly as their sum is numeric
+    Sum {
+        sum: Option<ScalarValue>,
+        exact: bool,
+    },
+    /// integers and numerics are also summed up exactly as their average is numeric
+    Avg {
+        sum: f64,
+        exact: Option<BigDecimal>,
+        count: i64,
+    },
+    Min(Option<ScalarValue>),
+    Max(Option<ScalarValue>),
+    JsonbAgg(Vec<Jsonb>),
+}
+
+/// `NULL`s are skipped by all aggregate functions except `jsonb_agg`
+/// which collects them as json `null`s
+struct Accumulator {
+    state: State,
+    seen: Option<HashSet<ScalarValue>>,
+}
+
+impl Accumulator {
+    fn new(call: &AggregateCall) -> Accumulator {
+        let state = match call.function {
+            AggregateFunction::Count => State::Count(0),
+            AggregateFunction::Sum => State::Sum {
+                sum: None,
+                exact: call.column.sql_type().family() == SqlTypeFamily::Numeric,
+            },
+            AggregateFunction::Avg => State::Avg {
+                sum: 0.0,
+                exact: None,
+                count: 0,
+            },
+            AggregateFunction::Min => State::Min(None),
+            AggregateFunction::Max => State::Max(None),
+            AggregateFunction::JsonbAgg => State::JsonbAgg(vec![]),
+        };
+        Accumulator {
+            state,
+            seen: if call.distinct { Some(HashSet::new()) } else { None },
+        }
+    }
+
+    fn update(&mut self, value: ScalarValue) -> Result<(), QueryExecutionError> {
+        if value == ScalarValue::Null && !matches!(self.state, State::JsonbAgg(_)) {
+            return Ok(());
+        }
+        if let Some(seen) = &mut self.seen {
+            if !seen.insert(value.clone()) {
+                return Ok(());
+            }
+        }
+        match &mut self.state {
+            State::Count(count) => *count += 1,
+            State::Sum { sum, exact } => {
+                *sum = Some(match sum.take() {
+                    None if *exact => numeric(&value).map(ScalarValue::Numeric).unwrap_or(value),
+                    None => integer(&value).map(ScalarValue::Int64).unwrap_or(value),
+                    Some(sum) => add(sum, value)?,
+                })
+            }
+            State::Avg { sum, exact, count } => {
+                *sum += float(&value).unwrap_or_default();
+                if let Some(value) = numeric(&value) {
+                    *exact = Some(exact.take().unwrap_or_default() + value);
+                }
+                *count += 1;
+            }
+            State::Min(min) => {
+                if min.as_ref().map(|min| sort::compare(&value, min)) != Some(Ordering::Greater) {
+                    *min = Some(value);
+                }
+            }
+            State::Max(max) => {
+                if max.as_ref().map(|max| sort::compare(&value, max)) != Some(Ordering::Less) {
+                    *max = Some(value);
+                }
+            }
+            State::JsonbAgg(elements) => elements.push(Jsonb::from(value)),
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> ScalarValue {
+        match self.state {
+            State::Count(count) => ScalarValue::Int64(count),
+            State::Avg { count: 0, .. } => ScalarValue::Null,
+            State::Avg {
+                exact: Some(sum),
+                count,
+                ..
+            } => Evaluation
+                .eval_dynamic(
+                    &DynamicTypedTree::Operation {
+                        left: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::Numeric(
+                            sum,
+                        )))),
+                        op: Operation::Arithmetic(Arithmetic::Div),
+                        right: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::BigInt(
+                            count,
+                        )))),
+                    },
+                    &[],
+                    &[],
+                )
+                .expect("count of averaged values is not zero"),
+            State::Avg { sum, count, .. } => ScalarValue::Float64(OrderedFloat(sum / count as f64)),
+            State::Sum { sum: value, .. } | State::Min(value) | State::Max(value) => value.unwrap_or(ScalarValue::Null),
+            State::JsonbAgg(elements) if elements.is_empty() => ScalarValue::Null,
+            State::JsonbAgg(elements) => ScalarValue::Jsonb(Jsonb::Array(elements)),
+        }
+    }
+}
+
+/// integers are summed up as `bigint`s, floats keep their precision and numerics are summed up exactly
+fn add(sum: ScalarValue, value: ScalarValue) -> Result<ScalarValue, QueryExecutionError> {
+    match (sum, value) {
+        (ScalarValue::Int64(sum), value) => match integer(&value).and_then(|value| sum.checked_add(value)) {
+            Some(sum) => Ok(ScalarValue::Int64(sum)),
+            None => Err(QueryExecutionError::OutOfRange(SqlType::big_int())),
+        },
+        (ScalarValue::Numeric(sum), value) => Ok(ScalarValue::Numeric(sum + numeric(&value).unwrap_or_default())),
+        (ScalarValue::Float32(sum), value) => Ok(ScalarValue::Float32(OrderedFloat(
+            sum.into_inner() + float(&value).unwrap_or_default() as f32,
+        ))),
+        (sum, value) => Ok(ScalarValue::Float64(OrderedFloat(
+            float(&sum).unwrap_or_default() + float(&value).unwrap_or_default(),
+        ))),
+    }
+}
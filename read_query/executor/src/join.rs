@@ -0,0 +1,203 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    sort::{float, integer},
+    Row, Rows,
+};
+use bigdecimal::ToPrimitive;
+use data_manipulation_evaluation::Evaluation;
+use data_manipulation_operators::JoinKind;
+use data_manipulation_query_result::QueryExecutionError;
+use data_manipulation_typed_tree::DynamicTypedTree;
+use data_scalar::ScalarValue;
+use definition::ColumnDef;
+use ordered_float::OrderedFloat;
+use read_query_plan::JoinStrategy;
+use std::collections::{HashMap, VecDeque};
+
+/// combines rows of `left` with rows of `right`, rows that have no match are
+/// padded with `NULL`s from the other side in outer joins; rows of `right` are
+/// read beforehand while rows of `left` are pulled one by one
+pub(crate) fn join(
+    left: (Vec<ColumnDef>, Rows),
+    right: (Vec<ColumnDef>, Rows),
+    kind: JoinKind,
+    condition: Option<DynamicTypedTree>,
+    strategy: JoinStrategy,
+) -> Result<(Vec<ColumnDef>, Rows), QueryExecutionError> {
+    let (left_columns, left_rows) = left;
+    let (right_columns, right_rows) = right;
+    let right_rows = right_rows.collect::<Result<Vec<Row>, QueryExecutionError>>()?;
+    let probe = match strategy {
+        JoinStrategy::NestedLoop => Probe::NestedLoop,
+        JoinStrategy::Hash { left_keys, right_keys } => {
+            let mut buckets = HashMap::<Vec<ScalarValue>, Vec<usize>>::new();
+            for (index, row) in right_rows.iter().enumerate() {
+                if let Some(key) = hash_key(&right_keys, &right_columns, row)? {
+                    buckets.entry(key).or_default().push(index);
+                }
+            }
+            Probe::Hash { left_keys, buckets }
+        }
+    };
+    let columns = left_columns
+        .iter()
+        .chain(right_columns.iter())
+        .cloned()
+        .collect::<Vec<ColumnDef>>();
+    let joined = Joined {
+        left_rows,
+        left_columns,
+        matched: vec![false; right_rows.len()],
+        right_rows,
+        columns: columns.clone(),
+        kind,
+        condition,
+        probe,
+        pending: VecDeque::new(),
+        left_exhausted: false,
+        next_right: 0,
+    };
+    Ok((columns, Box::new(joined)))
+}
+
+enum Probe {
+    NestedLoop,
+    Hash {
+        left_keys: Vec<DynamicTypedTree>,
+        buckets: HashMap<Vec<ScalarValue>, Vec<usize>>,
+    },
+}
+
+struct Joined {
+    left_rows: Rows,
+    left_columns: Vec<ColumnDef>,
+    right_rows: Vec<Row>,
+    /// right rows that were joined at least once
+    matched: Vec<bool>,
+    columns: Vec<ColumnDef>,
+    kind: JoinKind,
+    condition: Option<DynamicTypedTree>,
+    probe: Probe,
+    /// joined rows of the last pulled left row
+    pending: VecDeque<Row>,
+    left_exhausted: bool,
+    next_right: usize,
+}
+
+impl Joined {
+    fn join_left_row(&mut self, left_row: Row) -> Result<(), QueryExecutionError> {
+        let candidates = match &self.probe {
+            Probe::NestedLoop => (0..self.right_rows.len()).collect(),
+            Probe::Hash { left_keys, buckets } => match hash_key(left_keys, &self.left_columns, &left_row)? {
+                Some(key) => buckets.get(&key).cloned().unwrap_or_default(),
+                None => vec![],
+            },
+        };
+        let mut matched = false;
+        for index in candidates {
+            let row = left_row
+                .iter()
+                .chain(self.right_rows[index].iter())
+                .cloned()
+                .collect::<Row>();
+            let satisfies = match &self.condition {
+                None => true,
+                Some(condition) => Evaluation.eval_dynamic(condition, &self.columns, &row)? == ScalarValue::True,
+            };
+            if satisfies {
+                matched = true;
+                self.matched[index] = true;
+                self.pending.push_back(row);
+            }
+        }
+        if !matched && (self.kind == JoinKind::LeftOuter || self.kind == JoinKind::FullOuter) {
+            let mut row = left_row;
+            row.resize(self.columns.len(), ScalarValue::Null);
+            self.pending.push_back(row);
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for Joined {
+    type Item = Result<Row, QueryExecutionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.left_exhausted {
+            if let Some(row) = self.pending.pop_front() {
+                return Some(Ok(row));
+            }
+            match self.left_rows.next() {
+                Some(Ok(left_row)) => {
+                    if let Err(error) = self.join_left_row(left_row) {
+                        return Some(Err(error));
+                    }
+                }
+                Some(Err(error)) => return Some(Err(error)),
+                None => self.left_exhausted = true,
+            }
+        }
+        if self.kind != JoinKind::RightOuter && self.kind != JoinKind::FullOuter {
+            return None;
+        }
+        while self.next_right < self.right_rows.len() {
+            let index = self.next_right;
+            self.next_right += 1;
+            if !self.matched[index] {
+                let mut row = vec![ScalarValue::Null; self.left_columns.len()];
+                row.extend(self.right_rows[index].iter().cloned());
+                return Some(Ok(row));
+            }
+        }
+        None
+    }
+}
+
+/// values of join keys in a form that equal values of different numeric types
+/// or of dates and timestamps have the same hash, `None` if any of them is `NULL` as it can't be equal to anything
+fn hash_key(
+    keys: &[DynamicTypedTree],
+    columns: &[ColumnDef],
+    row: &[ScalarValue],
+) -> Result<Option<Vec<ScalarValue>>, QueryExecutionError> {
+    let evaluation = Evaluation;
+    let mut values = vec![];
+    for key in keys {
+        let value = evaluation.eval_dynamic(key, columns, row)?;
+        let value = match value {
+            ScalarValue::Null => return Ok(None),
+            ScalarValue::Int16(_) | ScalarValue::Int32(_) | ScalarValue::Int64(_) => {
+                integer(&value).map(ScalarValue::Int64).unwrap_or(value)
+            }
+            ScalarValue::Float32(_) | ScalarValue::Float64(_) => match float(&value) {
+                Some(float) if float.fract() == 0.0 && float.abs() < i64::MAX as f64 => {
+                    ScalarValue::Int64(float as i64)
+                }
+                Some(float) => ScalarValue::Float64(OrderedFloat(float)),
+                None => value,
+            },
+            ScalarValue::Numeric(number) if number.is_integer() && number.to_i64().is_some() => {
+                ScalarValue::Int64(number.to_i64().unwrap())
+            }
+            // dates are equal to timestamps at midnight
+            ScalarValue::Date(date) => ScalarValue::Timestamp(date.and_hms(0, 0, 0)),
+            ScalarValue::TimestampTz(timestamp) => ScalarValue::Timestamp(timestamp.naive_utc()),
+            value => value,
+        };
+        values.push(value);
+    }
+    Ok(Some(values))
+}
@@ -12,10 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use catalog::{Database, SqlTable};
+use catalog::{Database, Transaction};
+use data_manipulation_evaluation::Evaluation;
 use data_manipulation_query_result::{QueryExecution, QueryExecutionError};
-use read_query_plan::SelectPlan;
-use std::sync::Arc;
+use data_scalar::ScalarValue;
+use definition::ColumnDef;
+use read_query_plan::{OperatorStats, SelectPlan};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+mod aggregate;
+mod join;
+mod sort;
+
+pub(crate) type Row = Vec<ScalarValue>;
+
+/// rows pulled one by one from an operator
+pub(crate) type Rows = Box<dyn Iterator<Item = Result<Row, QueryExecutionError>>>;
 
 pub struct ReadQueryExecutor<D: Database> {
     database: Arc<D>,
@@ -26,19 +43,187 @@ impl<D: Database> ReadQueryExecutor<D> {
         ReadQueryExecutor { database }
     }
 
-    pub fn execute(&self, select: SelectPlan) -> Result<QueryExecution, QueryExecutionError> {
+    pub fn execute(
+        &self,
+        select: SelectPlan,
+        transaction: &Transaction,
+    ) -> Result<QueryExecution, QueryExecutionError> {
         log::debug!("PLAN {:?}", select);
-        if select.columns.is_empty() {
-            Ok(QueryExecution::Selected(
-                self.database.work_with(&select.table, |table| table.select()),
-            ))
-        } else {
-            self.database.work_with(&select.table, |table| {
-                match table.select_with_columns(select.columns.clone()) {
-                    Ok(data) => Ok(QueryExecution::Selected(data)),
-                    Err(column_name) => Err(QueryExecutionError::SchemaDoesNotExist(column_name)),
-                }
-            })
+        let (columns, rows) = self.open(select, transaction, None)?;
+        let rows = rows.collect::<Result<Vec<Row>, QueryExecutionError>>()?;
+        Ok(QueryExecution::Selected((columns, rows)))
+    }
+
+    /// executes the plan discarding its rows and counts what each operator did
+    pub fn analyze(&self, select: SelectPlan, transaction: &Transaction) -> Result<OperatorStats, QueryExecutionError> {
+        log::debug!("ANALYZE PLAN {:?}", select);
+        let counters = Rc::new(Counters::default());
+        let (_columns, rows) = self.open(select, transaction, Some(counters.clone()))?;
+        for row in rows {
+            row?;
+        }
+        Ok(counters.stats())
+    }
+
+    /// columns of rows produced by the operator and a stream of them, rows and time
+    /// are counted when the operator has `counters`
+    fn open(
+        &self,
+        plan: SelectPlan,
+        transaction: &Transaction,
+        counters: Option<Rc<Counters>>,
+    ) -> Result<(Vec<ColumnDef>, Rows), QueryExecutionError> {
+        match counters {
+            None => self.operator(plan, transaction, None),
+            Some(counters) => {
+                let start = Instant::now();
+                let (columns, rows) = self.operator(plan, transaction, Some(&counters))?;
+                counters.elapsed(start);
+                Ok((columns, Box::new(Counted { rows, counters })))
+            }
+        }
+    }
+
+    /// operators that need all rows of their sources, like sort or aggregation, consume them here
+    fn operator(
+        &self,
+        plan: SelectPlan,
+        transaction: &Transaction,
+        counters: Option<&Rc<Counters>>,
+    ) -> Result<(Vec<ColumnDef>, Rows), QueryExecutionError> {
+        match plan {
+            SelectPlan::Result => Ok((vec![], Box::new(std::iter::once(Ok(vec![]))))),
+            SelectPlan::Scan { table, relation } => {
+                let (columns, rows) = self.database.work_in(transaction, &table, |table| table.select(None))?;
+                let columns = match relation {
+                    None => columns,
+                    Some(relation) => columns
+                        .iter()
+                        .enumerate()
+                        .map(|(index, column)| column.qualified(&relation, index))
+                        .collect(),
+                };
+                Ok((columns, Box::new(rows.into_iter().map(Ok))))
+            }
+            SelectPlan::IndexScan {
+                table, index, range, ..
+            } => {
+                let (columns, rows) = self.database.work_in(transaction, &table, |table| {
+                    table.select_by_index(&index, range.clone())
+                })?;
+                Ok((columns, Box::new(rows.into_iter().map(Ok))))
+            }
+            SelectPlan::Filter { source, predicate } => {
+                let (columns, rows) = self.open(*source, transaction, counters.map(Counters::source))?;
+                let row_columns = columns.clone();
+                let rows = rows.filter_map(move |row| {
+                    let row = match row {
+                        Ok(row) => row,
+                        Err(error) => return Some(Err(error)),
+                    };
+                    match Evaluation.eval_dynamic(&predicate, &row_columns, &row) {
+                        Ok(ScalarValue::True) => Some(Ok(row)),
+                        Ok(_) => None,
+                        Err(error) => Some(Err(error.into())),
+                    }
+                });
+                Ok((columns, Box::new(rows)))
+            }
+            SelectPlan::Join {
+                left,
+                right,
+                kind,
+                condition,
+                strategy,
+            } => {
+                let left = self.open(*left, transaction, counters.map(Counters::source))?;
+                let right = self.open(*right, transaction, counters.map(Counters::source))?;
+                join::join(left, right, kind, condition, strategy)
+            }
+            SelectPlan::Aggregate {
+                source,
+                group_by,
+                aggregates,
+            } => aggregate::aggregate(
+                self.open(*source, transaction, counters.map(Counters::source))?,
+                &group_by,
+                aggregates,
+            ),
+            SelectPlan::Sort { source, keys, top } => {
+                let (columns, rows) = self.open(*source, transaction, counters.map(Counters::source))?;
+                let rows = sort::sort(rows, &columns, &keys, top)?;
+                Ok((columns, Box::new(rows.into_iter().map(Ok))))
+            }
+            SelectPlan::Limit { source, offset, limit } => {
+                let (columns, rows) = self.open(*source, transaction, counters.map(Counters::source))?;
+                // errors of skipped rows are not lost
+                let rows = rows
+                    .enumerate()
+                    .filter(move |(index, row)| *index >= offset || row.is_err())
+                    .map(|(_, row)| row)
+                    .take(limit.unwrap_or(usize::MAX));
+                Ok((columns, Box::new(rows)))
+            }
+            SelectPlan::Project { source, items, columns } => {
+                let (row_columns, rows) = self.open(*source, transaction, counters.map(Counters::source))?;
+                let rows = rows.map(move |row| {
+                    let row = row?;
+                    let mut projected = Vec::with_capacity(items.len());
+                    for item in items.iter() {
+                        projected.push(Evaluation.eval_dynamic(item, &row_columns, &row)?);
+                    }
+                    Ok(projected)
+                });
+                Ok((columns, Box::new(rows)))
+            }
+        }
+    }
+}
+
+/// rows produced by an operator and time spent in it, including time of its sources
+#[derive(Default)]
+struct Counters {
+    rows: Cell<usize>,
+    time: Cell<Duration>,
+    sources: RefCell<Vec<Rc<Counters>>>,
+}
+
+impl Counters {
+    /// counters of the next source of the operator
+    fn source(self: &Rc<Self>) -> Rc<Counters> {
+        let source = Rc::new(Counters::default());
+        self.sources.borrow_mut().push(source.clone());
+        source
+    }
+
+    fn elapsed(&self, start: Instant) {
+        self.time.set(self.time.get() + start.elapsed());
+    }
+
+    fn stats(&self) -> OperatorStats {
+        OperatorStats {
+            rows: self.rows.get(),
+            time: self.time.get(),
+            sources: self.sources.borrow().iter().map(|source| source.stats()).collect(),
+        }
+    }
+}
+
+struct Counted {
+    rows: Rows,
+    counters: Rc<Counters>,
+}
+
+impl Iterator for Counted {
+    type Item = Result<Row, QueryExecutionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = Instant::now();
+        let row = self.rows.next();
+        self.counters.elapsed(start);
+        if let Some(Ok(_)) = row {
+            self.counters.rows.set(self.counters.rows.get() + 1);
         }
+        row
     }
 }
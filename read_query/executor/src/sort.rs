@@ -0,0 +1,183 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Row, Rows};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use data_manipulation_evaluation::Evaluation;
+use data_manipulation_query_result::QueryExecutionError;
+use data_scalar::ScalarValue;
+use definition::ColumnDef;
+use read_query_plan::SortKey;
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+/// sorts `rows` by `order_by` keys, rows with equal keys keep their order;
+/// only `top` first rows are kept if it is specified
+pub(crate) fn sort(
+    rows: Rows,
+    columns: &[ColumnDef],
+    order_by: &[SortKey],
+    top: Option<usize>,
+) -> Result<Vec<Row>, QueryExecutionError> {
+    let evaluation = Evaluation;
+    let mut sorted = match top {
+        Some(top) => Sorted::TopN(BinaryHeap::new(), top),
+        None => Sorted::All(vec![]),
+    };
+    for (position, row) in rows.enumerate() {
+        let row = row?;
+        let mut keys = vec![];
+        for key in order_by {
+            keys.push(evaluation.eval_dynamic(&key.expr, columns, &row)?);
+        }
+        sorted.push(SortedRow {
+            keys,
+            position,
+            row,
+            order_by,
+        });
+    }
+    Ok(sorted.into_rows())
+}
+
+enum Sorted<'o> {
+    All(Vec<SortedRow<'o>>),
+    /// keeps the smallest rows, the largest one is on top of the heap
+    TopN(BinaryHeap<SortedRow<'o>>, usize),
+}
+
+impl<'o> Sorted<'o> {
+    fn push(&mut self, row: SortedRow<'o>) {
+        match self {
+            Sorted::All(rows) => rows.push(row),
+            Sorted::TopN(heap, top) => {
+                heap.push(row);
+                if heap.len() > *top {
+                    heap.pop();
+                }
+            }
+        }
+    }
+
+    fn into_rows(self) -> Vec<Row> {
+        let rows = match self {
+            Sorted::All(mut rows) => {
+                rows.sort();
+                rows
+            }
+            Sorted::TopN(heap, _) => heap.into_sorted_vec(),
+        };
+        rows.into_iter().map(|sorted| sorted.row).collect()
+    }
+}
+
+struct SortedRow<'o> {
+    keys: Vec<ScalarValue>,
+    position: usize,
+    row: Row,
+    order_by: &'o [SortKey],
+}
+
+impl<'o> Ord for SortedRow<'o> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for ((left, right), key) in self.keys.iter().zip(other.keys.iter()).zip(self.order_by.iter()) {
+            let ordering = match (left, right) {
+                (ScalarValue::Null, ScalarValue::Null) => Ordering::Equal,
+                (ScalarValue::Null, _) if key.nulls_first => Ordering::Less,
+                (ScalarValue::Null, _) => Ordering::Greater,
+                (_, ScalarValue::Null) if key.nulls_first => Ordering::Greater,
+                (_, ScalarValue::Null) => Ordering::Less,
+                (left, right) if key.ascending => compare(left, right),
+                (left, right) => compare(left, right).reverse(),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        self.position.cmp(&other.position)
+    }
+}
+
+impl<'o> PartialOrd for SortedRow<'o> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'o> PartialEq for SortedRow<'o> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'o> Eq for SortedRow<'o> {}
+
+pub(crate) fn compare(left: &ScalarValue, right: &ScalarValue) -> Ordering {
+    if let (Some(left), Some(right)) = (integer(left), integer(right)) {
+        return left.cmp(&right);
+    }
+    if let (Some(left), Some(right)) = (numeric(left), numeric(right)) {
+        return left.cmp(&right);
+    }
+    if let (Some(left), Some(right)) = (float(left), float(right)) {
+        // NaN is greater than any other value
+        return left
+            .partial_cmp(&right)
+            .unwrap_or_else(|| left.is_nan().cmp(&right.is_nan()));
+    }
+    match (left, right) {
+        (ScalarValue::String(left), ScalarValue::String(right)) => left.cmp(right),
+        (ScalarValue::Date(left), ScalarValue::Date(right)) => left.cmp(right),
+        (ScalarValue::Time(left), ScalarValue::Time(right)) => left.cmp(right),
+        (ScalarValue::Timestamp(left), ScalarValue::Timestamp(right)) => left.cmp(right),
+        (ScalarValue::TimestampTz(left), ScalarValue::TimestampTz(right)) => left.cmp(right),
+        (ScalarValue::Interval(left), ScalarValue::Interval(right)) => left.cmp(right),
+        (ScalarValue::Bytea(left), ScalarValue::Bytea(right)) => left.cmp(right),
+        (ScalarValue::Uuid(left), ScalarValue::Uuid(right)) => left.cmp(right),
+        (ScalarValue::Jsonb(left), ScalarValue::Jsonb(right)) => left.cmp(right),
+        (left, right) => boolean(left).cmp(&boolean(right)),
+    }
+}
+
+pub(crate) fn integer(value: &ScalarValue) -> Option<i64> {
+    match value {
+        ScalarValue::Int16(value) => Some(*value as i64),
+        ScalarValue::Int32(value) => Some(*value as i64),
+        ScalarValue::Int64(value) => Some(*value),
+        _ => None,
+    }
+}
+
+pub(crate) fn float(value: &ScalarValue) -> Option<f64> {
+    match value {
+        ScalarValue::Float32(value) => Some(value.into_inner() as f64),
+        ScalarValue::Float64(value) => Some(value.into_inner()),
+        ScalarValue::Numeric(value) => value.to_f64(),
+        value => integer(value).map(|value| value as f64),
+    }
+}
+
+pub(crate) fn numeric(value: &ScalarValue) -> Option<BigDecimal> {
+    match value {
+        ScalarValue::Numeric(value) => Some(value.clone()),
+        value => integer(value).map(BigDecimal::from),
+    }
+}
+
+fn boolean(value: &ScalarValue) -> Option<bool> {
+    match value {
+        ScalarValue::True => Some(true),
+        ScalarValue::False => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,356 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{JoinStrategy, SelectPlan, SortKey};
+use data_manipulation_operators::JoinKind;
+use std::time::Duration;
+
+/// how `EXPLAIN` presents a plan
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ExplainFormat {
+    /// indented tree of operators, a row per line
+    Text,
+    /// single row with a JSON document
+    Json,
+}
+
+/// rows produced by an operator and time spent in it together with its sources
+/// while the query was executed by `EXPLAIN ANALYZE`; `sources` are in the same
+/// order as sources of the operator in the plan
+#[derive(Debug, PartialEq, Default)]
+pub struct OperatorStats {
+    pub rows: usize,
+    pub time: Duration,
+    pub sources: Vec<OperatorStats>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Property {
+    Text(String),
+    List(Vec<String>),
+    Count(usize),
+}
+
+/// operator of a plan as it is shown by `EXPLAIN`
+#[derive(Debug, PartialEq)]
+pub struct ExplainNode {
+    pub node_type: &'static str,
+    pub join_type: Option<&'static str>,
+    pub index: Option<String>,
+    pub relation: Option<String>,
+    pub alias: Option<String>,
+    pub properties: Vec<(&'static str, Property)>,
+    pub sources: Vec<ExplainNode>,
+}
+
+impl ExplainNode {
+    fn new(node_type: &'static str, sources: Vec<ExplainNode>) -> ExplainNode {
+        ExplainNode {
+            node_type,
+            join_type: None,
+            index: None,
+            relation: None,
+            alias: None,
+            properties: vec![],
+            sources,
+        }
+    }
+
+    /// lines or a JSON document describing the plan, actual rows and time
+    /// of operators are shown when `stats` are given
+    pub fn render(&self, format: ExplainFormat, stats: Option<&OperatorStats>) -> Vec<String> {
+        match format {
+            ExplainFormat::Text => {
+                let mut lines = vec![];
+                self.text(stats, None, 2, &mut lines);
+                if let Some(stats) = stats {
+                    lines.push(format!("Execution Time: {:.3} ms", millis(stats.time)));
+                }
+                lines
+            }
+            ExplainFormat::Json => {
+                let mut json = "[\n  {\n    \"Plan\": ".to_owned();
+                self.json(stats, 4, &mut json);
+                if let Some(stats) = stats {
+                    json.push_str(&format!(",\n    \"Execution Time\": {:.3}", millis(stats.time)));
+                }
+                json.push_str("\n  }\n]");
+                vec![json]
+            }
+        }
+    }
+
+    /// operator names follow PostgreSQL, e.g. `Hash Left Join` or `Nested Loop`
+    fn title(&self) -> String {
+        let mut title = match (self.node_type, self.join_type) {
+            (_, None) | (_, Some("Inner")) => self.node_type.to_owned(),
+            ("Hash Join", Some(join_type)) => format!("Hash {} Join", join_type),
+            (node_type, Some(join_type)) => format!("{} {} Join", node_type, join_type),
+        };
+        if let Some(index) = &self.index {
+            title.push_str(" using ");
+            title.push_str(index);
+        }
+        if let Some(relation) = &self.relation {
+            title.push_str(" on ");
+            title.push_str(relation);
+        }
+        if let Some(alias) = &self.alias {
+            title.push(' ');
+            title.push_str(alias);
+        }
+        title
+    }
+
+    /// `arrow` is a position of `->` that points to operator of a source,
+    /// properties and sources of the operator are placed at `indent`
+    fn text(&self, stats: Option<&OperatorStats>, arrow: Option<usize>, indent: usize, lines: &mut Vec<String>) {
+        let mut line = match arrow {
+            None => self.title(),
+            Some(arrow) => format!("{}->  {}", " ".repeat(arrow), self.title()),
+        };
+        if let Some(stats) = stats {
+            line.push_str(&format!(
+                "  (actual rows={} time={:.3} ms)",
+                stats.rows,
+                millis(stats.time)
+            ));
+        }
+        lines.push(line);
+        for (name, property) in self.properties.iter() {
+            let value = match property {
+                Property::Text(text) => text.clone(),
+                Property::List(items) => items.join(", "),
+                Property::Count(count) => count.to_string(),
+            };
+            lines.push(format!("{}{}: {}", " ".repeat(indent), name, value));
+        }
+        for (index, source) in self.sources.iter().enumerate() {
+            let source_stats = stats.and_then(|stats| stats.sources.get(index));
+            source.text(source_stats, Some(indent), indent + 6, lines);
+        }
+    }
+
+    /// object that starts at the current position of `json` and ends at `indent`
+    fn json(&self, stats: Option<&OperatorStats>, indent: usize, json: &mut String) {
+        let mut fields = vec![("Node Type", quoted(self.node_type))];
+        if let Some(join_type) = self.join_type {
+            fields.push(("Join Type", quoted(join_type)));
+        }
+        if let Some(index) = &self.index {
+            fields.push(("Index Name", quoted(index)));
+        }
+        if let Some(relation) = &self.relation {
+            fields.push(("Relation Name", quoted(relation)));
+        }
+        if let Some(alias) = &self.alias {
+            fields.push(("Alias", quoted(alias)));
+        }
+        for (name, property) in self.properties.iter() {
+            let value = match property {
+                Property::Text(text) => quoted(text),
+                Property::List(items) => format!(
+                    "[{}]",
+                    items
+                        .iter()
+                        .map(|item| quoted(item))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                Property::Count(count) => count.to_string(),
+            };
+            fields.push((name, value));
+        }
+        if let Some(stats) = stats {
+            fields.push(("Actual Rows", stats.rows.to_string()));
+            fields.push(("Actual Total Time", format!("{:.3}", millis(stats.time))));
+        }
+        let padding = " ".repeat(indent + 2);
+        json.push('{');
+        for (index, (name, value)) in fields.into_iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str(&format!("\n{}{}: {}", padding, quoted(name), value));
+        }
+        if !self.sources.is_empty() {
+            json.push_str(&format!(",\n{}\"Plans\": [", padding));
+            for (index, source) in self.sources.iter().enumerate() {
+                if index > 0 {
+                    json.push(',');
+                }
+                json.push_str(&format!("\n{}  ", padding));
+                let source_stats = stats.and_then(|stats| stats.sources.get(index));
+                source.json(source_stats, indent + 4, json);
+            }
+            json.push_str(&format!("\n{}]", padding));
+        }
+        json.push_str(&format!("\n{}}}", " ".repeat(indent)));
+    }
+}
+
+impl SelectPlan {
+    /// operators of the plan with their properties
+    pub fn explain(&self) -> ExplainNode {
+        match self {
+            SelectPlan::Result => ExplainNode::new("Result", vec![]),
+            SelectPlan::Scan { table, relation } => {
+                let mut node = ExplainNode::new("Seq Scan", vec![]);
+                node.relation = Some(table.to_string());
+                node.alias = relation.clone().filter(|relation| relation != table.table());
+                node
+            }
+            SelectPlan::IndexScan {
+                table,
+                index,
+                condition,
+                ..
+            } => {
+                let mut node = ExplainNode::new("Index Scan", vec![]);
+                node.index = Some(index.clone());
+                node.relation = Some(table.to_string());
+                node.properties
+                    .push(("Index Cond", Property::Text(condition.to_string())));
+                node
+            }
+            SelectPlan::Filter { source, predicate } => {
+                let mut node = ExplainNode::new("Filter", vec![source.explain()]);
+                node.properties.push(("Filter", Property::Text(predicate.to_string())));
+                node
+            }
+            SelectPlan::Join {
+                left,
+                right,
+                kind,
+                condition,
+                strategy,
+            } => {
+                let node_type = match strategy {
+                    JoinStrategy::NestedLoop => "Nested Loop",
+                    JoinStrategy::Hash { .. } => "Hash Join",
+                };
+                let mut node = ExplainNode::new(node_type, vec![left.explain(), right.explain()]);
+                node.join_type = Some(match kind {
+                    JoinKind::Inner | JoinKind::Cross => "Inner",
+                    JoinKind::LeftOuter => "Left",
+                    JoinKind::RightOuter => "Right",
+                    JoinKind::FullOuter => "Full",
+                });
+                if let JoinStrategy::Hash { left_keys, right_keys } = strategy {
+                    let keys = left_keys
+                        .iter()
+                        .zip(right_keys.iter())
+                        .map(|(left, right)| format!("({} = {})", left, right))
+                        .collect::<Vec<String>>();
+                    node.properties.push(("Hash Cond", Property::Text(keys.join(" AND "))));
+                }
+                if let Some(condition) = condition {
+                    node.properties
+                        .push(("Join Filter", Property::Text(condition.to_string())));
+                }
+                node
+            }
+            SelectPlan::Aggregate {
+                source,
+                group_by,
+                aggregates,
+            } => {
+                let node_type = if group_by.is_empty() {
+                    "Aggregate"
+                } else {
+                    "HashAggregate"
+                };
+                let mut node = ExplainNode::new(node_type, vec![source.explain()]);
+                if !group_by.is_empty() {
+                    let keys = group_by.iter().map(ToString::to_string).collect();
+                    node.properties.push(("Group Key", Property::List(keys)));
+                }
+                let calls = aggregates
+                    .iter()
+                    .map(|call| {
+                        let mut text = format!("{}(", call.function);
+                        if call.distinct {
+                            text.push_str("DISTINCT ");
+                        }
+                        match &call.argument {
+                            None => text.push('*'),
+                            Some(argument) => text.push_str(&argument.to_string()),
+                        }
+                        format!("{}) AS {}", text, call.column.name())
+                    })
+                    .collect();
+                node.properties.push(("Aggregates", Property::List(calls)));
+                node
+            }
+            SelectPlan::Sort { source, keys, top } => {
+                let mut node = ExplainNode::new("Sort", vec![source.explain()]);
+                node.properties
+                    .push(("Sort Key", Property::List(keys.iter().map(sort_key).collect())));
+                if let Some(top) = top {
+                    node.properties.push(("Top Rows", Property::Count(*top)));
+                }
+                node
+            }
+            SelectPlan::Limit { source, offset, limit } => {
+                let mut node = ExplainNode::new("Limit", vec![source.explain()]);
+                if *offset > 0 {
+                    node.properties.push(("Offset", Property::Count(*offset)));
+                }
+                if let Some(limit) = limit {
+                    node.properties.push(("Count", Property::Count(*limit)));
+                }
+                node
+            }
+            SelectPlan::Project { source, items, .. } => {
+                let mut node = ExplainNode::new("Project", vec![source.explain()]);
+                node.properties.push((
+                    "Output",
+                    Property::List(items.iter().map(ToString::to_string).collect()),
+                ));
+                node
+            }
+        }
+    }
+}
+
+/// `ASC NULLS LAST` and `DESC NULLS FIRST` are defaults and are not shown
+fn sort_key(key: &SortKey) -> String {
+    match (key.ascending, key.nulls_first) {
+        (true, false) => key.expr.to_string(),
+        (true, true) => format!("{} NULLS FIRST", key.expr),
+        (false, true) => format!("{} DESC", key.expr),
+        (false, false) => format!("{} DESC NULLS LAST", key.expr),
+    }
+}
+
+fn millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1000.0
+}
+
+fn quoted(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
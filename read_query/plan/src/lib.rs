@@ -12,10 +12,105 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use definition::FullTableName;
+use data_manipulation_operators::{AggregateFunction, JoinKind};
+use data_manipulation_typed_tree::DynamicTypedTree;
+use data_scalar::ScalarValue;
+use definition::{ColumnDef, FullTableName};
+pub use explain::{ExplainFormat, ExplainNode, OperatorStats, Property};
+use std::ops::Bound;
 
+mod explain;
+
+/// rows are sorted by value of `expr`, nulls are placed according to `nulls_first`
+/// regardless of the direction
+#[derive(Debug)]
+pub struct SortKey {
+    pub expr: DynamicTypedTree,
+    pub ascending: bool,
+    pub nulls_first: bool,
+}
+
+/// physical operator that combines rows of a joined table with rows of preceding tables
+#[derive(Debug, PartialEq)]
+pub enum JoinStrategy {
+    /// every pair of rows is checked against join condition
+    NestedLoop,
+    /// rows of the joined table are hashed by values of `right_keys`
+    /// and looked up by values of `left_keys` of preceding rows
+    Hash {
+        left_keys: Vec<DynamicTypedTree>,
+        right_keys: Vec<DynamicTypedTree>,
+    },
+}
+
+/// aggregate function call computed over a group of rows, trees evaluated
+/// above `Aggregate` operator reference its value as `column`
 #[derive(Debug)]
-pub struct SelectPlan {
-    pub table: FullTableName,
-    pub columns: Vec<String>,
+pub struct AggregateCall {
+    pub function: AggregateFunction,
+    pub argument: Option<DynamicTypedTree>,
+    pub distinct: bool,
+    pub column: ColumnDef,
 }
+
+/// tree of physical operators, each of them pulls rows from its sources
+#[derive(Debug)]
+pub enum SelectPlan {
+    /// single row without columns that a query without `FROM` clause is evaluated against
+    Result,
+    /// rows of a table, columns are qualified with `relation` when several tables are read
+    Scan {
+        table: FullTableName,
+        relation: Option<String>,
+    },
+    /// rows of a table found by `index` whose leading column is within `range`
+    /// derived from `condition`, rows have to be checked against the condition
+    IndexScan {
+        table: FullTableName,
+        index: String,
+        range: (Bound<ScalarValue>, Bound<ScalarValue>),
+        condition: DynamicTypedTree,
+    },
+    /// rows that `predicate` evaluates to `TRUE`
+    Filter {
+        source: Box<SelectPlan>,
+        predicate: DynamicTypedTree,
+    },
+    /// pairs of rows from `left` and `right` that satisfy `condition`
+    Join {
+        left: Box<SelectPlan>,
+        right: Box<SelectPlan>,
+        kind: JoinKind,
+        /// part of join condition that is not covered by hash keys
+        condition: Option<DynamicTypedTree>,
+        strategy: JoinStrategy,
+    },
+    /// rows grouped by values of `group_by`, each group is reduced into its first row
+    /// followed by values of `aggregates`; without grouping expressions all rows
+    /// form a single group even if there are none of them
+    Aggregate {
+        source: Box<SelectPlan>,
+        group_by: Vec<DynamicTypedTree>,
+        aggregates: Vec<AggregateCall>,
+    },
+    /// only `top` first rows are kept if it is specified
+    Sort {
+        source: Box<SelectPlan>,
+        keys: Vec<SortKey>,
+        top: Option<usize>,
+    },
+    Limit {
+        source: Box<SelectPlan>,
+        offset: usize,
+        limit: Option<usize>,
+    },
+    /// rows of `items` values described by `columns`
+    Project {
+        source: Box<SelectPlan>,
+        items: Vec<DynamicTypedTree>,
+        columns: Vec<ColumnDef>,
+    },
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,189 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use data_manipulation_operators::{Comparison, Operation};
+use data_manipulation_typed_tree::{DynamicTypedItem, TypedValue};
+use std::time::Duration;
+
+fn column(name: &str) -> DynamicTypedTree {
+    DynamicTypedTree::Item(DynamicTypedItem::Column(name.to_owned()))
+}
+
+fn scan(table: &str, relation: Option<&str>) -> SelectPlan {
+    SelectPlan::Scan {
+        table: FullTableName::from((&"schema_name", &table)),
+        relation: relation.map(ToOwned::to_owned),
+    }
+}
+
+fn filtered_and_sorted() -> SelectPlan {
+    SelectPlan::Limit {
+        source: Box::new(SelectPlan::Sort {
+            source: Box::new(SelectPlan::Filter {
+                source: Box::new(scan("table_name", None)),
+                predicate: DynamicTypedTree::Operation {
+                    left: Box::new(column("col1")),
+                    op: Operation::Comparison(Comparison::Gt),
+                    right: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::SmallInt(1)))),
+                },
+            }),
+            keys: vec![SortKey {
+                expr: column("col1"),
+                ascending: false,
+                nulls_first: true,
+            }],
+            top: Some(2),
+        }),
+        offset: 0,
+        limit: Some(2),
+    }
+}
+
+fn stats(rows: usize, micros: u64, sources: Vec<OperatorStats>) -> OperatorStats {
+    OperatorStats {
+        rows,
+        time: Duration::from_micros(micros),
+        sources,
+    }
+}
+
+#[test]
+fn text() {
+    assert_eq!(
+        filtered_and_sorted().explain().render(ExplainFormat::Text, None),
+        vec![
+            "Limit",
+            "  Count: 2",
+            "  ->  Sort",
+            "        Sort Key: col1 DESC",
+            "        Top Rows: 2",
+            "        ->  Filter",
+            "              Filter: (col1 > 1)",
+            "              ->  Seq Scan on schema_name.table_name",
+        ]
+    );
+}
+
+#[test]
+fn analyzed_text() {
+    let stats = stats(
+        2,
+        250,
+        vec![stats(2, 200, vec![stats(3, 150, vec![stats(4, 100, vec![])])])],
+    );
+    assert_eq!(
+        filtered_and_sorted()
+            .explain()
+            .render(ExplainFormat::Text, Some(&stats)),
+        vec![
+            "Limit  (actual rows=2 time=0.250 ms)",
+            "  Count: 2",
+            "  ->  Sort  (actual rows=2 time=0.200 ms)",
+            "        Sort Key: col1 DESC",
+            "        Top Rows: 2",
+            "        ->  Filter  (actual rows=3 time=0.150 ms)",
+            "              Filter: (col1 > 1)",
+            "              ->  Seq Scan on schema_name.table_name  (actual rows=4 time=0.100 ms)",
+            "Execution Time: 0.250 ms",
+        ]
+    );
+}
+
+#[test]
+fn joins() {
+    let plan = SelectPlan::Join {
+        left: Box::new(scan("customers", Some("c"))),
+        right: Box::new(scan("orders", Some("orders"))),
+        kind: JoinKind::LeftOuter,
+        condition: None,
+        strategy: JoinStrategy::Hash {
+            left_keys: vec![column("c.id")],
+            right_keys: vec![column("orders.customer_id")],
+        },
+    };
+    assert_eq!(
+        plan.explain().render(ExplainFormat::Text, None),
+        vec![
+            "Hash Left Join",
+            "  Hash Cond: (c.id = orders.customer_id)",
+            "  ->  Seq Scan on schema_name.customers c",
+            "  ->  Seq Scan on schema_name.orders",
+        ]
+    );
+}
+
+#[test]
+fn json() {
+    let plan = SelectPlan::Limit {
+        source: Box::new(scan("table_name", None)),
+        offset: 1,
+        limit: None,
+    };
+    assert_eq!(
+        plan.explain()
+            .render(ExplainFormat::Json, Some(&stats(1, 20, vec![stats(2, 10, vec![])]))),
+        vec![
+            r#"[
+  {
+    "Plan": {
+      "Node Type": "Limit",
+      "Offset": 1,
+      "Actual Rows": 1,
+      "Actual Total Time": 0.020,
+      "Plans": [
+        {
+          "Node Type": "Seq Scan",
+          "Relation Name": "schema_name.table_name",
+          "Actual Rows": 2,
+          "Actual Total Time": 0.010
+        }
+      ]
+    },
+    "Execution Time": 0.020
+  }
+]"#
+        ]
+    );
+}
+
+#[test]
+fn index_scan() {
+    let condition = DynamicTypedTree::Operation {
+        left: Box::new(column("col1")),
+        op: Operation::Comparison(Comparison::Eq),
+        right: Box::new(DynamicTypedTree::Item(DynamicTypedItem::Const(TypedValue::SmallInt(1)))),
+    };
+    let plan = SelectPlan::Filter {
+        source: Box::new(SelectPlan::IndexScan {
+            table: FullTableName::from((&"schema_name", &"table_name")),
+            index: "idx".to_owned(),
+            range: (
+                Bound::Included(ScalarValue::Int16(1)),
+                Bound::Included(ScalarValue::Int16(1)),
+            ),
+            condition: condition.clone(),
+        }),
+        predicate: condition,
+    };
+    assert_eq!(
+        plan.explain().render(ExplainFormat::Text, None),
+        vec![
+            "Filter",
+            "  Filter: (col1 = 1)",
+            "  ->  Index Scan using idx on schema_name.table_name",
+            "        Index Cond: (col1 = 1)",
+        ]
+    );
+}
@@ -12,33 +12,459 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use catalog::Database;
-use data_manipulation_typed_queries::TypedSelectQuery;
+use catalog::CatalogDefinition;
+use data_manipulation_evaluation::Evaluation;
+use data_manipulation_operators::{Comparison, Logical, Operation};
+use data_manipulation_typed_queries::{Join, TypedSelectQuery};
 use data_manipulation_typed_tree::{DynamicTypedItem, DynamicTypedTree};
-use read_query_plan::SelectPlan;
-use std::sync::Arc;
+use data_scalar::ScalarValue;
+use definition::{ColumnDef, FullTableName};
+use query_processing_type_check::TypeChecker;
+use read_query_plan::{AggregateCall, JoinStrategy, SelectPlan, SortKey};
+use std::{ops::Bound, sync::Arc};
+use types::{Num, SqlType, SqlTypeFamily, Temporal};
 
-pub struct ReadQueryPlanner<D: Database> {
-    #[allow(dead_code)]
+type ScalarRange = (Bound<ScalarValue>, Bound<ScalarValue>);
+
+pub struct ReadQueryPlanner<D: CatalogDefinition> {
     database: Arc<D>,
 }
 
-impl<D: Database> ReadQueryPlanner<D> {
+impl<D: CatalogDefinition> ReadQueryPlanner<D> {
     pub fn new(database: Arc<D>) -> ReadQueryPlanner<D> {
         ReadQueryPlanner { database }
     }
 
+    /// rows flow from table scans through joins, filter, aggregation, sorting and limit into projection
     pub fn plan(&self, select: TypedSelectQuery) -> SelectPlan {
-        SelectPlan {
-            table: select.full_table_name,
-            columns: select
-                .projection_items
-                .into_iter()
-                .map(|item| match item {
-                    DynamicTypedTree::Item(DynamicTypedItem::Column(name)) => name,
-                    _ => unimplemented!(),
+        let TypedSelectQuery {
+            full_table_name,
+            relation,
+            joins,
+            projection_items,
+            projection_columns,
+            filter,
+            group_by,
+            having,
+            order_by,
+            limit,
+            offset,
+        } = select;
+        // columns are qualified with names of their tables only when several tables are read
+        let qualified = !joins.is_empty();
+        let (mut columns, mut plan) = match full_table_name {
+            None => (vec![], SelectPlan::Result),
+            Some(full_table_name) => {
+                let columns = if qualified {
+                    self.relation_columns(&full_table_name, &relation, 0)
+                } else {
+                    self.table_columns(&full_table_name)
+                };
+                let index_scan = match &filter {
+                    Some(predicate) if !qualified => self.index_scan(&full_table_name, predicate, &columns),
+                    _ => None,
+                };
+                let plan = match index_scan {
+                    Some(index_scan) => index_scan,
+                    None => SelectPlan::Scan {
+                        table: full_table_name,
+                        relation: if qualified { Some(relation) } else { None },
+                    },
+                };
+                (columns, plan)
+            }
+        };
+        for join in joins {
+            let joined_columns = self.relation_columns(&join.full_table_name, &join.relation, columns.len());
+            plan = join_plan(plan, join, &columns, &joined_columns);
+            columns.extend(joined_columns);
+        }
+        if let Some(predicate) = filter {
+            plan = SelectPlan::Filter {
+                source: Box::new(plan),
+                predicate,
+            };
+        }
+
+        let aggregated = !group_by.is_empty()
+            || having.is_some()
+            || projection_items.iter().any(DynamicTypedTree::has_aggregates)
+            || order_by.iter().any(|order_by| order_by.expr.has_aggregates());
+        let mut projection_items = projection_items;
+        let mut sort_keys = order_by
+            .into_iter()
+            .map(|order_by| SortKey {
+                expr: order_by.expr,
+                ascending: order_by.ascending,
+                nulls_first: order_by.nulls_first,
+            })
+            .collect::<Vec<SortKey>>();
+        if aggregated {
+            let mut calls = vec![];
+            for tree in projection_items
+                .iter()
+                .chain(having.iter())
+                .chain(sort_keys.iter().map(|key| &key.expr))
+            {
+                collect_calls(tree, &mut calls);
+            }
+            let type_checker = TypeChecker;
+            let aggregates = calls
+                .iter()
+                .enumerate()
+                .map(|(index, call)| match call {
+                    DynamicTypedTree::Aggregate {
+                        function,
+                        argument,
+                        distinct,
+                    } => AggregateCall {
+                        function: *function,
+                        argument: argument.as_deref().cloned(),
+                        distinct: *distinct,
+                        column: ColumnDef::new(
+                            format!("{}#{}", function, index),
+                            type_checker
+                                .type_family(call, &columns)
+                                .ok()
+                                .flatten()
+                                .map(|type_family| type_family.sql_type())
+                                .unwrap_or_else(|| SqlType::var_char(u64::MAX)),
+                            columns.len() + index,
+                        ),
+                    },
+                    _ => unreachable!(),
                 })
-                .collect(),
+                .collect::<Vec<AggregateCall>>();
+            projection_items = projection_items
+                .into_iter()
+                .map(|tree| replace_aggregates(tree, &calls, &aggregates))
+                .collect();
+            for key in sort_keys.iter_mut() {
+                key.expr = replace_aggregates(key.expr.clone(), &calls, &aggregates);
+            }
+            let having = having.map(|having| replace_aggregates(having, &calls, &aggregates));
+            plan = SelectPlan::Aggregate {
+                source: Box::new(plan),
+                group_by,
+                aggregates,
+            };
+            if let Some(predicate) = having {
+                plan = SelectPlan::Filter {
+                    source: Box::new(plan),
+                    predicate,
+                };
+            }
+        }
+
+        let offset = offset.unwrap_or_default();
+        if !sort_keys.is_empty() {
+            plan = SelectPlan::Sort {
+                source: Box::new(plan),
+                keys: sort_keys,
+                top: limit.map(|limit| offset.saturating_add(limit)),
+            };
+        }
+        if offset > 0 || limit.is_some() {
+            plan = SelectPlan::Limit {
+                source: Box::new(plan),
+                offset,
+                limit,
+            };
+        }
+        SelectPlan::Project {
+            source: Box::new(plan),
+            items: projection_items,
+            columns: projection_columns,
+        }
+    }
+
+    /// rows are looked up in an index when a conjunct of `predicate` compares its leading
+    /// column with a constant, equalities are preferred over ranges
+    fn index_scan(
+        &self,
+        full_table_name: &FullTableName,
+        predicate: &DynamicTypedTree,
+        columns: &[ColumnDef],
+    ) -> Option<SelectPlan> {
+        let indexes = self.database.table_indexes(full_table_name);
+        if indexes.is_empty() {
+            return None;
+        }
+        let mut conjuncts = vec![];
+        split_conjunction(predicate.clone(), &mut conjuncts);
+        let mut candidates = conjuncts
+            .into_iter()
+            .filter_map(|conjunct| {
+                let (column, range) = index_range(&conjunct, columns)?;
+                let index = indexes
+                    .iter()
+                    .find(|index| index.columns().first().map(String::as_str) == Some(column))?;
+                Some((index.name().to_owned(), range, conjunct))
+            })
+            .collect::<Vec<(String, ScalarRange, DynamicTypedTree)>>();
+        if candidates.is_empty() {
+            return None;
+        }
+        let position = candidates
+            .iter()
+            .position(|(_index, range, _conjunct)| matches!(range, (Bound::Included(_), Bound::Included(_))))
+            .unwrap_or_default();
+        let (index, range, condition) = candidates.swap_remove(position);
+        Some(SelectPlan::IndexScan {
+            table: FullTableName::from((&full_table_name.schema(), &full_table_name.table())),
+            index,
+            range,
+            condition,
+        })
+    }
+
+    fn table_columns(&self, full_table_name: &FullTableName) -> Vec<ColumnDef> {
+        match self.database.table_definition(full_table_name) {
+            Some(Some(table_info)) => table_info.columns().to_vec(),
+            _ => vec![],
+        }
+    }
+
+    fn relation_columns(&self, full_table_name: &FullTableName, relation: &str, offset: usize) -> Vec<ColumnDef> {
+        self.table_columns(full_table_name)
+            .iter()
+            .enumerate()
+            .map(|(index, column)| column.qualified(relation, offset + index))
+            .collect()
+    }
+}
+
+/// equalities between expressions over preceding tables and expressions over
+/// the joined table are used as hash keys, a join without them is done by nested loops
+fn join_plan(left: SelectPlan, join: Join, left_columns: &[ColumnDef], right_columns: &[ColumnDef]) -> SelectPlan {
+    let Join {
+        kind,
+        full_table_name,
+        relation,
+        condition,
+    } = join;
+    let mut conjuncts = vec![];
+    if let Some(condition) = condition {
+        split_conjunction(condition, &mut conjuncts);
+    }
+    let mut left_keys = vec![];
+    let mut right_keys = vec![];
+    let mut rest = vec![];
+    for conjunct in conjuncts {
+        match conjunct {
+            DynamicTypedTree::Operation {
+                left,
+                op: Operation::Comparison(Comparison::Eq),
+                right,
+            } => {
+                if refers_only(&left, left_columns) && refers_only(&right, right_columns) {
+                    left_keys.push(*left);
+                    right_keys.push(*right);
+                } else if refers_only(&right, left_columns) && refers_only(&left, right_columns) {
+                    left_keys.push(*right);
+                    right_keys.push(*left);
+                } else {
+                    rest.push(DynamicTypedTree::Operation {
+                        left,
+                        op: Operation::Comparison(Comparison::Eq),
+                        right,
+                    });
+                }
+            }
+            conjunct => rest.push(conjunct),
+        }
+    }
+    let condition = rest.into_iter().fold(None, |condition, conjunct| match condition {
+        None => Some(conjunct),
+        Some(condition) => Some(DynamicTypedTree::Operation {
+            left: Box::new(condition),
+            op: Operation::Logical(Logical::And),
+            right: Box::new(conjunct),
+        }),
+    });
+    let strategy = if left_keys.is_empty() {
+        JoinStrategy::NestedLoop
+    } else {
+        JoinStrategy::Hash { left_keys, right_keys }
+    };
+    SelectPlan::Join {
+        left: Box::new(left),
+        right: Box::new(SelectPlan::Scan {
+            table: full_table_name,
+            relation: Some(relation),
+        }),
+        kind,
+        condition,
+        strategy,
+    }
+}
+
+/// column compared by `conjunct` with a constant and range of values that satisfy it;
+/// index keeps values as they are stored, constants are used only if they are compared
+/// with stored values in the same way
+fn index_range<'c>(conjunct: &DynamicTypedTree, columns: &'c [ColumnDef]) -> Option<(&'c str, ScalarRange)> {
+    let (left, comparison, right) = match conjunct {
+        DynamicTypedTree::Operation {
+            left,
+            op: Operation::Comparison(comparison),
+            right,
+        } => (&**left, *comparison, &**right),
+        _ => return None,
+    };
+    let (name, constant, comparison) = match (left, right) {
+        (DynamicTypedTree::Item(DynamicTypedItem::Column(name)), constant) if is_constant(constant) => {
+            (name, constant, comparison)
+        }
+        (constant, DynamicTypedTree::Item(DynamicTypedItem::Column(name))) if is_constant(constant) => {
+            let comparison = match comparison {
+                Comparison::Lt => Comparison::Gt,
+                Comparison::LtEq => Comparison::GtEq,
+                Comparison::Gt => Comparison::Lt,
+                Comparison::GtEq => Comparison::LtEq,
+                comparison => comparison,
+            };
+            (name, constant, comparison)
+        }
+        _ => return None,
+    };
+    let column = columns.iter().find(|column| column.has_name(name))?;
+    let value = Evaluation.eval_dynamic(constant, &[], &[]).ok()?;
+    let comparable = match (column.sql_type(), &value) {
+        (sql_type, ScalarValue::Int16(_)) | (sql_type, ScalarValue::Int32(_)) | (sql_type, ScalarValue::Int64(_)) => {
+            sql_type.family().is_int()
+        }
+        (SqlType::Num(Num::Real), ScalarValue::Float32(_)) => true,
+        (SqlType::Num(Num::Double), ScalarValue::Float64(_)) => true,
+        (SqlType::Num(Num::Numeric { .. }), ScalarValue::Numeric(_)) => true,
+        (sql_type, ScalarValue::String(_)) => sql_type.family() == SqlTypeFamily::String,
+        (SqlType::Bool, ScalarValue::True) | (SqlType::Bool, ScalarValue::False) => comparison == Comparison::Eq,
+        (SqlType::Temporal(Temporal::Date), ScalarValue::Date(_))
+        | (SqlType::Temporal(Temporal::Time), ScalarValue::Time(_))
+        | (SqlType::Temporal(Temporal::Timestamp), ScalarValue::Timestamp(_))
+        | (SqlType::Temporal(Temporal::TimestampTz), ScalarValue::TimestampTz(_))
+        | (SqlType::Temporal(Temporal::Interval), ScalarValue::Interval(_))
+        | (SqlType::Bytea, ScalarValue::Bytea(_))
+        | (SqlType::Uuid, ScalarValue::Uuid(_))
+        | (SqlType::Jsonb, ScalarValue::Jsonb(_)) => true,
+        _ => false,
+    };
+    if !comparable {
+        return None;
+    }
+    let range = match comparison {
+        Comparison::Eq => (Bound::Included(value.clone()), Bound::Included(value)),
+        Comparison::Lt => (Bound::Unbounded, Bound::Excluded(value)),
+        Comparison::LtEq => (Bound::Unbounded, Bound::Included(value)),
+        Comparison::Gt => (Bound::Excluded(value), Bound::Unbounded),
+        Comparison::GtEq => (Bound::Included(value), Bound::Unbounded),
+        Comparison::NotEq => return None,
+    };
+    Some((column.name(), range))
+}
+
+/// whether `tree` could be evaluated without rows
+fn is_constant(tree: &DynamicTypedTree) -> bool {
+    match tree {
+        DynamicTypedTree::Item(DynamicTypedItem::Const(_)) => true,
+        DynamicTypedTree::Item(DynamicTypedItem::Column(_)) => false,
+        DynamicTypedTree::Operation { left, right, .. } => is_constant(left) && is_constant(right),
+        DynamicTypedTree::UnaryOperation { item, .. } => is_constant(item),
+        DynamicTypedTree::Cast { tree, .. } => is_constant(tree),
+        DynamicTypedTree::Function { function, args } => !function.is_volatile() && args.iter().all(is_constant),
+        DynamicTypedTree::Aggregate { .. } => false,
+    }
+}
+
+fn split_conjunction(tree: DynamicTypedTree, conjuncts: &mut Vec<DynamicTypedTree>) {
+    match tree {
+        DynamicTypedTree::Operation {
+            left,
+            op: Operation::Logical(Logical::And),
+            right,
+        } => {
+            split_conjunction(*left, conjuncts);
+            split_conjunction(*right, conjuncts);
         }
+        tree => conjuncts.push(tree),
+    }
+}
+
+/// whether `tree` references at least one column and all of them are in `columns`
+fn refers_only(tree: &DynamicTypedTree, columns: &[ColumnDef]) -> bool {
+    fn visit(tree: &DynamicTypedTree, columns: &[ColumnDef], found: &mut bool) -> bool {
+        match tree {
+            DynamicTypedTree::Item(DynamicTypedItem::Column(name)) => {
+                *found = true;
+                columns.iter().any(|column| column.has_name(name))
+            }
+            DynamicTypedTree::Item(DynamicTypedItem::Const(_)) => true,
+            DynamicTypedTree::Operation { left, right, .. } => {
+                visit(left, columns, found) && visit(right, columns, found)
+            }
+            DynamicTypedTree::UnaryOperation { item, .. } => visit(item, columns, found),
+            DynamicTypedTree::Cast { tree, .. } => visit(tree, columns, found),
+            DynamicTypedTree::Function { args, .. } => args.iter().all(|arg| visit(arg, columns, found)),
+            DynamicTypedTree::Aggregate { .. } => false,
+        }
+    }
+    let mut found = false;
+    visit(tree, columns, &mut found) && found
+}
+
+/// the same aggregate calls are computed only once
+fn collect_calls(tree: &DynamicTypedTree, calls: &mut Vec<DynamicTypedTree>) {
+    match tree {
+        DynamicTypedTree::Operation { left, right, .. } => {
+            collect_calls(left, calls);
+            collect_calls(right, calls);
+        }
+        DynamicTypedTree::UnaryOperation { item, .. } => collect_calls(item, calls),
+        DynamicTypedTree::Cast { tree, .. } => collect_calls(tree, calls),
+        DynamicTypedTree::Function { args, .. } => {
+            for arg in args {
+                collect_calls(arg, calls);
+            }
+        }
+        DynamicTypedTree::Item(_) => {}
+        DynamicTypedTree::Aggregate { .. } => {
+            if !calls.contains(tree) {
+                calls.push(tree.clone());
+            }
+        }
+    }
+}
+
+/// aggregate calls are replaced with references to their values computed by `Aggregate` operator
+fn replace_aggregates(
+    tree: DynamicTypedTree,
+    calls: &[DynamicTypedTree],
+    aggregates: &[AggregateCall],
+) -> DynamicTypedTree {
+    match tree {
+        DynamicTypedTree::Operation { left, op, right } => DynamicTypedTree::Operation {
+            left: Box::new(replace_aggregates(*left, calls, aggregates)),
+            op,
+            right: Box::new(replace_aggregates(*right, calls, aggregates)),
+        },
+        DynamicTypedTree::UnaryOperation { op, item } => DynamicTypedTree::UnaryOperation {
+            op,
+            item: Box::new(replace_aggregates(*item, calls, aggregates)),
+        },
+        DynamicTypedTree::Cast { tree, target } => DynamicTypedTree::Cast {
+            tree: Box::new(replace_aggregates(*tree, calls, aggregates)),
+            target,
+        },
+        DynamicTypedTree::Function { function, args } => DynamicTypedTree::Function {
+            function,
+            args: args
+                .into_iter()
+                .map(|arg| replace_aggregates(arg, calls, aggregates))
+                .collect(),
+        },
+        DynamicTypedTree::Item(item) => DynamicTypedTree::Item(item),
+        call @ DynamicTypedTree::Aggregate { .. } => match calls.iter().position(|known| known == &call) {
+            Some(index) => DynamicTypedTree::Item(DynamicTypedItem::Column(aggregates[index].column.name().to_owned())),
+            None => call,
+        },
     }
 }
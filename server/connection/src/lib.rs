@@ -17,7 +17,11 @@ use async_native_tls::TlsStream;
 use blocking::Unblock;
 use byteorder::{ByteOrder, NetworkEndian};
 use futures_lite::{future::block_on, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
-use pg_model::{results::QueryResult, Command, ConnSupervisor, Encryption, ProtocolConfiguration};
+use pg_model::{
+    pg_types::PgType,
+    results::{QueryEvent, QueryResult},
+    Command, ConnSupervisor, Encryption, ProtocolConfiguration,
+};
 use pg_wire::{
     BackendMessage, ConnId, Error, FrontendMessage, HandShakeProcess, HandShakeRequest, HandShakeStatus,
     MessageDecoder, MessageDecoderStatus, Result,
@@ -277,7 +281,10 @@ impl<RW: AsyncRead + AsyncWrite + Unpin> Receiver for RequestReceiver<RW> {
             } => Ok(Ok(Command::Parse {
                 statement_name,
                 sql,
-                param_types,
+                param_types: param_types
+                    .into_iter()
+                    .map(|param_type| param_type.map(PgType::from))
+                    .collect(),
             })),
             FrontendMessage::Query { sql } => Ok(Ok(Command::Query { sql })),
             FrontendMessage::Terminate => Ok(Ok(Command::Terminate)),
@@ -330,15 +337,25 @@ impl<RW: AsyncRead + AsyncWrite + Unpin> Sender for ResponseSender<RW> {
 
     fn send(&self, query_result: QueryResult) -> io::Result<()> {
         block_on(async {
-            let message: BackendMessage = match query_result {
-                Ok(event) => event.into(),
-                Err(error) => error.into(),
+            let message = match query_result {
+                Ok(QueryEvent::QueryComplete(transaction_status)) => {
+                    log::debug!("ready for query in {:?} status", transaction_status);
+                    transaction_status.ready_for_query()
+                }
+                Ok(event) => {
+                    log::debug!("response event {:?}", event);
+                    event.as_vec()
+                }
+                Err(error) => {
+                    let message: BackendMessage = error.into();
+                    log::debug!("response message {:?}", message);
+                    message.as_vec()
+                }
             };
-            log::debug!("response message {:?}", message);
             self.channel
                 .lock()
                 .await
-                .write_all(message.as_vec().as_slice())
+                .write_all(message.as_slice())
                 .await
                 .expect("OK");
             log::trace!("end of the command is sent");
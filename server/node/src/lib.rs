@@ -18,7 +18,7 @@ use crate::query_engine::QueryEngine;
 use async_dup::Arc as AsyncArc;
 use async_executor::Executor;
 use async_io::Async;
-use catalog::InMemoryDatabase;
+use catalog::{CatalogDefinition, Database, InMemoryDatabase, OnDiskDatabase};
 use connection::ClientRequest;
 use pg_model::{ConnSupervisor, ProtocolConfiguration};
 use std::{
@@ -34,11 +34,9 @@ const HOST: [u8; 4] = [0, 0, 0, 0];
 const MIN_CONN_ID: i32 = 1;
 const MAX_CONN_ID: i32 = 1 << 16;
 
-pub fn start() {
-    let _root_path = env::var("ROOT_PATH").map(PathBuf::from).unwrap_or_default();
-
-    static GLOBAL: Executor<'_> = Executor::new();
+static GLOBAL: Executor<'_> = Executor::new();
 
+pub fn start() {
     std::thread::Builder::new()
         .name("main-executor".to_owned())
         .spawn(|| loop {
@@ -46,8 +44,19 @@ pub fn start() {
         })
         .expect("cannot spawn executor thread");
 
+    if persistent() {
+        let root_path = root_path();
+        log::info!("data is stored in {:?}", root_path);
+        let database = OnDiskDatabase::new(&root_path).expect("to open database on disk");
+        serve(database);
+    } else {
+        log::info!("data is stored in memory");
+        serve(InMemoryDatabase::new());
+    }
+}
+
+fn serve<D: 'static + Database + CatalogDefinition>(database: Arc<D>) {
     async_io::block_on(async {
-        let database = InMemoryDatabase::new();
         let listener = Async::<TcpListener>::bind((HOST, PORT)).expect("OK");
 
         let config = protocol_configuration();
@@ -93,6 +102,20 @@ pub fn start() {
     });
 }
 
+fn persistent() -> bool {
+    match env::var("PERSISTENT") {
+        Ok(s) => matches!(s.to_lowercase().as_str(), "1" | "true"),
+        _ => false,
+    }
+}
+
+fn root_path() -> PathBuf {
+    match env::var("ROOT_PATH") {
+        Ok(path) => PathBuf::from(path),
+        _ => env::current_dir().unwrap().join("root_directory"),
+    }
+}
+
 fn pfx_certificate_path() -> PathBuf {
     let file = env::var("PFX_CERTIFICATE_FILE").unwrap();
     let path = Path::new(&file);
@@ -13,31 +13,42 @@
 // limitations under the License.
 
 use bigdecimal::BigDecimal;
-use catalog::{CatalogDefinition, Database};
+use catalog::{CatalogDefinition, Database, Transaction};
 use connection::Sender;
+use data_definition_execution_plan::SchemaChange;
 use data_definition_operations::{ExecutionError, ExecutionOutcome};
 use data_manipulation_query_result::{QueryExecution, QueryExecutionError};
-use data_manipulation_typed_queries::{DeleteQuery, InsertQuery, TypedSelectQuery, TypedWrite, UpdateQuery};
-use data_manipulation_typed_tree::{DynamicTypedTree, StaticTypedTree};
-use data_manipulation_untyped_queries::UntypedWrite;
+use data_manipulation_typed_queries::{
+    DeleteQuery, InsertQuery, Join, OrderBy, TypedSelectQuery, TypedWrite, UpdateQuery,
+};
+use data_manipulation_typed_tree::{DynamicTypedItem, DynamicTypedTree, StaticTypedTree};
+use data_manipulation_untyped_queries::{SelectQuery, UntypedWrite};
+use data_manipulation_untyped_tree::{DynamicUntypedTree, ImplicitCastError};
+use definition::{ColumnDef, FullTableName};
 use itertools::izip;
+use parser::{AlterColumnType, ExplainWithOptions};
 use pg_model::{
-    results::{QueryError, QueryEvent},
+    pg_types::{ColumnMetadata, PgType},
+    results::{QueryError, QueryEvent, TransactionStatus},
     session::Session,
     statement::PreparedStatement,
     Command,
 };
-use pg_wire::{ColumnMetadata, PgFormat, PgType};
+use pg_wire::PgFormat;
 use query_analyzer::{AnalysisError, Analyzer, QueryAnalysis};
-use query_processing_type_check::TypeChecker;
+use query_processing_type_check::{TypeCheckError, TypeChecker};
 use query_processing_type_coercion::TypeCoercion;
 use query_processing_type_inference::TypeInference;
 use read_query_executor::ReadQueryExecutor;
+use read_query_plan::{ExplainFormat, SelectPlan};
 use read_query_planner::ReadQueryPlanner;
 use schema_planner::SystemSchemaPlanner;
-use sql_ast::{Expr, Ident, Statement, Value};
-use std::{convert::TryFrom, iter, sync::Arc};
-use types::SqlType;
+use sql_ast::{
+    AlterTableOperation, Assignment, ColumnOption, Expr, Ident, JoinConstraint, JoinOperator, SelectItem, SetExpr,
+    Statement, UnaryOperator, Value, Values,
+};
+use std::{collections::HashMap, convert::TryFrom, iter, sync::Arc};
+use types::{SqlType, SqlTypeFamily};
 use write_query_executor::WriteQueryExecutor;
 
 unsafe impl<D: Database + CatalogDefinition> Send for QueryEngine<D> {}
@@ -56,6 +67,8 @@ pub(crate) struct QueryEngine<D: Database + CatalogDefinition> {
     read_query_planner: ReadQueryPlanner<D>,
     read_query_executor: ReadQueryExecutor<D>,
     database: Arc<D>,
+    transaction: Option<Transaction>,
+    transaction_status: TransactionStatus,
 }
 
 impl<D: Database + CatalogDefinition> QueryEngine<D> {
@@ -72,6 +85,8 @@ impl<D: Database + CatalogDefinition> QueryEngine<D> {
             read_query_planner: ReadQueryPlanner::new(database.clone()),
             read_query_executor: ReadQueryExecutor::new(database.clone()),
             database,
+            transaction: None,
+            transaction_status: TransactionStatus::Idle,
         }
     }
 
@@ -94,9 +109,8 @@ impl<D: Database + CatalogDefinition> QueryEngine<D> {
                                 actual = raw_params.len(),
                                 expected = param_types.len()
                             );
-                            self.sender
-                                .send(Err(QueryError::protocol_violation(message)))
-                                .expect("To Send Error to Client");
+                            self.send_error(QueryError::protocol_violation(message));
+                            return Ok(());
                         }
                         match self.bind_prepared_statement(
                             &prepared_statement,
@@ -115,20 +129,21 @@ impl<D: Database + CatalogDefinition> QueryEngine<D> {
                                     .send(Ok(QueryEvent::BindComplete))
                                     .expect("To Send Bind Complete Event");
                             }
-                            Err(error) => log::error!("{:?}", error),
+                            Err(error) => {
+                                log::error!("{:?}", error);
+                                self.fail_transaction();
+                            }
                         }
                     }
                     None => {
-                        self.sender
-                            .send(Err(QueryError::prepared_statement_does_not_exist(statement_name)))
-                            .expect("To Send Error to Client");
+                        self.send_error(QueryError::prepared_statement_does_not_exist(statement_name));
                     }
                 }
                 Ok(())
             }
             Command::Continue => {
                 self.sender
-                    .send(Ok(QueryEvent::QueryComplete))
+                    .send(Ok(QueryEvent::QueryComplete(self.transaction_status)))
                     .expect("To Send Query Complete to Client");
                 Ok(())
             }
@@ -143,9 +158,7 @@ impl<D: Database + CatalogDefinition> QueryEngine<D> {
                             .expect("To Send Statement Description to Client");
                     }
                     None => {
-                        self.sender
-                            .send(Err(QueryError::prepared_statement_does_not_exist(name)))
-                            .expect("To Send Error to Client");
+                        self.send_error(QueryError::prepared_statement_does_not_exist(name));
                     }
                 }
                 Ok(())
@@ -153,16 +166,17 @@ impl<D: Database + CatalogDefinition> QueryEngine<D> {
             Command::DescribePortal { name } => {
                 match self.session.get_portal(&name) {
                     None => {
-                        self.sender
-                            .send(Err(QueryError::portal_does_not_exist(name)))
-                            .expect("To Send Error to Client");
+                        self.send_error(QueryError::portal_does_not_exist(name));
                     }
-                    Some(_portal) => {
-                        log::debug!("DESCRIBING PORTAL START");
+                    Some(portal) => {
+                        let description = self
+                            .session
+                            .get_prepared_statement(portal.stmt_name())
+                            .map(|stmt| stmt.description().to_vec())
+                            .unwrap_or_default();
                         self.sender
-                            .send(Ok(QueryEvent::StatementDescription(vec![])))
+                            .send(Ok(QueryEvent::StatementDescription(description)))
                             .expect("To Send Statement Description to Client");
-                        log::debug!("DESCRIBING PORTAL END");
                     }
                 }
                 Ok(())
@@ -174,14 +188,11 @@ impl<D: Database + CatalogDefinition> QueryEngine<D> {
             } => {
                 match self.session.get_portal(&portal_name) {
                     Some(portal) => {
-                        self.sender
-                            .send(Err(QueryError::syntax_error(portal.stmt())))
-                            .expect("To Send Error to Client");
+                        let statement = portal.stmt().clone();
+                        self.process_statement(statement, false);
                     }
                     None => {
-                        self.sender
-                            .send(Err(QueryError::portal_does_not_exist(portal_name)))
-                            .expect("To Send Error to Client");
+                        self.send_error(QueryError::portal_does_not_exist(portal_name));
                     }
                 }
                 Ok(())
@@ -195,369 +206,862 @@ impl<D: Database + CatalogDefinition> QueryEngine<D> {
                 sql,
                 param_types,
             } => {
-                match parser::Parser::parse_sql(&parser::PreparedStatementDialect, &sql) {
-                    Ok(mut statements) => {
-                        let statement = statements.pop().expect("single statement");
-                        match self.create_prepared_statement(statement_name, statement, param_types) {
-                            Ok(()) => {
-                                self.sender.send(Ok(QueryEvent::ParseComplete)).expect("To Send Result");
-                            }
-                            Err(error) => self.sender.send(Err(error)).expect("To Send Result"),
+                match parser::parse_prepared_statement(&sql) {
+                    Ok(statement) => match self.create_prepared_statement(statement_name, statement, param_types) {
+                        Ok(()) => {
+                            self.sender.send(Ok(QueryEvent::ParseComplete)).expect("To Send Result");
                         }
-                    }
+                        Err(error) => self.send_error(error),
+                    },
                     Err(parser_error) => {
-                        self.sender
-                            .send(Err(QueryError::syntax_error(parser_error)))
-                            .expect("To Send Syntax Error Event");
+                        self.send_error(QueryError::syntax_error(parser_error));
                     }
                 }
                 Ok(())
             }
             Command::Query { sql } => {
-                match parser::Parser::parse_sql(&parser::PreparedStatementDialect, &sql) {
-                    Ok(mut statements) => match statements.pop().expect("single query") {
-                        Statement::Prepare {
-                            name,
-                            data_types,
-                            statement,
-                        } => {
-                            let Ident { value: name, .. } = name;
-                            let mut pg_types = vec![];
-                            for data_type in data_types {
-                                match SqlType::try_from(&data_type) {
-                                    Ok(sql_type) => pg_types.push(Some((&sql_type).into())),
-                                    Err(_) => {
-                                        self.sender
-                                            .send(Err(QueryError::type_does_not_exist(data_type)))
-                                            .expect("To Send Error to Client");
-                                        self.sender
-                                            .send(Ok(QueryEvent::QueryComplete))
-                                            .expect("To Send Error to Client");
-                                        return Ok(());
-                                    }
-                                }
-                            }
-                            match self.create_prepared_statement(name, *statement, pg_types) {
-                                Ok(()) => {
-                                    self.sender
-                                        .send(Ok(QueryEvent::StatementPrepared))
-                                        .expect("To Send Result");
-                                }
-                                Err(error) => {
-                                    self.sender.send(Err(error)).expect("To Send Result");
-                                    self.sender
-                                        .send(Ok(QueryEvent::QueryComplete))
-                                        .expect("To Send Error to Client");
-                                    return Ok(());
-                                }
-                            }
+                if let Some(statement) = parser::parse_alter_column_type(&sql) {
+                    match statement {
+                        Ok(statement) => self.alter_column_type(statement),
+                        Err(parser_error) => self.send_error(QueryError::syntax_error(parser_error)),
+                    }
+                    return self.query_complete();
+                }
+                if let Some(explain) = parser::parse_explain_with_options(&sql) {
+                    match explain {
+                        Ok(explain) => self.explain_with_options(explain),
+                        Err(parser_error) => self.send_error(QueryError::syntax_error(parser_error)),
+                    }
+                    return self.query_complete();
+                }
+                match parser::parse_sql(&sql) {
+                    Ok(mut statements) => self.process_statement(statements.pop().expect("single query"), true),
+                    Err(parser_error) => {
+                        self.send_error(QueryError::syntax_error(parser_error));
+                    }
+                }
+                self.query_complete()
+            }
+            Command::Terminate => {
+                log::debug!("closing connection with client");
+                Err(())
+            }
+        }
+    }
+
+    fn process_statement(&mut self, statement: Statement, describe: bool) {
+        if self.transaction_status == TransactionStatus::Failed {
+            match statement {
+                Statement::Commit { .. } | Statement::Rollback { .. } => {}
+                _ => {
+                    self.send_error(QueryError::in_failed_sql_transaction());
+                    return;
+                }
+            }
+        }
+        match statement {
+            Statement::Prepare {
+                name,
+                data_types,
+                statement,
+            } => {
+                let Ident { value: name, .. } = name;
+                let mut pg_types = vec![];
+                for data_type in data_types {
+                    match SqlType::try_from(&data_type) {
+                        Ok(sql_type) => pg_types.push(Some((&sql_type).into())),
+                        Err(_) => {
+                            self.send_error(QueryError::type_does_not_exist(data_type));
+                            return;
                         }
-                        Statement::Execute { name, parameters } => {
-                            let Ident { value: name, .. } = name;
-                            match self.session.get_prepared_statement(&name) {
-                                Some(prepared_statement) => {
-                                    let param_types = prepared_statement.param_types();
-                                    if param_types.len() != parameters.len() {
-                                        let message = format!(
-                                            "Bind message supplies {actual} parameters, but prepared statement \"{name}\" requires {expected}",
-                                            name = name,
-                                            actual = parameters.len(),
-                                            expected = param_types.len()
-                                        );
-                                        self.sender
-                                            .send(Err(QueryError::protocol_violation(message)))
-                                            .expect("To Send Error to Client");
-                                    }
-                                    self.sender
-                                        .send(Err(QueryError::syntax_error(prepared_statement.stmt())))
-                                        .expect("To Send Error to Client");
-                                }
+                    }
+                }
+                match self.create_prepared_statement(name, *statement, pg_types) {
+                    Ok(()) => {
+                        self.sender
+                            .send(Ok(QueryEvent::StatementPrepared))
+                            .expect("To Send Result");
+                    }
+                    Err(error) => self.send_error(error),
+                }
+            }
+            Statement::Execute { name, parameters } => {
+                let Ident { value: name, .. } = name;
+                match self.session.get_prepared_statement(&name) {
+                    Some(prepared_statement) => {
+                        let param_types = prepared_statement.param_types();
+                        if param_types.len() != parameters.len() {
+                            let message = format!(
+                                "Bind message supplies {actual} parameters, but prepared statement \"{name}\" requires {expected}",
+                                name = name,
+                                actual = parameters.len(),
+                                expected = param_types.len()
+                            );
+                            self.send_error(QueryError::protocol_violation(message));
+                            return;
+                        }
+                        // arguments are bound as text parameters of the extended query protocol
+                        // so they are decoded into values of the declared types the same way
+                        let mut raw_params = vec![];
+                        for parameter in parameters.iter() {
+                            match param_text(parameter) {
+                                Some(raw_param) => raw_params.push(raw_param),
                                 None => {
-                                    self.sender
-                                        .send(Err(QueryError::prepared_statement_does_not_exist(name)))
-                                        .expect("To Send Error to Client");
+                                    self.send_error(QueryError::feature_not_supported(format!(
+                                        "{} as argument of EXECUTE",
+                                        parameter
+                                    )));
+                                    return;
                                 }
                             }
                         }
-                        Statement::Deallocate { name, .. } => {
-                            let Ident { value: name, .. } = name;
-                            self.session.remove_prepared_statement(&name);
-                            self.sender
-                                .send(Ok(QueryEvent::StatementDeallocated))
-                                .expect("To Send Statement Deallocated Event");
+                        match self.bind_prepared_statement(&prepared_statement, &[], &raw_params, &[]) {
+                            Ok((statement, _result_formats)) => self.process_statement(statement, describe),
+                            Err(()) => self.fail_transaction(),
                         }
-                        statement @ Statement::CreateSchema { .. }
-                        | statement @ Statement::CreateTable { .. }
-                        | statement @ Statement::Drop { .. } => match self.query_analyzer.analyze(statement) {
-                            Ok(QueryAnalysis::DataDefinition(schema_change)) => {
-                                log::debug!("SCHEMA CHANGE - {:?}", schema_change);
-                                let operations = self.system_planner.schema_change_plan(&schema_change);
-                                let query_result = match self.database.execute(operations) {
-                                    Ok(ExecutionOutcome::SchemaCreated) => Ok(QueryEvent::SchemaCreated),
-                                    Ok(ExecutionOutcome::SchemaDropped) => Ok(QueryEvent::SchemaDropped),
-                                    Ok(ExecutionOutcome::TableCreated) => Ok(QueryEvent::TableCreated),
-                                    Ok(ExecutionOutcome::TableDropped) => Ok(QueryEvent::TableDropped),
-                                    Err(ExecutionError::SchemaAlreadyExists(schema_name)) => {
-                                        Err(QueryError::schema_already_exists(schema_name))
-                                    }
-                                    Err(ExecutionError::SchemaDoesNotExist(schema_name)) => {
-                                        Err(QueryError::schema_does_not_exist(schema_name))
-                                    }
-                                    Err(ExecutionError::TableAlreadyExists(schema_name, table_name)) => Err(
-                                        QueryError::table_already_exists(format!("{}.{}", schema_name, table_name)),
-                                    ),
-                                    Err(ExecutionError::TableDoesNotExist(schema_name, table_name)) => Err(
-                                        QueryError::table_does_not_exist(format!("{}.{}", schema_name, table_name)),
-                                    ),
-                                    Err(ExecutionError::SchemaHasDependentObjects(schema_name)) => {
-                                        Err(QueryError::schema_has_dependent_objects(schema_name))
-                                    }
-                                };
-                                self.sender.send(query_result).expect("To Send Result to Client");
-                            }
-                            Err(AnalysisError::SchemaDoesNotExist(schema_name)) => self
-                                .sender
-                                .send(Err(QueryError::schema_does_not_exist(schema_name)))
-                                .expect("To Send Result to Client"),
-                            analysis => unreachable!("that couldn't happen {:?}", analysis),
-                        },
-                        statement @ Statement::Insert { .. }
-                        | statement @ Statement::Update { .. }
-                        | statement @ Statement::Delete { .. }
-                        | statement @ Statement::Query(_) => match self.query_analyzer.analyze(statement) {
-                            Ok(QueryAnalysis::Write(UntypedWrite::Delete(delete))) => {
-                                match self.write_query_executor.execute(TypedWrite::Delete(DeleteQuery {
-                                    full_table_name: delete.full_table_name,
-                                })) {
-                                    Ok(QueryExecution::Deleted(deleted)) => {
-                                        self.sender
-                                            .send(Ok(QueryEvent::RecordsDeleted(deleted)))
-                                            .expect("To Send to client");
-                                    }
-                                    Ok(_) => unimplemented!(),
-                                    Err(QueryExecutionError::SchemaDoesNotExist(schema_name)) => {
-                                        self.sender
-                                            .send(Err(QueryError::schema_does_not_exist(schema_name)))
-                                            .expect("To Send to client");
-                                    }
-                                    Err(_) => unimplemented!(),
-                                }
-                            }
-                            Ok(QueryAnalysis::Write(UntypedWrite::Update(update))) => {
-                                let typed_values = update
-                                    .assignments
-                                    .into_iter()
-                                    .map(|value| self.type_inference.infer_dynamic(value))
-                                    .collect::<Vec<DynamicTypedTree>>();
-                                log::debug!("UPDATE TYPED VALUES - {:?}", typed_values);
-                                let type_checked = typed_values
-                                    .into_iter()
-                                    .map(|value| self.type_checker.check_dynamic(value))
-                                    .collect::<Vec<DynamicTypedTree>>();
-                                log::debug!("UPDATE TYPE CHECKED VALUES - {:?}", type_checked);
-                                let type_coerced = type_checked
-                                    .into_iter()
-                                    .map(|value| self.type_coercion.coerce_dynamic(value))
-                                    .collect::<Vec<DynamicTypedTree>>();
-                                log::debug!("UPDATE TYPE COERCED VALUES - {:?}", type_coerced);
-                                match self.write_query_executor.execute(TypedWrite::Update(UpdateQuery {
-                                    full_table_name: update.full_table_name,
-                                    column_names: update.column_names,
-                                    assignments: type_coerced,
-                                })) {
-                                    Ok(QueryExecution::Updated(updated)) => {
-                                        self.sender
-                                            .send(Ok(QueryEvent::RecordsUpdated(updated)))
-                                            .expect("To Send to client");
-                                    }
-                                    Ok(_) => unimplemented!(),
-                                    Err(QueryExecutionError::SchemaDoesNotExist(schema_name)) => {
-                                        self.sender
-                                            .send(Err(QueryError::schema_does_not_exist(schema_name)))
-                                            .expect("To Send to client");
-                                    }
-                                    Err(_) => unimplemented!(),
-                                }
-                            }
-                            Ok(QueryAnalysis::Write(UntypedWrite::Insert(insert))) => {
-                                log::debug!("INSERT UNTYPED VALUES {:?}", insert.values);
-                                let typed_values = insert
-                                    .values
-                                    .into_iter()
-                                    .map(|values| {
-                                        values
-                                            .into_iter()
-                                            .map(|value| value.map(|v| self.type_inference.infer_static(v)))
-                                            .collect()
-                                    })
-                                    .collect::<Vec<Vec<Option<StaticTypedTree>>>>();
-                                log::debug!("INSERT TYPED VALUES {:?}", typed_values);
-                                let type_checked = typed_values
-                                    .into_iter()
-                                    .map(|values| {
-                                        values
-                                            .into_iter()
-                                            .map(|value| value.map(|v| self.type_checker.check_static(v)))
-                                            .collect()
-                                    })
-                                    .collect::<Vec<Vec<Option<StaticTypedTree>>>>();
-                                log::debug!("INSERT TYPE CHECKED VALUES {:?}", type_checked);
-                                let table_info = self
-                                    .database
-                                    .table_definition(&insert.full_table_name)
-                                    .unwrap()
-                                    .unwrap();
-                                let table_columns = table_info.columns();
-                                let mut type_coerced = vec![];
-                                for checked in type_checked {
-                                    let mut row = vec![];
-                                    for (index, c) in checked.into_iter().enumerate() {
-                                        row.push(c.map(|c| {
-                                            self.type_coercion.coerce_static(c, table_columns[index].sql_type())
-                                        }));
-                                    }
-                                    type_coerced.push(row);
-                                }
-                                log::debug!("INSERT TYPE COERCED VALUES {:?}", type_coerced);
-                                match self.write_query_executor.execute(TypedWrite::Insert(InsertQuery {
-                                    full_table_name: insert.full_table_name,
-                                    values: type_coerced,
-                                })) {
-                                    Ok(QueryExecution::Inserted(inserted)) => {
-                                        self.sender
-                                            .send(Ok(QueryEvent::RecordsInserted(inserted)))
-                                            .expect("To Send to client");
-                                    }
-                                    Ok(_) => unimplemented!(),
-                                    Err(QueryExecutionError::SchemaDoesNotExist(schema_name)) => {
-                                        self.sender
-                                            .send(Err(QueryError::schema_does_not_exist(schema_name)))
-                                            .expect("To Send to client");
-                                    }
-                                    Err(QueryExecutionError::ColumnNotFound(column_name)) => {
-                                        self.sender
-                                            .send(Err(QueryError::column_does_not_exist(column_name)))
-                                            .expect("To Send to client");
-                                    }
+                    }
+                    None => {
+                        self.send_error(QueryError::prepared_statement_does_not_exist(name));
+                    }
+                }
+            }
+            Statement::Deallocate { name, .. } => {
+                let Ident { value: name, .. } = name;
+                self.session.remove_prepared_statement(&name);
+                self.sender
+                    .send(Ok(QueryEvent::StatementDeallocated))
+                    .expect("To Send Statement Deallocated Event");
+            }
+            // schema changes are applied to the catalog right away and can't be rolled back
+            statement @ Statement::CreateSchema { .. }
+            | statement @ Statement::CreateTable { .. }
+            | statement @ Statement::CreateIndex { .. }
+            | statement @ Statement::Drop { .. }
+            | statement @ Statement::AlterTable { .. }
+                if self.transaction_status == TransactionStatus::InTransaction =>
+            {
+                self.send_error(QueryError::active_sql_transaction(command_name(&statement)))
+            }
+            statement @ Statement::CreateSchema { .. }
+            | statement @ Statement::CreateTable { .. }
+            | statement @ Statement::CreateIndex { .. }
+            | statement @ Statement::Drop { .. }
+            | statement @ Statement::AlterTable { .. } => match self.query_analyzer.analyze(statement.clone()) {
+                Ok(QueryAnalysis::DataDefinition(schema_change)) => {
+                    log::debug!("SCHEMA CHANGE - {:?}", schema_change);
+                    match self.execute_schema_change(&schema_change) {
+                        Ok(event) => {
+                            if let Err(errors) = self.fill_added_column(&statement) {
+                                for error in errors {
+                                    self.send_error(error);
                                 }
+                                return;
                             }
-                            Ok(QueryAnalysis::Read(select)) => {
-                                log::debug!("SELECT UNTYPED VALUES - {:?}", select.projection_items);
-                                let typed_values = select
-                                    .projection_items
-                                    .into_iter()
-                                    .map(|value| self.type_inference.infer_dynamic(value))
-                                    .collect::<Vec<DynamicTypedTree>>();
-                                log::debug!("SELECT TYPED VALUES - {:?}", typed_values);
-                                let type_checked = typed_values
-                                    .into_iter()
-                                    .map(|value| self.type_checker.check_dynamic(value))
-                                    .collect::<Vec<DynamicTypedTree>>();
-                                log::debug!("SELECT TYPE CHECKED VALUES - {:?}", type_checked);
-                                let type_coerced = type_checked
-                                    .into_iter()
-                                    .map(|value| self.type_coercion.coerce_dynamic(value))
-                                    .collect::<Vec<DynamicTypedTree>>();
-                                log::debug!("SELECT TYPE COERCED VALUES - {:?}", type_coerced);
-                                let plan = self.read_query_planner.plan(TypedSelectQuery {
-                                    projection_items: type_coerced,
-                                    full_table_name: select.full_table_name,
-                                });
-                                match self.read_query_executor.execute(plan) {
-                                    Ok(QueryExecution::Selected((desc, data))) => {
-                                        self.sender
-                                            .send(Ok(QueryEvent::RowDescription(
-                                                desc.into_iter()
-                                                    .map(|col_def| {
-                                                        let pg_type: PgType = (&col_def.sql_type()).into();
-                                                        ColumnMetadata::new(col_def.name(), pg_type)
-                                                    })
-                                                    .collect(),
-                                            )))
-                                            .expect("To Send to client");
-                                        let len = data.len();
-                                        for row in data {
-                                            self.sender
-                                                .send(Ok(QueryEvent::DataRow(
-                                                    row.into_iter().map(|scalar| scalar.as_text()).collect(),
-                                                )))
-                                                .expect("To Send to client");
-                                        }
-                                        self.sender
-                                            .send(Ok(QueryEvent::RecordsSelected(len)))
-                                            .expect("To Send to client");
-                                    }
-                                    Ok(_) => unimplemented!(),
-                                    Err(_) => unimplemented!(),
-                                }
+                            self.sender.send(Ok(event)).expect("To Send Result to Client")
+                        }
+                        Err(error) => self.send_error(error),
+                    }
+                }
+                Ok(analysis) => unreachable!("that couldn't happen {:?}", analysis),
+                Err(AnalysisError::FeatureNotSupported(_)) => {
+                    self.send_error(QueryError::feature_not_supported(statement))
+                }
+                Err(error) => self.send_error(analysis_error(error)),
+            },
+            statement @ Statement::Insert { .. }
+            | statement @ Statement::Update { .. }
+            | statement @ Statement::Delete { .. }
+            | statement @ Statement::Query(_) => match self.query_analyzer.analyze(statement) {
+                Ok(QueryAnalysis::Write(UntypedWrite::Delete(delete))) => {
+                    let table_info = self
+                        .database
+                        .table_definition(&delete.full_table_name)
+                        .unwrap()
+                        .unwrap();
+                    let filter = match self.process_filter(delete.filter, table_info.columns()) {
+                        Ok(filter) => filter,
+                        Err(error) => {
+                            self.send_error(type_check_error(error));
+                            return;
+                        }
+                    };
+                    log::debug!("DELETE FILTER - {:?}", filter);
+                    let query = TypedWrite::Delete(DeleteQuery {
+                        full_table_name: delete.full_table_name,
+                        filter,
+                    });
+                    match self.in_transaction(|transaction| self.write_query_executor.execute(query, transaction)) {
+                        Ok(QueryExecution::Deleted(deleted)) => {
+                            self.sender
+                                .send(Ok(QueryEvent::RecordsDeleted(deleted)))
+                                .expect("To Send to client");
+                        }
+                        Ok(_) => unimplemented!(),
+                        Err(error) => {
+                            for error in execution_errors(error) {
+                                self.send_error(error);
                             }
-                            Err(AnalysisError::TableDoesNotExist(full_table_name)) => {
-                                self.sender
-                                    .send(Err(QueryError::table_does_not_exist(full_table_name)))
-                                    .expect("To Send Error to Client");
+                        }
+                    }
+                }
+                Ok(QueryAnalysis::Write(UntypedWrite::Update(update))) => {
+                    let query = match self.typed_update(update) {
+                        Ok(query) => query,
+                        Err(error) => {
+                            self.send_error(type_check_error(error));
+                            return;
+                        }
+                    };
+                    match self.in_transaction(|transaction| self.write_query_executor.execute(query, transaction)) {
+                        Ok(QueryExecution::Updated(updated)) => {
+                            self.sender
+                                .send(Ok(QueryEvent::RecordsUpdated(updated)))
+                                .expect("To Send to client");
+                        }
+                        Ok(_) => unimplemented!(),
+                        Err(error) => {
+                            for error in execution_errors(error) {
+                                self.send_error(error);
                             }
-                            Err(AnalysisError::ColumnNotFound(column_name)) => {
-                                self.sender
-                                    .send(Err(QueryError::column_does_not_exist(column_name)))
-                                    .expect("To Send Error to Client");
+                        }
+                    }
+                }
+                Ok(QueryAnalysis::Write(UntypedWrite::Insert(insert))) => {
+                    log::debug!("INSERT UNTYPED VALUES {:?}", insert.values);
+                    let typed_values = insert
+                        .values
+                        .into_iter()
+                        .map(|values| {
+                            values
+                                .into_iter()
+                                .map(|value| value.map(|v| self.type_inference.infer_static(v)))
+                                .collect()
+                        })
+                        .collect::<Vec<Vec<Option<StaticTypedTree>>>>();
+                    log::debug!("INSERT TYPED VALUES {:?}", typed_values);
+                    let type_checked = match typed_values
+                        .into_iter()
+                        .map(|values| {
+                            values
+                                .into_iter()
+                                .map(|value| value.map(|v| self.type_checker.check_static(v)).transpose())
+                                .collect()
+                        })
+                        .collect::<Result<Vec<Vec<Option<StaticTypedTree>>>, TypeCheckError>>()
+                    {
+                        Ok(type_checked) => type_checked,
+                        Err(error) => {
+                            self.send_error(type_check_error(error));
+                            return;
+                        }
+                    };
+                    log::debug!("INSERT TYPE CHECKED VALUES {:?}", type_checked);
+                    let table_info = self
+                        .database
+                        .table_definition(&insert.full_table_name)
+                        .unwrap()
+                        .unwrap();
+                    let table_columns = table_info.columns();
+                    let mut type_coerced = vec![];
+                    for checked in type_checked {
+                        let mut row = vec![];
+                        for (index, c) in checked.into_iter().enumerate() {
+                            row.push(c.map(|c| self.type_coercion.coerce_static(c, table_columns[index].sql_type())));
+                        }
+                        type_coerced.push(row);
+                    }
+                    log::debug!("INSERT TYPE COERCED VALUES {:?}", type_coerced);
+                    let checks = match self.process_checks(insert.checks, table_columns) {
+                        Ok(checks) => checks,
+                        Err(error) => {
+                            self.send_error(type_check_error(error));
+                            return;
+                        }
+                    };
+                    let query = TypedWrite::Insert(InsertQuery {
+                        full_table_name: insert.full_table_name,
+                        values: type_coerced,
+                        checks,
+                    });
+                    match self.in_transaction(|transaction| self.write_query_executor.execute(query, transaction)) {
+                        Ok(QueryExecution::Inserted(inserted)) => {
+                            self.sender
+                                .send(Ok(QueryEvent::RecordsInserted(inserted)))
+                                .expect("To Send to client");
+                        }
+                        Ok(_) => unimplemented!(),
+                        Err(error) => {
+                            for error in execution_errors(error) {
+                                self.send_error(error);
                             }
-                            Err(AnalysisError::SyntaxError(message)) => {
+                        }
+                    }
+                }
+                Ok(QueryAnalysis::Read(select)) => {
+                    let plan = match self.select_plan(select) {
+                        Ok(plan) => plan,
+                        Err(error) => {
+                            self.send_error(error);
+                            return;
+                        }
+                    };
+                    match self.in_transaction(|transaction| self.read_query_executor.execute(plan, transaction)) {
+                        Ok(QueryExecution::Selected((desc, data))) => {
+                            // row description of a bound statement is sent on portal describe
+                            if describe {
                                 self.sender
-                                    .send(Err(QueryError::syntax_error(message)))
-                                    .expect("To Send Error to Client");
+                                    .send(Ok(QueryEvent::RowDescription(
+                                        desc.into_iter()
+                                            .map(|col_def| {
+                                                let pg_type: PgType = (&col_def.sql_type()).into();
+                                                ColumnMetadata::new(col_def.name(), pg_type)
+                                            })
+                                            .collect(),
+                                    )))
+                                    .expect("To Send to client");
                             }
-                            Err(AnalysisError::SchemaDoesNotExist(schema_name)) => {
+                            let len = data.len();
+                            for row in data {
                                 self.sender
-                                    .send(Err(QueryError::schema_does_not_exist(schema_name)))
-                                    .expect("To Send Error to Client");
+                                    .send(Ok(QueryEvent::DataRow(
+                                        row.into_iter().map(|scalar| scalar.as_text()).collect(),
+                                    )))
+                                    .expect("To Send to client");
                             }
-                            branch => unimplemented!("handling {:?} is not implemented", branch),
-                        },
-                        sql_ast::Statement::SetVariable { .. } => {
-                            // sending ok to the client to proceed with other requests
                             self.sender
-                                .send(Ok(QueryEvent::VariableSet))
-                                .expect("To Send Result to Client");
+                                .send(Ok(QueryEvent::RecordsSelected(len)))
+                                .expect("To Send to client");
                         }
-                        sql_ast::Statement::Copy { .. } => unimplemented!(),
-                        sql_ast::Statement::CreateView { .. } => unimplemented!(),
-                        sql_ast::Statement::CreateVirtualTable { .. } => unimplemented!(),
-                        sql_ast::Statement::CreateIndex { .. } => unimplemented!(),
-                        sql_ast::Statement::AlterTable { .. } => unimplemented!(),
-                        sql_ast::Statement::ShowVariable { .. } => unimplemented!(),
-                        sql_ast::Statement::ShowColumns { .. } => unimplemented!(),
-                        sql_ast::Statement::StartTransaction { .. } => unimplemented!(),
-                        sql_ast::Statement::SetTransaction { .. } => unimplemented!(),
-                        sql_ast::Statement::Commit { .. } => unimplemented!(),
-                        sql_ast::Statement::Rollback { .. } => unimplemented!(),
-                        sql_ast::Statement::Assert { .. } => unimplemented!(),
-                        sql_ast::Statement::Analyze { .. } => unimplemented!(),
-                        sql_ast::Statement::Explain { .. } => unimplemented!(),
+                        Ok(_) => unimplemented!(),
+                        Err(error) => {
+                            for error in execution_errors(error) {
+                                self.send_error(error);
+                            }
+                        }
+                    }
+                }
+                Ok(analysis) => unreachable!("that couldn't happen {:?}", analysis),
+                Err(error) => self.send_error(analysis_error(error)),
+            },
+            sql_ast::Statement::SetVariable { .. } => {
+                // sending ok to the client to proceed with other requests
+                self.sender
+                    .send(Ok(QueryEvent::VariableSet))
+                    .expect("To Send Result to Client");
+            }
+            sql_ast::Statement::Copy { .. } => unimplemented!(),
+            sql_ast::Statement::CreateView { .. } => unimplemented!(),
+            sql_ast::Statement::CreateVirtualTable { .. } => unimplemented!(),
+            sql_ast::Statement::ShowVariable { .. } => unimplemented!(),
+            sql_ast::Statement::ShowColumns { .. } => unimplemented!(),
+            sql_ast::Statement::StartTransaction { .. } => {
+                if self.transaction.is_none() {
+                    self.transaction = Some(self.database.begin());
+                }
+                self.transaction_status = TransactionStatus::InTransaction;
+                self.sender
+                    .send(Ok(QueryEvent::TransactionStarted))
+                    .expect("To Send Result to Client");
+            }
+            sql_ast::Statement::SetTransaction { .. } => unimplemented!(),
+            sql_ast::Statement::Commit { .. } => {
+                let result = match self.transaction.take() {
+                    // failed transaction could not be committed and is rolled back
+                    Some(transaction) if self.transaction_status == TransactionStatus::Failed => {
+                        self.database.rollback(transaction);
+                        Ok(QueryEvent::TransactionRolledBack)
+                    }
+                    Some(transaction) => match self.database.commit(transaction) {
+                        Ok(()) => Ok(QueryEvent::TransactionCommitted),
+                        Err(error) => Err(execution_errors(error)),
                     },
-                    Err(parser_error) => {
-                        self.sender
-                            .send(Err(QueryError::syntax_error(parser_error)))
-                            .expect("To Send ParseComplete Event");
+                    None => Ok(QueryEvent::TransactionCommitted),
+                };
+                self.transaction_status = TransactionStatus::Idle;
+                match result {
+                    Ok(event) => self.sender.send(Ok(event)).expect("To Send Result to Client"),
+                    Err(errors) => {
+                        for error in errors {
+                            self.send_error(error);
+                        }
                     }
                 }
+            }
+            sql_ast::Statement::Rollback { .. } => {
+                if let Some(transaction) = self.transaction.take() {
+                    self.database.rollback(transaction);
+                }
+                self.transaction_status = TransactionStatus::Idle;
                 self.sender
-                    .send(Ok(QueryEvent::QueryComplete))
-                    .expect("To Send Query Complete to Client");
-                Ok(())
+                    .send(Ok(QueryEvent::TransactionRolledBack))
+                    .expect("To Send Result to Client");
             }
-            Command::Terminate => {
-                log::debug!("closing connection with client");
-                Err(())
+            sql_ast::Statement::Assert { .. } => unimplemented!(),
+            sql_ast::Statement::Analyze { .. } => unimplemented!(),
+            statement @ sql_ast::Statement::Explain { .. } => self.explain(statement, ExplainFormat::Text, describe),
+        }
+    }
+
+    fn send_error(&mut self, error: QueryError) {
+        self.fail_transaction();
+        self.sender.send(Err(error)).expect("To Send Error to Client");
+    }
+
+    fn fail_transaction(&mut self) {
+        if self.transaction_status == TransactionStatus::InTransaction {
+            self.transaction_status = TransactionStatus::Failed;
+        }
+    }
+
+    /// runs `operation` in the explicit transaction or in an implicit one that ends with the statement
+    fn in_transaction<R>(
+        &self,
+        operation: impl FnOnce(&Transaction) -> Result<R, QueryExecutionError>,
+    ) -> Result<R, QueryExecutionError> {
+        match &self.transaction {
+            Some(transaction) => operation(transaction),
+            None => {
+                let transaction = self.database.begin();
+                match operation(&transaction) {
+                    Ok(result) => self.database.commit(transaction).map(|()| result),
+                    Err(error) => {
+                        self.database.rollback(transaction);
+                        Err(error)
+                    }
+                }
             }
         }
     }
 
+    fn query_complete(&self) -> Result<(), ()> {
+        self.sender
+            .send(Ok(QueryEvent::QueryComplete(self.transaction_status)))
+            .expect("To Send Query Complete to Client");
+        Ok(())
+    }
+
+    /// type checked select query planned as a tree of operators
+    fn select_plan(&self, select: SelectQuery) -> Result<SelectPlan, QueryError> {
+        log::debug!("SELECT UNTYPED VALUES - {:?}", select.projection_items);
+        let mut table_columns = self.first_relation_columns(&select);
+        let mut joins = vec![];
+        for join in select.joins {
+            table_columns.extend(self.relation_columns(&join.full_table_name, &join.relation, table_columns.len()));
+            let condition = match join.condition {
+                None => None,
+                Some(condition) => {
+                    let typed = self.type_inference.infer_dynamic(condition);
+                    match self.type_checker.check_join_condition(typed, &table_columns) {
+                        Ok(condition) => Some(condition),
+                        Err(error) => {
+                            return Err(type_check_error(error));
+                        }
+                    }
+                }
+            };
+            joins.push(Join {
+                kind: join.kind,
+                full_table_name: join.full_table_name,
+                relation: join.relation,
+                condition,
+            });
+        }
+        log::debug!("SELECT JOINS - {:?}", joins);
+        let table_columns = table_columns.as_slice();
+        let typed_values = select
+            .projection_items
+            .into_iter()
+            .map(|value| self.type_inference.infer_dynamic(value))
+            .collect::<Vec<DynamicTypedTree>>();
+        log::debug!("SELECT TYPED VALUES - {:?}", typed_values);
+        let mut type_checked = vec![];
+        let mut projection_columns = vec![];
+        for (index, value) in typed_values.into_iter().enumerate() {
+            match self.type_checker.type_family(&value, table_columns) {
+                Ok(type_family) => {
+                    projection_columns.push(projection_column(&value, type_family, table_columns, index));
+                    type_checked.push(value);
+                }
+                Err(error) => {
+                    return Err(type_check_error(error));
+                }
+            }
+        }
+        log::debug!("SELECT TYPE CHECKED VALUES - {:?}", type_checked);
+        let filter = match self.process_filter(select.filter, table_columns) {
+            Ok(filter) => filter,
+            Err(error) => {
+                return Err(type_check_error(error));
+            }
+        };
+        log::debug!("SELECT FILTER - {:?}", filter);
+        let mut group_by = vec![];
+        for item in select.group_by {
+            let typed = self.type_inference.infer_dynamic(item);
+            match self.type_checker.check_dynamic(typed, table_columns) {
+                Ok(item) => group_by.push(item),
+                Err(error) => {
+                    return Err(type_check_error(error));
+                }
+            }
+        }
+        log::debug!("SELECT GROUP BY - {:?}", group_by);
+        let having = match select.having {
+            None => None,
+            Some(having) => {
+                let typed = self.type_inference.infer_dynamic(having);
+                match self.type_checker.check_group_predicate(typed, table_columns) {
+                    Ok(having) => Some(having),
+                    Err(error) => {
+                        return Err(type_check_error(error));
+                    }
+                }
+            }
+        };
+        log::debug!("SELECT HAVING - {:?}", having);
+        let mut order_by = vec![];
+        for item in select.order_by {
+            let typed = self.type_inference.infer_dynamic(item.expr);
+            match self.type_checker.check_dynamic(typed, table_columns) {
+                Ok(expr) => order_by.push(OrderBy {
+                    expr,
+                    ascending: item.ascending,
+                    nulls_first: item.nulls_first,
+                }),
+                Err(error) => {
+                    return Err(type_check_error(error));
+                }
+            }
+        }
+        log::debug!("SELECT ORDER BY - {:?}", order_by);
+        Ok(self.read_query_planner.plan(TypedSelectQuery {
+            projection_items: type_checked,
+            projection_columns,
+            full_table_name: select.full_table_name,
+            relation: select.relation,
+            joins,
+            filter,
+            group_by,
+            having,
+            order_by,
+            limit: select.limit,
+            offset: select.offset,
+        }))
+    }
+
+    /// columns of the table in `FROM` clause,
+    /// they are qualified with names of their tables only when several tables are read
+    fn first_relation_columns(&self, select: &SelectQuery) -> Vec<ColumnDef> {
+        match &select.full_table_name {
+            None => vec![],
+            Some(full_table_name) if select.joins.is_empty() => self
+                .database
+                .table_definition(full_table_name)
+                .unwrap()
+                .unwrap()
+                .columns()
+                .to_vec(),
+            Some(full_table_name) => self.relation_columns(full_table_name, &select.relation, 0),
+        }
+    }
+
+    /// names and types of the columns that `select` returns
+    fn projection_columns(&self, select: &SelectQuery) -> Result<Vec<ColumnDef>, QueryError> {
+        let mut table_columns = self.first_relation_columns(select);
+        for join in select.joins.iter() {
+            table_columns.extend(self.relation_columns(&join.full_table_name, &join.relation, table_columns.len()));
+        }
+        let mut projection_columns = vec![];
+        for (index, item) in select.projection_items.iter().enumerate() {
+            let value = self.type_inference.infer_dynamic(item.clone());
+            match self.type_checker.type_family(&value, &table_columns) {
+                Ok(type_family) => {
+                    projection_columns.push(projection_column(&value, type_family, &table_columns, index))
+                }
+                Err(error) => return Err(type_check_error(error)),
+            }
+        }
+        Ok(projection_columns)
+    }
+
+    /// columns of a table referenced by `relation` name in a query
+    fn relation_columns(&self, full_table_name: &FullTableName, relation: &str, offset: usize) -> Vec<ColumnDef> {
+        let table_info = self.database.table_definition(full_table_name).unwrap().unwrap();
+        table_info
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(index, column)| column.qualified(relation, offset + index))
+            .collect()
+    }
+
+    fn typed_update(
+        &self,
+        update: data_manipulation_untyped_queries::UpdateQuery,
+    ) -> Result<TypedWrite, TypeCheckError> {
+        let table_info = self
+            .database
+            .table_definition(&update.full_table_name)
+            .unwrap()
+            .unwrap();
+        let table_columns = table_info.columns();
+        let typed_values = update
+            .assignments
+            .into_iter()
+            .map(|value| self.type_inference.infer_dynamic(value))
+            .collect::<Vec<DynamicTypedTree>>();
+        log::debug!("UPDATE TYPED VALUES - {:?}", typed_values);
+        let type_checked = typed_values
+            .into_iter()
+            .map(|value| self.type_checker.check_dynamic(value, table_columns))
+            .collect::<Result<Vec<DynamicTypedTree>, TypeCheckError>>()?;
+        log::debug!("UPDATE TYPE CHECKED VALUES - {:?}", type_checked);
+        let type_coerced = type_checked
+            .into_iter()
+            .zip(update.column_names.iter())
+            .map(
+                |(value, column_name)| match table_columns.iter().find(|column| column.has_name(column_name)) {
+                    Some(column) => self.type_coercion.coerce_dynamic(value, column.sql_type()),
+                    None => value,
+                },
+            )
+            .collect::<Vec<DynamicTypedTree>>();
+        log::debug!("UPDATE TYPE COERCED VALUES - {:?}", type_coerced);
+        let filter = self.process_filter(update.filter, table_columns)?;
+        log::debug!("UPDATE FILTER - {:?}", filter);
+        let checks = self.process_checks(update.checks, table_columns)?;
+        Ok(TypedWrite::Update(UpdateQuery {
+            full_table_name: update.full_table_name,
+            column_names: update.column_names,
+            assignments: type_coerced,
+            filter,
+            checks,
+        }))
+    }
+
+    /// values of a column added with `DEFAULT` are computed for existing rows
+    /// as if the column was updated with its default expression
+    /// applies `schema_change` to the catalog
+    fn execute_schema_change(&self, schema_change: &SchemaChange) -> Result<QueryEvent, QueryError> {
+        let operations = self.system_planner.schema_change_plan(schema_change);
+        match self.database.execute(operations) {
+            Ok(ExecutionOutcome::SchemaCreated) => Ok(QueryEvent::SchemaCreated),
+            Ok(ExecutionOutcome::SchemaDropped) => Ok(QueryEvent::SchemaDropped),
+            Ok(ExecutionOutcome::TableCreated) => Ok(QueryEvent::TableCreated),
+            Ok(ExecutionOutcome::TableDropped) => Ok(QueryEvent::TableDropped),
+            Ok(ExecutionOutcome::IndexCreated) => Ok(QueryEvent::IndexCreated),
+            Ok(ExecutionOutcome::IndexDropped) => Ok(QueryEvent::IndexDropped),
+            Ok(ExecutionOutcome::TableAltered) => Ok(QueryEvent::TableAltered),
+            Err(ExecutionError::SchemaAlreadyExists(schema_name)) => {
+                Err(QueryError::schema_already_exists(schema_name))
+            }
+            Err(ExecutionError::SchemaDoesNotExist(schema_name)) => Err(QueryError::schema_does_not_exist(schema_name)),
+            Err(ExecutionError::TableAlreadyExists(schema_name, table_name)) => Err(QueryError::table_already_exists(
+                format!("{}.{}", schema_name, table_name),
+            )),
+            Err(ExecutionError::TableDoesNotExist(schema_name, table_name)) => Err(QueryError::table_does_not_exist(
+                format!("{}.{}", schema_name, table_name),
+            )),
+            Err(ExecutionError::SchemaHasDependentObjects(schema_name)) => {
+                Err(QueryError::schema_has_dependent_objects(schema_name))
+            }
+            Err(ExecutionError::TableHasDependentObjects(schema_name, table_name)) => Err(
+                QueryError::table_has_dependent_objects(format!("{}.{}", schema_name, table_name)),
+            ),
+            Err(ExecutionError::IndexAlreadyExists(schema_name, index_name)) => Err(QueryError::index_already_exists(
+                format!("{}.{}", schema_name, index_name),
+            )),
+            Err(ExecutionError::IndexDoesNotExist(schema_name, index_name)) => Err(QueryError::index_does_not_exist(
+                format!("{}.{}", schema_name, index_name),
+            )),
+            Err(ExecutionError::IndexIsRequiredByConstraint(schema_name, table_name, index_name)) => {
+                Err(QueryError::index_is_required_by_constraint(
+                    format!("{}.{}", schema_name, index_name),
+                    format!("{}.{}", schema_name, table_name),
+                ))
+            }
+            Err(ExecutionError::ColumnAlreadyExists(table_name, column_name)) => {
+                Err(QueryError::column_already_exists(table_name, column_name))
+            }
+            Err(ExecutionError::ColumnDoesNotExist(table_name, column_name)) => {
+                Err(QueryError::column_of_table_does_not_exist(table_name, column_name))
+            }
+            Err(ExecutionError::ColumnContainsNulls(table_name, column_name)) => {
+                Err(QueryError::column_contains_nulls(table_name, column_name))
+            }
+            Err(ExecutionError::ColumnHasDependentObjects(table_name, column_name)) => {
+                Err(QueryError::column_has_dependent_objects(table_name, column_name))
+            }
+            Err(ExecutionError::ColumnCannotBeCast(_table_name, column_name, sql_type)) => {
+                Err(QueryError::column_cannot_be_cast(column_name, sql_type))
+            }
+        }
+    }
+
+    fn explain(&mut self, statement: Statement, format: ExplainFormat, describe: bool) {
+        match self.query_analyzer.analyze(statement.clone()) {
+            Ok(QueryAnalysis::Explain { analyze, select }) => {
+                let plan = match self.select_plan(select) {
+                    Ok(plan) => plan,
+                    Err(error) => {
+                        self.send_error(error);
+                        return;
+                    }
+                };
+                let explained = plan.explain();
+                let stats = if analyze {
+                    match self.in_transaction(|transaction| self.read_query_executor.analyze(plan, transaction)) {
+                        Ok(stats) => Some(stats),
+                        Err(error) => {
+                            for error in execution_errors(error) {
+                                self.send_error(error);
+                            }
+                            return;
+                        }
+                    }
+                } else {
+                    None
+                };
+                if describe {
+                    self.sender
+                        .send(Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                            "QUERY PLAN",
+                            PgType::Text,
+                        )])))
+                        .expect("To Send to client");
+                }
+                let lines = explained.render(format, stats.as_ref());
+                let len = lines.len();
+                for line in lines {
+                    self.sender
+                        .send(Ok(QueryEvent::DataRow(vec![line])))
+                        .expect("To Send to client");
+                }
+                self.sender
+                    .send(Ok(QueryEvent::RecordsSelected(len)))
+                    .expect("To Send to client");
+            }
+            Ok(analysis) => unreachable!("that couldn't happen {:?}", analysis),
+            Err(AnalysisError::FeatureNotSupported(_)) => self.send_error(QueryError::feature_not_supported(statement)),
+            Err(error) => self.send_error(analysis_error(error)),
+        }
+    }
+
+    fn explain_with_options(&mut self, explain: ExplainWithOptions) {
+        if self.transaction_status == TransactionStatus::Failed {
+            self.send_error(QueryError::in_failed_sql_transaction());
+            return;
+        }
+        let format = match explain.format.as_deref() {
+            None | Some("text") => ExplainFormat::Text,
+            Some("json") => ExplainFormat::Json,
+            Some(format) => {
+                self.send_error(QueryError::invalid_parameter_value(format!(
+                    "unrecognized value for EXPLAIN option \"format\": \"{}\"",
+                    format
+                )));
+                return;
+            }
+        };
+        let statement = Statement::Explain {
+            analyze: explain.analyze,
+            verbose: false,
+            statement: Box::new(explain.statement),
+        };
+        self.explain(statement, format, true)
+    }
+
+    fn alter_column_type(&mut self, statement: AlterColumnType) {
+        if self.transaction_status == TransactionStatus::Failed {
+            self.send_error(QueryError::in_failed_sql_transaction());
+        } else if self.transaction_status == TransactionStatus::InTransaction {
+            self.send_error(QueryError::active_sql_transaction("ALTER TABLE"))
+        } else {
+            match self.query_analyzer.analyze_alter_column_type(&statement) {
+                Ok(QueryAnalysis::DataDefinition(schema_change)) => {
+                    log::debug!("SCHEMA CHANGE - {:?}", schema_change);
+                    match self.execute_schema_change(&schema_change) {
+                        Ok(event) => self.sender.send(Ok(event)).expect("To Send Result to Client"),
+                        Err(error) => self.send_error(error),
+                    }
+                }
+                Ok(analysis) => unreachable!("that couldn't happen {:?}", analysis),
+                Err(error) => self.send_error(analysis_error(error)),
+            }
+        }
+    }
+
+    fn fill_added_column(&self, statement: &Statement) -> Result<(), Vec<QueryError>> {
+        let (table_name, column_def) = match statement {
+            Statement::AlterTable {
+                name,
+                operation: AlterTableOperation::AddColumn { column_def },
+            } => (name, column_def),
+            _ => return Ok(()),
+        };
+        let default_expr = column_def.options.iter().find_map(|option| match &option.option {
+            ColumnOption::Default(expr) => Some(expr.clone()),
+            _ => None,
+        });
+        let default_expr = match default_expr {
+            Some(default_expr) => default_expr,
+            None => return Ok(()),
+        };
+        let update = Statement::Update {
+            table_name: table_name.clone(),
+            assignments: vec![Assignment {
+                id: column_def.name.clone(),
+                value: default_expr,
+            }],
+            selection: None,
+        };
+        match self.query_analyzer.analyze(update) {
+            Ok(QueryAnalysis::Write(UntypedWrite::Update(update))) => {
+                let query = self
+                    .typed_update(update)
+                    .map_err(|error| vec![type_check_error(error)])?;
+                self.in_transaction(|transaction| self.write_query_executor.execute(query, transaction))
+                    .map(|_updated| ())
+                    .map_err(execution_errors)
+            }
+            Ok(analysis) => unreachable!("that couldn't happen {:?}", analysis),
+            Err(error) => Err(vec![analysis_error(error)]),
+        }
+    }
+
+    fn process_filter(
+        &self,
+        filter: Option<DynamicUntypedTree>,
+        columns: &[ColumnDef],
+    ) -> Result<Option<DynamicTypedTree>, TypeCheckError> {
+        match filter {
+            None => Ok(None),
+            Some(filter) => {
+                let typed = self.type_inference.infer_dynamic(filter);
+                Ok(Some(self.type_checker.check_predicate(typed, columns)?))
+            }
+        }
+    }
+
+    fn process_checks(
+        &self,
+        checks: Vec<(String, DynamicUntypedTree)>,
+        columns: &[ColumnDef],
+    ) -> Result<Vec<(String, DynamicTypedTree)>, TypeCheckError> {
+        let mut typed_checks = vec![];
+        for (name, predicate) in checks {
+            let typed = self.type_inference.infer_dynamic(predicate);
+            typed_checks.push((name, self.type_checker.check_predicate(typed, columns)?));
+        }
+        Ok(typed_checks)
+    }
+
     fn bind_prepared_statement(
         &self,
         prepared_statement: &PreparedStatement<Statement>,
         param_formats: &[PgFormat],
         raw_params: &[Option<Vec<u8>>],
-        _result_formats: &[PgFormat],
+        result_formats: &[PgFormat],
     ) -> Result<(Statement, Vec<PgFormat>), ()> {
         log::debug!("prepared statement -  {:#?}", prepared_statement);
         let param_formats = match pad_formats(param_formats, raw_params.len()) {
@@ -589,23 +1093,196 @@ impl<D: Database + CatalogDefinition> QueryEngine<D> {
             }
         }
 
-        self.sender
-            .send(Err(QueryError::syntax_error(prepared_statement.stmt())))
-            .expect("To Send Error to Client");
-
-        Err(())
+        let mut statement = prepared_statement.stmt().clone();
+        bind_params(&mut statement, &params);
+        Ok((statement, result_formats.to_vec()))
     }
 
     fn create_prepared_statement(
         &mut self,
-        _statement_name: String,
+        statement_name: String,
         statement: Statement,
-        _param_types: Vec<Option<PgType>>,
+        param_types: Vec<Option<PgType>>,
     ) -> Result<(), QueryError> {
-        Err(QueryError::syntax_error(statement))
+        let mut inferred_types = HashMap::new();
+        let description = match statement {
+            Statement::Insert { .. } | Statement::Update { .. } | Statement::Delete { .. } | Statement::Query(_) => {
+                match self.query_analyzer.analyze(statement.clone()) {
+                    Ok(QueryAnalysis::Write(UntypedWrite::Insert(insert))) => {
+                        let table_info = self
+                            .database
+                            .table_definition(&insert.full_table_name)
+                            .unwrap()
+                            .unwrap();
+                        for row in insert.values.iter() {
+                            for (value, column) in row.iter().zip(table_info.columns()) {
+                                if let Some(value) = value {
+                                    self.type_inference.infer_static_params(
+                                        value,
+                                        Some(column.sql_type()),
+                                        &mut inferred_types,
+                                    );
+                                }
+                            }
+                        }
+                        vec![]
+                    }
+                    Ok(QueryAnalysis::Write(UntypedWrite::Update(update))) => {
+                        let table_info = self
+                            .database
+                            .table_definition(&update.full_table_name)
+                            .unwrap()
+                            .unwrap();
+                        for (value, column_name) in update.assignments.iter().zip(update.column_names.iter()) {
+                            let column_type = table_info
+                                .columns()
+                                .iter()
+                                .find(|column| column.has_name(column_name))
+                                .map(ColumnDef::sql_type);
+                            self.type_inference
+                                .infer_dynamic_params(value, column_type, &mut inferred_types);
+                        }
+                        self.infer_filter_params(update.filter.as_ref(), &mut inferred_types);
+                        vec![]
+                    }
+                    Ok(QueryAnalysis::Write(UntypedWrite::Delete(delete))) => {
+                        self.infer_filter_params(delete.filter.as_ref(), &mut inferred_types);
+                        vec![]
+                    }
+                    Ok(QueryAnalysis::Read(select)) => {
+                        self.infer_filter_params(select.filter.as_ref(), &mut inferred_types);
+                        if let Statement::Query(query) = &statement {
+                            // LIMIT and OFFSET are row counts
+                            let row_counts = query
+                                .limit
+                                .iter()
+                                .chain(query.offset.iter().map(|offset| &offset.value));
+                            for row_count in row_counts {
+                                if let Expr::Identifier(Ident { value, .. }) = row_count {
+                                    if let Some(index) = param_index(value) {
+                                        inferred_types.insert(index, SqlType::big_int());
+                                    }
+                                }
+                            }
+                        }
+                        self.projection_columns(&select)?
+                            .iter()
+                            .map(|column| (column.name().to_owned(), (&column.sql_type()).into()))
+                            .collect()
+                    }
+                    Ok(analysis) => unreachable!("that couldn't happen {:?}", analysis),
+                    Err(error) => return Err(analysis_error(error)),
+                }
+            }
+            _ => vec![],
+        };
+
+        let params_len = inferred_types
+            .keys()
+            .map(|index| index + 1)
+            .max()
+            .unwrap_or_default()
+            .max(param_types.len());
+        let mut resolved_types = vec![];
+        for index in 0..params_len {
+            match param_types.get(index).cloned().flatten() {
+                Some(pg_type) => resolved_types.push(pg_type),
+                None => match inferred_types.get(&index) {
+                    Some(sql_type) => resolved_types.push(sql_type.into()),
+                    None => return Err(QueryError::indeterminate_parameter_data_type(index)),
+                },
+            }
+        }
+
+        self.session.set_prepared_statement(
+            statement_name,
+            PreparedStatement::new(statement, resolved_types, description),
+        );
+        Ok(())
+    }
+
+    fn infer_filter_params(&self, filter: Option<&DynamicUntypedTree>, inferred_types: &mut HashMap<usize, SqlType>) {
+        if let Some(filter) = filter {
+            self.type_inference
+                .infer_dynamic_params(filter, Some(SqlType::Bool), inferred_types);
+        }
+    }
+}
+
+fn analysis_error(error: AnalysisError) -> QueryError {
+    match error {
+        AnalysisError::TableDoesNotExist(full_table_name) => QueryError::table_does_not_exist(full_table_name),
+        AnalysisError::ColumnNotFound(column_name) => QueryError::column_does_not_exist(column_name),
+        AnalysisError::SyntaxError(message) => QueryError::syntax_error(message),
+        AnalysisError::SchemaDoesNotExist(schema_name) => QueryError::schema_does_not_exist(schema_name),
+        AnalysisError::InvalidInputSyntaxForType { sql_type, value } => {
+            QueryError::invalid_text_representation((&sql_type).into(), value)
+        }
+        AnalysisError::OrderByPositionIsNotInSelectList(position) => {
+            QueryError::order_by_position_is_not_in_select_list(position)
+        }
+        AnalysisError::InvalidRowCountInLimitClause => QueryError::invalid_row_count_in_limit_clause(),
+        AnalysisError::InvalidRowCountInResultOffsetClause => QueryError::invalid_row_count_in_result_offset_clause(),
+        AnalysisError::FunctionDoesNotExist(function) => QueryError::function_does_not_exist(function),
+        AnalysisError::AggregateFunctionsAreNotAllowedIn(clause) => {
+            QueryError::aggregate_functions_are_not_allowed_in(clause)
+        }
+        AnalysisError::AggregateFunctionCallsCannotBeNested => QueryError::aggregate_function_calls_cannot_be_nested(),
+        AnalysisError::ColumnMustAppearInGroupBy(column_name) => {
+            QueryError::column_must_appear_in_group_by(column_name)
+        }
+        AnalysisError::AmbiguousColumn(column_name) => QueryError::ambiguous_column(column_name),
+        AnalysisError::MissingFromClauseEntry(table_name) => QueryError::missing_from_clause_entry(table_name),
+        AnalysisError::DuplicateAlias(table_name) => QueryError::duplicate_alias(table_name),
+        AnalysisError::MultiplePrimaryKeys(table_name) => QueryError::multiple_primary_keys(table_name),
+        AnalysisError::NoUniqueConstraintForReferencedTable(table_name) => {
+            QueryError::no_unique_constraint_for_referenced_table(table_name)
+        }
+        AnalysisError::ForeignKeyColumnsDisagree => QueryError::foreign_key_columns_disagree(),
+        AnalysisError::ForeignKeyCannotBeImplemented(constraint_name) => {
+            QueryError::foreign_key_cannot_be_implemented(constraint_name)
+        }
+        AnalysisError::SchemaNamingError(message) => QueryError::syntax_error(message),
+        AnalysisError::SchemaAlreadyExists(schema_name) => QueryError::schema_already_exists(schema_name),
+        AnalysisError::TableNamingError(message) => QueryError::syntax_error(message),
+        AnalysisError::TableAlreadyExists(full_table_name) => QueryError::table_already_exists(full_table_name),
+        AnalysisError::TypeIsNotSupported(type_name) => QueryError::type_does_not_exist(type_name),
+        AnalysisError::ColumnCantBeReferenced(column_name) => QueryError::column_does_not_exist(column_name),
+        AnalysisError::StringDataRightTruncation(sql_type) => QueryError::string_data_right_truncation(sql_type),
+        AnalysisError::DatatypeMismatch {
+            column_type,
+            source_type,
+        } => QueryError::datatype_mismatch("assignment", column_type.to_string(), source_type.to_string()),
+        AnalysisError::AmbiguousFunction(operation) => QueryError::ambiguous_function(operation),
+        AnalysisError::FeatureNotSupported(feature) => QueryError::feature_not_supported(format!("{:?}", feature)),
     }
 }
 
+/// columns are named as in PostgreSQL: selected columns keep their names,
+/// aggregates and function calls are named after their functions and other expressions are unnamed
+fn projection_column(
+    tree: &DynamicTypedTree,
+    type_family: Option<SqlTypeFamily>,
+    columns: &[ColumnDef],
+    ord_num: usize,
+) -> ColumnDef {
+    let name = match tree {
+        DynamicTypedTree::Item(DynamicTypedItem::Column(name)) => {
+            if let Some(column) = columns.iter().find(|column| column.has_name(name)) {
+                return ColumnDef::new(column.name().to_owned(), column.sql_type(), ord_num);
+            }
+            name.clone()
+        }
+        DynamicTypedTree::Aggregate { function, .. } => function.to_string(),
+        DynamicTypedTree::Function { function, .. } => function.to_string(),
+        _ => "?column?".to_owned(),
+    };
+    let sql_type = type_family
+        .map(|type_family| type_family.sql_type())
+        .unwrap_or_else(|| SqlType::var_char(u64::MAX));
+    ColumnDef::new(name, sql_type, ord_num)
+}
+
 fn pad_formats(formats: &[PgFormat], param_len: usize) -> Result<Vec<PgFormat>, String> {
     match (formats.len(), param_len) {
         (0, n) => Ok(vec![PgFormat::Text; n]),
@@ -615,6 +1292,90 @@ fn pad_formats(formats: &[PgFormat], param_len: usize) -> Result<Vec<PgFormat>,
     }
 }
 
+fn execution_errors(error: QueryExecutionError) -> Vec<QueryError> {
+    match error {
+        QueryExecutionError::SchemaDoesNotExist(schema_name) => vec![QueryError::schema_does_not_exist(schema_name)],
+        QueryExecutionError::ColumnNotFound(column_name) => vec![QueryError::column_does_not_exist(column_name)],
+        QueryExecutionError::OutOfRange(sql_type) => vec![QueryError::value_out_of_range(sql_type)],
+        QueryExecutionError::DivisionByZero => vec![QueryError::division_by_zero()],
+        QueryExecutionError::InvalidArgumentForPower => vec![QueryError::invalid_argument_for_power_function()],
+        QueryExecutionError::InvalidParameterValue(message) => vec![QueryError::invalid_parameter_value(message)],
+        QueryExecutionError::InvalidTextRepresentation { sql_type, value } => {
+            vec![QueryError::invalid_text_representation((&sql_type).into(), value)]
+        }
+        QueryExecutionError::CannotCoerce {
+            source_type,
+            target_type,
+        } => vec![QueryError::cannot_coerce(source_type, target_type)],
+        QueryExecutionError::UndefinedFunction { op, left, right } => {
+            vec![QueryError::undefined_function(op, left, right)]
+        }
+        QueryExecutionError::ColumnTypeViolations { row_index, violations } => violations
+            .into_iter()
+            .map(|(column_name, error)| cast_error(error, column_name, row_index))
+            .collect(),
+        QueryExecutionError::SerializationFailure => vec![QueryError::serialization_failure()],
+        QueryExecutionError::UniqueViolation(constraint_name) => vec![QueryError::unique_violation(constraint_name)],
+        QueryExecutionError::CheckViolation {
+            table_name,
+            constraint_name,
+        } => vec![QueryError::check_violation(table_name, constraint_name)],
+        QueryExecutionError::NotNullViolation(column_name) => vec![QueryError::not_null_violation(column_name)],
+        QueryExecutionError::ForeignKeyViolation {
+            table_name,
+            constraint_name,
+        } => vec![QueryError::foreign_key_violation(table_name, constraint_name)],
+        QueryExecutionError::RowIsReferenced {
+            table_name,
+            constraint_name,
+            referencing_table_name,
+        } => vec![QueryError::row_is_referenced(
+            table_name,
+            constraint_name,
+            referencing_table_name,
+        )],
+    }
+}
+
+fn cast_error(error: ImplicitCastError, column_name: String, row_index: usize) -> QueryError {
+    match error {
+        ImplicitCastError::StringDataRightTruncation(sql_type) => QueryError::string_length_mismatch(
+            (&sql_type).into(),
+            sql_type.chars_len().unwrap(),
+            column_name,
+            row_index,
+        ),
+        ImplicitCastError::DatatypeMismatch {
+            column_type,
+            source_type,
+        } => QueryError::type_mismatch(source_type.to_string(), (&column_type).into(), column_name, row_index),
+        ImplicitCastError::InvalidInputSyntaxForType { sql_type, value } => {
+            QueryError::invalid_text_representation((&sql_type).into(), value)
+        }
+        ImplicitCastError::NumericValueOutOfRange(sql_type) => {
+            QueryError::out_of_range((&sql_type).into(), column_name, row_index)
+        }
+    }
+}
+
+/// text representation of a constant argument of `EXECUTE`, `None` for other expressions
+fn param_text(expr: &Expr) -> Option<Option<Vec<u8>>> {
+    match expr {
+        Expr::Value(Value::Null) => Some(None),
+        Expr::Value(Value::Boolean(value)) => Some(Some(value.to_string().into_bytes())),
+        Expr::Value(Value::Number(value)) => Some(Some(value.to_string().into_bytes())),
+        Expr::Value(Value::SingleQuotedString(value)) => Some(Some(value.clone().into_bytes())),
+        Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr,
+        } => match &**expr {
+            Expr::Value(Value::Number(value)) => Some(Some(format!("-{}", value).into_bytes())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn value_to_expr(value: pg_wire::Value) -> Expr {
     match value {
         pg_wire::Value::Null => Expr::Value(Value::Null),
@@ -627,5 +1388,139 @@ fn value_to_expr(value: pg_wire::Value) -> Expr {
     }
 }
 
+/// name of a schema changing command as PostgreSQL reports it
+fn command_name(statement: &Statement) -> String {
+    match statement {
+        Statement::CreateSchema { .. } => "CREATE SCHEMA".to_owned(),
+        Statement::CreateTable { .. } => "CREATE TABLE".to_owned(),
+        Statement::CreateIndex { .. } => "CREATE INDEX".to_owned(),
+        Statement::Drop { object_type, .. } => format!("DROP {}", object_type),
+        Statement::AlterTable { .. } => "ALTER TABLE".to_owned(),
+        statement => statement.to_string(),
+    }
+}
+
+/// zero based index of `$n` parameter placeholder
+fn param_index(value: &str) -> Option<usize> {
+    value
+        .strip_prefix('$')
+        .and_then(|index| index.parse::<usize>().ok())
+        .filter(|index| *index > 0)
+        .map(|index| index - 1)
+}
+
+fn bind_params(statement: &mut Statement, params: &[Expr]) {
+    match statement {
+        Statement::Insert { source, .. } => {
+            if let SetExpr::Values(Values(rows)) = &mut source.body {
+                for value in rows.iter_mut().flatten() {
+                    bind_expr(value, params);
+                }
+            }
+        }
+        Statement::Update {
+            assignments, selection, ..
+        } => {
+            for assignment in assignments.iter_mut() {
+                bind_expr(&mut assignment.value, params);
+            }
+            if let Some(selection) = selection {
+                bind_expr(selection, params);
+            }
+        }
+        Statement::Delete {
+            selection: Some(selection),
+            ..
+        } => bind_expr(selection, params),
+        Statement::Query(query) => {
+            if let SetExpr::Select(select) = &mut query.body {
+                for item in select.projection.iter_mut() {
+                    match item {
+                        SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                            bind_expr(expr, params)
+                        }
+                        SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => {}
+                    }
+                }
+                for join in select.from.iter_mut().flat_map(|table| table.joins.iter_mut()) {
+                    match &mut join.join_operator {
+                        JoinOperator::Inner(JoinConstraint::On(expr))
+                        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+                        | JoinOperator::RightOuter(JoinConstraint::On(expr))
+                        | JoinOperator::FullOuter(JoinConstraint::On(expr)) => bind_expr(expr, params),
+                        _ => {}
+                    }
+                }
+                if let Some(selection) = &mut select.selection {
+                    bind_expr(selection, params);
+                }
+                for expr in select.group_by.iter_mut() {
+                    bind_expr(expr, params);
+                }
+                if let Some(having) = &mut select.having {
+                    bind_expr(having, params);
+                }
+            }
+            for item in query.order_by.iter_mut() {
+                // a parameter is a constant to sort by and not a position in the select list
+                if matches!(&item.expr, Expr::Identifier(Ident { value, .. }) if param_index(value).is_some()) {
+                    item.expr = Expr::Nested(Box::new(item.expr.clone()));
+                }
+                bind_expr(&mut item.expr, params);
+            }
+            if let Some(limit) = &mut query.limit {
+                bind_expr(limit, params);
+            }
+            if let Some(offset) = &mut query.offset {
+                bind_expr(&mut offset.value, params);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn bind_expr(expr: &mut Expr, params: &[Expr]) {
+    match expr {
+        Expr::Identifier(Ident { value, .. }) => {
+            if let Some(param) = param_index(value).and_then(|index| params.get(index)) {
+                *expr = param.clone();
+            }
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            bind_expr(left, params);
+            bind_expr(right, params);
+        }
+        Expr::UnaryOp { expr, .. } | Expr::Nested(expr) => bind_expr(expr, params),
+        Expr::Function(function) => {
+            for arg in function.args.iter_mut() {
+                bind_expr(arg, params);
+            }
+        }
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests;
+
+fn type_check_error(error: TypeCheckError) -> QueryError {
+    match error {
+        TypeCheckError::UndefinedFunction { op, left, right } => QueryError::undefined_function(op, left, right),
+        TypeCheckError::UndefinedUnaryOperation { op, operand } => QueryError::undefined_prefix_operator(op, operand),
+        TypeCheckError::UndefinedAggregateFunction { function, argument } => {
+            QueryError::function_does_not_exist(format!("{}({})", function, argument))
+        }
+        TypeCheckError::UndefinedScalarFunction { function, arguments } => {
+            let arguments = arguments
+                .iter()
+                .map(|argument| argument.map_or_else(|| "unknown".to_owned(), |argument| argument.to_string()))
+                .collect::<Vec<String>>();
+            QueryError::function_does_not_exist(format!("{}({})", function, arguments.join(", ")))
+        }
+        TypeCheckError::DatatypeMismatch {
+            argument_of,
+            expected,
+            actual,
+        } => QueryError::datatype_mismatch(argument_of, expected, actual),
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::{
+    results::{QueryError, QueryEvent},
+    Command,
+};
+
+#[rstest::fixture]
+fn database_with_rows(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (col1 smallint, col2 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1, 1), (2, 2), (1, 3), (3, null), (2, 2);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(5)));
+
+    (engine, collector)
+}
+
+fn selected(columns: Vec<(&str, PgType)>, rows: Vec<Vec<&str>>) -> Vec<QueryResult> {
+    let len = rows.len();
+    iter::once(Ok(QueryEvent::RowDescription(
+        columns
+            .into_iter()
+            .map(|(name, pg_type)| ColumnMetadata::new(name, pg_type))
+            .collect(),
+    )))
+    .chain(rows.into_iter().map(|row| {
+        Ok(QueryEvent::DataRow(
+            row.into_iter().map(|value| value.to_owned()).collect(),
+        ))
+    }))
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+#[rstest::rstest]
+fn count_all_rows(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select count(*) from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![("count", PgType::BigInt)], vec![vec!["5"]]));
+}
+
+#[rstest::rstest]
+fn count_skips_nulls(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select count(col2) from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![("count", PgType::BigInt)], vec![vec!["4"]]));
+}
+
+#[rstest::rstest]
+fn count_distinct_values(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select count(distinct col2) from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![("count", PgType::BigInt)], vec![vec!["3"]]));
+}
+
+#[rstest::rstest]
+fn sum_min_and_max(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select sum(col2), sum(distinct col2), min(col2), max(col2) from schema_name.table_name;",
+    );
+    collector.assert_receive_many(selected(
+        vec![
+            ("sum", PgType::BigInt),
+            ("sum", PgType::BigInt),
+            ("min", PgType::SmallInt),
+            ("max", PgType::SmallInt),
+        ],
+        vec![vec!["8", "6", "1", "3"]],
+    ));
+}
+
+#[rstest::rstest]
+fn aggregates_over_no_rows(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select count(*), sum(col1), max(col1) from schema_name.table_name where col1 > 10;",
+    );
+    collector.assert_receive_many(selected(
+        vec![
+            ("count", PgType::BigInt),
+            ("sum", PgType::BigInt),
+            ("max", PgType::SmallInt),
+        ],
+        vec![vec!["0", "NULL", "NULL"]],
+    ));
+}
+
+#[rstest::rstest]
+fn group_by_column(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select col1, count(*), sum(col2) from schema_name.table_name group by col1 order by col1;",
+    );
+    collector.assert_receive_many(selected(
+        vec![
+            ("col1", PgType::SmallInt),
+            ("count", PgType::BigInt),
+            ("sum", PgType::BigInt),
+        ],
+        vec![vec!["1", "2", "4"], vec!["2", "2", "4"], vec!["3", "1", "NULL"]],
+    ));
+}
+
+#[rstest::rstest]
+fn group_by_expression(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select col1 % 2, count(*) from schema_name.table_name group by col1 % 2 order by 1;",
+    );
+    collector.assert_receive_many(selected(
+        vec![("?column?", PgType::SmallInt), ("count", PgType::BigInt)],
+        vec![vec!["0", "2"], vec!["1", "3"]],
+    ));
+}
+
+#[rstest::rstest]
+fn group_by_over_no_rows(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select col1, count(*) from schema_name.table_name where col1 > 10 group by col1;",
+    );
+    collector.assert_receive_many(selected(
+        vec![("col1", PgType::SmallInt), ("count", PgType::BigInt)],
+        vec![],
+    ));
+}
+
+#[rstest::rstest]
+fn having(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select col1, count(*) from schema_name.table_name group by col1 having count(*) > 1 order by col1;",
+    );
+    collector.assert_receive_many(selected(
+        vec![("col1", PgType::SmallInt), ("count", PgType::BigInt)],
+        vec![vec!["1", "2"], vec!["2", "2"]],
+    ));
+}
+
+#[rstest::rstest]
+fn order_by_aggregate_with_limit(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select col1 from schema_name.table_name group by col1 order by max(col2) desc, col1 limit 2;",
+    );
+    collector.assert_receive_many(selected(vec![("col1", PgType::SmallInt)], vec![vec!["3"], vec!["1"]]));
+}
+
+#[rstest::rstest]
+fn column_that_is_not_grouped(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select col1, col2 from schema_name.table_name group by col1;",
+    );
+    collector.assert_receive_single(Err(QueryError::column_must_appear_in_group_by("col2")));
+}
+
+#[rstest::rstest]
+fn aggregate_in_where_clause(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select col1 from schema_name.table_name where count(*) > 1;",
+    );
+    collector.assert_receive_single(Err(QueryError::aggregate_functions_are_not_allowed_in("WHERE")));
+}
+
+#[rstest::rstest]
+fn nested_aggregates(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select sum(count(*)) from schema_name.table_name;");
+    collector.assert_receive_single(Err(QueryError::aggregate_function_calls_cannot_be_nested()));
+}
+
+#[rstest::rstest]
+fn sum_of_booleans(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select sum(col1 > 1) from schema_name.table_name;");
+    collector.assert_receive_single(Err(QueryError::function_does_not_exist("sum(boolean)")));
+}
+
+#[rstest::rstest]
+fn undefined_function(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select median(col1) from schema_name.table_name;");
+    collector.assert_receive_single(Err(QueryError::function_does_not_exist("median")));
+}
+
+#[rstest::rstest]
+fn sum_of_bigints_and_average_of_integers_are_numeric(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select avg(col1), avg(col2) from schema_name.table_name;");
+    collector.assert_receive_many(selected(
+        vec![("avg", PgType::Numeric), ("avg", PgType::Numeric)],
+        vec![vec!["1.8000000000000000", "2.0000000000000000"]],
+    ));
+
+    query(&mut engine, "create table schema_name.big_numbers (col1 bigint);");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    query(
+        &mut engine,
+        "insert into schema_name.big_numbers values (9223372036854775807), (9223372036854775807);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(&mut engine, "select sum(col1) from schema_name.big_numbers;");
+    collector.assert_receive_many(selected(
+        vec![("sum", PgType::Numeric)],
+        vec![vec!["18446744073709551614"]],
+    ));
+}
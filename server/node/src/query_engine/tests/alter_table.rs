@@ -0,0 +1,289 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::results::{QueryError, QueryEvent};
+
+#[rstest::fixture]
+fn database_with_rows(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col1 smallint, col2 smallint);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (1, 10), (2, 20);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    (engine, collector)
+}
+
+fn selected(columns: Vec<&str>, rows: Vec<Vec<&str>>) -> Vec<QueryResult> {
+    let len = rows.len();
+    iter::once(Ok(QueryEvent::RowDescription(
+        columns
+            .into_iter()
+            .map(|name| ColumnMetadata::new(name, PgType::SmallInt))
+            .collect(),
+    )))
+    .chain(rows.into_iter().map(|row| {
+        Ok(QueryEvent::DataRow(
+            row.into_iter().map(|value| value.to_owned()).collect(),
+        ))
+    }))
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+#[rstest::rstest]
+fn add_column_with_default(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name add column col3 smallint not null default 5;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableAltered));
+
+    query(&mut engine, "insert into schema_name.table_name values (3, 30, 300);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(
+        vec!["col1", "col2", "col3"],
+        vec![vec!["1", "10", "5"], vec!["2", "20", "5"], vec!["3", "30", "300"]],
+    ));
+}
+
+#[rstest::rstest]
+fn add_column_without_default(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name add column col3 smallint;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableAltered));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(
+        vec!["col1", "col2", "col3"],
+        vec![vec!["1", "10", "NULL"], vec!["2", "20", "NULL"]],
+    ));
+}
+
+#[rstest::rstest]
+fn add_not_null_column_without_default(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name add column col3 smallint not null;",
+    );
+    collector.assert_receive_single(Err(QueryError::column_contains_nulls("table_name", "col3")));
+}
+
+#[rstest::rstest]
+fn add_existing_column(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name add column col1 smallint;",
+    );
+    collector.assert_receive_single(Err(QueryError::column_already_exists("table_name", "col1")));
+}
+
+#[rstest::rstest]
+fn drop_column(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "create index col1_idx on schema_name.table_name (col1);");
+    collector.assert_receive_single(Ok(QueryEvent::IndexCreated));
+
+    query(&mut engine, "alter table schema_name.table_name drop column col1;");
+    collector.assert_receive_single(Ok(QueryEvent::TableAltered));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec!["col2"], vec![vec!["10"], vec!["20"]]));
+
+    query(&mut engine, "drop index schema_name.col1_idx;");
+    collector.assert_receive_single(Err(QueryError::index_does_not_exist("schema_name.col1_idx")));
+}
+
+#[rstest::rstest]
+fn drop_nonexistent_column(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "alter table schema_name.table_name drop column col3;");
+    collector.assert_receive_single(Err(QueryError::column_of_table_does_not_exist("table_name", "col3")));
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name drop column if exists col3;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableAltered));
+}
+
+#[rstest::rstest]
+fn drop_referenced_column(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+
+    query(
+        &mut engine,
+        "create table schema_name.parent (id smallint primary key);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    query(
+        &mut engine,
+        "create table schema_name.child (parent_id smallint references schema_name.parent (id));",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(&mut engine, "alter table schema_name.parent drop column id;");
+    collector.assert_receive_single(Err(QueryError::column_has_dependent_objects("parent", "id")));
+
+    query(&mut engine, "alter table schema_name.parent drop column id cascade;");
+    collector.assert_receive_single(Ok(QueryEvent::TableAltered));
+
+    query(&mut engine, "insert into schema_name.child values (1);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+}
+
+#[rstest::rstest]
+fn rename_column(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name rename column col1 to col3;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableAltered));
+
+    query(&mut engine, "select col3 from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec!["col3"], vec![vec!["1"], vec!["2"]]));
+}
+
+#[rstest::rstest]
+fn rename_table(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "alter table schema_name.table_name rename to other_table;");
+    collector.assert_receive_single(Ok(QueryEvent::TableAltered));
+
+    query(&mut engine, "select * from schema_name.other_table;");
+    collector.assert_receive_many(selected(vec!["col1", "col2"], vec![vec!["1", "10"], vec!["2", "20"]]));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_single(Err(QueryError::table_does_not_exist("schema_name.table_name")));
+}
+
+#[rstest::rstest]
+fn change_column_type(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name alter column col2 type bigint;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableAltered));
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (3, 3000000000);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "select col2 from schema_name.table_name;");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "col2",
+            PgType::BigInt,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["10".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["20".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["3000000000".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(3)),
+    ]);
+}
+
+#[rstest::rstest]
+fn change_column_type_spelled_with_set_data(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name alter col2 set data type varchar(5);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableAltered));
+
+    query(&mut engine, "select col2 from schema_name.table_name;");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "col2",
+            PgType::VarChar,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["10".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["20".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(2)),
+    ]);
+}
+
+#[rstest::rstest]
+fn change_column_type_with_values_that_can_not_be_cast(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name alter column col2 type varchar(1);",
+    );
+    collector.assert_receive_single(Err(QueryError::column_cannot_be_cast("col2", SqlType::var_char(1))));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec!["col1", "col2"], vec![vec!["1", "10"], vec!["2", "20"]]));
+}
+
+#[rstest::rstest]
+fn change_type_of_nonexistent_column(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name alter column col3 type bigint;",
+    );
+    collector.assert_receive_single(Err(QueryError::column_of_table_does_not_exist("table_name", "col3")));
+}
+
+#[rstest::rstest]
+fn change_column_type_inside_transaction(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "begin;");
+    collector.assert_receive_till_this_moment(vec![
+        Ok(QueryEvent::TransactionStarted),
+        Ok(QueryEvent::QueryComplete(TransactionStatus::InTransaction)),
+    ]);
+
+    query(
+        &mut engine,
+        "alter table schema_name.table_name alter column col2 type bigint;",
+    );
+    collector.assert_receive_till_this_moment(vec![
+        Err(QueryError::active_sql_transaction("ALTER TABLE")),
+        Ok(QueryEvent::QueryComplete(TransactionStatus::Failed)),
+    ]);
+}
@@ -0,0 +1,389 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::results::{QueryError, QueryEvent};
+
+#[rstest::fixture]
+fn database_with_keys(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col1 smallint primary key, col2 smallint unique);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (1, 10), (2, 20), (3, null);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(3)));
+
+    (engine, collector)
+}
+
+fn selected(rows: Vec<(&str, &str)>) -> Vec<QueryResult> {
+    let len = rows.len();
+    iter::once(Ok(QueryEvent::RowDescription(vec![
+        ColumnMetadata::new("col1", PgType::SmallInt),
+        ColumnMetadata::new("col2", PgType::SmallInt),
+    ])))
+    .chain(
+        rows.into_iter()
+            .map(|(col1, col2)| Ok(QueryEvent::DataRow(vec![col1.to_owned(), col2.to_owned()]))),
+    )
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+#[rstest::rstest]
+fn multiple_primary_keys(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col1 smallint primary key, col2 smallint, primary key (col2));",
+    );
+    collector.assert_receive_single(Err(QueryError::multiple_primary_keys("table_name")));
+}
+
+#[rstest::rstest]
+fn insert_duplicate_primary_key(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+
+    query(&mut engine, "insert into schema_name.table_name values (1, 40);");
+    collector.assert_receive_single(Err(QueryError::unique_violation("table_name_pkey")));
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (4, 40), (4, 50);",
+    );
+    collector.assert_receive_single(Err(QueryError::unique_violation("table_name_pkey")));
+}
+
+#[rstest::rstest]
+fn insert_null_primary_key(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+
+    query(&mut engine, "insert into schema_name.table_name values (null, 40);");
+    collector.assert_receive_single(Err(QueryError::not_null_violation("col1")));
+}
+
+#[rstest::rstest]
+fn insert_duplicate_unique_value(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+
+    query(&mut engine, "insert into schema_name.table_name values (4, 10);");
+    collector.assert_receive_single(Err(QueryError::unique_violation("table_name_col2_key")));
+
+    query(&mut engine, "insert into schema_name.table_name values (4, null);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+}
+
+#[rstest::rstest]
+fn update_to_duplicate_value(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+
+    query(
+        &mut engine,
+        "update schema_name.table_name set col2 = 20 where col1 = 1;",
+    );
+    collector.assert_receive_single(Err(QueryError::unique_violation("table_name_col2_key")));
+
+    query(
+        &mut engine,
+        "update schema_name.table_name set col1 = 2 where col1 = 1;",
+    );
+    collector.assert_receive_single(Err(QueryError::unique_violation("table_name_pkey")));
+}
+
+#[rstest::rstest]
+fn update_primary_key(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+
+    query(&mut engine, "update schema_name.table_name set col1 = col1 + 10;");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(3)));
+
+    query(&mut engine, "insert into schema_name.table_name values (1, 10);");
+    collector.assert_receive_single(Err(QueryError::unique_violation("table_name_col2_key")));
+
+    query(&mut engine, "select * from schema_name.table_name where col1 > 10;");
+    collector.assert_receive_many(selected(vec![("11", "10"), ("12", "20"), ("13", "NULL")]));
+}
+
+#[rstest::rstest]
+fn drop_constraint_index(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+
+    query(&mut engine, "drop index schema_name.table_name_pkey;");
+    collector.assert_receive_single(Err(QueryError::index_is_required_by_constraint(
+        "schema_name.table_name_pkey",
+        "schema_name.table_name",
+    )));
+}
+
+#[rstest::rstest]
+fn index_name_is_taken_by_constraint(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+
+    query(
+        &mut engine,
+        "create table schema_name.other_table (col1 smallint, constraint table_name_pkey unique (col1));",
+    );
+    collector.assert_receive_single(Err(QueryError::index_already_exists("schema_name.table_name_pkey")));
+
+    query(&mut engine, "drop table schema_name.table_name;");
+    collector.assert_receive_single(Ok(QueryEvent::TableDropped));
+
+    query(
+        &mut engine,
+        "create table schema_name.other_table (col1 smallint, constraint table_name_pkey unique (col1));",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+}
+
+#[rstest::rstest]
+fn not_null_column(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col1 smallint not null, col2 smallint null);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(&mut engine, "insert into schema_name.table_name values (null, 1);");
+    collector.assert_receive_single(Err(QueryError::not_null_violation("col1")));
+
+    query(&mut engine, "insert into schema_name.table_name (col2) values (1);");
+    collector.assert_receive_single(Err(QueryError::not_null_violation("col1")));
+
+    query(&mut engine, "insert into schema_name.table_name values (1, null);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "update schema_name.table_name set col1 = col2;");
+    collector.assert_receive_single(Err(QueryError::not_null_violation("col1")));
+}
+
+#[rstest::rstest]
+fn default_values(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col1 smallint default 5, col2 smallint not null default 1 + 2);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(&mut engine, "insert into schema_name.table_name (col1) values (1);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (default, default);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "insert into schema_name.table_name values (default, 10);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![("1", "3"), ("5", "3"), ("5", "10")]));
+}
+
+#[rstest::rstest]
+fn check_constraints(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col1 smallint check (col1 > 0), col2 smallint constraint col2_is_small check (col2 < 100));",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(&mut engine, "insert into schema_name.table_name values (1, 200);");
+    collector.assert_receive_single(Err(QueryError::check_violation("table_name", "col2_is_small")));
+
+    query(&mut engine, "insert into schema_name.table_name values (0, 1);");
+    collector.assert_receive_single(Err(QueryError::check_violation("table_name", "table_name_col1_check")));
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (null, 1), (2, 2);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(&mut engine, "update schema_name.table_name set col1 = col1 - 2;");
+    collector.assert_receive_single(Err(QueryError::check_violation("table_name", "table_name_col1_check")));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![("NULL", "1"), ("2", "2")]));
+}
+
+#[rstest::rstest]
+fn insert_and_update_referencing_rows(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+    query(
+        &mut engine,
+        "create table schema_name.child (col1 smallint primary key, col2 smallint references schema_name.table_name);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(&mut engine, "insert into schema_name.child values (1, 1), (2, null);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(&mut engine, "insert into schema_name.child values (3, 5);");
+    collector.assert_receive_single(Err(QueryError::foreign_key_violation("child", "child_col2_fkey")));
+
+    query(&mut engine, "update schema_name.child set col2 = 4 where col1 = 1;");
+    collector.assert_receive_single(Err(QueryError::foreign_key_violation("child", "child_col2_fkey")));
+
+    query(&mut engine, "update schema_name.child set col2 = 3 where col1 = 2;");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+}
+
+#[rstest::rstest]
+fn references_unique_column(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+    query(
+        &mut engine,
+        "create table schema_name.child (col1 smallint, col2 smallint, foreign key (col2) references schema_name.table_name (col2));",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(&mut engine, "insert into schema_name.child values (1, 10);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "insert into schema_name.child values (2, 1);");
+    collector.assert_receive_single(Err(QueryError::foreign_key_violation("child", "child_col2_fkey")));
+}
+
+#[rstest::rstest]
+fn referenced_rows_are_restricted(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+    query(
+        &mut engine,
+        "create table schema_name.child (col1 smallint primary key, col2 smallint references schema_name.table_name on delete restrict);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    query(&mut engine, "insert into schema_name.child values (1, 1);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "delete from schema_name.table_name where col1 = 1;");
+    collector.assert_receive_single(Err(QueryError::row_is_referenced(
+        "table_name",
+        "child_col2_fkey",
+        "child",
+    )));
+
+    query(
+        &mut engine,
+        "update schema_name.table_name set col1 = 4 where col1 = 1;",
+    );
+    collector.assert_receive_single(Err(QueryError::row_is_referenced(
+        "table_name",
+        "child_col2_fkey",
+        "child",
+    )));
+
+    query(&mut engine, "delete from schema_name.table_name where col1 = 2;");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsDeleted(1)));
+}
+
+#[rstest::rstest]
+fn cascade_deletes_and_updates(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+    query(
+        &mut engine,
+        "create table schema_name.child (col1 smallint primary key, col2 smallint references schema_name.table_name on delete cascade on update cascade);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    query(
+        &mut engine,
+        "insert into schema_name.child values (1, 1), (2, 2), (3, 1);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(3)));
+
+    query(
+        &mut engine,
+        "update schema_name.table_name set col1 = 4 where col1 = 1;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+    query(&mut engine, "select * from schema_name.child;");
+    collector.assert_receive_many(selected(vec![("1", "4"), ("2", "2"), ("3", "4")]));
+
+    query(&mut engine, "delete from schema_name.table_name where col1 = 4;");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsDeleted(1)));
+
+    query(&mut engine, "select * from schema_name.child;");
+    collector.assert_receive_many(selected(vec![("2", "2")]));
+}
+
+#[rstest::rstest]
+fn set_null_on_delete(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+    query(
+        &mut engine,
+        "create table schema_name.child (col1 smallint primary key, col2 smallint references schema_name.table_name on delete set null);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    query(&mut engine, "insert into schema_name.child values (1, 1), (2, 2);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(&mut engine, "delete from schema_name.table_name where col1 = 1;");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsDeleted(1)));
+
+    query(&mut engine, "select * from schema_name.child;");
+    collector.assert_receive_many(selected(vec![("1", "NULL"), ("2", "2")]));
+}
+
+#[rstest::rstest]
+fn self_referencing_table(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col1 smallint primary key, col2 smallint references schema_name.table_name on delete cascade);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (1, null), (2, 1), (3, 2);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(3)));
+
+    query(&mut engine, "delete from schema_name.table_name where col1 = 1;");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsDeleted(1)));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![]));
+
+    query(&mut engine, "drop table schema_name.table_name;");
+    collector.assert_receive_single(Ok(QueryEvent::TableDropped));
+}
+
+#[rstest::rstest]
+fn drop_referenced_table(database_with_keys: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_keys;
+    query(
+        &mut engine,
+        "create table schema_name.child (col1 smallint, col2 smallint references schema_name.table_name);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(&mut engine, "drop table schema_name.table_name;");
+    collector.assert_receive_single(Err(QueryError::table_has_dependent_objects("schema_name.table_name")));
+
+    query(&mut engine, "drop table schema_name.table_name cascade;");
+    collector.assert_receive_single(Ok(QueryEvent::TableDropped));
+
+    query(&mut engine, "insert into schema_name.child values (1, 5);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+}
@@ -0,0 +1,293 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::{
+    results::{QueryError, QueryEvent},
+    Command,
+};
+
+#[rstest::fixture]
+fn database_with_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (col1 smallint, col2 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1, 10), (2, 20), (3, 30), (4, 40);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(4)));
+
+    (engine, collector)
+}
+
+fn query_plan(lines: Vec<&str>) -> Vec<QueryResult> {
+    let len = lines.len();
+    iter::once(Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+        "QUERY PLAN",
+        PgType::Text,
+    )])))
+    .chain(
+        lines
+            .into_iter()
+            .map(|line| Ok(QueryEvent::DataRow(vec![line.to_owned()]))),
+    )
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+/// measured time differs from run to run and is replaced with `_`
+fn without_time(line: &str) -> String {
+    let mut previous = "";
+    line.split(' ')
+        .map(|word| {
+            let masked = if word.starts_with("time=") {
+                "time=_"
+            } else if previous == "Time:" {
+                "_"
+            } else {
+                word
+            };
+            previous = word;
+            masked
+        })
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+#[rstest::rstest]
+fn explain_select(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(
+        &mut engine,
+        "explain select col1 + col2 from schema_name.table_name where col1 > 1 order by col2 desc limit 2;",
+    );
+    collector.assert_receive_many(query_plan(vec![
+        "Project",
+        "  Output: (col1 + col2)",
+        "  ->  Limit",
+        "        Count: 2",
+        "        ->  Sort",
+        "              Sort Key: col2 DESC",
+        "              Top Rows: 2",
+        "              ->  Filter",
+        "                    Filter: (col1 > 1)",
+        "                    ->  Seq Scan on schema_name.table_name",
+    ]));
+}
+
+#[rstest::rstest]
+fn explain_does_not_execute_query(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(&mut engine, "explain select col1 / 0 from schema_name.table_name;");
+    collector.assert_receive_many(query_plan(vec![
+        "Project",
+        "  Output: (col1 / 0)",
+        "  ->  Seq Scan on schema_name.table_name",
+    ]));
+}
+
+#[rstest::rstest]
+fn explain_aggregation(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(
+        &mut engine,
+        "explain select col1 % 2, count(*) from schema_name.table_name group by col1 % 2 having sum(col2) > 10;",
+    );
+    collector.assert_receive_many(query_plan(vec![
+        "Project",
+        "  Output: (col1 % 2), count#0",
+        "  ->  Filter",
+        "        Filter: (sum#1 > 10)",
+        "        ->  HashAggregate",
+        "              Group Key: (col1 % 2)",
+        "              Aggregates: count(*) AS count#0, sum(col2) AS sum#1",
+        "              ->  Seq Scan on schema_name.table_name",
+    ]));
+}
+
+#[rstest::rstest]
+fn explain_join(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+    query(
+        &mut engine,
+        "create table schema_name.other_table (col1 smallint, col3 smallint);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(
+        &mut engine,
+        "explain select t.col2, o.col3 from schema_name.table_name t \
+         left join schema_name.other_table o on t.col1 = o.col1;",
+    );
+    collector.assert_receive_many(query_plan(vec![
+        "Project",
+        "  Output: t.col2, o.col3",
+        "  ->  Hash Left Join",
+        "        Hash Cond: (t.col1 = o.col1)",
+        "        ->  Seq Scan on schema_name.table_name t",
+        "        ->  Seq Scan on schema_name.other_table o",
+    ]));
+}
+
+#[rstest::rstest]
+fn explain_analyze(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(
+        &mut engine,
+        "explain analyze select col2 from schema_name.table_name where col1 > 1 limit 1 offset 1;",
+    );
+    let results = collector
+        .0
+        .lock()
+        .expect("locked")
+        .drain(..)
+        .map(|result| match result {
+            Ok(QueryEvent::DataRow(values)) => Ok(QueryEvent::DataRow(
+                values.iter().map(|value| without_time(value)).collect(),
+            )),
+            other => other,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        results,
+        query_plan(vec![
+            "Project  (actual rows=1 time=_ ms)",
+            "  Output: col2",
+            "  ->  Limit  (actual rows=1 time=_ ms)",
+            "        Offset: 1",
+            "        Count: 1",
+            "        ->  Filter  (actual rows=2 time=_ ms)",
+            "              Filter: (col1 > 1)",
+            "              ->  Seq Scan on schema_name.table_name  (actual rows=3 time=_ ms)",
+            "Execution Time: _ ms",
+        ])
+        .into_iter()
+        .chain(iter::once(Ok(QueryEvent::QueryComplete(TransactionStatus::Idle))))
+        .collect::<Vec<_>>()
+    );
+}
+
+#[rstest::rstest]
+fn explain_analyze_reports_execution_errors(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(
+        &mut engine,
+        "explain analyze select col1 / 0 from schema_name.table_name;",
+    );
+    collector.assert_receive_single(Err(QueryError::division_by_zero()));
+}
+
+#[rstest::rstest]
+fn explain_in_json_format(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(
+        &mut engine,
+        "explain (format json) select col1 from schema_name.table_name where col2 = 20;",
+    );
+    collector.assert_receive_many(query_plan(vec![
+        r#"[
+  {
+    "Plan": {
+      "Node Type": "Project",
+      "Output": ["col1"],
+      "Plans": [
+        {
+          "Node Type": "Filter",
+          "Filter": "(col2 = 20)",
+          "Plans": [
+            {
+              "Node Type": "Seq Scan",
+              "Relation Name": "schema_name.table_name"
+            }
+          ]
+        }
+      ]
+    }
+  }
+]"#,
+    ]));
+
+    query(&mut engine, "explain select col1 from schema_name.table_name;");
+    collector.assert_receive_many(query_plan(vec![
+        "Project",
+        "  Output: col1",
+        "  ->  Seq Scan on schema_name.table_name",
+    ]));
+}
+
+#[rstest::rstest]
+fn explain_with_options(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(
+        &mut engine,
+        "explain (analyze false, costs off, format text) select col1 from schema_name.table_name;",
+    );
+    collector.assert_receive_many(query_plan(vec![
+        "Project",
+        "  Output: col1",
+        "  ->  Seq Scan on schema_name.table_name",
+    ]));
+}
+
+#[rstest::rstest]
+fn explain_in_unknown_format(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(
+        &mut engine,
+        "explain (format xml) select col1 from schema_name.table_name;",
+    );
+    collector.assert_receive_single(Err(QueryError::invalid_parameter_value(
+        "unrecognized value for EXPLAIN option \"format\": \"xml\"",
+    )));
+}
+
+#[rstest::rstest]
+fn explain_with_unknown_option(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(
+        &mut engine,
+        "explain (colors on) select col1 from schema_name.table_name;",
+    );
+    collector.assert_receive_single(Err(QueryError::syntax_error(
+        "sql parser error: unrecognized EXPLAIN option \"colors\"",
+    )));
+}
+
+#[rstest::rstest]
+fn explain_insert(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    query(
+        &mut engine,
+        "explain insert into schema_name.table_name values (5, 50);",
+    );
+    collector.assert_receive_single(Err(QueryError::feature_not_supported(
+        "EXPLAIN INSERT INTO schema_name.table_name VALUES (5, 50)",
+    )));
+}
@@ -46,6 +46,31 @@ mod statement_description {
         collector.assert_receive_intermediate(Ok(QueryEvent::StatementParameters(vec![])));
     }
 
+    #[rstest::rstest]
+    fn statement_description_of_expressions(database_with_table: (InMemory, ResultCollector)) {
+        let (mut engine, collector) = database_with_table;
+
+        engine
+            .execute(Command::Parse {
+                statement_name: "statement_name".to_owned(),
+                sql: "select col1 % 2, count(*) from schema_name.table_name group by col1 % 2;".to_owned(),
+                param_types: vec![],
+            })
+            .expect("statement parsed");
+        collector.assert_receive_intermediate(Ok(QueryEvent::ParseComplete));
+
+        engine
+            .execute(Command::DescribeStatement {
+                name: "statement_name".to_owned(),
+            })
+            .expect("statement described");
+        collector.assert_receive_intermediate(Ok(QueryEvent::StatementDescription(vec![
+            ("?column?".to_owned(), PgType::SmallInt),
+            ("count".to_owned(), PgType::BigInt),
+        ])));
+        collector.assert_receive_intermediate(Ok(QueryEvent::StatementParameters(vec![])));
+    }
+
     #[rstest::rstest]
     fn statement_parameters(database_with_table: (InMemory, ResultCollector)) {
         let (mut engine, collector) = database_with_table;
@@ -204,6 +229,61 @@ mod parse_bind_execute {
                 .expect("portal executed");
             collector.assert_receive_intermediate(Ok(QueryEvent::RecordsSelected(1)));
         }
+
+        #[rstest::rstest]
+        fn select_with_limit_and_offset(database_with_table: (InMemory, ResultCollector)) {
+            let (mut engine, collector) = database_with_table;
+
+            engine
+                .execute(Command::Query {
+                    sql: "insert into schema_name.table_name values (1, 2), (2, 3), (3, 4);".to_owned(),
+                })
+                .expect("query executed");
+            collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(3)));
+
+            engine
+                .execute(Command::Parse {
+                    statement_name: "statement_name".to_owned(),
+                    sql: "select col1 from schema_name.table_name order by col1 limit $1 offset $2".to_owned(),
+                    param_types: vec![],
+                })
+                .expect("query parsed");
+            collector.assert_receive_intermediate(Ok(QueryEvent::ParseComplete));
+
+            engine
+                .execute(Command::DescribeStatement {
+                    name: "statement_name".to_owned(),
+                })
+                .expect("statement described");
+            collector.assert_receive_intermediate(Ok(QueryEvent::StatementDescription(vec![(
+                "col1".to_owned(),
+                PgType::SmallInt,
+            )])));
+            collector.assert_receive_intermediate(Ok(QueryEvent::StatementParameters(vec![
+                PgType::BigInt,
+                PgType::BigInt,
+            ])));
+
+            engine
+                .execute(Command::Bind {
+                    portal_name: "portal_name".to_owned(),
+                    statement_name: "statement_name".to_owned(),
+                    param_formats: vec![PgFormat::Text, PgFormat::Binary],
+                    raw_params: vec![Some(b"1".to_vec()), Some(vec![0, 0, 0, 0, 0, 0, 0, 1])],
+                    result_formats: vec![],
+                })
+                .expect("statement bound to portal");
+            collector.assert_receive_intermediate(Ok(QueryEvent::BindComplete));
+
+            engine
+                .execute(Command::Execute {
+                    portal_name: "portal_name".to_owned(),
+                    max_rows: 0,
+                })
+                .expect("portal executed");
+            collector.assert_receive_intermediate(Ok(QueryEvent::RecordsSelected(1)));
+            collector.assert_receive_intermediate(Ok(QueryEvent::DataRow(vec!["2".to_owned()])));
+        }
     }
 
     #[cfg(test)]
@@ -374,7 +454,7 @@ mod parse_bind_execute {
                     statement_name: "statement_name".to_owned(),
                     portal_name: "portal_name".to_owned(),
                     param_formats: vec![PgFormat::Text; 3],
-                    raw_params: vec![Some(b"100".to_vec()), Some(b"200".to_vec()), Some(b"40".to_vec())],
+                    raw_params: vec![Some(b"100".to_vec()), Some(b"200".to_vec()), Some(b"4".to_vec())],
                     result_formats: vec![],
                 })
                 .expect("statement bound to portal");
@@ -386,9 +466,7 @@ mod parse_bind_execute {
                     max_rows: 0,
                 })
                 .expect("portal executed");
-
-            // TODO: `where` clause needs to be handled in `query_planner`.
-            collector.assert_receive_intermediate(Ok(QueryEvent::RecordsUpdated(3)));
+            collector.assert_receive_intermediate(Ok(QueryEvent::RecordsUpdated(2)));
         }
     }
 }
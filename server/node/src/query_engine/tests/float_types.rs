@@ -0,0 +1,122 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::results::{QueryError, QueryEvent};
+
+#[rstest::fixture]
+fn float_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col_r real, col_d double precision, col_f float);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    (engine, collector)
+}
+
+fn selected(rows: Vec<(&str, &str, &str)>) -> Vec<QueryResult> {
+    let len = rows.len();
+    iter::once(Ok(QueryEvent::RowDescription(vec![
+        ColumnMetadata::new("col_r", PgType::Real),
+        ColumnMetadata::new("col_d", PgType::DoublePrecision),
+        ColumnMetadata::new("col_f", PgType::DoublePrecision),
+    ])))
+    .chain(rows.into_iter().map(|(col_r, col_d, col_f)| {
+        Ok(QueryEvent::DataRow(vec![
+            col_r.to_owned(),
+            col_d.to_owned(),
+            col_f.to_owned(),
+        ]))
+    }))
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+#[rstest::rstest]
+fn insert_and_select(float_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = float_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (1.5, 0.1, 100000000000000000000), (-2, 1234567, 0.00001);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![("1.5", "0.1", "1e+20"), ("-2", "1234567", "1e-05")]));
+}
+
+#[rstest::rstest]
+fn decimal_literals_are_not_rounded(float_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = float_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (0.3, 0.3, 2.675);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![("0.3", "0.3", "2.675")]));
+}
+
+#[rstest::rstest]
+fn special_values(float_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = float_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values ('NaN', 'Infinity', '-Infinity');",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![("NaN", "Infinity", "-Infinity")]));
+}
+
+#[rstest::rstest]
+fn arithmetic_and_comparison(float_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = float_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (1.5, 2.5, 1), (3.5, 4.5, 2);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(
+        &mut engine,
+        "update schema_name.table_name set col_d = col_d * col_r, col_f = col_f / 4 where col_r > 2;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name where col_d = 15.75 or col_r < 1.6;",
+    );
+    collector.assert_receive_many(selected(vec![("1.5", "2.5", "1"), ("3.5", "15.75", "0.5")]));
+}
+
+#[rstest::rstest]
+fn value_out_of_range(float_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = float_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name (col_r) values (1000000000000000000000000000000000000000);",
+    );
+    collector.assert_receive_single(Err(QueryError::out_of_range(PgType::Real, "col_r", 1)));
+}
@@ -0,0 +1,168 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::results::{QueryError, QueryEvent};
+
+#[rstest::fixture]
+fn database_with_index(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col1 smallint, col2 smallint);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (1, 10), (2, 20), (3, 30), (2, 40);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(4)));
+    query(&mut engine, "create index idx on schema_name.table_name (col1);");
+    collector.assert_receive_single(Ok(QueryEvent::IndexCreated));
+
+    (engine, collector)
+}
+
+fn selected(rows: Vec<(&str, &str)>) -> Vec<QueryResult> {
+    let len = rows.len();
+    iter::once(Ok(QueryEvent::RowDescription(vec![
+        ColumnMetadata::new("col1", PgType::SmallInt),
+        ColumnMetadata::new("col2", PgType::SmallInt),
+    ])))
+    .chain(
+        rows.into_iter()
+            .map(|(col1, col2)| Ok(QueryEvent::DataRow(vec![col1.to_owned(), col2.to_owned()]))),
+    )
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+#[rstest::rstest]
+fn create_index_on_non_existent_table(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+
+    query(&mut engine, "create index idx on schema_name.non_existent (col1);");
+    collector.assert_receive_single(Err(QueryError::table_does_not_exist("schema_name.non_existent")));
+}
+
+#[rstest::rstest]
+fn create_same_index(database_with_index: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_index;
+
+    query(&mut engine, "create index idx on schema_name.table_name (col2);");
+    collector.assert_receive_single(Err(QueryError::index_already_exists("schema_name.idx")));
+}
+
+#[rstest::rstest]
+fn create_index_if_not_exists(database_with_index: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_index;
+
+    query(
+        &mut engine,
+        "create index if not exists idx on schema_name.table_name (col2);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::IndexCreated));
+}
+
+#[rstest::rstest]
+fn drop_index(database_with_index: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_index;
+
+    query(&mut engine, "drop index schema_name.idx;");
+    collector.assert_receive_single(Ok(QueryEvent::IndexDropped));
+
+    query(&mut engine, "drop index schema_name.idx;");
+    collector.assert_receive_single(Err(QueryError::index_does_not_exist("schema_name.idx")));
+
+    query(&mut engine, "drop index if exists schema_name.idx;");
+    collector.assert_receive_single(Ok(QueryEvent::IndexDropped));
+}
+
+#[rstest::rstest]
+fn select_by_equality(database_with_index: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_index;
+
+    query(&mut engine, "select * from schema_name.table_name where col1 = 2;");
+    collector.assert_receive_many(selected(vec![("2", "20"), ("2", "40")]));
+}
+
+#[rstest::rstest]
+fn select_by_range(database_with_index: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_index;
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name where 1 < col1 and col2 < 40;",
+    );
+    collector.assert_receive_many(selected(vec![("2", "20"), ("3", "30")]));
+}
+
+#[rstest::rstest]
+fn index_follows_table_changes(database_with_index: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_index;
+
+    query(
+        &mut engine,
+        "update schema_name.table_name set col1 = 5 where col2 = 20;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+    query(&mut engine, "delete from schema_name.table_name where col2 = 40;");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsDeleted(1)));
+    query(&mut engine, "insert into schema_name.table_name values (2, 50);");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "select * from schema_name.table_name where col1 = 2;");
+    collector.assert_receive_many(selected(vec![("2", "50")]));
+
+    query(&mut engine, "select * from schema_name.table_name where col1 >= 3;");
+    collector.assert_receive_many(selected(vec![("3", "30"), ("5", "20")]));
+}
+
+#[rstest::rstest]
+fn explain_index_scan(database_with_index: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_index;
+
+    query(
+        &mut engine,
+        "explain select * from schema_name.table_name where col1 = 2;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "QUERY PLAN",
+            PgType::Text,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["Project".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["  Output: col1, col2".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["  ->  Filter".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["        Filter: (col1 = 2)".to_owned()])),
+        Ok(QueryEvent::DataRow(vec![
+            "        ->  Index Scan using idx on schema_name.table_name".to_owned(),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "              Index Cond: (col1 = 2)".to_owned()
+        ])),
+        Ok(QueryEvent::RecordsSelected(6)),
+    ]);
+}
+
+#[rstest::rstest]
+fn drop_table_drops_its_indexes(database_with_index: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_index;
+
+    query(&mut engine, "drop table schema_name.table_name;");
+    collector.assert_receive_single(Ok(QueryEvent::TableDropped));
+
+    query(&mut engine, "drop index schema_name.idx;");
+    collector.assert_receive_single(Err(QueryError::index_does_not_exist("schema_name.idx")));
+}
@@ -0,0 +1,285 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::{
+    results::{QueryError, QueryEvent},
+    Command,
+};
+
+#[rstest::fixture]
+fn database_with_tables(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.customers (id smallint, name varchar(10));".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.customers values (1, 'alice'), (2, 'bob'), (3, 'carol');".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(3)));
+
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.orders (id smallint, customer_id smallint, amount smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.orders values (1, 1, 10), (2, 1, 20), (3, 2, 30), (4, 4, 40);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(4)));
+
+    (engine, collector)
+}
+
+fn selected(columns: Vec<(&str, PgType)>, rows: Vec<Vec<&str>>) -> Vec<QueryResult> {
+    let len = rows.len();
+    iter::once(Ok(QueryEvent::RowDescription(
+        columns
+            .into_iter()
+            .map(|(name, pg_type)| ColumnMetadata::new(name, pg_type))
+            .collect(),
+    )))
+    .chain(rows.into_iter().map(|row| {
+        Ok(QueryEvent::DataRow(
+            row.into_iter().map(|value| value.to_owned()).collect(),
+        ))
+    }))
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+fn name_and_amount(rows: Vec<Vec<&str>>) -> Vec<QueryResult> {
+    selected(vec![("name", PgType::VarChar), ("amount", PgType::SmallInt)], rows)
+}
+
+#[rstest::rstest]
+fn inner_join(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select c.name, o.amount from schema_name.customers c \
+         join schema_name.orders o on c.id = o.customer_id;",
+    );
+    collector.assert_receive_many(name_and_amount(vec![
+        vec!["alice", "10"],
+        vec!["alice", "20"],
+        vec!["bob", "30"],
+    ]));
+}
+
+#[rstest::rstest]
+fn left_outer_join(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select c.name, o.amount from schema_name.customers as c \
+         left join schema_name.orders as o on c.id = o.customer_id;",
+    );
+    collector.assert_receive_many(name_and_amount(vec![
+        vec!["alice", "10"],
+        vec!["alice", "20"],
+        vec!["bob", "30"],
+        vec!["carol", "NULL"],
+    ]));
+}
+
+#[rstest::rstest]
+fn right_outer_join(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select customers.name, orders.amount from schema_name.customers \
+         right outer join schema_name.orders on customers.id = orders.customer_id;",
+    );
+    collector.assert_receive_many(name_and_amount(vec![
+        vec!["alice", "10"],
+        vec!["alice", "20"],
+        vec!["bob", "30"],
+        vec!["NULL", "40"],
+    ]));
+}
+
+#[rstest::rstest]
+fn full_outer_join(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select c.name, o.amount from schema_name.customers c \
+         full join schema_name.orders o on c.id = o.customer_id;",
+    );
+    collector.assert_receive_many(name_and_amount(vec![
+        vec!["alice", "10"],
+        vec!["alice", "20"],
+        vec!["bob", "30"],
+        vec!["carol", "NULL"],
+        vec!["NULL", "40"],
+    ]));
+}
+
+#[rstest::rstest]
+fn join_on_condition_without_equalities(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select c.name, o.amount from schema_name.customers c \
+         join schema_name.orders o on o.customer_id > c.id;",
+    );
+    collector.assert_receive_many(name_and_amount(vec![
+        vec!["alice", "30"],
+        vec!["alice", "40"],
+        vec!["bob", "40"],
+        vec!["carol", "40"],
+    ]));
+}
+
+#[rstest::rstest]
+fn left_join_on_equality_with_additional_condition(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select c.name, o.amount from schema_name.customers c \
+         left join schema_name.orders o on c.id = o.customer_id and o.amount > 10;",
+    );
+    collector.assert_receive_many(name_and_amount(vec![
+        vec!["alice", "20"],
+        vec!["bob", "30"],
+        vec!["carol", "NULL"],
+    ]));
+}
+
+#[rstest::rstest]
+fn cross_join_of_tables_listed_through_comma(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select count(*) from schema_name.customers, schema_name.orders;",
+    );
+    collector.assert_receive_many(selected(vec![("count", PgType::BigInt)], vec![vec!["12"]]));
+}
+
+#[rstest::rstest]
+fn all_columns_of_joined_tables(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select * from schema_name.customers c \
+         join schema_name.orders o on c.id = o.customer_id where o.id = 3;",
+    );
+    collector.assert_receive_many(selected(
+        vec![
+            ("id", PgType::SmallInt),
+            ("name", PgType::VarChar),
+            ("id", PgType::SmallInt),
+            ("customer_id", PgType::SmallInt),
+            ("amount", PgType::SmallInt),
+        ],
+        vec![vec!["2", "bob", "3", "2", "30"]],
+    ));
+}
+
+#[rstest::rstest]
+fn joined_rows_filtered_and_sorted(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select c.name, o.amount from schema_name.customers c \
+         join schema_name.orders o on c.id = o.customer_id \
+         where o.amount > 10 order by o.amount desc;",
+    );
+    collector.assert_receive_many(name_and_amount(vec![vec!["bob", "30"], vec!["alice", "20"]]));
+}
+
+#[rstest::rstest]
+fn joined_rows_grouped(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select c.name, count(o.id) from schema_name.customers c \
+         left join schema_name.orders o on c.id = o.customer_id group by c.name;",
+    );
+    collector.assert_receive_many(selected(
+        vec![("name", PgType::VarChar), ("count", PgType::BigInt)],
+        vec![vec!["alice", "2"], vec!["bob", "1"], vec!["carol", "0"]],
+    ));
+}
+
+#[rstest::rstest]
+fn qualified_columns_of_single_table(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select c.name from schema_name.customers c where c.id = 2;",
+    );
+    collector.assert_receive_many(selected(vec![("name", PgType::VarChar)], vec![vec!["bob"]]));
+}
+
+#[rstest::rstest]
+fn ambiguous_column(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select id from schema_name.customers c join schema_name.orders o on c.id = o.customer_id;",
+    );
+    collector.assert_receive_single(Err(QueryError::ambiguous_column("id")));
+}
+
+#[rstest::rstest]
+fn column_of_table_missing_in_from_clause(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(&mut engine, "select o.amount from schema_name.customers c;");
+    collector.assert_receive_single(Err(QueryError::missing_from_clause_entry("o")));
+}
+
+#[rstest::rstest]
+fn same_table_joined_without_alias(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select * from schema_name.customers cross join schema_name.customers;",
+    );
+    collector.assert_receive_single(Err(QueryError::duplicate_alias("customers")));
+}
+
+#[rstest::rstest]
+fn join_condition_of_non_boolean_type(database_with_tables: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_tables;
+
+    query(
+        &mut engine,
+        "select * from schema_name.customers c join schema_name.orders o on c.id + o.id;",
+    );
+    collector.assert_receive_single(Err(QueryError::datatype_mismatch("JOIN/ON", "boolean", "smallint")));
+}
@@ -0,0 +1,196 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::results::{QueryError, QueryEvent};
+
+#[rstest::fixture]
+fn table_with_documents(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (id integer, doc jsonb, raw json);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values \
+        (1, '{\"tags\": [\"x\", \"y\"], \"name\": \"a\", \"nested\": {\"k\": 1.50}}', '{\"b\":1, \"a\" : 2}'), \
+        (2, '{\"name\": \"b\", \"tags\": []}', '[1, 2]');",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    (engine, collector)
+}
+
+#[rstest::rstest]
+fn insert_and_select(table_with_documents: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_documents;
+
+    query(&mut engine, "select doc, raw from schema_name.table_name;");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("doc", PgType::Jsonb),
+            ColumnMetadata::new("raw", PgType::Json),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "{\"name\": \"a\", \"tags\": [\"x\", \"y\"], \"nested\": {\"k\": 1.50}}".to_owned(),
+            "{\"b\":1, \"a\" : 2}".to_owned(),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "{\"name\": \"b\", \"tags\": []}".to_owned(),
+            "[1, 2]".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(2)),
+    ]);
+}
+
+#[rstest::rstest]
+fn operators(table_with_documents: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_documents;
+
+    query(
+        &mut engine,
+        "select jsonb_object_field_text(doc, 'name'), jsonb_extract_path_op(doc, '{nested,k}') \
+        from schema_name.table_name where jsonb_contains(doc, '{\"tags\": [\"y\"]}');",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("?column?", PgType::Text),
+            ColumnMetadata::new("?column?", PgType::Jsonb),
+        ])),
+        Ok(QueryEvent::DataRow(vec!["a".to_owned(), "1.50".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+
+    query(
+        &mut engine,
+        "select json_array_element(raw, -1) from schema_name.table_name where jsonb_exists(doc, 'nested') = false;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "?column?",
+            PgType::Json,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["2".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+
+    query(
+        &mut engine,
+        "select id from schema_name.table_name where doc = '{\"tags\": [], \"name\": \"b\"}';",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "id",
+            PgType::Integer,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["2".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn operator_syntax(table_with_documents: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_documents;
+
+    query(
+        &mut engine,
+        "select doc->>'name', doc #> '{nested,k}', doc -> 'nested' -> 'k' \
+        from schema_name.table_name where doc @> '{\"tags\": [\"y\"]}';",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("?column?", PgType::Text),
+            ColumnMetadata::new("?column?", PgType::Jsonb),
+            ColumnMetadata::new("?column?", PgType::Jsonb),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "a".to_owned(),
+            "1.50".to_owned(),
+            "1.50".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+
+    query(
+        &mut engine,
+        "select raw -> -1 from schema_name.table_name where not doc ? 'nested' and (doc->'tags')::text = '[]';",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "?column?",
+            PgType::Json,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["2".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+
+    query(&mut engine, "select -> 'name' from schema_name.table_name;");
+    collector.assert_receive_single(Err(QueryError::syntax_error(
+        "sql parser error: Expected an expression before ->",
+    )));
+}
+
+#[rstest::rstest]
+fn build_and_aggregate(table_with_documents: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_documents;
+
+    query(
+        &mut engine,
+        "select jsonb_agg(jsonb_build_object('id', id, 'name', jsonb_object_field(doc, 'name'))) \
+        from schema_name.table_name;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "jsonb_agg",
+            PgType::Jsonb,
+        )])),
+        Ok(QueryEvent::DataRow(vec![
+            "[{\"id\": 1, \"name\": \"a\"}, {\"id\": 2, \"name\": \"b\"}]".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+
+    query(
+        &mut engine,
+        "select jsonb_agg(raw) from schema_name.table_name where id > 2;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "jsonb_agg",
+            PgType::Jsonb,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["NULL".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn invalid_documents(table_with_documents: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_documents;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name (doc) values ('{a: 1}');",
+    );
+    collector.assert_receive_single(Err(QueryError::invalid_text_representation(PgType::Jsonb, "{a: 1}")));
+
+    query(&mut engine, "insert into schema_name.table_name (raw) values ('[1,');");
+    collector.assert_receive_single(Err(QueryError::invalid_text_representation(PgType::Json, "[1,")));
+
+    query(&mut engine, "insert into schema_name.table_name (doc) values (1);");
+    collector.assert_receive_single(Err(QueryError::type_mismatch("integer", PgType::Jsonb, "doc", 1)));
+}
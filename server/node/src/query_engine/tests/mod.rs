@@ -18,37 +18,58 @@ use pg_model::{
     results::{QueryEvent, QueryResult},
     Command,
 };
-use pg_wire::ColumnMetadata;
 use std::{
     io,
     ops::DerefMut,
     sync::{Arc, Mutex},
 };
 
+#[cfg(test)]
+mod aggregates;
+#[cfg(test)]
+mod alter_table;
+#[cfg(test)]
+mod constraints;
 #[cfg(test)]
 mod delete;
-// TODO: new engine does not handle extended query flow
-// #[cfg(test)]
-// mod extended_query_flow;
+#[cfg(test)]
+mod explain;
+#[cfg(test)]
+mod extended_query_flow;
+#[cfg(test)]
+mod float_types;
+#[cfg(test)]
+mod index;
 #[cfg(test)]
 mod insert;
 #[cfg(test)]
+mod joins;
+#[cfg(test)]
+mod json_types;
+#[cfg(test)]
+mod numeric_types;
+#[cfg(test)]
+mod order_by;
+#[cfg(test)]
 mod schema;
 #[cfg(test)]
 mod select;
-// TODO: new engine does not handle extended query flow
-// #[cfg(test)]
-// mod simple_prepared_statement;
+#[cfg(test)]
+mod simple_prepared_statement;
 #[cfg(test)]
 mod table;
-// TODO: new engine does not handle type constraints
-// #[cfg(test)]
-// mod type_constraints;
+#[cfg(test)]
+mod temporal_types;
+#[cfg(test)]
+mod text_bytea_uuid_types;
+#[cfg(test)]
+mod transaction;
+#[cfg(test)]
+mod type_constraints;
 #[cfg(test)]
 mod update;
-// TODO: new engine does not support sophisticated selection plans
-// #[cfg(test)]
-// mod where_clause;
+#[cfg(test)]
+mod where_clause;
 
 type InMemory = QueryEngine<InMemoryDatabase>;
 type ResultCollector = Arc<Collector>;
@@ -102,10 +123,20 @@ impl Collector {
 
     fn assert_query_complete(&self) {
         let mut actual = self.0.lock().expect("locked");
-        assert_eq!(actual.deref_mut().pop(), Some(Ok(QueryEvent::QueryComplete)));
+        assert_eq!(
+            actual.deref_mut().pop(),
+            Some(Ok(QueryEvent::QueryComplete(TransactionStatus::Idle)))
+        );
     }
 }
 
+/// executes `sql` as a simple query, results are received by the collector
+fn query(engine: &mut InMemory, sql: &str) {
+    engine
+        .execute(Command::Query { sql: sql.to_owned() })
+        .expect("query executed");
+}
+
 #[rstest::fixture]
 fn empty_database() -> (InMemory, ResultCollector) {
     let collector = Collector::new();
@@ -0,0 +1,120 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::results::{QueryError, QueryEvent};
+
+#[rstest::fixture]
+fn numeric_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (col_p numeric(10, 2), col_n numeric);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    (engine, collector)
+}
+
+fn selected(rows: Vec<(&str, &str)>) -> Vec<QueryResult> {
+    let len = rows.len();
+    iter::once(Ok(QueryEvent::RowDescription(vec![
+        ColumnMetadata::new("col_p", PgType::Numeric),
+        ColumnMetadata::new("col_n", PgType::Numeric),
+    ])))
+    .chain(
+        rows.into_iter()
+            .map(|(col_p, col_n)| Ok(QueryEvent::DataRow(vec![col_p.to_owned(), col_n.to_owned()]))),
+    )
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+#[rstest::rstest]
+fn insert_rounded_to_scale(numeric_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = numeric_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (1.005, 1.005), (-2, 123456789012345678901234567890);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(selected(vec![
+        ("1.01", "1.005"),
+        ("-2.00", "123456789012345678901234567890"),
+    ]));
+}
+
+#[rstest::rstest]
+fn exact_arithmetic_and_comparison(numeric_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = numeric_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (0.1, 0.1), (5, 5);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(
+        &mut engine,
+        "update schema_name.table_name set col_n = col_n + 0.2, col_p = col_p / 3 where col_p < 1;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name where col_n = 0.3 or col_p > 4;",
+    );
+    collector.assert_receive_many(selected(vec![("0.03", "0.3"), ("5.00", "5")]));
+}
+
+#[rstest::rstest]
+fn sum_and_average(numeric_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = numeric_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values (1.25, 1), (2.5, 2);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(
+        &mut engine,
+        "select sum(col_p), avg(col_n) from schema_name.table_name;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("sum", PgType::Numeric),
+            ColumnMetadata::new("avg", PgType::Numeric),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "3.75".to_owned(),
+            "1.5000000000000000".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn value_out_of_range(numeric_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = numeric_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name (col_p) values (123456789);",
+    );
+    collector.assert_receive_single(Err(QueryError::out_of_range(PgType::Numeric, "col_p", 1)));
+}
@@ -0,0 +1,205 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::{
+    results::{QueryError, QueryEvent},
+    Command,
+};
+
+#[rstest::fixture]
+fn database_with_rows(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (col1 smallint, col2 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (2, 1), (1, null), (3, 2), (1, 3);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(4)));
+
+    (engine, collector)
+}
+
+fn selected(rows: Vec<(&str, &str)>) -> Vec<QueryResult> {
+    let len = rows.len();
+    iter::once(Ok(QueryEvent::RowDescription(vec![
+        ColumnMetadata::new("col1", PgType::SmallInt),
+        ColumnMetadata::new("col2", PgType::SmallInt),
+    ])))
+    .chain(
+        rows.into_iter()
+            .map(|(col1, col2)| Ok(QueryEvent::DataRow(vec![col1.to_owned(), col2.to_owned()]))),
+    )
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+#[rstest::rstest]
+fn order_by_column(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select * from schema_name.table_name order by col2;");
+    collector.assert_receive_many(selected(vec![("2", "1"), ("3", "2"), ("1", "3"), ("1", "NULL")]));
+}
+
+#[rstest::rstest]
+fn order_by_column_descending(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select * from schema_name.table_name order by col2 desc;");
+    collector.assert_receive_many(selected(vec![("1", "NULL"), ("1", "3"), ("3", "2"), ("2", "1")]));
+}
+
+#[rstest::rstest]
+fn order_by_with_explicit_nulls_placement(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name order by col2 asc nulls first;",
+    );
+    collector.assert_receive_many(selected(vec![("1", "NULL"), ("2", "1"), ("3", "2"), ("1", "3")]));
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name order by col2 desc nulls last;",
+    );
+    collector.assert_receive_many(selected(vec![("1", "3"), ("3", "2"), ("2", "1"), ("1", "NULL")]));
+}
+
+#[rstest::rstest]
+fn order_by_multiple_keys(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name order by col1 desc, col2;",
+    );
+    collector.assert_receive_many(selected(vec![("3", "2"), ("2", "1"), ("1", "3"), ("1", "NULL")]));
+}
+
+#[rstest::rstest]
+fn order_by_expression(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name order by col1 + col2, col1;",
+    );
+    collector.assert_receive_many(selected(vec![("2", "1"), ("1", "3"), ("3", "2"), ("1", "NULL")]));
+}
+
+#[rstest::rstest]
+fn order_by_column_that_is_not_selected(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select col1 from schema_name.table_name order by col2 desc;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "col1",
+            PgType::SmallInt,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["1".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["1".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["3".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["2".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(4)),
+    ]);
+}
+
+#[rstest::rstest]
+fn order_by_position_in_select_list(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select * from schema_name.table_name order by 2;");
+    collector.assert_receive_many(selected(vec![("2", "1"), ("3", "2"), ("1", "3"), ("1", "NULL")]));
+}
+
+#[rstest::rstest]
+fn order_by_position_that_is_not_in_select_list(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select * from schema_name.table_name order by 3;");
+    collector.assert_receive_single(Err(QueryError::order_by_position_is_not_in_select_list(3)));
+}
+
+#[rstest::rstest]
+fn limit(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select * from schema_name.table_name limit 2;");
+    collector.assert_receive_many(selected(vec![("2", "1"), ("1", "NULL")]));
+}
+
+#[rstest::rstest]
+fn offset(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(&mut engine, "select * from schema_name.table_name offset 3;");
+    collector.assert_receive_many(selected(vec![("1", "3")]));
+}
+
+#[rstest::rstest]
+fn order_by_with_limit_and_offset(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name order by col2 limit 2 offset 1;",
+    );
+    collector.assert_receive_many(selected(vec![("3", "2"), ("1", "3")]));
+}
+
+#[rstest::rstest]
+fn limit_all(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name order by col1, col2 limit all;",
+    );
+    collector.assert_receive_many(selected(vec![("1", "3"), ("1", "NULL"), ("2", "1"), ("3", "2")]));
+}
+
+#[rstest::rstest]
+fn order_by_with_limit_larger_than_table(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name order by col2 limit 100000000000;",
+    );
+    collector.assert_receive_many(selected(vec![("2", "1"), ("3", "2"), ("1", "3"), ("1", "NULL")]));
+}
+
+#[rstest::rstest]
+fn order_by_with_largest_limit_and_offset(database_with_rows: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_rows;
+
+    query(
+        &mut engine,
+        "select * from schema_name.table_name order by col2 limit 9223372036854775807 offset 9223372036854775807;",
+    );
+    collector.assert_receive_many(selected(vec![]));
+}
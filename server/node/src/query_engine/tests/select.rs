@@ -353,3 +353,147 @@ fn select_different_character_strings_types(database_with_schema: (InMemory, Res
         Ok(QueryEvent::RecordsSelected(2)),
     ]);
 }
+
+#[rstest::rstest]
+fn select_computed_columns(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (column_1 smallint, column_2 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1, 2), (3, 4);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    engine
+        .execute(Command::Query {
+            sql: "select column_1 + column_2, column_2 from schema_name.table_name;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("?column?", PgType::SmallInt),
+            ColumnMetadata::new("column_2", PgType::SmallInt),
+        ])),
+        Ok(QueryEvent::DataRow(vec!["3".to_owned(), "2".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["7".to_owned(), "4".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(2)),
+    ]);
+}
+
+#[rstest::rstest]
+fn select_computed_columns_of_filtered_and_sorted_rows(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (column_1 smallint, column_2 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1, 2), (3, 4), (5, 6), (7, 8);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(4)));
+
+    engine
+        .execute(Command::Query {
+            sql: "select column_2 * 10 from schema_name.table_name \
+                  where column_1 > 1 order by column_1 desc limit 2;"
+                .to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "?column?",
+            PgType::SmallInt,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["80".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["60".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(2)),
+    ]);
+}
+
+#[rstest::rstest]
+fn select_constants_for_each_row(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (column_1 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1), (2);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    engine
+        .execute(Command::Query {
+            sql: "select 1, column_1 from schema_name.table_name;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("?column?", PgType::SmallInt),
+            ColumnMetadata::new("column_1", PgType::SmallInt),
+        ])),
+        Ok(QueryEvent::DataRow(vec!["1".to_owned(), "1".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["1".to_owned(), "2".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(2)),
+    ]);
+}
+
+#[rstest::rstest]
+fn select_without_from(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+
+    query(&mut engine, "select 0.1 + 0.2, 'str';");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("?column?", PgType::Numeric),
+            ColumnMetadata::new("?column?", PgType::Text),
+        ])),
+        Ok(QueryEvent::DataRow(vec!["0.3".to_owned(), "str".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+
+    query(&mut engine, "select current_date = cast(now() as date);");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "?column?",
+            PgType::Bool,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["t".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+
+    query(&mut engine, "select count(*) where 1 = 2;");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "count",
+            PgType::BigInt,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["0".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn select_column_without_from(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+
+    query(&mut engine, "select column_1;");
+    collector.assert_receive_single(Err(QueryError::column_does_not_exist("column_1")));
+}
@@ -17,6 +17,7 @@ use pg_model::{
     results::{QueryError, QueryEvent},
     Command,
 };
+use pg_wire::PgFormat;
 
 #[rstest::rstest]
 fn prepare_execute_and_deallocate(database_with_schema: (InMemory, ResultCollector)) {
@@ -294,9 +295,7 @@ fn prepare_reassign_operation_for_specified_rows(database_with_table: (InMemory,
             sql: "execute fooplan(999, 6)".to_owned(),
         })
         .expect("query executed");
-
-    // TODO: `where` clause needs to be handled in `query_planner`.
-    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(2)));
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
 
     engine
         .execute(Command::Query {
@@ -310,8 +309,6 @@ fn prepare_reassign_operation_for_specified_rows(database_with_table: (InMemory,
             sql: "select * from schema_name.table_name".to_owned(),
         })
         .expect("query executed");
-
-    // TODO: `where` clause needs to be handled in `query_planner`.
     collector.assert_receive_many(vec![
         Ok(QueryEvent::RowDescription(vec![
             ColumnMetadata::new("col1", PgType::SmallInt),
@@ -320,7 +317,7 @@ fn prepare_reassign_operation_for_specified_rows(database_with_table: (InMemory,
         ])),
         Ok(QueryEvent::DataRow(vec![
             "1".to_owned(),
-            "999".to_owned(),
+            "2".to_owned(),
             "3".to_owned(),
         ])),
         Ok(QueryEvent::DataRow(vec![
@@ -331,3 +328,86 @@ fn prepare_reassign_operation_for_specified_rows(database_with_table: (InMemory,
         Ok(QueryEvent::RecordsSelected(2)),
     ]);
 }
+
+#[rstest::rstest]
+fn execute_with_wrong_number_of_parameters(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+    engine
+        .execute(Command::Query {
+            sql: "prepare fooplan (smallint, smallint) as insert into schema_name.table_name values ($1, $2)"
+                .to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::StatementPrepared));
+
+    engine
+        .execute(Command::Query {
+            sql: "execute fooplan(123)".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Err(QueryError::protocol_violation(
+        "Bind message supplies 1 parameters, but prepared statement \"fooplan\" requires 2",
+    )));
+}
+
+#[rstest::rstest]
+fn execute_with_arguments_of_declared_types(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+    query(
+        &mut engine,
+        "prepare fooplan (smallint, smallint) as insert into schema_name.table_name values ($1, 456, $2)",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::StatementPrepared));
+
+    query(&mut engine, "execute fooplan('123', -789)");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "execute fooplan(1 + 1, 2)");
+    collector.assert_receive_single(Err(QueryError::feature_not_supported("1 + 1 as argument of EXECUTE")));
+
+    query(&mut engine, "select * from schema_name.table_name");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("col1", PgType::SmallInt),
+            ColumnMetadata::new("col2", PgType::SmallInt),
+            ColumnMetadata::new("col3", PgType::SmallInt),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "123".to_owned(),
+            "456".to_owned(),
+            "-789".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn bind_and_execute_prepared_statement_with_extended_query(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+    engine
+        .execute(Command::Query {
+            sql: "prepare fooplan (smallint, smallint) as insert into schema_name.table_name values ($1, $2)"
+                .to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::StatementPrepared));
+
+    engine
+        .execute(Command::Bind {
+            statement_name: "fooplan".to_owned(),
+            portal_name: "portal_name".to_owned(),
+            param_formats: vec![PgFormat::Text; 2],
+            raw_params: vec![Some(b"123".to_vec()), Some(b"456".to_vec())],
+            result_formats: vec![],
+        })
+        .expect("statement bound to portal");
+    collector.assert_receive_intermediate(Ok(QueryEvent::BindComplete));
+
+    engine
+        .execute(Command::Execute {
+            portal_name: "portal_name".to_owned(),
+            max_rows: 0,
+        })
+        .expect("portal executed");
+    collector.assert_receive_intermediate(Ok(QueryEvent::RecordsInserted(1)));
+}
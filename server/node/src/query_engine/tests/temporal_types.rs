@@ -0,0 +1,219 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::results::{QueryError, QueryEvent};
+
+#[rstest::fixture]
+fn temporal_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (d date, t time, ts timestamp, tz timestamptz, i interval);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values \
+        ('2020-02-29', '13:45:00.5', '2020-02-29 13:45:00', '2020-02-29 13:45:00+03', '1 year 2 months 3 days 04:05:06');",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    (engine, collector)
+}
+
+#[rstest::rstest]
+fn insert_and_select(temporal_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = temporal_table;
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("d", PgType::Date),
+            ColumnMetadata::new("t", PgType::Time),
+            ColumnMetadata::new("ts", PgType::Timestamp),
+            ColumnMetadata::new("tz", PgType::TimestampTz),
+            ColumnMetadata::new("i", PgType::Interval),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "2020-02-29".to_owned(),
+            "13:45:00.5".to_owned(),
+            "2020-02-29 13:45:00".to_owned(),
+            "2020-02-29 10:45:00+00".to_owned(),
+            "1 year 2 mons 3 days 04:05:06".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn date_arithmetic(temporal_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = temporal_table;
+
+    query(
+        &mut engine,
+        "select d + 1, ts + interval '1 month', ts - timestamp '2020-01-01 00:00:00', d - date '2020-01-01' \
+        from schema_name.table_name;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("?column?", PgType::Date),
+            ColumnMetadata::new("?column?", PgType::Timestamp),
+            ColumnMetadata::new("?column?", PgType::Interval),
+            ColumnMetadata::new("?column?", PgType::Integer),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "2020-03-01".to_owned(),
+            "2020-03-29 13:45:00".to_owned(),
+            "59 days 13:45:00".to_owned(),
+            "59".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn extract_and_truncate(temporal_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = temporal_table;
+
+    query(
+        &mut engine,
+        "select extract(year from d), date_trunc('month', ts), date_part('hour', i) from schema_name.table_name;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("date_part", PgType::DoublePrecision),
+            ColumnMetadata::new("date_trunc", PgType::Timestamp),
+            ColumnMetadata::new("date_part", PgType::DoublePrecision),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "2020".to_owned(),
+            "2020-02-01 00:00:00".to_owned(),
+            "4".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn compare_with_strings(temporal_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = temporal_table;
+
+    query(
+        &mut engine,
+        "select d from schema_name.table_name \
+        where ts > '2020-01-01' and tz = '2020-02-29 10:45:00' and i < interval '2 years' and d < now();",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new("d", PgType::Date)])),
+        Ok(QueryEvent::DataRow(vec!["2020-02-29".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn current_date(temporal_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = temporal_table;
+
+    query(
+        &mut engine,
+        "update schema_name.table_name set d = current_date where current_date > d;",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+    query(
+        &mut engine,
+        "select count(*) from schema_name.table_name where d = cast(now() as date);",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "count",
+            PgType::BigInt,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["1".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn invalid_values(temporal_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = temporal_table;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name (d) values ('2020-02-30');",
+    );
+    collector.assert_receive_single(Err(QueryError::invalid_text_representation(PgType::Date, "2020-02-30")));
+
+    query(
+        &mut engine,
+        "select date_part('fortnight', ts) from schema_name.table_name;",
+    );
+    collector.assert_receive_single(Err(QueryError::invalid_parameter_value(
+        "timestamp units \"fortnight\" not recognized",
+    )));
+
+    query(&mut engine, "select d + d from schema_name.table_name;");
+    collector.assert_receive_single(Err(QueryError::undefined_function(
+        "+".to_owned(),
+        "date".to_owned(),
+        "date".to_owned(),
+    )));
+}
+
+#[rstest::rstest]
+fn time_zone_spelled_out(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+
+    query(
+        &mut engine,
+        "create table schema_name.table_name (tz timestamp with time zone, ts timestamp without time zone, \"with\" time);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name values ('2020-02-29 13:45:00+03', '2020-02-29 13:45:00', '13:45:00');",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(
+        &mut engine,
+        "select tz, tz::timestamp with time zone = '2020-02-29 10:45:00+00' from schema_name.table_name;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("tz", PgType::TimestampTz),
+            ColumnMetadata::new("?column?", PgType::Bool),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "2020-02-29 10:45:00+00".to_owned(),
+            "t".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+
+    query(
+        &mut engine,
+        "select ts::time with time zone from schema_name.table_name;",
+    );
+    collector.assert_receive_single(Err(QueryError::type_does_not_exist("timetz")));
+
+    query(
+        &mut engine,
+        "create table schema_name.other_table (t time with time zone);",
+    );
+    collector.assert_receive_single(Err(QueryError::type_does_not_exist("timetz")));
+}
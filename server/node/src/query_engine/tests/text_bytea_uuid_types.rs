@@ -0,0 +1,149 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::results::{QueryError, QueryEvent};
+
+const UUID: &str = "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11";
+
+#[rstest::fixture]
+fn table_with_row(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    query(
+        &mut engine,
+        "create table schema_name.table_name (id uuid default gen_random_uuid(), t text, b bytea, v varchar, c char);",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    query(
+        &mut engine,
+        &format!(
+            "insert into schema_name.table_name values ('{}', 'text of any length', '\\xDEADbeef', 'unbounded', 'c');",
+            UUID.to_uppercase()
+        ),
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    (engine, collector)
+}
+
+#[rstest::rstest]
+fn insert_and_select(table_with_row: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_row;
+
+    query(&mut engine, "select * from schema_name.table_name;");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("id", PgType::Uuid),
+            ColumnMetadata::new("t", PgType::Text),
+            ColumnMetadata::new("b", PgType::Bytea),
+            ColumnMetadata::new("v", PgType::VarChar),
+            ColumnMetadata::new("c", PgType::Char),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            UUID.to_owned(),
+            "text of any length".to_owned(),
+            "\\xdeadbeef".to_owned(),
+            "unbounded".to_owned(),
+            "c".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn hex_and_escape_formats(table_with_row: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_row;
+
+    query(
+        &mut engine,
+        "update schema_name.table_name set b = X'CAFE' where b = '\\xdeadbeef';",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name (b) values ('a\\\\b\\000');",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+
+    query(&mut engine, "select b from schema_name.table_name order by b;");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "b",
+            PgType::Bytea,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["\\x615c6200".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["\\xcafe".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(2)),
+    ]);
+}
+
+#[rstest::rstest]
+fn random_uuids(table_with_row: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_row;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name (t) values ('first'), ('second');",
+    );
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(2)));
+
+    query(
+        &mut engine,
+        &format!(
+            "select t from schema_name.table_name where id <> '{}' and id <> gen_random_uuid() order by t;",
+            UUID
+        ),
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new("t", PgType::Text)])),
+        Ok(QueryEvent::DataRow(vec!["first".to_owned()])),
+        Ok(QueryEvent::DataRow(vec!["second".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(2)),
+    ]);
+
+    query(
+        &mut engine,
+        "select count(*) from schema_name.table_name as l join schema_name.table_name as r on l.id = r.id;",
+    );
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+            "count",
+            PgType::BigInt,
+        )])),
+        Ok(QueryEvent::DataRow(vec!["3".to_owned()])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn invalid_values(table_with_row: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_row;
+
+    query(
+        &mut engine,
+        "insert into schema_name.table_name (id) values ('not-a-uuid');",
+    );
+    collector.assert_receive_single(Err(QueryError::invalid_text_representation(PgType::Uuid, "not-a-uuid")));
+
+    query(&mut engine, "insert into schema_name.table_name (b) values ('\\xabc');");
+    collector.assert_receive_single(Err(QueryError::invalid_text_representation(PgType::Bytea, "\\xabc")));
+
+    query(&mut engine, "insert into schema_name.table_name (c) values ('cc');");
+    collector.assert_receive_single(Err(QueryError::string_length_mismatch(PgType::Char, 1, "c", 1)));
+
+    query(&mut engine, "insert into schema_name.table_name (id) values (1);");
+    collector.assert_receive_single(Err(QueryError::type_mismatch("integer", PgType::Uuid, "id", 1)));
+}
@@ -0,0 +1,262 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pg_model::{
+    results::{QueryError, QueryEvent},
+    Command,
+};
+
+fn in_transaction(event: QueryResult) -> Vec<QueryResult> {
+    vec![event, Ok(QueryEvent::QueryComplete(TransactionStatus::InTransaction))]
+}
+
+#[rstest::rstest]
+fn begin_transaction(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    engine
+        .execute(Command::Query {
+            sql: "begin;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(in_transaction(Ok(QueryEvent::TransactionStarted)));
+}
+
+#[rstest::rstest]
+fn commit_transaction(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    engine
+        .execute(Command::Query {
+            sql: "begin;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(in_transaction(Ok(QueryEvent::TransactionStarted)));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1, 2, 3);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(in_transaction(Ok(QueryEvent::RecordsInserted(1))));
+
+    engine
+        .execute(Command::Query {
+            sql: "commit;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TransactionCommitted));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("col1", PgType::SmallInt),
+            ColumnMetadata::new("col2", PgType::SmallInt),
+            ColumnMetadata::new("col3", PgType::SmallInt),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "1".to_owned(),
+            "2".to_owned(),
+            "3".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+    ]);
+}
+
+#[rstest::rstest]
+fn rollback_transaction(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    engine
+        .execute(Command::Query {
+            sql: "begin;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(in_transaction(Ok(QueryEvent::TransactionStarted)));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1, 2, 3);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(in_transaction(Ok(QueryEvent::RecordsInserted(1))));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("col1", PgType::SmallInt),
+            ColumnMetadata::new("col2", PgType::SmallInt),
+            ColumnMetadata::new("col3", PgType::SmallInt),
+        ])),
+        Ok(QueryEvent::DataRow(vec![
+            "1".to_owned(),
+            "2".to_owned(),
+            "3".to_owned(),
+        ])),
+        Ok(QueryEvent::RecordsSelected(1)),
+        Ok(QueryEvent::QueryComplete(TransactionStatus::InTransaction)),
+    ]);
+
+    engine
+        .execute(Command::Query {
+            sql: "rollback;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TransactionRolledBack));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("col1", PgType::SmallInt),
+            ColumnMetadata::new("col2", PgType::SmallInt),
+            ColumnMetadata::new("col3", PgType::SmallInt),
+        ])),
+        Ok(QueryEvent::RecordsSelected(0)),
+    ]);
+}
+
+#[rstest::rstest]
+fn error_aborts_transaction(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    engine
+        .execute(Command::Query {
+            sql: "begin;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(in_transaction(Ok(QueryEvent::TransactionStarted)));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1, 2, 3);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(in_transaction(Ok(QueryEvent::RecordsInserted(1))));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.non_existent;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(vec![
+        Err(QueryError::table_does_not_exist("schema_name.non_existent")),
+        Ok(QueryEvent::QueryComplete(TransactionStatus::Failed)),
+    ]);
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(vec![
+        Err(QueryError::in_failed_sql_transaction()),
+        Ok(QueryEvent::QueryComplete(TransactionStatus::Failed)),
+    ]);
+
+    engine
+        .execute(Command::Query {
+            sql: "commit;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TransactionRolledBack));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(vec![
+        Ok(QueryEvent::RowDescription(vec![
+            ColumnMetadata::new("col1", PgType::SmallInt),
+            ColumnMetadata::new("col2", PgType::SmallInt),
+            ColumnMetadata::new("col3", PgType::SmallInt),
+        ])),
+        Ok(QueryEvent::RecordsSelected(0)),
+    ]);
+}
+
+#[rstest::rstest]
+fn error_outside_of_transaction_block_does_not_abort_session(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.non_existent;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Err(QueryError::table_does_not_exist("schema_name.non_existent")));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1, 2, 3);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(1)));
+}
+
+#[rstest::rstest]
+fn schema_change_inside_transaction_block(database_with_table: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_table;
+
+    engine
+        .execute(Command::Query {
+            sql: "begin;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(in_transaction(Ok(QueryEvent::TransactionStarted)));
+
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.other_table (col1 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_till_this_moment(vec![
+        Err(QueryError::active_sql_transaction("CREATE TABLE")),
+        Ok(QueryEvent::QueryComplete(TransactionStatus::Failed)),
+    ]);
+
+    engine
+        .execute(Command::Query {
+            sql: "rollback;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TransactionRolledBack));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.other_table;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Err(QueryError::table_does_not_exist("schema_name.other_table")));
+
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.other_table (col1 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+}
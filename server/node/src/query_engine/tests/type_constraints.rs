@@ -26,7 +26,10 @@ fn int_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, Re
             sql: "create table schema_name.table_name(col smallint);".to_owned(),
         })
         .expect("query executed");
-    collector.assert_receive_till_this_moment(vec![Ok(QueryEvent::TableCreated), Ok(QueryEvent::QueryComplete)]);
+    collector.assert_receive_till_this_moment(vec![
+        Ok(QueryEvent::TableCreated),
+        Ok(QueryEvent::QueryComplete(TransactionStatus::Idle)),
+    ]);
 
     (engine, collector)
 }
@@ -40,7 +43,10 @@ fn multiple_ints_table(database_with_schema: (InMemory, ResultCollector)) -> (In
                 .to_owned(),
         })
         .expect("query executed");
-    collector.assert_receive_till_this_moment(vec![Ok(QueryEvent::TableCreated), Ok(QueryEvent::QueryComplete)]);
+    collector.assert_receive_till_this_moment(vec![
+        Ok(QueryEvent::TableCreated),
+        Ok(QueryEvent::QueryComplete(TransactionStatus::Idle)),
+    ]);
 
     (engine, collector)
 }
@@ -53,7 +59,10 @@ fn str_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, Re
             sql: "create table schema_name.table_name(col varchar(5));".to_owned(),
         })
         .expect("query executed");
-    collector.assert_receive_till_this_moment(vec![Ok(QueryEvent::TableCreated), Ok(QueryEvent::QueryComplete)]);
+    collector.assert_receive_till_this_moment(vec![
+        Ok(QueryEvent::TableCreated),
+        Ok(QueryEvent::QueryComplete(TransactionStatus::Idle)),
+    ]);
 
     (engine, collector)
 }
@@ -109,7 +118,6 @@ mod insert {
     }
 
     #[rstest::rstest]
-    // #[ignore] // TODO constraints is going to be reworked
     fn value_too_long(str_table: (InMemory, ResultCollector)) {
         let (mut engine, collector) = str_table;
         engine
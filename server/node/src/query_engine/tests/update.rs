@@ -337,7 +337,6 @@ fn update_non_existent_columns_of_records(database_with_schema: (InMemory, Resul
     collector.assert_receive_many(vec![Err(QueryError::column_does_not_exist("col1"))]);
 }
 
-#[ignore] // TODO: Binary operations is not supported
 #[rstest::rstest]
 #[allow(clippy::identity_op)]
 fn test_update_with_dynamic_expression(database_with_schema: (InMemory, ResultCollector)) {
@@ -431,601 +430,593 @@ fn test_update_with_dynamic_expression(database_with_schema: (InMemory, ResultCo
     ]);
 }
 
-// TODO: operators are not supported
-// #[cfg(test)]
-// mod operators {
-//     use super::*;
-//
-//     #[cfg(test)]
-//     mod mathematical {
-//         use super::*;
-//
-//         #[cfg(test)]
-//         mod integers {
-//             use super::*;
-//
-//             #[rstest::fixture]
-//             fn with_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
-//                 let (mut engine, collector) = database_with_schema;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "create table schema_name.table_name(column_si smallint);".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "insert into schema_name.table_name values (2);".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_till_this_moment(vec![
-//                     Ok(QueryEvent::TableCreated),
-//                     Ok(QueryEvent::QueryComplete),
-//                     Ok(QueryEvent::RecordsInserted(1)),
-//                     Ok(QueryEvent::QueryComplete),
-//                 ]);
-//
-//                 (engine, collector)
-//             }
-//
-//             #[rstest::rstest]
-//             fn addition(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 1 + 2;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["3".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             fn subtraction(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 1 - 2;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["-1".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             fn multiplication(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 3 * 2;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["6".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             fn division(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 8 / 2;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["4".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             fn modulo(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 8 % 2;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["0".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             #[ignore]
-//             // TODO ^ is bitwise in SQL standard
-//             //      # is bitwise in PostgreSQL and it does not supported in sqlparser-rs
-//             fn exponentiation(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 8 ^ 2;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["64".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             #[ignore]
-//             // TODO |/<n> is square root in PostgreSQL and it does not supported in sqlparser-rs
-//             fn square_root(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = |/ 16;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["4".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             #[ignore]
-//             // TODO ||/<n> is cube root in PostgreSQL and it does not supported in sqlparser-rs
-//             fn cube_root(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = ||/ 8;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["2".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             #[ignore]
-//             // TODO <n>! is factorial in PostgreSQL and it does not supported in sqlparser-rs
-//             fn factorial(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 5!;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["120".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             #[ignore]
-//             // TODO !!<n> is prefix factorial in PostgreSQL and it does not supported in sqlparser-rs
-//             fn prefix_factorial(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = !!5;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["120".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             #[ignore]
-//             // TODO @<n> is absolute value in PostgreSQL and it does not supported in sqlparser-rs
-//             fn absolute_value(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = @-5;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["5".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             fn bitwise_and(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 5 & 1;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["1".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             fn bitwise_or(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 5 | 2;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["7".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             #[ignore]
-//             // TODO ~ <n> is bitwise NOT in PostgreSQL and it does not supported in sqlparser-rs
-//             fn bitwise_not(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = ~1;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["-2".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             #[ignore]
-//             // TODO <n> << <m> is bitwise SHIFT LEFT in PostgreSQL and it does not supported in sqlparser-rs
-//             fn bitwise_shift_left(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 1 << 4;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["16".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             #[ignore]
-//             // TODO <n> >> <m> is bitwise SHIFT RIGHT in PostgreSQL and it does not supported in sqlparser-rs
-//             fn bitwise_right_left(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 8 >> 2;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["2".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//
-//             #[rstest::rstest]
-//             fn evaluate_many_operations(with_table: (InMemory, ResultCollector)) {
-//                 let (mut engine, collector) = with_table;
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "update schema_name.table_name set column_si = 5 & 13 % 10 + 1 * 20 - 40 / 4;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//                 engine
-//                     .execute(Command::Query {
-//                         sql: "select * from schema_name.table_name;".to_owned(),
-//                     })
-//                     .expect("query executed");
-//                 collector.assert_receive_many(vec![
-//                     Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                         "column_si",
-//                         PgType::SmallInt,
-//                     )])),
-//                     Ok(QueryEvent::DataRow(vec!["5".to_owned()])),
-//                     Ok(QueryEvent::RecordsSelected(1)),
-//                 ]);
-//             }
-//         }
-//     }
-//
-//     #[cfg(test)]
-//     mod string {
-//         use super::*;
-//
-//         #[rstest::fixture]
-//         fn with_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
-//             let (mut engine, collector) = database_with_schema;
-//             engine
-//                 .execute(Command::Query {
-//                     sql: "create table schema_name.table_name(strings char(5));".to_owned(),
-//                 })
-//                 .expect("query executed");
-//             engine
-//                 .execute(Command::Query {
-//                     sql: "insert into schema_name.table_name values ('x');".to_owned(),
-//                 })
-//                 .expect("query executed");
-//             collector.assert_receive_till_this_moment(vec![
-//                 Ok(QueryEvent::TableCreated),
-//                 Ok(QueryEvent::QueryComplete),
-//                 Ok(QueryEvent::RecordsInserted(1)),
-//                 Ok(QueryEvent::QueryComplete),
-//             ]);
-//
-//             (engine, collector)
-//         }
-//
-//         #[rstest::rstest]
-//         fn concatenation(with_table: (InMemory, ResultCollector)) {
-//             let (mut engine, collector) = with_table;
-//             engine
-//                 .execute(Command::Query {
-//                     sql: "update schema_name.table_name set strings = '123' || '45';".to_owned(),
-//                 })
-//                 .expect("query executed");
-//             collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//             engine
-//                 .execute(Command::Query {
-//                     sql: "select * from schema_name.table_name;".to_owned(),
-//                 })
-//                 .expect("query executed");
-//             collector.assert_receive_many(vec![
-//                 Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                     "strings",
-//                     PgType::Char,
-//                 )])),
-//                 Ok(QueryEvent::DataRow(vec!["12345".to_owned()])),
-//                 Ok(QueryEvent::RecordsSelected(1)),
-//             ]);
-//         }
-//
-//         #[rstest::rstest]
-//         fn concatenation_with_number(with_table: (InMemory, ResultCollector)) {
-//             let (mut engine, collector) = with_table;
-//             engine
-//                 .execute(Command::Query {
-//                     sql: "update schema_name.table_name set strings = 1 || '45';".to_owned(),
-//                 })
-//                 .expect("query executed");
-//             collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//             engine
-//                 .execute(Command::Query {
-//                     sql: "select * from schema_name.table_name;".to_owned(),
-//                 })
-//                 .expect("query executed");
-//             collector.assert_receive_many(vec![
-//                 Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                     "strings",
-//                     PgType::Char,
-//                 )])),
-//                 Ok(QueryEvent::DataRow(vec!["145".to_owned()])),
-//                 Ok(QueryEvent::RecordsSelected(1)),
-//             ]);
-//
-//             engine
-//                 .execute(Command::Query {
-//                     sql: "update schema_name.table_name set strings = '45' || 1;".to_owned(),
-//                 })
-//                 .expect("query executed");
-//             collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
-//
-//             engine
-//                 .execute(Command::Query {
-//                     sql: "select * from schema_name.table_name;".to_owned(),
-//                 })
-//                 .expect("query executed");
-//             collector.assert_receive_many(vec![
-//                 Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
-//                     "strings",
-//                     PgType::Char,
-//                 )])),
-//                 Ok(QueryEvent::DataRow(vec!["451".to_owned()])),
-//                 Ok(QueryEvent::RecordsSelected(1)),
-//             ]);
-//         }
-//
-//         #[rstest::rstest]
-//         fn non_string_concatenation_not_supported(with_table: (InMemory, ResultCollector)) {
-//             let (mut engine, collector) = with_table;
-//             engine
-//                 .execute(Command::Query {
-//                     sql: "update schema_name.table_name set strings = 1 || 2;".to_owned(),
-//                 })
-//                 .expect("query executed");
-//             collector.assert_receive_single(Err(QueryError::undefined_function(
-//                 "||".to_owned(),
-//                 "NUMBER".to_owned(),
-//                 "NUMBER".to_owned(),
-//             )));
-//         }
-//     }
-// }
+#[cfg(test)]
+mod operators {
+    use super::*;
+
+    #[cfg(test)]
+    mod mathematical {
+        use super::*;
+
+        #[cfg(test)]
+        mod integers {
+            use super::*;
+
+            #[rstest::fixture]
+            fn with_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+                let (mut engine, collector) = database_with_schema;
+                engine
+                    .execute(Command::Query {
+                        sql: "create table schema_name.table_name(column_si smallint);".to_owned(),
+                    })
+                    .expect("query executed");
+                engine
+                    .execute(Command::Query {
+                        sql: "insert into schema_name.table_name values (2);".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_till_this_moment(vec![
+                    Ok(QueryEvent::TableCreated),
+                    Ok(QueryEvent::QueryComplete(TransactionStatus::Idle)),
+                    Ok(QueryEvent::RecordsInserted(1)),
+                    Ok(QueryEvent::QueryComplete(TransactionStatus::Idle)),
+                ]);
+
+                (engine, collector)
+            }
+
+            #[rstest::rstest]
+            fn addition(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 1 + 2;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["3".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn subtraction(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 1 - 2;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["-1".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn multiplication(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 3 * 2;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["6".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn division(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 8 / 2;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["4".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn modulo(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 8 % 2;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["0".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn exponentiation(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 8 ^ 2;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["64".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            #[ignore]
+            // TODO |/<n> is square root in PostgreSQL and it does not supported in sqlparser-rs
+            fn square_root(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = |/ 16;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["4".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            #[ignore]
+            // TODO ||/<n> is cube root in PostgreSQL and it does not supported in sqlparser-rs
+            fn cube_root(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = ||/ 8;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["2".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            #[ignore]
+            // TODO <n>! is factorial in PostgreSQL and it does not supported in sqlparser-rs
+            fn factorial(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 5!;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["120".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            #[ignore]
+            // TODO !!<n> is prefix factorial in PostgreSQL and it does not supported in sqlparser-rs
+            fn prefix_factorial(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = !!5;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["120".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            #[ignore]
+            // TODO @<n> is absolute value in PostgreSQL and it does not supported in sqlparser-rs
+            fn absolute_value(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = @-5;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["5".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn bitwise_and(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 5 & 1;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["1".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn bitwise_or(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 5 | 2;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["7".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            #[ignore]
+            // TODO ~ <n> is bitwise NOT in PostgreSQL and it does not supported in sqlparser-rs
+            fn bitwise_not(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = ~1;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["-2".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn bitwise_shift_left(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 1 << 4;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["16".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn bitwise_right_left(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 8 >> 2;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["2".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+
+            #[rstest::rstest]
+            fn evaluate_many_operations(with_table: (InMemory, ResultCollector)) {
+                let (mut engine, collector) = with_table;
+                engine
+                    .execute(Command::Query {
+                        sql: "update schema_name.table_name set column_si = 5 & 13 % 10 + 1 * 20 - 40 / 4;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+                engine
+                    .execute(Command::Query {
+                        sql: "select * from schema_name.table_name;".to_owned(),
+                    })
+                    .expect("query executed");
+                collector.assert_receive_many(vec![
+                    Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                        "column_si",
+                        PgType::SmallInt,
+                    )])),
+                    Ok(QueryEvent::DataRow(vec!["5".to_owned()])),
+                    Ok(QueryEvent::RecordsSelected(1)),
+                ]);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod string {
+        use super::*;
+
+        #[rstest::fixture]
+        fn with_table(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+            let (mut engine, collector) = database_with_schema;
+            engine
+                .execute(Command::Query {
+                    sql: "create table schema_name.table_name(strings char(5));".to_owned(),
+                })
+                .expect("query executed");
+            engine
+                .execute(Command::Query {
+                    sql: "insert into schema_name.table_name values ('x');".to_owned(),
+                })
+                .expect("query executed");
+            collector.assert_receive_till_this_moment(vec![
+                Ok(QueryEvent::TableCreated),
+                Ok(QueryEvent::QueryComplete(TransactionStatus::Idle)),
+                Ok(QueryEvent::RecordsInserted(1)),
+                Ok(QueryEvent::QueryComplete(TransactionStatus::Idle)),
+            ]);
+
+            (engine, collector)
+        }
+
+        #[rstest::rstest]
+        fn concatenation(with_table: (InMemory, ResultCollector)) {
+            let (mut engine, collector) = with_table;
+            engine
+                .execute(Command::Query {
+                    sql: "update schema_name.table_name set strings = '123' || '45';".to_owned(),
+                })
+                .expect("query executed");
+            collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+            engine
+                .execute(Command::Query {
+                    sql: "select * from schema_name.table_name;".to_owned(),
+                })
+                .expect("query executed");
+            collector.assert_receive_many(vec![
+                Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                    "strings",
+                    PgType::Char,
+                )])),
+                Ok(QueryEvent::DataRow(vec!["12345".to_owned()])),
+                Ok(QueryEvent::RecordsSelected(1)),
+            ]);
+        }
+
+        #[rstest::rstest]
+        fn concatenation_with_number(with_table: (InMemory, ResultCollector)) {
+            let (mut engine, collector) = with_table;
+            engine
+                .execute(Command::Query {
+                    sql: "update schema_name.table_name set strings = 1 || '45';".to_owned(),
+                })
+                .expect("query executed");
+            collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+            engine
+                .execute(Command::Query {
+                    sql: "select * from schema_name.table_name;".to_owned(),
+                })
+                .expect("query executed");
+            collector.assert_receive_many(vec![
+                Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                    "strings",
+                    PgType::Char,
+                )])),
+                Ok(QueryEvent::DataRow(vec!["145".to_owned()])),
+                Ok(QueryEvent::RecordsSelected(1)),
+            ]);
+
+            engine
+                .execute(Command::Query {
+                    sql: "update schema_name.table_name set strings = '45' || 1;".to_owned(),
+                })
+                .expect("query executed");
+            collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+            engine
+                .execute(Command::Query {
+                    sql: "select * from schema_name.table_name;".to_owned(),
+                })
+                .expect("query executed");
+            collector.assert_receive_many(vec![
+                Ok(QueryEvent::RowDescription(vec![ColumnMetadata::new(
+                    "strings",
+                    PgType::Char,
+                )])),
+                Ok(QueryEvent::DataRow(vec!["451".to_owned()])),
+                Ok(QueryEvent::RecordsSelected(1)),
+            ]);
+        }
+
+        #[rstest::rstest]
+        fn non_string_concatenation_not_supported(with_table: (InMemory, ResultCollector)) {
+            let (mut engine, collector) = with_table;
+            engine
+                .execute(Command::Query {
+                    sql: "update schema_name.table_name set strings = 1 || 2;".to_owned(),
+                })
+                .expect("query executed");
+            collector.assert_receive_single(Err(QueryError::undefined_function(
+                "||".to_owned(),
+                "smallint".to_owned(),
+                "smallint".to_owned(),
+            )));
+        }
+    }
+}
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use super::*;
-use pg_model::{results::QueryEvent, Command};
+use pg_model::{
+    results::{QueryError, QueryEvent},
+    Command,
+};
 
 #[rstest::rstest]
 fn select_row_by_column_equality_predicate(database_with_schema: (InMemory, ResultCollector)) {
@@ -52,3 +55,207 @@ fn select_row_by_column_equality_predicate(database_with_schema: (InMemory, Resu
         Ok(QueryEvent::RecordsSelected(1)),
     ]);
 }
+
+#[rstest::rstest]
+fn non_boolean_predicate(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (column_1 smallint, column_2 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where column_1 + 1;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Err(QueryError::datatype_mismatch("WHERE", "boolean", "smallint")));
+
+    engine
+        .execute(Command::Query {
+            sql: "delete from schema_name.table_name where column_1 and column_2;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Err(QueryError::datatype_mismatch("AND", "boolean", "smallint")));
+}
+
+#[rstest::rstest]
+fn predicate_with_undefined_operator(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (column_1 smallint, column_2 varchar(10));".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    engine
+        .execute(Command::Query {
+            sql: "update schema_name.table_name set column_1 = 1 where column_1 = column_2;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Err(QueryError::undefined_function("=", "smallint", "text")));
+}
+
+#[rstest::fixture]
+fn table_with_nulls(database_with_schema: (InMemory, ResultCollector)) -> (InMemory, ResultCollector) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (column_1 smallint, column_2 smallint);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    engine
+        .execute(Command::Query {
+            sql: "insert into schema_name.table_name values (1, 4), (2, null), (3, 6);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsInserted(3)));
+
+    (engine, collector)
+}
+
+fn selected_rows(rows: Vec<(&str, &str)>) -> Vec<QueryResult> {
+    let len = rows.len();
+    iter::once(Ok(QueryEvent::RowDescription(vec![
+        ColumnMetadata::new("column_1", PgType::SmallInt),
+        ColumnMetadata::new("column_2", PgType::SmallInt),
+    ])))
+    .chain(
+        rows.into_iter()
+            .map(|(first, second)| Ok(QueryEvent::DataRow(vec![first.to_owned(), second.to_owned()]))),
+    )
+    .chain(iter::once(Ok(QueryEvent::RecordsSelected(len))))
+    .collect()
+}
+
+#[rstest::rstest]
+fn select_rows_where_column_is_null(table_with_nulls: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_nulls;
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where column_2 is null;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(selected_rows(vec![("2", "NULL")]));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where column_2 is not null;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(selected_rows(vec![("1", "4"), ("3", "6")]));
+}
+
+#[rstest::rstest]
+fn select_rows_by_negated_predicate(table_with_nulls: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_nulls;
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where not column_1 = 1;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(selected_rows(vec![("2", "NULL"), ("3", "6")]));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where not column_2 = 4;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(selected_rows(vec![("3", "6")]));
+}
+
+#[rstest::rstest]
+fn select_rows_by_parenthesized_expression(table_with_nulls: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_nulls;
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where (column_1 + column_2) * 2 > 12;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(selected_rows(vec![("3", "6")]));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where -column_1 < -1;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(selected_rows(vec![("2", "NULL"), ("3", "6")]));
+}
+
+#[rstest::rstest]
+fn select_rows_by_range_and_list(table_with_nulls: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_nulls;
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where column_1 between 2 and 3;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(selected_rows(vec![("2", "NULL"), ("3", "6")]));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where column_1 not in (1, 3);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(selected_rows(vec![("2", "NULL")]));
+}
+
+#[rstest::rstest]
+fn update_and_delete_by_null_predicates(table_with_nulls: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = table_with_nulls;
+
+    engine
+        .execute(Command::Query {
+            sql: "update schema_name.table_name set column_2 = 0 where column_2 is null;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsUpdated(1)));
+
+    engine
+        .execute(Command::Query {
+            sql: "delete from schema_name.table_name where not (column_2 = 0 or column_1 = 1);".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::RecordsDeleted(1)));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_many(selected_rows(vec![("1", "4"), ("2", "0")]));
+}
+
+#[rstest::rstest]
+fn unary_operators_with_wrong_operand_types(database_with_schema: (InMemory, ResultCollector)) {
+    let (mut engine, collector) = database_with_schema;
+    engine
+        .execute(Command::Query {
+            sql: "create table schema_name.table_name (column_1 smallint, column_2 varchar(10));".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Ok(QueryEvent::TableCreated));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where not column_1;".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Err(QueryError::datatype_mismatch("NOT", "boolean", "smallint")));
+
+    engine
+        .execute(Command::Query {
+            sql: "select * from schema_name.table_name where -column_2 = 'a';".to_owned(),
+        })
+        .expect("query executed");
+    collector.assert_receive_single(Err(QueryError::undefined_prefix_operator("-", "text")));
+}
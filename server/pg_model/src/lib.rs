@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pg_wire::{Error, PgFormat, PgType, Result};
+use crate::pg_types::PgType;
+use pg_wire::{Error, PgFormat, Result};
 use rand::Rng;
 use std::{
     collections::{HashMap, VecDeque},
     path::PathBuf,
 };
 
+/// Module contains PostgreSQL types and their representation in wire protocol
+pub mod pg_types;
 /// Module contains functionality to represent query result
 pub mod results;
 /// Module contains functionality to represent server side client session
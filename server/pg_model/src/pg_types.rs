@@ -0,0 +1,576 @@
+// Copyright 2020 - present Alex Dukhno
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use pg_wire::{PgFormat, Value};
+use std::{
+    convert::TryInto,
+    fmt::{self, Display, Formatter},
+};
+
+/// PostgreSQL data types that server sends to and receives from clients
+///
+/// `pg_wire` knows only about a subset of them, thus type OIDs, sizes and
+/// decoding of parameters of the other types are handled here
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+pub enum PgType {
+    /// boolean
+    Bool,
+    /// single-byte character
+    Char,
+    /// variable-length character string
+    VarChar,
+    /// 2-byte signed integer
+    SmallInt,
+    /// 4-byte signed integer
+    Integer,
+    /// 8-byte signed integer
+    BigInt,
+    /// single precision floating-point number
+    Real,
+    /// double precision floating-point number
+    DoublePrecision,
+    /// exact numeric of selectable precision
+    Numeric,
+    /// calendar date
+    Date,
+    /// time of day without time zone
+    Time,
+    /// date and time without time zone
+    Timestamp,
+    /// date and time including time zone
+    TimestampTz,
+    /// time span
+    Interval,
+    /// variable-length character string without limit
+    Text,
+    /// binary data
+    Bytea,
+    /// universally unique identifier
+    Uuid,
+    /// textual JSON data
+    Json,
+    /// binary JSON data, decomposed
+    Jsonb,
+}
+
+impl PgType {
+    /// OID of the type in `pg_type` system catalog
+    pub fn type_oid(&self) -> u32 {
+        match self {
+            PgType::Bool => 16,
+            PgType::Char => 18,
+            PgType::VarChar => 1043,
+            PgType::SmallInt => 21,
+            PgType::Integer => 23,
+            PgType::BigInt => 20,
+            PgType::Real => 700,
+            PgType::DoublePrecision => 701,
+            PgType::Numeric => 1700,
+            PgType::Date => 1082,
+            PgType::Time => 1083,
+            PgType::Timestamp => 1114,
+            PgType::TimestampTz => 1184,
+            PgType::Interval => 1186,
+            PgType::Text => 25,
+            PgType::Bytea => 17,
+            PgType::Uuid => 2950,
+            PgType::Json => 114,
+            PgType::Jsonb => 3802,
+        }
+    }
+
+    /// Size of the type in bytes, negative values denote variable-width types
+    pub fn type_len(&self) -> i16 {
+        match self {
+            PgType::Bool => 1,
+            PgType::Char => 1,
+            PgType::VarChar => -1,
+            PgType::SmallInt => 2,
+            PgType::Integer => 4,
+            PgType::BigInt => 8,
+            PgType::Real => 4,
+            PgType::DoublePrecision => 8,
+            PgType::Numeric => -1,
+            PgType::Date => 4,
+            PgType::Time => 8,
+            PgType::Timestamp => 8,
+            PgType::TimestampTz => 8,
+            PgType::Interval => 16,
+            PgType::Text => -1,
+            PgType::Bytea => -1,
+            PgType::Uuid => 16,
+            PgType::Json => -1,
+            PgType::Jsonb => -1,
+        }
+    }
+
+    /// Decodes parameter value sent by client in the given format
+    pub fn decode(&self, format: &PgFormat, raw: &[u8]) -> Result<Value, String> {
+        match self.wire_type() {
+            Some(wire_type) => wire_type.decode(format, raw),
+            None => match format {
+                PgFormat::Text => self.decode_text(raw).map(Value::String),
+                PgFormat::Binary => self.decode_binary(raw).map(Value::String),
+            },
+        }
+    }
+
+    fn wire_type(&self) -> Option<pg_wire::PgType> {
+        match self {
+            PgType::Bool => Some(pg_wire::PgType::Bool),
+            PgType::Char => Some(pg_wire::PgType::Char),
+            PgType::VarChar => Some(pg_wire::PgType::VarChar),
+            PgType::SmallInt => Some(pg_wire::PgType::SmallInt),
+            PgType::Integer => Some(pg_wire::PgType::Integer),
+            PgType::BigInt => Some(pg_wire::PgType::BigInt),
+            _ => None,
+        }
+    }
+
+    fn decode_text(&self, raw: &[u8]) -> Result<String, String> {
+        std::str::from_utf8(raw)
+            .map(ToOwned::to_owned)
+            .map_err(|_| format!("invalid byte sequence for type {}", self))
+    }
+
+    fn decode_binary(&self, raw: &[u8]) -> Result<String, String> {
+        match self {
+            PgType::Real => match raw {
+                [b0, b1, b2, b3] => Ok(f32::from_be_bytes([*b0, *b1, *b2, *b3]).to_string()),
+                _ => Err(self.incorrect_binary_format()),
+            },
+            PgType::DoublePrecision => {
+                if raw.len() != 8 {
+                    return Err(self.incorrect_binary_format());
+                }
+                let mut bytes = [0; 8];
+                bytes.copy_from_slice(raw);
+                Ok(f64::from_be_bytes(bytes).to_string())
+            }
+            PgType::Numeric => self.decode_numeric(raw),
+            PgType::Date => {
+                let days = raw
+                    .try_into()
+                    .map(i32::from_be_bytes)
+                    .map_err(|_| self.incorrect_binary_format())?;
+                epoch()
+                    .date()
+                    .checked_add_signed(Duration::days(days as i64))
+                    .map(|date| date.to_string())
+                    .ok_or_else(|| self.out_of_range())
+            }
+            PgType::Time => {
+                let micros = raw
+                    .try_into()
+                    .map(i64::from_be_bytes)
+                    .map_err(|_| self.incorrect_binary_format())?;
+                if !(0..24 * 3_600 * 1_000_000).contains(&micros) {
+                    return Err(self.out_of_range());
+                }
+                let midnight = NaiveTime::from_hms(0, 0, 0);
+                Ok((midnight + Duration::microseconds(micros)).to_string())
+            }
+            PgType::Timestamp | PgType::TimestampTz => {
+                let micros = raw
+                    .try_into()
+                    .map(i64::from_be_bytes)
+                    .map_err(|_| self.incorrect_binary_format())?;
+                let timestamp = epoch()
+                    .checked_add_signed(Duration::microseconds(micros))
+                    .ok_or_else(|| self.out_of_range())?;
+                match self {
+                    PgType::TimestampTz => Ok(format!("{}+00", timestamp)),
+                    _ => Ok(timestamp.to_string()),
+                }
+            }
+            PgType::Interval => {
+                if raw.len() != 16 {
+                    return Err(self.incorrect_binary_format());
+                }
+                let micros = i64::from_be_bytes(raw[0..8].try_into().unwrap());
+                let days = i32::from_be_bytes(raw[8..12].try_into().unwrap());
+                let months = i32::from_be_bytes(raw[12..16].try_into().unwrap());
+                Ok(format!("{} months {} days {} microseconds", months, days, micros))
+            }
+            PgType::Text | PgType::Json => self.decode_text(raw),
+            // jsonb is sent as its text representation prefixed with format version
+            PgType::Jsonb => match raw.split_first() {
+                Some((1, document)) => self.decode_text(document),
+                _ => Err(self.incorrect_binary_format()),
+            },
+            PgType::Bytea => Ok(raw.iter().fold("\\x".to_owned(), |mut hex, byte| {
+                hex.push_str(&format!("{:02x}", byte));
+                hex
+            })),
+            PgType::Uuid => {
+                if raw.len() != 16 {
+                    return Err(self.incorrect_binary_format());
+                }
+                let hex = raw.iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+                Ok(format!(
+                    "{}-{}-{}-{}-{}",
+                    &hex[0..8],
+                    &hex[8..12],
+                    &hex[12..16],
+                    &hex[16..20],
+                    &hex[20..32]
+                ))
+            }
+            _ => Err(format!("binary format of type {} is not supported", self)),
+        }
+    }
+
+    // numeric is sent as number of base-10000 digits, weight of the first digit,
+    // sign and display scale followed by the digits
+    fn decode_numeric(&self, raw: &[u8]) -> Result<String, String> {
+        let words = raw
+            .chunks(2)
+            .map(|word| match word {
+                [high, low] => Ok(u16::from_be_bytes([*high, *low])),
+                _ => Err(self.incorrect_binary_format()),
+            })
+            .collect::<Result<Vec<u16>, String>>()?;
+        let (ndigits, weight, sign, dscale, digits) = match words.as_slice() {
+            [ndigits, weight, sign, dscale, digits @ ..] => (*ndigits as usize, *weight as i16, *sign, *dscale, digits),
+            _ => return Err(self.incorrect_binary_format()),
+        };
+        if digits.len() != ndigits {
+            return Err(self.incorrect_binary_format());
+        }
+        let sign = match sign {
+            0x0000 => "",
+            0x4000 => "-",
+            0xC000 => return Ok("NaN".to_owned()),
+            _ => return Err(self.incorrect_binary_format()),
+        };
+        let digit = |index: i32| -> u16 {
+            if index < 0 {
+                0
+            } else {
+                digits.get(index as usize).copied().unwrap_or(0)
+            }
+        };
+
+        let mut number = sign.to_owned();
+        if weight < 0 {
+            number.push('0');
+        } else {
+            number.push_str(&digit(0).to_string());
+            for index in 1..=weight as i32 {
+                number.push_str(&format!("{:04}", digit(index)));
+            }
+        }
+        if dscale > 0 {
+            let mut fraction = String::new();
+            for group in 1..=(dscale as i32 + 3) / 4 {
+                fraction.push_str(&format!("{:04}", digit(weight as i32 + group)));
+            }
+            fraction.truncate(dscale as usize);
+            number.push('.');
+            number.push_str(&fraction);
+        }
+        Ok(number)
+    }
+
+    fn out_of_range(&self) -> String {
+        format!("{} out of range", self)
+    }
+
+    fn incorrect_binary_format(&self) -> String {
+        format!("incorrect binary data format of type {}", self)
+    }
+}
+
+// dates and timestamps are sent as number of days or microseconds since 2000-01-01
+fn epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd(2000, 1, 1).and_hms(0, 0, 0)
+}
+
+impl From<pg_wire::PgType> for PgType {
+    fn from(wire_type: pg_wire::PgType) -> PgType {
+        match wire_type {
+            pg_wire::PgType::Bool => PgType::Bool,
+            pg_wire::PgType::Char => PgType::Char,
+            pg_wire::PgType::VarChar => PgType::VarChar,
+            pg_wire::PgType::SmallInt => PgType::SmallInt,
+            pg_wire::PgType::Integer => PgType::Integer,
+            pg_wire::PgType::BigInt => PgType::BigInt,
+        }
+    }
+}
+
+impl Display for PgType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PgType::Bool => write!(f, "boolean"),
+            PgType::Char => write!(f, "character"),
+            PgType::VarChar => write!(f, "variable character"),
+            PgType::SmallInt => write!(f, "smallint"),
+            PgType::Integer => write!(f, "integer"),
+            PgType::BigInt => write!(f, "bigint"),
+            PgType::Real => write!(f, "real"),
+            PgType::DoublePrecision => write!(f, "double precision"),
+            PgType::Numeric => write!(f, "numeric"),
+            PgType::Date => write!(f, "date"),
+            PgType::Time => write!(f, "time without time zone"),
+            PgType::Timestamp => write!(f, "timestamp without time zone"),
+            PgType::TimestampTz => write!(f, "timestamp with time zone"),
+            PgType::Interval => write!(f, "interval"),
+            PgType::Text => write!(f, "text"),
+            PgType::Bytea => write!(f, "bytea"),
+            PgType::Uuid => write!(f, "uuid"),
+            PgType::Json => write!(f, "json"),
+            PgType::Jsonb => write!(f, "jsonb"),
+        }
+    }
+}
+
+/// Description of a column that is sent to client in `RowDescription` message
+#[derive(Debug, PartialEq, Clone)]
+pub struct ColumnMetadata {
+    /// name of the column
+    pub name: String,
+    /// OID of the column type
+    pub type_id: u32,
+    /// size of the column type
+    pub type_size: i16,
+}
+
+impl ColumnMetadata {
+    /// Creates column description of the given type
+    pub fn new<S: ToString>(name: S, pg_type: PgType) -> ColumnMetadata {
+        ColumnMetadata {
+            name: name.to_string(),
+            type_id: pg_type.type_oid(),
+            type_size: pg_type.type_len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(test)]
+    mod decode {
+        use super::*;
+
+        #[test]
+        fn real_in_text_format() {
+            assert_eq!(
+                PgType::Real.decode(&PgFormat::Text, b"1.5"),
+                Ok(Value::String("1.5".to_owned()))
+            );
+        }
+
+        #[test]
+        fn real_in_binary_format() {
+            assert_eq!(
+                PgType::Real.decode(&PgFormat::Binary, &1.5f32.to_be_bytes()),
+                Ok(Value::String("1.5".to_owned()))
+            );
+        }
+
+        #[test]
+        fn double_precision_in_binary_format() {
+            assert_eq!(
+                PgType::DoublePrecision.decode(&PgFormat::Binary, &(-2.25f64).to_be_bytes()),
+                Ok(Value::String("-2.25".to_owned()))
+            );
+        }
+
+        #[test]
+        fn double_precision_of_wrong_size() {
+            assert_eq!(
+                PgType::DoublePrecision.decode(&PgFormat::Binary, &1.5f32.to_be_bytes()),
+                Err("incorrect binary data format of type double precision".to_owned())
+            );
+        }
+
+        #[test]
+        fn numeric_in_binary_format() {
+            assert_eq!(
+                PgType::Numeric.decode(&PgFormat::Binary, &[0, 3, 0, 1, 0, 0, 0, 3, 0, 1, 9, 41, 26, 124]),
+                Ok(Value::String("12345.678".to_owned()))
+            );
+        }
+
+        #[test]
+        fn negative_numeric_less_than_one() {
+            assert_eq!(
+                PgType::Numeric.decode(&PgFormat::Binary, &[0, 1, 255, 255, 64, 0, 0, 2, 1, 244]),
+                Ok(Value::String("-0.05".to_owned()))
+            );
+        }
+
+        #[test]
+        fn numeric_with_trailing_zeros() {
+            assert_eq!(
+                PgType::Numeric.decode(&PgFormat::Binary, &[0, 1, 0, 1, 0, 0, 0, 0, 0, 1]),
+                Ok(Value::String("10000".to_owned()))
+            );
+        }
+
+        #[test]
+        fn numeric_with_missing_digits() {
+            assert_eq!(
+                PgType::Numeric.decode(&PgFormat::Binary, &[0, 2, 0, 0, 0, 0, 0, 0, 0, 1]),
+                Err("incorrect binary data format of type numeric".to_owned())
+            );
+        }
+
+        #[test]
+        fn date_in_binary_format() {
+            assert_eq!(
+                PgType::Date.decode(&PgFormat::Binary, &7364i32.to_be_bytes()),
+                Ok(Value::String("2020-02-29".to_owned()))
+            );
+        }
+
+        #[test]
+        fn date_before_epoch() {
+            assert_eq!(
+                PgType::Date.decode(&PgFormat::Binary, &(-1i32).to_be_bytes()),
+                Ok(Value::String("1999-12-31".to_owned()))
+            );
+        }
+
+        #[test]
+        fn time_in_binary_format() {
+            assert_eq!(
+                PgType::Time.decode(&PgFormat::Binary, &49_500_500_000i64.to_be_bytes()),
+                Ok(Value::String("13:45:00.500".to_owned()))
+            );
+        }
+
+        #[test]
+        fn time_out_of_range() {
+            assert_eq!(
+                PgType::Time.decode(&PgFormat::Binary, &(-1i64).to_be_bytes()),
+                Err("time without time zone out of range".to_owned())
+            );
+        }
+
+        #[test]
+        fn timestamp_in_binary_format() {
+            assert_eq!(
+                PgType::Timestamp.decode(&PgFormat::Binary, &636_299_100_000_000i64.to_be_bytes()),
+                Ok(Value::String("2020-02-29 13:45:00".to_owned()))
+            );
+        }
+
+        #[test]
+        fn timestamp_with_time_zone_in_binary_format() {
+            assert_eq!(
+                PgType::TimestampTz.decode(&PgFormat::Binary, &636_299_100_000_000i64.to_be_bytes()),
+                Ok(Value::String("2020-02-29 13:45:00+00".to_owned()))
+            );
+        }
+
+        #[test]
+        fn interval_in_binary_format() {
+            let mut raw = 14_706_000_000i64.to_be_bytes().to_vec();
+            raw.extend_from_slice(&3i32.to_be_bytes());
+            raw.extend_from_slice(&14i32.to_be_bytes());
+            assert_eq!(
+                PgType::Interval.decode(&PgFormat::Binary, &raw),
+                Ok(Value::String("14 months 3 days 14706000000 microseconds".to_owned()))
+            );
+        }
+
+        #[test]
+        fn date_of_wrong_size() {
+            assert_eq!(
+                PgType::Date.decode(&PgFormat::Binary, &1i64.to_be_bytes()),
+                Err("incorrect binary data format of type date".to_owned())
+            );
+        }
+
+        #[test]
+        fn text_in_binary_format() {
+            assert_eq!(
+                PgType::Text.decode(&PgFormat::Binary, "日本".as_bytes()),
+                Ok(Value::String("日本".to_owned()))
+            );
+        }
+
+        #[test]
+        fn bytea_in_binary_format() {
+            assert_eq!(
+                PgType::Bytea.decode(&PgFormat::Binary, &[0, 1, 171, 255]),
+                Ok(Value::String("\\x0001abff".to_owned()))
+            );
+        }
+
+        #[test]
+        fn bytea_in_text_format() {
+            assert_eq!(
+                PgType::Bytea.decode(&PgFormat::Text, b"\\x0001abff"),
+                Ok(Value::String("\\x0001abff".to_owned()))
+            );
+        }
+
+        #[test]
+        fn uuid_in_binary_format() {
+            assert_eq!(
+                PgType::Uuid.decode(
+                    &PgFormat::Binary,
+                    &[160, 238, 188, 153, 156, 11, 78, 248, 187, 109, 107, 185, 189, 56, 10, 17]
+                ),
+                Ok(Value::String("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11".to_owned()))
+            );
+        }
+
+        #[test]
+        fn uuid_of_wrong_size() {
+            assert_eq!(
+                PgType::Uuid.decode(&PgFormat::Binary, &[160, 238]),
+                Err("incorrect binary data format of type uuid".to_owned())
+            );
+        }
+
+        #[test]
+        fn json_in_binary_format() {
+            assert_eq!(
+                PgType::Json.decode(&PgFormat::Binary, br#"{"a": 1}"#),
+                Ok(Value::String(r#"{"a": 1}"#.to_owned()))
+            );
+        }
+
+        #[test]
+        fn jsonb_in_binary_format() {
+            assert_eq!(
+                PgType::Jsonb.decode(&PgFormat::Binary, b"\x01{\"a\": 1}"),
+                Ok(Value::String(r#"{"a": 1}"#.to_owned()))
+            );
+        }
+
+        #[test]
+        fn jsonb_of_unknown_version() {
+            assert_eq!(
+                PgType::Jsonb.decode(&PgFormat::Binary, b"\x02{}"),
+                Err("incorrect binary data format of type jsonb".to_owned())
+            );
+        }
+
+        #[test]
+        fn types_known_to_wire_protocol() {
+            assert_eq!(
+                PgType::SmallInt.decode(&PgFormat::Binary, &1i16.to_be_bytes()),
+                Ok(Value::Int16(1))
+            );
+        }
+    }
+}
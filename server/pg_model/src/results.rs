@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pg_wire::{BackendMessage, ColumnMetadata, PgType};
+use crate::pg_types::{ColumnMetadata, PgType};
+use pg_wire::BackendMessage;
 use std::fmt::{self, Display, Formatter};
 
 /// Represents result of SQL query execution
@@ -31,10 +32,20 @@ pub enum QueryEvent {
     TableCreated,
     /// Table successfully dropped
     TableDropped,
+    /// Index successfully created
+    IndexCreated,
+    /// Index successfully dropped
+    IndexDropped,
+    /// Table successfully altered
+    TableAltered,
     /// Variable successfully set
     VariableSet,
     /// Transaction is started
     TransactionStarted,
+    /// Transaction is committed
+    TransactionCommitted,
+    /// Transaction is rolled back
+    TransactionRolledBack,
     /// Number of records inserted into a table
     RecordsInserted(usize),
     /// Row description information
@@ -56,47 +67,111 @@ pub enum QueryEvent {
     /// Prepare statement description
     StatementDescription(Description),
     /// Processing of the query is complete
-    QueryComplete,
+    QueryComplete(TransactionStatus),
     /// Parsing the extended query is complete
     ParseComplete,
     /// Binding the extended query is complete
     BindComplete,
 }
 
-impl Into<BackendMessage> for QueryEvent {
-    fn into(self) -> BackendMessage {
-        match self {
+impl QueryEvent {
+    /// Encoded backend message of the event, descriptions are encoded here
+    /// as `BackendMessage` can't describe types unknown to `pg_wire`
+    pub fn as_vec(&self) -> Vec<u8> {
+        let message = match self {
             QueryEvent::SchemaCreated => BackendMessage::CommandComplete("CREATE SCHEMA".to_owned()),
             QueryEvent::SchemaDropped => BackendMessage::CommandComplete("DROP SCHEMA".to_owned()),
             QueryEvent::TableCreated => BackendMessage::CommandComplete("CREATE TABLE".to_owned()),
             QueryEvent::TableDropped => BackendMessage::CommandComplete("DROP TABLE".to_owned()),
+            QueryEvent::IndexCreated => BackendMessage::CommandComplete("CREATE INDEX".to_owned()),
+            QueryEvent::IndexDropped => BackendMessage::CommandComplete("DROP INDEX".to_owned()),
+            QueryEvent::TableAltered => BackendMessage::CommandComplete("ALTER TABLE".to_owned()),
             QueryEvent::VariableSet => BackendMessage::CommandComplete("SET".to_owned()),
             QueryEvent::TransactionStarted => BackendMessage::CommandComplete("BEGIN".to_owned()),
+            QueryEvent::TransactionCommitted => BackendMessage::CommandComplete("COMMIT".to_owned()),
+            QueryEvent::TransactionRolledBack => BackendMessage::CommandComplete("ROLLBACK".to_owned()),
             QueryEvent::RecordsInserted(records) => BackendMessage::CommandComplete(format!("INSERT 0 {}", records)),
-            QueryEvent::RowDescription(description) => BackendMessage::RowDescription(description),
-            QueryEvent::DataRow(data) => BackendMessage::DataRow(data),
+            QueryEvent::RowDescription(description) => return row_description(description),
+            QueryEvent::DataRow(data) => BackendMessage::DataRow(data.clone()),
             QueryEvent::RecordsSelected(records) => BackendMessage::CommandComplete(format!("SELECT {}", records)),
             QueryEvent::RecordsUpdated(records) => BackendMessage::CommandComplete(format!("UPDATE {}", records)),
             QueryEvent::RecordsDeleted(records) => BackendMessage::CommandComplete(format!("DELETE {}", records)),
             QueryEvent::StatementPrepared => BackendMessage::CommandComplete("PREPARE".to_owned()),
             QueryEvent::StatementDeallocated => BackendMessage::CommandComplete("DEALLOCATE".to_owned()),
-            QueryEvent::StatementParameters(param_types) => BackendMessage::ParameterDescription(param_types),
+            QueryEvent::StatementParameters(param_types) => return parameter_description(param_types),
             QueryEvent::StatementDescription(description) => {
                 if description.is_empty() {
                     BackendMessage::NoData
                 } else {
-                    BackendMessage::RowDescription(
-                        description
-                            .into_iter()
-                            .map(|(name, pg_type)| ColumnMetadata::new(name, pg_type))
-                            .collect(),
-                    )
+                    return row_description(
+                        &description
+                            .iter()
+                            .map(|(name, pg_type)| ColumnMetadata::new(name, *pg_type))
+                            .collect::<Vec<_>>(),
+                    );
                 }
             }
-            QueryEvent::QueryComplete => BackendMessage::ReadyForQuery,
+            // use `TransactionStatus::ready_for_query` to report status other than idle
+            QueryEvent::QueryComplete(_) => BackendMessage::ReadyForQuery,
             QueryEvent::ParseComplete => BackendMessage::ParseComplete,
             QueryEvent::BindComplete => BackendMessage::BindComplete,
-        }
+        };
+        message.as_vec()
+    }
+}
+
+fn row_description(columns: &[ColumnMetadata]) -> Vec<u8> {
+    let mut body = (columns.len() as i16).to_be_bytes().to_vec();
+    for column in columns {
+        body.extend_from_slice(column.name.as_bytes());
+        body.push(0);
+        body.extend_from_slice(&0i32.to_be_bytes()); // table OID
+        body.extend_from_slice(&0i16.to_be_bytes()); // column attribute number
+        body.extend_from_slice(&column.type_id.to_be_bytes());
+        body.extend_from_slice(&column.type_size.to_be_bytes());
+        body.extend_from_slice(&(-1i32).to_be_bytes()); // type modifier
+        body.extend_from_slice(&0i16.to_be_bytes()); // text format code
+    }
+    message(b'T', body)
+}
+
+fn parameter_description(param_types: &[PgType]) -> Vec<u8> {
+    let mut body = (param_types.len() as i16).to_be_bytes().to_vec();
+    for param_type in param_types {
+        body.extend_from_slice(&param_type.type_oid().to_be_bytes());
+    }
+    message(b't', body)
+}
+
+fn message(tag: u8, body: Vec<u8>) -> Vec<u8> {
+    let mut message = vec![tag];
+    message.extend_from_slice(&((body.len() + 4) as i32).to_be_bytes());
+    message.extend(body);
+    message
+}
+
+/// Transaction status of a session that is reported when backend is ready
+/// for a new query
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransactionStatus {
+    /// Not in a transaction block
+    Idle,
+    /// In a transaction block
+    InTransaction,
+    /// In a failed transaction block, queries are rejected until the block is ended
+    Failed,
+}
+
+impl TransactionStatus {
+    /// Encoded `ReadyForQuery` message with transaction status indicator,
+    /// `BackendMessage::ReadyForQuery` always reports idle status
+    pub fn ready_for_query(&self) -> Vec<u8> {
+        let indicator = match self {
+            TransactionStatus::Idle => b'I',
+            TransactionStatus::InTransaction => b'T',
+            TransactionStatus::Failed => b'E',
+        };
+        vec![b'Z', 0, 0, 0, 5, indicator]
     }
 }
 
@@ -137,6 +212,8 @@ pub(crate) enum QueryErrorKind {
     SchemaDoesNotExist(String),
     SchemaHasDependentObjects(String),
     TableDoesNotExist(String),
+    IndexAlreadyExists(String),
+    IndexDoesNotExist(String),
     ColumnDoesNotExist(String),
     IndeterminateParameterDataType {
         param_index: usize,
@@ -165,11 +242,17 @@ pub(crate) enum QueryErrorKind {
         column_name: String,
         row_index: usize, // TODO make it optional - does not make sense for update query
     },
+    StringDataRightTruncation(String),
     UndefinedFunction {
         operator: String,
         left_type: String,
         right_type: String,
     },
+    UndefinedPrefixOperator {
+        operator: String,
+        operand_type: String,
+    },
+    AmbiguousFunction(String),
     AmbiguousColumnName {
         column: String,
     },
@@ -182,6 +265,73 @@ pub(crate) enum QueryErrorKind {
         value: String,
     },
     DuplicateColumn(String),
+    ValueOutOfRange(String),
+    DivisionByZero,
+    InvalidArgumentForPowerFunction,
+    DatatypeMismatch {
+        argument_of: String,
+        expected_type: String,
+        actual_type: String,
+    },
+    InFailedSqlTransaction,
+    ActiveSqlTransaction(String),
+    SerializationFailure,
+    UniqueViolation(String),
+    NotNullViolation(String),
+    CheckViolation {
+        table_name: String,
+        constraint_name: String,
+    },
+    ForeignKeyViolation {
+        table_name: String,
+        constraint_name: String,
+    },
+    RowIsReferenced {
+        table_name: String,
+        constraint_name: String,
+        referencing_table_name: String,
+    },
+    MultiplePrimaryKeys(String),
+    IndexIsRequiredByConstraint {
+        index_name: String,
+        table_name: String,
+    },
+    TableHasDependentObjects(String),
+    ColumnAlreadyExists {
+        table_name: String,
+        column_name: String,
+    },
+    UndefinedColumnOfTable {
+        table_name: String,
+        column_name: String,
+    },
+    ColumnContainsNulls {
+        table_name: String,
+        column_name: String,
+    },
+    ColumnHasDependentObjects {
+        table_name: String,
+        column_name: String,
+    },
+    ColumnCannotBeCast {
+        column_name: String,
+        type_name: String,
+    },
+    InvalidForeignKey(String),
+    ForeignKeyCannotBeImplemented(String),
+    InvalidRowCountInLimitClause,
+    InvalidRowCountInResultOffsetClause,
+    OrderByPositionIsNotInSelectList(String),
+    FunctionDoesNotExist(String),
+    AggregateFunctionsAreNotAllowedIn(String),
+    AggregateFunctionCallsCannotBeNested,
+    ColumnMustAppearInGroupBy(String),
+    MissingFromClauseEntry(String),
+    DuplicateAlias(String),
+    CannotCoerce {
+        source_type: String,
+        target_type: String,
+    },
 }
 
 impl QueryErrorKind {
@@ -192,6 +342,8 @@ impl QueryErrorKind {
             Self::SchemaDoesNotExist(_) => "3F000",
             Self::SchemaHasDependentObjects(_) => "2BP01",
             Self::TableDoesNotExist(_) => "42P01",
+            Self::IndexAlreadyExists(_) => "42P07",
+            Self::IndexDoesNotExist(_) => "42704",
             Self::ColumnDoesNotExist(_) => "42703",
             Self::IndeterminateParameterDataType { .. } => "42P18",
             Self::InvalidParameterValue(_) => "22023",
@@ -203,13 +355,48 @@ impl QueryErrorKind {
             Self::TooManyInsertExpressions => "42601",
             Self::NumericTypeOutOfRange { .. } => "22003",
             Self::DataTypeMismatch { .. } => "2200G",
-            Self::StringTypeLengthMismatch { .. } => "22026",
+            Self::StringTypeLengthMismatch { .. } => "22001",
+            Self::StringDataRightTruncation(_) => "22001",
             Self::UndefinedFunction { .. } => "42883",
+            Self::UndefinedPrefixOperator { .. } => "42883",
+            Self::AmbiguousFunction(_) => "42725",
             Self::AmbiguousColumnName { .. } => "42702",
             Self::UndefinedColumn { .. } => "42883",
             Self::SyntaxError(_) => "42601",
             Self::InvalidTextRepresentation { .. } => "22P02",
             Self::DuplicateColumn(_) => "42701",
+            Self::ValueOutOfRange(_) => "22003",
+            Self::DivisionByZero => "22012",
+            Self::InvalidArgumentForPowerFunction => "2201F",
+            Self::DatatypeMismatch { .. } => "42804",
+            Self::InFailedSqlTransaction => "25P02",
+            Self::ActiveSqlTransaction(_) => "25001",
+            Self::SerializationFailure => "40001",
+            Self::UniqueViolation(_) => "23505",
+            Self::NotNullViolation(_) => "23502",
+            Self::CheckViolation { .. } => "23514",
+            Self::ForeignKeyViolation { .. } => "23503",
+            Self::RowIsReferenced { .. } => "23503",
+            Self::MultiplePrimaryKeys(_) => "42P16",
+            Self::IndexIsRequiredByConstraint { .. } => "2BP01",
+            Self::TableHasDependentObjects(_) => "2BP01",
+            Self::ColumnAlreadyExists { .. } => "42701",
+            Self::UndefinedColumnOfTable { .. } => "42703",
+            Self::ColumnContainsNulls { .. } => "23502",
+            Self::ColumnHasDependentObjects { .. } => "2BP01",
+            Self::ColumnCannotBeCast { .. } => "42804",
+            Self::InvalidForeignKey(_) => "42830",
+            Self::ForeignKeyCannotBeImplemented(_) => "42804",
+            Self::InvalidRowCountInLimitClause => "2201W",
+            Self::InvalidRowCountInResultOffsetClause => "2201X",
+            Self::OrderByPositionIsNotInSelectList(_) => "42P10",
+            Self::FunctionDoesNotExist(_) => "42883",
+            Self::AggregateFunctionsAreNotAllowedIn(_) => "42803",
+            Self::AggregateFunctionCallsCannotBeNested => "42803",
+            Self::ColumnMustAppearInGroupBy(_) => "42803",
+            Self::MissingFromClauseEntry(_) => "42P01",
+            Self::DuplicateAlias(_) => "42712",
+            Self::CannotCoerce { .. } => "42846",
         }
     }
 }
@@ -224,6 +411,8 @@ impl Display for QueryErrorKind {
                 write!(f, "schema \"{}\" has dependent objects", schema_name)
             }
             Self::TableDoesNotExist(table_name) => write!(f, "table \"{}\" does not exist", table_name),
+            Self::IndexAlreadyExists(index_name) => write!(f, "relation \"{}\" already exists", index_name),
+            Self::IndexDoesNotExist(index_name) => write!(f, "index \"{}\" does not exist", index_name),
             Self::ColumnDoesNotExist(column) => write!(f, "column {} does not exist", column),
             Self::IndeterminateParameterDataType { param_index } => {
                 write!(f, "could not determine data type of parameter ${}", param_index + 1)
@@ -277,6 +466,11 @@ impl Display for QueryErrorKind {
                 "operator does not exist: ({} {} {})",
                 left_type, operator, right_type
             ),
+            Self::UndefinedPrefixOperator { operator, operand_type } => {
+                write!(f, "operator does not exist: {} {}", operator, operand_type)
+            }
+            Self::StringDataRightTruncation(type_name) => write!(f, "value too long for type {}", type_name),
+            Self::AmbiguousFunction(operator) => write!(f, "operator is not unique: {}", operator),
             Self::AmbiguousColumnName { column } => write!(f, "use of ambiguous column name in context: '{}'", column),
             Self::UndefinedColumn { column } => write!(f, "use of undefined column: '{}'", column),
             Self::SyntaxError(expression) => write!(f, "syntax error: {}", expression),
@@ -284,6 +478,140 @@ impl Display for QueryErrorKind {
                 write!(f, "invalid input syntax for type {}: \"{}\"", pg_type, value)
             }
             Self::DuplicateColumn(name) => write!(f, "column \"{}\" specified more than once", name),
+            Self::ValueOutOfRange(type_name) => write!(f, "{} out of range", type_name),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::InvalidArgumentForPowerFunction => write!(f, "invalid argument for power function"),
+            Self::DatatypeMismatch {
+                argument_of,
+                expected_type,
+                actual_type,
+            } => write!(
+                f,
+                "argument of {} must be type {}, not type {}",
+                argument_of, expected_type, actual_type
+            ),
+            Self::InFailedSqlTransaction => write!(
+                f,
+                "current transaction is aborted, commands ignored until end of transaction block"
+            ),
+            Self::ActiveSqlTransaction(command) => write!(f, "{} cannot run inside a transaction block", command),
+            Self::SerializationFailure => write!(f, "could not serialize access due to concurrent update"),
+            Self::UniqueViolation(constraint_name) => {
+                write!(
+                    f,
+                    "duplicate key value violates unique constraint \"{}\"",
+                    constraint_name
+                )
+            }
+            Self::NotNullViolation(column_name) => {
+                write!(
+                    f,
+                    "null value in column \"{}\" violates not-null constraint",
+                    column_name
+                )
+            }
+            Self::CheckViolation {
+                table_name,
+                constraint_name,
+            } => write!(
+                f,
+                "new row for relation \"{}\" violates check constraint \"{}\"",
+                table_name, constraint_name
+            ),
+            Self::ForeignKeyViolation {
+                table_name,
+                constraint_name,
+            } => write!(
+                f,
+                "insert or update on table \"{}\" violates foreign key constraint \"{}\"",
+                table_name, constraint_name
+            ),
+            Self::RowIsReferenced {
+                table_name,
+                constraint_name,
+                referencing_table_name,
+            } => write!(
+                f,
+                "update or delete on table \"{}\" violates foreign key constraint \"{}\" on table \"{}\"",
+                table_name, constraint_name, referencing_table_name
+            ),
+            Self::MultiplePrimaryKeys(table_name) => {
+                write!(f, "multiple primary keys for table \"{}\" are not allowed", table_name)
+            }
+            Self::IndexIsRequiredByConstraint { index_name, table_name } => write!(
+                f,
+                "cannot drop index {} because constraint {} on table {} requires it",
+                index_name, index_name, table_name
+            ),
+            Self::TableHasDependentObjects(table_name) => {
+                write!(f, "cannot drop table {} because other objects depend on it", table_name)
+            }
+            Self::ColumnAlreadyExists {
+                table_name,
+                column_name,
+            } => write!(
+                f,
+                "column \"{}\" of relation \"{}\" already exists",
+                column_name, table_name
+            ),
+            Self::UndefinedColumnOfTable {
+                table_name,
+                column_name,
+            } => write!(
+                f,
+                "column \"{}\" of relation \"{}\" does not exist",
+                column_name, table_name
+            ),
+            Self::ColumnContainsNulls {
+                table_name,
+                column_name,
+            } => write!(
+                f,
+                "column \"{}\" of relation \"{}\" contains null values",
+                column_name, table_name
+            ),
+            Self::ColumnHasDependentObjects {
+                table_name,
+                column_name,
+            } => write!(
+                f,
+                "cannot drop column {} of table {} because other objects depend on it",
+                column_name, table_name
+            ),
+            Self::ColumnCannotBeCast { column_name, type_name } => write!(
+                f,
+                "column \"{}\" cannot be cast automatically to type {}",
+                column_name, type_name
+            ),
+            Self::InvalidForeignKey(message) => write!(f, "{}", message),
+            Self::ForeignKeyCannotBeImplemented(constraint_name) => {
+                write!(
+                    f,
+                    "foreign key constraint \"{}\" cannot be implemented",
+                    constraint_name
+                )
+            }
+            Self::InvalidRowCountInLimitClause => write!(f, "LIMIT must not be negative"),
+            Self::InvalidRowCountInResultOffsetClause => write!(f, "OFFSET must not be negative"),
+            Self::OrderByPositionIsNotInSelectList(position) => {
+                write!(f, "ORDER BY position {} is not in select list", position)
+            }
+            Self::FunctionDoesNotExist(function) => write!(f, "function {} does not exist", function),
+            Self::AggregateFunctionsAreNotAllowedIn(clause) => {
+                write!(f, "aggregate functions are not allowed in {}", clause)
+            }
+            Self::AggregateFunctionCallsCannotBeNested => write!(f, "aggregate function calls cannot be nested"),
+            Self::ColumnMustAppearInGroupBy(column) => write!(
+                f,
+                "column \"{}\" must appear in the GROUP BY clause or be used in an aggregate function",
+                column
+            ),
+            Self::MissingFromClauseEntry(table) => write!(f, "missing FROM-clause entry for table \"{}\"", table),
+            Self::DuplicateAlias(table) => write!(f, "table name \"{}\" specified more than once", table),
+            Self::CannotCoerce {
+                source_type,
+                target_type,
+            } => write!(f, "cannot cast type {} to {}", source_type, target_type),
         }
     }
 }
@@ -357,6 +685,22 @@ impl QueryError {
         }
     }
 
+    /// index already exists error constructor
+    pub fn index_already_exists<S: ToString>(index_name: S) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::IndexAlreadyExists(index_name.to_string()),
+        }
+    }
+
+    /// index does not exist error constructor
+    pub fn index_does_not_exist<S: ToString>(index_name: S) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::IndexDoesNotExist(index_name.to_string()),
+        }
+    }
+
     /// column does not exists error constructor
     pub fn column_does_not_exist<S: ToString>(non_existing_column: S) -> QueryError {
         QueryError {
@@ -449,6 +793,25 @@ impl QueryError {
         }
     }
 
+    /// prefix operator is not defined for the type of its operand
+    pub fn undefined_prefix_operator<O: ToString, S: ToString>(operator: O, operand_type: S) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::UndefinedPrefixOperator {
+                operator: operator.to_string(),
+                operand_type: operand_type.to_string(),
+            },
+        }
+    }
+
+    /// operator could be resolved to more than one function for operands
+    pub fn ambiguous_function<O: ToString>(operator: O) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::AmbiguousFunction(operator.to_string()),
+        }
+    }
+
     /// when the name of a column is ambiguous in a multi-table context
     pub fn ambiguous_column<S: ToString>(column: S) -> QueryError {
         QueryError {
@@ -512,6 +875,14 @@ impl QueryError {
         }
     }
 
+    /// string value does not fit into its type
+    pub fn string_data_right_truncation<S: ToString>(type_name: S) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::StringDataRightTruncation(type_name.to_string()),
+        }
+    }
+
     /// invalid text representation
     pub fn invalid_text_representation<S: ToString>(pg_type: PgType, value: S) -> QueryError {
         QueryError {
@@ -530,152 +901,549 @@ impl QueryError {
             kind: QueryErrorKind::DuplicateColumn(column.to_string()),
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// result of an operation does not fit into its type
+    pub fn value_out_of_range<S: ToString>(type_name: S) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::ValueOutOfRange(type_name.to_string()),
+        }
+    }
 
-    #[cfg(test)]
-    mod query_event {
-        use super::*;
+    /// division or modulo by zero
+    pub fn division_by_zero() -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::DivisionByZero,
+        }
+    }
 
-        #[test]
-        fn create_schema() {
-            let message: BackendMessage = QueryEvent::SchemaCreated.into();
-            assert_eq!(message, BackendMessage::CommandComplete("CREATE SCHEMA".to_owned()))
+    /// zero raised to a negative power or negative number raised to a non-integer power
+    pub fn invalid_argument_for_power_function() -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::InvalidArgumentForPowerFunction,
         }
+    }
 
-        #[test]
-        fn drop_schema() {
-            let message: BackendMessage = QueryEvent::SchemaDropped.into();
-            assert_eq!(message, BackendMessage::CommandComplete("DROP SCHEMA".to_owned()))
+    /// argument of a clause or an operator has a type other than required
+    pub fn datatype_mismatch<A: ToString, S: ToString>(argument_of: A, expected_type: S, actual_type: S) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::DatatypeMismatch {
+                argument_of: argument_of.to_string(),
+                expected_type: expected_type.to_string(),
+                actual_type: actual_type.to_string(),
+            },
         }
+    }
 
-        #[test]
-        fn create_table() {
-            let message: BackendMessage = QueryEvent::TableCreated.into();
-            assert_eq!(message, BackendMessage::CommandComplete("CREATE TABLE".to_owned()));
+    /// query is sent in a transaction block that failed before
+    pub fn in_failed_sql_transaction() -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::InFailedSqlTransaction,
         }
+    }
 
-        #[test]
-        fn drop_table() {
-            let message: BackendMessage = QueryEvent::TableDropped.into();
-            assert_eq!(message, BackendMessage::CommandComplete("DROP TABLE".to_owned()));
+    /// command can't be executed in an explicit transaction block
+    pub fn active_sql_transaction<S: ToString>(command: S) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::ActiveSqlTransaction(command.to_string()),
         }
+    }
 
-        #[test]
-        fn insert_record() {
-            let records_number = 3;
-            let message: BackendMessage = QueryEvent::RecordsInserted(records_number).into();
-            assert_eq!(
-                message,
-                BackendMessage::CommandComplete(format!("INSERT 0 {}", records_number))
-            )
+    /// transaction could not be committed because of a concurrent update
+    pub fn serialization_failure() -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::SerializationFailure,
         }
+    }
 
-        #[test]
-        fn row_description() {
-            let message: BackendMessage = QueryEvent::RowDescription(vec![
-                ColumnMetadata::new("column_name_1", PgType::SmallInt),
-                ColumnMetadata::new("column_name_2", PgType::SmallInt),
-            ])
-            .into();
+    /// row has the same values of unique or primary key columns as another row
+    pub fn unique_violation<C: ToString>(constraint_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::UniqueViolation(constraint_name.to_string()),
+        }
+    }
 
-            assert_eq!(
-                message,
-                BackendMessage::RowDescription(vec![
-                    ColumnMetadata::new("column_name_1", PgType::SmallInt),
-                    ColumnMetadata::new("column_name_2", PgType::SmallInt),
-                ])
-            )
+    /// `NULL` is stored into a column that does not allow it
+    pub fn not_null_violation<C: ToString>(column_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::NotNullViolation(column_name.to_string()),
         }
+    }
 
-        #[test]
-        fn data_row() {
-            let message: BackendMessage = QueryEvent::DataRow(vec!["1".to_owned(), "2".to_owned()]).into();
-            assert_eq!(message, BackendMessage::DataRow(vec!["1".to_owned(), "2".to_owned()]))
+    /// inserted or updated row does not satisfy a check constraint of the table
+    pub fn check_violation<T: ToString, C: ToString>(table_name: T, constraint_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::CheckViolation {
+                table_name: table_name.to_string(),
+                constraint_name: constraint_name.to_string(),
+            },
         }
+    }
 
-        #[test]
-        fn select_records() {
-            let message: BackendMessage = QueryEvent::RecordsSelected(2).into();
-            assert_eq!(message, BackendMessage::CommandComplete("SELECT 2".to_owned()));
+    /// inserted or updated row references a row that does not exist
+    pub fn foreign_key_violation<T: ToString, C: ToString>(table_name: T, constraint_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::ForeignKeyViolation {
+                table_name: table_name.to_string(),
+                constraint_name: constraint_name.to_string(),
+            },
         }
+    }
 
-        #[test]
-        fn update_records() {
-            let records_number = 3;
-            let message: BackendMessage = QueryEvent::RecordsUpdated(records_number).into();
-            assert_eq!(
-                message,
-                BackendMessage::CommandComplete(format!("UPDATE {}", records_number))
-            );
+    /// deleted or updated row is referenced by a row of another table
+    pub fn row_is_referenced<T: ToString, C: ToString, R: ToString>(
+        table_name: T,
+        constraint_name: C,
+        referencing_table_name: R,
+    ) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::RowIsReferenced {
+                table_name: table_name.to_string(),
+                constraint_name: constraint_name.to_string(),
+                referencing_table_name: referencing_table_name.to_string(),
+            },
         }
+    }
 
-        #[test]
-        fn delete_records() {
-            let records_number = 3;
-            let message: BackendMessage = QueryEvent::RecordsDeleted(records_number).into();
-            assert_eq!(
-                message,
-                BackendMessage::CommandComplete(format!("DELETE {}", records_number))
-            )
+    /// table definition has more than one primary key
+    pub fn multiple_primary_keys<T: ToString>(table_name: T) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::MultiplePrimaryKeys(table_name.to_string()),
         }
+    }
 
-        #[test]
-        fn prepare_statement() {
-            let message: BackendMessage = QueryEvent::StatementPrepared.into();
-            assert_eq!(message, BackendMessage::CommandComplete("PREPARE".to_owned()))
+    /// index of unique or primary key constraint is dropped only together with its table
+    pub fn index_is_required_by_constraint<I: ToString, T: ToString>(index_name: I, table_name: T) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::IndexIsRequiredByConstraint {
+                index_name: index_name.to_string(),
+                table_name: table_name.to_string(),
+            },
         }
+    }
 
-        #[test]
-        fn deallocate_statement() {
-            let message: BackendMessage = QueryEvent::StatementDeallocated.into();
-            assert_eq!(message, BackendMessage::CommandComplete("DEALLOCATE".to_owned()))
+    /// table is referenced by a foreign key of another table
+    pub fn table_has_dependent_objects<T: ToString>(table_name: T) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::TableHasDependentObjects(table_name.to_string()),
         }
+    }
 
-        #[test]
-        fn statement_description() {
-            let message: BackendMessage =
-                QueryEvent::StatementDescription(vec![("si_column".to_owned(), PgType::SmallInt)]).into();
-            assert_eq!(
-                message,
-                BackendMessage::RowDescription(vec![ColumnMetadata::new("si_column", PgType::SmallInt)])
-            )
+    pub fn column_already_exists<T: ToString, C: ToString>(table_name: T, column_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::ColumnAlreadyExists {
+                table_name: table_name.to_string(),
+                column_name: column_name.to_string(),
+            },
         }
+    }
 
-        #[test]
-        fn statement_parameters() {
-            let message: BackendMessage = QueryEvent::StatementParameters(vec![PgType::SmallInt]).into();
-            assert_eq!(message, BackendMessage::ParameterDescription(vec![PgType::SmallInt]))
+    /// altered column does not exist in the table
+    pub fn column_of_table_does_not_exist<T: ToString, C: ToString>(table_name: T, column_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::UndefinedColumnOfTable {
+                table_name: table_name.to_string(),
+                column_name: column_name.to_string(),
+            },
         }
+    }
 
-        #[test]
-        fn complete_query() {
-            let message: BackendMessage = QueryEvent::QueryComplete.into();
-            assert_eq!(message, BackendMessage::ReadyForQuery)
+    /// column that can't be `NULL` is added to a table that has rows
+    pub fn column_contains_nulls<T: ToString, C: ToString>(table_name: T, column_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::ColumnContainsNulls {
+                table_name: table_name.to_string(),
+                column_name: column_name.to_string(),
+            },
         }
+    }
 
-        #[test]
-        fn complete_parse() {
-            let message: BackendMessage = QueryEvent::ParseComplete.into();
-            assert_eq!(message, BackendMessage::ParseComplete)
+    /// column is referenced by a foreign key of another table
+    pub fn column_has_dependent_objects<T: ToString, C: ToString>(table_name: T, column_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::ColumnHasDependentObjects {
+                table_name: table_name.to_string(),
+                column_name: column_name.to_string(),
+            },
         }
+    }
 
-        #[test]
-        fn complete_bind() {
-            let message: BackendMessage = QueryEvent::BindComplete.into();
-            assert_eq!(message, BackendMessage::BindComplete)
+    pub fn column_cannot_be_cast<C: ToString, T: ToString>(column_name: C, type_name: T) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::ColumnCannotBeCast {
+                column_name: column_name.to_string(),
+                type_name: type_name.to_string(),
+            },
         }
     }
 
-    #[cfg(test)]
-    mod query_error {
-        use super::*;
+    /// referenced columns of a foreign key are not columns of a unique constraint
+    pub fn no_unique_constraint_for_referenced_table<T: ToString>(table_name: T) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::InvalidForeignKey(format!(
+                "there is no unique constraint matching given keys for referenced table \"{}\"",
+                table_name.to_string()
+            )),
+        }
+    }
 
-        #[test]
-        fn schema_already_exists() {
+    pub fn foreign_key_columns_disagree() -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::InvalidForeignKey(
+                "number of referencing and referenced columns for foreign key disagree".to_owned(),
+            ),
+        }
+    }
+
+    /// referencing and referenced columns of a foreign key have incompatible types
+    pub fn foreign_key_cannot_be_implemented<C: ToString>(constraint_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::ForeignKeyCannotBeImplemented(constraint_name.to_string()),
+        }
+    }
+
+    /// negative LIMIT
+    pub fn invalid_row_count_in_limit_clause() -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::InvalidRowCountInLimitClause,
+        }
+    }
+
+    /// negative OFFSET
+    pub fn invalid_row_count_in_result_offset_clause() -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::InvalidRowCountInResultOffsetClause,
+        }
+    }
+
+    /// ORDER BY refers to a position that is out of select list
+    pub fn order_by_position_is_not_in_select_list<P: ToString>(position: P) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::OrderByPositionIsNotInSelectList(position.to_string()),
+        }
+    }
+
+    /// function or aggregate with the given arguments is not defined
+    pub fn function_does_not_exist<F: ToString>(function: F) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::FunctionDoesNotExist(function.to_string()),
+        }
+    }
+
+    /// aggregate function is used in a clause that is evaluated for each row
+    pub fn aggregate_functions_are_not_allowed_in<C: ToString>(clause: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::AggregateFunctionsAreNotAllowedIn(clause.to_string()),
+        }
+    }
+
+    /// aggregate function is used in an argument of another aggregate function
+    pub fn aggregate_function_calls_cannot_be_nested() -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::AggregateFunctionCallsCannotBeNested,
+        }
+    }
+
+    /// column of a grouped query is used outside of grouping expressions and aggregate functions
+    pub fn column_must_appear_in_group_by<C: ToString>(column_name: C) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::ColumnMustAppearInGroupBy(column_name.to_string()),
+        }
+    }
+
+    /// column is qualified with a name of a table that is not listed in `FROM` clause
+    pub fn missing_from_clause_entry<T: ToString>(table_name: T) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::MissingFromClauseEntry(table_name.to_string()),
+        }
+    }
+
+    /// the same table name or alias is used for several tables of `FROM` clause
+    pub fn duplicate_alias<T: ToString>(table_name: T) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::DuplicateAlias(table_name.to_string()),
+        }
+    }
+
+    /// there is no conversion between the types
+    pub fn cannot_coerce<S: ToString, T: ToString>(source_type: S, target_type: T) -> QueryError {
+        QueryError {
+            severity: Severity::Error,
+            kind: QueryErrorKind::CannotCoerce {
+                source_type: source_type.to_string(),
+                target_type: target_type.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(test)]
+    mod query_event {
+        use super::*;
+
+        #[test]
+        fn create_schema() {
+            assert_eq!(
+                QueryEvent::SchemaCreated.as_vec(),
+                BackendMessage::CommandComplete("CREATE SCHEMA".to_owned()).as_vec()
+            )
+        }
+
+        #[test]
+        fn drop_schema() {
+            assert_eq!(
+                QueryEvent::SchemaDropped.as_vec(),
+                BackendMessage::CommandComplete("DROP SCHEMA".to_owned()).as_vec()
+            )
+        }
+
+        #[test]
+        fn create_table() {
+            assert_eq!(
+                QueryEvent::TableCreated.as_vec(),
+                BackendMessage::CommandComplete("CREATE TABLE".to_owned()).as_vec()
+            );
+        }
+
+        #[test]
+        fn drop_table() {
+            assert_eq!(
+                QueryEvent::TableDropped.as_vec(),
+                BackendMessage::CommandComplete("DROP TABLE".to_owned()).as_vec()
+            );
+        }
+
+        #[test]
+        fn create_index() {
+            assert_eq!(
+                QueryEvent::IndexCreated.as_vec(),
+                BackendMessage::CommandComplete("CREATE INDEX".to_owned()).as_vec()
+            );
+        }
+
+        #[test]
+        fn drop_index() {
+            assert_eq!(
+                QueryEvent::IndexDropped.as_vec(),
+                BackendMessage::CommandComplete("DROP INDEX".to_owned()).as_vec()
+            );
+        }
+
+        #[test]
+        fn alter_table() {
+            assert_eq!(
+                QueryEvent::TableAltered.as_vec(),
+                BackendMessage::CommandComplete("ALTER TABLE".to_owned()).as_vec()
+            );
+        }
+
+        #[test]
+        fn insert_record() {
+            let records_number = 3;
+            assert_eq!(
+                QueryEvent::RecordsInserted(records_number).as_vec(),
+                BackendMessage::CommandComplete(format!("INSERT 0 {}", records_number)).as_vec()
+            )
+        }
+
+        #[test]
+        fn row_description() {
+            assert_eq!(
+                QueryEvent::RowDescription(vec![
+                    ColumnMetadata::new("c1", PgType::SmallInt),
+                    ColumnMetadata::new("c2", PgType::Real),
+                ])
+                .as_vec(),
+                vec![
+                    b'T', 0, 0, 0, 48, 0, 2, // tag, length, number of columns
+                    b'c', b'1', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 2, 255, 255, 255, 255, 0, 0, // smallint
+                    b'c', b'2', 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 188, 0, 4, 255, 255, 255, 255, 0, 0, // real
+                ]
+            )
+        }
+
+        #[test]
+        fn data_row() {
+            assert_eq!(
+                QueryEvent::DataRow(vec!["1".to_owned(), "2".to_owned()]).as_vec(),
+                BackendMessage::DataRow(vec!["1".to_owned(), "2".to_owned()]).as_vec()
+            )
+        }
+
+        #[test]
+        fn select_records() {
+            assert_eq!(
+                QueryEvent::RecordsSelected(2).as_vec(),
+                BackendMessage::CommandComplete("SELECT 2".to_owned()).as_vec()
+            );
+        }
+
+        #[test]
+        fn update_records() {
+            let records_number = 3;
+            assert_eq!(
+                QueryEvent::RecordsUpdated(records_number).as_vec(),
+                BackendMessage::CommandComplete(format!("UPDATE {}", records_number)).as_vec()
+            );
+        }
+
+        #[test]
+        fn delete_records() {
+            let records_number = 3;
+            assert_eq!(
+                QueryEvent::RecordsDeleted(records_number).as_vec(),
+                BackendMessage::CommandComplete(format!("DELETE {}", records_number)).as_vec()
+            )
+        }
+
+        #[test]
+        fn prepare_statement() {
+            assert_eq!(
+                QueryEvent::StatementPrepared.as_vec(),
+                BackendMessage::CommandComplete("PREPARE".to_owned()).as_vec()
+            )
+        }
+
+        #[test]
+        fn deallocate_statement() {
+            assert_eq!(
+                QueryEvent::StatementDeallocated.as_vec(),
+                BackendMessage::CommandComplete("DEALLOCATE".to_owned()).as_vec()
+            )
+        }
+
+        #[test]
+        fn statement_description() {
+            assert_eq!(
+                QueryEvent::StatementDescription(vec![("c1".to_owned(), PgType::Numeric)]).as_vec(),
+                QueryEvent::RowDescription(vec![ColumnMetadata::new("c1", PgType::Numeric)]).as_vec()
+            )
+        }
+
+        #[test]
+        fn empty_statement_description() {
+            assert_eq!(
+                QueryEvent::StatementDescription(vec![]).as_vec(),
+                BackendMessage::NoData.as_vec()
+            )
+        }
+
+        #[test]
+        fn statement_parameters() {
+            assert_eq!(
+                QueryEvent::StatementParameters(vec![PgType::SmallInt, PgType::DoublePrecision]).as_vec(),
+                vec![b't', 0, 0, 0, 14, 0, 2, 0, 0, 0, 21, 0, 0, 2, 189]
+            )
+        }
+
+        #[test]
+        fn complete_query() {
+            assert_eq!(
+                QueryEvent::QueryComplete(TransactionStatus::Idle).as_vec(),
+                BackendMessage::ReadyForQuery.as_vec()
+            )
+        }
+
+        #[test]
+        fn commit_transaction() {
+            assert_eq!(
+                QueryEvent::TransactionCommitted.as_vec(),
+                BackendMessage::CommandComplete("COMMIT".to_owned()).as_vec()
+            )
+        }
+
+        #[test]
+        fn rollback_transaction() {
+            assert_eq!(
+                QueryEvent::TransactionRolledBack.as_vec(),
+                BackendMessage::CommandComplete("ROLLBACK".to_owned()).as_vec()
+            )
+        }
+    }
+
+    #[cfg(test)]
+    mod transaction_status {
+        use super::*;
+
+        #[test]
+        fn idle() {
+            assert_eq!(
+                TransactionStatus::Idle.ready_for_query(),
+                BackendMessage::ReadyForQuery.as_vec()
+            )
+        }
+
+        #[test]
+        fn in_transaction() {
+            assert_eq!(
+                TransactionStatus::InTransaction.ready_for_query(),
+                vec![b'Z', 0, 0, 0, 5, b'T']
+            )
+        }
+
+        #[test]
+        fn failed() {
+            assert_eq!(
+                TransactionStatus::Failed.ready_for_query(),
+                vec![b'Z', 0, 0, 0, 5, b'E']
+            )
+        }
+
+        #[test]
+        fn complete_parse() {
+            assert_eq!(
+                QueryEvent::ParseComplete.as_vec(),
+                BackendMessage::ParseComplete.as_vec()
+            )
+        }
+
+        #[test]
+        fn complete_bind() {
+            assert_eq!(QueryEvent::BindComplete.as_vec(), BackendMessage::BindComplete.as_vec())
+        }
+    }
+
+    #[cfg(test)]
+    mod query_error {
+        use super::*;
+
+        #[test]
+        fn schema_already_exists() {
             let schema_name = "some_table_name";
             let message: BackendMessage = QueryError::schema_already_exists(schema_name).into();
             assert_eq!(
@@ -730,6 +1498,34 @@ mod tests {
             )
         }
 
+        #[test]
+        fn index_already_exists() {
+            let index_name = "some_index_name";
+            let message: BackendMessage = QueryError::index_already_exists(index_name).into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42P07"),
+                    Some(format!("relation \"{}\" already exists", index_name)),
+                )
+            )
+        }
+
+        #[test]
+        fn index_does_not_exist() {
+            let index_name = "some_index_name";
+            let message: BackendMessage = QueryError::index_does_not_exist(index_name).into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42704"),
+                    Some(format!("index \"{}\" does not exist", index_name)),
+                )
+            )
+        }
+
         #[test]
         fn one_column_does_not_exists() {
             let message: BackendMessage = QueryError::column_does_not_exist("column_not_in_table").into();
@@ -874,22 +1670,48 @@ mod tests {
                 message,
                 BackendMessage::ErrorResponse(
                     Some("ERROR"),
-                    Some("22026"),
+                    Some("22001"),
                     Some("value too long for type character(5) for column 'col1' at row 1".to_owned()),
                 )
             )
         }
 
         #[test]
-        fn undefined_function() {
-            let message: BackendMessage =
-                QueryError::undefined_function("||".to_owned(), "NUMBER".to_owned(), "NUMBER".to_owned()).into();
+        fn string_data_right_truncation() {
+            let message: BackendMessage = QueryError::string_data_right_truncation("char(5)").into();
             assert_eq!(
                 message,
                 BackendMessage::ErrorResponse(
                     Some("ERROR"),
-                    Some("42883"),
-                    Some("operator does not exist: (NUMBER || NUMBER)".to_owned()),
+                    Some("22001"),
+                    Some("value too long for type char(5)".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn undefined_function() {
+            let message: BackendMessage =
+                QueryError::undefined_function("||".to_owned(), "NUMBER".to_owned(), "NUMBER".to_owned()).into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42883"),
+                    Some("operator does not exist: (NUMBER || NUMBER)".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn ambiguous_function() {
+            let message: BackendMessage = QueryError::ambiguous_function("+").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42725"),
+                    Some("operator is not unique: +".to_owned()),
                 )
             )
         }
@@ -919,6 +1741,393 @@ mod tests {
                 )
             )
         }
+
+        #[test]
+        fn value_out_of_range() {
+            let message: BackendMessage = QueryError::value_out_of_range("smallint").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(Some("ERROR"), Some("22003"), Some("smallint out of range".to_owned()),)
+            )
+        }
+
+        #[test]
+        fn division_by_zero() {
+            let message: BackendMessage = QueryError::division_by_zero().into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(Some("ERROR"), Some("22012"), Some("division by zero".to_owned()),)
+            )
+        }
+
+        #[test]
+        fn invalid_argument_for_power_function() {
+            let message: BackendMessage = QueryError::invalid_argument_for_power_function().into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("2201F"),
+                    Some("invalid argument for power function".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn datatype_mismatch() {
+            let message: BackendMessage = QueryError::datatype_mismatch("WHERE", "boolean", "integer").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42804"),
+                    Some("argument of WHERE must be type boolean, not type integer".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn active_sql_transaction() {
+            let message: BackendMessage = QueryError::active_sql_transaction("CREATE TABLE").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("25001"),
+                    Some("CREATE TABLE cannot run inside a transaction block".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn in_failed_sql_transaction() {
+            let message: BackendMessage = QueryError::in_failed_sql_transaction().into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("25P02"),
+                    Some("current transaction is aborted, commands ignored until end of transaction block".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn serialization_failure() {
+            let message: BackendMessage = QueryError::serialization_failure().into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("40001"),
+                    Some("could not serialize access due to concurrent update".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn unique_violation() {
+            let message: BackendMessage = QueryError::unique_violation("table_name_pkey").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("23505"),
+                    Some("duplicate key value violates unique constraint \"table_name_pkey\"".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn not_null_violation() {
+            let message: BackendMessage = QueryError::not_null_violation("col1").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("23502"),
+                    Some("null value in column \"col1\" violates not-null constraint".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn check_violation() {
+            let message: BackendMessage = QueryError::check_violation("table_name", "table_name_col1_check").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("23514"),
+                    Some(
+                        "new row for relation \"table_name\" violates check constraint \"table_name_col1_check\""
+                            .to_owned()
+                    ),
+                )
+            )
+        }
+
+        #[test]
+        fn foreign_key_violation() {
+            let message: BackendMessage = QueryError::foreign_key_violation("child", "child_col1_fkey").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("23503"),
+                    Some(
+                        "insert or update on table \"child\" violates foreign key constraint \"child_col1_fkey\""
+                            .to_owned()
+                    ),
+                )
+            )
+        }
+
+        #[test]
+        fn row_is_referenced() {
+            let message: BackendMessage = QueryError::row_is_referenced("parent", "child_col1_fkey", "child").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("23503"),
+                    Some(
+                        "update or delete on table \"parent\" violates foreign key constraint \"child_col1_fkey\" on table \"child\""
+                            .to_owned()
+                    ),
+                )
+            )
+        }
+
+        #[test]
+        fn multiple_primary_keys() {
+            let message: BackendMessage = QueryError::multiple_primary_keys("table_name").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42P16"),
+                    Some("multiple primary keys for table \"table_name\" are not allowed".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn index_is_required_by_constraint() {
+            let message: BackendMessage =
+                QueryError::index_is_required_by_constraint("table_name_pkey", "table_name").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("2BP01"),
+                    Some(
+                        "cannot drop index table_name_pkey because constraint table_name_pkey on table table_name requires it"
+                            .to_owned()
+                    ),
+                )
+            )
+        }
+
+        #[test]
+        fn table_has_dependent_objects() {
+            let message: BackendMessage = QueryError::table_has_dependent_objects("public.parent").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("2BP01"),
+                    Some("cannot drop table public.parent because other objects depend on it".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn column_already_exists() {
+            let message: BackendMessage = QueryError::column_already_exists("table_name", "col").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42701"),
+                    Some("column \"col\" of relation \"table_name\" already exists".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn column_contains_nulls() {
+            let message: BackendMessage = QueryError::column_contains_nulls("table_name", "col").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("23502"),
+                    Some("column \"col\" of relation \"table_name\" contains null values".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn column_has_dependent_objects() {
+            let message: BackendMessage = QueryError::column_has_dependent_objects("parent", "id").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("2BP01"),
+                    Some("cannot drop column id of table parent because other objects depend on it".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn no_unique_constraint_for_referenced_table() {
+            let message: BackendMessage = QueryError::no_unique_constraint_for_referenced_table("parent").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42830"),
+                    Some(
+                        "there is no unique constraint matching given keys for referenced table \"parent\"".to_owned()
+                    ),
+                )
+            )
+        }
+
+        #[test]
+        fn invalid_row_count_in_limit_clause() {
+            let message: BackendMessage = QueryError::invalid_row_count_in_limit_clause().into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("2201W"),
+                    Some("LIMIT must not be negative".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn invalid_row_count_in_result_offset_clause() {
+            let message: BackendMessage = QueryError::invalid_row_count_in_result_offset_clause().into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("2201X"),
+                    Some("OFFSET must not be negative".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn order_by_position_is_not_in_select_list() {
+            let message: BackendMessage = QueryError::order_by_position_is_not_in_select_list(3).into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42P10"),
+                    Some("ORDER BY position 3 is not in select list".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn function_does_not_exist() {
+            let message: BackendMessage = QueryError::function_does_not_exist("sum(boolean)").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42883"),
+                    Some("function sum(boolean) does not exist".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn aggregate_functions_are_not_allowed_in() {
+            let message: BackendMessage = QueryError::aggregate_functions_are_not_allowed_in("WHERE").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42803"),
+                    Some("aggregate functions are not allowed in WHERE".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn aggregate_function_calls_cannot_be_nested() {
+            let message: BackendMessage = QueryError::aggregate_function_calls_cannot_be_nested().into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42803"),
+                    Some("aggregate function calls cannot be nested".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn column_must_appear_in_group_by() {
+            let message: BackendMessage = QueryError::column_must_appear_in_group_by("col1").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42803"),
+                    Some(
+                        "column \"col1\" must appear in the GROUP BY clause or be used in an aggregate function"
+                            .to_owned()
+                    ),
+                )
+            )
+        }
+
+        #[test]
+        fn missing_from_clause_entry() {
+            let message: BackendMessage = QueryError::missing_from_clause_entry("t").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42P01"),
+                    Some("missing FROM-clause entry for table \"t\"".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn duplicate_alias() {
+            let message: BackendMessage = QueryError::duplicate_alias("t").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42712"),
+                    Some("table name \"t\" specified more than once".to_owned()),
+                )
+            )
+        }
+
+        #[test]
+        fn cannot_coerce() {
+            let message: BackendMessage = QueryError::cannot_coerce("integer", "date").into();
+            assert_eq!(
+                message,
+                BackendMessage::ErrorResponse(
+                    Some("ERROR"),
+                    Some("42846"),
+                    Some("cannot cast type integer to date".to_owned()),
+                )
+            )
+        }
     }
 
     #[cfg(test)]
@@ -34,8 +34,8 @@
 //! 4. The client issues an `Execute` message with the name of a portal, causing
 //!    that portal to actually start scanning and returning results.
 
-use crate::results::Description;
-use pg_wire::{PgFormat, PgType};
+use crate::{pg_types::PgType, results::Description};
+use pg_wire::PgFormat;
 
 /// A prepared statement.
 #[derive(Clone, Debug, PartialEq)]
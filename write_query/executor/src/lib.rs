@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use catalog::{Database, SqlTable};
+use catalog::{Database, Transaction};
 use data_manipulation_query_result::{QueryExecution, QueryExecutionError};
 use data_manipulation_typed_queries::{DeleteQuery, InsertQuery, TypedWrite, UpdateQuery};
 use std::sync::Arc;
@@ -27,26 +27,39 @@ impl<D: Database> WriteQueryExecutor<D> {
         WriteQueryExecutor { database }
     }
 
-    pub fn execute(&self, write_query: TypedWrite) -> Result<QueryExecution, QueryExecutionError> {
+    pub fn execute(
+        &self,
+        write_query: TypedWrite,
+        transaction: &Transaction,
+    ) -> Result<QueryExecution, QueryExecutionError> {
         match write_query {
             TypedWrite::Insert(InsertQuery {
                 full_table_name,
                 values,
-            }) => Ok(QueryExecution::Inserted(
-                self.database.work_with(&full_table_name, |table| table.insert(&values)),
-            )),
-            TypedWrite::Delete(DeleteQuery { full_table_name }) => Ok(QueryExecution::Deleted(
-                self.database.work_with(&full_table_name, |table| table.delete_all()),
-            )),
+                checks,
+            }) => self
+                .database
+                .work_in(transaction, &full_table_name, |table| table.insert(&values, &checks))
+                .map(QueryExecution::Inserted),
+            TypedWrite::Delete(DeleteQuery {
+                full_table_name,
+                filter,
+            }) => self
+                .database
+                .work_in(transaction, &full_table_name, |table| table.delete(filter.as_ref()))
+                .map(QueryExecution::Deleted),
             TypedWrite::Update(UpdateQuery {
                 full_table_name,
                 column_names,
                 assignments,
-            }) => Ok(QueryExecution::Updated(
-                self.database.work_with(&full_table_name, |table| {
-                    table.update(column_names.clone(), assignments.clone())
-                }),
-            )),
+                filter,
+                checks,
+            }) => self
+                .database
+                .work_in(transaction, &full_table_name, |table| {
+                    table.update(column_names.clone(), assignments.clone(), filter.as_ref(), &checks)
+                })
+                .map(QueryExecution::Updated),
         }
     }
 }
@@ -57,7 +70,7 @@ mod tests {
     use catalog::InMemoryDatabase;
     use data_definition_operations::{Kind, Record, Step, SystemObject, SystemOperation};
     use data_manipulation_typed_tree::{StaticTypedItem, StaticTypedTree, TypedValue};
-    use definition::FullTableName;
+    use definition::{ColumnConstraints, FullTableName};
     use types::SqlType;
 
     fn create_schema_ops(schema_name: &str) -> SystemOperation {
@@ -90,6 +103,7 @@ mod tests {
                     table_name: table_name.to_owned(),
                     column_name: column_name.to_owned(),
                     sql_type: column_type,
+                    constraints: ColumnConstraints::default(),
                 },
             })
             .collect();
@@ -131,14 +145,19 @@ mod tests {
         database
             .execute(create_table_ops(SCHEMA, TABLE, vec![("col1", SqlType::small_int())]))
             .unwrap();
+        let transaction = database.begin();
         let executor = WriteQueryExecutor::new(database);
 
-        let r = executor.execute(TypedWrite::Insert(InsertQuery {
-            full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
-            values: vec![vec![Some(StaticTypedTree::Item(StaticTypedItem::Const(
-                TypedValue::SmallInt(1),
-            )))]],
-        }));
+        let r = executor.execute(
+            TypedWrite::Insert(InsertQuery {
+                full_table_name: FullTableName::from((&SCHEMA, &TABLE)),
+                values: vec![vec![Some(StaticTypedTree::Item(StaticTypedItem::Const(
+                    TypedValue::SmallInt(1),
+                )))]],
+                checks: vec![],
+            }),
+            &transaction,
+        );
 
         assert_eq!(r, Ok(QueryExecution::Inserted(1)));
     }